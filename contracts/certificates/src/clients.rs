@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Typed clients for the contracts certificates calls.
+//!
+//! The user management, course registry and progress clients come from
+//! `skillcert_interfaces`, so a signature change shows up as a compile error
+//! instead of a failed call at runtime. The helpers below build a client for
+//! the addresses set with `initialize` and `set_progress_contract`.
+
+use soroban_sdk::Env;
+
+pub use skillcert_interfaces::course_registry::{CourseRegistryClient, CourseRegistryInterface};
+pub use skillcert_interfaces::progress::{ProgressClient, ProgressInterface};
+pub use skillcert_interfaces::user_management::{UserManagementClient, UserManagementInterface};
+
+use crate::functions::completion::get_progress_contract;
+use crate::functions::config::get_config;
+
+/// Returns a client for the configured user management contract.
+pub fn user_management(env: &Env) -> UserManagementClient<'_> {
+    UserManagementClient::new(env, &get_config(env).user_mgmt_addr)
+}
+
+/// Returns a client for the configured course registry.
+pub fn course_registry(env: &Env) -> CourseRegistryClient<'_> {
+    CourseRegistryClient::new(env, &get_config(env).course_registry_addr)
+}
+
+/// Returns a client for the progress contract, or `None` if none is configured.
+pub fn try_progress(env: &Env) -> Option<ProgressClient<'_>> {
+    get_progress_contract(env).map(|address| ProgressClient::new(env, &address))
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    EmptyCourseId = 4,
+    CertificateNotFound = 5,
+    CertificateAlreadyIssued = 6,
+    CertificateRevoked = 7,
+    InvalidValidity = 8,
+    InvalidTemplate = 9,
+    InvalidPagination = 10,
+    CourseNotCompleted = 11,
+}
+
+pub fn handle_error(env: &Env, error: Error) -> ! {
+    panic_with_error!(env, error);
+}
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "AlreadyInitialized: The contract is already initialized",
+        2 => "NotInitialized: The contract is not initialized",
+        3 => "Unauthorized: The caller is not allowed to do this",
+        4 => "EmptyCourseId: The course ID is empty",
+        5 => "CertificateNotFound: The certificate does not exist",
+        6 => "CertificateAlreadyIssued: The certificate is already issued",
+        7 => "CertificateRevoked: The certificate is revoked",
+        8 => "InvalidValidity: The validity period is not valid",
+        9 => "InvalidTemplate: The certificate template is not valid",
+        10 => "InvalidPagination: The page parameters are out of range",
+        11 => "CourseNotCompleted: The student has not met the completion criteria of the course",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
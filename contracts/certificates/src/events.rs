@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! Certificate events are published with the topics
+//! `("cert", "issued" | "renewed" | "revoked", cert_id)` and badge events with
+//! `("badge", "minted" | "pending", cert_id)` and template changes with
+//! `("template", "updated", course_id)`, each with a typed payload whose
+//! first field is `version`, following the same convention as the course
+//! registry.
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol};
+
+use crate::schema::{Certificate, CertificateTemplate};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const CERTIFICATE: Symbol = symbol_short!("cert");
+const BADGE: Symbol = symbol_short!("badge");
+const TEMPLATE: Symbol = symbol_short!("template");
+
+const ISSUED: Symbol = symbol_short!("issued");
+const RENEWED: Symbol = symbol_short!("renewed");
+const REVOKED: Symbol = symbol_short!("revoked");
+const MINTED: Symbol = symbol_short!("minted");
+const PENDING: Symbol = symbol_short!("pending");
+const UPDATED: Symbol = symbol_short!("updated");
+
+/// Payload of `("cert", "issued" | "renewed" | "revoked", cert_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CertificateEvent {
+    pub version: u32,
+    pub cert_id: u32,
+    pub course_id: String,
+    pub student: Address,
+    /// Issuer, or the address that renewed or revoked the certificate
+    pub actor: Address,
+}
+
+/// Payload of `("badge", "minted" | "pending", cert_id)`.
+///
+/// `pending` is published instead of minting when no badge collection is
+/// configured, so the badge can be minted off-chain or later.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgeEvent {
+    pub version: u32,
+    pub cert_id: u32,
+    pub course_id: String,
+    pub student: Address,
+}
+
+/// Payload of `("template", "updated", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub template_uri_hash: BytesN<32>,
+    pub field_count: u32,
+    pub by: Address,
+}
+
+pub fn template_updated(
+    env: &Env,
+    course_id: &String,
+    template: &CertificateTemplate,
+    by: &Address,
+) {
+    env.events().publish(
+        (TEMPLATE, UPDATED, course_id.clone()),
+        TemplateEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            template_uri_hash: template.template_uri_hash.clone(),
+            field_count: template.fields.len(),
+            by: by.clone(),
+        },
+    );
+}
+
+pub fn certificate_issued(env: &Env, certificate: &Certificate) {
+    publish_certificate_event(env, ISSUED, certificate, &certificate.issuer);
+}
+
+pub fn certificate_renewed(env: &Env, certificate: &Certificate, by: &Address) {
+    publish_certificate_event(env, RENEWED, certificate, by);
+}
+
+pub fn certificate_revoked(env: &Env, certificate: &Certificate, by: &Address) {
+    publish_certificate_event(env, REVOKED, certificate, by);
+}
+
+pub fn badge_minted(env: &Env, certificate: &Certificate) {
+    publish_badge_event(env, MINTED, certificate);
+}
+
+pub fn badge_pending(env: &Env, certificate: &Certificate) {
+    publish_badge_event(env, PENDING, certificate);
+}
+
+fn publish_certificate_event(
+    env: &Env,
+    action: Symbol,
+    certificate: &Certificate,
+    actor: &Address,
+) {
+    env.events().publish(
+        (CERTIFICATE, action, certificate.id),
+        CertificateEvent {
+            version: EVENT_VERSION,
+            cert_id: certificate.id,
+            course_id: certificate.course_id.clone(),
+            student: certificate.student.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+fn publish_badge_event(env: &Env, action: Symbol, certificate: &Certificate) {
+    env.events().publish(
+        (BADGE, action, certificate.id),
+        BadgeEvent {
+            version: EVENT_VERSION,
+            cert_id: certificate.id,
+            course_id: certificate.course_id.clone(),
+            student: certificate.student.clone(),
+        },
+    );
+}
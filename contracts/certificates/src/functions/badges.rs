@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Completion badges.
+//!
+//! When a badge collection is configured, every issued certificate mints a
+//! badge NFT to the student by calling `mint(to, token_id)` on the collection,
+//! with the certificate ID as token ID. Without a collection, issuance only
+//! publishes a `("badge", "pending", cert_id)` event.
+
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol};
+
+use crate::events;
+use crate::functions::config::require_admin;
+use crate::schema::{Certificate, DataKey};
+
+const SET_BADGE_COLLECTION_EVENT: Symbol = symbol_short!("setBadge");
+
+/// Sets the NFT contract badges are minted on (admins only).
+///
+/// The collection must let this contract call its `mint(to, token_id)`.
+pub fn set_badge_collection(env: &Env, admin: Address, nft_contract: Address) {
+    require_admin(env, &admin);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::BadgeCollection, &nft_contract);
+
+    env.events()
+        .publish((SET_BADGE_COLLECTION_EVENT,), (admin, nft_contract));
+}
+
+/// Returns the NFT contract badges are minted on, if configured.
+pub fn get_badge_collection(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::BadgeCollection)
+}
+
+/// Mints the badge of a newly issued certificate, or only announces it when
+/// no collection is configured.
+pub(crate) fn award_badge(env: &Env, certificate: &Certificate) {
+    match get_badge_collection(env) {
+        Some(collection) => {
+            env.invoke_contract::<()>(
+                &collection,
+                &Symbol::new(env, "mint"),
+                (certificate.student.clone(), certificate.id).into_val(env),
+            );
+            events::badge_minted(env, certificate);
+        }
+        None => events::badge_pending(env, certificate),
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Certificate issuance, revocation and verification.
+
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, String, Vec};
+
+use crate::clients::{course_registry, user_management};
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::badges::award_badge;
+use crate::functions::completion::require_completion;
+use crate::functions::config::is_admin;
+use crate::functions::listing::{add_to_indexes, remove_from_indexes};
+use crate::functions::templates::get_certificate_template;
+use crate::schema::{Certificate, CertificateStatus, CertificateTemplate, Course, DataKey};
+
+pub(crate) fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
+    course_registry(env).is_course_creator(course_id, who)
+}
+
+/// Panics with `Unauthorized` unless `caller` is the course creator or an admin.
+fn require_issuer(env: &Env, caller: &Address, course_id: &String) {
+    caller.require_auth();
+    if !(is_course_creator(env, course_id, caller) || is_admin(env, caller)) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Hashes the course as the registry currently returns it, so a credential
+/// pins the course metadata it was issued for.
+fn course_hash(env: &Env, course_id: &String) -> BytesN<32> {
+    let course: Course = course_registry(env).get_course(course_id);
+    env.crypto().sha256(&course.to_xdr(env)).into()
+}
+
+fn require_certificate(env: &Env, cert_id: u32) -> Certificate {
+    get_certificate(env, cert_id).unwrap_or_else(|| handle_error(env, Error::CertificateNotFound))
+}
+
+fn store_certificate(env: &Env, certificate: &Certificate) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Certificate(certificate.id), certificate);
+}
+
+/// Returns a certificate, or `None` if it does not exist.
+pub fn get_certificate(env: &Env, cert_id: u32) -> Option<Certificate> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Certificate(cert_id))
+}
+
+fn stored_student_certificate(
+    env: &Env,
+    course_id: String,
+    student: Address,
+) -> Option<Certificate> {
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::StudentCertificate(course_id, student))
+        .and_then(|cert_id| get_certificate(env, cert_id))
+}
+
+/// Returns the primary address `student` is linked to in user management, or
+/// `None` if it isn't a linked secondary address.
+fn primary_identity(env: &Env, student: &Address) -> Option<Address> {
+    match user_management(env).try_resolve_identity(student) {
+        Ok(Ok(primary)) if primary != *student => Some(primary),
+        _ => None,
+    }
+}
+
+/// Returns the certificate of a student for a course, if any, falling back
+/// to the certificate of the learner identity the student is linked to.
+pub fn get_student_certificate(
+    env: &Env,
+    course_id: String,
+    student: Address,
+) -> Option<Certificate> {
+    stored_student_certificate(env, course_id.clone(), student.clone()).or_else(|| {
+        primary_identity(env, &student)
+            .and_then(|primary| stored_student_certificate(env, course_id, primary))
+    })
+}
+
+fn require_future(env: &Env, valid_until: u64) {
+    if valid_until <= env.ledger().timestamp() {
+        handle_error(env, Error::InvalidValidity)
+    }
+}
+
+/// Reports an issued certificate to the platform statistics of the course
+/// registry. Best effort: the registry only counts reports from allowlisted
+/// contracts, and a rejected report must not block the issuance.
+fn report_certificate_issued(env: &Env) {
+    let _ = course_registry(env).try_record_certificate_issued(&env.current_contract_address());
+}
+
+/// Issues a completion certificate to a student, valid until `valid_until`
+/// or forever, embedding the course's certificate template. The issuer must
+/// be the course creator or an admin; a student holds at most one
+/// certificate per course, revoked ones included.
+pub fn issue_certificate(
+    env: &Env,
+    issuer: Address,
+    course_id: String,
+    student: Address,
+    valid_until: Option<u64>,
+) -> Certificate {
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    require_issuer(env, &issuer, &course_id);
+    if let Some(valid_until) = valid_until {
+        require_future(env, valid_until);
+    }
+
+    let student_key: DataKey = DataKey::StudentCertificate(course_id.clone(), student.clone());
+    if env.storage().persistent().has(&student_key) {
+        handle_error(env, Error::CertificateAlreadyIssued)
+    }
+    require_completion(env, &course_id, &student);
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::CertificateCount)
+        .unwrap_or(0)
+        + 1;
+    let template: Option<CertificateTemplate> = get_certificate_template(env, course_id.clone());
+    let certificate: Certificate = Certificate {
+        id,
+        course_id: course_id.clone(),
+        student,
+        issuer,
+        issued_at: env.ledger().timestamp(),
+        issued_ledger: env.ledger().sequence(),
+        course_hash: course_hash(env, &course_id),
+        valid_until,
+        revoked: false,
+        template_uri_hash: template
+            .as_ref()
+            .map(|template| template.template_uri_hash.clone()),
+        metadata_fields: template.map_or_else(|| Vec::new(env), |template| template.fields),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::CertificateCount, &id);
+    env.storage().persistent().set(&student_key, &id);
+    store_certificate(env, &certificate);
+    add_to_indexes(env, &certificate);
+
+    events::certificate_issued(env, &certificate);
+    award_badge(env, &certificate);
+    report_certificate_issued(env);
+    certificate
+}
+
+/// Revokes a certificate, dropping it from the student and course listings.
+/// The caller must be the course creator or an admin.
+pub fn revoke_certificate(env: &Env, caller: Address, cert_id: u32) -> Certificate {
+    let mut certificate: Certificate = require_certificate(env, cert_id);
+    require_issuer(env, &caller, &certificate.course_id);
+    if certificate.revoked {
+        handle_error(env, Error::CertificateRevoked)
+    }
+
+    certificate.revoked = true;
+    store_certificate(env, &certificate);
+    remove_from_indexes(env, &certificate);
+
+    events::certificate_revoked(env, &certificate, &caller);
+    certificate
+}
+
+/// Extends an expiring certificate to `new_valid_until`, whether or not it
+/// has expired yet. The caller must be the course creator or an admin.
+pub fn renew_certificate(
+    env: &Env,
+    issuer: Address,
+    cert_id: u32,
+    new_valid_until: u64,
+) -> Certificate {
+    let mut certificate: Certificate = require_certificate(env, cert_id);
+    require_issuer(env, &issuer, &certificate.course_id);
+    if certificate.revoked {
+        handle_error(env, Error::CertificateRevoked)
+    }
+    require_future(env, new_valid_until);
+
+    certificate.valid_until = Some(new_valid_until);
+    store_certificate(env, &certificate);
+
+    events::certificate_renewed(env, &certificate, &issuer);
+    certificate
+}
+
+/// Returns the standing of a certificate at the current ledger time.
+pub fn certificate_status(env: &Env, certificate: &Certificate) -> CertificateStatus {
+    if certificate.revoked {
+        CertificateStatus::Revoked
+    } else if certificate
+        .valid_until
+        .is_some_and(|valid_until| env.ledger().timestamp() >= valid_until)
+    {
+        CertificateStatus::Expired
+    } else {
+        CertificateStatus::Valid
+    }
+}
+
+/// Returns the standing of a certificate, panicking if it doesn't exist.
+pub fn verify_certificate(env: &Env, cert_id: u32) -> CertificateStatus {
+    certificate_status(env, &require_certificate(env, cert_id))
+}
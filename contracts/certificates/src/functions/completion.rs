@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Completion checks before issuance.
+//!
+//! When a progress contract is configured, certificates are only issued to
+//! students it reports as having met the course's completion criteria.
+//! Without one, issuers vouch for completion themselves.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::clients::try_progress;
+use crate::error::{handle_error, Error};
+use crate::functions::config::require_admin;
+use crate::schema::DataKey;
+
+const SET_PROGRESS_CONTRACT_EVENT: Symbol = symbol_short!("setProg");
+
+/// Sets the progress contract completion is checked against (admins only).
+pub fn set_progress_contract(env: &Env, admin: Address, progress_contract: Address) {
+    require_admin(env, &admin);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ProgressContract, &progress_contract);
+
+    env.events()
+        .publish((SET_PROGRESS_CONTRACT_EVENT,), (admin, progress_contract));
+}
+
+/// Returns the progress contract completion is checked against, if configured.
+pub fn get_progress_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ProgressContract)
+}
+
+/// Panics with `CourseNotCompleted` unless the configured progress contract
+/// reports the student as having completed the course.
+pub(crate) fn require_completion(env: &Env, course_id: &String, student: &Address) {
+    let Some(progress) = try_progress(env) else {
+        return;
+    };
+    if !progress.is_course_completed(student, course_id) {
+        handle_error(env, Error::CourseNotCompleted)
+    }
+}
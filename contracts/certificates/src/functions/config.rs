@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::clients::user_management;
+use crate::error::{handle_error, Error};
+use crate::schema::{CertificatesConfig, DataKey};
+
+const INIT_EVENT: Symbol = symbol_short!("init");
+
+/// Stores the owner and the contracts used to check issuers.
+pub fn initialize(env: &Env, config: CertificatesConfig) {
+    config.owner.require_auth();
+
+    if env.storage().instance().has(&DataKey::Config) {
+        handle_error(env, Error::AlreadyInitialized)
+    }
+    env.storage().instance().set(&DataKey::Config, &config);
+
+    env.events().publish((INIT_EVENT,), config);
+}
+
+/// Returns whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Config)
+}
+
+/// Returns the configuration, panicking with `NotInitialized` before `initialize`.
+pub fn get_config(env: &Env) -> CertificatesConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
+
+/// Returns whether `who` is the owner or an admin in user management.
+pub fn is_admin(env: &Env, who: &Address) -> bool {
+    let config: CertificatesConfig = get_config(env);
+    config.owner == *who || user_management(env).is_admin(who)
+}
+
+/// Panics with `Unauthorized` unless `caller` is an admin.
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+    if !is_admin(env, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Soulbound credential export.
+//!
+//! A certificate is exported as a `CredentialClaim` together with its XDR
+//! encoding and SHA-256 digest. The encoding is deterministic, so anyone can
+//! rebuild it from the claim fields and check it against this contract's
+//! ledger state.
+
+use soroban_sdk::{xdr::ToXdr, Bytes, BytesN, Env};
+
+use crate::error::{handle_error, Error};
+use crate::functions::certificates::{certificate_status, get_certificate};
+use crate::schema::{Certificate, CredentialClaim, CredentialProof, CREDENTIAL_VERSION};
+
+/// Returns the claim a certificate attests.
+pub fn credential_claim(env: &Env, certificate: &Certificate) -> CredentialClaim {
+    CredentialClaim {
+        version: CREDENTIAL_VERSION,
+        contract: env.current_contract_address(),
+        cert_id: certificate.id,
+        issuer: certificate.issuer.clone(),
+        subject: certificate.student.clone(),
+        course_id: certificate.course_id.clone(),
+        course_hash: certificate.course_hash.clone(),
+        issued_ledger: certificate.issued_ledger,
+        issued_at: certificate.issued_at,
+    }
+}
+
+/// Returns the credential proof of a certificate.
+pub fn get_credential_proof(env: &Env, cert_id: u32) -> CredentialProof {
+    let certificate: Certificate = get_certificate(env, cert_id)
+        .unwrap_or_else(|| handle_error(env, Error::CertificateNotFound));
+
+    let claim: CredentialClaim = credential_claim(env, &certificate);
+    let serialized: Bytes = claim.clone().to_xdr(env);
+    let digest: BytesN<32> = env.crypto().sha256(&serialized).into();
+    CredentialProof {
+        claim,
+        serialized,
+        digest,
+        valid_until: certificate.valid_until,
+        status: certificate_status(env, &certificate),
+    }
+}
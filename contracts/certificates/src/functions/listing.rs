@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Certificate listings by student and by course.
+//!
+//! Both are backed by ID indexes appended to on issuance and pruned on
+//! revocation, and paged with the ID of the last certificate returned as
+//! cursor.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::certificates::get_certificate;
+use crate::schema::{
+    Certificate, CertificatePagination, DataKey, PaginatedCertificates, MAX_PAGE_SIZE,
+};
+
+fn index(env: &Env, key: &DataKey) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn push_to_index(env: &Env, key: DataKey, cert_id: u32) {
+    let mut ids: Vec<u32> = index(env, &key);
+    ids.push_back(cert_id);
+    env.storage().persistent().set(&key, &ids);
+}
+
+fn remove_from_index(env: &Env, key: DataKey, cert_id: u32) {
+    let mut ids: Vec<u32> = index(env, &key);
+    if let Some(position) = ids.first_index_of(cert_id) {
+        ids.remove(position);
+        if ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &ids);
+        }
+    }
+}
+
+pub(crate) fn add_to_indexes(env: &Env, certificate: &Certificate) {
+    push_to_index(
+        env,
+        DataKey::StudentCertificates(certificate.student.clone()),
+        certificate.id,
+    );
+    push_to_index(
+        env,
+        DataKey::CourseCertificates(certificate.course_id.clone()),
+        certificate.id,
+    );
+}
+
+pub(crate) fn remove_from_indexes(env: &Env, certificate: &Certificate) {
+    remove_from_index(
+        env,
+        DataKey::StudentCertificates(certificate.student.clone()),
+        certificate.id,
+    );
+    remove_from_index(
+        env,
+        DataKey::CourseCertificates(certificate.course_id.clone()),
+        certificate.id,
+    );
+}
+
+fn paginate(env: &Env, ids: Vec<u32>, pagination: &CertificatePagination) -> PaginatedCertificates {
+    if pagination.limit == 0 || pagination.limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidPagination)
+    }
+
+    let mut data: Vec<Certificate> = Vec::new(env);
+    let mut next_cursor: Option<u32> = None;
+    let mut has_more: bool = false;
+    for cert_id in ids.iter() {
+        if pagination.cursor.is_some_and(|cursor| cert_id <= cursor) {
+            continue;
+        }
+        if data.len() == pagination.limit {
+            has_more = true;
+            break;
+        }
+        if let Some(certificate) = get_certificate(env, cert_id) {
+            next_cursor = Some(cert_id);
+            data.push_back(certificate);
+        }
+    }
+
+    PaginatedCertificates {
+        data,
+        next_cursor: if has_more { next_cursor } else { None },
+        total_count: ids.len(),
+        has_more,
+    }
+}
+
+/// Returns a page of the unrevoked certificates of a student.
+pub fn list_certificates_by_student(
+    env: &Env,
+    student: Address,
+    pagination: CertificatePagination,
+) -> PaginatedCertificates {
+    paginate(
+        env,
+        index(env, &DataKey::StudentCertificates(student)),
+        &pagination,
+    )
+}
+
+/// Returns a page of the unrevoked certificates of a course.
+pub fn list_certificates_by_course(
+    env: &Env,
+    course_id: String,
+    pagination: CertificatePagination,
+) -> PaginatedCertificates {
+    paginate(
+        env,
+        index(env, &DataKey::CourseCertificates(course_id)),
+        &pagination,
+    )
+}
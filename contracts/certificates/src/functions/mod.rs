@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+pub mod badges;
+pub mod certificates;
+pub mod completion;
+pub mod config;
+pub mod credentials;
+pub mod listing;
+pub mod templates;
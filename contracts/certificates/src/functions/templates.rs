@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Per-course certificate templates.
+//!
+//! The creator of a course sets the template renderers draw its
+//! certificates with, plus course-specific metadata fields. Both are copied
+//! into each certificate at issuance, so later template changes don't alter
+//! certificates already issued.
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::certificates::is_course_creator;
+use crate::schema::{CertificateTemplate, DataKey, MAX_TEMPLATE_FIELDS, MAX_TEMPLATE_FIELD_LENGTH};
+
+/// Sets the certificate template of a course. Only the course creator can.
+pub fn set_certificate_template(
+    env: &Env,
+    creator: Address,
+    course_id: String,
+    template_uri_hash: BytesN<32>,
+    fields: Vec<String>,
+) -> CertificateTemplate {
+    creator.require_auth();
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if !is_course_creator(env, &course_id, &creator) {
+        handle_error(env, Error::Unauthorized)
+    }
+    if fields.len() > MAX_TEMPLATE_FIELDS
+        || fields
+            .iter()
+            .any(|field| field.is_empty() || field.len() > MAX_TEMPLATE_FIELD_LENGTH)
+    {
+        handle_error(env, Error::InvalidTemplate)
+    }
+
+    let template: CertificateTemplate = CertificateTemplate {
+        template_uri_hash,
+        fields,
+        updated_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Template(course_id.clone()), &template);
+
+    events::template_updated(env, &course_id, &template, &creator);
+    template
+}
+
+/// Returns the certificate template of a course, if any.
+pub fn get_certificate_template(env: &Env, course_id: String) -> Option<CertificateTemplate> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Template(course_id))
+}
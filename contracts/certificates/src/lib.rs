@@ -0,0 +1,321 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+/// Contract version for tracking deployments and upgrades
+pub const VERSION: &str = "1.0.0";
+
+pub mod clients;
+pub mod error;
+pub mod events;
+pub mod functions;
+pub mod schema;
+
+#[cfg(test)]
+mod test;
+
+use crate::schema::{
+    Certificate, CertificatePagination, CertificateStatus, CertificateTemplate, CertificatesConfig,
+    CredentialProof, PaginatedCertificates,
+};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+
+/// Certificates Contract
+///
+/// This contract issues, revokes and verifies course completion certificates
+/// and mints a badge NFT for each one when a badge collection is configured.
+#[contract]
+pub struct CertificatesContract;
+
+#[contractimpl]
+impl CertificatesContract {
+    /// Initialize the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `config` - The owner and the user management and course registry
+    ///   contract addresses
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is already initialized
+    pub fn initialize(env: Env, config: CertificatesConfig) {
+        functions::config::initialize(&env, config)
+    }
+
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::config::is_initialized(&env)
+    }
+
+    /// Get the contract configuration.
+    pub fn get_config(env: Env) -> CertificatesConfig {
+        functions::config::get_config(&env)
+    }
+
+    /// Set the NFT contract completion badges are minted on (admins only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `nft_contract` - Collection exposing `mint(to, token_id)` to this contract
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    pub fn set_badge_collection(env: Env, admin: Address, nft_contract: Address) {
+        functions::badges::set_badge_collection(&env, admin, nft_contract)
+    }
+
+    /// Get the NFT contract badges are minted on, `None` if not configured.
+    pub fn get_badge_collection(env: Env) -> Option<Address> {
+        functions::badges::get_badge_collection(&env)
+    }
+
+    /// Set the progress contract whose `is_course_completed` must hold before
+    /// a certificate is issued (admins only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `progress_contract` - Progress contract applying the course registry's
+    ///   completion criteria
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    pub fn set_progress_contract(env: Env, admin: Address, progress_contract: Address) {
+        functions::completion::set_progress_contract(&env, admin, progress_contract)
+    }
+
+    /// Get the progress contract completion is checked against, `None` if not configured.
+    pub fn get_progress_contract(env: Env) -> Option<Address> {
+        functions::completion::get_progress_contract(&env)
+    }
+
+    /// Set the template the certificates of a course are rendered with.
+    ///
+    /// Certificates issued afterwards embed the template and its
+    /// course-specific metadata fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The course the template applies to
+    /// * `template_uri_hash` - SHA-256 of the template URI
+    /// * `fields` - Up to 20 metadata fields of at most 100 bytes each
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored template.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the course creator
+    /// * If there are too many fields or a field is empty or too long
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already issued certificates**: Keep the template they were issued with
+    pub fn set_certificate_template(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        template_uri_hash: BytesN<32>,
+        fields: Vec<String>,
+    ) -> CertificateTemplate {
+        functions::templates::set_certificate_template(
+            &env,
+            creator,
+            course_id,
+            template_uri_hash,
+            fields,
+        )
+    }
+
+    /// Get the certificate template of a course, `None` if it has none.
+    pub fn get_certificate_template(env: Env, course_id: String) -> Option<CertificateTemplate> {
+        functions::templates::get_certificate_template(&env, course_id)
+    }
+
+    /// Issue a completion certificate to a student.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `issuer` - The course creator or an admin
+    /// * `course_id` - The completed course
+    /// * `student` - The student receiving the certificate
+    /// * `valid_until` - Expiry timestamp for certifications that must be
+    ///   renewed, `None` for a certificate that never expires
+    ///
+    /// # Returns
+    ///
+    /// Returns the new certificate.
+    ///
+    /// # Panics
+    ///
+    /// * If course_id is empty
+    /// * If the caller is neither the course creator nor an admin
+    /// * If the student already has a certificate for the course
+    /// * If `valid_until` has already passed
+    /// * If a progress contract is configured and the student has not met the
+    ///   course's completion criteria
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Templates**: The course's certificate template and metadata fields are embedded
+    /// * **Badges**: A badge NFT with the certificate ID as token ID is minted
+    ///   to the student; without a badge collection only an event is published
+    pub fn issue_certificate(
+        env: Env,
+        issuer: Address,
+        course_id: String,
+        student: Address,
+        valid_until: Option<u64>,
+    ) -> Certificate {
+        functions::certificates::issue_certificate(&env, issuer, course_id, student, valid_until)
+    }
+
+    /// Renew an expiring certificate (course creator or admin).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `issuer` - The course creator or an admin
+    /// * `cert_id` - The certificate to renew, expired or not
+    /// * `new_valid_until` - New expiry timestamp
+    ///
+    /// # Panics
+    ///
+    /// * If the certificate doesn't exist or is revoked
+    /// * If the caller is neither the course creator nor an admin
+    /// * If `new_valid_until` has already passed
+    pub fn renew_certificate(
+        env: Env,
+        issuer: Address,
+        cert_id: u32,
+        new_valid_until: u64,
+    ) -> Certificate {
+        functions::certificates::renew_certificate(&env, issuer, cert_id, new_valid_until)
+    }
+
+    /// Revoke a certificate (course creator or admin).
+    ///
+    /// # Panics
+    ///
+    /// * If the certificate doesn't exist or is already revoked
+    /// * If the caller is neither the course creator nor an admin
+    pub fn revoke_certificate(env: Env, caller: Address, cert_id: u32) -> Certificate {
+        functions::certificates::revoke_certificate(&env, caller, cert_id)
+    }
+
+    /// Get a certificate, `None` if it doesn't exist.
+    pub fn get_certificate(env: Env, cert_id: u32) -> Option<Certificate> {
+        functions::certificates::get_certificate(&env, cert_id)
+    }
+
+    /// Get the certificate of a student for a course, if any. A wallet linked
+    /// to a learner identity in user management finds the identity's certificate.
+    pub fn get_student_certificate(
+        env: Env,
+        course_id: String,
+        student: Address,
+    ) -> Option<Certificate> {
+        functions::certificates::get_student_certificate(&env, course_id, student)
+    }
+
+    /// List the certificates of a student, for dashboards.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student whose certificates to list
+    /// * `pagination` - Cursor from the previous page and page size (1 to 50)
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of certificates by ascending ID, with the cursor of the next page.
+    ///
+    /// # Panics
+    ///
+    /// * If the page size is 0 or above 50
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revoked certificates**: Not listed; use `get_certificate` to read them
+    pub fn list_certificates_by_student(
+        env: Env,
+        student: Address,
+        pagination: CertificatePagination,
+    ) -> PaginatedCertificates {
+        functions::listing::list_certificates_by_student(&env, student, pagination)
+    }
+
+    /// List the certificates of a course, for verification portals.
+    ///
+    /// Pages work as in `list_certificates_by_student`.
+    pub fn list_certificates_by_course(
+        env: Env,
+        course_id: String,
+        pagination: CertificatePagination,
+    ) -> PaginatedCertificates {
+        functions::listing::list_certificates_by_course(&env, course_id, pagination)
+    }
+
+    /// Export a certificate as a verifiable credential.
+    ///
+    /// The proof carries the issuer, subject, course metadata hash and
+    /// issuance ledger, the deterministic XDR serialization of those facts
+    /// and its SHA-256 digest, so employers or other chains can validate the
+    /// credential against Stellar ledger state.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `cert_id` - The certificate to export
+    ///
+    /// # Panics
+    ///
+    /// * If the certificate doesn't exist
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revoked or expired certificates**: Still exported, with their status
+    pub fn get_credential_proof(env: Env, cert_id: u32) -> CredentialProof {
+        functions::credentials::get_credential_proof(&env, cert_id)
+    }
+
+    /// Check the standing of a certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Valid`, `Expired` or `Revoked`.
+    ///
+    /// # Panics
+    ///
+    /// * If the certificate doesn't exist
+    pub fn verify_certificate(env: Env, cert_id: u32) -> CertificateStatus {
+        functions::certificates::verify_certificate(&env, cert_id)
+    }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec};
+
+/// Maximum number of metadata fields of a certificate template.
+pub const MAX_TEMPLATE_FIELDS: u32 = 20;
+
+/// Maximum length of one certificate template field.
+pub const MAX_TEMPLATE_FIELD_LENGTH: u32 = 100;
+
+/// Maximum number of certificates returned by one listing call.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Version of the `CredentialClaim` layout. Bump it when the claim changes shape.
+pub const CREDENTIAL_VERSION: u32 = 1;
+
+/// Storage keys of the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Contracts this contract talks to (instance storage)
+    Config,
+    /// NFT contract badges are minted on (instance storage)
+    BadgeCollection,
+    /// Progress contract checked for course completion before issuance (instance storage)
+    ProgressContract,
+    /// Number of certificates issued so far, used as the last certificate ID
+    CertificateCount,
+    /// Certificate by ID: cert_id -> Certificate
+    Certificate(u32),
+    /// Certificate of a student for a course: (course_id, student) -> cert_id
+    StudentCertificate(String, Address),
+    /// Rendering template of a course's certificates: course_id -> CertificateTemplate
+    Template(String),
+    /// Unrevoked certificates of a student, by ascending ID: student -> Vec<u32>
+    StudentCertificates(Address),
+    /// Unrevoked certificates of a course, by ascending ID: course_id -> Vec<u32>
+    CourseCertificates(String),
+}
+
+/// Cursor-based pagination parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificatePagination {
+    /// ID of the last certificate of the previous page, `None` for the first page
+    pub cursor: Option<u32>,
+    /// Maximum number of certificates to return, at most `MAX_PAGE_SIZE`
+    pub limit: u32,
+}
+
+/// A page of certificates.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginatedCertificates {
+    pub data: Vec<Certificate>,
+    /// Cursor of the next page, `None` on the last page
+    pub next_cursor: Option<u32>,
+    /// Number of certificates in the whole list
+    pub total_count: u32,
+    pub has_more: bool,
+}
+
+/// Owner and addresses of the contracts used to check issuers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificatesConfig {
+    pub owner: Address,
+    pub user_mgmt_addr: Address,
+    pub course_registry_addr: Address,
+}
+
+/// A course completion certificate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Certificate {
+    pub id: u32,
+    pub course_id: String,
+    pub student: Address,
+    /// Course creator or admin who issued it
+    pub issuer: Address,
+    /// Ledger timestamp of the issuance
+    pub issued_at: u64,
+    /// Ledger sequence of the issuance
+    pub issued_ledger: u32,
+    /// SHA-256 of the XDR of the course, as returned by the registry's
+    /// `get_course` when the certificate was issued
+    pub course_hash: BytesN<32>,
+    /// Ledger timestamp the certificate expires at, `None` if it never does
+    pub valid_until: Option<u64>,
+    pub revoked: bool,
+    /// Template of the course at issuance, `None` if the course had none
+    pub template_uri_hash: Option<BytesN<32>>,
+    /// Course-specific metadata fields of the template at issuance
+    pub metadata_fields: Vec<String>,
+}
+
+/// How renderers display the certificates of a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateTemplate {
+    /// SHA-256 of the template URI
+    pub template_uri_hash: BytesN<32>,
+    /// Course-specific metadata embedded in each issued certificate
+    pub fields: Vec<String>,
+    pub updated_at: u64,
+}
+
+/// Standing of a certificate, as returned by `verify_certificate`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CertificateStatus {
+    Valid,
+    /// Past its `valid_until`; the issuer can renew it
+    Expired,
+    Revoked,
+}
+
+/// The facts a credential attests, in the order they are serialized.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialClaim {
+    /// `CREDENTIAL_VERSION` the claim was built with
+    pub version: u32,
+    /// This certificates contract
+    pub contract: Address,
+    pub cert_id: u32,
+    pub issuer: Address,
+    /// The student the credential was issued to
+    pub subject: Address,
+    pub course_id: String,
+    pub course_hash: BytesN<32>,
+    pub issued_ledger: u32,
+    pub issued_at: u64,
+}
+
+/// Portable proof of a certificate for external verifiers.
+///
+/// `serialized` is the XDR encoding of `claim` and `digest` its SHA-256. A
+/// verifier re-encodes the claim, checks the digest, then reads the
+/// certificate from this contract's ledger state to confirm it is still valid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CredentialProof {
+    pub claim: CredentialClaim,
+    pub serialized: Bytes,
+    pub digest: BytesN<32>,
+    /// Current expiry, which renewals move without changing the claim
+    pub valid_until: Option<u64>,
+    pub status: CertificateStatus,
+}
+
+/// Course as returned by the course registry's `get_course`, hashed into
+/// `Certificate::course_hash`.
+pub use skillcert_interfaces::course_registry::Course;
@@ -0,0 +1,391 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, BytesN, Env, String, Vec,
+};
+
+use skillcert_interfaces::course_registry::PlatformStats;
+
+use crate::schema::{CertificatePagination, CertificateStatus, CertificatesConfig, Course};
+use crate::{CertificatesContract, CertificatesContractClient};
+
+#[contract]
+pub struct UserManagement;
+
+#[contractimpl]
+impl UserManagement {
+    pub fn is_admin(_env: Env, _who: Address) -> bool {
+        false
+    }
+}
+
+#[contract]
+pub struct CourseRegistry;
+
+#[contractimpl]
+impl CourseRegistry {
+    pub fn set_creator(env: Env, creator: Address) {
+        env.storage().instance().set(&"creator", &creator);
+    }
+    pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+    }
+    pub fn get_course(env: Env, course_id: String) -> Course {
+        Course {
+            id: course_id,
+            title: String::from_str(&env, "Rust 101"),
+            description: String::from_str(&env, "Intro"),
+            creator: env.current_contract_address(),
+            price: 0,
+            category: None,
+            language: None,
+            thumbnail_url: None,
+            published: true,
+            prerequisites: Vec::new(&env),
+            is_archived: false,
+            level: None,
+            duration_hours: None,
+        }
+    }
+    pub fn record_certificate_issued(env: Env, _reporter: Address) -> PlatformStats {
+        let total_certificates: u32 = Self::total_certificates(env.clone()) + 1;
+        env.storage()
+            .instance()
+            .set(&"certificates", &total_certificates);
+        PlatformStats {
+            total_certificates,
+            ..Default::default()
+        }
+    }
+    pub fn total_certificates(env: Env) -> u32 {
+        env.storage().instance().get(&"certificates").unwrap_or(0)
+    }
+}
+
+#[contract]
+pub struct BadgeCollection;
+
+#[contractimpl]
+impl BadgeCollection {
+    pub fn mint(env: Env, to: Address, token_id: u32) {
+        env.storage().instance().set(&token_id, &to);
+    }
+    pub fn owner_of(env: Env, token_id: u32) -> Option<Address> {
+        env.storage().instance().get(&token_id)
+    }
+}
+
+#[contract]
+pub struct Progress;
+
+#[contractimpl]
+impl Progress {
+    pub fn complete(env: Env, student: Address) {
+        env.storage().instance().set(&student, &true);
+    }
+    pub fn is_course_completed(env: Env, user: Address, _course_id: String) -> bool {
+        env.storage().instance().has(&user)
+    }
+}
+
+struct Setup<'a> {
+    client: CertificatesContractClient<'a>,
+    course_registry: CourseRegistryClient<'a>,
+    owner: Address,
+    creator: Address,
+    student: Address,
+    course_id: String,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let user_mgmt_addr: Address = env.register(UserManagement, ());
+    let course_registry_addr: Address = env.register(CourseRegistry, ());
+    let contract_id: Address = env.register(CertificatesContract, ());
+    let client = CertificatesContractClient::new(env, &contract_id);
+
+    let owner: Address = Address::generate(env);
+    client.initialize(&CertificatesConfig {
+        owner: owner.clone(),
+        user_mgmt_addr,
+        course_registry_addr: course_registry_addr.clone(),
+    });
+    let creator: Address = Address::generate(env);
+    let course_registry = CourseRegistryClient::new(env, &course_registry_addr);
+    course_registry.set_creator(&creator);
+
+    Setup {
+        client,
+        course_registry,
+        owner,
+        creator,
+        student: Address::generate(env),
+        course_id: String::from_str(env, "1"),
+    }
+}
+
+#[test]
+fn test_issue_and_revoke_certificate() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let certificate = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+    assert_eq!(certificate.id, 1);
+    assert_eq!(s.course_registry.total_certificates(), 1);
+    assert_eq!(
+        s.client.verify_certificate(&certificate.id),
+        CertificateStatus::Valid
+    );
+    assert_eq!(
+        s.client.get_student_certificate(&s.course_id, &s.student),
+        Some(certificate.clone())
+    );
+
+    s.client.revoke_certificate(&s.owner, &certificate.id);
+    assert_eq!(
+        s.client.verify_certificate(&certificate.id),
+        CertificateStatus::Revoked
+    );
+}
+
+#[test]
+fn test_certificates_expire_and_are_renewed() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let certificate =
+        s.client
+            .issue_certificate(&s.creator, &s.course_id, &s.student, &Some(1_000));
+    assert_eq!(
+        s.client.verify_certificate(&certificate.id),
+        CertificateStatus::Valid
+    );
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+    assert_eq!(
+        s.client.verify_certificate(&certificate.id),
+        CertificateStatus::Expired
+    );
+    assert_eq!(
+        s.client.get_credential_proof(&certificate.id).status,
+        CertificateStatus::Expired
+    );
+
+    let renewed = s
+        .client
+        .renew_certificate(&s.creator, &certificate.id, &2_000);
+    assert_eq!(renewed.valid_until, Some(2_000));
+    assert_eq!(
+        s.client.verify_certificate(&certificate.id),
+        CertificateStatus::Valid
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #5)")]
+fn test_verify_unknown_certificate_fails() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.verify_certificate(&1);
+}
+
+#[test]
+fn test_issuance_mints_badge_when_collection_is_configured() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    // Without a collection, issuance still succeeds
+    let first = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+
+    let collection: Address = env.register(BadgeCollection, ());
+    s.client.set_badge_collection(&s.owner, &collection);
+    let other: Address = Address::generate(&env);
+    let second = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &other, &None);
+
+    let badges = BadgeCollectionClient::new(&env, &collection);
+    assert_eq!(badges.owner_of(&first.id), None);
+    assert_eq!(badges.owner_of(&second.id), Some(other));
+}
+
+#[test]
+fn test_issuance_requires_completion_when_progress_is_configured() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let progress_addr: Address = env.register(Progress, ());
+    s.client.set_progress_contract(&s.owner, &progress_addr);
+    assert_eq!(
+        s.client.get_progress_contract(),
+        Some(progress_addr.clone())
+    );
+
+    assert!(s
+        .client
+        .try_issue_certificate(&s.creator, &s.course_id, &s.student, &None)
+        .is_err());
+    ProgressClient::new(&env, &progress_addr).complete(&s.student);
+    let certificate = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+    assert_eq!(certificate.student, s.student);
+}
+
+#[test]
+fn test_credential_proof_is_deterministic() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let certificate = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+
+    let proof = s.client.get_credential_proof(&certificate.id);
+    assert_eq!(proof.claim.issuer, s.creator);
+    assert_eq!(proof.claim.subject, s.student);
+    assert_eq!(proof.claim.contract, s.client.address);
+    assert_eq!(proof.claim.course_hash, certificate.course_hash);
+    assert_eq!(proof.claim.issued_ledger, certificate.issued_ledger);
+    assert_eq!(proof.status, CertificateStatus::Valid);
+
+    // A verifier rebuilds the serialization from the claim alone
+    assert_eq!(proof.claim.clone().to_xdr(&env), proof.serialized);
+    let digest: BytesN<32> = env.crypto().sha256(&proof.serialized).into();
+    assert_eq!(digest, proof.digest);
+    assert_eq!(s.client.get_credential_proof(&certificate.id), proof);
+
+    s.client.revoke_certificate(&s.creator, &certificate.id);
+    let revoked = s.client.get_credential_proof(&certificate.id);
+    assert_eq!(revoked.status, CertificateStatus::Revoked);
+    assert_eq!(revoked.digest, proof.digest);
+}
+
+#[test]
+fn test_certificates_embed_the_course_template() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let without_template = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+    assert_eq!(without_template.template_uri_hash, None);
+    assert!(without_template.metadata_fields.is_empty());
+
+    let uri_hash: BytesN<32> = BytesN::from_array(&env, &[3; 32]);
+    let fields = vec![
+        &env,
+        String::from_str(&env, "Duration: 40 hours"),
+        String::from_str(&env, "Level: Advanced"),
+    ];
+    s.client
+        .set_certificate_template(&s.creator, &s.course_id, &uri_hash, &fields);
+    assert_eq!(
+        s.client
+            .get_certificate_template(&s.course_id)
+            .unwrap()
+            .fields,
+        fields
+    );
+
+    let other: Address = Address::generate(&env);
+    let certificate = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &other, &None);
+    assert_eq!(certificate.template_uri_hash, Some(uri_hash));
+    assert_eq!(certificate.metadata_fields, fields);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_template_fields_cannot_be_empty() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_certificate_template(
+        &s.creator,
+        &s.course_id,
+        &BytesN::from_array(&env, &[3; 32]),
+        &vec![&env, String::from_str(&env, "")],
+    );
+}
+
+#[test]
+fn test_list_certificates_by_student_and_course() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let other_course: String = String::from_str(&env, "2");
+    let classmate: Address = Address::generate(&env);
+    let first = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+    let second = s
+        .client
+        .issue_certificate(&s.creator, &other_course, &s.student, &None);
+    let third = s
+        .client
+        .issue_certificate(&s.creator, &s.course_id, &classmate, &None);
+
+    let page = s.client.list_certificates_by_student(
+        &s.student,
+        &CertificatePagination {
+            cursor: None,
+            limit: 1,
+        },
+    );
+    assert_eq!(page.data, vec![&env, first.clone()]);
+    assert_eq!(page.total_count, 2);
+    assert!(page.has_more);
+
+    let rest = s.client.list_certificates_by_student(
+        &s.student,
+        &CertificatePagination {
+            cursor: page.next_cursor,
+            limit: 1,
+        },
+    );
+    assert_eq!(rest.data, vec![&env, second]);
+    assert!(!rest.has_more);
+    assert_eq!(rest.next_cursor, None);
+
+    // Revoked certificates leave the listings
+    s.client.revoke_certificate(&s.creator, &first.id);
+    let course_page = s.client.list_certificates_by_course(
+        &s.course_id,
+        &CertificatePagination {
+            cursor: None,
+            limit: 50,
+        },
+    );
+    assert_eq!(course_page.data, vec![&env, third]);
+    assert_eq!(course_page.total_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_one_certificate_per_course() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+    s.client
+        .issue_certificate(&s.creator, &s.course_id, &s.student, &None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_students_cannot_issue_certificates() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .issue_certificate(&s.student, &s.course_id, &s.student, &None);
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Typed clients for the contracts course access calls.
+//!
+//! The course registry, user management and progress clients come from
+//! `skillcert_interfaces`, so a signature change shows up as a compile error
+//! instead of a failed call at runtime. The helpers below build a client for
+//! the address set with `initialize` or `set_config`.
+//!
+//! Soroban rejects any call back into a contract that is already on the
+//! call stack, so a callee can't re-enter this contract halfway through an
+//! update. Grants still finish their own writes before reporting to the
+//! course registry, and best-effort calls go through the `try_` variants so
+//! a failing callee can't block them.
+
+use soroban_sdk::{Address, Env};
+
+pub use skillcert_interfaces::course_registry::{CourseRegistryClient, CourseRegistryInterface};
+pub use skillcert_interfaces::progress::{ProgressClient, ProgressInterface};
+pub use skillcert_interfaces::user_management::{UserManagementClient, UserManagementInterface};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{KEY_COURSE_REG_ADDR, KEY_PROGRESS_ADDR, KEY_USER_MGMT_ADDR};
+
+/// Returns a client for the configured course registry, or `None` before `initialize`.
+pub fn try_course_registry(env: &Env) -> Option<CourseRegistryClient<'_>> {
+    let address: Address = env.storage().instance().get(&(KEY_COURSE_REG_ADDR,))?;
+    Some(CourseRegistryClient::new(env, &address))
+}
+
+/// Returns a client for the configured course registry.
+pub fn course_registry(env: &Env) -> CourseRegistryClient<'_> {
+    try_course_registry(env)
+        .expect("course_registry_addr not configured; call initialize/set_config")
+}
+
+/// Returns a client for the configured user management contract, or `None` before `initialize`.
+pub fn try_user_management(env: &Env) -> Option<UserManagementClient<'_>> {
+    let address: Address = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,))?;
+    Some(UserManagementClient::new(env, &address))
+}
+
+/// Returns a client for the configured user management contract.
+pub fn user_management(env: &Env) -> UserManagementClient<'_> {
+    try_user_management(env).expect("user_mgmt_addr not configured; call initialize/set_config")
+}
+
+/// Returns a client for the progress contract, panicking with
+/// `ProgressContractNotConfigured` if none was set.
+pub fn progress(env: &Env) -> ProgressClient<'_> {
+    let address: Address = env
+        .storage()
+        .instance()
+        .get(&(KEY_PROGRESS_ADDR,))
+        .unwrap_or_else(|| handle_error(env, Error::ProgressContractNotConfigured));
+    ProgressClient::new(env, &address)
+}
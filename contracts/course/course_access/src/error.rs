@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, panic_with_error, Env};
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -18,9 +18,92 @@ pub enum Error {
     EmptyCourseId = 9,
     InvalidTransferData = 10,
     SameUserTransfer = 11,
-    Initialized = 12
+    Initialized = 12,
+    InvalidEmailFormat = 13,
+    InvalidCountryCode = 14,
+    PrerequisitesNotMet = 15,
+    ProgressContractNotConfigured = 16,
+    InvalidTtlConfig = 17,
+    CourseNotOpenForEnrollment = 18,
+    CourseFull = 19,
+    NotOrgMember = 20,
+    NoSeatsAvailable = 21,
+    InvalidAnnouncementTitle = 22,
+    InvalidTokenGate = 23,
+    TokenGateNotMet = 24,
+    VoucherNotFound = 25,
+    VoucherExpired = 26,
+    VoucherAlreadyRedeemed = 27,
+    InvalidVoucher = 28,
+    InvalidBackupPage = 29,
+    BackupChecksumMismatch = 30,
+    InvalidPageLimit = 31,
+    ContractPaused = 32,
+    NotInitialized = 33,
+    NoConfigProposal = 34,
+    ConfigTimelockActive = 35,
+    ConfigTimelockRequired = 36,
+    InvalidConfigDelay = 37,
+    NoPendingOwner = 38,
+    InvalidSlot = 39,
+    SlotNotFound = 40,
+    SlotUnavailable = 41,
+    SlotNotBooked = 42,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
     panic_with_error!(env, error);
 }
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "UserAlreadyHasAccess: The user already has access to the course",
+        2 => "UserNoAccessCourse: The user has no access to the course",
+        3 => "Unauthorized: The caller is not allowed to do this",
+        4 => "NameRequired: A name is required",
+        5 => "EmailRequired: An email address is required",
+        6 => "CountryRequired: A country is required",
+        7 => "InvalidCourseId: The course ID is invalid",
+        8 => "InvalidUser: The user is not valid",
+        9 => "EmptyCourseId: The course ID is empty",
+        10 => "InvalidTransferData: The transfer is not valid",
+        11 => "SameUserTransfer: Access cannot be transferred to the same user",
+        12 => "Initialized: The contract is already initialized",
+        13 => "InvalidEmailFormat: The email address is not valid",
+        14 => "InvalidCountryCode: The country code is not valid",
+        15 => "PrerequisitesNotMet: The user has not completed the prerequisites",
+        16 => "ProgressContractNotConfigured: The progress contract is not configured",
+        17 => "InvalidTtlConfig: The TTL threshold must be positive and below the extension",
+        18 => "CourseNotOpenForEnrollment: The course is not open for enrollment",
+        19 => "CourseFull: The course is full",
+        20 => "NotOrgMember: The user is not a member of the organization",
+        21 => "NoSeatsAvailable: The organization has no seats left for the course",
+        22 => "InvalidAnnouncementTitle: The announcement title is not valid",
+        23 => "InvalidTokenGate: The token gate is not valid",
+        24 => "TokenGateNotMet: The user does not hold enough of the gate token",
+        25 => "VoucherNotFound: The voucher does not exist",
+        26 => "VoucherExpired: The voucher has expired",
+        27 => "VoucherAlreadyRedeemed: The voucher is already redeemed",
+        28 => "InvalidVoucher: The voucher is not valid",
+        29 => "InvalidBackupPage: The backup page is malformed or out of order",
+        30 => "BackupChecksumMismatch: The backup checksum does not match its data",
+        31 => "InvalidPageLimit: The page limit is out of range",
+        32 => "ContractPaused: The contract is paused",
+        33 => "NotInitialized: The contract is not initialized",
+        34 => "NoConfigProposal: No configuration change is proposed",
+        35 => "ConfigTimelockActive: The proposed configuration cannot be applied yet",
+        36 => "ConfigTimelockRequired: Configuration changes must go through propose_config",
+        37 => "InvalidConfigDelay: The configuration delay is too long",
+        38 => "NoPendingOwner: No ownership transfer to this account is pending",
+        39 => "InvalidSlot: The slots are missing, too many, in the past or end before they start",
+        40 => "SlotNotFound: The office-hour slot does not exist",
+        41 => "SlotUnavailable: The office-hour slot is already booked or has started",
+        42 => "SlotNotBooked: The office-hour slot is not booked",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
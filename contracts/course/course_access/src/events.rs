@@ -0,0 +1,385 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! Access events are published with the topics `("access", action, course_id)`,
+//! course manager changes with `("manager", action, course_id)`,
+//! announcements with `("announce", "posted", course_id)`, office hours
+//! with `("slot", "published" | "booked" | "cancelled", course_id)`,
+//! vouchers with `("voucher", "issued" | "redeemed", course_id)`,
+//! upgrades with `("contract", "upgraded", to_version)`, pauses with
+//! `("contract", "paused" | "unpaused", admin)`, allowlist changes with
+//! `("contract", "trusted", contract)`, configuration proposals with
+//! `("config", "proposed" | "applied" | "cancelled", owner)`, ownership
+//! transfers with `("owner", "proposed" | "accepted", new_owner)`, each with a typed
+//! payload whose first field is `version`, following the same convention as
+//! the course registry.
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol};
+
+use crate::schema::{Announcement, ConfigProposal, OfficeHourSlot, Voucher};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const ACCESS: Symbol = symbol_short!("access");
+const CONTRACT: Symbol = symbol_short!("contract");
+const MANAGER: Symbol = symbol_short!("manager");
+const ANNOUNCEMENT: Symbol = symbol_short!("announce");
+const VOUCHER: Symbol = symbol_short!("voucher");
+const CONFIG: Symbol = symbol_short!("config");
+const OWNER: Symbol = symbol_short!("owner");
+const SLOT: Symbol = symbol_short!("slot");
+
+const GRANTED: Symbol = symbol_short!("granted");
+const REVOKED: Symbol = symbol_short!("revoked");
+const CLEARED: Symbol = symbol_short!("cleared");
+const TRANSFERRED: Symbol = symbol_short!("transfer");
+const UPGRADED: Symbol = symbol_short!("upgraded");
+const ADDED: Symbol = symbol_short!("added");
+const REMOVED: Symbol = symbol_short!("removed");
+const POSTED: Symbol = symbol_short!("posted");
+const ISSUED: Symbol = symbol_short!("issued");
+const REDEEMED: Symbol = symbol_short!("redeemed");
+const PAUSED: Symbol = symbol_short!("paused");
+const UNPAUSED: Symbol = symbol_short!("unpaused");
+const TRUSTED: Symbol = symbol_short!("trusted");
+const PROPOSED: Symbol = symbol_short!("proposed");
+const APPLIED: Symbol = symbol_short!("applied");
+const CANCELLED: Symbol = symbol_short!("cancelled");
+const ACCEPTED: Symbol = symbol_short!("accepted");
+const PUBLISHED: Symbol = symbol_short!("published");
+const BOOKED: Symbol = symbol_short!("booked");
+
+/// Payload of `("access", "granted" | "revoked", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub user: Address,
+}
+
+/// Payload of `("access", "cleared", course_id)`, published when every user's access is revoked.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessClearedEvent {
+    pub version: u32,
+    pub course_id: String,
+    /// Number of users whose access was revoked
+    pub count: u32,
+}
+
+/// Payload of `("access", "transfer", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessTransferredEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub from: Address,
+    pub to: Address,
+}
+
+/// Payload of `("manager", "added" | "removed", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseManagerEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub manager: Address,
+    /// Course creator that made the change
+    pub by: Address,
+}
+
+/// Payload of `("announce", "posted", course_id)`, used by off-chain
+/// pipelines to notify the students of the course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnouncementEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub announcement_id: u32,
+    pub author: Address,
+    pub title: String,
+    pub body_hash: BytesN<32>,
+}
+
+/// Payload of `("slot", "published" | "booked" | "cancelled", course_id)`,
+/// used to sync calendars off-chain. `student` is the student who booked
+/// or whose booking was cancelled, `None` for published slots.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlotEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub slot_id: u32,
+    pub instructor: Address,
+    pub start: u64,
+    pub end: u64,
+    pub student: Option<Address>,
+}
+
+/// Payload of `("voucher", "issued" | "redeemed", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoucherEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub voucher_id: u32,
+    /// Issuer, or student redeeming the voucher
+    pub actor: Address,
+}
+
+/// Payload of `("contract", "upgraded", to_version)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgradedEvent {
+    pub version: u32,
+    pub from_version: String,
+    pub to_version: String,
+    pub wasm_hash: BytesN<32>,
+    pub by: Address,
+}
+
+/// Payload of `("contract", "paused" | "unpaused", admin)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseEvent {
+    pub version: u32,
+    pub admin: Address,
+}
+
+/// Payload of `("contract", "trusted", contract)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrustedContractEvent {
+    pub version: u32,
+    pub contract: Address,
+    /// Whether the contract was added to or removed from the allowlist
+    pub trusted: bool,
+    pub by: Address,
+}
+
+/// Payload of `("config", "proposed" | "applied" | "cancelled", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigProposalEvent {
+    pub version: u32,
+    pub proposal: ConfigProposal,
+}
+
+/// Payload of `("owner", "proposed" | "accepted", new_owner)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnershipEvent {
+    pub version: u32,
+    pub previous_owner: Address,
+    pub new_owner: Address,
+}
+
+pub fn access_granted(env: &Env, course_id: &String, user: &Address) {
+    publish_access_event(env, GRANTED, course_id, user);
+}
+
+pub fn access_revoked(env: &Env, course_id: &String, user: &Address) {
+    publish_access_event(env, REVOKED, course_id, user);
+}
+
+pub fn access_cleared(env: &Env, course_id: &String, count: u32) {
+    env.events().publish(
+        (ACCESS, CLEARED, course_id.clone()),
+        AccessClearedEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            count,
+        },
+    );
+}
+
+pub fn access_transferred(env: &Env, course_id: &String, from: &Address, to: &Address) {
+    env.events().publish(
+        (ACCESS, TRANSFERRED, course_id.clone()),
+        AccessTransferredEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            from: from.clone(),
+            to: to.clone(),
+        },
+    );
+}
+
+pub fn manager_added(env: &Env, course_id: &String, manager: &Address, by: &Address) {
+    publish_manager_event(env, ADDED, course_id, manager, by);
+}
+
+pub fn manager_removed(env: &Env, course_id: &String, manager: &Address, by: &Address) {
+    publish_manager_event(env, REMOVED, course_id, manager, by);
+}
+
+pub fn announcement_posted(env: &Env, announcement: &Announcement) {
+    env.events().publish(
+        (ANNOUNCEMENT, POSTED, announcement.course_id.clone()),
+        AnnouncementEvent {
+            version: EVENT_VERSION,
+            course_id: announcement.course_id.clone(),
+            announcement_id: announcement.id,
+            author: announcement.author.clone(),
+            title: announcement.title.clone(),
+            body_hash: announcement.body_hash.clone(),
+        },
+    );
+}
+
+pub fn slot_published(env: &Env, slot: &OfficeHourSlot) {
+    publish_slot_event(env, PUBLISHED, slot, None);
+}
+
+pub fn slot_booked(env: &Env, slot: &OfficeHourSlot, student: &Address) {
+    publish_slot_event(env, BOOKED, slot, Some(student.clone()));
+}
+
+pub fn slot_booking_cancelled(env: &Env, slot: &OfficeHourSlot, student: &Address) {
+    publish_slot_event(env, CANCELLED, slot, Some(student.clone()));
+}
+
+fn publish_slot_event(env: &Env, action: Symbol, slot: &OfficeHourSlot, student: Option<Address>) {
+    env.events().publish(
+        (SLOT, action, slot.course_id.clone()),
+        SlotEvent {
+            version: EVENT_VERSION,
+            course_id: slot.course_id.clone(),
+            slot_id: slot.id,
+            instructor: slot.instructor.clone(),
+            start: slot.start,
+            end: slot.end,
+            student,
+        },
+    );
+}
+
+pub fn voucher_issued(env: &Env, voucher: &Voucher) {
+    publish_voucher_event(env, ISSUED, voucher, &voucher.issuer);
+}
+
+pub fn voucher_redeemed(env: &Env, voucher: &Voucher, student: &Address) {
+    publish_voucher_event(env, REDEEMED, voucher, student);
+}
+
+pub fn contract_upgraded(
+    env: &Env,
+    from_version: &String,
+    to_version: &String,
+    wasm_hash: &BytesN<32>,
+    by: &Address,
+) {
+    env.events().publish(
+        (CONTRACT, UPGRADED, to_version.clone()),
+        ContractUpgradedEvent {
+            version: EVENT_VERSION,
+            from_version: from_version.clone(),
+            to_version: to_version.clone(),
+            wasm_hash: wasm_hash.clone(),
+            by: by.clone(),
+        },
+    );
+}
+
+pub fn contract_paused(env: &Env, admin: &Address, paused: bool) {
+    let action: Symbol = if paused { PAUSED } else { UNPAUSED };
+    env.events().publish(
+        (CONTRACT, action, admin.clone()),
+        PauseEvent {
+            version: EVENT_VERSION,
+            admin: admin.clone(),
+        },
+    );
+}
+
+pub fn trusted_contract_set(env: &Env, contract: &Address, trusted: bool, by: &Address) {
+    env.events().publish(
+        (CONTRACT, TRUSTED, contract.clone()),
+        TrustedContractEvent {
+            version: EVENT_VERSION,
+            contract: contract.clone(),
+            trusted,
+            by: by.clone(),
+        },
+    );
+}
+
+pub fn config_proposed(env: &Env, owner: &Address, proposal: &ConfigProposal) {
+    publish_config_event(env, PROPOSED, owner, proposal);
+}
+
+pub fn config_applied(env: &Env, owner: &Address, proposal: &ConfigProposal) {
+    publish_config_event(env, APPLIED, owner, proposal);
+}
+
+pub fn config_proposal_cancelled(env: &Env, owner: &Address, proposal: &ConfigProposal) {
+    publish_config_event(env, CANCELLED, owner, proposal);
+}
+
+pub fn ownership_proposed(env: &Env, owner: &Address, new_owner: &Address) {
+    publish_ownership_event(env, PROPOSED, owner, new_owner);
+}
+
+pub fn ownership_accepted(env: &Env, previous_owner: &Address, new_owner: &Address) {
+    publish_ownership_event(env, ACCEPTED, previous_owner, new_owner);
+}
+
+fn publish_ownership_event(env: &Env, action: Symbol, previous_owner: &Address, new_owner: &Address) {
+    env.events().publish(
+        (OWNER, action, new_owner.clone()),
+        OwnershipEvent {
+            version: EVENT_VERSION,
+            previous_owner: previous_owner.clone(),
+            new_owner: new_owner.clone(),
+        },
+    );
+}
+
+fn publish_config_event(env: &Env, action: Symbol, owner: &Address, proposal: &ConfigProposal) {
+    env.events().publish(
+        (CONFIG, action, owner.clone()),
+        ConfigProposalEvent {
+            version: EVENT_VERSION,
+            proposal: proposal.clone(),
+        },
+    );
+}
+
+fn publish_access_event(env: &Env, action: Symbol, course_id: &String, user: &Address) {
+    env.events().publish(
+        (ACCESS, action, course_id.clone()),
+        AccessEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            user: user.clone(),
+        },
+    );
+}
+
+fn publish_manager_event(env: &Env, action: Symbol, course_id: &String, manager: &Address, by: &Address) {
+    env.events().publish(
+        (MANAGER, action, course_id.clone()),
+        CourseManagerEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            manager: manager.clone(),
+            by: by.clone(),
+        },
+    );
+}
+
+fn publish_voucher_event(env: &Env, action: Symbol, voucher: &Voucher, actor: &Address) {
+    env.events().publish(
+        (VOUCHER, action, voucher.course_id.clone()),
+        VoucherEvent {
+            version: EVENT_VERSION,
+            course_id: voucher.course_id.clone(),
+            voucher_id: voucher.id,
+            actor: actor.clone(),
+        },
+    );
+}
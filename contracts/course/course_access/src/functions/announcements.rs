@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Per-course announcements.
+//!
+//! The creator, managers and admins of a course post short announcements;
+//! students with access read them page by page, newest first. Each post
+//! emits an event so off-chain pipelines can notify the students.
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::course_managers::require_enrollment_authority;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{Announcement, DataKey};
+
+/// Maximum length of an announcement title.
+const MAX_ANNOUNCEMENT_TITLE_LENGTH: u32 = 200;
+
+/// Maximum number of announcements returned by one `list_announcements` call.
+pub const MAX_ANNOUNCEMENTS_PAGE: u32 = 50;
+
+fn announcement_count(env: &Env, course_id: &String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AnnouncementCount(course_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Posts an announcement to the students of a course.
+///
+/// The author must be the course creator, one of its managers or an admin.
+pub fn post_announcement(
+    env: Env,
+    author: Address,
+    course_id: String,
+    title: String,
+    body_hash: BytesN<32>,
+) -> Announcement {
+    author.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId);
+    }
+    if title.is_empty() || title.len() > MAX_ANNOUNCEMENT_TITLE_LENGTH {
+        handle_error(&env, Error::InvalidAnnouncementTitle);
+    }
+    require_enrollment_authority(&env, &author, &course_id);
+
+    let id: u32 = announcement_count(&env, &course_id) + 1;
+    let announcement: Announcement = Announcement {
+        id,
+        course_id: course_id.clone(),
+        author,
+        title,
+        body_hash,
+        posted_at: env.ledger().timestamp(),
+    };
+
+    let key: DataKey = DataKey::Announcement(course_id.clone(), id);
+    env.storage().persistent().set(&key, &announcement);
+    bump_persistent(&env, &key);
+    let count_key: DataKey = DataKey::AnnouncementCount(course_id);
+    env.storage().persistent().set(&count_key, &id);
+    bump_persistent(&env, &count_key);
+
+    events::announcement_posted(&env, &announcement);
+
+    announcement
+}
+
+/// Returns up to `limit` announcements of a course, newest first, skipping
+/// the `offset` most recent ones.
+///
+/// The reader must have access to the course, or be allowed to post to it.
+pub fn list_announcements(
+    env: Env,
+    reader: Address,
+    course_id: String,
+    offset: u32,
+    limit: u32,
+) -> Vec<Announcement> {
+    reader.require_auth();
+
+    let has_access: bool = env
+        .storage()
+        .persistent()
+        .has(&DataKey::CourseAccess(course_id.clone(), reader.clone()));
+    if !has_access {
+        require_enrollment_authority(&env, &reader, &course_id);
+    }
+
+    let mut announcements: Vec<Announcement> = Vec::new(&env);
+    let count: u32 = announcement_count(&env, &course_id);
+    let mut id: u32 = count.saturating_sub(offset);
+    let limit: u32 = limit.min(MAX_ANNOUNCEMENTS_PAGE);
+    while id > 0 && announcements.len() < limit {
+        if let Some(announcement) = env
+            .storage()
+            .persistent()
+            .get::<_, Announcement>(&DataKey::Announcement(course_id.clone(), id))
+        {
+            announcements.push_back(announcement);
+        }
+        id -= 1;
+    }
+    announcements
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Announcement;
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, Address, BytesN, Env, String, Vec,
+    };
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+    }
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn set_creator(env: Env, creator: Address) {
+            env.storage().instance().set(&"creator", &creator);
+        }
+        pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+            env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+        }
+        pub fn record_enrollment(_env: Env, _reporter: Address, _course_id: String) -> u32 {
+            0
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseAccessContractClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        let course_registry_id: Address = env.register(CourseRegistry, ());
+        let contract_id: Address = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &user_mgmt_id, &course_registry_id);
+
+        let creator: Address = Address::generate(env);
+        CourseRegistryClient::new(env, &course_registry_id).set_creator(&creator);
+        (client, creator, String::from_str(env, "course_1"))
+    }
+
+    #[test]
+    fn test_post_and_list_announcements() {
+        let env: Env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let manager: Address = Address::generate(&env);
+        let student: Address = Address::generate(&env);
+        client.add_course_manager(&creator, &course_id, &manager);
+        client.grant_access(&creator, &course_id, &student);
+
+        let hash: BytesN<32> = BytesN::from_array(&env, &[7; 32]);
+        for (author, title) in [(&creator, "Welcome"), (&manager, "Week 1"), (&creator, "Week 2")] {
+            client.post_announcement(author, &course_id, &String::from_str(&env, title), &hash);
+        }
+
+        let page: Vec<Announcement> = client.list_announcements(&student, &course_id, &0, &2);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap().title, String::from_str(&env, "Week 2"));
+        assert_eq!(page.get(1).unwrap().author, manager);
+
+        let rest: Vec<Announcement> = client.list_announcements(&creator, &course_id, &2, &2);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest.get(0).unwrap().id, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #3)")]
+    fn test_students_cannot_post_announcements() {
+        let env: Env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let student: Address = Address::generate(&env);
+        client.grant_access(&creator, &course_id, &student);
+
+        client.post_announcement(
+            &student,
+            &course_id,
+            &String::from_str(&env, "Hi"),
+            &BytesN::from_array(&env, &[0; 32]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #3)")]
+    fn test_announcements_require_access() {
+        let env: Env = Env::default();
+        let (client, _creator, course_id) = setup(&env);
+
+        client.list_announcements(&Address::generate(&env), &course_id, &0, &10);
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Paged export and import of access grants.
+//!
+//! Together with the user management and course registry backups, this lets
+//! the three contracts be backed up and restored consistently. Courses are
+//! listed in an index kept by `grant_with_record`; courses only granted
+//! before the index existed are not exported.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::course_managers::is_admin;
+use crate::functions::course_users::{add_course_user, all_course_users};
+use crate::functions::grant_access::get_enrollment;
+use crate::schema::{
+    AccessBackupPage, BackupCursor, CourseAccess, DataKey, EnrollmentRecord,
+    EnrollmentSource, UserAccessBackup, UserCourses, MAX_BACKUP_PAGE_SIZE,
+};
+
+fn access_courses(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AccessCourses)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a course to the index of courses with access grants.
+pub(crate) fn index_access_course(env: &Env, course_id: &String) {
+    let mut courses: Vec<String> = access_courses(env);
+    if !courses.contains(course_id) {
+        courses.push_back(course_id.clone());
+        env.storage().persistent().set(&DataKey::AccessCourses, &courses);
+    }
+}
+
+fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+    if !is_admin(env, caller) {
+        handle_error(env, Error::Unauthorized);
+    }
+}
+
+/// Chains the checksum of the pages before a page with the page's contents.
+fn chain_checksum(
+    env: &Env,
+    previous: &BytesN<32>,
+    courses: &Vec<String>,
+    users: &Vec<Vec<UserAccessBackup>>,
+) -> BytesN<32> {
+    let mut data: Bytes = Bytes::from_array(env, &previous.to_array());
+    data.append(&(courses.clone(), users.clone()).to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+/// Exports the users of up to `limit` courses, starting at `cursor`.
+pub fn export_access_data(env: Env, caller: Address, cursor: BackupCursor, limit: u32) -> AccessBackupPage {
+    require_admin(&env, &caller);
+
+    let all_courses: Vec<String> = access_courses(&env);
+    let total_courses: u32 = all_courses.len();
+    if limit == 0 || limit > MAX_BACKUP_PAGE_SIZE || cursor.offset > total_courses {
+        handle_error(&env, Error::InvalidBackupPage);
+    }
+
+    let end: u32 = (cursor.offset + limit).min(total_courses);
+    let courses: Vec<String> = all_courses.slice(cursor.offset..end);
+    let mut users: Vec<Vec<UserAccessBackup>> = Vec::new(&env);
+    for course_id in courses.iter() {
+        let course_users: Vec<Address> = all_course_users(&env, &course_id);
+        let mut backups: Vec<UserAccessBackup> = Vec::new(&env);
+        for user in course_users.iter() {
+            // Grants older than enrollment records are restored as manual grants
+            let enrollment: EnrollmentRecord = get_enrollment(&env, course_id.clone(), user.clone())
+                .unwrap_or(EnrollmentRecord {
+                    granted_at: 0,
+                    granted_by: None,
+                    source: EnrollmentSource::Manual,
+                });
+            backups.push_back(UserAccessBackup { user, enrollment });
+        }
+        users.push_back(backups);
+    }
+
+    let next_cursor: BackupCursor = BackupCursor {
+        offset: end,
+        checksum: chain_checksum(&env, &cursor.checksum, &courses, &users),
+    };
+    AccessBackupPage {
+        cursor,
+        courses,
+        users,
+        next_cursor,
+        has_more: end < total_courses,
+        total_courses,
+    }
+}
+
+fn restore_grant(env: &Env, course_id: &String, backup: &UserAccessBackup) {
+    let user: &Address = &backup.user;
+    env.storage().persistent().set(
+        &DataKey::CourseAccess(course_id.clone(), user.clone()),
+        &CourseAccess {
+            course_id: course_id.clone(),
+            user: user.clone(),
+        },
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::Enrollment(course_id.clone(), user.clone()), &backup.enrollment);
+
+    let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
+    let mut user_courses: UserCourses = env
+        .storage()
+        .persistent()
+        .get(&user_courses_key)
+        .unwrap_or(UserCourses {
+            user: user.clone(),
+            courses: Vec::new(env),
+        });
+    if !user_courses.courses.contains(course_id) {
+        user_courses.courses.push_back(course_id.clone());
+        env.storage().persistent().set(&user_courses_key, &user_courses);
+    }
+}
+
+/// Imports a page returned by `export_access_data`, returning the number of
+/// grants restored. Pages must be imported in export order.
+pub fn import_access_data(env: Env, caller: Address, page: AccessBackupPage) -> u32 {
+    require_admin(&env, &caller);
+
+    let expected: BackupCursor = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BackupImportCursor)
+        .unwrap_or(BackupCursor {
+            offset: 0,
+            checksum: BytesN::from_array(&env, &[0u8; 32]),
+        });
+    if page.cursor != expected || page.courses.len() != page.users.len() {
+        handle_error(&env, Error::InvalidBackupPage);
+    }
+    if chain_checksum(&env, &page.cursor.checksum, &page.courses, &page.users) != page.next_cursor.checksum {
+        handle_error(&env, Error::BackupChecksumMismatch);
+    }
+
+    let mut restored: u32 = 0;
+    for (course_id, backups) in page.courses.iter().zip(page.users.iter()) {
+        for backup in backups.iter() {
+            restore_grant(&env, &course_id, &backup);
+            add_course_user(&env, &course_id, &backup.user);
+            restored += 1;
+        }
+        index_access_course(&env, &course_id);
+    }
+
+    // The last page completes the import, so a new one can start
+    if page.has_more {
+        env.storage()
+            .persistent()
+            .set(&DataKey::BackupImportCursor, &page.next_cursor);
+    } else {
+        env.storage().persistent().remove(&DataKey::BackupImportCursor);
+    }
+
+    restored
+}
@@ -1,14 +1,18 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, String, Symbol, symbol_short};
 use soroban_sdk::storage::Instance;
 
 use crate::error::{Error, handle_error};
-use crate::schema::{KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
+use crate::events;
+use crate::functions::config_timelock::get_config_delay;
+use crate::functions::contract_versioning::record_version;
+use crate::schema::{CourseAccessConfig, DataKey, KEY_COURSE_REG_ADDR, KEY_PROGRESS_ADDR, KEY_USER_MGMT_ADDR};
 
 const INIT_EVENT: Symbol = symbol_short!("initialz");
 const UPDATE_ADDRESS_EVENT: Symbol = symbol_short!("updAddr");
+const UPDATE_PROGRESS_EVENT: Symbol = symbol_short!("updPrgAdr");
 
 
 const KEY_INIT: &str = "init";
@@ -37,12 +41,7 @@ pub fn initialize(
     caller.require_auth();
 
     // Prevent re-initialization
-    if env
-        .storage()
-        .instance()
-        .get::<_, bool>(&((KEY_INIT,),))
-        .unwrap_or(false)
-    {
+    if is_initialized(&env) {
         handle_error(&env, Error::Initialized);
     }
 
@@ -52,6 +51,8 @@ pub fn initialize(
     inst.set(&(KEY_COURSE_REG_ADDR,), &course_registry_addr);
     inst.set(&(KEY_INIT,), &true);
 
+    record_version(&env, &caller, String::from_str(&env, crate::VERSION));
+
     env.events()
         .publish((INIT_EVENT,), (caller, user_mgmt_addr, course_registry_addr));
 }
@@ -68,8 +69,10 @@ pub fn initialize(
 ///
 /// # Panics
 ///
-/// * This function will panic with the message "not initialized" if the contract has not been initialized.
-/// * This function will panic with the message "only owner" if the caller is not the owner.
+/// * This function will call `handle_error` with `Error::NotInitialized` if the contract has not been initialized.
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+/// * This function will call `handle_error` with `Error::ConfigTimelockRequired` unless the
+///   configuration delay is 0; changes otherwise go through `propose_config`.
 pub fn set_contract_addrs(
     env: Env,
     caller: Address,
@@ -78,31 +81,130 @@ pub fn set_contract_addrs(
 ) {
     caller.require_auth();
 
-    // Require initialized
-    if !env
-        .storage()
+    if get_config(&env).owner != caller {
+        handle_error(&env, Error::Unauthorized);
+    }
+    if get_config_delay(&env) > 0 {
+        handle_error(&env, Error::ConfigTimelockRequired);
+    }
+
+    let inst: Instance = env.storage().instance();
+    inst.set(&(KEY_USER_MGMT_ADDR,), &user_mgmt_addr);
+    inst.set(&(KEY_COURSE_REG_ADDR,), &course_registry_addr);
+    env.events()
+        .publish((UPDATE_ADDRESS_EVENT,), (caller, user_mgmt_addr, course_registry_addr));
+}
+
+/// Returns whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage()
         .instance()
-        .get::<_, bool>(&((KEY_INIT,),))
+        .get::<_, bool>(&(KEY_INIT,))
         .unwrap_or(false)
-    {
-        panic!("not initialized");
+}
+
+/// Returns the owner and the addresses of the contracts this contract calls.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::NotInitialized` before `initialize`.
+pub fn get_config(env: &Env) -> CourseAccessConfig {
+    let inst: Instance = env.storage().instance();
+    let owner: Address = inst
+        .get(&(KEY_OWNER,))
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    CourseAccessConfig {
+        owner,
+        user_mgmt_addr: inst.get(&(KEY_USER_MGMT_ADDR,)).unwrap(),
+        course_registry_addr: inst.get(&(KEY_COURSE_REG_ADDR,)).unwrap(),
+        progress_addr: inst.get(&(KEY_PROGRESS_ADDR,)),
     }
+}
 
+/// Returns whether `who` is the contract owner set at initialization.
+pub fn is_owner(env: &Env, who: &Address) -> bool {
+    let owner: Option<Address> = env.storage().instance().get(&(KEY_OWNER,));
+    owner.as_ref() == Some(who)
+}
 
-    let owner: Address = env
-        .storage()
+/// Returns the contract owner, panicking with `NotInitialized` before `initialize`.
+pub fn get_owner(env: &Env) -> Address {
+    env.storage()
         .instance()
-        .get(&((KEY_OWNER,),))
-        .expect("owner missing");
-    if caller != owner {
-        panic!("only owner");
+        .get(&(KEY_OWNER,))
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
+
+/// Returns the account ownership is being transferred to, if any.
+pub fn get_pending_owner(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingOwner)
+}
+
+/// Starts transferring ownership to `new_owner`, who must call
+/// `accept_ownership` to complete it. Replaces any pending transfer; passing
+/// the current owner cancels it.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+pub fn transfer_ownership(env: &Env, current_owner: Address, new_owner: Address) {
+    current_owner.require_auth();
+    if get_owner(env) != current_owner {
+        handle_error(env, Error::Unauthorized);
     }
 
+    if new_owner == current_owner {
+        env.storage().instance().remove(&DataKey::PendingOwner);
+    } else {
+        env.storage().instance().set(&DataKey::PendingOwner, &new_owner);
+    }
+
+    events::ownership_proposed(env, &current_owner, &new_owner);
+}
+
+/// Completes a pending ownership transfer.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::NoPendingOwner` if no transfer to
+///   `new_owner` is pending.
+pub fn accept_ownership(env: &Env, new_owner: Address) {
+    new_owner.require_auth();
+    if get_pending_owner(env) != Some(new_owner.clone()) {
+        handle_error(env, Error::NoPendingOwner);
+    }
+
+    let previous_owner: Address = get_owner(env);
     let inst: Instance = env.storage().instance();
-    inst.set(&(KEY_USER_MGMT_ADDR,), &user_mgmt_addr);
-    inst.set(&(KEY_COURSE_REG_ADDR,), &course_registry_addr);
+    inst.set(&(KEY_OWNER,), &new_owner);
+    inst.remove(&DataKey::PendingOwner);
+
+    events::ownership_accepted(env, &previous_owner, &new_owner);
+}
+
+/// Sets the address of the progress contract used to verify course completions.
+/// This function can only be called by the contract owner.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment object.
+/// * `caller` - The address of the caller. Must be the current owner of the contract.
+/// * `progress_addr` - The address of the progress contract.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+pub fn set_progress_addr(env: Env, caller: Address, progress_addr: Address) {
+    caller.require_auth();
+
+    let owner: Option<Address> = env.storage().instance().get(&(KEY_OWNER,));
+    if owner != Some(caller.clone()) {
+        handle_error(&env, Error::Unauthorized);
+    }
+
+    env.storage().instance().set(&(KEY_PROGRESS_ADDR,), &progress_addr);
     env.events()
-        .publish((UPDATE_ADDRESS_EVENT,), (caller, user_mgmt_addr, course_registry_addr));
+        .publish((UPDATE_PROGRESS_EVENT,), (caller, progress_addr));
 }
 
 /* /// TTL configuration constants for persistent storage entries
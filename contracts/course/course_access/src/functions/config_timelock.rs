@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Two-step configuration changes.
+//!
+//! The owner proposes new contract addresses with `propose_config`; they
+//! only take effect once `apply_config` is called after the configured delay,
+//! which gives everyone watching the `config` events time to react if the
+//! owner key is compromised. The delay itself is part of the proposal, so it
+//! can't be shortened without waiting out the current one.
+
+use soroban_sdk::{Address, Env};
+use soroban_sdk::storage::Instance;
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::{get_config, is_owner};
+use crate::schema::{
+    ConfigProposal, CourseAccessConfig, DataKey, DEFAULT_CONFIG_DELAY, KEY_COURSE_REG_ADDR,
+    KEY_USER_MGMT_ADDR, MAX_CONFIG_DELAY,
+};
+
+/// Returns the seconds a proposal waits before it can be applied.
+pub fn get_config_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ConfigDelay)
+        .unwrap_or(DEFAULT_CONFIG_DELAY)
+}
+
+/// Returns the pending configuration proposal, if any.
+pub fn get_config_proposal(env: &Env) -> Option<ConfigProposal> {
+    env.storage().instance().get(&DataKey::ConfigProposal)
+}
+
+/// Proposes new contract addresses and delay (owner only), replacing any
+/// pending proposal and restarting its delay.
+pub fn propose_config(
+    env: &Env,
+    owner: Address,
+    user_mgmt_addr: Address,
+    course_registry_addr: Address,
+    config_delay: u64,
+) -> ConfigProposal {
+    require_owner(env, &owner);
+    if config_delay > MAX_CONFIG_DELAY {
+        handle_error(env, Error::InvalidConfigDelay)
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let proposal: ConfigProposal = ConfigProposal {
+        user_mgmt_addr,
+        course_registry_addr,
+        config_delay,
+        proposed_at: now,
+        executable_at: now.saturating_add(get_config_delay(env)),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::ConfigProposal, &proposal);
+
+    events::config_proposed(env, &owner, &proposal);
+    proposal
+}
+
+/// Applies the pending proposal once its delay has passed (owner only).
+pub fn apply_config(env: &Env, owner: Address) -> CourseAccessConfig {
+    require_owner(env, &owner);
+    let proposal: ConfigProposal =
+        get_config_proposal(env).unwrap_or_else(|| handle_error(env, Error::NoConfigProposal));
+    if env.ledger().timestamp() < proposal.executable_at {
+        handle_error(env, Error::ConfigTimelockActive)
+    }
+
+    let inst: Instance = env.storage().instance();
+    inst.set(&(KEY_USER_MGMT_ADDR,), &proposal.user_mgmt_addr);
+    inst.set(&(KEY_COURSE_REG_ADDR,), &proposal.course_registry_addr);
+    inst.set(&DataKey::ConfigDelay, &proposal.config_delay);
+    inst.remove(&DataKey::ConfigProposal);
+
+    events::config_applied(env, &owner, &proposal);
+    get_config(env)
+}
+
+/// Drops the pending proposal (owner only).
+pub fn cancel_config_proposal(env: &Env, owner: Address) {
+    require_owner(env, &owner);
+    let proposal: ConfigProposal =
+        get_config_proposal(env).unwrap_or_else(|| handle_error(env, Error::NoConfigProposal));
+    env.storage().instance().remove(&DataKey::ConfigProposal);
+
+    events::config_proposal_cancelled(env, &owner, &proposal);
+}
+
+fn require_owner(env: &Env, owner: &Address) {
+    owner.require_auth();
+    if !is_owner(env, owner) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, Address, Env, String, Vec, vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env, String, Vec, vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::is_owner;
 
 /// Errors that can occur during contract versioning operations
 #[contracterror]
@@ -22,53 +26,143 @@ pub enum VersioningError {
     MigrationFailed = 6,
 }
 
+/// A single entry of the contract version history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionRecord {
+    /// Version string that was deployed or migrated to
+    pub version: String,
+    /// Ledger timestamp at which the version was recorded
+    pub ledger_timestamp: u64,
+    /// Address that initialized, upgraded or migrated the contract
+    pub by: Address,
+}
+
+/// Current migration state of the contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    /// Version of the code that is currently running
+    pub code_version: String,
+    /// Latest version in the history (empty if nothing was recorded yet)
+    pub recorded_version: String,
+    /// True after an upgrade until a data migration completes, or when the
+    /// recorded version does not match the running code
+    pub migration_pending: bool,
+    /// Outcome of the last migration attempt (empty if none ran)
+    pub last_result: String,
+}
 
+
+/// Storage keys for versioning data
 const VERSION_HISTORY_KEY: &str = "version_history";
 const MIGRATION_STATUS_KEY: &str = "migration_status";
+const MIGRATION_PENDING_KEY: &str = "migration_pending";
 
-
-pub fn get_version_history(env: &Env) -> Vec<String> {
-    let key = String::from_str(env, VERSION_HISTORY_KEY);
+/// Get the version history of the contract
+pub fn get_version_history(env: &Env) -> Vec<VersionRecord> {
+    let key: String = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage()
         .instance()
-        .get::<String, Vec<String>>(&key)
+        .get::<String, Vec<VersionRecord>>(&key)
         .unwrap_or_else(|| vec![env])
 }
 
-fn store_version_in_history(env: &Env, version: String) {
-    let mut history: Vec<String> = get_version_history(env);
-    history.push_back(version.clone());
-    
+/// Append `{version, ledger_timestamp, by}` to the version history.
+///
+/// Called on initialization, upgrade and completed migrations. Authorization
+/// is the caller's responsibility.
+pub fn record_version(env: &Env, admin: &Address, version: String) {
+    let mut history: Vec<VersionRecord> = get_version_history(env);
+    history.push_back(VersionRecord {
+        version,
+        ledger_timestamp: env.ledger().timestamp(),
+        by: admin.clone(),
+    });
+
     let key: String = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage().instance().set(&key, &history);
 }
 
+/// Latest version in the history, if any
+fn latest_recorded_version(env: &Env) -> Option<String> {
+    get_version_history(env).last().map(|record| record.version)
+}
 
+/// Check if a version exists in the history
 fn version_exists_in_history(env: &Env, version: &String) -> bool {
-    let history: Vec<String> = get_version_history(env);
-    for v in history.iter() {
-        if &v == version {
-            return true;
+    get_version_history(env)
+        .iter()
+        .any(|record| &record.version == version)
+}
+
+/// Replace the contract code with an uploaded WASM and record the upgrade.
+///
+/// The version history gets the current version (if missing) and `new_version`
+/// appended before the code is swapped, so the history survives the upgrade.
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>, new_version: String) {
+    admin.require_auth();
+
+    if !is_owner(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let from_version: String = match latest_recorded_version(env) {
+        Some(version) => version,
+        None => {
+            let current: String = String::from_str(env, crate::VERSION);
+            record_version(env, &admin, current.clone());
+            current
         }
+    };
+    record_version(env, &admin, new_version.clone());
+    if from_version != new_version {
+        set_migration_pending(env, true);
     }
-    false
-}
 
+    events::contract_upgraded(env, &from_version, &new_version, &new_wasm_hash, &admin);
 
-pub fn get_migration_status(env: &Env) -> String {
-    let key: String = String::from_str(env, MIGRATION_STATUS_KEY);
-    env.storage()
-        .instance()
-        .get::<String, String>(&key)
-        .unwrap_or_else(|| String::from_str(env, "No migrations pending"))
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
 }
 
+/// Get migration status information
+pub fn get_migration_status(env: &Env) -> MigrationStatus {
+    let code_version: String = String::from_str(env, crate::VERSION);
+    let recorded_version: String =
+        latest_recorded_version(env).unwrap_or_else(|| String::from_str(env, ""));
+    let pending_flag: bool = env
+        .storage()
+        .instance()
+        .get::<String, bool>(&String::from_str(env, MIGRATION_PENDING_KEY))
+        .unwrap_or(false);
+    let migration_pending: bool =
+        pending_flag || (!recorded_version.is_empty() && recorded_version != code_version);
+    let last_result: String = env
+        .storage()
+        .instance()
+        .get::<String, String>(&String::from_str(env, MIGRATION_STATUS_KEY))
+        .unwrap_or_else(|| String::from_str(env, ""));
 
+    MigrationStatus {
+        code_version,
+        recorded_version,
+        migration_pending,
+        last_result,
+    }
+}
+
+/// Set the result message of the last migration attempt
 fn set_migration_status(env: &Env, status: String) {
-    let key = String::from_str(env, MIGRATION_STATUS_KEY);
+    let key: String = String::from_str(env, MIGRATION_STATUS_KEY);
     env.storage().instance().set(&key, &status);
 }
 
+/// Set or clear the pending migration flag
+fn set_migration_pending(env: &Env, pending: bool) {
+    let key: String = String::from_str(env, MIGRATION_PENDING_KEY);
+    env.storage().instance().set(&key, &pending);
+}
+
 
 pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: String) -> bool {
     // Simple compatibility check - for now, assume all versions are compatible
@@ -77,17 +171,9 @@ pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: Str
 }
 
 
-fn is_authorized_for_migration(_env: &Env, _caller: Address) -> bool {
-    // For now, we'll allow any authenticated user
-    // In a real implementation, you would check against user management contract
-    // or implement your own authorization logic
-    
-    // You could call the user management contract to check if the caller is admin
-    // let user_mgmt_addr = get_user_mgmt_addr(env);
-    // let client = UserManagementClient::new(env, &user_mgmt_addr);
-    // client.is_admin(&caller)
-    
-    true // Placeholder - allow all authenticated users
+/// Migrations are restricted to the owner, like `upgrade`
+fn is_authorized_for_migration(env: &Env, caller: &Address) -> bool {
+    is_owner(env, caller)
 }
 
 pub fn migrate_access_data(
@@ -96,10 +182,9 @@ pub fn migrate_access_data(
     from_version: String,
     to_version: String,
 ) -> bool {
-
-    if !is_authorized_for_migration(env, caller.clone()) {
-        set_migration_status(env, String::from_str(env, "Migration failed: Unauthorized"));
-        return false;
+    caller.require_auth();
+    if !is_authorized_for_migration(env, &caller) {
+        handle_error(env, Error::Unauthorized)
     }
     
 
@@ -118,8 +203,11 @@ pub fn migrate_access_data(
     let migration_result: bool = perform_access_data_migration(env, &from_version, &to_version);
     
     if migration_result {
-        // Update version history with new version
-        store_version_in_history(env, to_version.clone());
+        // Record the target version unless the upgrade already did
+        if latest_recorded_version(env) != Some(to_version.clone()) {
+            record_version(env, &caller, to_version.clone());
+        }
+        set_migration_pending(env, false);
         
         // Set successful migration status
         let status: String = String::from_str(env, "Migration completed successfully");
@@ -207,10 +295,74 @@ mod test {
 
     #[test]
     fn test_migration_authorization() {
-        let env = Env::default();
-        let contract_id = env.register(crate::CourseAccessContract, ());
-        
-        // For now, all users are authorized (placeholder implementation)
-        assert!(is_authorized_for_migration(&env, contract_id));
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::CourseAccessContract, ());
+        let client = crate::CourseAccessContractClient::new(&env, &contract_id);
+        let owner: Address = Address::generate(&env);
+        client.initialize(&owner, &Address::generate(&env), &Address::generate(&env));
+
+        let (owner_allowed, stranger_allowed) = env.as_contract(&contract_id, || {
+            (
+                is_authorized_for_migration(&env, &owner),
+                is_authorized_for_migration(&env, &Address::generate(&env)),
+            )
+        });
+        assert!(owner_allowed);
+        assert!(!stranger_allowed);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #3)")]
+    fn test_migration_requires_owner() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::CourseAccessContract, ());
+        let client = crate::CourseAccessContractClient::new(&env, &contract_id);
+        let version: String = String::from_str(&env, crate::VERSION);
+        client.initialize(&Address::generate(&env), &Address::generate(&env), &Address::generate(&env));
+
+        client.migrate_access_data(&Address::generate(&env), &version, &version);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #3)")]
+    fn test_upgrade_requires_admin() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::CourseAccessContract, ());
+        let client = crate::CourseAccessContractClient::new(&env, &contract_id);
+
+        let stranger: Address = Address::generate(&env);
+        client.upgrade(
+            &stranger,
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &String::from_str(&env, "1.1.0"),
+        );
+    }
+
+    #[test]
+    fn test_initialize_records_version() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::CourseAccessContract, ());
+        let client = crate::CourseAccessContractClient::new(&env, &contract_id);
+        let owner: Address = Address::generate(&env);
+
+        client.initialize(&owner, &Address::generate(&env), &Address::generate(&env));
+
+        let history: Vec<VersionRecord> = client.get_version_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap().version, String::from_str(&env, crate::VERSION));
+        assert_eq!(history.get(0).unwrap().by, owner);
+        assert!(!client.get_migration_status().migration_pending);
     }
 }
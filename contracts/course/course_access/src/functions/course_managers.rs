@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Delegated enrollment management.
+//!
+//! A course creator can appoint managers who grant and revoke access to the
+//! course on their behalf, without being able to edit the course itself.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::clients::{course_registry, user_management};
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::trusted_contracts::is_trusted_contract;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::DataKey;
+
+pub(crate) fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
+    course_registry(env).is_course_creator(course_id, who)
+}
+
+pub(crate) fn is_admin(env: &Env, who: &Address) -> bool {
+    user_management(env).is_admin(who)
+}
+
+fn require_creator(env: &Env, creator: &Address, course_id: &String) {
+    creator.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId);
+    }
+    if !is_course_creator(env, course_id, creator) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Returns the managers of a course.
+pub fn get_course_managers(env: &Env, course_id: String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseManagers(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns whether `who` manages enrollments of the course.
+pub fn is_course_manager(env: &Env, course_id: String, who: Address) -> bool {
+    get_course_managers(env, course_id).contains(&who)
+}
+
+/// Lets `manager` grant and revoke access to the course. Creator only.
+pub fn add_course_manager(env: Env, creator: Address, course_id: String, manager: Address) {
+    require_creator(&env, &creator, &course_id);
+
+    let mut managers: Vec<Address> = get_course_managers(&env, course_id.clone());
+    if managers.contains(&manager) {
+        return;
+    }
+    managers.push_back(manager.clone());
+
+    let key: DataKey = DataKey::CourseManagers(course_id.clone());
+    env.storage().persistent().set(&key, &managers);
+    bump_persistent(&env, &key);
+
+    events::manager_added(&env, &course_id, &manager, &creator);
+}
+
+/// Withdraws the delegation given to `manager`. Creator only.
+pub fn remove_course_manager(env: Env, creator: Address, course_id: String, manager: Address) {
+    require_creator(&env, &creator, &course_id);
+
+    let mut managers: Vec<Address> = get_course_managers(&env, course_id.clone());
+    if let Some(index) = managers.first_index_of(&manager) {
+        managers.remove(index);
+
+        let key: DataKey = DataKey::CourseManagers(course_id.clone());
+        if managers.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &managers);
+            bump_persistent(&env, &key);
+        }
+
+        events::manager_removed(&env, &course_id, &manager, &creator);
+    }
+}
+
+/// Panics with `Unauthorized` unless `caller` is a manager of the course,
+/// a trusted contract, an admin or the course creator. The caller must
+/// already be authenticated.
+pub fn require_enrollment_authority(env: &Env, caller: &Address, course_id: &String) {
+    if is_course_manager(env, course_id.clone(), caller.clone())
+        || is_trusted_contract(env, caller)
+    {
+        return;
+    }
+    if !(is_admin(env, caller) || is_course_creator(env, course_id, caller)) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::EnrollmentSource;
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+    }
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn set_creator(env: Env, creator: Address) {
+            env.storage().instance().set(&"creator", &creator);
+        }
+        pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+            env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+        }
+        pub fn record_enrollment(_env: Env, _reporter: Address, _course_id: String) -> u32 {
+            0
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseAccessContractClient<'_>, Address, Address, String) {
+        env.mock_all_auths();
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        let course_registry_id: Address = env.register(CourseRegistry, ());
+        let contract_id: Address = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(env, &contract_id);
+        let owner: Address = Address::generate(env);
+        client.initialize(&owner, &user_mgmt_id, &course_registry_id);
+
+        let creator: Address = Address::generate(env);
+        CourseRegistryClient::new(env, &course_registry_id).set_creator(&creator);
+        (client, owner, creator, String::from_str(env, "course_1"))
+    }
+
+    #[test]
+    fn test_manager_can_grant_and_revoke_access() {
+        let env: Env = Env::default();
+        let (client, _owner, creator, course_id) = setup(&env);
+        let manager: Address = Address::generate(&env);
+        let student: Address = Address::generate(&env);
+
+        client.add_course_manager(&creator, &course_id, &manager);
+        client.add_course_manager(&creator, &course_id, &manager);
+        assert_eq!(client.get_course_managers(&course_id).len(), 1);
+        assert!(client.is_course_manager(&course_id, &manager));
+
+        client.grant_access(&manager, &course_id, &student);
+        assert_eq!(client.get_enrollment(&course_id, &student).unwrap().granted_by, Some(manager.clone()));
+        assert!(client.revoke_access(&manager, &course_id, &student, &None, &None));
+        client.grant_access_with_source(&manager, &course_id, &student, &EnrollmentSource::Manual);
+        client.revoke_all_access(&manager, &course_id);
+
+        client.remove_course_manager(&creator, &course_id, &manager);
+        assert!(!client.is_course_manager(&course_id, &manager));
+        assert_eq!(
+            client
+                .try_grant_access_with_source(&manager, &course_id, &Address::generate(&env), &EnrollmentSource::Manual)
+                .err(),
+            Some(Ok(soroban_sdk::Error::from_contract_error(crate::error::Error::Unauthorized as u32)))
+        );
+    }
+
+    #[test]
+    fn test_only_enrollment_authorities_ensure_access() {
+        let env: Env = Env::default();
+        let (client, _owner, creator, course_id) = setup(&env);
+        let student: Address = Address::generate(&env);
+
+        assert_eq!(
+            client.try_ensure_access(&student, &course_id, &student).err(),
+            Some(Ok(soroban_sdk::Error::from_contract_error(crate::error::Error::Unauthorized as u32)))
+        );
+        assert!(!client.has_access(&course_id, &student));
+
+        assert!(client.ensure_access(&creator, &course_id, &student));
+        assert!(!client.ensure_access(&creator, &course_id, &student));
+    }
+
+    #[test]
+    fn test_only_enrollment_authorities_grant_and_revoke_access() {
+        let env: Env = Env::default();
+        let (client, _owner, creator, course_id) = setup(&env);
+        let student: Address = Address::generate(&env);
+        let unauthorized = Some(Ok(soroban_sdk::Error::from_contract_error(
+            crate::error::Error::Unauthorized as u32,
+        )));
+
+        assert_eq!(client.try_grant_access(&student, &course_id, &student).err(), unauthorized);
+        assert!(!client.has_access(&course_id, &student));
+
+        client.grant_access(&creator, &course_id, &student);
+        assert_eq!(
+            client.try_revoke_access(&student, &course_id, &student, &None, &None).err(),
+            unauthorized
+        );
+        assert!(client.has_access(&course_id, &student));
+        assert!(client.revoke_access(&creator, &course_id, &student, &None, &None));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #3)")]
+    fn test_only_creator_can_add_managers() {
+        let env: Env = Env::default();
+        let (client, _owner, _creator, course_id) = setup(&env);
+        let manager: Address = Address::generate(&env);
+
+        client.add_course_manager(&manager, &course_id, &manager);
+    }
+
+    #[test]
+    fn test_trusted_contract_can_grant_access() {
+        let env: Env = Env::default();
+        let (client, owner, _creator, course_id) = setup(&env);
+        let payments: Address = Address::generate(&env);
+        let student: Address = Address::generate(&env);
+        assert!(client
+            .try_grant_access_with_source(&payments, &course_id, &student, &EnrollmentSource::Purchase)
+            .is_err());
+
+        client.set_trusted_contract(&owner, &payments, &true);
+        assert!(client.is_trusted_contract(&payments));
+        client.grant_access_with_source(&payments, &course_id, &student, &EnrollmentSource::Purchase);
+        assert_eq!(client.get_enrollment(&course_id, &student).unwrap().granted_by, Some(payments.clone()));
+
+        client.set_trusted_contract(&owner, &payments, &false);
+        assert_eq!(client.get_trusted_contracts().len(), 0);
+        assert!(client
+            .try_grant_access_with_source(&payments, &course_id, &Address::generate(&env), &EnrollmentSource::Purchase)
+            .is_err());
+        assert!(client.try_set_trusted_contract(&payments, &payments, &true).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Reverse index of the users with access to a course.
+//!
+//! The users of a course are stored in shards of `COURSE_USERS_SHARD_SIZE`
+//! addresses, so no storage entry grows with the course. Each member has a
+//! slot key holding their position, which makes removal O(1): the last user
+//! is moved into the freed position. Access checks don't use this index,
+//! they read the `CourseAccess` key of the grant.
+//!
+//! Courses indexed before sharding keep a single `CourseUsers` entry, which
+//! is moved into shards the first time the course's index is used.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{
+    CourseUsers, CourseUsersPage, DataKey, COURSE_USERS_SHARD_SIZE, MAX_PAGE_SIZE,
+};
+
+/// Moves a course's legacy single-entry user list into shards.
+fn migrate_legacy(env: &Env, course_id: &String) {
+    let key: DataKey = DataKey::CourseUsers(course_id.clone());
+    if let Some(legacy) = env.storage().persistent().get::<_, CourseUsers>(&key) {
+        env.storage().persistent().remove(&key);
+        for user in legacy.users.iter() {
+            add_course_user(env, course_id, &user);
+        }
+    }
+}
+
+fn shard(env: &Env, course_id: &String, shard: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseUsersShard(course_id.clone(), shard))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_shard(env: &Env, course_id: &String, shard: u32, users: &Vec<Address>) {
+    let key: DataKey = DataKey::CourseUsersShard(course_id.clone(), shard);
+    if users.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, users);
+        bump_persistent(env, &key);
+    }
+}
+
+fn set_count(env: &Env, course_id: &String, count: u32) {
+    let key: DataKey = DataKey::CourseUserCount(course_id.clone());
+    env.storage().persistent().set(&key, &count);
+    bump_persistent(env, &key);
+}
+
+fn set_slot(env: &Env, course_id: &String, user: &Address, position: u32) {
+    let key: DataKey = DataKey::CourseUserSlot(course_id.clone(), user.clone());
+    env.storage().persistent().set(&key, &position);
+    bump_persistent(env, &key);
+}
+
+/// Returns how many users are in a course's index.
+pub fn course_user_count(env: &Env, course_id: &String) -> u32 {
+    if let Some(legacy) = env
+        .storage()
+        .persistent()
+        .get::<_, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+    {
+        return legacy.users.len();
+    }
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseUserCount(course_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Adds a user to a course's index. Returns `false` if they were already in it.
+pub(crate) fn add_course_user(env: &Env, course_id: &String, user: &Address) -> bool {
+    migrate_legacy(env, course_id);
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::CourseUserSlot(course_id.clone(), user.clone()))
+    {
+        return false;
+    }
+
+    let position: u32 = course_user_count(env, course_id);
+    let shard_id: u32 = position / COURSE_USERS_SHARD_SIZE;
+    let mut users: Vec<Address> = shard(env, course_id, shard_id);
+    users.push_back(user.clone());
+    set_shard(env, course_id, shard_id, &users);
+    set_slot(env, course_id, user, position);
+    set_count(env, course_id, position + 1);
+    true
+}
+
+/// Removes a user from a course's index, moving the last user into their
+/// position. Returns `false` if they weren't in it.
+pub(crate) fn remove_course_user(env: &Env, course_id: &String, user: &Address) -> bool {
+    migrate_legacy(env, course_id);
+    let slot_key: DataKey = DataKey::CourseUserSlot(course_id.clone(), user.clone());
+    let position: u32 = match env.storage().persistent().get(&slot_key) {
+        Some(position) => position,
+        None => return false,
+    };
+
+    let last: u32 = course_user_count(env, course_id) - 1;
+    let last_shard_id: u32 = last / COURSE_USERS_SHARD_SIZE;
+    let mut last_shard: Vec<Address> = shard(env, course_id, last_shard_id);
+    let moved: Address = last_shard.pop_back().unwrap();
+    if position != last {
+        let shard_id: u32 = position / COURSE_USERS_SHARD_SIZE;
+        if shard_id == last_shard_id {
+            last_shard.set(position % COURSE_USERS_SHARD_SIZE, moved.clone());
+        } else {
+            let mut users: Vec<Address> = shard(env, course_id, shard_id);
+            users.set(position % COURSE_USERS_SHARD_SIZE, moved.clone());
+            set_shard(env, course_id, shard_id, &users);
+        }
+        set_slot(env, course_id, &moved, position);
+    }
+    set_shard(env, course_id, last_shard_id, &last_shard);
+    env.storage().persistent().remove(&slot_key);
+    set_count(env, course_id, last);
+    true
+}
+
+/// Returns up to `limit` users of a course, starting at position `offset`.
+///
+/// Positions are stable while no user is removed; a removal moves the
+/// last user into the freed position.
+pub fn list_course_access_paged(
+    env: &Env,
+    course_id: String,
+    offset: u32,
+    limit: u32,
+) -> CourseUsersPage {
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId);
+    }
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidPageLimit);
+    }
+    migrate_legacy(env, &course_id);
+
+    let total: u32 = course_user_count(env, &course_id);
+    let end: u32 = offset.saturating_add(limit).min(total);
+    let mut users: Vec<Address> = Vec::new(env);
+    let mut position: u32 = offset;
+    while position < end {
+        let shard_id: u32 = position / COURSE_USERS_SHARD_SIZE;
+        let shard_users: Vec<Address> = shard(env, &course_id, shard_id);
+        let shard_end: u32 = end.min((shard_id + 1) * COURSE_USERS_SHARD_SIZE);
+        for index in
+            (position % COURSE_USERS_SHARD_SIZE)..(shard_end - shard_id * COURSE_USERS_SHARD_SIZE)
+        {
+            users.push_back(shard_users.get(index).unwrap());
+        }
+        position = shard_end;
+    }
+
+    CourseUsersPage {
+        course: course_id,
+        users,
+        total,
+        has_more: end < total,
+    }
+}
+
+/// Returns every user of a course, reading all of its shards.
+pub(crate) fn all_course_users(env: &Env, course_id: &String) -> Vec<Address> {
+    if let Some(legacy) = env
+        .storage()
+        .persistent()
+        .get::<_, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+    {
+        return legacy.users;
+    }
+    let shards: u32 = course_user_count(env, course_id).div_ceil(COURSE_USERS_SHARD_SIZE);
+    let mut users: Vec<Address> = Vec::new(env);
+    for shard_id in 0..shards {
+        users.append(&shard(env, course_id, shard_id));
+    }
+    users
+}
+
+/// Extends the TTL of a course's index: the user count and every shard.
+pub(crate) fn bump_course_users(env: &Env, course_id: &String) {
+    let count: u32 = course_user_count(env, course_id);
+    bump_persistent(env, &DataKey::CourseUsers(course_id.clone()));
+    bump_persistent(env, &DataKey::CourseUserCount(course_id.clone()));
+    for shard_id in 0..count.div_ceil(COURSE_USERS_SHARD_SIZE) {
+        bump_persistent(env, &DataKey::CourseUsersShard(course_id.clone(), shard_id));
+    }
+}
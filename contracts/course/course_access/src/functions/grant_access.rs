@@ -1,15 +1,133 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, String, Vec, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::schema::{CourseAccess, DataKey, UserCourses, CourseUsers};
+use crate::clients::{try_course_registry, try_user_management};
+use crate::schema::{CourseAccess, DataKey, EnrollmentRecord, EnrollmentSource, UserCourses};
 use crate::error::{Error, handle_error};
+use crate::events;
+use crate::functions::backup::index_access_course;
+use crate::functions::course_managers::require_enrollment_authority;
+use crate::functions::course_users::{add_course_user, course_user_count};
+use crate::functions::prerequisites::require_prerequisites_met;
+use crate::functions::token_gate::holds_gate_token;
+use crate::functions::ttl::bump_persistent;
 
-const COURSE_ACCESS_EVENT: Symbol = symbol_short!("crsAccess");
 
-/// Grant access to a specific user for a given course
-pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
+/// Grant access to a specific user for a given course on behalf of `caller`.
+///
+/// The caller must be the course creator, one of its managers, a trusted
+/// contract or an admin.
+pub fn course_access_grant_access(env: Env, caller: Address, course_id: String, user: Address) {
+    caller.require_auth();
+    require_enrollment_authority(&env, &caller, &course_id);
+
+    grant_with_record(env, course_id, user, Some(caller), EnrollmentSource::Manual)
+}
+
+/// Returns whether `user` currently has access to the course, either through
+/// an access grant, by holding the course's gate token, or through the grant
+/// of the learner identity `user` is linked to in user management.
+pub fn has_access(env: &Env, course_id: String, user: Address) -> bool {
+    if has_access_grant(env, course_id.clone(), user.clone())
+        || holds_gate_token(env, course_id.clone(), user.clone())
+    {
+        return true;
+    }
+    match primary_identity(env, &user) {
+        Some(primary) => has_access_grant(env, course_id, primary),
+        None => false,
+    }
+}
+
+/// Returns the primary address `user` is linked to in user management, or
+/// `None` if it isn't a linked secondary address.
+///
+/// A missing or older user management contract resolves to `None`, so access
+/// checks keep working without identity links.
+fn primary_identity(env: &Env, user: &Address) -> Option<Address> {
+    match try_user_management(env)?.try_resolve_identity(user) {
+        Ok(Ok(primary)) if primary != *user => Some(primary),
+        _ => None,
+    }
+}
+
+/// Returns whether an access grant is stored for `user`, ignoring token gates.
+pub(crate) fn has_access_grant(env: &Env, course_id: String, user: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CourseAccess(course_id, user))
+}
+
+/// Grant access on behalf of `granter` unless the user already has it.
+///
+/// The granter must be the course creator, one of its managers, a trusted
+/// contract or an admin. Returns `true` if access was granted, `false` if it
+/// was already present, so retried transactions succeed instead of failing
+/// with `UserAlreadyHasAccess`.
+pub fn ensure_access(env: Env, granter: Address, course_id: String, user: Address) -> bool {
+    granter.require_auth();
+    require_enrollment_authority(&env, &granter, &course_id);
+
+    if has_access_grant(&env, course_id.clone(), user.clone()) {
+        return false;
+    }
+
+    grant_with_record(env, course_id, user, Some(granter), EnrollmentSource::Manual);
+    true
+}
+
+/// Grant access on behalf of `granter`, recording how the access was obtained.
+///
+/// The granter must be the course creator, one of its managers, a trusted
+/// contract such as payments or disputes, or an admin.
+pub fn grant_access_with_source(
+    env: Env,
+    granter: Address,
+    course_id: String,
+    user: Address,
+    source: EnrollmentSource,
+) {
+    granter.require_auth();
+    require_enrollment_authority(&env, &granter, &course_id);
+
+    grant_with_record(env, course_id, user, Some(granter), source)
+}
+
+/// Returns how and when a user obtained access to a course, if they have access.
+pub fn get_enrollment(env: &Env, course_id: String, user: Address) -> Option<EnrollmentRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Enrollment(course_id, user))
+}
+
+/// Stores the enrollment record of a grant.
+pub fn store_enrollment(
+    env: &Env,
+    course_id: &String,
+    user: &Address,
+    granted_by: Option<Address>,
+    source: EnrollmentSource,
+) {
+    let key: DataKey = DataKey::Enrollment(course_id.clone(), user.clone());
+    let record: EnrollmentRecord = EnrollmentRecord {
+        granted_at: env.ledger().timestamp(),
+        granted_by,
+        source,
+    };
+    env.storage().persistent().set(&key, &record);
+    bump_persistent(env, &key);
+}
+
+/// Grants access and records who granted it and why.
+pub fn grant_with_record(
+    env: Env,
+    course_id: String,
+    user: Address,
+    granted_by: Option<Address>,
+    source: EnrollmentSource,
+) {
     // Validate input parameters
     if course_id.is_empty() {
         handle_error(&env, Error::EmptyCourseId);
@@ -27,6 +145,9 @@ pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
         handle_error(&env, Error::UserAlreadyHasAccess)
     }
 
+    // Courses can require their prerequisites to be completed first
+    require_prerequisites_met(&env, &course_id, &user);
+
     // Create the course access entry
     let course_access: CourseAccess = CourseAccess {
         course_id: course_id.clone(),
@@ -35,7 +156,8 @@ pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
 
     // Store the access entry
     env.storage().persistent().set(&key, &course_access);
-    env.storage().persistent().extend_ttl(&key, 100, 1000);
+    bump_persistent(&env, &key);
+    store_enrollment(&env, &course_id, &user, granted_by, source);
 
     // Update UserCourses
     let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
@@ -50,24 +172,26 @@ pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
     if !user_courses.courses.contains(&course_id) {
         user_courses.courses.push_back(course_id.clone());
         env.storage().persistent().set(&user_courses_key, &user_courses);
-        env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
+        bump_persistent(&env, &user_courses_key);
     }
 
-    // Update CourseUsers
-    let course_users_key: DataKey = DataKey::CourseUsers(course_id.clone());
-    let mut course_users: CourseUsers = env
-        .storage()
-        .persistent()
-        .get(&course_users_key)
-        .unwrap_or(CourseUsers {
-            course: course_id.clone(),
-            users: Vec::new(&env),
-        });
-    if !course_users.users.contains(&user) {
-        course_users.users.push_back(user.clone());
-        env.storage().persistent().set(&course_users_key, &course_users);
-        env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
+    // Update the course's user index
+    if course_user_count(&env, &course_id) == 0 {
+        index_access_course(&env, &course_id);
+    }
+    add_course_user(&env, &course_id, &user);
+
+    report_enrollment(&env, &course_id);
+
+    events::access_granted(&env, &course_id, &user);
+}
+
+/// Report the enrollment to the course registry statistics.
+///
+/// Best effort: a registry that doesn't accept this contract as a stats
+/// reporter must not prevent access from being granted.
+fn report_enrollment(env: &Env, course_id: &String) {
+    if let Some(course_registry) = try_course_registry(env) {
+        let _ = course_registry.try_record_enrollment(&env.current_contract_address(), course_id);
     }
-    env.events()
-        .publish((COURSE_ACCESS_EVENT, &user.clone()), (course_id, user, course_users.users.len(),));
 }
@@ -1,10 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Env, String, Vec};
+use soroban_sdk::{Env, String};
 
-use crate::schema::{CourseUsers, DataKey};
+use crate::schema::CourseUsers;
 use crate::error::{Error, handle_error};
+use crate::functions::course_users::{all_course_users, bump_course_users, course_user_count};
 
 pub fn course_access_list_course_access(env: Env, course_id: String) -> CourseUsers {
     // Validate input parameters
@@ -17,15 +18,16 @@ pub fn course_access_list_course_access(env: Env, course_id: String) -> CourseUs
         handle_error(&env, Error::InvalidCourseId);
     }
     
-    let key: DataKey = DataKey::CourseUsers(course_id.clone());
-
-    let res: CourseUsers = env.storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or(CourseUsers {
-            course: course_id.clone(),
-            users: Vec::new(&env),
-        });
+    let res: CourseUsers = CourseUsers {
+        course: course_id.clone(),
+        users: all_course_users(&env, &course_id),
+    };
+    bump_course_users(&env, &course_id);
 
     return res
 }
+
+/// Returns how many users currently have access to a course.
+pub fn course_access_count(env: &Env, course_id: String) -> u32 {
+    course_user_count(env, &course_id)
+}
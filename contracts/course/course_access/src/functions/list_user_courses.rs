@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::schema::{DataKey, UserCourses};
+use crate::error::{handle_error, Error};
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{DataKey, UserCourses, UserCoursesPage, MAX_PAGE_SIZE};
 
 
 pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
@@ -12,10 +14,29 @@ pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
         user: user.clone(),
         courses: Vec::new(&env),
     });
+    bump_persistent(&env, &key);
 
     return res
 }
 
+/// Returns up to `limit` of the courses a user has access to, starting at `offset`.
+pub fn list_user_courses_paged(env: Env, user: Address, offset: u32, limit: u32) -> UserCoursesPage {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(&env, Error::InvalidPageLimit);
+    }
+
+    let courses: Vec<String> = list_user_courses(env, user.clone()).courses;
+    let total: u32 = courses.len();
+    let start: u32 = offset.min(total);
+    let end: u32 = offset.saturating_add(limit).min(total);
+    UserCoursesPage {
+        user,
+        courses: courses.slice(start..end),
+        total,
+        has_more: end < total,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::schema::DataKey;
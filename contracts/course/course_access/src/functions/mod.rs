@@ -1,12 +1,27 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+pub mod announcements;
+pub mod backup;
 pub mod config;
+pub mod config_timelock;
 pub mod contract_versioning;
+pub mod course_managers;
+pub mod course_users;
 pub mod grant_access;
 pub mod list_course_access;
 pub mod list_user_courses;
+pub mod module_release;
+pub mod office_hours;
+pub mod org_access;
+pub mod pause;
+pub mod prerequisites;
 pub mod revoke_access;
 pub mod revoke_all_access;
 pub mod save_profile;
+pub mod self_enroll;
+pub mod token_gate;
+pub mod trusted_contracts;
 pub mod transfer_course_access;
+pub mod ttl;
+pub mod vouchers;
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Modules unlocked by the drip schedule of a course.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use skillcert_interfaces::course_registry::{CourseModule, ModuleRelease, ReleaseTime};
+
+use crate::clients::course_registry;
+use crate::functions::grant_access::{get_enrollment, has_access};
+
+/// Returns the IDs of the modules of a course a student can open now, in
+/// registry order.
+///
+/// Modules scheduled `AfterEnrollment` unlock that many seconds after the
+/// student's enrollment record; students with access but no record, such as
+/// gate token holders, are treated as enrolled since the start. Students
+/// without access get no module.
+pub fn get_unlocked_modules(env: &Env, student: Address, course_id: String) -> Vec<String> {
+    let mut unlocked: Vec<String> = Vec::new(env);
+    if !has_access(env, course_id.clone(), student.clone()) {
+        return unlocked;
+    }
+
+    let registry = course_registry(env);
+    let modules: Vec<CourseModule> = registry.list_modules(&course_id);
+    let schedule: Vec<ModuleRelease> = registry.get_module_release_schedule(&course_id);
+    let enrolled_at: u64 = get_enrollment(env, course_id, student)
+        .map(|record| record.granted_at)
+        .unwrap_or(0);
+    let now: u64 = env.ledger().timestamp();
+    for module in modules.iter() {
+        let released_at: u64 = match schedule.iter().find(|entry| entry.module_id == module.id) {
+            None => 0,
+            Some(entry) => match entry.release {
+                ReleaseTime::AfterEnrollment(offset) => enrolled_at.saturating_add(offset),
+                ReleaseTime::At(timestamp) => timestamp,
+            },
+        };
+        if released_at <= now {
+            unlocked.push_back(module.id);
+        }
+    }
+    unlocked
+}
+
+#[cfg(test)]
+mod tests {
+    use skillcert_interfaces::course_registry::{CourseModule, ModuleRelease, ReleaseTime};
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, testutils::Ledger, vec, Address, Env,
+        String, Vec,
+    };
+
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+        pub fn resolve_identity(_env: Env, address: Address) -> Address {
+            address
+        }
+    }
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn is_course_creator(_env: Env, _course_id: String, _user: Address) -> bool {
+            true
+        }
+        pub fn record_enrollment(_env: Env, _reporter: Address, _course_id: String) -> u32 {
+            0
+        }
+        pub fn list_modules(env: Env, course_id: String) -> Vec<CourseModule> {
+            let mut modules: Vec<CourseModule> = Vec::new(&env);
+            for (position, id) in ["m1", "m2", "m3"].iter().enumerate() {
+                modules.push_back(CourseModule {
+                    id: String::from_str(&env, id),
+                    course_id: course_id.clone(),
+                    position: position as u32,
+                    title: String::from_str(&env, "Lesson"),
+                    created_at: 0,
+                });
+            }
+            modules
+        }
+        pub fn get_module_release_schedule(env: Env, _course_id: String) -> Vec<ModuleRelease> {
+            vec![
+                &env,
+                ModuleRelease {
+                    module_id: String::from_str(&env, "m2"),
+                    release: ReleaseTime::AfterEnrollment(100),
+                },
+                ModuleRelease {
+                    module_id: String::from_str(&env, "m3"),
+                    release: ReleaseTime::At(5_000),
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn test_modules_unlock_after_enrollment() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        let course_registry_id: Address = env.register(CourseRegistry, ());
+        let client = CourseAccessContractClient::new(&env, &env.register(CourseAccessContract, ()));
+        client.initialize(&Address::generate(&env), &user_mgmt_id, &course_registry_id);
+        let course_id: String = String::from_str(&env, "course_1");
+        let student: Address = Address::generate(&env);
+        let m1: String = String::from_str(&env, "m1");
+        let m2: String = String::from_str(&env, "m2");
+        let m3: String = String::from_str(&env, "m3");
+
+        assert!(client.get_unlocked_modules(&student, &course_id).is_empty());
+        client.grant_access(&Address::generate(&env), &course_id, &student);
+        assert_eq!(
+            client.get_unlocked_modules(&student, &course_id),
+            vec![&env, m1.clone()]
+        );
+
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_100);
+        assert_eq!(
+            client.get_unlocked_modules(&student, &course_id),
+            vec![&env, m1.clone(), m2.clone()]
+        );
+
+        env.ledger().with_mut(|ledger| ledger.timestamp = 5_000);
+        assert_eq!(
+            client.get_unlocked_modules(&student, &course_id),
+            vec![&env, m1, m2, m3]
+        );
+    }
+}
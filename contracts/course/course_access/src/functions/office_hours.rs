@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Office-hour booking.
+//!
+//! Instructors publish time slots for a course; each slot can be booked by
+//! one student with access to the course. Every change emits an event so
+//! calendars can be synced off-chain.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::course_managers::require_enrollment_authority;
+use crate::functions::grant_access::has_access;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{DataKey, OfficeHourSlot};
+
+/// Maximum number of slots published by one `publish_slots` call.
+pub const MAX_SLOTS_PER_PUBLISH: u32 = 20;
+
+fn store_slot(env: &Env, slot: &OfficeHourSlot) {
+    let key: DataKey = DataKey::OfficeHourSlot(slot.id);
+    env.storage().persistent().set(&key, slot);
+    bump_persistent(env, &key);
+}
+
+fn require_slot(env: &Env, slot_id: u32) -> OfficeHourSlot {
+    get_slot(env, slot_id).unwrap_or_else(|| handle_error(env, Error::SlotNotFound))
+}
+
+/// Returns an office-hour slot, if it exists.
+pub fn get_slot(env: &Env, slot_id: u32) -> Option<OfficeHourSlot> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OfficeHourSlot(slot_id))
+}
+
+/// Publishes office-hour slots for a course, given as `(start, end)`
+/// timestamps.
+///
+/// The instructor must be the course creator, one of its managers or an
+/// admin. Every slot must end after it starts and start in the future.
+pub fn publish_slots(
+    env: &Env,
+    instructor: Address,
+    course_id: String,
+    slots: Vec<(u64, u64)>,
+) -> Vec<OfficeHourSlot> {
+    instructor.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId);
+    }
+    require_enrollment_authority(env, &instructor, &course_id);
+    if slots.is_empty() || slots.len() > MAX_SLOTS_PER_PUBLISH {
+        handle_error(env, Error::InvalidSlot);
+    }
+    let now: u64 = env.ledger().timestamp();
+    for (start, end) in slots.iter() {
+        if start <= now || end <= start {
+            handle_error(env, Error::InvalidSlot);
+        }
+    }
+
+    let mut id: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SlotCount)
+        .unwrap_or(0);
+    let mut published: Vec<OfficeHourSlot> = Vec::new(env);
+    for (start, end) in slots.iter() {
+        id += 1;
+        let slot: OfficeHourSlot = OfficeHourSlot {
+            id,
+            course_id: course_id.clone(),
+            instructor: instructor.clone(),
+            start,
+            end,
+            booked_by: None,
+        };
+        store_slot(env, &slot);
+        events::slot_published(env, &slot);
+        published.push_back(slot);
+    }
+    env.storage().persistent().set(&DataKey::SlotCount, &id);
+
+    published
+}
+
+/// Books a free slot that hasn't started for a student with access to its
+/// course.
+pub fn book_slot(env: &Env, student: Address, slot_id: u32) -> OfficeHourSlot {
+    student.require_auth();
+
+    let mut slot: OfficeHourSlot = require_slot(env, slot_id);
+    if slot.booked_by.is_some() || slot.start <= env.ledger().timestamp() {
+        handle_error(env, Error::SlotUnavailable);
+    }
+    if !has_access(env, slot.course_id.clone(), student.clone()) {
+        handle_error(env, Error::UserNoAccessCourse);
+    }
+
+    slot.booked_by = Some(student.clone());
+    store_slot(env, &slot);
+    events::slot_booked(env, &slot, &student);
+
+    slot
+}
+
+/// Frees a booked slot that hasn't started. The student who booked it or
+/// the slot's instructor can cancel.
+pub fn cancel_booking(env: &Env, caller: Address, slot_id: u32) -> OfficeHourSlot {
+    caller.require_auth();
+
+    let mut slot: OfficeHourSlot = require_slot(env, slot_id);
+    let Some(student) = slot.booked_by.clone() else {
+        handle_error(env, Error::SlotNotBooked)
+    };
+    if caller != student && caller != slot.instructor {
+        handle_error(env, Error::Unauthorized);
+    }
+    if slot.start <= env.ledger().timestamp() {
+        handle_error(env, Error::SlotUnavailable);
+    }
+
+    slot.booked_by = None;
+    store_slot(env, &slot);
+    events::slot_booking_cancelled(env, &slot, &student);
+
+    slot
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{
+        contract, contractimpl,
+        testutils::{Address as _, Ledger},
+        vec, Address, Env, String,
+    };
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+        pub fn resolve_identity(_env: Env, address: Address) -> Address {
+            address
+        }
+    }
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn set_creator(env: Env, creator: Address) {
+            env.storage().instance().set(&"creator", &creator);
+        }
+        pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+            env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+        }
+        pub fn record_enrollment(_env: Env, _reporter: Address, _course_id: String) -> u32 {
+            0
+        }
+    }
+
+    #[test]
+    fn test_publish_book_and_cancel_slots() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        let course_registry_id: Address = env.register(CourseRegistry, ());
+        let client = CourseAccessContractClient::new(&env, &env.register(CourseAccessContract, ()));
+        client.initialize(&Address::generate(&env), &user_mgmt_id, &course_registry_id);
+        let instructor: Address = Address::generate(&env);
+        CourseRegistryClient::new(&env, &course_registry_id).set_creator(&instructor);
+        let course_id: String = String::from_str(&env, "course_1");
+        let student: Address = Address::generate(&env);
+        let other: Address = Address::generate(&env);
+        client.grant_access(&instructor, &course_id, &student);
+        client.grant_access(&instructor, &course_id, &other);
+
+        // Only instructors publish, and only future slots
+        let slots = vec![&env, (2_000_u64, 3_800_u64), (4_000_u64, 5_800_u64)];
+        assert!(client
+            .try_publish_slots(&student, &course_id, &slots)
+            .is_err());
+        assert!(client
+            .try_publish_slots(&instructor, &course_id, &vec![&env, (500_u64, 900_u64)])
+            .is_err());
+        let published = client.publish_slots(&instructor, &course_id, &slots);
+        assert_eq!(published.len(), 2);
+        let slot_id: u32 = published.get(0).unwrap().id;
+
+        // One student per slot, and only students of the course
+        assert!(client
+            .try_book_slot(&Address::generate(&env), &slot_id)
+            .is_err());
+        let booked = client.book_slot(&student, &slot_id);
+        assert_eq!(booked.booked_by, Some(student.clone()));
+        assert!(client.try_book_slot(&other, &slot_id).is_err());
+
+        // Cancelling frees the slot for someone else
+        assert!(client.try_cancel_booking(&other, &slot_id).is_err());
+        client.cancel_booking(&student, &slot_id);
+        assert_eq!(client.get_slot(&slot_id).unwrap().booked_by, None);
+        client.book_slot(&other, &slot_id);
+
+        // Started slots can't be booked or cancelled
+        env.ledger().with_mut(|ledger| ledger.timestamp = 2_000);
+        assert!(client.try_cancel_booking(&other, &slot_id).is_err());
+        assert!(client
+            .try_book_slot(&student, &published.get(0).unwrap().id)
+            .is_err());
+    }
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Seat-based access for organization members.
+//!
+//! Organizations, their members and their purchased seats live in the user
+//! management contract; this contract counts the seats in use and frees a
+//! seat when the access it paid for is revoked.
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::clients::{user_management, UserManagementClient};
+use crate::error::{handle_error, Error};
+use crate::functions::grant_access::grant_with_record;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{DataKey, EnrollmentSource};
+
+/// Returns how many of an organization's seats on a course are in use.
+pub fn get_org_seats_used(env: &Env, org_id: u32, course_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgSeatsUsed(org_id, course_id))
+        .unwrap_or(0)
+}
+
+fn set_org_seats_used(env: &Env, org_id: u32, course_id: &String, used: u32) {
+    let key: DataKey = DataKey::OrgSeatsUsed(org_id, course_id.clone());
+    env.storage().persistent().set(&key, &used);
+    bump_persistent(env, &key);
+}
+
+/// Grants a member of an organization access to a course using one of the
+/// organization's purchased seats. Org admins only.
+pub fn grant_org_access(env: Env, org_admin: Address, org_id: u32, course_id: String, member: Address) {
+    org_admin.require_auth();
+
+    let user_management: UserManagementClient = user_management(&env);
+    if !user_management.is_org_admin(&org_id, &org_admin) {
+        handle_error(&env, Error::Unauthorized)
+    }
+    if !user_management.is_org_member(&org_id, &member) {
+        handle_error(&env, Error::NotOrgMember)
+    }
+
+    let seats: u32 = user_management.get_org_seats(&org_id, &course_id);
+    let used: u32 = get_org_seats_used(&env, org_id, course_id.clone());
+    if used >= seats {
+        handle_error(&env, Error::NoSeatsAvailable)
+    }
+
+    grant_with_record(
+        env.clone(),
+        course_id.clone(),
+        member.clone(),
+        Some(org_admin),
+        EnrollmentSource::Organization,
+    );
+
+    set_org_seats_used(&env, org_id, &course_id, used + 1);
+    let key: DataKey = DataKey::OrgEnrollment(course_id, member);
+    env.storage().persistent().set(&key, &org_id);
+    bump_persistent(&env, &key);
+}
+
+/// Gives the seat occupied by `user` back to their organization, if any.
+///
+/// Called when the user's access to the course is revoked.
+pub fn release_org_seat(env: &Env, course_id: &String, user: &Address) {
+    let key: DataKey = DataKey::OrgEnrollment(course_id.clone(), user.clone());
+    if let Some(org_id) = env.storage().persistent().get::<_, u32>(&key) {
+        env.storage().persistent().remove(&key);
+        let used: u32 = get_org_seats_used(env, org_id, course_id.clone());
+        set_org_seats_used(env, org_id, course_id, used.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{EnrollmentRecord, EnrollmentSource};
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(env: Env, who: Address) -> bool {
+            Self::is_org_admin(env, 1, who)
+        }
+        pub fn set_org(env: Env, org_admin: Address, member: Address, seats: u32) {
+            env.storage().instance().set(&"admin", &org_admin);
+            env.storage().instance().set(&"member", &member);
+            env.storage().instance().set(&"seats", &seats);
+        }
+        pub fn is_org_admin(env: Env, _org_id: u32, who: Address) -> bool {
+            env.storage().instance().get::<_, Address>(&"admin") == Some(who)
+        }
+        pub fn is_org_member(env: Env, org_id: u32, who: Address) -> bool {
+            env.storage().instance().get::<_, Address>(&"member") == Some(who.clone())
+                || Self::is_org_admin(env, org_id, who)
+        }
+        pub fn get_org_seats(env: Env, _org_id: u32, _course_id: String) -> u32 {
+            env.storage().instance().get(&"seats").unwrap_or(0)
+        }
+    }
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn record_enrollment(_env: Env, _reporter: Address, _course_id: String) -> u32 {
+            0
+        }
+    }
+
+    fn setup(env: &Env, seats: u32) -> (CourseAccessContractClient<'_>, Address, Address, String) {
+        env.mock_all_auths();
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        let course_registry_id: Address = env.register(CourseRegistry, ());
+        let contract_id: Address = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &user_mgmt_id, &course_registry_id);
+
+        let org_admin: Address = Address::generate(env);
+        let member: Address = Address::generate(env);
+        UserManagementClient::new(env, &user_mgmt_id).set_org(&org_admin, &member, &seats);
+        (client, org_admin, member, String::from_str(env, "course_1"))
+    }
+
+    #[test]
+    fn test_grant_org_access_uses_a_seat() {
+        let env: Env = Env::default();
+        let (client, org_admin, member, course_id) = setup(&env, 1);
+
+        client.grant_org_access(&org_admin, &1, &course_id, &member);
+        assert_eq!(client.get_org_seats_used(&1, &course_id), 1);
+        let record: EnrollmentRecord = client.get_enrollment(&course_id, &member).unwrap();
+        assert_eq!(record.source, EnrollmentSource::Organization);
+
+        // Revoking the access gives the seat back
+        client.revoke_access(&org_admin, &course_id, &member, &None, &None);
+        assert_eq!(client.get_org_seats_used(&1, &course_id), 0);
+        client.grant_org_access(&org_admin, &1, &course_id, &org_admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #21)")]
+    fn test_grant_org_access_is_limited_by_seats() {
+        let env: Env = Env::default();
+        let (client, org_admin, member, course_id) = setup(&env, 1);
+
+        client.grant_org_access(&org_admin, &1, &course_id, &member);
+        client.grant_org_access(&org_admin, &1, &course_id, &org_admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #20)")]
+    fn test_grant_org_access_requires_membership() {
+        let env: Env = Env::default();
+        let (client, org_admin, _member, course_id) = setup(&env, 5);
+
+        client.grant_org_access(&org_admin, &1, &course_id, &Address::generate(&env));
+    }
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::clients::{course_registry, progress};
+use crate::error::{handle_error, Error};
+use crate::schema::DataKey;
+
+/// Event symbol for prerequisite policy changes
+const ENFORCE_PREREQ_EVENT: Symbol = symbol_short!("enfPrereq");
+
+/// Enables or disables prerequisite checks when granting access to a course.
+/// Only the course creator can change the policy.
+pub fn set_enforce_prerequisites(env: Env, creator: Address, course_id: String, enabled: bool) {
+    creator.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId);
+    }
+
+    if !course_registry(&env).is_course_creator(&course_id, &creator) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::EnforcePrerequisites(course_id.clone()), &enabled);
+
+    env.events()
+        .publish((ENFORCE_PREREQ_EVENT, course_id), (creator, enabled));
+}
+
+/// Returns whether prerequisite checks are enabled for a course.
+pub fn is_enforcing_prerequisites(env: &Env, course_id: &String) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EnforcePrerequisites(course_id.clone()))
+        .unwrap_or(false)
+}
+
+/// Returns the direct prerequisites of a course that the user has not completed yet.
+///
+/// Prerequisites come from the course registry, completions from the progress contract.
+pub fn get_missing_prerequisites(env: Env, user: Address, course_id: String) -> Vec<String> {
+    let prerequisites: Vec<String> = course_registry(&env).get_prerequisites(&course_id, &false);
+
+    let mut missing: Vec<String> = Vec::new(&env);
+    if prerequisites.is_empty() {
+        return missing;
+    }
+
+    let progress = progress(&env);
+    for prerequisite_id in prerequisites.iter() {
+        if !progress.is_course_completed(&user, &prerequisite_id) {
+            missing.push_back(prerequisite_id);
+        }
+    }
+
+    missing
+}
+
+/// Panics with `Error::PrerequisitesNotMet` if the course enforces prerequisites
+/// and the user has not completed all of them.
+///
+/// The error doesn't name the missing courses and an event would be rolled
+/// back with the failed call, so callers list them with
+/// `get_missing_prerequisites`.
+pub fn require_prerequisites_met(env: &Env, course_id: &String, user: &Address) {
+    if !is_enforcing_prerequisites(env, course_id) {
+        return;
+    }
+
+    let missing: Vec<String> =
+        get_missing_prerequisites(env.clone(), user.clone(), course_id.clone());
+    if !missing.is_empty() {
+        handle_error(env, Error::PrerequisitesNotMet)
+    }
+}
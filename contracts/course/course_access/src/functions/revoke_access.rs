@@ -1,12 +1,18 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, String, Symbol, symbol_short};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
-use crate::schema::{DataKey, UserCourses, CourseUsers};
+use crate::clients::user_management;
+use crate::schema::{DataKey, RevocationReason, RevocationRecord, UserCourses};
+use crate::events;
+use crate::functions::course_managers::require_enrollment_authority;
+use crate::functions::course_users::remove_course_user;
+use crate::functions::org_access::release_org_seat;
+use crate::functions::trusted_contracts::is_trusted_contract;
+use crate::functions::ttl::bump_persistent;
 use crate::error::{Error, handle_error};
 
-const COURSE_ACCESS_REVOKE_EVENT: Symbol = symbol_short!("crsAcRvk");
 
 /// Revokes a user's access to a specific course and updates all related storage entries.
 ///
@@ -17,14 +23,31 @@ const COURSE_ACCESS_REVOKE_EVENT: Symbol = symbol_short!("crsAcRvk");
 /// # Arguments
 ///
 /// * `env` - The Soroban environment for accessing storage and publishing events.
+/// * `caller` - The course creator, a course manager, a trusted contract or an admin.
 /// * `course_id` - The unique identifier of the course from which access is being revoked.
 /// * `user` - The address of the user whose access is being revoked.
+/// * `reason` - Optional reason code, stored in the revocation history.
+/// * `note_hash` - Optional hash of an off-chain note, stored in the revocation history.
 ///
 /// # Returns
 ///
 /// * `bool` - Returns `true` if the access was successfully revoked (entry existed and was removed),
 ///   or `false` if no access entry was found for the user-course combination.
-pub fn course_access_revoke_access(env: Env, course_id: String, user: Address) -> bool {
+///
+/// # Panics
+///
+/// * If the caller may not manage enrollments of the course (`Unauthorized`)
+pub fn course_access_revoke_access(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    reason: Option<RevocationReason>,
+    note_hash: Option<BytesN<32>>,
+) -> bool {
+    caller.require_auth();
+    require_enrollment_authority(&env, &caller, &course_id);
+
     // Validate input parameters
     if course_id.is_empty() {
         handle_error(&env, Error::EmptyCourseId);
@@ -41,6 +64,9 @@ pub fn course_access_revoke_access(env: Env, course_id: String, user: Address) -
     if env.storage().persistent().has(&key) {
         // Remove the CourseAccess entry
         env.storage().persistent().remove(&key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Enrollment(course_id.clone(), user.clone()));
 
         // Update UserCourses
         let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
@@ -48,24 +74,65 @@ pub fn course_access_revoke_access(env: Env, course_id: String, user: Address) -
             if let Some(index) = user_courses.courses.iter().position(|c| c == course_id) {
                 user_courses.courses.remove(index as u32);
                 env.storage().persistent().set(&user_courses_key, &user_courses);
-                env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
+                bump_persistent(&env, &user_courses_key);
             }
         }
 
-        // Update CourseUsers
-        let course_users_key: DataKey = DataKey::CourseUsers(course_id.clone());
-        if let Some(mut course_users) = env.storage().persistent().get::<DataKey, CourseUsers>(&course_users_key) {
-            if let Some(index) = course_users.users.iter().position(|u| u == user) {
-                course_users.users.remove(index as u32);
-                env.storage().persistent().set(&course_users_key, &course_users);
-                env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
-            }
-        }
-    env.events()
-        .publish((COURSE_ACCESS_REVOKE_EVENT,), (course_id, user));
+        // Update the course's user index
+        remove_course_user(&env, &course_id, &user);
+        release_org_seat(&env, &course_id, &user);
+        record_revocation(&env, &course_id, &user, reason, note_hash);
+
+        events::access_revoked(&env, &course_id, &user);
 
         true
     } else {
         false
     }
 }
+
+/// Appends a revocation to the user's history for the course.
+fn record_revocation(
+    env: &Env,
+    course_id: &String,
+    user: &Address,
+    reason: Option<RevocationReason>,
+    note_hash: Option<BytesN<32>>,
+) {
+    let key: DataKey = DataKey::RevocationHistory(course_id.clone(), user.clone());
+    let mut history: Vec<RevocationRecord> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    history.push_back(RevocationRecord {
+        revoked_at: env.ledger().timestamp(),
+        reason: reason.unwrap_or(RevocationReason::Unspecified),
+        note_hash,
+    });
+    env.storage().persistent().set(&key, &history);
+    bump_persistent(env, &key);
+}
+
+/// Returns every revocation of a user's access to a course, oldest first.
+///
+/// Only admins of the user management contract and trusted contracts can
+/// read the history; the disputes contract uses it to let students whose
+/// access was revoked open a dispute.
+pub fn get_revocation_history(
+    env: Env,
+    admin: Address,
+    course_id: String,
+    user: Address,
+) -> Vec<RevocationRecord> {
+    admin.require_auth();
+
+    if !(is_trusted_contract(&env, &admin) || user_management(&env).is_admin(&admin)) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .get(&DataKey::RevocationHistory(course_id, user))
+        .unwrap_or(Vec::new(&env))
+}
@@ -1,10 +1,12 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, IntoVal, String, Symbol, Vec};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::events;
 use crate::error::{handle_error, Error};
-use crate::schema::{DataKey, KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
+use crate::functions::course_managers::require_enrollment_authority;
+use crate::schema::DataKey;
 
 /// Storage key symbol for user data
 const USER_KEY: Symbol = symbol_short!("user");
@@ -12,10 +14,6 @@ const USER_KEY: Symbol = symbol_short!("user");
 /// Storage key symbol for courses data
 const COURSES_KEY: Symbol = symbol_short!("courses");
 
-/// Event symbol for revoke all access operations
-const REVOKE_ALL_EVENT: Symbol = symbol_short!("revokeAll");
-
-
 pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
     caller.require_auth();
 
@@ -29,34 +27,8 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
         handle_error(&env, Error::InvalidCourseId);
     }
 
-    // Resolve admin via cross-contract if configured
-    let user_mgmt_addr: Address = env
-        .storage()
-        .instance()
-        .get(&(KEY_USER_MGMT_ADDR,))
-        .expect("user_mgmt_addr not configured; call initialize/set_config");
-    let is_admin: bool = env.invoke_contract(
-        &user_mgmt_addr,
-        &Symbol::new(&env, "is_admin"),
-        (caller.clone(),).into_val(&env),
-    );
-
-    // Resolve creator via cross-contract if configured
-    let course_registry_addr: Address = env
-        .storage()
-        .instance()
-        .get(&(KEY_COURSE_REG_ADDR,))
-        .expect("course_registry_addr not configured; call initialize/set_config");
-    let is_creator: bool = env.invoke_contract(
-        &course_registry_addr,
-        &Symbol::new(&env, "is_course_creator"),
-        (course_id.clone(), caller.clone()).into_val(&env),
-    );
-
-    // Authorization: only admin or course creator
-    if !(is_admin || is_creator) {
-        handle_error(&env, Error::Unauthorized)
-    }
+    // Authorization: admin, course creator or course manager
+    require_enrollment_authority(&env, &caller, &course_id);
 
     // Fetch all users with access to this course
     let course_key: (Symbol, String) = (COURSES_KEY, course_id.clone());
@@ -70,8 +42,7 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
 
     let count: u32 = affected_users.len();
     if count == 0 {
-        env.events()
-            .publish((REVOKE_ALL_EVENT, course_id.clone()), count);
+        events::access_cleared(&env, &course_id, count);
         return 0;
     }
 
@@ -83,6 +54,9 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
             if env.storage().persistent().has(&access_key) {
                 env.storage().persistent().remove(&access_key);
             }
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Enrollment(course_id.clone(), user.clone()));
 
             let user_key: (Symbol, String) = (USER_KEY, user.to_string());
             if let Some(mut uc) = env
@@ -117,8 +91,7 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
         env.storage().persistent().set(&course_key, &cu);
     }
 
-    env.events()
-        .publish((REVOKE_ALL_EVENT, course_id.clone()), count);
+    events::access_cleared(&env, &course_id, count);
 
     count
 }
@@ -3,44 +3,79 @@
 
 use soroban_sdk::{Address, Env, String, Symbol, symbol_short};
 
+use crate::clients::{user_management, UserManagementClient};
 use crate::error::{handle_error, Error};
-use crate::schema::{DataKey, UserProfile};
+use crate::schema::{ProfileUpdateParams, UserProfile};
+use validation::{validate_country_code, validate_email};
 
 const SAVE_USER_PROFILE_EVENT: Symbol = symbol_short!("saveUsPrl");
 
 
+/// Saves a user's profile by forwarding it to the user_management contract.
+///
+/// Creates the profile if the user has none yet, otherwise updates the
+/// provided fields in place.
+///
+/// # Panics
+///
+/// * If the user has not authorized the call
+/// * If name, email, or country are empty or malformed
+/// * If the user management contract address is not configured
 pub fn save_user_profile(
     env: Env,
+    user: Address,
     name: String,
     email: String,
     profession: Option<String>,
     goals: Option<String>,
     country: String,
-    user: Address,
 ) {
+    user.require_auth();
+
     // Validate required fields
     if name.is_empty() {
         handle_error(&env, Error::NameRequired)
     }
-    // TODO: Implement full email validation according to RFC 5322 standard
     if email.is_empty() {
         handle_error(&env, Error::EmailRequired)
     }
+    if !validate_email(&email) {
+        handle_error(&env, Error::InvalidEmailFormat)
+    }
     if country.is_empty() {
         handle_error(&env, Error::CountryRequired)
     }
+    if !validate_country_code(&country) {
+        handle_error(&env, Error::InvalidCountryCode)
+    }
+
+    let user_management: UserManagementClient = user_management(&env);
 
-    let profile: UserProfile = UserProfile {
-        name: name.clone(),
-        email: email.clone(),
-        profession: profession.clone(),
-        goals: goals.clone(),
-        country: country.clone(),
-    };
-
-    env.storage()
-        .persistent()
-        .set(&DataKey::UserProfile(user.clone()), &profile);
+    // A user can always read their own profile, so a failed lookup means it does not exist yet
+    let existing = user_management.try_get_user_by_id(&user, &user);
+
+    if let Ok(Ok(_)) = existing {
+        let updates: ProfileUpdateParams = ProfileUpdateParams {
+            full_name: Some(name.clone()),
+            contact_email: Some(email.clone()),
+            profession: profession.clone(),
+            country: Some(country.clone()),
+            purpose: goals.clone(),
+            profile_picture_url: None,
+            social_links: None,
+        };
+        user_management.edit_user_profile(&user, &user, &updates);
+    } else {
+        let profile: UserProfile = UserProfile {
+            full_name: name.clone(),
+            contact_email: email.clone(),
+            profession: profession.clone(),
+            country: Some(country.clone()),
+            purpose: goals.clone(),
+            profile_picture_url: None,
+        };
+        user_management.create_user_profile(&user, &profile);
+    }
 
     env.events()
         .publish((SAVE_USER_PROFILE_EVENT,), (name, email, profession, goals, country, user));
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::clients::course_registry;
+use crate::error::{handle_error, Error};
+use crate::functions::course_users::course_user_count;
+use crate::functions::grant_access::grant_with_record;
+use crate::schema::{DataKey, EnrollmentSource};
+
+/// Event symbol for course capacity changes
+const SET_CAPACITY_EVENT: Symbol = symbol_short!("setCap");
+
+/// Sets the maximum number of students of a course, `None` for no limit.
+/// Only the course creator can change the capacity.
+pub fn set_course_capacity(env: Env, creator: Address, course_id: String, max_students: Option<u32>) {
+    creator.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId);
+    }
+
+    if !course_registry(&env).is_course_creator(&course_id, &creator) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let key: DataKey = DataKey::CourseCapacity(course_id.clone());
+    match max_students {
+        Some(max) => env.storage().persistent().set(&key, &max),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_CAPACITY_EVENT, course_id), (creator, max_students));
+}
+
+/// Returns the maximum number of students of a course, if limited.
+pub fn get_course_capacity(env: &Env, course_id: String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseCapacity(course_id))
+}
+
+/// Lets a user enroll themselves in a free, published course that isn't full.
+///
+/// Whether the course is free, published and not archived is asked to the
+/// course registry. Holders of a course's gate token don't enroll: their
+/// access is checked against their balance by `has_access`, so it ends with
+/// the balance instead of becoming a lasting grant.
+/// The access itself is granted exactly like `grant_access`, prerequisites included.
+pub fn self_enroll(env: Env, user: Address, course_id: String) {
+    user.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId);
+    }
+
+    if !course_registry(&env).is_self_enrollable(&course_id) {
+        handle_error(&env, Error::CourseNotOpenForEnrollment)
+    }
+
+    if let Some(max_students) = get_course_capacity(&env, course_id.clone()) {
+        let enrolled: u32 = course_user_count(&env, &course_id);
+        if enrolled >= max_students {
+            handle_error(&env, Error::CourseFull)
+        }
+    }
+
+    grant_with_record(env, course_id, user.clone(), Some(user), EnrollmentSource::SelfEnroll);
+}
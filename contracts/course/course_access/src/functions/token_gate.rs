@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Token-gated access.
+//!
+//! A course creator can name a token (or NFT) contract and a minimum balance;
+//! holders of that balance have access to the course without a grant. The
+//! balance is checked whenever access is, so the access follows the token
+//! and no lasting grant is ever stored for it.
+
+use soroban_sdk::{symbol_short, token, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::course_managers::is_course_creator;
+use crate::schema::{DataKey, TokenGate};
+
+/// Event symbols for token gate changes
+const SET_TOKEN_GATE_EVENT: Symbol = symbol_short!("setGate");
+const CLEAR_TOKEN_GATE_EVENT: Symbol = symbol_short!("clrGate");
+
+fn require_course_creator(env: &Env, creator: &Address, course_id: &String) {
+    creator.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId);
+    }
+    if !is_course_creator(env, course_id, creator) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Gives holders of at least `min_balance` of `token_contract` access to the
+/// course. Only the course creator can set the gate; setting it again
+/// replaces the previous one.
+pub fn set_token_gate(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    token_contract: Address,
+    min_balance: i128,
+) -> TokenGate {
+    require_course_creator(&env, &creator, &course_id);
+    if min_balance <= 0 {
+        handle_error(&env, Error::InvalidTokenGate)
+    }
+
+    let gate: TokenGate = TokenGate {
+        token: token_contract,
+        min_balance,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenGate(course_id.clone()), &gate);
+
+    env.events()
+        .publish((SET_TOKEN_GATE_EVENT, course_id), (creator, gate.clone()));
+    gate
+}
+
+/// Removes the token gate of a course. Holders lose their access unless they
+/// hold a grant of their own.
+pub fn clear_token_gate(env: Env, creator: Address, course_id: String) {
+    require_course_creator(&env, &creator, &course_id);
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TokenGate(course_id.clone()));
+
+    env.events()
+        .publish((CLEAR_TOKEN_GATE_EVENT, course_id), creator);
+}
+
+/// Returns the token gate of a course, if any.
+pub fn get_token_gate(env: &Env, course_id: String) -> Option<TokenGate> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenGate(course_id))
+}
+
+/// Returns whether `user` holds enough of the course's gate token, `false`
+/// if the course has no gate.
+pub fn holds_gate_token(env: &Env, course_id: String, user: Address) -> bool {
+    match get_token_gate(env, course_id) {
+        Some(gate) => token::TokenClient::new(env, &gate.token).balance(&user) >= gate.min_balance,
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, token, Address, Env, String,
+    };
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+    }
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn set_creator(env: Env, creator: Address) {
+            env.storage().instance().set(&"creator", &creator);
+        }
+        pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+            env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+        }
+        pub fn is_self_enrollable(_env: Env, _course_id: String) -> bool {
+            false
+        }
+        pub fn record_enrollment(_env: Env, _reporter: Address, _course_id: String) -> u32 {
+            0
+        }
+    }
+
+    struct Setup<'a> {
+        client: CourseAccessContractClient<'a>,
+        token: token::StellarAssetClient<'a>,
+        creator: Address,
+        course_id: String,
+    }
+
+    fn setup(env: &Env) -> Setup<'_> {
+        env.mock_all_auths();
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        let course_registry_id: Address = env.register(CourseRegistry, ());
+        let contract_id: Address = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &user_mgmt_id, &course_registry_id);
+
+        let creator: Address = Address::generate(env);
+        CourseRegistryClient::new(env, &course_registry_id).set_creator(&creator);
+        let token_addr: Address = env
+            .register_stellar_asset_contract_v2(Address::generate(env))
+            .address();
+        Setup {
+            client,
+            token: token::StellarAssetClient::new(env, &token_addr),
+            creator,
+            course_id: String::from_str(env, "course_1"),
+        }
+    }
+
+    #[test]
+    fn test_token_holders_have_access_while_they_hold_the_token() {
+        let env: Env = Env::default();
+        let s = setup(&env);
+        let holder: Address = Address::generate(&env);
+        let outsider: Address = Address::generate(&env);
+        s.token.mint(&holder, &5);
+        s.token.mint(&outsider, &4);
+
+        s.client.set_token_gate(&s.creator, &s.course_id, &s.token.address, &5);
+        assert!(s.client.has_access(&s.course_id, &holder));
+        assert!(!s.client.has_access(&s.course_id, &outsider));
+
+        // Passing the token on passes the access on, nothing stays behind
+        token::TokenClient::new(&env, &s.token.address).transfer(&holder, &outsider, &1);
+        assert!(!s.client.has_access(&s.course_id, &holder));
+        assert!(s.client.has_access(&s.course_id, &outsider));
+        assert_eq!(s.client.get_enrollment(&s.course_id, &outsider), None);
+
+        s.client.clear_token_gate(&s.creator, &s.course_id);
+        assert_eq!(s.client.get_token_gate(&s.course_id), None);
+        assert!(!s.client.has_access(&s.course_id, &outsider));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #18)")]
+    fn test_holders_cannot_self_enroll_in_a_paid_course() {
+        let env: Env = Env::default();
+        let s = setup(&env);
+        let holder: Address = Address::generate(&env);
+        s.token.mint(&holder, &1);
+
+        s.client.set_token_gate(&s.creator, &s.course_id, &s.token.address, &1);
+        s.client.self_enroll(&holder, &s.course_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #3)")]
+    fn test_only_the_creator_sets_a_token_gate() {
+        let env: Env = Env::default();
+        let s = setup(&env);
+        let student: Address = Address::generate(&env);
+
+        s.client.set_token_gate(&student, &s.course_id, &s.token.address, &1);
+    }
+}
@@ -1,12 +1,14 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{Address, Env, String};
 
-use crate::schema::{CourseAccess, DataKey};
+use crate::schema::{CourseAccess, DataKey, EnrollmentSource};
+use crate::functions::grant_access::store_enrollment;
+use crate::events;
+use crate::functions::ttl::bump_persistent;
 use crate::error::{Error, handle_error};
 
-const COURSE_TRANSFER_EVENT: Symbol = symbol_short!("transfer");
 
 // Transfer course access from one user to another
 pub fn transfer_course_access(env: Env, course_id: String, from: Address, to: Address) {
@@ -47,15 +49,22 @@ pub fn transfer_course_access(env: Env, course_id: String, from: Address, to: Ad
 
     // Remove the old user's access
     env.storage().persistent().remove(&key);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Enrollment(course_id.clone(), from.clone()));
+    store_enrollment(&env, &course_id, &to, Some(from.clone()), EnrollmentSource::Transfer);
+
+    // An organization seat follows the access to the new user
+    let org_key: DataKey = DataKey::OrgEnrollment(course_id.clone(), from.clone());
+    if let Some(org_id) = env.storage().persistent().get::<_, u32>(&org_key) {
+        env.storage().persistent().remove(&org_key);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OrgEnrollment(course_id.clone(), to.clone()), &org_id);
+    }
 
     // Extend the TTL for the new user's storage entry
-    env.storage().persistent().extend_ttl(
-        &DataKey::CourseAccess(course_id.clone(), to.clone()),
-        100,
-        1000,
-    );
+    bump_persistent(&env, &DataKey::CourseAccess(course_id.clone(), to.clone()));
 
-    // emit an event
-    env.events()
-        .publish((COURSE_TRANSFER_EVENT,), (course_id, from, to));
+    events::access_transferred(&env, &course_id, &from, &to);
 }
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Allowlist of contracts trusted to call privileged hooks.
+//!
+//! A trusted contract, such as payments, can grant access with
+//! `grant_access_with_source` for any course without being an admin of the
+//! user management contract. The list lives in instance storage, so it is
+//! loaded with the contract and shares its TTL.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::is_owner;
+use crate::schema::DataKey;
+
+/// Returns the contracts trusted to call privileged hooks.
+pub fn get_trusted_contracts(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TrustedContracts)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns whether `contract` is on the trusted allowlist.
+pub fn is_trusted_contract(env: &Env, contract: &Address) -> bool {
+    get_trusted_contracts(env).contains(contract)
+}
+
+/// Adds a contract to, or removes it from, the trusted allowlist. Owner only.
+pub fn set_trusted_contract(env: &Env, owner: Address, contract: Address, trusted: bool) {
+    owner.require_auth();
+    if !is_owner(env, &owner) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let mut contracts: Vec<Address> = get_trusted_contracts(env);
+    match (contracts.first_index_of(&contract), trusted) {
+        (None, true) => contracts.push_back(contract.clone()),
+        (Some(index), false) => {
+            contracts.remove(index);
+        }
+        _ => return,
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::TrustedContracts, &contracts);
+
+    events::trusted_contract_set(env, &contract, trusted, &owner);
+}
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, String, Symbol, Val};
+
+use crate::error::{handle_error, Error};
+use crate::functions::config::is_owner;
+use crate::functions::course_users::{all_course_users, bump_course_users};
+use crate::schema::{
+    DataKey, TtlConfig, UserCourses, DEFAULT_TTL_EXTEND_TO, DEFAULT_TTL_THRESHOLD,
+};
+
+const SET_TTL_CONFIG_EVENT: Symbol = symbol_short!("setTtlCfg");
+
+/// Returns the TTL thresholds, falling back to the defaults.
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        })
+}
+
+/// Updates the TTL thresholds. Owner only.
+pub fn set_ttl_config(env: Env, caller: Address, threshold: u32, extend_to: u32) {
+    caller.require_auth();
+
+    if !is_owner(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if threshold == 0 || threshold >= extend_to || extend_to > env.storage().max_ttl() {
+        handle_error(&env, Error::InvalidTtlConfig)
+    }
+
+    let config: TtlConfig = TtlConfig { threshold, extend_to };
+    env.storage().instance().set(&DataKey::TtlConfig, &config);
+
+    env.events()
+        .publish((SET_TTL_CONFIG_EVENT,), (caller, threshold, extend_to));
+}
+
+/// Extends the TTL of a persistent entry if it exists.
+pub fn bump_persistent<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    if env.storage().persistent().has(key) {
+        let config: TtlConfig = get_ttl_config(env);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, config.threshold, config.extend_to);
+    }
+}
+
+/// Extends the TTL of the contract instance, which holds the configuration.
+pub fn bump_instance(env: &Env) {
+    let config: TtlConfig = get_ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Extends the TTL of a course's user index and of every access entry in it.
+pub fn extend_course_ttl(env: Env, course_id: String) {
+    for user in all_course_users(&env, &course_id).iter() {
+        bump_persistent(&env, &DataKey::CourseAccess(course_id.clone(), user.clone()));
+        bump_persistent(&env, &DataKey::CourseUserSlot(course_id.clone(), user));
+    }
+    bump_course_users(&env, &course_id);
+    bump_persistent(&env, &DataKey::EnforcePrerequisites(course_id));
+    bump_instance(&env);
+}
+
+/// Extends the TTL of a user's course list and of every access entry in it.
+pub fn extend_profile_ttl(env: Env, user: Address) {
+    let key: DataKey = DataKey::UserCourses(user.clone());
+    if let Some(user_courses) = env.storage().persistent().get::<_, UserCourses>(&key) {
+        for course_id in user_courses.courses.iter() {
+            bump_persistent(&env, &DataKey::CourseAccess(course_id, user.clone()));
+        }
+    }
+    bump_persistent(&env, &key);
+    bump_instance(&env);
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Scholarship vouchers.
+//!
+//! The creator, managers and admins of a course issue vouchers granting free
+//! access, either to a known student or to whoever presents a code. Issuance
+//! and redemption are counted per course.
+
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::course_managers::require_enrollment_authority;
+use crate::functions::grant_access::grant_with_record;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{DataKey, EnrollmentSource, Voucher, VoucherClaim, VoucherStats, VoucherTarget};
+
+fn store_voucher(env: &Env, voucher: &Voucher) {
+    let key: DataKey = DataKey::Voucher(voucher.id);
+    env.storage().persistent().set(&key, voucher);
+    bump_persistent(env, &key);
+}
+
+fn update_stats(env: &Env, course_id: &String, update: impl FnOnce(&mut VoucherStats)) {
+    let key: DataKey = DataKey::VoucherStats(course_id.clone());
+    let mut stats: VoucherStats = get_voucher_stats(env, course_id.clone());
+    update(&mut stats);
+    env.storage().persistent().set(&key, &stats);
+    bump_persistent(env, &key);
+}
+
+/// Issues a voucher for free access to a course, redeemable until `expires_at`.
+///
+/// The issuer must be the course creator, one of its managers or an admin.
+pub fn issue_voucher(
+    env: Env,
+    issuer: Address,
+    course_id: String,
+    target: VoucherTarget,
+    expires_at: u64,
+) -> Voucher {
+    issuer.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId);
+    }
+    if expires_at <= env.ledger().timestamp() {
+        handle_error(&env, Error::InvalidVoucher);
+    }
+    require_enrollment_authority(&env, &issuer, &course_id);
+
+    if let VoucherTarget::CodeHash(code_hash) = &target {
+        let code_key: DataKey = DataKey::VoucherCode(code_hash.clone());
+        if env.storage().persistent().has(&code_key) {
+            handle_error(&env, Error::InvalidVoucher);
+        }
+    }
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::VoucherCount)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::VoucherCount, &id);
+
+    let voucher: Voucher = Voucher {
+        id,
+        course_id: course_id.clone(),
+        issuer,
+        target,
+        expires_at,
+        redeemed_by: None,
+        redeemed_at: None,
+    };
+    store_voucher(&env, &voucher);
+    if let VoucherTarget::CodeHash(code_hash) = &voucher.target {
+        let code_key: DataKey = DataKey::VoucherCode(code_hash.clone());
+        env.storage().persistent().set(&code_key, &id);
+        bump_persistent(&env, &code_key);
+    }
+    update_stats(&env, &course_id, |stats| stats.issued += 1);
+
+    events::voucher_issued(&env, &voucher);
+    voucher
+}
+
+/// Redeems a voucher, granting the student access to its course.
+///
+/// Vouchers issued to a recipient are claimed by ID by that recipient; code
+/// vouchers are claimed by presenting the code.
+pub fn redeem_voucher(env: Env, student: Address, claim: VoucherClaim) -> Voucher {
+    student.require_auth();
+
+    let voucher_id: u32 = match &claim {
+        VoucherClaim::Id(voucher_id) => *voucher_id,
+        VoucherClaim::Code(code) => {
+            let code_hash: BytesN<32> = env.crypto().sha256(code).into();
+            env.storage()
+                .persistent()
+                .get(&DataKey::VoucherCode(code_hash))
+                .unwrap_or_else(|| handle_error(&env, Error::VoucherNotFound))
+        }
+    };
+    let mut voucher: Voucher = get_voucher(&env, voucher_id)
+        .unwrap_or_else(|| handle_error(&env, Error::VoucherNotFound));
+
+    match (&voucher.target, &claim) {
+        (VoucherTarget::Recipient(recipient), VoucherClaim::Id(_)) if *recipient == student => {}
+        (VoucherTarget::CodeHash(_), VoucherClaim::Code(_)) => {}
+        _ => handle_error(&env, Error::InvalidVoucher),
+    }
+    if voucher.redeemed_by.is_some() {
+        handle_error(&env, Error::VoucherAlreadyRedeemed);
+    }
+    if env.ledger().timestamp() >= voucher.expires_at {
+        handle_error(&env, Error::VoucherExpired);
+    }
+
+    voucher.redeemed_by = Some(student.clone());
+    voucher.redeemed_at = Some(env.ledger().timestamp());
+    store_voucher(&env, &voucher);
+    update_stats(&env, &voucher.course_id, |stats| stats.redeemed += 1);
+
+    grant_with_record(
+        env.clone(),
+        voucher.course_id.clone(),
+        student.clone(),
+        Some(voucher.issuer.clone()),
+        EnrollmentSource::Promotion,
+    );
+
+    events::voucher_redeemed(&env, &voucher, &student);
+    voucher
+}
+
+/// Returns a voucher, or `None` if it does not exist.
+pub fn get_voucher(env: &Env, voucher_id: u32) -> Option<Voucher> {
+    env.storage().persistent().get(&DataKey::Voucher(voucher_id))
+}
+
+/// Returns how many vouchers of a course were issued and redeemed.
+pub fn get_voucher_stats(env: &Env, course_id: String) -> VoucherStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VoucherStats(course_id))
+        .unwrap_or_default()
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{EnrollmentSource, VoucherClaim, VoucherStats, VoucherTarget};
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{
+        contract, contractimpl,
+        testutils::{Address as _, Ledger},
+        Address, Bytes, BytesN, Env, String,
+    };
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+    }
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn set_creator(env: Env, creator: Address) {
+            env.storage().instance().set(&"creator", &creator);
+        }
+        pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+            env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+        }
+        pub fn record_enrollment(_env: Env, _reporter: Address, _course_id: String) -> u32 {
+            0
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseAccessContractClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        let course_registry_id: Address = env.register(CourseRegistry, ());
+        let contract_id: Address = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env), &user_mgmt_id, &course_registry_id);
+
+        let creator: Address = Address::generate(env);
+        CourseRegistryClient::new(env, &course_registry_id).set_creator(&creator);
+        (client, creator, String::from_str(env, "course_1"))
+    }
+
+    #[test]
+    fn test_issue_and_redeem_vouchers() {
+        let env: Env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let scholar: Address = Address::generate(&env);
+        let student: Address = Address::generate(&env);
+        let code: Bytes = Bytes::from_slice(&env, b"SCHOLARSHIP-2025");
+        let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+
+        let direct = client.issue_voucher(
+            &creator,
+            &course_id,
+            &VoucherTarget::Recipient(scholar.clone()),
+            &1_000,
+        );
+        client.issue_voucher(&creator, &course_id, &VoucherTarget::CodeHash(code_hash), &1_000);
+        assert_eq!(client.get_voucher_stats(&course_id), VoucherStats { issued: 2, redeemed: 0 });
+
+        client.redeem_voucher(&scholar, &VoucherClaim::Id(direct.id));
+        let redeemed = client.redeem_voucher(&student, &VoucherClaim::Code(code));
+        assert_eq!(redeemed.redeemed_by, Some(student.clone()));
+
+        assert!(client.has_access(&course_id, &scholar));
+        let record = client.get_enrollment(&course_id, &student).unwrap();
+        assert_eq!(record.source, EnrollmentSource::Promotion);
+        assert_eq!(client.get_voucher_stats(&course_id), VoucherStats { issued: 2, redeemed: 2 });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #28)")]
+    fn test_recipient_vouchers_cannot_be_claimed_by_others() {
+        let env: Env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let voucher = client.issue_voucher(
+            &creator,
+            &course_id,
+            &VoucherTarget::Recipient(Address::generate(&env)),
+            &1_000,
+        );
+
+        client.redeem_voucher(&Address::generate(&env), &VoucherClaim::Id(voucher.id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #26)")]
+    fn test_expired_vouchers_cannot_be_redeemed() {
+        let env: Env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let student: Address = Address::generate(&env);
+        let voucher = client.issue_voucher(
+            &creator,
+            &course_id,
+            &VoucherTarget::Recipient(student.clone()),
+            &1_000,
+        );
+
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        client.redeem_voucher(&student, &VoucherClaim::Id(voucher.id));
+    }
+}
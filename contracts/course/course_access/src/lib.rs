@@ -6,17 +6,22 @@
 /// Contract version for tracking deployments and upgrades
 pub const VERSION: &str = "1.0.0";
 
+pub mod clients;
 mod error;
+mod events;
 mod functions;
 mod schema;
 
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
-use functions::{config::initialize,config::set_contract_addrs, grant_access::course_access_grant_access, revoke_access::course_access_revoke_access, revoke_all_access::revoke_all_access, save_profile::save_user_profile, list_user_courses::list_user_courses, list_course_access::course_access_list_course_access, contract_versioning::{is_version_compatible, get_migration_status, get_version_history, migrate_access_data}, transfer_course_access::transfer_course_access};
-use schema::{CourseUsers, UserCourses};
+use functions::{announcements::{list_announcements, post_announcement}, backup::{export_access_data, import_access_data}, config::{accept_ownership, get_config, get_owner, get_pending_owner, initialize, is_initialized, set_contract_addrs, transfer_ownership}, config_timelock::{apply_config, cancel_config_proposal, get_config_delay, get_config_proposal, propose_config}, config::set_progress_addr, course_managers::{add_course_manager, get_course_managers, is_course_manager, remove_course_manager}, course_users::list_course_access_paged, prerequisites::{get_missing_prerequisites, set_enforce_prerequisites}, grant_access::{course_access_grant_access, ensure_access, get_enrollment, grant_access_with_source, has_access}, revoke_access::{course_access_revoke_access, get_revocation_history}, revoke_all_access::revoke_all_access, save_profile::save_user_profile, self_enroll::{get_course_capacity, self_enroll, set_course_capacity}, token_gate::{clear_token_gate, get_token_gate, set_token_gate}, trusted_contracts::{get_trusted_contracts, is_trusted_contract, set_trusted_contract}, list_user_courses::{list_user_courses, list_user_courses_paged}, module_release::get_unlocked_modules, office_hours::{book_slot, cancel_booking, get_slot, publish_slots}, org_access::{get_org_seats_used, grant_org_access}, pause::{is_paused, pause, require_not_paused, unpause}, list_course_access::{course_access_count, course_access_list_course_access}, contract_versioning::{MigrationStatus, VersionRecord, is_version_compatible, get_migration_status, get_version_history, migrate_access_data, upgrade}, transfer_course_access::transfer_course_access, ttl::{extend_course_ttl, extend_profile_ttl, get_ttl_config, set_ttl_config}, vouchers::{get_voucher, get_voucher_stats, issue_voucher, redeem_voucher}};
+use schema::{
+    AccessBackupPage, Announcement, BackupCursor, ConfigProposal, CourseAccessConfig, CourseUsers, CourseUsersPage, UserCoursesPage, EnrollmentRecord, EnrollmentSource, OfficeHourSlot, RevocationReason, RevocationRecord, TokenGate,
+    TtlConfig, UserCourses, Voucher, VoucherClaim, VoucherStats, VoucherTarget,
+};
 
 /// Course Access Contract
 ///
@@ -71,22 +76,83 @@ impl CourseAccessContract {
         initialize(env, caller, user_mgmt_addr, course_registry_addr)
     }
 
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        is_initialized(&env)
+    }
+
+    /// Get the owner and the addresses of the contracts this contract calls.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is not initialized
+    pub fn get_config(env: Env) -> CourseAccessConfig {
+        get_config(&env)
+    }
+
+    /// Get the contract owner.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is not initialized
+    pub fn get_owner(env: Env) -> Address {
+        get_owner(&env)
+    }
+
+    /// Get the account ownership is being transferred to, if any.
+    pub fn get_pending_owner(env: Env) -> Option<Address> {
+        get_pending_owner(&env)
+    }
+
+    /// Start transferring ownership of the contract (owner only).
+    ///
+    /// Ownership only changes once `new_owner` calls `accept_ownership`, so
+    /// a mistyped address can't lock the owner out.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `current_owner` - The contract owner
+    /// * `new_owner` - The account to transfer ownership to; the current
+    ///   owner cancels a pending transfer
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    pub fn transfer_ownership(env: Env, current_owner: Address, new_owner: Address) {
+        require_not_paused(&env);
+        transfer_ownership(&env, current_owner, new_owner)
+    }
+
+    /// Accept a pending ownership transfer.
+    ///
+    /// # Panics
+    ///
+    /// * If no transfer to `new_owner` is pending
+    pub fn accept_ownership(env: Env, new_owner: Address) {
+        require_not_paused(&env);
+        accept_ownership(&env, new_owner)
+    }
+
     /// Grant access to a specific user for a given course.
     ///
-    /// Allows a user to access a specific course. Only authorized users
-    /// (course creators or admins) can grant access.
+    /// Allows a user to access a specific course. Only the course creator, a
+    /// course manager, a trusted contract or an admin can grant access.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
+    /// * `caller` - The course creator, a course manager, a trusted contract or an admin
     /// * `course_id` - The unique identifier of the course
     /// * `user` - The address of the user to grant access to
     ///
     /// # Panics
     ///
     /// * If course doesn't exist
-    /// * If caller is not authorized (not course creator or admin)
+    /// * If caller may not manage enrollments of the course
     /// * If user already has access
+    /// * If the course enforces prerequisites and the user hasn't completed them all;
+    ///   the error doesn't say which, `get_missing_prerequisites` lists them
     ///
     /// # Examples
     ///
@@ -94,6 +160,7 @@ impl CourseAccessContract {
     /// // Course creator granting access
     /// contract.grant_access(
     ///     env.clone(),
+    ///     creator_address,
     ///     "course_123".try_into().unwrap(),
     ///     student_address
     /// );
@@ -101,6 +168,7 @@ impl CourseAccessContract {
     /// // Admin granting access
     /// contract.grant_access(
     ///     env.clone(),
+    ///     admin_address,
     ///     "course_456".try_into().unwrap(),
     ///     student_address
     /// );
@@ -110,230 +178,1125 @@ impl CourseAccessContract {
     ///
     /// * **Already has access**: Will panic if user already has access
     /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Permission denied**: Only course creators and admins can grant access
+    /// * **Permission denied**: Only the course creator, course managers, trusted
+    ///   contracts and admins can grant access
     /// * **User validation**: User address must be valid
-    pub fn grant_access(env: Env, course_id: String, user: Address) {
-        course_access_grant_access(env, course_id, user)
+    pub fn grant_access(env: Env, caller: Address, course_id: String, user: Address) {
+        require_not_paused(&env);
+        course_access_grant_access(env, caller, course_id, user)
     }
 
-    /// Revoke access for a specific user from a course.
+    /// Grant access to a course if the user doesn't have it yet.
     ///
-    /// Removes a user's access to a specific course. Only authorized users
-    /// (course creators or admins) can revoke access.
+    /// Idempotent variant of `grant_access` for payment flows and bulk
+    /// migrations, which can retry without checking for access first.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
+    /// * `granter` - The course creator, a course manager, a trusted contract or an admin
     /// * `course_id` - The unique identifier of the course
-    /// * `user` - The address of the user to revoke access from
+    /// * `user` - The address of the user to grant access to
     ///
     /// # Returns
     ///
-    /// Returns `true` if access was successfully revoked, `false` otherwise.
+    /// Returns `true` if access was granted, `false` if the user already had it.
     ///
     /// # Panics
     ///
-    /// * If course doesn't exist
-    /// * If caller is not authorized (not course creator or admin)
+    /// * If the granter may not enroll users in the course
+    /// * Same input validation and prerequisite checks as `grant_access`
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Revoke access from a user
-    /// let success = contract.revoke_access(
+    /// let granted = contract.ensure_access(env.clone(), granter_address, "course_123".try_into().unwrap(), student_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already has access**: Nothing is written and no event is published
+    pub fn ensure_access(env: Env, granter: Address, course_id: String, user: Address) -> bool {
+        require_not_paused(&env);
+        ensure_access(env, granter, course_id, user)
+    }
+
+    /// Check whether a user currently has access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user has access to the course, through a grant,
+    /// by holding the course's gate token, or through the grant of the primary
+    /// address `user` is linked to in user management.
+    pub fn has_access(env: Env, course_id: String, user: Address) -> bool {
+        has_access(&env, course_id, user)
+    }
+
+    /// Grant access to a course and record how it was obtained.
+    ///
+    /// Like `grant_access`, but authenticated: the granter is stored in the
+    /// enrollment record together with the source of the access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `granter` - The course creator, a course manager, a trusted contract or an admin granting the access
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to grant access to
+    /// * `source` - How the access was obtained (purchase, promotion, ...)
+    ///
+    /// # Panics
+    ///
+    /// * If the granter is neither the course creator, a course manager, a trusted contract nor an admin
+    /// * If the user already has access
+    /// * Same input validation as `grant_access`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.grant_access_with_source(
     ///     env.clone(),
+    ///     payment_contract_address,
     ///     "course_123".try_into().unwrap(),
-    ///     student_address
+    ///     student_address,
+    ///     EnrollmentSource::Purchase
     /// );
-    /// 
-    /// if success {
-    ///     println!("Access revoked successfully");
-    /// } else {
-    ///     println!("User didn't have access");
-    /// }
     /// ```
+    pub fn grant_access_with_source(
+        env: Env,
+        granter: Address,
+        course_id: String,
+        user: Address,
+        source: EnrollmentSource,
+    ) {
+        require_not_paused(&env);
+        grant_access_with_source(env, granter, course_id, user, source)
+    }
+
+    /// Get how and when a user obtained access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns the `EnrollmentRecord` with the grant timestamp, granter and
+    /// source, or `None` if the user has no access.
     ///
     /// # Edge Cases
     ///
-    /// * **No access to revoke**: Returns `false` if user didn't have access
-    /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Permission denied**: Only course creators and admins can revoke access
-    /// * **Idempotent**: Safe to call multiple times
-    pub fn revoke_access(env: Env, course_id: String, user: Address) -> bool {
-        course_access_revoke_access(env, course_id, user)
+    /// * **Transfers**: The receiver's record has source `Transfer` and the sender as granter
+    /// * **Revocation**: The record is removed together with the access
+    /// * **Older grants**: Access granted before records existed has no record
+    pub fn get_enrollment(env: Env, course_id: String, user: Address) -> Option<EnrollmentRecord> {
+        get_enrollment(&env, course_id, user)
     }
 
-    /// Save or update a user's profile on-chain.
+    /// List the modules of a course a student can open now.
     ///
-    /// Stores user profile information in the contract storage.
-    /// This includes personal and professional information.
+    /// Applies the drip schedule set in the course registry with
+    /// `set_module_release_schedule`, counting relative release times from
+    /// the student's enrollment.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `name` - The user's full name
-    /// * `email` - The user's email address
-    /// * `profession` - Optional profession/job title
-    /// * `goals` - Optional learning goals or objectives
-    /// * `country` - The user's country of residence
+    /// * `student` - The address of the student
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the IDs of the unlocked modules in registry order; empty if
+    /// the student has no access.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unscheduled modules**: Are unlocked as soon as the student has access
+    /// * **No enrollment record**: Token holders and older grants count as
+    ///   enrolled since the start, so only fixed release times apply
+    pub fn get_unlocked_modules(env: Env, student: Address, course_id: String) -> Vec<String> {
+        get_unlocked_modules(&env, student, course_id)
+    }
+
+    /// Enroll in a free course.
+    ///
+    /// Lets a student join a course without a manual grant, as long as the
+    /// course registry reports it as free and published and the course has
+    /// room left.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The student enrolling, who must authorize the call
+    /// * `course_id` - The unique identifier of the course
     ///
     /// # Panics
     ///
-    /// * If name, email, or country are empty
-    /// * If email format is invalid
+    /// * If course_id is empty
+    /// * If the course is not free, not published, archived or doesn't exist
+    /// * If the course has reached its capacity
+    /// * If the user already has access
+    /// * If prerequisites are enforced and not met (see `get_missing_prerequisites`)
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Save user profile
-    /// contract.save_user_profile(
-    ///     env.clone(),
-    ///     "John Doe".try_into().unwrap(),
-    ///     "john@example.com".try_into().unwrap(),
-    ///     Some("Software Developer".try_into().unwrap()),
-    ///     Some("Learn Rust programming".try_into().unwrap()),
-    ///     "US".try_into().unwrap()
-    /// );
-    /// 
-    /// // Save minimal profile
-    /// contract.save_user_profile(
+    /// contract.self_enroll(env.clone(), student_address, "course_123".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Capacity**: Only applies to self-enrollment; creators and admins can still grant access
+    /// * **Token-gated courses**: Holders of the gate token already have access
+    ///   and don't need to enroll; a paid gated course can't be self-enrolled
+    pub fn self_enroll(env: Env, user: Address, course_id: String) {
+        require_not_paused(&env);
+        self_enroll(env, user, course_id)
+    }
+
+    /// Set the maximum number of students of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `max_students` - The capacity, or `None` to remove the limit
+    ///
+    /// # Panics
+    ///
+    /// * If course_id is empty
+    /// * If the caller is not the course creator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_course_capacity(env.clone(), creator_address, "course_123".try_into().unwrap(), Some(30));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Lowering below enrollment**: Existing students keep access; new self-enrollments are refused
+    pub fn set_course_capacity(env: Env, creator: Address, course_id: String, max_students: Option<u32>) {
+        require_not_paused(&env);
+        set_course_capacity(env, creator, course_id, max_students)
+    }
+
+    /// Get the maximum number of students of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the capacity, or `None` if the course is unlimited.
+    pub fn get_course_capacity(env: Env, course_id: String) -> Option<u32> {
+        get_course_capacity(&env, course_id)
+    }
+
+    /// Give holders of a token access to a course.
+    ///
+    /// Users holding at least `min_balance` of the token have access without
+    /// a grant, so NFT or community-token holders join automatically. The
+    /// balance is checked on every access check.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator, who must authorize the call
+    /// * `course_id` - The unique identifier of the course
+    /// * `token_contract` - Token or NFT contract exposing `balance(id) -> i128`
+    /// * `min_balance` - Balance required for access
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored gate.
+    ///
+    /// # Panics
+    ///
+    /// * If course_id is empty
+    /// * If the caller is not the course creator
+    /// * If `min_balance` is not positive
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_token_gate(env.clone(), creator_address, "course_123".try_into().unwrap(), nft_address, 1);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Balance drops**: Access through the gate ends with the balance;
+    ///   passing the token on passes the access on
+    pub fn set_token_gate(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        token_contract: Address,
+        min_balance: i128,
+    ) -> TokenGate {
+        require_not_paused(&env);
+        set_token_gate(env, creator, course_id, token_contract, min_balance)
+    }
+
+    /// Remove the token gate of a course (course creator only).
+    ///
+    /// Holders lose their access unless they hold a grant of their own.
+    pub fn clear_token_gate(env: Env, creator: Address, course_id: String) {
+        require_not_paused(&env);
+        clear_token_gate(env, creator, course_id)
+    }
+
+    /// Get the token gate of a course, `None` if the course isn't gated.
+    pub fn get_token_gate(env: Env, course_id: String) -> Option<TokenGate> {
+        get_token_gate(&env, course_id)
+    }
+
+    /// Issue a voucher granting free access to a course.
+    ///
+    /// Lets instructors run scholarship programs: a voucher is either issued
+    /// to a known student or redeemable by whoever presents its code.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator, a manager or an admin, who must authorize the call
+    /// * `course_id` - The unique identifier of the course
+    /// * `target` - `Recipient(address)` or `CodeHash(sha256(code))`
+    /// * `expires_at` - Ledger timestamp after which the voucher can't be redeemed
+    ///
+    /// # Returns
+    ///
+    /// Returns the new voucher.
+    ///
+    /// # Panics
+    ///
+    /// * If course_id is empty
+    /// * If the caller can't manage enrollments of the course
+    /// * If `expires_at` has already passed or the code hash is already in use
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let voucher = contract.issue_voucher(env.clone(), creator_address, "course_123".try_into().unwrap(), VoucherTarget::Recipient(student_address), expires_at);
+    /// ```
+    pub fn issue_voucher(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        target: VoucherTarget,
+        expires_at: u64,
+    ) -> Voucher {
+        require_not_paused(&env);
+        issue_voucher(env, creator, course_id, target, expires_at)
+    }
+
+    /// Redeem a voucher and gain access to its course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student redeeming, who must authorize the call
+    /// * `claim` - `Id(voucher_id)` for a voucher issued to the student, or
+    ///   `Code(code)` for a code voucher
+    ///
+    /// # Returns
+    ///
+    /// Returns the redeemed voucher.
+    ///
+    /// # Panics
+    ///
+    /// * If the voucher doesn't exist or wasn't issued to the student
+    /// * If the voucher was already redeemed or has expired
+    /// * If the student already has access or prerequisites are enforced and not met
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Enrollment record**: Source `Promotion`, with the issuer as granter
+    pub fn redeem_voucher(env: Env, student: Address, claim: VoucherClaim) -> Voucher {
+        require_not_paused(&env);
+        redeem_voucher(env, student, claim)
+    }
+
+    /// Get a voucher, `None` if it doesn't exist.
+    pub fn get_voucher(env: Env, voucher_id: u32) -> Option<Voucher> {
+        get_voucher(&env, voucher_id)
+    }
+
+    /// Get how many vouchers of a course were issued and redeemed.
+    pub fn get_voucher_stats(env: Env, course_id: String) -> VoucherStats {
+        get_voucher_stats(&env, course_id)
+    }
+
+    /// Enable or disable prerequisite enforcement for a course.
+    ///
+    /// When enabled, `grant_access` verifies through the progress contract that
+    /// the student completed every prerequisite course before granting access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `enabled` - Whether prerequisites must be completed before access is granted
+    ///
+    /// # Panics
+    ///
+    /// * If course_id is empty
+    /// * If the caller is not the course creator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_enforce_prerequisites(
     ///     env.clone(),
-    ///     "Jane Smith".try_into().unwrap(),
-    ///     "jane@example.com".try_into().unwrap(),
-    ///     None,
-    ///     None,
-    ///     "CA".try_into().unwrap()
+    ///     creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     true
     /// );
     /// ```
     ///
     /// # Edge Cases
     ///
-    /// * **Empty required fields**: Name, email, and country cannot be empty
-    /// * **Invalid email**: Email must be in valid format
-    /// * **Profile updates**: Overwrites existing profile data
-    /// * **Optional fields**: Profession and goals can be None
-    pub fn save_user_profile(
-        env: Env,
-        name: String,
-        email: String,
-        profession: Option<String>,
-        goals: Option<String>,
-        country: String,
-    ) {
-        let user: Address = env.current_contract_address();
-        save_user_profile(env, name, email, profession, goals, country, user);
+    /// * **Default**: Enforcement is off until enabled
+    /// * **No prerequisites**: Enabling has no effect on courses without prerequisites
+    pub fn set_enforce_prerequisites(env: Env, creator: Address, course_id: String, enabled: bool) {
+        require_not_paused(&env);
+        set_enforce_prerequisites(env, creator, course_id, enabled)
+    }
+
+    /// List the prerequisites a user still has to complete for a course.
+    ///
+    /// `PrerequisitesNotMet` doesn't carry the missing courses, and events
+    /// published by a failed call are discarded, so clients call this to
+    /// explain why `grant_access`, `self_enroll` or `redeem_voucher` failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the student
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the IDs of the direct prerequisite courses the user hasn't completed.
+    ///
+    /// # Panics
+    ///
+    /// * If the course has prerequisites but no progress contract is configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let missing = contract.get_missing_prerequisites(
+    ///     env.clone(),
+    ///     student_address,
+    ///     "course_123".try_into().unwrap()
+    /// );
+    /// ```
+    pub fn get_missing_prerequisites(env: Env, user: Address, course_id: String) -> Vec<String> {
+        get_missing_prerequisites(env, user, course_id)
+    }
+
+    /// Set the progress contract used to verify course completions.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `progress_addr` - Address of the progress contract (must expose `is_course_completed(user, course_id) -> bool`)
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not the contract owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_progress_contract(env.clone(), contract_owner_address, progress_contract_address);
+    /// ```
+    pub fn set_progress_contract(env: Env, caller: Address, progress_addr: Address) {
+        require_not_paused(&env);
+        set_progress_addr(env, caller, progress_addr)
+    }
+
+    /// Revoke access for a specific user from a course.
+    ///
+    /// Removes a user's access to a specific course. Only the course creator, a
+    /// course manager, a trusted contract or an admin can revoke access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator, a course manager, a trusted contract or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to revoke access from
+    /// * `reason` - Optional reason code for the revocation
+    /// * `note_hash` - Optional hash of an off-chain note explaining the revocation
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if access was successfully revoked, `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// * If course doesn't exist
+    /// * If caller may not manage enrollments of the course
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Revoke access from a user
+    /// let success = contract.revoke_access(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address,
+    ///     Some(RevocationReason::Refund),
+    ///     None
+    /// );
+    /// 
+    /// if success {
+    ///     println!("Access revoked successfully");
+    /// } else {
+    ///     println!("User didn't have access");
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No access to revoke**: Returns `false` if user didn't have access
+    /// * **Non-existent course**: Will panic if course doesn't exist
+    /// * **Permission denied**: Only the course creator, course managers, trusted
+    ///   contracts and admins can revoke access
+    /// * **Idempotent**: Safe to call multiple times
+    /// * **History**: Each successful revocation is appended to the revocation history
+    pub fn revoke_access(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        reason: Option<RevocationReason>,
+        note_hash: Option<BytesN<32>>,
+    ) -> bool {
+        require_not_paused(&env);
+        course_access_revoke_access(env, caller, course_id, user, reason, note_hash)
+    }
+
+    /// Get the revocation history of a user's access to a course (admin or
+    /// trusted contract only).
+    ///
+    /// Support staff use the history to review appeals against revocations,
+    /// and the disputes contract to accept disputes from revoked students.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - An admin of the user management contract, or a trusted contract
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns the revocations, oldest first, each with its timestamp,
+    /// reason and note hash.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither an admin nor a trusted contract
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let history = contract.get_revocation_history(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Regranted access**: The history is kept when access is granted again
+    /// * **Never revoked**: Returns an empty vector
+    pub fn get_revocation_history(
+        env: Env,
+        admin: Address,
+        course_id: String,
+        user: Address,
+    ) -> Vec<RevocationRecord> {
+        get_revocation_history(env, admin, course_id, user)
+    }
+
+    /// Save or update a user's profile on-chain.
+    ///
+    /// Forwards the profile to the user_management contract, creating it if the
+    /// user has none yet and updating it otherwise. No profile data is kept in
+    /// this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user whose profile is saved (must authorize)
+    /// * `name` - The user's full name
+    /// * `email` - The user's email address
+    /// * `profession` - Optional profession/job title
+    /// * `goals` - Optional learning goals or objectives
+    /// * `country` - The user's country of residence (ISO 3166-1 alpha-2 code)
+    ///
+    /// # Panics
+    ///
+    /// * If the user has not authorized the call
+    /// * If name, email, or country are empty
+    /// * If email format is invalid
+    /// * If country is not a two-letter uppercase country code
+    /// * If the email is already registered to another user in user_management
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Save user profile
+    /// contract.save_user_profile(
+    ///     env.clone(),
+    ///     user_address.clone(),
+    ///     "John Doe".try_into().unwrap(),
+    ///     "john@example.com".try_into().unwrap(),
+    ///     Some("Software Developer".try_into().unwrap()),
+    ///     Some("Learn Rust programming".try_into().unwrap()),
+    ///     "US".try_into().unwrap()
+    /// );
+    /// 
+    /// // Save minimal profile
+    /// contract.save_user_profile(
+    ///     env.clone(),
+    ///     other_user_address,
+    ///     "Jane Smith".try_into().unwrap(),
+    ///     "jane@example.com".try_into().unwrap(),
+    ///     None,
+    ///     None,
+    ///     "CA".try_into().unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty required fields**: Name, email, and country cannot be empty
+    /// * **Invalid email**: Email must be in valid format
+    /// * **Profile updates**: Updates the existing user_management profile
+    /// * **Optional fields**: Profession and goals can be None
+    pub fn save_user_profile(
+        env: Env,
+        user: Address,
+        name: String,
+        email: String,
+        profession: Option<String>,
+        goals: Option<String>,
+        country: String,
+    ) {
+        require_not_paused(&env);
+        save_user_profile(env, user, name, email, profession, goals, country);
+    }
+
+    /// List all courses a user has access to.
+    ///
+    /// Retrieves all courses that the specified user is enrolled in
+    /// or has been granted access to.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to query
+    ///
+    /// # Returns
+    ///
+    /// Returns a `UserCourses` struct containing the list of accessible courses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get user's accessible courses
+    /// let user_courses = contract.list_user_courses(env.clone(), user_address);
+    /// 
+    /// for course_id in user_courses.course_ids {
+    ///     println!("User has access to course: {}", course_id);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No access**: Returns empty list if user has no course access
+    /// * **Non-existent user**: Returns empty list for non-existent users
+    /// * **Public access**: Anyone can query user courses
+    /// * **Revoked courses**: Only includes currently accessible courses
+    pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
+        list_user_courses(env, user)
+    }
+
+    /// List one page of the courses a user has access to.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to query
+    /// * `offset` - Number of courses to skip
+    /// * `limit` - Maximum number of courses to return, up to 100
+    ///
+    /// # Returns
+    ///
+    /// Returns the course IDs of the page, in grant order, with the user's
+    /// total course count and whether more courses follow.
+    ///
+    /// # Panics
+    ///
+    /// * If `limit` is 0 or above 100 (`InvalidPageLimit`)
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Past the end**: Returns an empty page
+    pub fn list_user_courses_paged(env: Env, user: Address, offset: u32, limit: u32) -> UserCoursesPage {
+        list_user_courses_paged(env, user, offset, limit)
+    }
+
+    /// List all users who have access to a course.
+    ///
+    /// Retrieves all users who have been granted access to the specified course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseUsers` struct containing the list of users with access.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get all users with access to a course
+    /// let course_users = contract.list_course_access(env.clone(), "course_123".try_into().unwrap());
+    /// 
+    /// for user in course_users.users {
+    ///     println!("User with access: {}", user);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No users**: Returns empty list if no users have access
+    /// * **Non-existent course**: Returns empty list for non-existent courses
+    /// * **Public access**: Anyone can query course access
+    /// * **Real-time data**: Always returns current access status
+    /// * **Large courses**: Reads every shard of the course; use
+    ///   `list_course_access_paged` to page through popular courses
+    pub fn list_course_access(env: Env, course_id: String) -> CourseUsers {
+        course_access_list_course_access(env, course_id)
+    }
+
+    /// List one page of the users with access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `offset` - Number of users to skip
+    /// * `limit` - Maximum number of users to return, up to 100
+    ///
+    /// # Returns
+    ///
+    /// Returns the users of the page with the course's total user count and
+    /// whether more users follow.
+    ///
+    /// # Panics
+    ///
+    /// * If the course ID is empty
+    /// * If `limit` is 0 or above 100 (`InvalidPageLimit`)
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revocations**: Revoking a user moves the last user into the
+    ///   freed position, so pages can shift between calls
+    /// * **Past the end**: Returns an empty page
+    pub fn list_course_access_paged(env: Env, course_id: String, offset: u32, limit: u32) -> CourseUsersPage {
+        list_course_access_paged(&env, course_id, offset, limit)
+    }
+
+    /// Export one page of access grants for backup purposes (admin only).
+    ///
+    /// Complements the user management and course registry backups. Start
+    /// with offset 0 and an all-zero checksum, then pass each page's
+    /// `next_cursor` until `has_more` is false.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - Admin requesting the backup
+    /// * `cursor` - Cursor of the page to export
+    /// * `limit` - Maximum number of courses in the page (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the users of each course of the page, with how they obtained access.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin (`Unauthorized`)
+    /// * If the limit is out of range or the cursor is past the end (`InvalidBackupPage`)
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Older grants**: Courses only granted before this export existed are not listed
+    pub fn export_access_data(env: Env, caller: Address, cursor: BackupCursor, limit: u32) -> AccessBackupPage {
+        export_access_data(env, caller, cursor, limit)
+    }
+
+    /// Import one page of an access backup (admin only).
+    ///
+    /// Pages must be imported in export order; the checksum carried by the
+    /// cursors is verified. The course lists of users are rebuilt from the
+    /// restored grants.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of grants restored from the page.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin (`Unauthorized`)
+    /// * If the page isn't the next one expected (`InvalidBackupPage`)
+    /// * If the page's checksum doesn't match its contents (`BackupChecksumMismatch`)
+    pub fn import_access_data(env: Env, caller: Address, page: AccessBackupPage) -> u32 {
+        require_not_paused(&env);
+        import_access_data(env, caller, page)
+    }
+
+    /// Count the users who currently have access to a course.
+    ///
+    /// Used by the course registry to refuse deleting courses that still
+    /// have students.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of users with access, 0 for unknown courses.
+    pub fn get_course_access_count(env: Env, course_id: String) -> u32 {
+        course_access_count(&env, course_id)
+    }
+
+    /// Revoke all user access for a course.
+    ///
+    /// Removes access for all users from the specified course.
+    /// Only admin or course creator is allowed to perform this operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user requesting the operation
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of users affected by the revocation and emits an event.
+    ///
+    /// # Panics
+    ///
+    /// * If course doesn't exist
+    /// * If caller is not authorized (not course creator or admin)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Revoke all access for a course
+    /// let affected_users = contract.revoke_all_access(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     "course_123".try_into().unwrap()
+    /// );
+    /// 
+    /// println!("Revoked access for {} users", affected_users);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No users**: Returns 0 if no users had access
+    /// * **Non-existent course**: Will panic if course doesn't exist
+    /// * **Permission denied**: Only course creators, their managers and admins can perform this
+    /// * **Bulk operation**: Efficiently removes all access in one transaction
+    pub fn revoke_all_access(env: Env, user: Address, course_id: String) -> u32 {
+        require_not_paused(&env);
+        revoke_all_access(env, user, course_id)
+    }
+
+    /// Grant a member of an organization access to a course using one of
+    /// the organization's seats.
+    ///
+    /// Organizations and their purchased seats are managed in the user
+    /// management contract (`create_org`, `add_org_member`, `purchase_org_seats`).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `org_admin` - An admin of the organization
+    /// * `org_id` - The organization identifier
+    /// * `course_id` - The unique identifier of the course
+    /// * `member` - The organization member receiving access
+    ///
+    /// # Panics
+    ///
+    /// * If `org_admin` is not an admin of the organization (`Unauthorized`)
+    /// * If `member` does not belong to the organization (`NotOrgMember`)
+    /// * If every purchased seat is in use (`NoSeatsAvailable`)
+    /// * Same validation and prerequisite checks as `grant_access`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.grant_org_access(env.clone(), org_admin, org_id, "course_123".try_into().unwrap(), employee);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revocation**: Revoking the member's access frees the seat
+    /// * **Member removed**: Leaving the organization does not revoke access
+    pub fn grant_org_access(env: Env, org_admin: Address, org_id: u32, course_id: String, member: Address) {
+        require_not_paused(&env);
+        grant_org_access(env, org_admin, org_id, course_id, member)
+    }
+
+    /// Get how many of an organization's seats on a course are in use.
+    pub fn get_org_seats_used(env: Env, org_id: u32, course_id: String) -> u32 {
+        get_org_seats_used(&env, org_id, course_id)
+    }
+
+    /// Delegate enrollment management of a course to `manager`.
+    ///
+    /// Managers can grant access with `grant_access_with_source` and revoke
+    /// it with `revoke_all_access`, like the creator, without owning the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `manager` - The address receiving the delegation
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the course creator
+    /// * If the course ID is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_course_manager(env.clone(), creator_address, "course_123".try_into().unwrap(), assistant_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already a manager**: Nothing changes and no event is published
+    pub fn add_course_manager(env: Env, creator: Address, course_id: String, manager: Address) {
+        require_not_paused(&env);
+        add_course_manager(env, creator, course_id, manager)
+    }
+
+    /// Withdraw the delegation given to a course manager.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `manager` - The manager to remove
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the course creator
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Not a manager**: Nothing changes
+    /// * **Existing grants**: Access granted by the manager is kept
+    pub fn remove_course_manager(env: Env, creator: Address, course_id: String, manager: Address) {
+        require_not_paused(&env);
+        remove_course_manager(env, creator, course_id, manager)
     }
 
-    /// List all courses a user has access to.
-    ///
-    /// Retrieves all courses that the specified user is enrolled in
-    /// or has been granted access to.
+    /// List the managers of a course.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `user` - The address of the user to query
+    /// * `course_id` - The unique identifier of the course
     ///
     /// # Returns
     ///
-    /// Returns a `UserCourses` struct containing the list of accessible courses.
+    /// Returns the manager addresses in the order they were added.
+    pub fn get_course_managers(env: Env, course_id: String) -> Vec<Address> {
+        get_course_managers(&env, course_id)
+    }
+
+    /// Check whether an address manages enrollments of a course.
     ///
-    /// # Examples
+    /// # Arguments
     ///
-    /// ```rust
-    /// // Get user's accessible courses
-    /// let user_courses = contract.list_user_courses(env.clone(), user_address);
-    /// 
-    /// for course_id in user_courses.course_ids {
-    ///     println!("User has access to course: {}", course_id);
-    /// }
-    /// ```
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `who` - The address to check
     ///
-    /// # Edge Cases
+    /// # Returns
     ///
-    /// * **No access**: Returns empty list if user has no course access
-    /// * **Non-existent user**: Returns empty list for non-existent users
-    /// * **Public access**: Anyone can query user courses
-    /// * **Revoked courses**: Only includes currently accessible courses
-    pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
-        list_user_courses(env, user)
+    /// Returns `true` if `who` was added as a manager of the course.
+    pub fn is_course_manager(env: Env, course_id: String, who: Address) -> bool {
+        is_course_manager(&env, course_id, who)
     }
 
-    /// List all users who have access to a course.
+    /// Post an announcement to the students of a course.
     ///
-    /// Retrieves all users who have been granted access to the specified course.
+    /// Emits `("announce", "posted", course_id)` for off-chain notification
+    /// pipelines. Only the hash of the body is stored.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
+    /// * `author` - The course creator, one of its managers or an admin
     /// * `course_id` - The unique identifier of the course
+    /// * `title` - Headline of the announcement (1 to 200 bytes)
+    /// * `body_hash` - SHA-256 hash of the off-chain body
     ///
     /// # Returns
     ///
-    /// Returns a `CourseUsers` struct containing the list of users with access.
+    /// Returns the stored announcement with its sequential ID.
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// // Get all users with access to a course
-    /// let course_users = contract.list_course_access(env.clone(), "course_123".try_into().unwrap());
-    /// 
-    /// for user in course_users.users {
-    ///     println!("User with access: {}", user);
-    /// }
-    /// ```
+    /// * If the author is not the creator, a manager or an admin
+    /// * If the title is empty or too long
+    pub fn post_announcement(
+        env: Env,
+        author: Address,
+        course_id: String,
+        title: String,
+        body_hash: BytesN<32>,
+    ) -> Announcement {
+        require_not_paused(&env);
+        post_announcement(env, author, course_id, title, body_hash)
+    }
+
+    /// List the announcements of a course, newest first.
     ///
-    /// # Edge Cases
+    /// # Arguments
     ///
-    /// * **No users**: Returns empty list if no users have access
-    /// * **Non-existent course**: Returns empty list for non-existent courses
-    /// * **Public access**: Anyone can query course access
-    /// * **Real-time data**: Always returns current access status
-    pub fn list_course_access(env: Env, course_id: String) -> CourseUsers {
-        course_access_list_course_access(env, course_id)
+    /// * `env` - The Soroban environment
+    /// * `reader` - A user with access to the course, or someone allowed to post
+    /// * `course_id` - The unique identifier of the course
+    /// * `offset` - Number of most recent announcements to skip
+    /// * `limit` - Maximum number of announcements to return (capped at 50)
+    ///
+    /// # Panics
+    ///
+    /// * If the reader has no access to the course and cannot post to it
+    pub fn list_announcements(
+        env: Env,
+        reader: Address,
+        course_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Announcement> {
+        list_announcements(env, reader, course_id, offset, limit)
     }
 
-    /// Revoke all user access for a course.
+    /// Publish office-hour slots for a course.
     ///
-    /// Removes access for all users from the specified course.
-    /// Only admin or course creator is allowed to perform this operation.
+    /// Each slot can be booked by one student with access to the course.
+    /// A `("slot", "published", course_id)` event is emitted per slot.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `user` - The address of the user requesting the operation
+    /// * `instructor` - The course creator, a course manager or an admin
     /// * `course_id` - The unique identifier of the course
+    /// * `slots` - `(start, end)` ledger timestamps, at most 20
     ///
     /// # Returns
     ///
-    /// Returns the number of users affected by the revocation and emits an event.
+    /// Returns the published slots with their IDs.
     ///
     /// # Panics
     ///
-    /// * If course doesn't exist
-    /// * If caller is not authorized (not course creator or admin)
+    /// * If the instructor is not allowed to manage the course
+    /// * If no slot or more than 20 are given
+    /// * If a slot doesn't start in the future or doesn't end after it starts
+    pub fn publish_slots(
+        env: Env,
+        instructor: Address,
+        course_id: String,
+        slots: Vec<(u64, u64)>,
+    ) -> Vec<OfficeHourSlot> {
+        require_not_paused(&env);
+        publish_slots(&env, instructor, course_id, slots)
+    }
+
+    /// Book a free office-hour slot.
     ///
-    /// # Examples
+    /// # Arguments
     ///
-    /// ```rust
-    /// // Revoke all access for a course
-    /// let affected_users = contract.revoke_all_access(
-    ///     env.clone(),
-    ///     admin_address,
-    ///     "course_123".try_into().unwrap()
-    /// );
-    /// 
-    /// println!("Revoked access for {} users", affected_users);
-    /// ```
+    /// * `env` - The Soroban environment
+    /// * `student` - A student with access to the slot's course
+    /// * `slot_id` - The slot to book
     ///
-    /// # Edge Cases
+    /// # Returns
     ///
-    /// * **No users**: Returns 0 if no users had access
-    /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Permission denied**: Only course creators and admins can perform this
-    /// * **Bulk operation**: Efficiently removes all access in one transaction
-    pub fn revoke_all_access(env: Env, user: Address, course_id: String) -> u32 {
-        revoke_all_access(env, user, course_id)
+    /// Returns the booked slot.
+    ///
+    /// # Panics
+    ///
+    /// * If the slot doesn't exist, is already booked or has started
+    /// * If the student has no access to the course
+    pub fn book_slot(env: Env, student: Address, slot_id: u32) -> OfficeHourSlot {
+        require_not_paused(&env);
+        book_slot(&env, student, slot_id)
+    }
+
+    /// Cancel the booking of an office-hour slot that hasn't started.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The student who booked the slot or its instructor
+    /// * `slot_id` - The booked slot
+    ///
+    /// # Returns
+    ///
+    /// Returns the slot, free again.
+    ///
+    /// # Panics
+    ///
+    /// * If the slot doesn't exist, is not booked or has started
+    /// * If the caller is neither the booking student nor the instructor
+    pub fn cancel_booking(env: Env, caller: Address, slot_id: u32) -> OfficeHourSlot {
+        require_not_paused(&env);
+        cancel_booking(&env, caller, slot_id)
+    }
+
+    /// Get an office-hour slot, `None` if it doesn't exist.
+    pub fn get_slot(env: Env, slot_id: u32) -> Option<OfficeHourSlot> {
+        get_slot(&env, slot_id)
     }
 
     /// Configure external contract addresses used for auth checks.
@@ -352,6 +1315,7 @@ impl CourseAccessContract {
     ///
     /// * If caller is not the contract owner
     /// * If any of the provided addresses are invalid
+    /// * If the configuration delay is not 0 (see `propose_config`)
     ///
     /// # Storage
     ///
@@ -374,16 +1338,221 @@ impl CourseAccessContract {
     /// * **Owner only**: Only contract owner can update addresses
     /// * **Invalid addresses**: Will panic if addresses are invalid
     /// * **Runtime updates**: Can be called after contract deployment
-    /// * **Immediate effect**: Changes take effect immediately
+    /// * **Immediate effect**: Changes take effect immediately, so this is
+    ///   only allowed once a proposal has set the configuration delay to 0
     pub fn set_config(
         env: Env,
         caller: Address,
         user_mgmt_addr: Address,
         course_registry_addr: Address,
     ) {
+        require_not_paused(&env);
         set_contract_addrs(env, caller, user_mgmt_addr, course_registry_addr)
     }
 
+    /// Propose new contract addresses and configuration delay (owner only).
+    ///
+    /// The proposal can be applied with `apply_config` once the current
+    /// delay has passed. A new proposal replaces the pending one and
+    /// restarts the wait.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The contract owner
+    /// * `user_mgmt_addr` - Address of the user management contract
+    /// * `course_registry_addr` - Address of the course registry contract
+    /// * `config_delay` - Seconds later proposals will wait once this one is applied
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    /// * If `config_delay` is above `MAX_CONFIG_DELAY`
+    pub fn propose_config(
+        env: Env,
+        owner: Address,
+        user_mgmt_addr: Address,
+        course_registry_addr: Address,
+        config_delay: u64,
+    ) -> ConfigProposal {
+        require_not_paused(&env);
+        propose_config(&env, owner, user_mgmt_addr, course_registry_addr, config_delay)
+    }
+
+    /// Apply the pending configuration proposal once its delay has passed (owner only).
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    /// * If there is no pending proposal
+    /// * If the proposal's `executable_at` hasn't been reached
+    pub fn apply_config(env: Env, owner: Address) -> CourseAccessConfig {
+        require_not_paused(&env);
+        apply_config(&env, owner)
+    }
+
+    /// Drop the pending configuration proposal (owner only).
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    /// * If there is no pending proposal
+    pub fn cancel_config_proposal(env: Env, owner: Address) {
+        require_not_paused(&env);
+        cancel_config_proposal(&env, owner)
+    }
+
+    /// Get the pending configuration proposal, if any.
+    pub fn get_config_proposal(env: Env) -> Option<ConfigProposal> {
+        get_config_proposal(&env)
+    }
+
+    /// Get the seconds a configuration proposal waits before it can be applied.
+    pub fn get_config_delay(env: Env) -> u64 {
+        get_config_delay(&env)
+    }
+
+    /// Add a contract to, or remove it from, the trusted allowlist (owner only).
+    ///
+    /// Trusted contracts, such as the payments contract, may call privileged
+    /// hooks like `grant_access_with_source` for any course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The contract owner
+    /// * `contract` - The contract address
+    /// * `trusted` - Whether the contract is trusted
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    pub fn set_trusted_contract(env: Env, owner: Address, contract: Address, trusted: bool) {
+        require_not_paused(&env);
+        set_trusted_contract(&env, owner, contract, trusted)
+    }
+
+    /// Get the contracts trusted to call privileged hooks.
+    pub fn get_trusted_contracts(env: Env) -> Vec<Address> {
+        get_trusted_contracts(&env)
+    }
+
+    /// Check whether a contract is on the trusted allowlist.
+    pub fn is_trusted_contract(env: Env, contract: Address) -> bool {
+        is_trusted_contract(&env, &contract)
+    }
+
+    /// Pause the contract during an incident (owner or admin only).
+    ///
+    /// While paused, every state-changing entrypoint fails with
+    /// `ContractPaused`. Reads, TTL extensions and `upgrade` keep working.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin of the user management contract
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already paused**: Nothing changes and no event is published
+    pub fn pause(env: Env, admin: Address) {
+        pause(&env, admin)
+    }
+
+    /// Lift the pause set with `pause` (owner or admin only).
+    pub fn unpause(env: Env, admin: Address) {
+        unpause(&env, admin)
+    }
+
+    /// Check whether the contract is paused.
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    /// Extend the storage TTL of a course's access entries.
+    ///
+    /// Access entries are bumped automatically when read or written; this
+    /// lets anyone keep the entries of a rarely used course alive explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.extend_course_ttl(env.clone(), "course_123".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unknown course**: Does nothing besides bumping the contract instance
+    pub fn extend_course_ttl(env: Env, course_id: String) {
+        extend_course_ttl(env, course_id)
+    }
+
+    /// Extend the storage TTL of a user's course list and access entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.extend_profile_ttl(env.clone(), user_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No courses**: Does nothing besides bumping the contract instance
+    pub fn extend_profile_ttl(env: Env, user: Address) {
+        extend_profile_ttl(env, user)
+    }
+
+    /// Get the TTL thresholds used when bumping storage entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the current `TtlConfig`, or the defaults if none was set.
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        get_ttl_config(&env)
+    }
+
+    /// Set the TTL thresholds used when bumping storage entries (owner only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `threshold` - Entries with fewer ledgers to live than this are extended
+    /// * `extend_to` - Number of ledgers entries are extended to
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner
+    /// * If `threshold` is zero, not below `extend_to`, or `extend_to` exceeds the network maximum
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_ttl_config(env.clone(), owner, 120_960, 518_400);
+    /// ```
+    pub fn set_ttl_config(env: Env, caller: Address, threshold: u32, extend_to: u32) {
+        require_not_paused(&env);
+        set_ttl_config(env, caller, threshold, extend_to)
+    }
+
     /// Get the current contract version
     ///
     /// Returns the semantic version of the current contract deployment.
@@ -400,15 +1569,15 @@ impl CourseAccessContract {
 
     /// Get contract version history
     ///
-    /// Returns a list of all versions that have been deployed for this contract.
-    /// This helps track the evolution of the contract over time.
+    /// Returns every version recorded for this contract, written on
+    /// initialization, upgrade and completed migrations.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
     ///
     /// # Returns
-    /// * `Vec<String>` - Vector of version strings in chronological order
-    pub fn get_version_history(env: Env) -> Vec<String> {
+    /// * `Vec<VersionRecord>` - `{version, ledger_timestamp, by}` entries in chronological order
+    pub fn get_version_history(env: Env) -> Vec<VersionRecord> {
         get_version_history(&env)
     }
 
@@ -446,24 +1615,62 @@ impl CourseAccessContract {
     /// # Events
     /// Emits a migration event upon successful completion
     pub fn migrate_access_data(env: Env, caller: Address, from_version: String, to_version: String) -> bool {
+        require_not_paused(&env);
         migrate_access_data(&env, caller, from_version, to_version)
     }
 
     /// Get migration status for the current contract
     ///
-    /// Returns information about the current migration status and any
-    /// pending migrations that need to be completed.
+    /// Reports the running code version, the latest recorded version and
+    /// whether a data migration is still pending after an upgrade.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
     ///
     /// # Returns
-    /// * `String` - Migration status information
-    pub fn get_migration_status(env: Env) -> String {
+    /// * `MigrationStatus` - Versions, pending flag and last migration result
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
         get_migration_status(&env)
     }
 
+    /// Upgrade the contract code
+    ///
+    /// Swaps the contract WASM for one previously uploaded to the network and
+    /// records the upgrade in the version history. Storage is kept as is;
+    /// run the migration entrypoints afterwards if the schema changed.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner
+    /// * `new_wasm_hash` - Hash of the uploaded WASM to switch to
+    /// * `new_version` - Version string of the new code, stored in the history
+    ///
+    /// # Panics
+    /// * If the caller is not the contract owner
+    ///
+    /// # Events
+    /// Emits a `("contract", "upgraded", new_version)` event with the from/to versions
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>, new_version: String) {
+        upgrade(&env, admin, new_wasm_hash, new_version)
+    }
+
     pub fn transfer_course(env: Env, course_id: String, from: Address, to: Address){
+        require_not_paused(&env);
         transfer_course_access(env, course_id, from, to)
     }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
 }
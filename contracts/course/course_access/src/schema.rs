@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec};
 
 /// Represents access permission for a user to a specific course.
 ///
@@ -38,33 +38,267 @@ pub struct UserCourses {
 pub enum DataKey {
     /// Key for storing course access: (course_id, user) -> CourseAccess
     CourseAccess(String, Address),
-    /// Key for storing user profile: user -> UserProfile
-    UserProfile(Address),
     /// Key for storing courses per user: user -> UserCourses
     UserCourses(Address),
-    /// Key for storing users per course: course_id -> CourseUsers
+    /// Legacy key for storing users per course in a single entry: course_id -> CourseUsers.
+    /// Moved into `CourseUsersShard` entries the first time the course's users are used.
     CourseUsers(String),
+    /// Key for the per-course prerequisite enforcement flag: course_id -> bool
+    EnforcePrerequisites(String),
+    /// Key for the storage TTL thresholds (instance storage)
+    TtlConfig,
+    /// Key for the maximum number of students of a course: course_id -> u32
+    CourseCapacity(String),
+    /// Key for the token holders of a course get access with: course_id -> TokenGate
+    TokenGate(String),
+    /// Key for how and when a user obtained access: (course_id, user) -> EnrollmentRecord
+    Enrollment(String, Address),
+    /// Key for the revocations of a user's access: (course_id, user) -> Vec<RevocationRecord>
+    RevocationHistory(String, Address),
+    /// Key for the addresses managing enrollments of a course: course_id -> Vec<Address>
+    CourseManagers(String),
+    /// Key for the seats an organization has used on a course: (org_id, course_id) -> u32
+    OrgSeatsUsed(u32, String),
+    /// Key for the organization whose seat a user occupies: (course_id, user) -> org_id
+    OrgEnrollment(String, Address),
+    /// Key for the number of announcements posted on a course: course_id -> u32
+    AnnouncementCount(String),
+    /// Key for an announcement: (course_id, announcement_id) -> Announcement
+    Announcement(String, u32),
+    /// Key for the number of vouchers issued so far, used as the last voucher ID
+    VoucherCount,
+    /// Key for a voucher: voucher_id -> Voucher
+    Voucher(u32),
+    /// Key for the voucher a code redeems: sha256(code) -> voucher_id
+    VoucherCode(BytesN<32>),
+    /// Key for the voucher counters of a course: course_id -> VoucherStats
+    VoucherStats(String),
+    /// Key for the IDs of the courses access was ever granted to, in grant order: Vec<String>
+    AccessCourses,
+    /// Key for the cursor a paged access import continues from: BackupCursor
+    BackupImportCursor,
+    /// Key for a shard of the users of a course: (course_id, shard) -> Vec<Address>
+    CourseUsersShard(String, u32),
+    /// Key for the number of users in a course's shards: course_id -> u32
+    CourseUserCount(String),
+    /// Key for a user's position in a course's shards: (course_id, user) -> u32
+    CourseUserSlot(String, Address),
+    /// Key for whether state-changing entrypoints are frozen (instance storage)
+    Paused,
+    /// Key for the contracts trusted to call privileged hooks (instance storage): Vec<Address>
+    TrustedContracts,
+    /// Key for the seconds a configuration proposal waits before it can be applied (instance storage): u64
+    ConfigDelay,
+    /// Key for the pending configuration proposal (instance storage): ConfigProposal
+    ConfigProposal,
+    /// Key for the account ownership is being transferred to (instance storage): Address
+    PendingOwner,
+    /// Key for the number of office-hour slots published so far, used as the last slot ID
+    SlotCount,
+    /// Key for an office-hour slot: slot_id -> OfficeHourSlot
+    OfficeHourSlot(u32),
 }
 
-/// Represents a user's profile information.
+/// A message posted to the students of a course.
 ///
-/// This struct contains all the personal and professional information
-/// that users can store on-chain as part of their profile.
+/// Only the hash of the body is stored on-chain; the body itself lives
+/// off-chain and is checked against the hash by clients.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
-pub struct UserProfile {
-    /// The user's full name
-    pub name: String,
-    /// The user's email address
-    pub email: String,
-    /// Optional profession or job title
-    pub profession: Option<String>,
-    /// Optional learning goals or objectives
-    pub goals: Option<String>,
-    /// The user's country of residence
-    pub country: String,
+pub struct Announcement {
+    /// Sequential identifier within the course, starting at 1
+    pub id: u32,
+    /// The course the announcement was posted to
+    pub course_id: String,
+    /// The creator, manager or admin who posted it
+    pub author: Address,
+    /// Short headline shown in notifications
+    pub title: String,
+    /// SHA-256 hash of the announcement body
+    pub body_hash: BytesN<32>,
+    /// Ledger timestamp of the post
+    pub posted_at: u64,
+}
+
+/// A one-student office-hour slot an instructor published for a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OfficeHourSlot {
+    pub id: u32,
+    pub course_id: String,
+    /// The creator, manager or admin holding the office hour
+    pub instructor: Address,
+    /// Ledger timestamp the slot starts
+    pub start: u64,
+    /// Ledger timestamp the slot ends
+    pub end: u64,
+    /// The student who booked the slot, `None` while it is free
+    pub booked_by: Option<Address>,
+}
+
+/// Who can redeem a voucher.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum VoucherTarget {
+    /// Only this address, redeeming by voucher ID
+    Recipient(Address),
+    /// Whoever presents the code with this SHA-256 hash
+    CodeHash(BytesN<32>),
+}
+
+/// How a student identifies the voucher they redeem.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum VoucherClaim {
+    /// A voucher issued to the student, by ID
+    Id(u32),
+    /// The code of a code voucher
+    Code(Bytes),
+}
+
+/// Free access to a course handed out by its instructor, e.g. as a scholarship.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Voucher {
+    pub id: u32,
+    pub course_id: String,
+    /// The creator, manager or admin who issued it
+    pub issuer: Address,
+    pub target: VoucherTarget,
+    /// Ledger timestamp after which the voucher can no longer be redeemed
+    pub expires_at: u64,
+    pub redeemed_by: Option<Address>,
+    pub redeemed_at: Option<u64>,
+}
+
+/// Voucher counters of a course.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct VoucherStats {
+    pub issued: u32,
+    pub redeemed: u32,
 }
 
+/// Why a user's access to a course was revoked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum RevocationReason {
+    /// No reason was given
+    Unspecified,
+    /// The purchase was refunded
+    Refund,
+    /// The user broke the platform or course rules
+    PolicyViolation,
+    /// The access was obtained fraudulently, e.g. a charged-back payment
+    Fraud,
+    /// The access period ended
+    Expired,
+    /// Any other reason, detailed in the note
+    Other,
+}
+
+/// One revocation of a user's access, kept as an audit and appeal trail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RevocationRecord {
+    /// Ledger timestamp of the revocation
+    pub revoked_at: u64,
+    pub reason: RevocationReason,
+    /// Hash of an off-chain note explaining the revocation
+    pub note_hash: Option<BytesN<32>>,
+}
+
+/// How a user obtained access to a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EnrollmentSource {
+    /// Access bought through the payment flow
+    Purchase,
+    /// Access granted by the course creator or an admin
+    Manual,
+    /// Access transferred from another user
+    Transfer,
+    /// Access given away in a promotion
+    Promotion,
+    /// The user enrolled in a free course themselves
+    SelfEnroll,
+    /// A seat purchased by the user's organization
+    Organization,
+    /// The user enrolled themselves while holding the course's gate token.
+    /// No longer recorded: gate holders have access without a grant
+    TokenGate,
+}
+
+/// Token whose holders have access to a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TokenGate {
+    /// Token or NFT contract exposing `balance(id) -> i128`
+    pub token: Address,
+    /// Balance a user must hold to have access
+    pub min_balance: i128,
+}
+
+/// Audit record stored alongside each access grant.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EnrollmentRecord {
+    /// Ledger timestamp of the grant
+    pub granted_at: u64,
+    /// Who granted the access; `None` for grants made through `grant_access`,
+    /// which doesn't identify its caller
+    pub granted_by: Option<Address>,
+    pub source: EnrollmentSource,
+}
+
+/// Owner and addresses of the contracts course access calls, returned by `get_config`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CourseAccessConfig {
+    /// Account set at initialization, allowed to change the configuration
+    pub owner: Address,
+    pub user_mgmt_addr: Address,
+    pub course_registry_addr: Address,
+    /// Progress contract used to check prerequisites, if set
+    pub progress_addr: Option<Address>,
+}
+
+/// Seconds a configuration proposal waits before it can be applied, until
+/// another delay is applied: one day.
+pub const DEFAULT_CONFIG_DELAY: u64 = 86_400;
+
+/// Longest delay a configuration proposal can set: 30 days.
+pub const MAX_CONFIG_DELAY: u64 = 2_592_000;
+
+/// Contract addresses waiting for their delay to pass before `apply_config`
+/// can set them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ConfigProposal {
+    pub user_mgmt_addr: Address,
+    pub course_registry_addr: Address,
+    /// Delay later proposals wait once this one is applied
+    pub config_delay: u64,
+    pub proposed_at: u64,
+    /// Earliest ledger timestamp `apply_config` accepts the proposal at
+    pub executable_at: u64,
+}
+
+/// Storage TTL configuration.
+///
+/// Entries whose TTL drops below `threshold` ledgers are extended to `extend_to` ledgers
+/// whenever they are read or written.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// Profile types of the user_management contract, which course access
+/// forwards profiles to; it keeps no profile store of its own.
+pub use skillcert_interfaces::user_management::{ProfileUpdateParams, UserProfile};
+
 /// Contains all users who have access to a specific course.
 ///
 /// This struct is used to efficiently query and return all users
@@ -78,8 +312,93 @@ pub struct CourseUsers {
     pub users: Vec<Address>,
 }
 
+/// Number of users stored in each shard of a course's user index.
+pub const COURSE_USERS_SHARD_SIZE: u32 = 100;
+
+/// Maximum number of entries in a page of `list_course_access_paged` and
+/// `list_user_courses_paged`.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// One page of the users with access to a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CourseUsersPage {
+    /// The unique identifier of the course
+    pub course: String,
+    /// Users of the page
+    pub users: Vec<Address>,
+    /// Number of users with access to the course
+    pub total: u32,
+    /// Whether users remain after this page
+    pub has_more: bool,
+}
+
+/// One page of the courses a user has access to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UserCoursesPage {
+    /// The address of the user
+    pub user: Address,
+    /// Course IDs of the page
+    pub courses: Vec<String>,
+    /// Number of courses the user has access to
+    pub total: u32,
+    /// Whether courses remain after this page
+    pub has_more: bool,
+}
+
+/// Maximum number of courses in a page of a paged access backup.
+pub const MAX_BACKUP_PAGE_SIZE: u32 = 20;
+
+/// Position in a paged backup.
+///
+/// `checksum` chains the SHA-256 of every page before `offset`, so pages
+/// that were altered, dropped or reordered are rejected on import. The first
+/// page starts at offset 0 with an all-zero checksum.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BackupCursor {
+    /// Position in the list of courses with access grants
+    pub offset: u32,
+    pub checksum: BytesN<32>,
+}
+
+/// A user with access to a course, and how they obtained it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UserAccessBackup {
+    pub user: Address,
+    pub enrollment: EnrollmentRecord,
+}
+
+/// One chunk of a paged access backup, holding the users of each course.
+///
+/// The course list of every user is rebuilt from these on import.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessBackupPage {
+    /// Cursor the page was exported from
+    pub cursor: BackupCursor,
+    /// Course IDs of the page
+    pub courses: Vec<String>,
+    /// Users of each course, in the order of `courses`
+    pub users: Vec<Vec<UserAccessBackup>>,
+    /// Cursor of the next page; its checksum covers this page
+    pub next_cursor: BackupCursor,
+    pub has_more: bool,
+    /// Number of courses in the whole backup
+    pub total_courses: u32,
+}
+
 /// Global configuration key for storing the user management contract address
 pub const KEY_USER_MGMT_ADDR: &str = "USER_MGMT_ADDR";
 
 /// Global configuration key for storing the course registry contract address
 pub const KEY_COURSE_REG_ADDR: &str = "COURSE_REGISTRY_ADDR";
+
+/// Global configuration key for storing the progress contract address
+pub const KEY_PROGRESS_ADDR: &str = "PROGRESS_ADDR";
+
+/// Storage TTL defaults, in ledgers (about 5 seconds each)
+pub const DEFAULT_TTL_THRESHOLD: u32 = 120_960; // ~7 days
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 518_400; // ~30 days
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, panic_with_error, Env};
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -60,6 +60,187 @@ pub enum Error {
     CourseRateLimitNotConfigured = 58,
 }
 
-pub fn handle_error(env: &Env, error: Error) -> ! {
+/// Errors added once `Error` reached the 50-variant limit of `#[contracterror]`.
+/// Codes start at 100 so they never collide with `Error`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RegistryError {
+    // Category errors
+    CategoryNotFound = 100,
+    SameCategory = 101,
+    CategoryInUse = 102,
+    // Batch errors
+    InvalidBatchSize = 103,
+    // Draft errors
+    DraftNotFound = 104,
+    // Stats errors
+    InvalidRating = 105,
+    // Collection errors
+    CollectionTooLarge = 106,
+    // Price errors
+    PriceOutOfRange = 107,
+    // Rate limit errors
+    ActionRateLimitExceeded = 108,
+    // Deletion errors
+    CourseHasEnrollments = 109,
+    // Goal errors
+    InvalidGoalOrder = 110,
+    // Discussion errors
+    ThreadNotFound = 111,
+    NoCourseAccess = 112,
+    InvalidThreadTitle = 113,
+    // Backup errors
+    InvalidBackupPage = 114,
+    BackupChecksumMismatch = 115,
+    // Lifecycle errors
+    InvalidStatusReason = 116,
+    // Soft-delete errors
+    CourseNotDeleted = 117,
+    RecoveryWindowExpired = 118,
+    // Pause errors
+    ContractPaused = 119,
+    // Initialization errors
+    NotInitialized = 120,
+    // Module prerequisite errors
+    ModuleCourseMismatch = 121,
+    // Completion criteria errors
+    InvalidCompletionCriteria = 122,
+    // Drip schedule errors
+    InvalidReleaseSchedule = 123,
+    // Content limit errors
+    TitleTooLong = 124,
+    DescriptionTooLong = 125,
+    // Migration errors
+    MigrationInProgress = 126,
+    NoMigrationInProgress = 127,
+    MigrationNotRegistered = 128,
+    // TTL errors
+    InvalidTtlConfig = 129,
+}
+
+pub fn handle_error(env: &Env, error: impl Into<soroban_sdk::Error>) -> ! {
     panic_with_error!(env, error);
 }
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "OnlyCreatorCanAddGoals: Only the course creator can add goals",
+        2 => "EmptyGoalContent: The goal content is empty",
+        3 => "CourseIdNotExist: The course does not exist",
+        4 => "OnlyCreatorCanArchive: Only the course creator can archive the course",
+        5 => "CourseAlreadyArchived: The course is already archived",
+        6 => "Unauthorized: The caller is not allowed to do this",
+        7 => "NameRequired: A name is required",
+        8 => "EmptyCourseTitle: The course title is empty",
+        9 => "InvalidPrice: The price is not valid",
+        10 => "DuplicateCourseTitle: A course with this title already exists",
+        11 => "DuplicateCourseId: A course with this ID already exists",
+        12 => "OnlyCreatorCanEditPrereqs: Only the course creator can edit prerequisites",
+        13 => "PrereqCourseNotFound: The prerequisite course does not exist",
+        14 => "SelfPrerequisite: A course cannot be its own prerequisite",
+        15 => "CircularDependency: The prerequisites would form a cycle",
+        16 => "EmptyCourseId: The course ID is empty",
+        17 => "CourseNotFound: The course does not exist",
+        18 => "EmptyNewGoalContent: The new goal content is empty",
+        19 => "EmptyGoalId: The goal ID is empty",
+        20 => "GoalCourseMismatch: The goal does not belong to the course",
+        21 => "ModuleNotFound: The module does not exist",
+        22 => "EmptyModuleId: The module ID is empty",
+        23 => "PrereqNotInList: The course is not a prerequisite",
+        24 => "InvalidModulePosition: The module position is not valid",
+        25 => "InvalidModuleTitle: The module title is not valid",
+        26 => "InvalidCourseDescription: The course description is not valid",
+        27 => "InvalidCategoryName: The category name is not valid",
+        28 => "EmptyCategory: The category is empty",
+        29 => "InvalidTitleLength: The title length is out of range",
+        43 => "InvalidLanguageLength: The language length is out of range",
+        44 => "InvalidThumbnailUrlLength: The thumbnail URL length is out of range",
+        45 => "InvalidDurationValue: The duration is out of range",
+        46 => "InvalidLimitValue: The limit is out of range",
+        47 => "InvalidOffsetValue: The offset is out of range",
+        48 => "InvalidGoalContent: The goal content is not valid",
+        49 => "InvalidPrerequisiteId: The prerequisite ID is not valid",
+        50 => "EmptyPrerequisiteList: The prerequisite list is empty",
+        51 => "TooManyPrerequisites: The course has too many prerequisites",
+        52 => "EmptyPrerequisiteId: The prerequisite ID is empty",
+        53 => "InvalidCourseId: The course ID is invalid",
+        54 => "InvalidPrice100: The price is too large",
+        55 => "AlreadyInitialized: The contract is already initialized",
+        56 => "DuplicatePrerequisite: The prerequisite is listed twice",
+        57 => "CourseRateLimitExceeded: Too many courses created; try again later",
+        58 => "CourseRateLimitNotConfigured: Course creation rate limits are not configured",
+        100 => "CategoryNotFound: The category does not exist",
+        101 => "SameCategory: The source and target categories are the same",
+        102 => "CategoryInUse: The category is still used by courses",
+        103 => "InvalidBatchSize: The batch size is out of range",
+        104 => "DraftNotFound: The course has no draft",
+        105 => "InvalidRating: The rating is out of range",
+        106 => "CollectionTooLarge: The collection is full",
+        107 => "PriceOutOfRange: The price is outside the allowed range",
+        108 => "ActionRateLimitExceeded: Too many actions; try again later",
+        109 => "CourseHasEnrollments: The course still has enrolled users",
+        110 => "InvalidGoalOrder: The goal order does not match the course goals",
+        111 => "ThreadNotFound: The discussion thread does not exist",
+        112 => "NoCourseAccess: The user has no access to the course",
+        113 => "InvalidThreadTitle: The thread title is not valid",
+        114 => "InvalidBackupPage: The backup page is malformed or out of order",
+        115 => "BackupChecksumMismatch: The backup checksum does not match its data",
+        116 => "InvalidStatusReason: The status change reason is too long",
+        117 => "CourseNotDeleted: The course is not deleted",
+        118 => "RecoveryWindowExpired: The recovery window of the deleted course has ended",
+        119 => "ContractPaused: The contract is paused",
+        120 => "NotInitialized: The contract is not initialized",
+        121 => "ModuleCourseMismatch: The prerequisite module belongs to another course",
+        122 => "InvalidCompletionCriteria: The completion criteria name a module twice, a module of another course or a quiz score above 100",
+        401 => "UnauthorizedCaller: The caller is not allowed to do this",
+        402 => "UnauthorizedCourseAccess: The caller cannot access this course",
+        403 => "InvalidAdminOperation: The admin operation is not allowed",
+        404 => "EmptyModuleTitle: The module title is empty",
+        405 => "DuplicateModulePosition: Another module already has this position",
+        123 => "InvalidReleaseSchedule: The release schedule names a module twice or a module of another course",
+        124 => "TitleTooLong: The title is longer than the configured maximum title length",
+        125 => "DescriptionTooLong: The description is longer than the configured maximum description length",
+        126 => "MigrationInProgress: A migration is already in progress",
+        127 => "NoMigrationInProgress: No migration is in progress",
+        128 => "MigrationNotRegistered: No migration is registered for these versions",
+        129 => "InvalidTtlConfig: The TTL threshold must be positive and below the extension",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+
+    #[test]
+    fn test_describe_error_covers_every_code() {
+        let env = Env::default();
+        let client = CourseRegistryClient::new(&env, &env.register(CourseRegistry, ()));
+
+        for code in 0..500 {
+            let error = soroban_sdk::Error::from_contract_error(code);
+            let name = if let Ok(error) = Error::try_from(error) {
+                std::format!("{:?}", error)
+            } else if let Ok(error) = RegistryError::try_from(error) {
+                std::format!("{:?}", error)
+            } else {
+                std::string::String::from("Unknown")
+            };
+            let description = client.describe_error(&code);
+            let mut buf = [0u8; 128];
+            let buf = &mut buf[..description.len() as usize];
+            description.copy_into_slice(buf);
+            let description = std::str::from_utf8(buf).unwrap();
+            assert!(description.starts_with(&std::format!("{}: ", name)), "{}", description);
+        }
+    }
+}
@@ -0,0 +1,659 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! Every event is published with the topics `(entity, action, id)`, e.g.
+//! `("course", "created", course_id)` or `("module", "removed", module_id)`,
+//! and a typed payload whose first field is `version`. Indexers can filter
+//! on the first two topics and decode the payload by its version.
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::schema::{
+    CompletionCriteria, Course, CourseGoal, CourseModule, CourseStatus, DiscussionThread, ModuleRelease,
+    StatusChange, ThreadReply,
+};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const COURSE: Symbol = symbol_short!("course");
+const MODULE: Symbol = symbol_short!("module");
+const GOAL: Symbol = symbol_short!("goal");
+const PREREQUISITES: Symbol = symbol_short!("prereqs");
+const CATEGORY: Symbol = symbol_short!("category");
+const CONTRACT: Symbol = symbol_short!("contract");
+const MIGRATION: Symbol = symbol_short!("migration");
+const COLLECTION: Symbol = symbol_short!("collect");
+const THREAD: Symbol = symbol_short!("thread");
+
+const CREATED: Symbol = symbol_short!("created");
+const UPDATED: Symbol = symbol_short!("updated");
+const DELETED: Symbol = symbol_short!("deleted");
+const ARCHIVED: Symbol = symbol_short!("archived");
+const ADDED: Symbol = symbol_short!("added");
+const EDITED: Symbol = symbol_short!("edited");
+const REMOVED: Symbol = symbol_short!("removed");
+const REORDERED: Symbol = symbol_short!("reordered");
+const UPGRADED: Symbol = symbol_short!("upgraded");
+const MERGED: Symbol = symbol_short!("merged");
+const BATCH: Symbol = symbol_short!("batch");
+const DRAFTED: Symbol = symbol_short!("drafted");
+const DISCARDED: Symbol = symbol_short!("discarded");
+const LOCALIZED: Symbol = symbol_short!("localized");
+const STARTED: Symbol = symbol_short!("started");
+const FINISHED: Symbol = symbol_short!("finished");
+const REPLIED: Symbol = symbol_short!("replied");
+const STATUS: Symbol = symbol_short!("status");
+const PAUSED: Symbol = symbol_short!("paused");
+const UNPAUSED: Symbol = symbol_short!("unpaused");
+const CRITERIA: Symbol = symbol_short!("criteria");
+const DRIP: Symbol = symbol_short!("drip");
+
+/// Payload of `("course", "created", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseCreatedEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub creator: Address,
+    pub title: String,
+    pub price: u128,
+    pub category: Option<String>,
+}
+
+/// Payload of `("course", "batch", creator)`, published once per
+/// `create_courses_batch` instead of one `created` event per course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoursesBatchCreatedEvent {
+    pub version: u32,
+    pub creator: Address,
+    pub course_ids: Vec<String>,
+}
+
+/// Payload of the `course` events `updated`, `archived`,
+/// `drafted`, `discarded` and `localized`,
+/// and of `("module", "reordered", course_id)` and `("goal", "reordered", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub actor: Address,
+}
+
+/// Payload of `("course", "deleted", course_id)`, published once per deletion
+/// with the amount of dependent data removed along with the course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseDeletedEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub actor: Address,
+    pub modules_removed: u32,
+    pub goals_removed: u32,
+    pub prerequisites_removed: u32,
+    /// Other courses that listed the deleted course as a prerequisite
+    pub dependents_updated: u32,
+}
+
+/// Payload of the `module` events `added`, `edited` and `removed`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub module_id: String,
+    pub title: String,
+    pub position: u32,
+}
+
+/// Payload of `("module", "prereqs", module_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModulePrerequisitesEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub module_id: String,
+    /// The module's prerequisites after the change
+    pub prerequisites: Vec<String>,
+}
+
+/// Payload of `("course", "criteria", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompletionCriteriaEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub actor: Address,
+    /// The course's criteria after the change
+    pub criteria: CompletionCriteria,
+}
+
+/// Payload of `("course", "drip", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReleaseScheduleEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub actor: Address,
+    /// The course's schedule after the change
+    pub schedule: Vec<ModuleRelease>,
+}
+
+/// Payload of the `goal` events `added`, `edited` and `removed`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GoalEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub goal_id: String,
+    pub content: String,
+}
+
+/// Payload of the `prereqs` events `added`, `edited` and `removed`.
+///
+/// `prerequisites` holds the course IDs that were added, the new full list,
+/// or the removed ID respectively.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrerequisitesEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub prerequisites: Vec<String>,
+}
+
+/// Payload of `("thread", "created", thread_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadCreatedEvent {
+    pub version: u32,
+    pub thread_id: u32,
+    pub course_id: String,
+    pub module_id: String,
+    pub author: Address,
+    pub content_hash: BytesN<32>,
+}
+
+/// Payload of `("thread", "replied", thread_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadRepliedEvent {
+    pub version: u32,
+    pub thread_id: u32,
+    pub index: u32,
+    pub author: Address,
+    pub content_hash: BytesN<32>,
+}
+
+/// Payload of `("contract", "upgraded", to_version)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgradedEvent {
+    pub version: u32,
+    pub from_version: String,
+    pub to_version: String,
+    pub wasm_hash: BytesN<32>,
+    pub by: Address,
+}
+
+/// Payload of `("contract", "paused" | "unpaused", admin)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseEvent {
+    pub version: u32,
+    pub admin: Address,
+}
+
+/// Payload of `("category", "updated" | "deleted", category_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoryEvent {
+    pub version: u32,
+    pub category_id: u128,
+    pub actor: Address,
+}
+
+/// Payload of `("category", "merged", into_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoryMergedEvent {
+    pub version: u32,
+    pub from_id: u128,
+    pub into_id: u128,
+    pub actor: Address,
+    /// Number of courses moved to the target category
+    pub reassigned: u32,
+}
+
+/// Payload of `("collect", "updated", name)`, carrying the new course list.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectionEvent {
+    pub version: u32,
+    pub name: String,
+    pub actor: Address,
+    pub course_ids: Vec<String>,
+}
+
+/// Payload of `("migration", "started" | "finished", to_version)`.
+/// `processed` is 0 when the migration starts.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrationEvent {
+    pub version: u32,
+    pub from_version: String,
+    pub to_version: String,
+    pub by: Address,
+    pub processed: u32,
+}
+
+pub fn course_created(env: &Env, course: &Course) {
+    env.events().publish(
+        (COURSE, CREATED, course.id.clone()),
+        CourseCreatedEvent {
+            version: EVENT_VERSION,
+            course_id: course.id.clone(),
+            creator: course.creator.clone(),
+            title: course.title.clone(),
+            price: course.price,
+            category: course.category.clone(),
+        },
+    );
+}
+
+pub fn courses_batch_created(env: &Env, creator: &Address, course_ids: &Vec<String>) {
+    env.events().publish(
+        (COURSE, BATCH, creator.clone()),
+        CoursesBatchCreatedEvent {
+            version: EVENT_VERSION,
+            creator: creator.clone(),
+            course_ids: course_ids.clone(),
+        },
+    );
+}
+
+pub fn course_updated(env: &Env, course_id: &String, actor: &Address) {
+    publish_course_event(env, UPDATED, course_id, actor);
+}
+
+pub fn course_deleted(env: &Env, summary: &CourseDeletedEvent) {
+    env.events()
+        .publish((COURSE, DELETED, summary.course_id.clone()), summary.clone());
+}
+
+pub fn course_archived(env: &Env, course_id: &String, actor: &Address) {
+    publish_course_event(env, ARCHIVED, course_id, actor);
+}
+
+/// Payload of `("course", "status", course_id)`, published on every
+/// publish, unpublish and archive.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseStatusChangedEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub old_status: CourseStatus,
+    pub new_status: CourseStatus,
+    pub actor: Address,
+    pub reason: Option<String>,
+    pub changed_at: u64,
+}
+
+pub fn course_status_changed(env: &Env, course_id: &String, change: &StatusChange) {
+    env.events().publish(
+        (COURSE, STATUS, course_id.clone()),
+        CourseStatusChangedEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            old_status: change.old_status.clone(),
+            new_status: change.new_status.clone(),
+            actor: change.actor.clone(),
+            reason: change.reason.clone(),
+            changed_at: change.changed_at,
+        },
+    );
+}
+
+pub fn course_draft_saved(env: &Env, course_id: &String, actor: &Address) {
+    publish_course_event(env, DRAFTED, course_id, actor);
+}
+
+pub fn course_draft_discarded(env: &Env, course_id: &String, actor: &Address) {
+    publish_course_event(env, DISCARDED, course_id, actor);
+}
+
+pub fn course_localized(env: &Env, course_id: &String, actor: &Address) {
+    publish_course_event(env, LOCALIZED, course_id, actor);
+}
+
+pub fn module_added(env: &Env, module: &CourseModule) {
+    publish_module_event(env, ADDED, module);
+}
+
+pub fn module_edited(env: &Env, module: &CourseModule) {
+    publish_module_event(env, EDITED, module);
+}
+
+pub fn module_removed(env: &Env, module: &CourseModule) {
+    publish_module_event(env, REMOVED, module);
+}
+
+pub fn modules_reordered(env: &Env, course_id: &String, actor: &Address) {
+    env.events().publish(
+        (MODULE, REORDERED, course_id.clone()),
+        CourseEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+pub fn thread_created(env: &Env, thread: &DiscussionThread) {
+    env.events().publish(
+        (THREAD, CREATED, thread.id),
+        ThreadCreatedEvent {
+            version: EVENT_VERSION,
+            thread_id: thread.id,
+            course_id: thread.course_id.clone(),
+            module_id: thread.module_id.clone(),
+            author: thread.author.clone(),
+            content_hash: thread.content_hash.clone(),
+        },
+    );
+}
+
+pub fn thread_replied(env: &Env, reply: &ThreadReply) {
+    env.events().publish(
+        (THREAD, REPLIED, reply.thread_id),
+        ThreadRepliedEvent {
+            version: EVENT_VERSION,
+            thread_id: reply.thread_id,
+            index: reply.index,
+            author: reply.author.clone(),
+            content_hash: reply.content_hash.clone(),
+        },
+    );
+}
+
+pub fn goals_reordered(env: &Env, course_id: &String, actor: &Address) {
+    env.events().publish(
+        (GOAL, REORDERED, course_id.clone()),
+        CourseEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+pub fn goal_added(env: &Env, goal: &CourseGoal) {
+    publish_goal_event(env, ADDED, goal);
+}
+
+pub fn goal_edited(env: &Env, goal: &CourseGoal) {
+    publish_goal_event(env, EDITED, goal);
+}
+
+pub fn goal_removed(env: &Env, goal: &CourseGoal) {
+    publish_goal_event(env, REMOVED, goal);
+}
+
+pub fn prerequisites_added(env: &Env, course_id: &String, added: &Vec<String>) {
+    publish_prerequisites_event(env, ADDED, course_id, added);
+}
+
+pub fn prerequisites_edited(env: &Env, course_id: &String, prerequisites: &Vec<String>) {
+    publish_prerequisites_event(env, EDITED, course_id, prerequisites);
+}
+
+pub fn prerequisite_removed(env: &Env, course_id: &String, removed: &String) {
+    let removed: Vec<String> = Vec::from_array(env, [removed.clone()]);
+    publish_prerequisites_event(env, REMOVED, course_id, &removed);
+}
+
+pub fn contract_upgraded(
+    env: &Env,
+    from_version: &String,
+    to_version: &String,
+    wasm_hash: &BytesN<32>,
+    by: &Address,
+) {
+    env.events().publish(
+        (CONTRACT, UPGRADED, to_version.clone()),
+        ContractUpgradedEvent {
+            version: EVENT_VERSION,
+            from_version: from_version.clone(),
+            to_version: to_version.clone(),
+            wasm_hash: wasm_hash.clone(),
+            by: by.clone(),
+        },
+    );
+}
+
+pub fn contract_paused(env: &Env, admin: &Address, paused: bool) {
+    let action: Symbol = if paused { PAUSED } else { UNPAUSED };
+    env.events().publish(
+        (CONTRACT, action, admin.clone()),
+        PauseEvent {
+            version: EVENT_VERSION,
+            admin: admin.clone(),
+        },
+    );
+}
+
+pub fn category_updated(env: &Env, category_id: u128, actor: &Address) {
+    publish_category_event(env, UPDATED, category_id, actor);
+}
+
+pub fn category_deleted(env: &Env, category_id: u128, actor: &Address) {
+    publish_category_event(env, DELETED, category_id, actor);
+}
+
+pub fn category_merged(env: &Env, from_id: u128, into_id: u128, actor: &Address, reassigned: u32) {
+    env.events().publish(
+        (CATEGORY, MERGED, into_id),
+        CategoryMergedEvent {
+            version: EVENT_VERSION,
+            from_id,
+            into_id,
+            actor: actor.clone(),
+            reassigned,
+        },
+    );
+}
+
+pub fn collection_updated(env: &Env, name: &String, actor: &Address, course_ids: &Vec<String>) {
+    env.events().publish(
+        (COLLECTION, UPDATED, name.clone()),
+        CollectionEvent {
+            version: EVENT_VERSION,
+            name: name.clone(),
+            actor: actor.clone(),
+            course_ids: course_ids.clone(),
+        },
+    );
+}
+
+pub fn migration_started(env: &Env, from_version: &String, to_version: &String, by: &Address) {
+    publish_migration_event(env, STARTED, from_version, to_version, by, 0);
+}
+
+pub fn migration_finished(
+    env: &Env,
+    from_version: &String,
+    to_version: &String,
+    by: &Address,
+    processed: u32,
+) {
+    publish_migration_event(env, FINISHED, from_version, to_version, by, processed);
+}
+
+fn publish_migration_event(
+    env: &Env,
+    action: Symbol,
+    from_version: &String,
+    to_version: &String,
+    by: &Address,
+    processed: u32,
+) {
+    env.events().publish(
+        (MIGRATION, action, to_version.clone()),
+        MigrationEvent {
+            version: EVENT_VERSION,
+            from_version: from_version.clone(),
+            to_version: to_version.clone(),
+            by: by.clone(),
+            processed,
+        },
+    );
+}
+
+fn publish_category_event(env: &Env, action: Symbol, category_id: u128, actor: &Address) {
+    env.events().publish(
+        (CATEGORY, action, category_id),
+        CategoryEvent {
+            version: EVENT_VERSION,
+            category_id,
+            actor: actor.clone(),
+        },
+    );
+}
+
+fn publish_course_event(env: &Env, action: Symbol, course_id: &String, actor: &Address) {
+    env.events().publish(
+        (COURSE, action, course_id.clone()),
+        CourseEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+pub fn module_prerequisites_set(env: &Env, module: &CourseModule, prerequisites: &Vec<String>) {
+    env.events().publish(
+        (MODULE, PREREQUISITES, module.id.clone()),
+        ModulePrerequisitesEvent {
+            version: EVENT_VERSION,
+            course_id: module.course_id.clone(),
+            module_id: module.id.clone(),
+            prerequisites: prerequisites.clone(),
+        },
+    );
+}
+
+pub fn completion_criteria_set(
+    env: &Env,
+    course_id: &String,
+    actor: &Address,
+    criteria: &CompletionCriteria,
+) {
+    env.events().publish(
+        (COURSE, CRITERIA, course_id.clone()),
+        CompletionCriteriaEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            actor: actor.clone(),
+            criteria: criteria.clone(),
+        },
+    );
+}
+
+pub fn release_schedule_set(
+    env: &Env,
+    course_id: &String,
+    actor: &Address,
+    schedule: &Vec<ModuleRelease>,
+) {
+    env.events().publish(
+        (COURSE, DRIP, course_id.clone()),
+        ReleaseScheduleEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            actor: actor.clone(),
+            schedule: schedule.clone(),
+        },
+    );
+}
+
+fn publish_module_event(env: &Env, action: Symbol, module: &CourseModule) {
+    env.events().publish(
+        (MODULE, action, module.id.clone()),
+        ModuleEvent {
+            version: EVENT_VERSION,
+            course_id: module.course_id.clone(),
+            module_id: module.id.clone(),
+            title: module.title.clone(),
+            position: module.position,
+        },
+    );
+}
+
+fn publish_goal_event(env: &Env, action: Symbol, goal: &CourseGoal) {
+    env.events().publish(
+        (GOAL, action, goal.goal_id.clone()),
+        GoalEvent {
+            version: EVENT_VERSION,
+            course_id: goal.course_id.clone(),
+            goal_id: goal.goal_id.clone(),
+            content: goal.content.clone(),
+        },
+    );
+}
+
+fn publish_prerequisites_event(
+    env: &Env,
+    action: Symbol,
+    course_id: &String,
+    prerequisites: &Vec<String>,
+) {
+    env.events().publish(
+        (PREREQUISITES, action, course_id.clone()),
+        PrerequisitesEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            prerequisites: prerequisites.clone(),
+        },
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::{IntoVal, TryFromVal, Val};
+
+    #[test]
+    fn test_course_created_event_shape() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(&creator, &String::from_str(&env, "Event Course"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let expected_topics: Vec<Val> = (COURSE, CREATED, course.id.clone()).into_val(&env);
+        assert_eq!(topics, expected_topics);
+
+        let payload = CourseCreatedEvent::try_from_val(&env, &data).unwrap();
+        assert_eq!(
+            payload,
+            CourseCreatedEvent {
+                version: EVENT_VERSION,
+                course_id: course.id,
+                creator,
+                title: String::from_str(&env, "Event Course"),
+                price: 1000,
+                category: None,
+            }
+        );
+    }
+}
@@ -1,38 +1,47 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol, IntoVal};
+use skillcert_interfaces::course_access::CourseAccessClient;
+use skillcert_interfaces::user_management::UserManagementClient;
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
-use crate::error::{handle_error, Error};
-use crate::schema::Course;
-use super::course_rate_limit_utils::initialize_course_rate_limit_config;
+use crate::error::{handle_error, Error, RegistryError};
+use crate::schema::{Course, CourseRegistryConfig, DataKey, Permission, RegistryConfig};
+use super::contract_versioning::record_version;
+use super::course_rate_limit_utils::{initialize_course_rate_limit_config, update_course_rate_limit_config};
+use super::ttl::validate_ttl_config;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
 const INIT_ACCESS_CONTROL_EVENT: Symbol = symbol_short!("initAcCtr");
 const UPDATE_USER_MNGMT_EVENT: Symbol = symbol_short!("upUsrMgt");
+const SET_PERMISSIONLESS_EVENT: Symbol = symbol_short!("setPrmLss");
+const SET_COURSE_ACCESS_EVENT: Symbol = symbol_short!("setCrsAcc");
 
 const KEY_USER_MGMT_ADDR: &str = "user_mgmt_addr";
 const KEY_OWNER: &str = "owner";
 
+/// Returns a client for the configured user management contract, or `None` before `initialize`.
+fn try_user_management(env: &Env) -> Option<UserManagementClient<'_>> {
+    let user_mgmt_addr: Address = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,))?;
+    Some(UserManagementClient::new(env, &user_mgmt_addr))
+}
+
 /// Check if a user is an admin by querying the user management contract
 pub fn is_admin(env: &Env, who: &Address) -> bool {
-    // Get user management contract address
-    let user_mgmt_addr: Option<Address> = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,));
-
-    match user_mgmt_addr {
-        Some(addr) => {
-            // Cross-contract call to check admin status
-            env.invoke_contract(
-                &addr,
-                &Symbol::new(env, "is_admin"),
-                (who.clone(),).into_val(env),
-            )
-        }
+    match try_user_management(env) {
+        // Cross-contract call to check admin status
+        Some(user_management) => user_management.is_admin(who),
         None => false, // If user management contract isn't configured, no admins
     }
 }
 
+/// Check if an address is the contract owner set at initialization
+pub fn is_owner(env: &Env, who: &Address) -> bool {
+    let owner: Option<Address> = env.storage().instance().get(&(KEY_OWNER,));
+    owner.as_ref() == Some(who)
+}
+
 /// Check if a user is the creator of a specific course
 pub fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
     let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
@@ -71,11 +80,142 @@ pub fn initialize(env: &Env, owner: &Address, user_mgmt_addr: &Address) {
     
     // Initialize rate limiting configuration
     initialize_course_rate_limit_config(env);
-    
+
+    record_version(env, owner, String::from_str(env, crate::VERSION));
+
     env.events()
         .publish((INIT_ACCESS_CONTROL_EVENT,), (owner, user_mgmt_addr));
 }
 
+/// Initialize the contract with an admin, the user management contract and
+/// its configuration. The admin becomes the contract owner.
+pub fn initialize_registry(
+    env: &Env,
+    admin: &Address,
+    user_mgmt_addr: &Address,
+    config: &RegistryConfig,
+) {
+    admin.require_auth();
+
+    validate_ttl_config(env, &config.ttl);
+    if config.rate_limit.window_seconds == 0 || config.rate_limit.max_courses_per_window == 0 {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    initialize(env, admin, user_mgmt_addr);
+
+    env.storage().instance().set(&DataKey::TtlConfig, &config.ttl);
+    update_course_rate_limit_config(env, config.rate_limit.clone());
+}
+
+/// Whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&(KEY_OWNER,))
+}
+
+/// Returns the owner and the addresses of the contracts the registry calls,
+/// panicking with `NotInitialized` before `initialize`.
+pub fn get_config(env: &Env) -> CourseRegistryConfig {
+    let owner: Address = env
+        .storage()
+        .instance()
+        .get(&(KEY_OWNER,))
+        .unwrap_or_else(|| handle_error(env, RegistryError::NotInitialized));
+    CourseRegistryConfig {
+        owner,
+        user_mgmt_addr: env.storage().instance().get(&(KEY_USER_MGMT_ADDR,)).unwrap(),
+        course_access_addr: get_course_access_contract(env),
+    }
+}
+
+/// Require that the caller is the contract owner or an admin of the
+/// configured user management contract
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    if !is_owner(env, caller) && !is_admin(env, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Whether anyone can create courses; on until an admin turns it off.
+pub fn is_permissionless_creation(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::PermissionlessCreation)
+        .unwrap_or(true)
+}
+
+/// Turns permissionless course creation on or off (owner or admin only).
+///
+/// When off, creators need the `CreateCourses` permission in the user
+/// management contract.
+pub fn set_permissionless_creation(env: &Env, admin: &Address, enabled: bool) {
+    require_admin(env, admin);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PermissionlessCreation, &enabled);
+
+    env.events()
+        .publish((SET_PERMISSIONLESS_EVENT,), (admin.clone(), enabled));
+}
+
+/// Require that `creator` may create courses.
+///
+/// Always passes in permissionless mode; otherwise asks the user management
+/// contract whether the creator holds the `CreateCourses` permission.
+pub fn require_create_permission(env: &Env, creator: &Address) {
+    if is_permissionless_creation(env) {
+        return;
+    }
+
+    let allowed: bool = match try_user_management(env) {
+        Some(user_management) => {
+            user_management.has_permission(creator, &Permission::CreateCourses)
+        }
+        None => false,
+    };
+    if !allowed {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Sets the course access contract asked for active enrollments (owner or admin only).
+pub fn set_course_access_contract(env: &Env, admin: &Address, course_access_addr: &Address) {
+    require_admin(env, admin);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::CourseAccessContract, course_access_addr);
+
+    env.events()
+        .publish((SET_COURSE_ACCESS_EVENT,), (admin.clone(), course_access_addr.clone()));
+}
+
+/// Returns the configured course access contract, if any.
+pub fn get_course_access_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::CourseAccessContract)
+}
+
+/// Number of users with access to a course, asked from the course access
+/// contract. 0 while no course access contract is configured.
+pub fn active_access_count(env: &Env, course_id: &String) -> u32 {
+    match get_course_access_contract(env) {
+        Some(addr) => CourseAccessClient::new(env, &addr).get_course_access_count(course_id),
+        None => 0,
+    }
+}
+
+/// Whether `user` has access to a course according to the course access
+/// contract. `false` while no course access contract is configured.
+pub fn has_course_access(env: &Env, course_id: &String, user: &Address) -> bool {
+    match get_course_access_contract(env) {
+        Some(addr) => CourseAccessClient::new(env, &addr).has_access(course_id, user),
+        None => false,
+    }
+}
+
 /// Update the user management contract address
 /// Only the contract owner can perform this update
 pub fn update_user_mgmt_address(env: &Env, caller: &Address, new_addr: &Address) {
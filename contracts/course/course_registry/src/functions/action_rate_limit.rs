@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Per-address rate limits for spam-prone actions.
+//!
+//! Ledgers are grouped in fixed windows of `window_ledgers`. Each address has
+//! one counter per action and window, kept in temporary storage so it expires
+//! on its own once the window is over.
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::functions::access_control::require_admin;
+use crate::schema::{
+    ActionRateLimit, DataKey, RateLimitedAction, DEFAULT_ACTION_RATE_WINDOW_LEDGERS,
+    DEFAULT_MAX_CATEGORY_CREATIONS_PER_WINDOW,
+};
+
+const SET_ACTION_RATE_LIMIT_EVENT: Symbol = symbol_short!("setActRL");
+
+/// Returns the limit of an action, `None` if the action is not limited.
+///
+/// Category creation is limited by default. Reviews are limited per student
+/// by the feedback contract, since it reports the ratings of every student.
+pub fn get_action_rate_limit(env: &Env, action: RateLimitedAction) -> Option<ActionRateLimit> {
+    let configured: Option<Option<ActionRateLimit>> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ActionRateLimit(action.clone()));
+    match configured {
+        Some(limit) => limit,
+        None => match action {
+            RateLimitedAction::CategoryCreation => Some(ActionRateLimit {
+                window_ledgers: DEFAULT_ACTION_RATE_WINDOW_LEDGERS,
+                max_actions: DEFAULT_MAX_CATEGORY_CREATIONS_PER_WINDOW,
+            }),
+        },
+    }
+}
+
+/// Sets the limit of an action, `None` to lift it. Owner or admin only.
+pub fn set_action_rate_limit(
+    env: &Env,
+    admin: Address,
+    action: RateLimitedAction,
+    limit: Option<ActionRateLimit>,
+) {
+    require_admin(env, &admin);
+
+    if let Some(ref limit) = limit {
+        if limit.window_ledgers == 0 || limit.max_actions == 0 {
+            handle_error(env, Error::InvalidLimitValue)
+        }
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ActionRateLimit(action.clone()), &limit);
+
+    env.events()
+        .publish((SET_ACTION_RATE_LIMIT_EVENT, action), (admin, limit));
+}
+
+/// Counts one `action` for `who`, panicking with `ActionRateLimitExceeded`
+/// if the address already reached the limit in the current window.
+pub fn check_action_rate_limit(env: &Env, who: &Address, action: RateLimitedAction) {
+    let limit: ActionRateLimit = match get_action_rate_limit(env, action.clone()) {
+        Some(limit) => limit,
+        None => return,
+    };
+
+    let window: u32 = env.ledger().sequence() / limit.window_ledgers;
+    let key: DataKey = DataKey::ActionCounter(action, who.clone(), window);
+    let count: u32 = env.storage().temporary().get(&key).unwrap_or(0);
+    if count >= limit.max_actions {
+        handle_error(env, RegistryError::ActionRateLimitExceeded)
+    }
+
+    env.storage().temporary().set(&key, &(count + 1));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, limit.window_ledgers, limit.window_ledgers);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::String;
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let owner: Address = Address::generate(env);
+        let user_mgmt: Address = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &owner, &user_mgmt);
+        });
+        (client, owner)
+    }
+
+    #[test]
+    fn test_category_creation_is_rate_limited_per_window() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        client.set_action_rate_limit(
+            &owner,
+            &RateLimitedAction::CategoryCreation,
+            &Some(ActionRateLimit {
+                window_ledgers: 100,
+                max_actions: 2,
+            }),
+        );
+
+        client.create_course_category(&owner, &String::from_str(&env, "Rust"), &None);
+        client.create_course_category(&owner, &String::from_str(&env, "Go"), &None);
+        assert!(client
+            .try_create_course_category(&owner, &String::from_str(&env, "Zig"), &None)
+            .is_err());
+
+        // A new window starts with a fresh counter
+        env.ledger().with_mut(|ledger| ledger.sequence_number += 100);
+        client.create_course_category(&owner, &String::from_str(&env, "Zig"), &None);
+
+        client.set_action_rate_limit(&owner, &RateLimitedAction::CategoryCreation, &None);
+        assert_eq!(client.get_action_rate_limit(&RateLimitedAction::CategoryCreation), None);
+        for name in ["C", "D", "E"] {
+            client.create_course_category(&owner, &String::from_str(&env, name), &None);
+        }
+    }
+}
@@ -1,15 +1,16 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::events;
 use crate::error::{handle_error, Error};
+use crate::functions::course_index::{get_course_goal_ids, set_course_goal_ids};
 use crate::functions::utils::{self, trim};
 use crate::schema::{Course, CourseGoal, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-const GOAL_ADDED_EVENT: Symbol = symbol_short!("goalAdded");
 
 pub fn add_goal(env: Env, creator: Address, course_id: String, content: String) -> CourseGoal {
     creator.require_auth();
@@ -19,19 +20,12 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
         handle_error(&env, Error::EmptyCourseId);
     }
     
-    // Validate goal content - prevent empty or whitespace-only content
-    if content.is_empty() || trim(&env, &content).is_empty() {
-        handle_error(&env, Error::EmptyGoalContent);
-    }
+    validate_goal_content(&env, &content);
     
     // Check string lengths to prevent extremely long values
     if course_id.len() > 100 {
         handle_error(&env, Error::InvalidCourseId);
     }
-    
-    if content.len() > 1000 {
-        handle_error(&env, Error::InvalidGoalContent);
-    }
 
     // Load course
     let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
@@ -46,15 +40,31 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
         handle_error(&env, Error::OnlyCreatorCanAddGoals)
     }
 
+    store_new_goal(&env, &course_id, content, &creator)
+}
+
+/// Rejects empty, whitespace-only and overly long goal content.
+pub fn validate_goal_content(env: &Env, content: &String) {
+    if content.is_empty() || trim(env, content).is_empty() {
+        handle_error(env, Error::EmptyGoalContent);
+    }
+    if content.len() > 1000 {
+        handle_error(env, Error::InvalidGoalContent);
+    }
+}
+
+/// Creates a goal, adds it to the course's goal list and emits `goal_added`.
+/// Callers validate input and authorization.
+pub fn store_new_goal(env: &Env, course_id: &String, content: String, created_by: &Address) -> CourseGoal {
     // Generate a unique goal ID
-    let goal_id = utils::generate_unique_id(&env);
+    let goal_id = utils::generate_unique_id(env);
 
     // Create new goal
     let goal: CourseGoal = CourseGoal {
         course_id: course_id.clone(),
         goal_id: goal_id.clone(),
-        content: content.clone(),
-        created_by: creator.clone(),
+        content,
+        created_by: created_by.clone(),
         created_at: env.ledger().timestamp(),
     };
 
@@ -64,11 +74,12 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
         &goal,
     );
 
-    // Emit event
-    env.events().publish(
-        (GOAL_ADDED_EVENT, course_id.clone(), goal_id.clone()),
-        content.clone(),
-    );
+    // Track the goal in the per-course list
+    let mut goal_ids: Vec<String> = get_course_goal_ids(env, course_id);
+    goal_ids.push_back(goal_id.clone());
+    set_course_goal_ids(env, course_id, &goal_ids);
+
+    events::goal_added(env, &goal);
 
     goal
 }
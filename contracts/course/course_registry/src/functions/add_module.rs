@@ -4,13 +4,16 @@
 use soroban_sdk::{symbol_short, Vec, vec, Address, Env, String, Symbol};
 
 use crate::functions::utils::{concat_strings, u32_to_string};
+use crate::events;
+use crate::functions::course_index::{get_course_module_ids, set_course_module_ids};
+use crate::functions::module_duration::set_module_duration;
+use crate::functions::ttl::bump_persistent;
 use crate::error::{handle_error, Error};
-use crate::schema::{CourseModule};
+use crate::schema::{CourseModule, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
 
-const COURSE_REGISTRY_ADD_MODULE_EVENT: Symbol = symbol_short!("crsAddMod");
 
 pub fn course_registry_add_module(
     env: Env,
@@ -57,26 +60,38 @@ pub fn course_registry_add_module(
         handle_error(&env, Error::DuplicateModulePosition)
     }
 
+    store_new_module(&env, &course_id, position, title, None)
+}
+
+/// Creates a module at `position`, adds it to the course's module index
+/// and emits `module_added`. Callers validate input and authorization.
+pub fn store_new_module(
+    env: &Env,
+    course_id: &String,
+    position: u32,
+    title: String,
+    duration_minutes: Option<u32>,
+) -> CourseModule {
     let ledger_seq: u32 = env.ledger().sequence();
 
     let arr: Vec<String> = vec![
-        &env,
-        String::from_str(&env, "module_"),
+        env,
+        String::from_str(env, "module_"),
         course_id.clone(),
-        String::from_str(&env, "_"),
-        u32_to_string(&env, position),
-        String::from_str(&env, "_"),
-        u32_to_string(&env, ledger_seq),
+        String::from_str(env, "_"),
+        u32_to_string(env, position),
+        String::from_str(env, "_"),
+        u32_to_string(env, ledger_seq),
     ];
 
-    let module_id: String = concat_strings(&env, arr);
+    let module_id: String = concat_strings(env, arr);
 
     // Create new module
     let module: CourseModule = CourseModule {
         id: module_id.clone(),
         course_id: course_id.clone(),
         position,
-        title: title.clone(),
+        title,
         created_at: env.ledger().timestamp(),
     };
 
@@ -86,9 +101,17 @@ pub fn course_registry_add_module(
     env.storage().persistent().set(&storage_key, &module);
     env.storage().persistent().set(&position_key, &true);
 
-    // emit an event
-    env.events()
-        .publish((COURSE_REGISTRY_ADD_MODULE_EVENT,), (caller, course_id, position, title));
+    // Track the module in the per-course index
+    let mut module_ids: Vec<String> = get_course_module_ids(env, course_id);
+    module_ids.push_back(module_id.clone());
+    set_course_module_ids(env, course_id, &module_ids);
+    bump_persistent(env, &storage_key);
+    bump_persistent(env, &DataKey::CourseModules(course_id.clone()));
+    if let Some(duration) = duration_minutes {
+        set_module_duration(env, &module_id, duration);
+    }
+
+    events::module_added(env, &module);
 
     module
 }
@@ -3,15 +3,18 @@
 
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
+use crate::events;
 use crate::error::{handle_error, Error};
-use crate::schema::Course;
+use crate::functions::get_courses_by_instructor::course_status;
+use crate::functions::status_history::{record_status_change, validate_reason};
+use crate::schema::{Course, CourseStatus};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-const ARCHIVED_COURSE_EVENT: Symbol = symbol_short!("archiveCs");
 
-pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course {
+pub fn archive_course(env: &Env, creator: Address, course_id: String, reason: Option<String>) -> Course {
     creator.require_auth();
+    validate_reason(env, &reason);
 
     let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
     let mut course: Course = env
@@ -27,12 +30,13 @@ pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course
     if course.is_archived {
         handle_error(env, Error::CourseAlreadyArchived)
     }
+    let old_status: CourseStatus = course_status(&course);
     course.is_archived = true;
 
     env.storage().persistent().set(&key, &course);
     
-    env.events()
-        .publish((ARCHIVED_COURSE_EVENT, course_id.clone()), course.clone());
+    events::course_archived(env, &course_id, &creator);
+    record_status_change(env, &course_id, old_status, CourseStatus::Archived, &creator, reason);
 
     course
 }
@@ -71,7 +75,7 @@ mod tests {
             client.get_course(&new_course.id.clone()).creator
         );
 
-        let archived_new_course = client.archive_course(&creator, &new_course.id.clone());
+        let archived_new_course = client.archive_course(&creator, &new_course.id.clone(), &None);
         assert!(archived_new_course.is_archived);
     }
 
@@ -87,7 +91,7 @@ mod tests {
         let creator: Address = Address::generate(&env);
         let fake_course_id = String::from_str(&env, "nonexistent_course");
 
-        client.archive_course(&creator, &fake_course_id);
+        client.archive_course(&creator, &fake_course_id, &None);
     }
 
     #[test]
@@ -114,7 +118,7 @@ mod tests {
             &None,
         );
 
-        client.archive_course(&non_creator, &new_course.id);
+        client.archive_course(&non_creator, &new_course.id, &None);
     }
 
     #[test]
@@ -140,10 +144,10 @@ mod tests {
             &None,
         );
 
-        let archived_course = client.archive_course(&creator, &new_course.id);
+        let archived_course = client.archive_course(&creator, &new_course.id, &None);
         assert!(archived_course.is_archived);
 
-        client.archive_course(&creator, &new_course.id);
+        client.archive_course(&creator, &new_course.id, &None);
     }
 
     #[test]
@@ -168,7 +172,7 @@ mod tests {
             &None,
         );
 
-        client.archive_course(&creator, &new_course.id);
+        client.archive_course(&creator, &new_course.id, &None);
 
         let events = env.events().all();
         assert!(!events.is_empty());
@@ -1,8 +1,31 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use crate::schema::{Course, CourseBackupData, CourseCategory, CourseGoal, CourseId, CourseModule, DataKey};
-use soroban_sdk::{Address, Env, Map, String, Vec};
+use crate::error::{handle_error, RegistryError};
+use crate::schema::{
+    BackupCursor, Course, CourseBackupData, CourseBackupEntry, CourseBackupPage, CourseCategory, CourseGoal,
+    CourseId, CourseModule, DataKey, MAX_BACKUP_PAGE_SIZE,
+};
+use crate::functions::access_control::require_admin;
+use crate::functions::course_counts::on_instructor_course_changed;
+use crate::functions::course_index::{
+    get_course_goal_ids, get_course_module_ids, set_course_goal_ids, set_course_module_ids,
+};
+use crate::functions::create_course::title_key;
+use crate::functions::list_categories::invalidate_category_counts;
+use crate::functions::module_duration::{clear_module_duration, get_module_durations, set_module_duration};
+use crate::functions::prerequisite_index::set_course_prerequisites;
+use crate::functions::search_courses::index_course_title;
+use crate::functions::sort_index::index_course_sort_keys;
+use crate::functions::utils::{course_id_to_string, parse_course_id};
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_ID: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Version tag of paged backups produced by this contract
+const PAGED_BACKUP_VERSION: &str = "1.1.0";
 
 /// Export all course data for backup purposes
 ///
@@ -48,7 +71,7 @@ pub fn export_course_data(env: Env, caller: Address) -> CourseBackupData {
     
     // Iterate through all possible course IDs
     for id in 1..=max_course_id {
-        let course_id_str = super::utils::u32_to_string(&env, id as u32);
+        let course_id_str = super::utils::course_id_to_string(&env, id);
         let storage_key = (course_key.clone(), course_id_str.clone());
         
         if let Some(course) = env.storage().persistent().get::<_, Course>(&storage_key) {
@@ -56,11 +79,18 @@ pub fn export_course_data(env: Env, caller: Address) -> CourseBackupData {
             courses.set(course.id.clone(), course.clone());
             
             // Export course goals
-            if let Some(course_goals) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Vec<CourseGoal>>(&DataKey::CourseGoalList(course.id.clone()))
-            {
+            let goal_ids: Vec<String> = get_course_goal_ids(&env, &course.id);
+            if !goal_ids.is_empty() {
+                let mut course_goals: Vec<CourseGoal> = Vec::new(&env);
+                for goal_id in goal_ids.iter() {
+                    if let Some(goal) = env
+                        .storage()
+                        .persistent()
+                        .get::<DataKey, CourseGoal>(&DataKey::CourseGoal(course.id.clone(), goal_id))
+                    {
+                        course_goals.push_back(goal);
+                    }
+                }
                 goals.set(course.id.clone(), course_goals);
             }
 
@@ -174,6 +204,7 @@ pub fn import_course_data(env: Env, caller: Address, backup_data: CourseBackupDa
         env.storage()
             .persistent()
             .set(&storage_key, &course);
+        index_course_title(&env, &course.id, &course.title);
         imported_count += 1;
     }
 
@@ -193,16 +224,23 @@ pub fn import_course_data(env: Env, caller: Address, backup_data: CourseBackupDa
 
     // Import goals
     for (course_id, course_goals) in backup_data.goals.iter() {
-        env.storage()
-            .persistent()
-            .set(&DataKey::CourseGoalList(course_id), &course_goals);
+        let mut goal_ids: Vec<String> = Vec::new(&env);
+        for goal in course_goals.iter() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::CourseGoal(course_id.clone(), goal.goal_id.clone()), &goal);
+            goal_ids.push_back(goal.goal_id);
+        }
+        set_course_goal_ids(&env, &course_id, &goal_ids);
     }
 
     // Import prerequisites
     for (course_id, prereqs) in backup_data.prerequisites.iter() {
-        env.storage()
-            .persistent()
-            .set(&DataKey::CoursePrerequisites(course_id), &prereqs);
+        let mut prerequisite_ids: Vec<String> = Vec::new(&env);
+        for prereq in prereqs.iter() {
+            prerequisite_ids.push_back(prereq.id);
+        }
+        set_course_prerequisites(&env, &course_id, &prerequisite_ids);
     }
 
     // Import category sequence counter
@@ -215,6 +253,8 @@ pub fn import_course_data(env: Env, caller: Address, backup_data: CourseBackupDa
         .persistent()
         .set(&DataKey::Admins, &backup_data.admins);
 
+    invalidate_category_counts(&env);
+
     // Emit import event
     env.events().publish(
         (String::from_str(&env, "course_data_imported"),),
@@ -242,3 +282,330 @@ fn is_admin(env: &Env, address: Address) -> bool {
     }
     false
 }
+
+/// Chains the checksum of the pages before a page with the page's contents.
+fn chain_checksum(
+    env: &Env,
+    previous: &BytesN<32>,
+    entries: &Vec<CourseBackupEntry>,
+    categories: &Vec<CourseCategory>,
+    category_seq: u128,
+) -> BytesN<32> {
+    let mut data: Bytes = Bytes::from_array(env, &previous.to_array());
+    data.append(&(entries.clone(), categories.clone(), category_seq).to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+fn export_course(env: &Env, course: Course) -> CourseBackupEntry {
+    let module_ids: Vec<String> = get_course_module_ids(env, &course.id);
+    let mut modules: Vec<CourseModule> = Vec::new(env);
+    for module_id in module_ids.iter() {
+        if let Some(module) = env.storage().persistent().get::<_, CourseModule>(&(MODULE_KEY, module_id)) {
+            modules.push_back(module);
+        }
+    }
+
+    let goal_ids: Vec<String> = get_course_goal_ids(env, &course.id);
+    let mut goals: Vec<CourseGoal> = Vec::new(env);
+    for goal_id in goal_ids.iter() {
+        if let Some(goal) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CourseGoal>(&DataKey::CourseGoal(course.id.clone(), goal_id))
+        {
+            goals.push_back(goal);
+        }
+    }
+
+    let prerequisites: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course.id.clone()))
+        .unwrap_or(Vec::new(env));
+
+    let module_durations: Map<String, u32> = get_module_durations(env, &modules);
+
+    CourseBackupEntry {
+        course,
+        modules,
+        module_durations,
+        goals,
+        prerequisites,
+    }
+}
+
+/// Export one page of course data for backup purposes
+///
+/// Unlike `export_course_data`, which must fit every course in one call,
+/// courses are exported in chunks of up to `MAX_BACKUP_PAGE_SIZE` course IDs
+/// with their modules, module durations, goals and prerequisites. The first
+/// page also carries the categories.
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `caller` - Address requesting the backup (must be the owner or an admin)
+/// * `cursor` - `next_cursor` of the previous page, or offset 0 with an all-zero checksum
+/// * `limit` - Number of course IDs covered by the page (1 to `MAX_BACKUP_PAGE_SIZE`)
+///
+/// # Panics
+/// * If caller is neither the owner nor an admin
+/// * If the limit is out of range or the cursor is past the end (`InvalidBackupPage`)
+pub fn export_course_data_page(env: Env, caller: Address, cursor: BackupCursor, limit: u32) -> CourseBackupPage {
+    require_admin(&env, &caller);
+
+    let total_courses: u32 = env.storage().persistent().get::<_, u128>(&COURSE_ID).unwrap_or(0) as u32;
+    if limit == 0 || limit > MAX_BACKUP_PAGE_SIZE || cursor.offset > total_courses {
+        handle_error(&env, RegistryError::InvalidBackupPage)
+    }
+
+    let end: u32 = (cursor.offset + limit).min(total_courses);
+    let mut entries: Vec<CourseBackupEntry> = Vec::new(&env);
+    for id in (cursor.offset + 1)..=end {
+        let course_id: String = course_id_to_string(&env, id as u128);
+        if let Some(course) = env.storage().persistent().get::<_, Course>(&(COURSE_KEY, course_id)) {
+            entries.push_back(export_course(&env, course));
+        }
+    }
+
+    // Categories are small and go with the first page
+    let mut categories: Vec<CourseCategory> = Vec::new(&env);
+    let mut category_seq: u128 = 0;
+    if cursor.offset == 0 {
+        category_seq = env.storage().persistent().get(&DataKey::CategorySeq).unwrap_or(0);
+        for category_id in 1..=category_seq {
+            if let Some(category) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, CourseCategory>(&DataKey::CourseCategory(category_id))
+            {
+                categories.push_back(category);
+            }
+        }
+    }
+
+    let next_cursor: BackupCursor = BackupCursor {
+        offset: end,
+        checksum: chain_checksum(&env, &cursor.checksum, &entries, &categories, category_seq),
+    };
+    CourseBackupPage {
+        cursor,
+        entries,
+        categories,
+        category_seq,
+        next_cursor,
+        has_more: end < total_courses,
+        total_courses,
+        backup_version: String::from_str(&env, PAGED_BACKUP_VERSION),
+    }
+}
+
+fn import_course(env: &Env, entry: &CourseBackupEntry) {
+    let course: &Course = &entry.course;
+    if !env.storage().persistent().has(&(COURSE_KEY, course.id.clone())) {
+        on_instructor_course_changed(env, &course.creator, true);
+    }
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course.id.clone()), course);
+    env.storage().persistent().set(&title_key(env, &course.title), &true);
+    index_course_title(env, &course.id, &course.title);
+    index_course_sort_keys(env, course);
+
+    let mut module_ids: Vec<String> = Vec::new(env);
+    for module in entry.modules.iter() {
+        env.storage()
+            .persistent()
+            .set(&(MODULE_KEY, module.id.clone()), &module);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("pos"), course.id.clone(), module.position), &true);
+        match entry.module_durations.get(module.id.clone()) {
+            Some(duration) => set_module_duration(env, &module.id, duration),
+            None => clear_module_duration(env, &module.id),
+        }
+        module_ids.push_back(module.id);
+    }
+    set_course_module_ids(env, &course.id, &module_ids);
+
+    let mut goal_ids: Vec<String> = Vec::new(env);
+    for goal in entry.goals.iter() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseGoal(course.id.clone(), goal.goal_id.clone()), &goal);
+        goal_ids.push_back(goal.goal_id);
+    }
+    set_course_goal_ids(env, &course.id, &goal_ids);
+
+    if !entry.prerequisites.is_empty() {
+        set_course_prerequisites(env, &course.id, &entry.prerequisites);
+    }
+
+    // Keep new course IDs from colliding with restored ones
+    if let Some(id) = parse_course_id(&course.id) {
+        let current: u128 = env.storage().persistent().get(&COURSE_ID).unwrap_or(0);
+        if id > current {
+            env.storage().persistent().set(&COURSE_ID, &id);
+        }
+    }
+}
+
+/// Import one page of a paged course backup
+///
+/// Pages must be imported in the order they were exported, starting from
+/// the first one. Each page's checksum is verified against the pages
+/// imported before it.
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `caller` - Address performing the import (must be the owner or an admin)
+/// * `page` - Page returned by `export_course_data_page`
+///
+/// # Returns
+/// * `u32` - Number of courses imported from the page
+///
+/// # Panics
+/// * If caller is neither the owner nor an admin
+/// * If the page isn't the next one expected or has another version (`InvalidBackupPage`)
+/// * If the page's checksum doesn't match its contents (`BackupChecksumMismatch`)
+pub fn import_course_data_page(env: Env, caller: Address, page: CourseBackupPage) -> u32 {
+    require_admin(&env, &caller);
+
+    let expected: BackupCursor = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BackupImportCursor)
+        .unwrap_or(BackupCursor {
+            offset: 0,
+            checksum: BytesN::from_array(&env, &[0u8; 32]),
+        });
+    if page.cursor != expected || page.backup_version != String::from_str(&env, PAGED_BACKUP_VERSION) {
+        handle_error(&env, RegistryError::InvalidBackupPage)
+    }
+    let checksum: BytesN<32> = chain_checksum(
+        &env,
+        &page.cursor.checksum,
+        &page.entries,
+        &page.categories,
+        page.category_seq,
+    );
+    if checksum != page.next_cursor.checksum {
+        handle_error(&env, RegistryError::BackupChecksumMismatch)
+    }
+
+    for entry in page.entries.iter() {
+        import_course(&env, &entry);
+    }
+
+    if !page.categories.is_empty() {
+        for category in page.categories.iter() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::CourseCategory(category.id), &category);
+        }
+        let current_seq: u128 = env.storage().persistent().get(&DataKey::CategorySeq).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CategorySeq, &current_seq.max(page.category_seq));
+    }
+    invalidate_category_counts(&env);
+
+    // The last page completes the import, so a new one can start
+    if page.has_more {
+        env.storage()
+            .persistent()
+            .set(&DataKey::BackupImportCursor, &page.next_cursor);
+    } else {
+        env.storage().persistent().remove(&DataKey::BackupImportCursor);
+    }
+
+    page.entries.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{BackupCursor, CourseBackupPage};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let admin: Address = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &admin, &user_mgmt_id);
+        });
+        (CourseRegistryClient::new(env, &contract_id), admin)
+    }
+
+    #[test]
+    fn test_paged_course_backup_round_trip() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let (source, admin) = setup(&env);
+        let (target, _) = setup(&env);
+        let creator: Address = Address::generate(&env);
+
+        let mut course_ids = vec![&env];
+        for title in ["Rust", "Soroban", "Stellar"] {
+            let course = source.create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "description"),
+                &1000,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            );
+            course_ids.push_back(course.id);
+        }
+        let first_id: String = course_ids.get(0).unwrap();
+        let intro = source.add_module(&creator, &first_id, &1, &String::from_str(&env, "Intro"));
+        source.edit_module(&creator, &intro.id, &intro.title, &None, &Some(40));
+        source.add_goal(&creator, &first_id, &String::from_str(&env, "Learn the basics"));
+        source.add_prerequisite(&creator, &course_ids.get(2).unwrap(), &vec![&env, first_id.clone()]);
+        source.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        let first: CourseBackupPage = source.export_course_data_page(
+            &admin,
+            &BackupCursor { offset: 0, checksum: BytesN::from_array(&env, &[0u8; 32]) },
+            &2,
+        );
+        assert_eq!(first.entries.len(), 2);
+        assert_eq!(first.entries.get(0).unwrap().module_durations.get(intro.id.clone()), Some(40));
+        assert_eq!(first.categories.len(), 1);
+        assert!(first.has_more);
+        let second: CourseBackupPage = source.export_course_data_page(&admin, &first.next_cursor, &2);
+        assert_eq!(second.entries.len(), 1);
+        assert!(second.categories.is_empty());
+        assert!(!second.has_more);
+
+        // Pages are rejected out of order or when altered
+        assert!(target.try_import_course_data_page(&admin, &second).is_err());
+        let mut tampered: CourseBackupPage = first.clone();
+        tampered.category_seq += 1;
+        assert!(target.try_import_course_data_page(&admin, &tampered).is_err());
+
+        assert_eq!(target.import_course_data_page(&admin, &first), 2);
+        assert_eq!(target.import_course_data_page(&admin, &second), 1);
+        assert_eq!(target.get_course(&first_id), source.get_course(&first_id));
+        assert_eq!(target.list_modules(&first_id), source.list_modules(&first_id));
+        assert_eq!(target.get_module_duration(&intro.id), Some(40));
+        assert_eq!(target.list_categories(), source.list_categories());
+    }
+}
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::add_goal::store_new_goal;
+use crate::functions::add_module::store_new_module;
+use crate::functions::course_index::{get_course_goal_ids, get_course_module_ids};
+use crate::functions::create_course::store_new_course;
+use crate::functions::module_duration::get_module_duration;
+use crate::functions::prerequisite_index::set_course_prerequisites;
+use crate::schema::{Course, CourseGoal, CourseModule, DataKey, NewCourseParams};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Copies a course into a new unpublished course owned by `creator`.
+///
+/// The metadata of the source course is reused with `new_title`, and its
+/// modules, goals and prerequisites are copied under new IDs. The caller must
+/// be the creator of the source course or an admin, and the copy counts
+/// against the caller's course creation rate limit.
+pub fn clone_course(
+    env: Env,
+    creator: Address,
+    source_course_id: String,
+    new_title: String,
+) -> Course {
+    if source_course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId)
+    }
+
+    let source: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, source_course_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    require_course_management_auth(&env, &creator, &source_course_id);
+
+    let course: Course = store_new_course(
+        &env,
+        creator.clone(),
+        NewCourseParams {
+            title: new_title,
+            description: source.description,
+            price: source.price,
+            category: source.category,
+            language: source.language,
+            thumbnail_url: source.thumbnail_url,
+            level: source.level,
+            duration_hours: source.duration_hours,
+        },
+    );
+
+    // Modules keep their positions
+    let module_ids: Vec<String> = get_course_module_ids(&env, &source_course_id);
+    for module_id in module_ids.iter() {
+        if let Some(module) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseModule>(&(MODULE_KEY, module_id))
+        {
+            let duration: Option<u32> = get_module_duration(&env, module.id);
+            store_new_module(&env, &course.id, module.position, module.title, duration);
+        }
+    }
+
+    let goal_ids: Vec<String> = get_course_goal_ids(&env, &source_course_id);
+    for goal_id in goal_ids.iter() {
+        if let Some(goal) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseGoal>(&DataKey::CourseGoal(source_course_id.clone(), goal_id))
+        {
+            store_new_goal(&env, &course.id, goal.content, &creator);
+        }
+    }
+
+    // A fresh course has no dependents, so copied prerequisites cannot form a cycle
+    let prerequisites: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(source_course_id))
+        .unwrap_or(Vec::new(&env));
+    if !prerequisites.is_empty() {
+        set_course_prerequisites(&env, &course.id, &prerequisites);
+        events::prerequisites_added(&env, &course.id, &prerequisites);
+    }
+
+    course
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "Spring cohort"),
+            &1500,
+            &Some(String::from_str(&client.env, "Programming")),
+            &None,
+            &None,
+            &None,
+            &Some(20),
+        )
+    }
+
+    #[test]
+    fn test_clone_course_copies_content() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let basics: Course = create_course(&client, &creator, "Rust Basics");
+        let source: Course = create_course(&client, &creator, "Rust 2025");
+        let syntax = client.add_module(&creator, &source.id, &1, &String::from_str(&env, "Syntax"));
+        client.add_module(&creator, &source.id, &2, &String::from_str(&env, "Ownership"));
+        client.edit_module(&creator, &syntax.id, &syntax.title, &None, &Some(30));
+        client.add_goal(&creator, &source.id, &String::from_str(&env, "Write a CLI"));
+        client.add_prerequisite(&creator, &source.id, &soroban_sdk::vec![&env, basics.id.clone()]);
+
+        let copy: Course = client.clone_course(&creator, &source.id, &String::from_str(&env, "Rust 2026"));
+
+        assert_ne!(copy.id, source.id);
+        assert_eq!(copy.title, String::from_str(&env, "Rust 2026"));
+        assert_eq!(copy.creator, creator);
+        assert_eq!(copy.price, source.price);
+        assert_eq!(copy.category, source.category);
+        assert_eq!(copy.duration_hours, Some(20));
+        assert!(!copy.published);
+
+        let modules = client.list_modules(&copy.id);
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules.get(0).unwrap().title, String::from_str(&env, "Syntax"));
+        assert_eq!(modules.get(1).unwrap().position, 2);
+        assert_eq!(client.get_module_duration(&modules.get(0).unwrap().id), Some(30));
+        assert_eq!(client.get_module_duration(&modules.get(1).unwrap().id), None);
+
+        let detail = client.get_course_full(&copy.id);
+        assert_eq!(detail.goals.len(), 1);
+        assert_eq!(detail.goals.get(0).unwrap().content, String::from_str(&env, "Write a CLI"));
+        assert_eq!(client.get_prerequisites(&copy.id, &false), soroban_sdk::vec![&env, basics.id]);
+
+        // The source course is untouched
+        assert_eq!(client.list_modules(&source.id).len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_clone_course_requires_creator() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+        let source: Course = create_course(&client, &creator, "Rust 2025");
+
+        client.clone_course(
+            &Address::generate(&env),
+            &source.id,
+            &String::from_str(&env, "Rust 2026"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_clone_missing_course_fails() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.clone_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "99"),
+            &String::from_str(&env, "Rust 2026"),
+        );
+    }
+}
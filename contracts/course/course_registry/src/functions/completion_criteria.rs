@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! What a student must achieve for a course to count as completed.
+//!
+//! The registry only stores the criteria; the progress contract checks them
+//! whenever a student's progress or assessment results change.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_course_management_auth;
+use crate::schema::{CompletionCriteria, CourseModule, DataKey, MAX_QUIZ_SCORE};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Returns the completion criteria of a course.
+///
+/// Courses without criteria require every module and nothing else.
+pub fn get_completion_criteria(env: &Env, course_id: String) -> CompletionCriteria {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CompletionCriteria(course_id))
+        .unwrap_or(CompletionCriteria {
+            required_modules: Vec::new(env),
+            min_quiz_score: None,
+            require_assignments: false,
+            min_sessions_attended: None,
+        })
+}
+
+/// Replaces the completion criteria of a course (course creator or admin only).
+///
+/// Every required module must be a module of the course, listed once.
+pub fn set_completion_criteria(
+    env: &Env,
+    creator: Address,
+    course_id: String,
+    criteria: CompletionCriteria,
+) -> CompletionCriteria {
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id.clone())) {
+        handle_error(env, Error::CourseNotFound)
+    }
+    require_course_management_auth(env, &creator, &course_id);
+
+    if criteria.min_quiz_score.is_some_and(|score| score > MAX_QUIZ_SCORE) {
+        handle_error(env, RegistryError::InvalidCompletionCriteria)
+    }
+    let mut seen: Vec<String> = Vec::new(env);
+    for module_id in criteria.required_modules.iter() {
+        let module: CourseModule = env
+            .storage()
+            .persistent()
+            .get(&(MODULE_KEY, module_id.clone()))
+            .unwrap_or_else(|| handle_error(env, Error::ModuleNotFound));
+        if module.course_id != course_id || seen.contains(&module_id) {
+            handle_error(env, RegistryError::InvalidCompletionCriteria)
+        }
+        seen.push_back(module_id);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::CompletionCriteria(course_id.clone()), &criteria);
+
+    events::completion_criteria_set(env, &course_id, &creator, &criteria);
+    criteria
+}
+
+/// Drops the completion criteria of a purged course.
+pub fn clear_completion_criteria(env: &Env, course_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CompletionCriteria(course_id.clone()));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::CompletionCriteria;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                false
+            }
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let client = CourseRegistryClient::new(env, &env.register(CourseRegistry, ()));
+        let user_mgmt = env.register(mock_user_management::UserManagement, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::initialize(env, &Address::generate(env), &user_mgmt);
+        });
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Rust"),
+            &String::from_str(env, "Intro"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_set_completion_criteria() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let title = String::from_str(&env, "Module");
+        let first = client.add_module(&creator, &course_id, &1, &title);
+        client.add_module(&creator, &course_id, &2, &title);
+
+        // Every module is required until criteria are set
+        let default = client.get_completion_criteria(&course_id);
+        assert!(default.required_modules.is_empty());
+        assert_eq!(default.min_quiz_score, None);
+        assert!(!default.require_assignments);
+        assert_eq!(default.min_sessions_attended, None);
+
+        let criteria = CompletionCriteria {
+            required_modules: vec![&env, first.id.clone()],
+            min_quiz_score: Some(70),
+            require_assignments: true,
+            min_sessions_attended: Some(2),
+        };
+        client.set_completion_criteria(&creator, &course_id, &criteria);
+        assert_eq!(client.get_completion_criteria(&course_id), criteria);
+
+        // Strangers, scores above 100 and duplicate modules are rejected
+        assert!(client
+            .try_set_completion_criteria(&Address::generate(&env), &course_id, &criteria)
+            .is_err());
+        let mut invalid = criteria.clone();
+        invalid.min_quiz_score = Some(101);
+        assert!(client.try_set_completion_criteria(&creator, &course_id, &invalid).is_err());
+        let mut invalid = criteria.clone();
+        invalid.required_modules.push_back(first.id.clone());
+        assert!(client.try_set_completion_criteria(&creator, &course_id, &invalid).is_err());
+
+        // Modules of another course can't be required
+        let other_course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Go"),
+            &String::from_str(&env, "Intro"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let foreign = client.add_module(&creator, &other_course.id, &1, &title);
+        let mut invalid = criteria.clone();
+        invalid.required_modules = vec![&env, foreign.id];
+        assert!(client.try_set_completion_criteria(&creator, &course_id, &invalid).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::functions::access_control::require_admin;
+use crate::schema::{
+    ContentLimits, DataKey, DEFAULT_MAX_DESCRIPTION_LENGTH, DEFAULT_MAX_TITLE_LENGTH,
+    MAX_DESCRIPTION_LENGTH_CEILING, MAX_TITLE_LENGTH_CEILING,
+};
+
+const SET_CONTENT_LIMITS_EVENT: Symbol = symbol_short!("setCntLim");
+
+/// Returns the title and description length limits, falling back to the defaults.
+pub fn get_content_limits(env: &Env) -> ContentLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContentLimits)
+        .unwrap_or(ContentLimits {
+            max_title_length: DEFAULT_MAX_TITLE_LENGTH,
+            max_description_length: DEFAULT_MAX_DESCRIPTION_LENGTH,
+        })
+}
+
+/// Updates the title and description length limits. Owner or admin only.
+///
+/// Existing courses keep their texts; the limits apply to new and edited ones.
+pub fn set_content_limits(env: &Env, admin: Address, max_title_length: u32, max_description_length: u32) {
+    require_admin(env, &admin);
+
+    if max_title_length == 0
+        || max_title_length > MAX_TITLE_LENGTH_CEILING
+        || max_description_length == 0
+        || max_description_length > MAX_DESCRIPTION_LENGTH_CEILING
+    {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let limits: ContentLimits = ContentLimits {
+        max_title_length,
+        max_description_length,
+    };
+    env.storage().instance().set(&DataKey::ContentLimits, &limits);
+
+    env.events().publish(
+        (SET_CONTENT_LIMITS_EVENT,),
+        (admin, max_title_length, max_description_length),
+    );
+}
+
+/// Panics with `TitleTooLong` if `title` exceeds the configured limit.
+pub fn validate_title_length(env: &Env, title: &String) {
+    if title.len() > get_content_limits(env).max_title_length {
+        handle_error(env, RegistryError::TitleTooLong)
+    }
+}
+
+/// Panics with `DescriptionTooLong` if `description` exceeds the configured limit.
+pub fn validate_description_length(env: &Env, description: &String) {
+    if description.len() > get_content_limits(env).max_description_length {
+        handle_error(env, RegistryError::DescriptionTooLong)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let owner: Address = Address::generate(env);
+        let user_mgmt: Address = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &owner, &user_mgmt);
+        });
+        (client, owner)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, title: &str, description: &str) -> Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, description),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn description_params(description: &str, env: &Env) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: Some(String::from_str(env, description)),
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_content_limits() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        assert_eq!(client.get_content_limits().max_title_length, DEFAULT_MAX_TITLE_LENGTH);
+
+        client.set_content_limits(&owner, &10, &20);
+        assert_eq!(
+            client.get_content_limits(),
+            ContentLimits {
+                max_title_length: 10,
+                max_description_length: 20
+            }
+        );
+
+        let course: Course = create_course(&env, &client, "Rust 101", "Learn Rust");
+        let edited: Course = client.edit_course(
+            &course.creator,
+            &course.id,
+            &description_params("Twenty bytes exactly", &env),
+        );
+        assert_eq!(edited.description.len(), 20);
+
+        // Each field reports its own error
+        assert_eq!(
+            client.try_create_course(
+                &course.creator,
+                &String::from_str(&env, "Eleven char"),
+                &String::from_str(&env, ""),
+                &1000,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            ),
+            Err(Ok(RegistryError::TitleTooLong.into()))
+        );
+        assert_eq!(
+            client.try_edit_course(
+                &course.creator,
+                &course.id,
+                &description_params("Twenty-one bytes long", &env),
+            ),
+            Err(Ok(RegistryError::DescriptionTooLong.into()))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #46)")]
+    fn test_set_content_limits_above_ceiling_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+
+        client.set_content_limits(&owner, &(MAX_TITLE_LENGTH_CEILING + 1), &DEFAULT_MAX_DESCRIPTION_LENGTH);
+    }
+}
@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, Address, Env, String, Vec, vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env, String, Vec, vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::access_control::{is_admin, is_owner};
 
 /// Errors that can occur during contract versioning operations
 #[contracterror]
@@ -22,54 +26,142 @@ pub enum VersioningError {
     MigrationFailed = 6,
 }
 
+/// A single entry of the contract version history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionRecord {
+    /// Version string that was deployed or migrated to
+    pub version: String,
+    /// Ledger timestamp at which the version was recorded
+    pub ledger_timestamp: u64,
+    /// Address that initialized, upgraded or migrated the contract
+    pub by: Address,
+}
+
+/// Current migration state of the contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    /// Version of the code that is currently running
+    pub code_version: String,
+    /// Latest version in the history (empty if nothing was recorded yet)
+    pub recorded_version: String,
+    /// True after an upgrade until a data migration completes, or when the
+    /// recorded version does not match the running code
+    pub migration_pending: bool,
+    /// Outcome of the last migration attempt (empty if none ran)
+    pub last_result: String,
+}
+
 /// Storage keys for versioning data
 const VERSION_HISTORY_KEY: &str = "version_history";
 const MIGRATION_STATUS_KEY: &str = "migration_status";
+const MIGRATION_PENDING_KEY: &str = "migration_pending";
 
 /// Get the version history of the contract
-pub fn get_version_history(env: &Env) -> Vec<String> {
+pub fn get_version_history(env: &Env) -> Vec<VersionRecord> {
     let key: String = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage()
         .instance()
-        .get::<String, Vec<String>>(&key)
+        .get::<String, Vec<VersionRecord>>(&key)
         .unwrap_or_else(|| vec![env])
 }
 
-/// Store a new version in the history
-fn store_version_in_history(env: &Env, version: String) {
-    let mut history: Vec<String> = get_version_history(env);
-    history.push_back(version.clone());
-    
+/// Append `{version, ledger_timestamp, by}` to the version history.
+///
+/// Called on initialization, upgrade and completed migrations. Authorization
+/// is the caller's responsibility.
+pub fn record_version(env: &Env, admin: &Address, version: String) {
+    let mut history: Vec<VersionRecord> = get_version_history(env);
+    history.push_back(VersionRecord {
+        version,
+        ledger_timestamp: env.ledger().timestamp(),
+        by: admin.clone(),
+    });
+
     let key: String = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage().instance().set(&key, &history);
 }
 
+/// Latest version in the history, if any
+fn latest_recorded_version(env: &Env) -> Option<String> {
+    get_version_history(env).last().map(|record| record.version)
+}
+
 /// Check if a version exists in the history
 fn version_exists_in_history(env: &Env, version: &String) -> bool {
-    let history: Vec<String> = get_version_history(env);
-    for v in history.iter() {
-        if &v == version {
-            return true;
+    get_version_history(env)
+        .iter()
+        .any(|record| &record.version == version)
+}
+
+/// Replace the contract code with an uploaded WASM and record the upgrade.
+///
+/// The version history gets the current version (if missing) and `new_version`
+/// appended before the code is swapped, so the history survives the upgrade.
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>, new_version: String) {
+    admin.require_auth();
+
+    if !(is_owner(env, &admin) || is_admin(env, &admin)) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let from_version: String = match latest_recorded_version(env) {
+        Some(version) => version,
+        None => {
+            let current: String = String::from_str(env, crate::VERSION);
+            record_version(env, &admin, current.clone());
+            current
         }
+    };
+    record_version(env, &admin, new_version.clone());
+    if from_version != new_version {
+        set_migration_pending(env, true);
     }
-    false
+
+    events::contract_upgraded(env, &from_version, &new_version, &new_wasm_hash, &admin);
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
 }
 
 /// Get migration status information
-pub fn get_migration_status(env: &Env) -> String {
-    let key: String = String::from_str(env, MIGRATION_STATUS_KEY);
-    env.storage()
+pub fn get_migration_status(env: &Env) -> MigrationStatus {
+    let code_version: String = String::from_str(env, crate::VERSION);
+    let recorded_version: String =
+        latest_recorded_version(env).unwrap_or_else(|| String::from_str(env, ""));
+    let pending_flag: bool = env
+        .storage()
         .instance()
-        .get::<String, String>(&key)
-        .unwrap_or_else(|| String::from_str(env, "No migrations pending"))
+        .get::<String, bool>(&String::from_str(env, MIGRATION_PENDING_KEY))
+        .unwrap_or(false);
+    let migration_pending: bool =
+        pending_flag || (!recorded_version.is_empty() && recorded_version != code_version);
+    let last_result: String = env
+        .storage()
+        .instance()
+        .get::<String, String>(&String::from_str(env, MIGRATION_STATUS_KEY))
+        .unwrap_or_else(|| String::from_str(env, ""));
+
+    MigrationStatus {
+        code_version,
+        recorded_version,
+        migration_pending,
+        last_result,
+    }
 }
 
-/// Set migration status
-fn set_migration_status(env: &Env, status: String) {
+/// Set the result message of the last migration attempt
+pub(crate) fn set_migration_status(env: &Env, status: String) {
     let key: String = String::from_str(env, MIGRATION_STATUS_KEY);
     env.storage().instance().set(&key, &status);
 }
 
+/// Set or clear the pending migration flag
+pub(crate) fn set_migration_pending(env: &Env, pending: bool) {
+    let key: String = String::from_str(env, MIGRATION_PENDING_KEY);
+    env.storage().instance().set(&key, &pending);
+}
+
 /// Check compatibility between two versions
 pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: String) -> bool {
     // Simple compatibility check - for now, assume all versions are compatible
@@ -80,14 +172,17 @@ pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: Str
 /// Migrate course data between contract versions
 pub fn migrate_course_data(
     env: &Env,
-    _caller: Address,
+    caller: Address,
     from_version: String,
     to_version: String,
 ) -> bool {
-    // For course registry, we need to check if the caller is authorized
-    // This could be a course creator or admin (depending on your authorization logic)
-    // For now, we'll allow any authenticated user to perform migration
-    
+    // Migrations write the version history and clear the pending flag,
+    // so they are restricted like `upgrade`
+    caller.require_auth();
+    if !(is_owner(env, &caller) || is_admin(env, &caller)) {
+        handle_error(env, Error::Unauthorized)
+    }
+
     // Validate versions exist in history
     if !version_exists_in_history(env, &from_version) {
         set_migration_status(env, String::from_str(env, "Migration failed: Source version not found"));
@@ -104,8 +199,11 @@ pub fn migrate_course_data(
     let migration_result = perform_course_data_migration(env, &from_version, &to_version);
     
     if migration_result {
-        // Update version history with new version
-        store_version_in_history(env, to_version.clone());
+        // Record the target version unless the upgrade already did
+        if latest_recorded_version(env) != Some(to_version.clone()) {
+            record_version(env, &caller, to_version.clone());
+        }
+        set_migration_pending(env, false);
         
         // Set successful migration status
         let status = String::from_str(env, "Migration completed successfully");
@@ -170,7 +268,7 @@ mod test {
         let contract_id: Address = env.register(crate::CourseRegistry, ());
         
         // Test within contract context
-        let history: Vec<String> = env.as_contract(&contract_id, || {
+        let history: Vec<VersionRecord> = env.as_contract(&contract_id, || {
             get_version_history(&env)
         });
         assert_eq!(history.len(), 0);
@@ -190,4 +288,93 @@ mod test {
             String::from_str(&env, "1.0.0"), 
             String::from_str(&env, "2.0.0")));
     }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_upgrade_requires_admin() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let stranger: Address = Address::generate(&env);
+        client.upgrade(
+            &stranger,
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &String::from_str(&env, "1.1.0"),
+        );
+    }
+
+    #[test]
+    fn test_initialize_records_version() {
+        use soroban_sdk::testutils::{Address as _, Ledger};
+
+        let env: Env = Env::default();
+        env.ledger().set_timestamp(1_000);
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+        let owner: Address = Address::generate(&env);
+        let user_mgmt: Address = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &owner, &user_mgmt);
+        });
+
+        let history: Vec<VersionRecord> = client.get_version_history();
+        assert_eq!(history.len(), 1);
+        let record: VersionRecord = history.get(0).unwrap();
+        assert_eq!(record.version, String::from_str(&env, crate::VERSION));
+        assert_eq!(record.ledger_timestamp, 1_000);
+        assert_eq!(record.by, owner);
+
+        let status: MigrationStatus = client.get_migration_status();
+        assert_eq!(status.recorded_version, status.code_version);
+        assert!(!status.migration_pending);
+    }
+
+    #[test]
+    fn test_migration_clears_pending_flag() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+        let admin: Address = Address::generate(&env);
+        let version: String = String::from_str(&env, crate::VERSION);
+
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &Address::generate(&env));
+            set_migration_pending(&env, true);
+        });
+        assert!(client.get_migration_status().migration_pending);
+
+        assert!(client.migrate_course_data(&admin, &version, &version));
+
+        let status: MigrationStatus = client.get_migration_status();
+        assert!(!status.migration_pending);
+        assert_eq!(status.last_result, String::from_str(&env, "Migration completed successfully"));
+        // The target version was already recorded, so no duplicate entry
+        assert_eq!(client.get_version_history().len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_migration_requires_admin() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+        let version: String = String::from_str(&env, crate::VERSION);
+
+        env.as_contract(&contract_id, || {
+            set_migration_pending(&env, true);
+        });
+
+        client.migrate_course_data(&Address::generate(&env), &version, &version);
+    }
 }
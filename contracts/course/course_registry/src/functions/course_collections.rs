@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Curated course lists managed by admins, e.g. homepage carousels.
+//!
+//! A collection is an ordered list of course IDs stored under its name.
+//! The featured list is the collection named `featured`.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_admin;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{Course, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Name of the collection shown as featured courses.
+pub const FEATURED_COLLECTION: &str = "featured";
+
+/// Maximum number of courses in a collection.
+pub const MAX_COLLECTION_SIZE: u32 = 50;
+
+fn validate_name(env: &Env, name: &String) {
+    if name.is_empty() || name.len() > 50 {
+        handle_error(env, Error::NameRequired)
+    }
+}
+
+fn require_course(env: &Env, course_id: &String) {
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, course_id.clone()))
+    {
+        handle_error(env, Error::CourseNotFound)
+    }
+}
+
+fn store_collection(env: &Env, admin: &Address, name: String, course_ids: &Vec<String>) {
+    if course_ids.len() > MAX_COLLECTION_SIZE {
+        handle_error(env, RegistryError::CollectionTooLarge)
+    }
+
+    let key: DataKey = DataKey::Collection(name.clone());
+    env.storage().persistent().set(&key, course_ids);
+    bump_persistent(env, &key);
+
+    events::collection_updated(env, &name, admin, course_ids);
+}
+
+/// Returns the course IDs of a collection in display order.
+pub fn get_collection_ids(env: &Env, name: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Collection(name))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the courses of a collection in display order.
+///
+/// Courses that were deleted, archived or unpublished since being added are
+/// skipped, so drafts never show up in a public collection.
+pub fn get_collection(env: &Env, name: String) -> Vec<Course> {
+    let mut courses: Vec<Course> = Vec::new(env);
+    for course_id in get_collection_ids(env, name).iter() {
+        if let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id))
+        {
+            if course.published && !course.is_archived {
+                courses.push_back(course);
+            }
+        }
+    }
+    courses
+}
+
+/// Replaces the featured courses (admin-only).
+pub fn set_featured_courses(env: &Env, admin: Address, course_ids: Vec<String>) {
+    require_admin(env, &admin);
+
+    let mut unique: Vec<String> = Vec::new(env);
+    for course_id in course_ids.iter() {
+        require_course(env, &course_id);
+        if !unique.contains(&course_id) {
+            unique.push_back(course_id);
+        }
+    }
+
+    store_collection(env, &admin, String::from_str(env, FEATURED_COLLECTION), &unique);
+}
+
+/// Appends a course to a collection, creating the collection if needed (admin-only).
+///
+/// Adding a course that is already in the collection does nothing.
+pub fn add_to_collection(env: &Env, admin: Address, name: String, course_id: String) {
+    require_admin(env, &admin);
+    validate_name(env, &name);
+    require_course(env, &course_id);
+
+    let mut course_ids: Vec<String> = get_collection_ids(env, name.clone());
+    if course_ids.contains(&course_id) {
+        return;
+    }
+    course_ids.push_back(course_id);
+
+    store_collection(env, &admin, name, &course_ids);
+}
+
+/// Removes a course from a collection (admin-only).
+pub fn remove_from_collection(env: &Env, admin: Address, name: String, course_id: String) {
+    require_admin(env, &admin);
+
+    let mut course_ids: Vec<String> = get_collection_ids(env, name.clone());
+    if let Some(index) = course_ids.first_index_of(&course_id) {
+        course_ids.remove(index);
+        store_collection(env, &admin, name, &course_ids);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                false
+            }
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let owner: Address = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &owner, &user_mgmt_id);
+        });
+        (client, owner)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, title: &str) -> Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "Description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn set_published(client: &CourseRegistryClient, course: &Course, published: bool) -> Course {
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(published),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&course.creator, &course.id, &params)
+    }
+
+    fn create_published_course(env: &Env, client: &CourseRegistryClient, title: &str) -> Course {
+        set_published(client, &create_course(env, client, title), true)
+    }
+
+    #[test]
+    fn test_featured_courses() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        let rust: Course = create_published_course(&env, &client, "Rust");
+        let go: Course = create_published_course(&env, &client, "Go");
+
+        client.set_featured_courses(&owner, &soroban_sdk::vec![&env, go.id.clone(), rust.id.clone(), go.id.clone()]);
+
+        let featured: Vec<Course> = client.get_collection(&String::from_str(&env, FEATURED_COLLECTION));
+        assert_eq!(featured, soroban_sdk::vec![&env, go.clone(), rust]);
+
+        client.set_featured_courses(&owner, &soroban_sdk::vec![&env, go.id.clone()]);
+        assert_eq!(
+            client.get_collection(&String::from_str(&env, FEATURED_COLLECTION)),
+            soroban_sdk::vec![&env, go]
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_from_collection() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        let rust: Course = create_published_course(&env, &client, "Rust");
+        let go: Course = create_published_course(&env, &client, "Go");
+        let name: String = String::from_str(&env, "backend");
+
+        client.add_to_collection(&owner, &name, &rust.id);
+        client.add_to_collection(&owner, &name, &go.id);
+        client.add_to_collection(&owner, &name, &rust.id);
+        assert_eq!(client.get_collection(&name).len(), 2);
+
+        client.remove_from_collection(&owner, &name, &rust.id);
+        assert_eq!(client.get_collection(&name), soroban_sdk::vec![&env, go.clone()]);
+
+        // Drafts and unpublished courses are hidden
+        let draft: Course = create_course(&env, &client, "Zig");
+        client.add_to_collection(&owner, &name, &draft.id);
+        assert_eq!(client.get_collection(&name), soroban_sdk::vec![&env, go.clone()]);
+        let rust: Course = set_published(&client, &rust, false);
+        client.add_to_collection(&owner, &name, &rust.id);
+        assert_eq!(client.get_collection(&name), soroban_sdk::vec![&env, go.clone()]);
+
+        // Archived courses are hidden
+        client.archive_course(&go.creator, &go.id, &None);
+        assert!(client.get_collection(&name).is_empty());
+        assert!(client.get_collection(&String::from_str(&env, "unknown")).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_add_to_collection_requires_admin() {
+        let env: Env = Env::default();
+        let (client, _owner) = setup(&env);
+        let rust: Course = create_course(&env, &client, "Rust");
+
+        client.add_to_collection(&Address::generate(&env), &String::from_str(&env, "backend"), &rust.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_feature_missing_course_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+
+        client.set_featured_courses(&owner, &soroban_sdk::vec![&env, String::from_str(&env, "42")]);
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Existence checks and counters answering without loading courses.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::schema::DataKey;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_ID: Symbol = symbol_short!("course");
+
+/// Returns whether a course is stored under `course_id`, archived ones included.
+pub fn course_exists(env: &Env, course_id: String) -> bool {
+    env.storage().persistent().has(&(COURSE_KEY, course_id))
+}
+
+/// Returns the number of course IDs issued so far, deleted courses included.
+pub fn get_total_courses(env: &Env) -> u128 {
+    env.storage().persistent().get(&COURSE_ID).unwrap_or(0)
+}
+
+/// Returns the number of courses an instructor created that weren't deleted.
+pub fn get_courses_count_by_instructor(env: &Env, instructor: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InstructorCourseCount(instructor))
+        .unwrap_or(0)
+}
+
+/// Adjusts an instructor's course count when one of their courses is stored or deleted.
+pub(crate) fn on_instructor_course_changed(env: &Env, instructor: &Address, added: bool) {
+    let count: u32 = get_courses_count_by_instructor(env, instructor.clone());
+    let count: u32 = if added {
+        count.saturating_add(1)
+    } else {
+        count.saturating_sub(1)
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::InstructorCourseCount(instructor.clone()), &count);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_course_counts() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = CourseRegistryClient::new(&env, &env.register(CourseRegistry, ()));
+        let creator = Address::generate(&env);
+
+        let course1 = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course One"),
+            &String::from_str(&env, "description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.create_course(
+            &creator,
+            &String::from_str(&env, "Course Two"),
+            &String::from_str(&env, "description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert!(client.course_exists(&course1.id));
+        assert!(!client.course_exists(&String::from_str(&env, "99")));
+        assert_eq!(client.get_total_courses(), 2);
+        assert_eq!(client.get_courses_count_by_instructor(&creator), 2);
+
+        client.delete_course(&creator, &course1.id, &false);
+        assert!(!client.course_exists(&course1.id));
+        assert_eq!(client.get_total_courses(), 2);
+        assert_eq!(client.get_courses_count_by_instructor(&creator), 1);
+        assert_eq!(client.get_courses_count_by_instructor(&Address::generate(&env)), 0);
+    }
+}
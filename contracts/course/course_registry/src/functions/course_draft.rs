@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::edit_course::edit_course;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{Course, DataKey, EditCourseParams};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+fn require_course_creator(env: &Env, creator: &Address, course_id: &String) {
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+
+    if course.creator != *creator {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Returns the staged edits of a course, if any.
+pub fn get_course_draft(env: &Env, course_id: String) -> Option<EditCourseParams> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseDraft(course_id))
+}
+
+/// Stages edits for a course without touching the live version.
+///
+/// Fields set in `params` replace the matching fields of the existing draft;
+/// fields left `None` keep their staged value. Returns the merged draft.
+pub fn save_course_draft(
+    env: &Env,
+    creator: Address,
+    course_id: String,
+    params: EditCourseParams,
+) -> EditCourseParams {
+    creator.require_auth();
+    require_course_creator(env, &creator, &course_id);
+
+    let key: DataKey = DataKey::CourseDraft(course_id.clone());
+    let draft: EditCourseParams = match env.storage().persistent().get::<_, EditCourseParams>(&key) {
+        Some(staged) => EditCourseParams {
+            new_title: params.new_title.or(staged.new_title),
+            new_description: params.new_description.or(staged.new_description),
+            new_price: params.new_price.or(staged.new_price),
+            new_category: params.new_category.or(staged.new_category),
+            new_language: params.new_language.or(staged.new_language),
+            new_thumbnail_url: params.new_thumbnail_url.or(staged.new_thumbnail_url),
+            new_published: params.new_published.or(staged.new_published),
+            new_level: params.new_level.or(staged.new_level),
+            new_duration_hours: params.new_duration_hours.or(staged.new_duration_hours),
+        },
+        None => params,
+    };
+
+    env.storage().persistent().set(&key, &draft);
+    bump_persistent(env, &key);
+
+    events::course_draft_saved(env, &course_id, &creator);
+
+    draft
+}
+
+/// Applies the staged edits to the live course in one step and clears the draft.
+///
+/// The edits go through the same validation as `edit_course`.
+pub fn publish_draft(env: &Env, creator: Address, course_id: String) -> Course {
+    let key: DataKey = DataKey::CourseDraft(course_id.clone());
+    let draft: EditCourseParams = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(env, RegistryError::DraftNotFound));
+
+    let course: Course = edit_course(env.clone(), creator, course_id, draft);
+    env.storage().persistent().remove(&key);
+
+    course
+}
+
+/// Drops the staged edits of a course.
+pub fn discard_draft(env: &Env, creator: Address, course_id: String) {
+    creator.require_auth();
+    require_course_creator(env, &creator, &course_id);
+
+    let key: DataKey = DataKey::CourseDraft(course_id.clone());
+    if !env.storage().persistent().has(&key) {
+        handle_error(env, RegistryError::DraftNotFound)
+    }
+    env.storage().persistent().remove(&key);
+
+    events::course_draft_discarded(env, &course_id, &creator);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn empty_params() -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, Course) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator: Address = Address::generate(env);
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(env, "Rust Basics"),
+            &String::from_str(env, "Live description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course)
+    }
+
+    #[test]
+    fn test_draft_is_staged_until_published() {
+        let env: Env = Env::default();
+        let (client, creator, course) = setup(&env);
+
+        let mut first: EditCourseParams = empty_params();
+        first.new_title = Some(String::from_str(&env, "Rust Fundamentals"));
+        client.save_course_draft(&creator, &course.id, &first);
+
+        let mut second: EditCourseParams = empty_params();
+        second.new_price = Some(2500);
+        let draft: EditCourseParams = client.save_course_draft(&creator, &course.id, &second);
+        assert_eq!(draft.new_title, Some(String::from_str(&env, "Rust Fundamentals")));
+        assert_eq!(draft.new_price, Some(2500));
+
+        // The live version is unchanged while the draft is staged
+        assert_eq!(client.get_course(&course.id), course);
+
+        let published: Course = client.publish_draft(&creator, &course.id);
+        assert_eq!(published.title, String::from_str(&env, "Rust Fundamentals"));
+        assert_eq!(published.price, 2500);
+        assert_eq!(client.get_course(&course.id), published);
+        assert!(client.get_course_draft(&course.id).is_none());
+    }
+
+    #[test]
+    fn test_discard_draft() {
+        let env: Env = Env::default();
+        let (client, creator, course) = setup(&env);
+
+        let mut params: EditCourseParams = empty_params();
+        params.new_price = Some(2500);
+        client.save_course_draft(&creator, &course.id, &params);
+        client.discard_draft(&creator, &course.id);
+
+        assert!(client.get_course_draft(&course.id).is_none());
+        assert_eq!(client.get_course(&course.id).price, 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_publish_without_draft_fails() {
+        let env: Env = Env::default();
+        let (client, creator, course) = setup(&env);
+
+        client.publish_draft(&creator, &course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_save_draft_requires_creator() {
+        let env: Env = Env::default();
+        let (client, _creator, course) = setup(&env);
+
+        client.save_course_draft(&Address::generate(&env), &course.id, &empty_params());
+    }
+}
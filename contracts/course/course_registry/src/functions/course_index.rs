@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Per-course module and goal indexes.
+//!
+//! `DataKey::CourseModules` and `DataKey::CourseGoalList` list the module and
+//! goal IDs of a course. Modules and goals added before the indexes existed
+//! can't be found from their course: their IDs embed a ledger sequence or
+//! random numbers, and storage can't be scanned. An admin stages those IDs,
+//! taken from the `module_added` and `goal_added` events, with
+//! `stage_legacy_course_index`. Reads include the staged IDs until the
+//! 1.0.0 -> 1.1.0 migration folds them into the indexes.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::functions::access_control::require_admin;
+use crate::schema::{CourseModule, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Persistent storage key prefix: `(LEGACY_MODULES_KEY, course_id) -> Vec<String>`
+const LEGACY_MODULES_KEY: Symbol = symbol_short!("lgcyMods");
+/// Persistent storage key prefix: `(LEGACY_GOALS_KEY, course_id) -> Vec<String>`
+const LEGACY_GOALS_KEY: Symbol = symbol_short!("lgcyGoals");
+/// Persistent storage key: courses with staged IDs not folded yet -> Vec<String>
+const LEGACY_COURSES_KEY: Symbol = symbol_short!("lgcyCrses");
+
+/// Returns the IDs in `index`, followed by those staged in `legacy` that the
+/// index doesn't hold yet.
+fn merged_ids(env: &Env, index: &DataKey, legacy: &(Symbol, String)) -> Vec<String> {
+    let mut ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(index)
+        .unwrap_or(Vec::new(env));
+    let staged: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(legacy)
+        .unwrap_or(Vec::new(env));
+    for id in staged.iter() {
+        if !ids.contains(&id) {
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+/// Appends to the staged list under `legacy` the IDs of `ids` not in `known`.
+fn stage_ids(env: &Env, legacy: &(Symbol, String), known: &Vec<String>, ids: &Vec<String>) {
+    let mut staged: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(legacy)
+        .unwrap_or(Vec::new(env));
+    for id in ids.iter() {
+        if !known.contains(&id) && !staged.contains(&id) {
+            staged.push_back(id);
+        }
+    }
+    if !staged.is_empty() {
+        env.storage().persistent().set(legacy, &staged);
+    }
+}
+
+/// Returns the module IDs of a course, including staged legacy modules.
+pub fn get_course_module_ids(env: &Env, course_id: &String) -> Vec<String> {
+    merged_ids(
+        env,
+        &DataKey::CourseModules(course_id.clone()),
+        &(LEGACY_MODULES_KEY, course_id.clone()),
+    )
+}
+
+/// Replaces the module index of a course, folding in its staged legacy modules.
+pub fn set_course_module_ids(env: &Env, course_id: &String, module_ids: &Vec<String>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseModules(course_id.clone()), module_ids);
+    env.storage()
+        .persistent()
+        .remove(&(LEGACY_MODULES_KEY, course_id.clone()));
+}
+
+/// Returns the goal IDs of a course, including staged legacy goals.
+pub fn get_course_goal_ids(env: &Env, course_id: &String) -> Vec<String> {
+    merged_ids(
+        env,
+        &DataKey::CourseGoalList(course_id.clone()),
+        &(LEGACY_GOALS_KEY, course_id.clone()),
+    )
+}
+
+/// Replaces the goal list of a course, folding in its staged legacy goals.
+pub fn set_course_goal_ids(env: &Env, course_id: &String, goal_ids: &Vec<String>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseGoalList(course_id.clone()), goal_ids);
+    env.storage()
+        .persistent()
+        .remove(&(LEGACY_GOALS_KEY, course_id.clone()));
+}
+
+/// Removes both indexes of a deleted course, staged entries included.
+pub fn remove_course_indexes(env: &Env, course_id: &String) {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::CourseModules(course_id.clone()));
+    storage.remove(&DataKey::CourseGoalList(course_id.clone()));
+    storage.remove(&(LEGACY_MODULES_KEY, course_id.clone()));
+    storage.remove(&(LEGACY_GOALS_KEY, course_id.clone()));
+}
+
+/// Stages the IDs of modules and goals added to a course before the indexes
+/// existed (owner or admin only).
+///
+/// Every module must exist and belong to the course, and every goal must be
+/// stored under it. IDs already indexed or staged are skipped.
+pub fn stage_legacy_course_index(
+    env: &Env,
+    admin: Address,
+    course_id: String,
+    module_ids: Vec<String>,
+    goal_ids: Vec<String>,
+) {
+    require_admin(env, &admin);
+
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id.clone())) {
+        handle_error(env, Error::CourseIdNotExist)
+    }
+    for module_id in module_ids.iter() {
+        let module: CourseModule = env
+            .storage()
+            .persistent()
+            .get(&(MODULE_KEY, module_id))
+            .unwrap_or_else(|| handle_error(env, Error::ModuleNotFound));
+        if module.course_id != course_id {
+            handle_error(env, RegistryError::ModuleCourseMismatch)
+        }
+    }
+    for goal_id in goal_ids.iter() {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::CourseGoal(course_id.clone(), goal_id))
+        {
+            handle_error(env, Error::GoalCourseMismatch)
+        }
+    }
+
+    stage_ids(
+        env,
+        &(LEGACY_MODULES_KEY, course_id.clone()),
+        &get_course_module_ids(env, &course_id),
+        &module_ids,
+    );
+    stage_ids(
+        env,
+        &(LEGACY_GOALS_KEY, course_id.clone()),
+        &get_course_goal_ids(env, &course_id),
+        &goal_ids,
+    );
+
+    let mut courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&LEGACY_COURSES_KEY)
+        .unwrap_or(Vec::new(env));
+    if !courses.contains(&course_id) {
+        courses.push_back(course_id);
+        env.storage().persistent().set(&LEGACY_COURSES_KEY, &courses);
+    }
+}
+
+/// Number of courses with staged legacy IDs, walked by the migration step.
+pub(crate) fn legacy_course_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<_, Vec<String>>(&LEGACY_COURSES_KEY)
+        .map(|courses| courses.len())
+        .unwrap_or(0)
+}
+
+/// Folds the staged IDs of the course at `index` into its indexes. The list
+/// of staged courses is dropped once its last course was folded.
+pub(crate) fn fold_legacy_course_index(env: &Env, index: u32) {
+    let courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&LEGACY_COURSES_KEY)
+        .unwrap_or(Vec::new(env));
+    if let Some(course_id) = courses
+        .get(index)
+        .filter(|course_id| env.storage().persistent().has(&(COURSE_KEY, course_id.clone())))
+    {
+        set_course_module_ids(env, &course_id, &get_course_module_ids(env, &course_id));
+        set_course_goal_ids(env, &course_id, &get_course_goal_ids(env, &course_id));
+    }
+    if index + 1 >= courses.len() {
+        env.storage().persistent().remove(&LEGACY_COURSES_KEY);
+    }
+}
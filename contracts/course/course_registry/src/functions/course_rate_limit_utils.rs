@@ -2,7 +2,7 @@
 // Copyright (c) 2025 SkillCert
 
 use crate::error::{handle_error, Error};
-use crate::schema::{DataKey, CourseRateLimitData, CourseRateLimitConfig, DEFAULT_COURSE_RATE_LIMIT_WINDOW, DEFAULT_MAX_COURSE_CREATIONS_PER_WINDOW};
+use crate::schema::{DataKey, CourseRateLimitData, CourseRateLimitConfig, DEFAULT_COURSE_RATE_LIMIT_WINDOW, DEFAULT_MAX_COURSE_CREATIONS_PER_WINDOW, LEDGER_CLOSE_SECONDS};
 use soroban_sdk::{Address, Env};
 
 /// Check if the user has exceeded the rate limit for course creation operations.
@@ -38,7 +38,7 @@ pub fn check_course_creation_rate_limit(env: &Env, creator: &Address) {
     // Get existing rate limit data or create new one
     let mut rate_data = match env
         .storage()
-        .persistent()
+        .temporary()
         .get::<DataKey, CourseRateLimitData>(&rate_limit_key)
     {
         Some(data) => data,
@@ -63,8 +63,17 @@ pub fn check_course_creation_rate_limit(env: &Env, creator: &Address) {
     // Increment the count and save
     rate_data.count += 1;
     env.storage()
-        .persistent()
+        .temporary()
         .set(&rate_limit_key, &rate_data);
+
+    // The counter is only needed until its window ends
+    let window_ledgers: u32 = rate_config
+        .window_seconds
+        .div_ceil(LEDGER_CLOSE_SECONDS)
+        .clamp(1, env.storage().max_ttl() as u64) as u32;
+    env.storage()
+        .temporary()
+        .extend_ttl(&rate_limit_key, window_ledgers, window_ledgers);
 }
 
 /// Get the default rate limiting configuration for course operations.
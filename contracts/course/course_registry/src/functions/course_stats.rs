@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::functions::access_control::is_owner;
+use crate::functions::platform_stats::on_enrollment;
+use crate::functions::sort_index::{get_course_rating, update_sort_index};
+use crate::schema::{Course, CourseStats, DataKey, InstructorStats, SortField};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_STATS_REPORTER_EVENT: Symbol = symbol_short!("setStRep");
+const COURSE_STATS_EVENT: Symbol = symbol_short!("crsStats");
+const COURSE_RATING_EVENT: Symbol = symbol_short!("crsRating");
+
+/// Highest rating a student can give a course.
+pub const MAX_RATING: u32 = 5;
+
+/// Returns the contracts allowed to report statistics.
+pub fn get_stats_reporters(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::StatsReporters)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Allows or disallows a contract to report course statistics.
+/// Only the contract owner can manage reporters.
+pub fn set_stats_reporter(env: &Env, caller: Address, reporter: Address, allowed: bool) {
+    caller.require_auth();
+
+    if !is_owner(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let mut reporters: Vec<Address> = get_stats_reporters(env);
+
+    match reporters.first_index_of(&reporter) {
+        Some(index) if !allowed => {
+            reporters.remove(index);
+        }
+        None if allowed => reporters.push_back(reporter.clone()),
+        _ => {}
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::StatsReporters, &reporters);
+
+    env.events()
+        .publish((SET_STATS_REPORTER_EVENT,), (caller, reporter, allowed));
+}
+
+/// Records a new enrollment in a course.
+pub fn record_enrollment(env: &Env, reporter: Address, course_id: String) -> CourseStats {
+    update_stats(env, reporter, course_id, 1, 0, 0)
+}
+
+/// Records that a student completed a course.
+pub fn record_completion(env: &Env, reporter: Address, course_id: String) -> CourseStats {
+    update_stats(env, reporter, course_id, 0, 1, 0)
+}
+
+/// Records a purchase of a course for the given amount.
+pub fn record_purchase(env: &Env, reporter: Address, course_id: String, amount: u128) -> CourseStats {
+    update_stats(env, reporter, course_id, 0, 0, amount)
+}
+
+/// Records a student rating (1 to `MAX_RATING`) of a course.
+///
+/// Ratings are aggregated per instructor as a running sum and count.
+pub fn record_rating(env: &Env, reporter: Address, course_id: String, rating: u32) {
+    require_stats_reporter(env, &reporter);
+
+    if rating == 0 || rating > MAX_RATING {
+        handle_error(env, RegistryError::InvalidRating)
+    }
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+
+    let key: DataKey = DataKey::InstructorRating(course.creator);
+    let (sum, count): (u64, u32) = env.storage().persistent().get(&key).unwrap_or((0, 0));
+    env.storage()
+        .persistent()
+        .set(&key, &(sum.saturating_add(rating as u64), count.saturating_add(1)));
+
+    let (sum, count): (u64, u32) = get_course_rating(env, course_id.clone());
+    let (sum, count): (u64, u32) = (sum.saturating_add(rating as u64), count.saturating_add(1));
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseRating(course_id.clone()), &(sum, count));
+    update_sort_index(env, SortField::Rating, &course_id, (sum * 100 / count as u64) as u128);
+
+    env.events()
+        .publish((COURSE_RATING_EVENT, course_id), rating);
+}
+
+/// Returns the average rating of an instructor's courses in hundredths, 0 if unrated.
+pub fn get_instructor_avg_rating(env: &Env, instructor: Address) -> u32 {
+    let (sum, count): (u64, u32) = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InstructorRating(instructor))
+        .unwrap_or((0, 0));
+    if count == 0 {
+        return 0;
+    }
+    (sum * 100 / count as u64) as u32
+}
+
+/// Returns the counters of a course, all zero if nothing was reported yet.
+pub fn get_course_stats(env: &Env, course_id: String) -> CourseStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseStats(course_id))
+        .unwrap_or_default()
+}
+
+/// Returns the counters summed over the courses of an instructor.
+pub fn get_instructor_stats(env: &Env, instructor: Address) -> InstructorStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InstructorStats(instructor))
+        .unwrap_or_default()
+}
+
+/// Requires the reporter's auth and that it was registered with `set_stats_reporter`.
+pub fn require_stats_reporter(env: &Env, reporter: &Address) {
+    reporter.require_auth();
+
+    if !get_stats_reporters(env).contains(reporter) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+fn update_stats(
+    env: &Env,
+    reporter: Address,
+    course_id: String,
+    enrollments: u32,
+    completions: u32,
+    revenue: u128,
+) -> CourseStats {
+    require_stats_reporter(env, &reporter);
+
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+
+    let mut stats: CourseStats = get_course_stats(env, course_id.clone());
+    stats.enrollments = stats.enrollments.saturating_add(enrollments);
+    stats.completions = stats.completions.saturating_add(completions);
+    stats.revenue = stats.revenue.saturating_add(revenue);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseStats(course_id.clone()), &stats);
+
+    let mut instructor_stats: InstructorStats = get_instructor_stats(env, course.creator.clone());
+    instructor_stats.enrollments = instructor_stats.enrollments.saturating_add(enrollments);
+    instructor_stats.completions = instructor_stats.completions.saturating_add(completions);
+    instructor_stats.revenue = instructor_stats.revenue.saturating_add(revenue);
+    env.storage()
+        .persistent()
+        .set(&DataKey::InstructorStats(course.creator), &instructor_stats);
+
+    if enrollments > 0 {
+        on_enrollment(env);
+        update_sort_index(env, SortField::Enrollments, &course_id, stats.enrollments as u128);
+    }
+
+    env.events()
+        .publish((COURSE_STATS_EVENT, course_id), stats.clone());
+
+    stats
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::functions::access_control::initialize;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+
+        let owner = Address::generate(env);
+        let user_mgmt = Address::generate(env);
+        env.as_contract(&contract_id, || initialize(env, &owner, &user_mgmt));
+
+        let reporter = Address::generate(env);
+        client.set_stats_reporter(&owner, &reporter, &true);
+        (client, owner, reporter)
+    }
+
+    #[test]
+    fn test_course_and_instructor_stats() {
+        let env = Env::default();
+        let (client, _owner, reporter) = setup(&env);
+        let creator = Address::generate(&env);
+
+        let course1 = client.create_course(&creator, &String::from_str(&env, "Course One"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+        let course2 = client.create_course(&creator, &String::from_str(&env, "Course Two"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+
+        client.record_enrollment(&reporter, &course1.id);
+        client.record_enrollment(&reporter, &course1.id);
+        client.record_purchase(&reporter, &course1.id, &1000);
+        client.record_completion(&reporter, &course1.id);
+        client.record_enrollment(&reporter, &course2.id);
+        client.record_purchase(&reporter, &course2.id, &500);
+
+        let stats = client.get_course_stats(&course1.id);
+        assert_eq!(stats, CourseStats { enrollments: 2, completions: 1, revenue: 1000 });
+
+        let instructor = client.get_instructor_stats(&creator);
+        assert_eq!(instructor, InstructorStats { enrollments: 3, completions: 1, revenue: 1500 });
+
+        // Nothing reported yet
+        let other = client.get_course_stats(&String::from_str(&env, "404"));
+        assert_eq!(other, CourseStats::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_record_by_unknown_reporter_fails() {
+        let env = Env::default();
+        let (client, owner, reporter) = setup(&env);
+        let creator = Address::generate(&env);
+        let course = client.create_course(&creator, &String::from_str(&env, "Course One"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+
+        client.set_stats_reporter(&owner, &reporter, &false);
+        client.record_enrollment(&reporter, &course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_stats_reporter_not_owner() {
+        let env = Env::default();
+        let (client, _owner, _reporter) = setup(&env);
+        let stranger = Address::generate(&env);
+
+        client.set_stats_reporter(&stranger, &stranger, &true);
+    }
+}
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::content_limits::{validate_description_length, validate_title_length};
+use crate::functions::get_course::get_course;
+use crate::functions::ttl::bump_persistent;
+use crate::functions::utils::trim;
+use crate::schema::{Course, CourseTranslation, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Adds or replaces the title and description of a course in `language`.
+///
+/// Only the course creator can translate a course. The default record keeps
+/// its own language; translations are looked up with `get_course_localized`.
+pub fn add_course_translation(
+    env: &Env,
+    creator: Address,
+    course_id: String,
+    language: String,
+    title: String,
+    description: String,
+) -> CourseTranslation {
+    creator.require_auth();
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    // Same limits as the default course fields
+    if language.is_empty() || language.len() > 50 {
+        handle_error(env, Error::InvalidLanguageLength)
+    }
+    if trim(env, &title).is_empty() {
+        handle_error(env, Error::EmptyCourseTitle)
+    }
+    validate_title_length(env, &title);
+    validate_description_length(env, &description);
+
+    let translation: CourseTranslation = CourseTranslation {
+        course_id: course_id.clone(),
+        language: language.clone(),
+        title,
+        description,
+    };
+    let key: DataKey = DataKey::CourseTranslation(course_id.clone(), language.clone());
+    env.storage().persistent().set(&key, &translation);
+    bump_persistent(env, &key);
+
+    // Track the languages so the translations can be listed and cleaned up
+    let languages_key: DataKey = DataKey::CourseLanguages(course_id.clone());
+    let mut languages: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&languages_key)
+        .unwrap_or(Vec::new(env));
+    if !languages.contains(&language) {
+        languages.push_back(language);
+        env.storage().persistent().set(&languages_key, &languages);
+    }
+    bump_persistent(env, &languages_key);
+
+    events::course_localized(env, &course_id, &creator);
+
+    translation
+}
+
+/// Languages a course has been translated into.
+pub fn get_course_languages(env: &Env, course_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseLanguages(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the course with its title and description in `language`.
+///
+/// Falls back to the default record when no translation exists.
+pub fn get_course_localized(env: &Env, course_id: String, language: String) -> Course {
+    let mut course: Course = get_course(env, course_id.clone());
+
+    let key: DataKey = DataKey::CourseTranslation(course_id, language.clone());
+    if let Some(translation) = env
+        .storage()
+        .persistent()
+        .get::<_, CourseTranslation>(&key)
+    {
+        bump_persistent(env, &key);
+        course.title = translation.title;
+        course.description = translation.description;
+        course.language = Some(language);
+    }
+
+    course
+}
+
+/// Removes every translation of a course.
+pub fn remove_course_translations(env: &Env, course_id: &String) {
+    let languages_key: DataKey = DataKey::CourseLanguages(course_id.clone());
+    let languages: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&languages_key)
+        .unwrap_or(Vec::new(env));
+    for language in languages.iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CourseTranslation(course_id.clone(), language));
+    }
+    env.storage().persistent().remove(&languages_key);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, Course) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator: Address = Address::generate(env);
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(env, "Rust Basics"),
+            &String::from_str(env, "Learn Rust"),
+            &1000,
+            &None,
+            &Some(String::from_str(env, "en")),
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course)
+    }
+
+    #[test]
+    fn test_get_course_localized() {
+        let env: Env = Env::default();
+        let (client, creator, course) = setup(&env);
+        let es: String = String::from_str(&env, "es");
+
+        client.add_course_translation(
+            &creator,
+            &course.id,
+            &es,
+            &String::from_str(&env, "Fundamentos de Rust"),
+            &String::from_str(&env, "Aprende Rust"),
+        );
+
+        let localized: Course = client.get_course_localized(&course.id, &es);
+        assert_eq!(localized.title, String::from_str(&env, "Fundamentos de Rust"));
+        assert_eq!(localized.description, String::from_str(&env, "Aprende Rust"));
+        assert_eq!(localized.language, Some(es.clone()));
+        assert_eq!(localized.price, course.price);
+
+        // Unknown languages fall back to the default record
+        let fallback: Course = client.get_course_localized(&course.id, &String::from_str(&env, "fr"));
+        assert_eq!(fallback, course);
+
+        // The default record is unchanged
+        assert_eq!(client.get_course(&course.id), course);
+        assert_eq!(client.get_course_languages(&course.id), soroban_sdk::vec![&env, es]);
+    }
+
+    #[test]
+    fn test_translation_is_replaced() {
+        let env: Env = Env::default();
+        let (client, creator, course) = setup(&env);
+        let es: String = String::from_str(&env, "es");
+
+        for title in ["Rust basico", "Fundamentos de Rust"] {
+            client.add_course_translation(
+                &creator,
+                &course.id,
+                &es,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "Aprende Rust"),
+            );
+        }
+
+        assert_eq!(
+            client.get_course_localized(&course.id, &es).title,
+            String::from_str(&env, "Fundamentos de Rust")
+        );
+        assert_eq!(client.get_course_languages(&course.id).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_translation_requires_creator() {
+        let env: Env = Env::default();
+        let (client, _creator, course) = setup(&env);
+
+        client.add_course_translation(
+            &Address::generate(&env),
+            &course.id,
+            &String::from_str(&env, "es"),
+            &String::from_str(&env, "Fundamentos de Rust"),
+            &String::from_str(&env, "Aprende Rust"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #43)")]
+    fn test_translation_requires_language() {
+        let env: Env = Env::default();
+        let (client, creator, course) = setup(&env);
+
+        client.add_course_translation(
+            &creator,
+            &course.id,
+            &String::from_str(&env, ""),
+            &String::from_str(&env, "Fundamentos de Rust"),
+            &String::from_str(&env, "Aprende Rust"),
+        );
+    }
+}
@@ -1,17 +1,26 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use super::utils::{to_lowercase, trim, u32_to_string};
+use super::access_control::require_create_permission;
+use super::utils::{course_id_to_string, to_lowercase, trim};
 use super::course_rate_limit_utils::check_course_creation_rate_limit;
+use super::list_categories::invalidate_category_counts;
+use super::course_counts::on_instructor_course_changed;
+use super::platform_stats::on_course_created;
+use super::sort_index::index_course_sort_keys;
+use super::price_limits::validate_course_price;
+use super::content_limits::{validate_description_length, validate_title_length};
+use super::search_courses::index_course_title;
+use super::ttl::bump_persistent;
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, CourseLevel};
+use crate::events;
+use crate::schema::{Course, CourseLevel, DataKey, NewCourseParams};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const TITLE_KEY: Symbol = symbol_short!("title");
 const COURSE_ID: Symbol = symbol_short!("course");
 
-const CREATE_COURSE_EVENT: Symbol = symbol_short!("crtCourse");
 const GENERATE_COURSE_ID_EVENT: Symbol = symbol_short!("genCrsId");
 
 pub fn create_course(
@@ -28,99 +37,143 @@ pub fn create_course(
 ) -> Course {
     creator.require_auth();
 
+    store_new_course(
+        &env,
+        creator,
+        NewCourseParams {
+            title,
+            description,
+            price,
+            category,
+            language,
+            thumbnail_url,
+            level,
+            duration_hours,
+        },
+    )
+}
+
+/// Validates and stores a new unpublished course owned by `creator`.
+/// Callers are responsible for `creator.require_auth()`.
+pub fn store_new_course(env: &Env, creator: Address, params: NewCourseParams) -> Course {
+    require_create_permission(env, &creator);
+
     // Check rate limiting before proceeding with course creation
-    check_course_creation_rate_limit(&env, &creator);
+    check_course_creation_rate_limit(env, &creator);
 
-    // ensure the title is not empty and not just whitespace
-    let trimmed_title: String = trim(&env, &title);
-    if title.is_empty() || trimmed_title.is_empty() {
-        handle_error(&env, Error::EmptyCourseTitle);
-    }
+    validate_new_course(env, &params);
 
-    // Additional title validation
-    if title.len() > 200 {
-        handle_error(&env, Error::InvalidTitleLength);
-    }
+    let course: Course = insert_course(env, creator, params);
+    events::course_created(env, &course);
+
+    course
+}
 
-    // Validate description - only check length, allow empty
-    if description.len() > 2000 {
-        handle_error(&env, Error::InvalidCourseDescription);
+/// Checks the fields of a new course and that its title is not taken.
+pub fn validate_new_course(env: &Env, params: &NewCourseParams) {
+    // ensure the title is not empty and not just whitespace
+    let trimmed_title: String = trim(env, &params.title);
+    if params.title.is_empty() || trimmed_title.is_empty() {
+        handle_error(env, Error::EmptyCourseTitle);
     }
 
+    // Check the configured length limits; the description may be empty
+    validate_title_length(env, &params.title);
+    validate_description_length(env, &params.description);
+
     // ensure the price is greater than 0
-    if price == 0 {
-        handle_error(&env, Error::InvalidPrice);
+    if params.price == 0 {
+        handle_error(env, Error::InvalidPrice);
     }
+    validate_course_price(env, params.price);
 
     // Validate optional parameters
-    if let Some(ref cat) = category {
+    if let Some(ref cat) = params.category {
         if cat.is_empty() || cat.len() > 100 {
-            handle_error(&env, Error::EmptyCategory);
+            handle_error(env, Error::EmptyCategory);
         }
     }
 
-    if let Some(ref lang) = language {
+    if let Some(ref lang) = params.language {
         if lang.is_empty() || lang.len() > 50 {
-            handle_error(&env, Error::InvalidLanguageLength);
+            handle_error(env, Error::InvalidLanguageLength);
         }
     }
 
-    if let Some(ref url) = thumbnail_url {
+    if let Some(ref url) = params.thumbnail_url {
         if url.is_empty() || url.len() > 500 {
-            handle_error(&env, Error::InvalidThumbnailUrlLength);
+            handle_error(env, Error::InvalidThumbnailUrlLength);
         }
     }
 
-    if let Some(duration) = duration_hours {
+    if let Some(duration) = params.duration_hours {
         if duration == 0 || duration > 8760 {
             // 8760 hours = 1 year, reasonable maximum
-            handle_error(&env, Error::InvalidDurationValue);
+            handle_error(env, Error::InvalidDurationValue);
         }
     }
 
-    let lowercase_title: String = to_lowercase(&env, &title);
-
     // to avoid duplicate title,
-    let title_key: (Symbol, String) = (TITLE_KEY, lowercase_title);
-
-    if env.storage().persistent().has(&title_key) {
-        handle_error(&env, Error::DuplicateCourseTitle)
+    if env.storage().persistent().has(&title_key(env, &params.title)) {
+        handle_error(env, Error::DuplicateCourseTitle)
     }
+}
+
+/// Storage key that reserves a course title, compared case-insensitively.
+pub fn title_key(env: &Env, title: &String) -> (Symbol, String) {
+    (TITLE_KEY, to_lowercase(env, title))
+}
 
+/// Stores an already validated course and updates the title, search and
+/// statistics indexes. Does not emit an event.
+pub fn insert_course(env: &Env, creator: Address, params: NewCourseParams) -> Course {
     // generate the unique id
-    let id: u128 = generate_course_id(&env);
-    let converted_id: String = u32_to_string(&env, id as u32);
+    let id: u128 = generate_course_id(env);
+    let converted_id: String = course_id_to_string(env, id);
 
     let storage_key: (Symbol, String) = (COURSE_KEY, converted_id.clone());
 
     if env.storage().persistent().has(&storage_key) {
-        handle_error(&env, Error::DuplicateCourseId)
+        handle_error(env, Error::DuplicateCourseId)
     }
 
     // create a new course
     let new_course: Course = Course {
         id: converted_id.clone(),
-        title: title.clone(),
-        description: description.clone(),
-        creator: creator.clone(),
-        price,
-        category: category.clone(),
-        language: language.clone(),
-        thumbnail_url: thumbnail_url.clone(),
+        title: params.title,
+        description: params.description,
+        creator,
+        price: params.price,
+        category: params.category,
+        language: params.language,
+        thumbnail_url: params.thumbnail_url,
         published: false,
-        prerequisites: Vec::new(&env),
+        prerequisites: Vec::new(env),
         is_archived: false,
-        level: level.clone(),
-        duration_hours,
+        level: params.level,
+        duration_hours: params.duration_hours,
     };
 
     // save to the storage
+    let title_key: (Symbol, String) = title_key(env, &new_course.title);
     env.storage().persistent().set(&storage_key, &new_course);
     env.storage().persistent().set(&title_key, &true);
+    bump_persistent(env, &storage_key);
+    bump_persistent(env, &title_key);
+    index_course_title(env, &converted_id, &new_course.title);
+    on_course_created(env);
+    on_instructor_course_changed(env, &new_course.creator, true);
+    index_course_sort_keys(env, &new_course);
+    if new_course.category.is_some() {
+        invalidate_category_counts(env);
+    }
 
-    // emit an event
-    env.events()
-        .publish((CREATE_COURSE_EVENT,), (converted_id, creator, title, description, price, category, language, thumbnail_url, level, duration_hours));
+    let joined_key: DataKey = DataKey::InstructorJoined(new_course.creator.clone());
+    if !env.storage().persistent().has(&joined_key) {
+        env.storage()
+            .persistent()
+            .set(&joined_key, &String::from_str(env, crate::VERSION));
+    }
 
     new_course
 }
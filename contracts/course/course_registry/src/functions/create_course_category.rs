@@ -1,10 +1,12 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, String, Vec, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, String, Symbol, symbol_short};
 
 use crate::error::{handle_error, Error};
-use crate::schema::{CourseCategory, DataKey};
+use crate::functions::access_control::require_admin;
+use crate::functions::action_rate_limit::check_action_rate_limit;
+use crate::schema::{CourseCategory, DataKey, RateLimitedAction};
 
 const CREATE_COURSE_CATEGORY_EVENT: Symbol = symbol_short!("crtCrsCat");
 const NEXT_CATEGORY_ID_EVENT: Symbol = symbol_short!("nxtCatId");
@@ -13,7 +15,7 @@ const NEXT_CATEGORY_ID_EVENT: Symbol = symbol_short!("nxtCatId");
 ///
 /// Arguments:
 /// - env: Soroban environment.
-/// - caller: transaction caller (must be admin).
+/// - caller: transaction caller (must be the owner or a user management admin).
 /// - name: category name (must be non-empty).
 /// - description: optional category description.
 ///
@@ -30,10 +32,8 @@ pub fn create_course_category(
     description: Option<String>,
 ) -> u128 {
     // Authentication and authorization
-    caller.require_auth();
-    if !is_admin(&env, caller.clone()) {
-        handle_error(&env, Error::Unauthorized)
-    }
+    require_admin(&env, &caller);
+    check_action_rate_limit(&env, &caller, RateLimitedAction::CategoryCreation);
 
     // Basic validation
     if name.is_empty() {
@@ -73,18 +73,6 @@ pub fn create_course_category(
     id
 }
 
-/// Checks whether who is an admin using the same pattern as user_management contract.
-/// This assumes the course_registry contract has its own admin system or uses a similar pattern.
-fn is_admin(env: &Env, who: Address) -> bool {
-    // For now, we'll use a simple storage-based admin check
-    // In a production environment, you might want to integrate with the user_management contract
-    let admins: Option<Vec<Address>> = env.storage().persistent().get(&DataKey::Admins);
-    match admins {
-        Some(list) => list.iter().any(|a| a == who),
-        None => false,
-    }
-}
-
 /// Retrieves and increments a sequence used for category IDs.
 /// Storage key is DataKey::CategorySeq -> u128.
 fn next_category_id(env: &Env) -> u128 {
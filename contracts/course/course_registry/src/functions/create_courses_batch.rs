@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_create_permission;
+use crate::functions::course_rate_limit_utils::check_course_creation_rate_limit;
+use crate::functions::create_course::{insert_course, validate_new_course};
+use crate::functions::utils::to_lowercase;
+use crate::schema::{Course, NewCourseParams};
+
+/// Maximum number of courses accepted by a single `create_courses_batch` call.
+pub const MAX_COURSE_BATCH_SIZE: u32 = 20;
+
+/// Creates several unpublished courses owned by `creator` in one call.
+///
+/// Every entry is validated before anything is stored, so either all
+/// courses are created or none. The batch uses a single slot of the
+/// creator's rate limit and publishes one aggregated event.
+pub fn create_courses_batch(
+    env: Env,
+    creator: Address,
+    courses: Vec<NewCourseParams>,
+) -> Vec<Course> {
+    creator.require_auth();
+
+    if courses.is_empty() || courses.len() > MAX_COURSE_BATCH_SIZE {
+        handle_error(&env, RegistryError::InvalidBatchSize)
+    }
+
+    require_create_permission(&env, &creator);
+    check_course_creation_rate_limit(&env, &creator);
+
+    // Validate everything first, including duplicate titles inside the batch
+    let mut titles: Vec<String> = Vec::new(&env);
+    for params in courses.iter() {
+        validate_new_course(&env, &params);
+        let title: String = to_lowercase(&env, &params.title);
+        if titles.contains(&title) {
+            handle_error(&env, Error::DuplicateCourseTitle)
+        }
+        titles.push_back(title);
+    }
+
+    let mut created: Vec<Course> = Vec::new(&env);
+    let mut course_ids: Vec<String> = Vec::new(&env);
+    for params in courses.iter() {
+        let course: Course = insert_course(&env, creator.clone(), params);
+        course_ids.push_back(course.id.clone());
+        created.push_back(course);
+    }
+
+    events::courses_batch_created(&env, &creator, &course_ids);
+
+    created
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn params(env: &Env, title: &str) -> NewCourseParams {
+        NewCourseParams {
+            title: String::from_str(env, title),
+            description: String::from_str(env, "Imported course"),
+            price: 1000,
+            category: None,
+            language: None,
+            thumbnail_url: None,
+            level: None,
+            duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_create_courses_batch() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let mut batch: Vec<NewCourseParams> = Vec::new(&env);
+        for title in ["Algebra", "Geometry", "Calculus", "Statistics", "Topology"] {
+            batch.push_back(params(&env, title));
+        }
+
+        let created: Vec<Course> = client.create_courses_batch(&creator, &batch);
+
+        assert_eq!(created.len(), 5);
+        for (i, course) in created.iter().enumerate() {
+            assert_eq!(client.get_course(&course.id), course);
+            assert_eq!(course.title, batch.get(i as u32).unwrap().title);
+            assert!(!course.published);
+        }
+        // More courses than the per-window limit, yet the batch counts once
+        client.create_course(
+            &creator,
+            &String::from_str(&env, "Number Theory"),
+            &String::from_str(&env, "Single course"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    fn test_create_courses_batch_is_all_or_nothing() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let mut invalid: NewCourseParams = params(&env, "Geometry");
+        invalid.price = 0;
+        let batch: Vec<NewCourseParams> =
+            soroban_sdk::vec![&env, params(&env, "Algebra"), invalid];
+
+        assert!(client.try_create_courses_batch(&creator, &batch).is_err());
+        assert!(client.try_get_course(&String::from_str(&env, "1")).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #10)")]
+    fn test_create_courses_batch_rejects_duplicate_titles() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let batch: Vec<NewCourseParams> =
+            soroban_sdk::vec![&env, params(&env, "Algebra"), params(&env, "ALGEBRA")];
+        client.create_courses_batch(&Address::generate(&env), &batch);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_create_courses_batch_rejects_oversized_batch() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let mut batch: Vec<NewCourseParams> = Vec::new(&env);
+        for _ in 0..=MAX_COURSE_BATCH_SIZE {
+            batch.push_back(params(&env, "Course"));
+        }
+        client.create_courses_batch(&Address::generate(&env), &batch);
+    }
+}
@@ -3,12 +3,13 @@
 
 use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
 
+use crate::events;
 use crate::error::{handle_error, Error};
+use crate::functions::prerequisite_index::set_course_prerequisites;
 use crate::schema::{Course, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-const PREREQ_CREATED_EVENT: Symbol = symbol_short!("prereqAdd");
 
 pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequisites: Vec<String>) {
     creator.require_auth();
@@ -72,13 +73,9 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
 
     validate_no_circular_dependency(&env, &course_id, &prerequisites);
 
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &prerequisites,
-    );
+    set_course_prerequisites(&env, &course_id, &prerequisites);
 
-    env.events()
-        .publish((PREREQ_CREATED_EVENT, course_id), prerequisites.len());
+    events::prerequisites_added(&env, &course_id, &prerequisites);
 }
 
 fn validate_no_circular_dependency(env: &Env, course_id: &String, new_prerequisites: &Vec<String>) {
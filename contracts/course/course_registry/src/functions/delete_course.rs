@@ -1,20 +1,98 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+//! Two-phase course deletion.
+//!
+//! `delete_course` takes a course out of every listing and keeps it aside
+//! as a `DeletedCourse` for the deletion window. Its creator can restore it
+//! until then; afterwards `purge_deleted` removes it for good, together with
+//! everything that depends on it.
+
 use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, Vec};
 
-use crate::error::{handle_error, Error};
-use crate::schema::{Course, CourseModule};
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events::{self, CourseDeletedEvent};
+use crate::schema::{
+    Course, CourseModule, CourseStatus, DataKey, DeletedCourse, DEFAULT_DELETION_WINDOW,
+    MAX_REBUILD_BATCH,
+};
+use crate::functions::access_control::{active_access_count, is_admin, is_owner, require_admin};
+use crate::functions::course_index::{get_course_goal_ids, get_course_module_ids, remove_course_indexes};
+use crate::functions::course_translation::remove_course_translations;
+use crate::functions::free_course::clear_course_free;
+use crate::functions::get_courses_by_instructor::course_status;
+use crate::functions::list_categories::invalidate_category_counts;
+use crate::functions::completion_criteria::clear_completion_criteria;
+use crate::functions::module_duration::clear_module_duration;
+use crate::functions::module_prerequisites::clear_module_prerequisites;
+use crate::functions::module_release::clear_module_release_schedule;
+use crate::functions::course_counts::on_instructor_course_changed;
+use crate::functions::prerequisite_index::{remove_course_prerequisites, remove_from_dependents};
+use crate::functions::platform_stats::{on_course_created, on_course_deleted, on_course_published_changed};
+use crate::functions::sort_index::{index_course_sort_keys, remove_from_sort_indexes};
+use crate::functions::search_courses::{index_course_title, unindex_course_title};
+use crate::functions::status_history::record_status_change;
+use crate::functions::ttl::bump_persistent;
 use crate::functions::utils::{concat_strings, to_lowercase, u32_to_string};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
 const TITLE_KEY: Symbol = symbol_short!("title");
 
-const DELETE_COURSE_EVENT: Symbol = symbol_short!("delCourse");
+const SET_DELETION_WINDOW_EVENT: Symbol = symbol_short!("setDelWin");
 
-pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(), &'static str> {
-    creator.require_auth();
+/// Returns how many seconds a deleted course can be restored for.
+pub fn get_deletion_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DeletionWindow)
+        .unwrap_or(DEFAULT_DELETION_WINDOW)
+}
+
+/// Sets how many seconds a deleted course can be restored for. Owner or admin only.
+///
+/// Courses already deleted keep the window they were deleted with.
+pub fn set_deletion_window(env: &Env, admin: Address, seconds: u64) {
+    require_admin(env, &admin);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::DeletionWindow, &seconds);
+
+    env.events()
+        .publish((SET_DELETION_WINDOW_EVENT,), (admin, seconds));
+}
+
+/// Returns a deleted course that hasn't been purged yet.
+pub fn get_deleted_course(env: &Env, course_id: String) -> Option<DeletedCourse> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeletedCourse(course_id))
+}
+
+fn pending_deletions(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingDeletions)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_pending_deletions(env: &Env, pending: &Vec<String>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingDeletions, pending);
+    bump_persistent(env, &DataKey::PendingDeletions);
+}
+
+/// Deletes a course: it leaves the listings, search and counters at once,
+/// and is purged with its modules, goals and prerequisite edges once the
+/// deletion window has passed. Its title stays reserved until then.
+///
+/// The creator can delete a course nobody has access to. Courses with
+/// students can only be deleted by the owner or an admin passing `force`;
+/// their access grants are kept in the course access contract.
+pub fn delete_course(env: &Env, caller: Address, course_id: String, force: bool) -> Result<(), &'static str> {
+    caller.require_auth();
 
     if course_id.is_empty() {
         handle_error(env, Error::EmptyCourseId)
@@ -32,27 +110,194 @@ pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(
         .get(&course_storage_key)
         .ok_or("Course not found")?;
 
-    if course.creator != creator {
+    let forced_by_admin: bool = force && (is_owner(env, &caller) || is_admin(env, &caller));
+    if course.creator != caller && !forced_by_admin {
+        handle_error(env, Error::Unauthorized)
+    }
+    if !forced_by_admin && active_access_count(env, &course_id) > 0 {
+        handle_error(env, RegistryError::CourseHasEnrollments)
+    }
+
+    unindex_course_title(env, &course_id, &course.title);
+    on_course_deleted(env, course.published);
+    on_instructor_course_changed(env, &course.creator, false);
+    remove_from_sort_indexes(env, &course_id);
+    env.storage().persistent().remove(&course_storage_key);
+    if course.category.is_some() {
+        invalidate_category_counts(env);
+    }
+
+    let deleted_at: u64 = env.ledger().timestamp();
+    let deleted_key: DataKey = DataKey::DeletedCourse(course_id.clone());
+    let old_status: CourseStatus = course_status(&course);
+    env.storage().persistent().set(
+        &deleted_key,
+        &DeletedCourse {
+            course,
+            deleted_by: caller.clone(),
+            deleted_at,
+            purge_after: deleted_at.saturating_add(get_deletion_window(env)),
+        },
+    );
+    bump_persistent(env, &deleted_key);
+
+    let mut pending: Vec<String> = pending_deletions(env);
+    pending.push_back(course_id.clone());
+    set_pending_deletions(env, &pending);
+
+    record_status_change(env, &course_id, old_status, CourseStatus::Deleted, &caller, None);
+
+    Ok(())
+}
+
+/// Undoes the deletion of a course within its deletion window, putting it
+/// back in the listings, search and counters. Creator only.
+pub fn restore_course(env: &Env, creator: Address, course_id: String) -> Course {
+    creator.require_auth();
+
+    let deleted_key: DataKey = DataKey::DeletedCourse(course_id.clone());
+    let deleted: DeletedCourse = env
+        .storage()
+        .persistent()
+        .get(&deleted_key)
+        .unwrap_or_else(|| handle_error(env, RegistryError::CourseNotDeleted));
+    if deleted.course.creator != creator {
         handle_error(env, Error::Unauthorized)
     }
+    if env.ledger().timestamp() >= deleted.purge_after {
+        handle_error(env, RegistryError::RecoveryWindowExpired)
+    }
+
+    let course: Course = deleted.course;
+    let course_storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    env.storage().persistent().set(&course_storage_key, &course);
+    bump_persistent(env, &course_storage_key);
+    env.storage().persistent().remove(&deleted_key);
+
+    let mut pending: Vec<String> = pending_deletions(env);
+    if let Some(index) = pending.first_index_of(&course_id) {
+        pending.remove(index);
+        set_pending_deletions(env, &pending);
+    }
 
-    delete_course_modules(env, &course_id);
+    index_course_title(env, &course_id, &course.title);
+    on_course_created(env);
+    if course.published {
+        on_course_published_changed(env, true);
+    }
+    on_instructor_course_changed(env, &course.creator, true);
+    index_course_sort_keys(env, &course);
+    if course.category.is_some() {
+        invalidate_category_counts(env);
+    }
 
-    let lowercase_title: String = to_lowercase(env, &course.title);
+    record_status_change(
+        env,
+        &course_id,
+        CourseStatus::Deleted,
+        course_status(&course),
+        &creator,
+        None,
+    );
+
+    course
+}
+
+/// Permanently removes up to `limit` deleted courses whose deletion window
+/// has passed, with their modules, goals, prerequisite edges on either side,
+/// stats, drafts, translations and title. Each purge is summed up by one
+/// `("course", "deleted")` event. Owner or admin only.
+///
+/// Returns the number of courses purged; courses still in their window stay
+/// pending.
+pub fn purge_deleted(env: &Env, admin: Address, limit: u32) -> u32 {
+    require_admin(env, &admin);
+    if limit == 0 || limit > MAX_REBUILD_BATCH {
+        handle_error(env, RegistryError::InvalidBatchSize)
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let mut remaining: Vec<String> = Vec::new(env);
+    let mut purged: u32 = 0;
+    for course_id in pending_deletions(env).iter() {
+        if purged < limit {
+            match get_deleted_course(env, course_id.clone()) {
+                // Already restored or purged
+                None => continue,
+                Some(deleted) if now >= deleted.purge_after => {
+                    purge_course(env, deleted);
+                    purged += 1;
+                    continue;
+                }
+                Some(_) => {}
+            }
+        }
+        remaining.push_back(course_id);
+    }
+    set_pending_deletions(env, &remaining);
+
+    purged
+}
+
+/// Removes a deleted course and everything that depends on it.
+fn purge_course(env: &Env, deleted: DeletedCourse) {
+    let course_id: String = deleted.course.id.clone();
+
+    let modules_removed: u32 = delete_course_modules(env, &course_id);
+    let goals_removed: u32 = delete_course_goals(env, &course_id);
+    remove_course_indexes(env, &course_id);
+    let prerequisites_removed: u32 = remove_course_prerequisites(env, &course_id);
+    let dependents_updated: u32 = remove_from_dependents(env, &course_id);
+
+    let lowercase_title: String = to_lowercase(env, &deleted.course.title);
 
     let title_key: (Symbol, String) = (TITLE_KEY, lowercase_title);
     env.storage().persistent().remove(&title_key);
-    env.storage().persistent().remove(&course_storage_key);
-
-    // emit an event
-    env.events()
-        .publish((DELETE_COURSE_EVENT,), (creator, course_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseDraft(course_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseStats(course_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseRating(course_id.clone()));
+    remove_course_translations(env, &course_id);
+    clear_course_free(env, &course_id);
+    clear_completion_criteria(env, &course_id);
+    clear_module_release_schedule(env, &course_id);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DeletedCourse(course_id.clone()));
+
+    events::course_deleted(
+        env,
+        &CourseDeletedEvent {
+            version: events::EVENT_VERSION,
+            course_id,
+            actor: deleted.deleted_by,
+            modules_removed,
+            goals_removed,
+            prerequisites_removed,
+            dependents_updated,
+        },
+    );
+}
 
-    Ok(())
+/// Removes the goals of a course and returns how many there were.
+fn delete_course_goals(env: &Env, course_id: &String) -> u32 {
+    let goal_ids: Vec<String> = get_course_goal_ids(env, course_id);
+    for goal_id in goal_ids.iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CourseGoal(course_id.clone(), goal_id));
+    }
+    goal_ids.len()
 }
 
-fn delete_course_modules(env: &Env, course_id: &String) {
-    let mut modules_to_delete: Vec<String> = Vec::new(env);
+/// Removes the modules of a course and returns how many there were.
+fn delete_course_modules(env: &Env, course_id: &String) -> u32 {
+    let mut modules_to_delete: Vec<String> = get_course_module_ids(env, course_id);
 
     let mut counter = 0u32;
     loop {
@@ -69,7 +314,7 @@ fn delete_course_modules(env: &Env, course_id: &String) {
         let key = (MODULE_KEY, module_id.clone());
         if env.storage().persistent().has(&key) {
             if let Some(module) = env.storage().persistent().get::<_, CourseModule>(&key) {
-                if module.course_id == *course_id {
+                if module.course_id == *course_id && !modules_to_delete.contains(&module_id) {
                     modules_to_delete.push_back(module_id);
                 }
             }
@@ -82,18 +327,26 @@ fn delete_course_modules(env: &Env, course_id: &String) {
         }
     }
 
+    let mut removed: u32 = 0;
     for id in modules_to_delete.iter() {
-        env.storage().persistent().remove(&(MODULE_KEY, id.clone()));
-        env.events().publish((id.clone(),), "module_deleted");
+        let key = (MODULE_KEY, id.clone());
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+            clear_module_prerequisites(env, &id);
+            clear_module_duration(env, &id);
+            removed += 1;
+        }
     }
+    removed
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::functions::prerequisite_index::get_course_dependents;
     use crate::schema::Course;
     use crate::{CourseRegistry, CourseRegistryClient};
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
 
     // Mock UserManagement contract for testing
     mod mock_user_management {
@@ -110,6 +363,33 @@ mod tests {
         }
     }
 
+    // Mock CourseAccess contract reporting a fixed number of students
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn set_count(env: Env, count: u32) {
+                env.storage().instance().set(&"count", &count);
+            }
+            pub fn get_course_access_count(env: Env, _course_id: String) -> u32 {
+                env.storage().instance().get(&"count").unwrap_or(0)
+            }
+        }
+    }
+
+    fn setup_with_students(students: u32) -> (Env, Address, Address, CourseRegistryClient<'static>) {
+        let (env, contract_id, client) = setup_test_env();
+        let owner: Address = Address::generate(&env);
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        mock_course_access::CourseAccessClient::new(&env, &course_access_id).set_count(&students);
+        client.set_course_access_contract(&owner, &course_access_id);
+        (env, contract_id, owner, client)
+    }
+
     fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
         let env = Env::default();
         env.mock_all_auths();
@@ -128,6 +408,13 @@ mod tests {
 
         (env, contract_id, client)
     }
+
+    /// Moves past the default deletion window and purges every deleted course.
+    fn purge_all(env: &Env, client: &CourseRegistryClient<'static>) -> u32 {
+        env.ledger()
+            .with_mut(|ledger| ledger.timestamp += DEFAULT_DELETION_WINDOW);
+        client.purge_deleted(&Address::generate(env), &MAX_REBUILD_BATCH)
+    }
     use soroban_sdk::{Env, String};
 
     #[test]
@@ -154,7 +441,7 @@ mod tests {
             &None,
         );
 
-        client.delete_course(&impostor, &new_course.id.clone());
+        client.delete_course(&impostor, &new_course.id.clone(), &false);
     }
 
     #[test]
@@ -185,7 +472,7 @@ mod tests {
         let retrieved_course = client.get_course(&course.id);
         assert_eq!(retrieved_course.creator, actual_creator);
 
-        client.delete_course(&someone_else, &course.id);
+        client.delete_course(&someone_else, &course.id, &false);
     }
 
     #[test]
@@ -216,7 +503,7 @@ mod tests {
             client.get_course(&new_course.id.clone()).creator
         );
 
-        client.delete_course(&creator, &new_course.id.clone());
+        client.delete_course(&creator, &new_course.id.clone(), &false);
 
         let exists: bool = env.as_contract(&contract_id, || {
             env.storage()
@@ -258,7 +545,7 @@ mod tests {
         });
         assert!(module_exists);
 
-        client.delete_course(&creator, &new_course.id.clone());
+        client.delete_course(&creator, &new_course.id.clone(), &false);
 
         let course_exists: bool = env.as_contract(&contract_id, || {
             env.storage()
@@ -267,6 +554,15 @@ mod tests {
         });
         assert!(!course_exists);
 
+        // Modules are kept until the course is purged
+        let module_exists: bool = env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .has(&(MODULE_KEY, module.id.clone()))
+        });
+        assert!(module_exists);
+        assert_eq!(purge_all(&env, &client), 1);
+
         let module_exists: bool = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
@@ -287,7 +583,7 @@ mod tests {
         let creator: Address = Address::generate(&env);
         let fake_id = String::from_str(&env, "not_found");
 
-        client.delete_course(&creator, &fake_id);
+        client.delete_course(&creator, &fake_id, &false);
     }
 
     #[test]
@@ -324,7 +620,7 @@ mod tests {
             &None,
         );
 
-        client.delete_course(&creator, &course1.id.clone());
+        client.delete_course(&creator, &course1.id.clone(), &false);
 
         let course1_exists: bool = env.as_contract(&contract_id, || {
             env.storage()
@@ -340,4 +636,217 @@ mod tests {
         });
         assert!(course2_exists);
     }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #109)")]
+    fn test_delete_course_with_students_fails_without_force() {
+        let (env, _contract_id, _owner, client) = setup_with_students(2);
+        let creator: Address = Address::generate(&env);
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.delete_course(&creator, &course.id, &false);
+    }
+
+    #[test]
+    fn test_forced_delete_course_removes_goals_and_prerequisites() {
+        let (env, contract_id, owner, client) = setup_with_students(2);
+        let creator: Address = Address::generate(&env);
+        let basics: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "basics"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let goal = client.add_goal(&creator, &course.id, &String::from_str(&env, "Learn"));
+        client.add_prerequisite(&creator, &course.id, &vec![&env, basics.id.clone()]);
+
+        client.delete_course(&owner, &course.id, &true);
+        purge_all(&env, &client);
+
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            assert!(!storage.has(&(COURSE_KEY, course.id.clone())));
+            assert!(!storage.has(&DataKey::CourseGoal(course.id.clone(), goal.goal_id.clone())));
+            assert!(!storage.has(&DataKey::CourseGoalList(course.id.clone())));
+            assert!(!storage.has(&DataKey::CoursePrerequisites(course.id.clone())));
+        });
+    }
+
+    #[test]
+    fn test_delete_course_cascades_to_dependents_and_emits_summary() {
+        use crate::events::CourseDeletedEvent;
+        use soroban_sdk::testutils::Events;
+        use soroban_sdk::TryFromVal;
+
+        let (env, contract_id, client) = setup_test_env();
+        let creator: Address = Address::generate(&env);
+        let new_course = |title: &str| -> Course {
+            client.create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+        };
+        let basics: Course = new_course("basics");
+        let intro: Course = new_course("intro");
+        let advanced: Course = new_course("advanced");
+        client.add_prerequisite(&creator, &intro.id, &vec![&env, basics.id.clone()]);
+        client.add_prerequisite(
+            &creator,
+            &advanced.id,
+            &vec![&env, basics.id.clone(), intro.id.clone()],
+        );
+        client.add_module(&creator, &intro.id, &0, &String::from_str(&env, "Module"));
+        client.add_goal(&creator, &intro.id, &String::from_str(&env, "Goal"));
+
+        client.delete_course(&creator, &intro.id, &false);
+        purge_all(&env, &client);
+
+        let (_, _, data) = env.events().all().last().unwrap();
+        assert_eq!(
+            CourseDeletedEvent::try_from_val(&env, &data).unwrap(),
+            CourseDeletedEvent {
+                version: events::EVENT_VERSION,
+                course_id: intro.id.clone(),
+                actor: creator.clone(),
+                modules_removed: 1,
+                goals_removed: 1,
+                prerequisites_removed: 1,
+                dependents_updated: 1,
+            }
+        );
+
+        let advanced_prerequisites: Vec<String> = env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .get(&DataKey::CoursePrerequisites(advanced.id.clone()))
+                .unwrap()
+        });
+        assert_eq!(advanced_prerequisites, vec![&env, basics.id.clone()]);
+        let basics_dependents: Vec<String> = env.as_contract(&contract_id, || {
+            get_course_dependents(&env, &basics.id)
+        });
+        assert_eq!(basics_dependents, vec![&env, advanced.id]);
+    }
+
+    #[test]
+    fn test_soft_delete_restore_and_purge() {
+        let (env, contract_id, client) = setup_test_env();
+        let creator: Address = Address::generate(&env);
+        let admin: Address = Address::generate(&env);
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Rust basics"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module"));
+        let indexed = || -> bool {
+            env.as_contract(&contract_id, || {
+                env.storage()
+                    .persistent()
+                    .get::<_, Vec<String>>(&DataKey::TitleToken(String::from_str(&env, "rust")))
+                    .is_some_and(|ids| ids.contains(&course.id))
+            })
+        };
+
+        // A deleted course leaves listings and counters but keeps its title
+        client.delete_course(&creator, &course.id, &false);
+        assert!(!client.course_exists(&course.id));
+        assert_eq!(client.get_courses_count_by_instructor(&creator), 0);
+        assert!(!indexed());
+        let deleted = client.get_deleted_course(&course.id).unwrap();
+        assert_eq!(deleted.deleted_by, creator);
+        assert_eq!(deleted.purge_after, deleted.deleted_at + DEFAULT_DELETION_WINDOW);
+        assert_eq!(
+            client.get_status_history(&course.id).last().unwrap().new_status,
+            CourseStatus::Deleted
+        );
+        assert!(client
+            .try_create_course(
+                &creator,
+                &String::from_str(&env, "Rust basics"),
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .is_err());
+
+        // Nothing is purged within the window, and only the creator can restore
+        assert_eq!(client.purge_deleted(&admin, &10), 0);
+        assert!(client.try_restore_course(&admin, &course.id).is_err());
+        let restored: Course = client.restore_course(&creator, &course.id);
+        assert_eq!(restored, course);
+        assert!(client.course_exists(&course.id));
+        assert_eq!(client.get_courses_count_by_instructor(&creator), 1);
+        assert!(indexed());
+        assert_eq!(client.list_modules(&course.id).len(), 1);
+        assert_eq!(client.get_deleted_course(&course.id), None);
+        assert_eq!(
+            client.try_restore_course(&creator, &course.id),
+            Err(Ok(RegistryError::CourseNotDeleted.into()))
+        );
+
+        // After the window the course can no longer be restored and gets purged
+        client.set_deletion_window(&admin, &60);
+        assert_eq!(client.get_deletion_window(), 60);
+        client.delete_course(&creator, &course.id, &false);
+        env.ledger().with_mut(|ledger| ledger.timestamp += 60);
+        assert_eq!(
+            client.try_restore_course(&creator, &course.id),
+            Err(Ok(RegistryError::RecoveryWindowExpired.into()))
+        );
+        assert_eq!(client.purge_deleted(&admin, &10), 1);
+        assert_eq!(client.get_deleted_course(&course.id), None);
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            assert!(!storage.has(&DataKey::CourseModules(course.id.clone())));
+            assert!(!storage.has(&(TITLE_KEY, String::from_str(&env, "rust basics"))));
+        });
+        assert_eq!(
+            client.try_restore_course(&creator, &course.id),
+            Err(Ok(RegistryError::CourseNotDeleted.into()))
+        );
+    }
 }
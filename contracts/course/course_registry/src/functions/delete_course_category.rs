@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_admin;
+use crate::functions::merge_categories::{
+    category_course_count, reassign_category_courses, require_category,
+};
+use crate::schema::{CourseCategory, DataKey};
+
+/// Deletes a course category (admin-only).
+///
+/// A category still used by courses can only be deleted when a replacement
+/// category is given; its courses are then moved to the replacement.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - admin: transaction caller (must be the owner or a user management admin).
+/// - category_id: the category to delete.
+/// - replacement_id: category that receives the courses of the deleted one.
+///
+/// Returns:
+/// - u32: the number of courses moved to the replacement.
+pub fn delete_course_category(
+    env: Env,
+    admin: Address,
+    category_id: u128,
+    replacement_id: Option<u128>,
+) -> u32 {
+    require_admin(&env, &admin);
+
+    let category: CourseCategory = require_category(&env, category_id);
+
+    let reassigned: u32 = match replacement_id {
+        Some(replacement_id) => {
+            if replacement_id == category_id {
+                handle_error(&env, RegistryError::SameCategory)
+            }
+            let replacement: CourseCategory = require_category(&env, replacement_id);
+            reassign_category_courses(&env, &category.name, &replacement.name)
+        }
+        None => {
+            if category_course_count(&env, &category.name) > 0 {
+                handle_error(&env, RegistryError::CategoryInUse)
+            }
+            0
+        }
+    };
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseCategory(category_id));
+
+    events::category_deleted(&env, category_id, &admin);
+
+    reassigned
+}
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Q&A thread anchors per module.
+//!
+//! Threads and replies only store hashes of their text, which lives
+//! off-chain, so discussions can be verified without paying for the bodies.
+//! Students need access to the course, checked against the course access
+//! contract; the course creator and admins can always take part.
+
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::access_control::{has_course_access, is_admin, is_course_creator};
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{CourseModule, DataKey, DiscussionThread, ThreadReply};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Maximum length of a thread title.
+const MAX_THREAD_TITLE_LENGTH: u32 = 200;
+
+/// Maximum number of threads or replies returned by one listing call.
+pub const MAX_DISCUSSION_PAGE: u32 = 50;
+
+/// Panics with `NoCourseAccess` unless `who` has access to the course, created it or is an admin.
+fn require_participant(env: &Env, who: &Address, course_id: &String) {
+    if has_course_access(env, course_id, who) || is_course_creator(env, course_id, who) {
+        return;
+    }
+    if !is_admin(env, who) {
+        handle_error(env, RegistryError::NoCourseAccess)
+    }
+}
+
+/// Opens a thread about a module of a course.
+pub fn create_thread(
+    env: &Env,
+    student: Address,
+    course_id: String,
+    module_id: String,
+    title: String,
+    content_hash: BytesN<32>,
+) -> DiscussionThread {
+    student.require_auth();
+
+    if title.is_empty() || title.len() > MAX_THREAD_TITLE_LENGTH {
+        handle_error(env, RegistryError::InvalidThreadTitle)
+    }
+    let module: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&(MODULE_KEY, module_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::ModuleNotFound));
+    if module.course_id != course_id {
+        handle_error(env, Error::ModuleNotFound)
+    }
+    require_participant(env, &student, &course_id);
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::ThreadCount)
+        .unwrap_or(0)
+        + 1;
+    let thread: DiscussionThread = DiscussionThread {
+        id,
+        course_id,
+        module_id: module_id.clone(),
+        author: student,
+        title,
+        content_hash,
+        created_at: env.ledger().timestamp(),
+        reply_count: 0,
+    };
+    env.storage().persistent().set(&DataKey::ThreadCount, &id);
+    store_thread(env, &thread);
+
+    let threads_key: DataKey = DataKey::ModuleThreads(module_id);
+    let mut thread_ids: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&threads_key)
+        .unwrap_or(Vec::new(env));
+    thread_ids.push_back(id);
+    env.storage().persistent().set(&threads_key, &thread_ids);
+    bump_persistent(env, &threads_key);
+
+    events::thread_created(env, &thread);
+
+    thread
+}
+
+fn store_thread(env: &Env, thread: &DiscussionThread) {
+    let key: DataKey = DataKey::Thread(thread.id);
+    env.storage().persistent().set(&key, thread);
+    bump_persistent(env, &key);
+}
+
+/// Returns a thread, or `None` if it does not exist.
+pub fn get_thread(env: &Env, thread_id: u32) -> Option<DiscussionThread> {
+    env.storage().persistent().get(&DataKey::Thread(thread_id))
+}
+
+/// Appends a reply to a thread.
+pub fn reply_to_thread(env: &Env, user: Address, thread_id: u32, content_hash: BytesN<32>) -> ThreadReply {
+    user.require_auth();
+
+    let mut thread: DiscussionThread = get_thread(env, thread_id)
+        .unwrap_or_else(|| handle_error(env, RegistryError::ThreadNotFound));
+    require_participant(env, &user, &thread.course_id);
+
+    thread.reply_count += 1;
+    let reply: ThreadReply = ThreadReply {
+        thread_id,
+        index: thread.reply_count,
+        author: user,
+        content_hash,
+        created_at: env.ledger().timestamp(),
+    };
+    let key: DataKey = DataKey::ThreadReply(thread_id, reply.index);
+    env.storage().persistent().set(&key, &reply);
+    bump_persistent(env, &key);
+    store_thread(env, &thread);
+
+    events::thread_replied(env, &reply);
+
+    reply
+}
+
+/// Returns up to `limit` threads of a module, oldest first, after skipping `offset`.
+pub fn list_module_threads(env: &Env, module_id: String, offset: u32, limit: u32) -> Vec<DiscussionThread> {
+    let thread_ids: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ModuleThreads(module_id))
+        .unwrap_or(Vec::new(env));
+
+    let mut threads: Vec<DiscussionThread> = Vec::new(env);
+    let end: u32 = offset.saturating_add(limit.min(MAX_DISCUSSION_PAGE)).min(thread_ids.len());
+    for i in offset..end {
+        if let Some(thread) = get_thread(env, thread_ids.get_unchecked(i)) {
+            threads.push_back(thread);
+        }
+    }
+    threads
+}
+
+/// Returns up to `limit` replies of a thread, oldest first, after skipping `offset`.
+pub fn list_thread_replies(env: &Env, thread_id: u32, offset: u32, limit: u32) -> Vec<ThreadReply> {
+    let reply_count: u32 = get_thread(env, thread_id)
+        .map(|thread| thread.reply_count)
+        .unwrap_or(0);
+
+    let mut replies: Vec<ThreadReply> = Vec::new(env);
+    let end: u32 = offset.saturating_add(limit.min(MAX_DISCUSSION_PAGE)).min(reply_count);
+    for index in offset.saturating_add(1)..=end {
+        if let Some(reply) = env
+            .storage()
+            .persistent()
+            .get::<_, ThreadReply>(&DataKey::ThreadReply(thread_id, index))
+        {
+            replies.push_back(reply);
+        }
+    }
+    replies
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, contractimpl};
+
+    #[contract]
+    pub struct CourseAccess;
+
+    #[contractimpl]
+    impl CourseAccess {
+        pub fn grant(env: Env, user: Address) {
+            env.storage().instance().set(&user, &true);
+        }
+        pub fn has_access(env: Env, _course_id: String, user: Address) -> bool {
+            env.storage().instance().has(&user)
+        }
+    }
+
+    #[contract]
+    pub struct UserManagement;
+
+    #[contractimpl]
+    impl UserManagement {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, CourseAccessClient<'_>, Address, CourseModule) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let owner: Address = Address::generate(env);
+        let user_mgmt_id: Address = env.register(UserManagement, ());
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &owner, &user_mgmt_id);
+        });
+        let course_access_id: Address = env.register(CourseAccess, ());
+        client.set_course_access_contract(&owner, &course_access_id);
+
+        let creator: Address = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "Description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Intro"));
+        (client, CourseAccessClient::new(env, &course_access_id), creator, module)
+    }
+
+    #[test]
+    fn test_threads_and_replies() {
+        let env: Env = Env::default();
+        let (client, course_access, creator, module) = setup(&env);
+        let student: Address = Address::generate(&env);
+        course_access.grant(&student);
+        let hash: BytesN<32> = BytesN::from_array(&env, &[1; 32]);
+
+        let thread = client.create_thread(
+            &student,
+            &module.course_id,
+            &module.id,
+            &String::from_str(&env, "Question"),
+            &hash,
+        );
+        assert_eq!(thread.id, 1);
+        client.reply_to_thread(&creator, &thread.id, &BytesN::from_array(&env, &[2; 32]));
+        client.reply_to_thread(&student, &thread.id, &hash);
+
+        assert_eq!(client.get_thread(&thread.id).unwrap().reply_count, 2);
+        let threads = client.list_module_threads(&module.id, &0, &10);
+        assert_eq!(threads.len(), 1);
+        let replies = client.list_thread_replies(&thread.id, &1, &10);
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies.get(0).unwrap().author, student);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #112)")]
+    fn test_create_thread_requires_access() {
+        let env: Env = Env::default();
+        let (client, _course_access, _creator, module) = setup(&env);
+
+        client.create_thread(
+            &Address::generate(&env),
+            &module.course_id,
+            &module.id,
+            &String::from_str(&env, "Question"),
+            &BytesN::from_array(&env, &[1; 32]),
+        );
+    }
+}
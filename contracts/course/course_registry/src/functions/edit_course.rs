@@ -4,13 +4,23 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, EditCourseParams};
+use crate::events;
+use crate::schema::{Course, CourseStatus, EditCourseParams, SortField};
+use crate::functions::free_course::clear_course_free;
+use crate::functions::list_categories::invalidate_category_counts;
+use crate::functions::platform_stats::on_course_published_changed;
+use crate::functions::price_limits::validate_course_price;
+use crate::functions::content_limits::{validate_description_length, validate_title_length};
+use crate::functions::ttl::bump_persistent;
+use crate::functions::search_courses::{index_course_title, unindex_course_title};
+use crate::functions::sort_index::update_sort_index;
+use crate::functions::status_history::record_status_change;
+use crate::functions::get_courses_by_instructor::course_status;
 use crate::functions::utils::{to_lowercase, trim};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const TITLE_KEY: Symbol = symbol_short!("title");
 
-const EDIT_COURSE_EVENT: Symbol = symbol_short!("editCours");
 
 pub fn edit_course(
     env: Env,
@@ -43,6 +53,7 @@ pub fn edit_course(
         if t_trim.is_empty() {
             handle_error(&env, Error::EmptyCourseTitle)
         }
+        validate_title_length(&env, &t_str);
 
         // Only check/rotate title index if it's effectively changing (case-insensitive)
         let old_title_lc: String = to_lowercase(&env, &course.title);
@@ -60,12 +71,17 @@ pub fn edit_course(
             env.storage().persistent().remove(&old_title_key);
             env.storage().persistent().set(&new_title_key, &true);
 
+            // move the course to the search index entries of the new title
+            unindex_course_title(&env, &course.id, &course.title);
+            index_course_title(&env, &course.id, &t_trim);
+
             course.title = t_trim;
         }
     }
 
     // --- Description ---
     if let Some(ref d) = params.new_description {
+        validate_description_length(&env, d);
         course.description = d.clone();
     }
 
@@ -74,12 +90,16 @@ pub fn edit_course(
         if p == 0 {
             handle_error(&env, Error::InvalidPrice);
         }
+        validate_course_price(&env, p);
         course.price = p;
+        clear_course_free(&env, &course_id);
+        update_sort_index(&env, SortField::Price, &course_id, p);
     }
 
     // --- Optional fields: category / language / thumbnail ---
     if let Some(cat) = params.new_category {
         course.category = cat; // Some(value) sets; None clears
+        invalidate_category_counts(&env);
     }
     if let Some(lang) = params.new_language {
         course.language = lang;
@@ -89,7 +109,11 @@ pub fn edit_course(
     }
 
     // --- Published flag ---
+    let old_status: CourseStatus = course_status(&course);
     if let Some(p) = params.new_published {
+        if course.published != p {
+            on_course_published_changed(&env, p);
+        }
         course.published = p;
     }
 
@@ -105,10 +129,13 @@ pub fn edit_course(
 
     // --- Persist updated course ---
     env.storage().persistent().set(&storage_key, &course);
+    bump_persistent(&env, &storage_key);
 
     // --- Emit event ---
-    env.events()
-        .publish((EDIT_COURSE_EVENT,), (creator, course_id));
+    if course_status(&course) != old_status {
+        record_status_change(&env, &course_id, old_status, course_status(&course), &creator, None);
+    }
+    events::course_updated(&env, &course_id, &creator);
 
     course
 }
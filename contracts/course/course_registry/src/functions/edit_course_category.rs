@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::access_control::require_admin;
+use crate::functions::merge_categories::{reassign_category_courses, require_category};
+use crate::schema::{CourseCategory, DataKey};
+
+/// Renames a course category and replaces its description (admin-only).
+///
+/// Courses reference their category by name, so a rename is carried over to
+/// every course in the category.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - admin: transaction caller (must be the owner or a user management admin).
+/// - category_id: the category to edit.
+/// - new_name: category name (must be non-empty).
+/// - new_description: optional category description.
+///
+/// Returns:
+/// - CourseCategory: the updated category.
+pub fn edit_course_category(
+    env: Env,
+    admin: Address,
+    category_id: u128,
+    new_name: String,
+    new_description: Option<String>,
+) -> CourseCategory {
+    require_admin(&env, &admin);
+
+    // Same validation as category creation
+    if new_name.is_empty() {
+        handle_error(&env, Error::NameRequired)
+    }
+    if new_name.len() > 100 {
+        handle_error(&env, Error::InvalidCategoryName)
+    }
+    if let Some(ref desc) = new_description {
+        if desc.len() > 500 {
+            handle_error(&env, Error::InvalidCategoryName)
+        }
+    }
+
+    let mut category: CourseCategory = require_category(&env, category_id);
+    if category.name != new_name {
+        reassign_category_courses(&env, &category.name, &new_name);
+    }
+
+    category.name = new_name;
+    category.description = new_description;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseCategory(category_id), &category);
+
+    events::category_updated(&env, category_id, &admin);
+
+    category
+}
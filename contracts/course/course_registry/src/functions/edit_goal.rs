@@ -4,13 +4,13 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 use crate::functions::is_course_creator::is_course_creator;
+use crate::events;
 use crate::error::{handle_error, Error};
 use crate::functions::utils::trim;
 use crate::schema::{Course, CourseGoal, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-const GOAL_EDITED_EVENT: Symbol = symbol_short!("goalEdit");
 
 pub fn edit_goal(
     env: Env,
@@ -58,11 +58,7 @@ pub fn edit_goal(
     // Save updated goal
     env.storage().persistent().set(&goal_key, &goal);
 
-    // Emit event
-    env.events().publish(
-        (GOAL_EDITED_EVENT, course_id.clone(), goal_id.clone()),
-        new_content.clone(),
-    );
+    events::goal_edited(&env, &goal);
 
     goal
 }
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::events;
+use crate::error::{handle_error, Error};
+use crate::functions::module_duration::set_module_duration;
+use crate::schema::CourseModule;
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+
+/// Updates a module's title, and optionally its position and duration.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: course creator or admin performing the edit.
+/// - module_id: unique identifier of the module.
+/// - new_title: the module's new title.
+/// - new_position: optional new position; must not be taken by another module.
+/// - new_duration: optional estimated duration in minutes.
+///
+/// Returns:
+/// - CourseModule: the updated module.
+///
+/// Errors:
+/// - `Error::EmptyModuleId` / `Error::ModuleNotFound` for unknown modules.
+/// - `Error::InvalidModuleTitle` for empty or overly long titles.
+/// - `Error::InvalidModulePosition` / `Error::DuplicateModulePosition` for bad positions.
+/// - `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn edit_module(
+    env: Env,
+    creator: Address,
+    module_id: String,
+    new_title: String,
+    new_position: Option<u32>,
+    new_duration: Option<u32>,
+) -> CourseModule {
+    if module_id.is_empty() {
+        handle_error(&env, Error::EmptyModuleId)
+    }
+
+    if new_title.is_empty() || new_title.len() > 500 {
+        handle_error(&env, Error::InvalidModuleTitle)
+    }
+
+    let storage_key: (Symbol, String) = (MODULE_KEY, module_id.clone());
+    let mut module: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+
+    super::access_control::require_course_management_auth(&env, &creator, &module.course_id);
+
+    if let Some(position) = new_position {
+        if position > 10000 {
+            handle_error(&env, Error::InvalidModulePosition)
+        }
+
+        if position != module.position {
+            let new_position_key: (Symbol, String, u32) =
+                (POSITION_KEY, module.course_id.clone(), position);
+            if env.storage().persistent().has(&new_position_key) {
+                handle_error(&env, Error::DuplicateModulePosition)
+            }
+
+            env.storage()
+                .persistent()
+                .remove(&(POSITION_KEY, module.course_id.clone(), module.position));
+            env.storage().persistent().set(&new_position_key, &true);
+            module.position = position;
+        }
+    }
+
+    if let Some(duration) = new_duration {
+        if duration == 0 {
+            handle_error(&env, Error::InvalidDurationValue)
+        }
+        set_module_duration(&env, &module.id, duration);
+    }
+
+    module.title = new_title;
+    env.storage().persistent().set(&storage_key, &module);
+
+    events::module_edited(&env, &module);
+
+    module
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "title"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_edit_module() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let module = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "Intro"));
+
+        let updated = client.edit_module(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "Introduction"),
+            &Some(5),
+            &Some(45),
+        );
+
+        assert_eq!(updated.title, String::from_str(&env, "Introduction"));
+        assert_eq!(updated.position, 5);
+        assert_eq!(client.get_module_duration(&module.id), Some(45));
+
+        // The old position is free again
+        client.add_module(&creator, &course_id, &0, &String::from_str(&env, "Prelude"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #405)")]
+    fn test_edit_module_position_taken() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let module = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "A"));
+        client.add_module(&creator, &course_id, &1, &String::from_str(&env, "B"));
+
+        client.edit_module(&creator, &module.id, &String::from_str(&env, "A"), &Some(1), &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_edit_module_unauthorized() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let module = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "A"));
+        let other = Address::generate(&env);
+
+        client.edit_module(&other, &module.id, &String::from_str(&env, "B"), &None, &None);
+    }
+}
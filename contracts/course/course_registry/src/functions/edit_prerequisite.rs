@@ -3,12 +3,13 @@
 
 use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
 
+use crate::events;
 use crate::error::{handle_error, Error};
+use crate::functions::prerequisite_index::set_course_prerequisites;
 use crate::schema::{Course, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-const PREREQ_UPDATED_EVENT: Symbol = symbol_short!("preqEdit");
 
 pub fn edit_prerequisite(
     env: Env,
@@ -46,16 +47,9 @@ pub fn edit_prerequisite(
     validate_no_circular_dependency(&env, &course_id, &new_prerequisites);
 
     // Save updated prerequisites
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &new_prerequisites,
-    );
-
-    // Emit event
-    env.events().publish(
-        (PREREQ_UPDATED_EVENT, course_id),
-        new_prerequisites,
-    );
+    set_course_prerequisites(&env, &course_id, &new_prerequisites);
+
+    events::prerequisites_edited(&env, &course_id, &new_prerequisites);
 }
 
 fn validate_no_circular_dependency(env: &Env, course_id: &String, new_prerequisites: &Vec<String>) {
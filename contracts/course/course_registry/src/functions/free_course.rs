@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::sort_index::update_sort_index;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{Course, DataKey, SortField};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Makes a course free or paid again. Only the course creator can do this.
+///
+/// A free course has a price of 0 and can be joined with `self_enroll` in
+/// the course access contract. The paid price is kept aside and restored
+/// when the course stops being free.
+pub fn set_course_free(env: &Env, creator: Address, course_id: String, is_free: bool) -> Course {
+    creator.require_auth();
+
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let free_key: DataKey = DataKey::FreeCourse(course_id.clone());
+    match env.storage().persistent().get::<_, u128>(&free_key) {
+        None if is_free => {
+            env.storage().persistent().set(&free_key, &course.price);
+            bump_persistent(env, &free_key);
+            course.price = 0;
+        }
+        Some(paid_price) if !is_free => {
+            env.storage().persistent().remove(&free_key);
+            course.price = paid_price;
+        }
+        _ => return course,
+    }
+
+    env.storage().persistent().set(&storage_key, &course);
+    bump_persistent(env, &storage_key);
+    update_sort_index(env, SortField::Price, &course_id, course.price);
+
+    events::course_updated(env, &course_id, &creator);
+
+    course
+}
+
+/// Returns whether a course is free.
+pub fn is_course_free(env: &Env, course_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::FreeCourse(course_id))
+}
+
+/// Marks a free course as paid, e.g. when its creator sets a price.
+pub fn clear_course_free(env: &Env, course_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::FreeCourse(course_id.clone()));
+}
+
+/// Returns whether students can enroll themselves: the course exists and is
+/// free, published and not archived.
+pub fn is_self_enrollable(env: &Env, course_id: String) -> bool {
+    match env
+        .storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id.clone()))
+    {
+        Some(course) => course.published && !course.is_archived && is_course_free(env, course_id),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn edit_params(price: Option<u128>, published: Option<bool>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: price,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Course) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let course: Course = client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, "Rust"),
+            &String::from_str(env, "Description"),
+            &1500,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, course)
+    }
+
+    #[test]
+    fn test_set_course_free_and_back() {
+        let env: Env = Env::default();
+        let (client, course) = setup(&env);
+
+        let free: Course = client.set_course_free(&course.creator, &course.id, &true);
+        assert_eq!(free.price, 0);
+        assert!(client.is_course_free(&course.id));
+        assert!(!client.is_self_enrollable(&course.id));
+
+        client.edit_course(&course.creator, &course.id, &edit_params(None, Some(true)));
+        assert!(client.is_self_enrollable(&course.id));
+
+        let paid: Course = client.set_course_free(&course.creator, &course.id, &false);
+        assert_eq!(paid.price, 1500);
+        assert!(!client.is_course_free(&course.id));
+        assert!(!client.is_self_enrollable(&course.id));
+    }
+
+    #[test]
+    fn test_setting_a_price_makes_course_paid() {
+        let env: Env = Env::default();
+        let (client, course) = setup(&env);
+        client.set_course_free(&course.creator, &course.id, &true);
+
+        let edited: Course = client.edit_course(&course.creator, &course.id, &edit_params(Some(2000), None));
+        assert_eq!(edited.price, 2000);
+        assert!(!client.is_course_free(&course.id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_course_free_requires_creator() {
+        let env: Env = Env::default();
+        let (client, course) = setup(&env);
+
+        client.set_course_free(&Address::generate(&env), &course.id, &true);
+    }
+}
@@ -4,6 +4,8 @@
 use soroban_sdk::{Env, String, Symbol, symbol_short};
 
 use crate::error::{handle_error, Error};
+use crate::functions::ttl::bump_persistent;
+use crate::functions::utils::course_id_to_string;
 use crate::schema::Course;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
@@ -26,11 +28,13 @@ const COURSE_KEY: Symbol = symbol_short!("course");
 pub fn get_course(env: &Env, course_id: String) -> Course {
 
     // Get the course from storage
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
     let course: Course = env
         .storage()
         .persistent()
-        .get(&(COURSE_KEY, course_id.clone()))
+        .get(&key)
         .expect("Course not found");
+    bump_persistent(env, &key);
 
     match course.is_archived {
         true => handle_error(env, Error::CourseAlreadyArchived),
@@ -38,6 +42,12 @@ pub fn get_course(env: &Env, course_id: String) -> Course {
     }
 }
 
+/// Retrieves a course by its numeric ID, the `u128` its `String` ID was
+/// generated from. Same errors as `get_course`.
+pub fn get_course_by_number(env: &Env, course_number: u128) -> Course {
+    get_course(env, course_id_to_string(env, course_number))
+}
+
 #[cfg(test)]
 mod test {
     use crate::{schema::Course, CourseRegistry, CourseRegistryClient};
@@ -53,6 +63,7 @@ mod test {
         let course = create_course(&client, &creator);
         let fetched_course = client.get_course(&course.id);
         assert_eq!(fetched_course, course);
+        assert_eq!(client.get_course_by_number(&1), course);
     }
 
     #[test]
@@ -74,7 +85,7 @@ mod test {
         let client = CourseRegistryClient::new(&env, &contract_id);
         let creator = Address::generate(&env);
         let course = create_course(&client, &creator);
-        client.archive_course(&creator, &course.id);
+        client.archive_course(&creator, &course.id, &None);
         client.get_course(&course.id);
     }
 
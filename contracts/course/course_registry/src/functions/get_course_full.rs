@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::functions::course_index::get_course_goal_ids;
+use crate::functions::get_course::get_course;
+use crate::functions::list_modules::course_registry_list_modules;
+use crate::functions::module_duration::get_module_durations;
+use crate::schema::{Course, CourseCategory, CourseDetail, CourseGoal, DataKey};
+
+/// Retrieves a course together with its modules, goals, prerequisites and category.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - course_id: unique identifier of the course.
+///
+/// Returns:
+/// - CourseDetail: the course and all related data in a single read.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::CourseAlreadyArchived` if the course is archived.
+///
+/// Storage used:
+/// - ("course", id) -> Course
+/// - DataKey::CourseModules(id) -> Vec<String>
+/// - ("modDur", module_id) -> u32
+/// - DataKey::CourseGoalList(id) -> Vec<String>
+/// - DataKey::CourseGoal(id, goal_id) -> CourseGoal
+/// - DataKey::CoursePrerequisites(id) -> Vec<String>
+/// - DataKey::CourseCategory(category_id) -> CourseCategory
+pub fn get_course_full(env: &Env, course_id: String) -> CourseDetail {
+    let course: Course = get_course(env, course_id.clone());
+
+    let modules = course_registry_list_modules(env, course_id.clone());
+    let module_durations = get_module_durations(env, &modules);
+
+    let mut goals: Vec<CourseGoal> = Vec::new(env);
+    let goal_ids: Vec<String> = get_course_goal_ids(env, &course_id);
+    for goal_id in goal_ids.iter() {
+        if let Some(goal) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseGoal>(&DataKey::CourseGoal(course_id.clone(), goal_id))
+        {
+            goals.push_back(goal);
+        }
+    }
+
+    let prerequisites: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id))
+        .unwrap_or(Vec::new(env));
+
+    let mut category: Vec<CourseCategory> = Vec::new(env);
+    if let Some(found) = course
+        .category
+        .as_ref()
+        .and_then(|name| find_category_by_name(env, name))
+    {
+        category.push_back(found);
+    }
+
+    CourseDetail {
+        course,
+        modules,
+        module_durations,
+        goals,
+        prerequisites,
+        category,
+    }
+}
+
+/// Looks up a registered category by name, scanning IDs up to the category sequence.
+fn find_category_by_name(env: &Env, name: &String) -> Option<CourseCategory> {
+    let max_id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategorySeq)
+        .unwrap_or(0);
+
+    let mut id: u128 = 1;
+    while id <= max_id {
+        if let Some(category) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseCategory>(&DataKey::CourseCategory(id))
+        {
+            if category.name == *name {
+                return Some(category);
+            }
+        }
+        id += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{map, testutils::Address as _, vec, Address, Env, String};
+
+    #[test]
+    fn test_get_course_full() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let prereq = client.create_course(
+            &creator,
+            &String::from_str(&env, "Basics"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Advanced"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &Some(String::from_str(&env, "Programming")),
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Second"));
+        let first = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "First"));
+        client.edit_module(&creator, &first.id, &first.title, &None, &Some(45));
+        let goal = client.add_goal(&creator, &course.id, &String::from_str(&env, "Learn it"));
+        client.add_prerequisite(&creator, &course.id, &vec![&env, prereq.id.clone()]);
+
+        let detail = client.get_course_full(&course.id);
+
+        assert_eq!(detail.course, course);
+        assert_eq!(detail.modules.len(), 2);
+        assert_eq!(detail.modules.get(0).unwrap().title, String::from_str(&env, "First"));
+        assert_eq!(detail.module_durations, map![&env, (first.id, 45)]);
+        assert_eq!(detail.goals, vec![&env, goal]);
+        assert_eq!(detail.prerequisites, vec![&env, prereq.id]);
+        // No category record has been registered under this name
+        assert!(detail.category.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Course not found")]
+    fn test_get_course_full_not_found() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        client.get_course_full(&String::from_str(&env, "missing"));
+    }
+}
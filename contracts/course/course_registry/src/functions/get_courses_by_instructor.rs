@@ -1,18 +1,23 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use super::utils::u32_to_string;
-use crate::schema::Course;
+use super::utils::course_id_to_string;
+use crate::error::{handle_error, Error};
+use crate::schema::{
+    Course, CoursePagination, CourseStatus, PaginatedCourses, MAX_MY_COURSES_PAGE_SIZE,
+    MAX_MY_COURSES_SCAN,
+};
 use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec, String};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_ID: Symbol = symbol_short!("course");
 
 pub fn get_courses_by_instructor(env: &Env, instructor: Address) -> Vec<Course> {
     let mut results: Vec<Course> = Vec::new(env);
     let mut id: u128 = 1;
 
     loop {
-        let course_id: String = u32_to_string(env, id as u32);
+        let course_id: String = course_id_to_string(env, id);
         let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
 
         if !env.storage().persistent().has(&key) {
@@ -34,6 +39,60 @@ pub fn get_courses_by_instructor(env: &Env, instructor: Address) -> Vec<Course>
     results
 }
 
+/// Returns the lifecycle stage of a course; archiving takes precedence over publishing.
+pub fn course_status(course: &Course) -> CourseStatus {
+    if course.is_archived {
+        CourseStatus::Archived
+    } else if course.published {
+        CourseStatus::Published
+    } else {
+        CourseStatus::Draft
+    }
+}
+
+/// Returns a page of the instructor's own courses, drafts and archived ones
+/// included, optionally restricted to one status. Requires the instructor's auth.
+///
+/// A page reads at most `MAX_MY_COURSES_SCAN` course IDs, so it can hold
+/// fewer than `limit` courses while `has_more` is still true.
+pub fn get_my_courses(
+    env: &Env,
+    instructor: Address,
+    status_filter: Option<CourseStatus>,
+    pagination: CoursePagination,
+) -> PaginatedCourses {
+    instructor.require_auth();
+    if pagination.limit == 0 || pagination.limit > MAX_MY_COURSES_PAGE_SIZE {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let last_id: u128 = env.storage().persistent().get(&COURSE_ID).unwrap_or(0);
+    let mut data: Vec<Course> = Vec::new(env);
+    let mut id: u128 = pagination.cursor.unwrap_or(0);
+    let scan_end: u128 = last_id.min(id + MAX_MY_COURSES_SCAN as u128);
+    while id < scan_end && data.len() < pagination.limit {
+        id += 1;
+        let course: Option<Course> = env
+            .storage()
+            .persistent()
+            .get(&(COURSE_KEY, course_id_to_string(env, id)));
+        if let Some(course) = course {
+            if course.creator == instructor
+                && status_filter.as_ref().is_none_or(|status| course_status(&course) == *status)
+            {
+                data.push_back(course);
+            }
+        }
+    }
+
+    let has_more: bool = id < last_id;
+    PaginatedCourses {
+        data,
+        next_cursor: if has_more { Some(id) } else { None },
+        has_more,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -113,9 +172,61 @@ mod test {
         let course1 = create_course(&client, &instructor, "course1");
         let course2 = create_course(&client, &instructor, "course2");
 
-        client.archive_course(&instructor, &course2.id);
+        client.archive_course(&instructor, &course2.id, &None);
         let courses = client.get_courses_by_instructor(&instructor);
         assert_eq!(courses.len(), 1);
         assert_eq!(courses.get(0).unwrap(), course1);
     }
+
+    #[test]
+    fn test_get_my_courses() {
+        use crate::schema::{CoursePagination, CourseStatus, EditCourseParams};
+
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let instructor = Address::generate(&env);
+        let draft = create_course(&client, &instructor, "draft");
+        create_course(&client, &Address::generate(&env), "other");
+        let published = create_course(&client, &instructor, "published");
+        let archived = create_course(&client, &instructor, "archived");
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&instructor, &published.id, &params);
+        client.archive_course(&instructor, &archived.id, &None);
+
+        let first = client.get_my_courses(&instructor, &None, &CoursePagination { cursor: None, limit: 2 });
+        assert_eq!(first.data.len(), 2);
+        assert_eq!(first.data.get(0).unwrap().id, draft.id);
+        assert!(first.has_more);
+        let second = client.get_my_courses(&instructor, &None, &CoursePagination { cursor: first.next_cursor, limit: 2 });
+        assert_eq!(second.data.len(), 1);
+        assert_eq!(second.data.get(0).unwrap().id, archived.id);
+        assert!(!second.has_more);
+        assert_eq!(second.next_cursor, None);
+
+        let page = CoursePagination { cursor: None, limit: 10 };
+        let drafts = client.get_my_courses(&instructor, &Some(CourseStatus::Draft), &page);
+        assert_eq!(drafts.data.len(), 1);
+        assert_eq!(drafts.data.get(0).unwrap().id, draft.id);
+        let published_page = client.get_my_courses(&instructor, &Some(CourseStatus::Published), &page);
+        assert_eq!(published_page.data.get(0).unwrap().id, published.id);
+        let archived_page = client.get_my_courses(&instructor, &Some(CourseStatus::Archived), &page);
+        assert_eq!(archived_page.data.get(0).unwrap().id, archived.id);
+
+        let too_large = CoursePagination { cursor: None, limit: 51 };
+        assert!(client.try_get_my_courses(&instructor, &None, &too_large).is_err());
+    }
 }
@@ -1,8 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Env, String, Vec, Symbol};
-use crate::schema::{Course, CourseId};
+use soroban_sdk::{symbol_short, Env, Map, String, Vec, Symbol};
+use crate::schema::{Course, CourseId, DataKey, MAX_PREREQUISITE_DEPTH};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
@@ -14,3 +14,48 @@ pub fn get_prerequisites_by_course_id(env: &Env, course_id: String) -> Vec<Cours
         None => Vec::new(env), // Return empty if course doesn't exist
     }
 }
+
+/// Returns the prerequisite course IDs of a course.
+///
+/// With `transitive` set, walks the prerequisite graph breadth-first and returns
+/// every course in the requirement chain, nearest first. Each course appears once,
+/// cycles are skipped, and the walk stops after `MAX_PREREQUISITE_DEPTH` levels.
+///
+/// Storage used:
+/// - DataKey::CoursePrerequisites(course_id) -> Vec<String>
+pub fn get_prerequisites(env: &Env, course_id: String, transitive: bool) -> Vec<String> {
+    let direct: Vec<String> = direct_prerequisites(env, &course_id);
+    if !transitive {
+        return direct;
+    }
+
+    let mut seen: Map<String, bool> = Map::new(env);
+    seen.set(course_id, true);
+
+    let mut result: Vec<String> = Vec::new(env);
+    let mut frontier: Vec<String> = direct;
+    let mut depth: u32 = 0;
+
+    while !frontier.is_empty() && depth < MAX_PREREQUISITE_DEPTH {
+        let mut next: Vec<String> = Vec::new(env);
+        for prerequisite_id in frontier.iter() {
+            if seen.contains_key(prerequisite_id.clone()) {
+                continue;
+            }
+            seen.set(prerequisite_id.clone(), true);
+            result.push_back(prerequisite_id.clone());
+            next.append(&direct_prerequisites(env, &prerequisite_id));
+        }
+        frontier = next;
+        depth += 1;
+    }
+
+    result
+}
+
+fn direct_prerequisites(env: &Env, course_id: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
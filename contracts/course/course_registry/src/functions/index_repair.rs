@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Repair of indexes derived from the stored courses.
+//!
+//! Counters aggregated per instructor are updated incrementally and can
+//! drift after a bug or an interrupted migration, and courses created before
+//! a counter existed are missing from it. These functions recompute them
+//! from the course records in bounded chunks.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, RegistryError};
+use crate::functions::access_control::require_admin;
+use crate::functions::course_counts::get_courses_count_by_instructor;
+use crate::functions::course_stats::{get_course_stats, get_instructor_stats};
+use crate::functions::sort_index::index_course_sort_keys;
+use crate::functions::utils::course_id_to_string;
+use crate::schema::{Course, CourseStats, DataKey, InstructorStats, MAX_REBUILD_BATCH};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_ID: Symbol = symbol_short!("course");
+
+/// Recomputes instructor stats and course counts from course IDs
+/// `cursor + 1` to `cursor + limit`, and refreshes those courses in the
+/// sorted listing indexes.
+///
+/// A rebuild starts at cursor 0 and continues with the returned cursor until
+/// it is `None`. Each instructor's counters are reset the first time one of
+/// their courses is reached in a rebuild, then summed over their courses.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment
+/// * `admin` - Owner or admin running the rebuild (must be authorized)
+/// * `cursor` - Number of course IDs already processed
+/// * `limit` - Number of course IDs to process (1 to `MAX_REBUILD_BATCH`)
+pub fn rebuild_instructor_index(env: &Env, admin: Address, cursor: u32, limit: u32) -> Option<u32> {
+    require_admin(env, &admin);
+    if limit == 0 || limit > MAX_REBUILD_BATCH {
+        handle_error(env, RegistryError::InvalidBatchSize)
+    }
+
+    let mut run: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StatsRebuildRun)
+        .unwrap_or(0);
+    if cursor == 0 {
+        run += 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::StatsRebuildRun, &run);
+    }
+
+    let last_id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u128>(&COURSE_ID)
+        .unwrap_or(0) as u32;
+    let end: u32 = cursor.saturating_add(limit).min(last_id);
+    for id in (cursor + 1)..=end {
+        let course_id: String = course_id_to_string(env, id as u128);
+        let course: Course = match env
+            .storage()
+            .persistent()
+            .get(&(COURSE_KEY, course_id.clone()))
+        {
+            Some(course) => course,
+            None => continue,
+        };
+        index_course_sort_keys(env, &course);
+
+        let run_key: DataKey = DataKey::InstructorStatsRun(course.creator.clone());
+        let count_key: DataKey = DataKey::InstructorCourseCount(course.creator.clone());
+        let (mut instructor_stats, course_count): (InstructorStats, u32) =
+            if env.storage().persistent().get::<_, u32>(&run_key) == Some(run) {
+                (
+                    get_instructor_stats(env, course.creator.clone()),
+                    get_courses_count_by_instructor(env, course.creator.clone()),
+                )
+            } else {
+                env.storage().persistent().set(&run_key, &run);
+                (InstructorStats::default(), 0)
+            };
+        env.storage().persistent().set(&count_key, &(course_count + 1));
+        let stats: CourseStats = get_course_stats(env, course_id);
+        instructor_stats.enrollments = instructor_stats
+            .enrollments
+            .saturating_add(stats.enrollments);
+        instructor_stats.completions = instructor_stats
+            .completions
+            .saturating_add(stats.completions);
+        instructor_stats.revenue = instructor_stats.revenue.saturating_add(stats.revenue);
+        env.storage()
+            .persistent()
+            .set(&DataKey::InstructorStats(course.creator), &instructor_stats);
+    }
+
+    if end < last_id {
+        Some(end)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::functions::access_control::initialize;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_rebuild_instructor_index() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let owner = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            initialize(&env, &owner, &Address::generate(&env))
+        });
+        let reporter = Address::generate(&env);
+        client.set_stats_reporter(&owner, &reporter, &true);
+
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+        let course1 = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course One"),
+            &String::from_str(&env, "description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course2 = client.create_course(
+            &other,
+            &String::from_str(&env, "Course Two"),
+            &String::from_str(&env, "description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course3 = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course Three"),
+            &String::from_str(&env, "description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.record_enrollment(&reporter, &course1.id);
+        client.record_purchase(&reporter, &course2.id, &500);
+        client.record_completion(&reporter, &course3.id);
+        let expected = client.get_instructor_stats(&creator);
+
+        // Simulate drifted counters
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(
+                &DataKey::InstructorStats(creator.clone()),
+                &InstructorStats {
+                    enrollments: 40,
+                    completions: 0,
+                    revenue: 9,
+                },
+            );
+            env.storage()
+                .persistent()
+                .remove(&DataKey::InstructorCourseCount(creator.clone()));
+        });
+
+        assert_eq!(client.rebuild_instructor_index(&owner, &0, &2), Some(2));
+        assert_eq!(client.rebuild_instructor_index(&owner, &2, &2), None);
+        assert_eq!(client.get_instructor_stats(&creator), expected);
+        assert_eq!(client.get_instructor_stats(&other).revenue, 500);
+        assert_eq!(client.get_courses_count_by_instructor(&creator), 2);
+
+        // Running it again gives the same result
+        assert_eq!(client.rebuild_instructor_index(&owner, &0, &50), None);
+        assert_eq!(client.get_instructor_stats(&creator), expected);
+    }
+}
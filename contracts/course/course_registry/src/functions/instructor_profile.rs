@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::functions::course_stats::{get_instructor_avg_rating, get_instructor_stats};
+use crate::functions::get_courses_by_instructor::get_courses_by_instructor;
+use crate::schema::{Course, DataKey, InstructorSummary};
+
+/// Summarizes an instructor's courses, students and ratings.
+///
+/// Archived courses are not counted. Student and rating figures come from the
+/// counters reported through `record_enrollment` and `record_rating`.
+pub fn get_instructor_profile(env: &Env, instructor: Address) -> InstructorSummary {
+    let courses: Vec<Course> = get_courses_by_instructor(env, instructor.clone());
+    let published_count: u32 = courses.iter().filter(|course| course.published).count() as u32;
+
+    InstructorSummary {
+        course_count: courses.len(),
+        published_count,
+        total_students: get_instructor_stats(env, instructor.clone()).enrollments,
+        avg_rating: get_instructor_avg_rating(env, instructor.clone()),
+        joined_version: env
+            .storage()
+            .persistent()
+            .get(&DataKey::InstructorJoined(instructor)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::functions::access_control::initialize;
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::String;
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+
+        let owner: Address = Address::generate(env);
+        let user_mgmt: Address = Address::generate(env);
+        env.as_contract(&contract_id, || initialize(env, &owner, &user_mgmt));
+
+        let reporter: Address = Address::generate(env);
+        client.set_stats_reporter(&owner, &reporter, &true);
+        (client, reporter)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_get_instructor_profile() {
+        let env: Env = Env::default();
+        let (client, reporter) = setup(&env);
+        let instructor: Address = Address::generate(&env);
+
+        let rust: Course = create_course(&env, &client, &instructor, "Rust");
+        let go: Course = create_course(&env, &client, &instructor, "Go");
+        create_course(&env, &client, &Address::generate(&env), "Figma");
+
+        client.edit_course(
+            &instructor,
+            &rust.id,
+            &EditCourseParams {
+                new_title: None,
+                new_description: None,
+                new_price: None,
+                new_category: None,
+                new_language: None,
+                new_thumbnail_url: None,
+                new_published: Some(true),
+                new_level: None,
+                new_duration_hours: None,
+            },
+        );
+        client.record_enrollment(&reporter, &rust.id);
+        client.record_enrollment(&reporter, &go.id);
+        client.record_rating(&reporter, &rust.id, &5);
+        client.record_rating(&reporter, &go.id, &4);
+
+        let profile: InstructorSummary = client.get_instructor_profile(&instructor);
+        assert_eq!(profile.course_count, 2);
+        assert_eq!(profile.published_count, 1);
+        assert_eq!(profile.total_students, 2);
+        assert_eq!(profile.avg_rating, 450);
+        assert_eq!(profile.joined_version, Some(String::from_str(&env, crate::VERSION)));
+    }
+
+    #[test]
+    fn test_unknown_instructor_profile_is_empty() {
+        let env: Env = Env::default();
+        let (client, _reporter) = setup(&env);
+
+        let profile: InstructorSummary = client.get_instructor_profile(&Address::generate(&env));
+        assert_eq!(profile.course_count, 0);
+        assert_eq!(profile.avg_rating, 0);
+        assert_eq!(profile.joined_version, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #105)")]
+    fn test_record_rating_out_of_range_fails() {
+        let env: Env = Env::default();
+        let (client, reporter) = setup(&env);
+        let course: Course = create_course(&env, &client, &Address::generate(&env), "Rust");
+
+        client.record_rating(&reporter, &course.id, &6);
+    }
+}
@@ -2,8 +2,8 @@
 // Copyright (c) 2025 SkillCert
 
 use soroban_sdk::{symbol_short, Env, Map, String, Symbol, Vec};
-use crate::schema::{Category, Course};
-use crate::functions::utils::u32_to_string;
+use crate::schema::{Category, Course, DataKey, CATEGORY_COUNTS_TTL};
+use crate::functions::utils::course_id_to_string;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
@@ -21,8 +21,37 @@ const COURSE_KEY: Symbol = symbol_short!("course");
 /// - Iterates from course ID `1` to the last generated ID (`COURSE_ID_COUNTER`).
 /// - Skips deleted courses (holes in the ID sequence).
 /// - Ignores courses without a category (`None`).
-/// - Uses persistent storage to retrieve each course.
+/// - The result is cached in temporary storage; the cache is dropped whenever a
+///   course category may change and rebuilt on the next call.
 pub fn list_categories(env: &Env) -> Vec<Category> {
+    match env.storage().temporary().get(&DataKey::CategoryCounts) {
+        Some(categories) => categories,
+        None => rebuild_category_counts(env),
+    }
+}
+
+/// Recounts the courses of every category and refreshes the cached counts.
+///
+/// Anyone can call it; it only rebuilds data derived from the stored courses.
+pub fn rebuild_category_counts(env: &Env) -> Vec<Category> {
+    let categories: Vec<Category> = count_categories(env);
+    env.storage()
+        .temporary()
+        .set(&DataKey::CategoryCounts, &categories);
+    let ttl: u32 = CATEGORY_COUNTS_TTL.min(env.storage().max_ttl());
+    env.storage()
+        .temporary()
+        .extend_ttl(&DataKey::CategoryCounts, ttl, ttl);
+    categories
+}
+
+/// Drops the cached category counts after a course category changed.
+pub fn invalidate_category_counts(env: &Env) {
+    env.storage().temporary().remove(&DataKey::CategoryCounts);
+}
+
+/// Scans the persistent course entries and counts the courses of each category.
+fn count_categories(env: &Env) -> Vec<Category> {
     // Temporary map to store category name -> count
     let mut categories_map: Map<String, u128> = Map::new(env);
 
@@ -36,7 +65,7 @@ pub fn list_categories(env: &Env) -> Vec<Category> {
     // Iterate over all possible course IDs from 1 to max_id
     let mut id: u128 = 1;
     while id <= max_id {
-        let course_id: String = u32_to_string(env, id as u32);
+        let course_id: String = course_id_to_string(env, id);
         let key: (Symbol, String) = (COURSE_KEY, course_id);
 
         // Check if a course with this ID exists
@@ -1,25 +1,84 @@
 use crate::error::{handle_error, Error};
-use crate::functions::utils::u32_to_string;
-
-use crate::schema::{Course, CourseFilters, MAX_EMPTY_CHECKS};
-use soroban_sdk::{symbol_short, Env, Symbol, Vec, String};
-
-/// Helper function to check if a Soroban String contains a substring
-/// For now, this implements exact match only due to Soroban String limitations
-/// TODO: Implement proper substring search when Soroban provides better string utilities
-fn string_contains(haystack: &String, needle: &String) -> bool {
-    // For now, only exact match is supported
-    // This can be enhanced later when Soroban provides better string utilities
-    haystack == needle
-}
+use crate::functions::sort_index::SortedCourseIds;
+use crate::functions::utils::course_id_to_string;
+
+use crate::schema::{Course, CourseFilters, SortOption, MAX_EMPTY_CHECKS};
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+use validation::string_search::contains_ignore_case;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
+/// Applies the filters with early exits for performance.
+///
+/// - Price range filter (min/max)
+/// - Category filter
+/// - Level filter
+/// - Duration filter (min/max, only if course has duration)
+/// - Text search filter (title and description)
+fn passes_filters(course: &Course, filters: &CourseFilters) -> bool {
+    filters.min_price.is_none_or(|min| course.price >= min)
+        && filters.max_price.is_none_or(|max| course.price <= max)
+        && filters
+            .category
+            .as_ref()
+            .is_none_or(|cat| course.category.as_ref() == Some(cat))
+        && filters
+            .level
+            .as_ref()
+            .is_none_or(|lvl| course.level.as_ref() == Some(lvl))
+        && filters
+            .min_duration
+            .is_none_or(|min| course.duration_hours.is_some_and(|d| d >= min))
+        && filters
+            .max_duration
+            .is_none_or(|max| course.duration_hours.is_some_and(|d| d <= max))
+        && filters.search_text.as_ref().is_none_or(|search| {
+            // Case-insensitive substring search in title and description
+            contains_ignore_case(&course.title, search) || contains_ignore_case(&course.description, search)
+        })
+}
+
+/// Walks the courses in the order of `sort_by` and returns the page of
+/// published, unarchived courses passing the filters.
+fn list_sorted_courses(
+    env: &Env,
+    filters: &CourseFilters,
+    sort_by: SortOption,
+    offset: u32,
+    limit: u32,
+) -> Vec<Course> {
+    let mut ids: SortedCourseIds = SortedCourseIds::new(env, sort_by);
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut matched: u32 = 0;
+    let mut position: u32 = 0;
+
+    while let Some(course_id) = ids.get(env, position) {
+        position += 1;
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+            Some(course) => course,
+            None => continue,
+        };
+        if course.is_archived || !course.published || !passes_filters(&course, filters) {
+            continue;
+        }
+        if matched >= offset {
+            if results.len() >= limit {
+                break;
+            }
+            results.push_back(course);
+        }
+        matched += 1;
+    }
+
+    results
+}
+
 pub fn list_courses_with_filters(
     env: &Env,
     filters: CourseFilters,
     limit: Option<u32>,
     offset: Option<u32>,
+    sort_by: Option<SortOption>,
 ) -> Vec<Course> {
     // Validate pagination parameters to prevent abuse
     if let Some(l) = limit {
@@ -47,6 +106,10 @@ pub fn list_courses_with_filters(
     // Safety check for limit - reduced for budget constraints
     let max_limit: u32 = if limit_value > 20 { 20 } else { limit_value };
 
+    if let Some(sort_by) = sort_by {
+        return list_sorted_courses(env, &filters, sort_by, offset_value, max_limit);
+    }
+
     loop {
         // Much more aggressive safety limits for budget
         if id > crate::schema::MAX_SCAN_ID as u128
@@ -56,7 +119,7 @@ pub fn list_courses_with_filters(
         }
 
         // Use the utility function instead of to_string()
-        let course_id: String = u32_to_string(env, id as u32);
+        let course_id: String = course_id_to_string(env, id);
         let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
 
         if !env.storage().persistent().has(&key) {
@@ -76,37 +139,8 @@ pub fn list_courses_with_filters(
             continue;
         }
 
-        // Apply filters with early exits for performance.
-        //
-        // - Price range filter (min/max)
-        // - Category filter
-        // - Level filter
-        // - Duration filter (min/max, only if course has duration)
-        // - Text search filter (title and description)
-        let passes_filters: bool = filters.min_price.map_or(true, |min| course.price >= min)
-            && filters.max_price.map_or(true, |max| course.price <= max)
-            && filters
-                .category
-                .as_ref()
-                .is_none_or(|cat| course.category.as_ref() == Some(cat))
-            && filters
-                .level
-                .as_ref()
-                .map_or(true, |lvl| course.level.as_ref() == Some(lvl))
-            && filters.min_duration.map_or(true, |min| {
-                course.duration_hours.map_or(false, |d| d >= min)
-            })
-            && filters.max_duration.map_or(true, |max| {
-                course.duration_hours.map_or(false, |d| d <= max)
-            })
-            && filters.search_text.as_ref().map_or(true, |search| {
-                // Text search in title and description
-                // Note: Case-sensitive search due to Soroban String limitations
-                string_contains(&course.title, search) || string_contains(&course.description, search)
-            });
-
         // If course passes all filters
-        if passes_filters {
+        if passes_filters(&course, &filters) {
             // Handle pagination
             if matched >= offset_value {
                 if count < max_limit {
@@ -151,7 +185,7 @@ mod test {
             search_text: None,
         };
 
-        let results = client.list_courses_with_filters(&filters, &None, &None);
+        let results = client.list_courses_with_filters(&filters, &None, &None, &None);
         assert_eq!(results.len(), 0);
     }
 
@@ -203,7 +237,7 @@ mod test {
             search_text: None,
         };
 
-        let results = client.list_courses_with_filters(&filters, &None, &None);
+        let results = client.list_courses_with_filters(&filters, &None, &None, &None);
         assert_eq!(results.len(), 1);
         assert_eq!(results.get(0).unwrap().price, 100);
     }
@@ -241,7 +275,7 @@ mod test {
             search_text: None,
         };
 
-        let results = client.list_courses_with_filters(&filters, &None, &None);
+        let results = client.list_courses_with_filters(&filters, &None, &None, &None);
         assert_eq!(results.len(), 0);
     }
 
@@ -278,7 +312,7 @@ mod test {
             search_text: None,
         };
 
-        let results = client.list_courses_with_filters(&filters, &Some(0), &None);
+        let results = client.list_courses_with_filters(&filters, &Some(0), &None, &None);
         assert_eq!(results.len(), 0);
     }
 
@@ -343,7 +377,7 @@ mod test {
             search_text: Some(String::from_str(&env, "Rust Programming")),
         };
 
-        let exact_title_results = client.list_courses_with_filters(&exact_title_filters, &None, &None);
+        let exact_title_results = client.list_courses_with_filters(&exact_title_filters, &None, &None, &None);
         assert_eq!(exact_title_results.len(), 1);
         assert_eq!(exact_title_results.get(0).unwrap().title, String::from_str(&env, "Rust Programming"));
 
@@ -358,10 +392,25 @@ mod test {
             search_text: Some(String::from_str(&env, "Introduction to web development")),
         };
 
-        let exact_desc_results = client.list_courses_with_filters(&exact_desc_filters, &None, &None);
+        let exact_desc_results = client.list_courses_with_filters(&exact_desc_filters, &None, &None, &None);
         assert_eq!(exact_desc_results.len(), 1);
         assert_eq!(exact_desc_results.get(0).unwrap().title, String::from_str(&env, "JavaScript Basics"));
 
+        // Search for part of a description in another case
+        let partial_filters = CourseFilters {
+            min_price: None,
+            max_price: None,
+            category: None,
+            level: None,
+            min_duration: None,
+            max_duration: None,
+            search_text: Some(String::from_str(&env, "WEB DEV")),
+        };
+
+        let partial_results = client.list_courses_with_filters(&partial_filters, &None, &None, &None);
+        assert_eq!(partial_results.len(), 1);
+        assert_eq!(partial_results.get(0).unwrap().title, String::from_str(&env, "JavaScript Basics"));
+
         // Search for non-existent term
         let none_filters = CourseFilters {
             min_price: None,
@@ -373,7 +422,98 @@ mod test {
             search_text: Some(String::from_str(&env, "Python")),
         };
 
-        let none_results = client.list_courses_with_filters(&none_filters, &None, &None);
+        let none_results = client.list_courses_with_filters(&none_filters, &None, &None, &None);
         assert_eq!(none_results.len(), 0);
     }
+
+    #[test]
+    fn test_sorted_listing() {
+        use crate::schema::EditCourseParams;
+
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let owner = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &owner, &Address::generate(&env))
+        });
+        let reporter = Address::generate(&env);
+        client.set_stats_reporter(&owner, &reporter, &true);
+        let creator = Address::generate(&env);
+
+        let mut ids = Vec::new(&env);
+        for (title, price) in [("Course A", 3000u128), ("Course B", 1000), ("Course C", 2000)] {
+            let course = client.create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "Description"),
+                &price,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            );
+            let params = EditCourseParams {
+                new_title: None,
+                new_description: None,
+                new_price: None,
+                new_category: None,
+                new_language: None,
+                new_thumbnail_url: None,
+                new_published: Some(true),
+                new_level: None,
+                new_duration_hours: None,
+            };
+            client.edit_course(&creator, &course.id, &params);
+            ids.push_back(course.id);
+        }
+        let (a, b, c) = (ids.get(0).unwrap(), ids.get(1).unwrap(), ids.get(2).unwrap());
+        client.record_enrollment(&reporter, &b);
+        client.record_enrollment(&reporter, &b);
+        client.record_enrollment(&reporter, &c);
+        client.record_rating(&reporter, &c, &5);
+        client.record_rating(&reporter, &a, &3);
+
+        let filters = CourseFilters {
+            min_price: None,
+            max_price: None,
+            category: None,
+            level: None,
+            min_duration: None,
+            max_duration: None,
+            search_text: None,
+        };
+        let order = |sort_by: SortOption| {
+            let mut order = Vec::new(&env);
+            for course in client.list_courses_with_filters(&filters, &None, &None, &Some(sort_by)).iter() {
+                order.push_back(course.id);
+            }
+            order
+        };
+        assert_eq!(order(SortOption::Newest), soroban_sdk::vec![&env, c.clone(), b.clone(), a.clone()]);
+        assert_eq!(order(SortOption::PriceAsc), soroban_sdk::vec![&env, b.clone(), c.clone(), a.clone()]);
+        assert_eq!(order(SortOption::PriceDesc), soroban_sdk::vec![&env, a.clone(), c.clone(), b.clone()]);
+        assert_eq!(order(SortOption::RatingDesc), soroban_sdk::vec![&env, c.clone(), a.clone(), b.clone()]);
+        assert_eq!(order(SortOption::MostEnrolled).get(0).unwrap(), b.clone());
+
+        // Index positions follow price edits and pagination applies after sorting
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: Some(500),
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &a, &params);
+        let page = client.list_courses_with_filters(&filters, &Some(1), &Some(1), &Some(SortOption::PriceAsc));
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, b);
+    }
 }
@@ -1,66 +1,141 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Env, String, Symbol, symbol_short};
+use soroban_sdk::{Env, String, Symbol, Vec, symbol_short};
 
 use crate::error::{handle_error, Error};
+use crate::functions::course_index::get_course_module_ids;
 use crate::schema::CourseModule;
 
+const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
 
-pub fn course_registry_list_modules(env: &Env, course_id: String) -> CourseModule {
+/// Lists the modules of a course, sorted by position.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - course_id: unique identifier of the course.
+///
+/// Returns:
+/// - Vec<CourseModule>: the course's modules in ascending position order.
+///
+/// Errors:
+/// - Panics with `Error::EmptyCourseId` if the course ID is empty.
+/// - Panics with `Error::CourseIdNotExist` if the course does not exist.
+///
+/// Storage used:
+/// - DataKey::CourseModules(course_id) -> Vec<String>   // module IDs per course
+/// - ("module", module_id) -> CourseModule             // module record by id
+pub fn course_registry_list_modules(env: &Env, course_id: String) -> Vec<CourseModule> {
     if course_id.is_empty() {
         handle_error(env, Error::EmptyCourseId)
     }
 
-    // Get the course from storage
-    let module: CourseModule = env
-        .storage()
-        .persistent()
-        .get(&(MODULE_KEY, course_id.clone()))
-        .expect("Module with the specified ID does not exist");
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id.clone())) {
+        handle_error(env, Error::CourseIdNotExist)
+    }
+
+    let module_ids: Vec<String> = get_course_module_ids(env, &course_id);
 
-    module
+    // Insertion sort by position; courses hold few modules
+    let mut modules: Vec<CourseModule> = Vec::new(env);
+    for module_id in module_ids.iter() {
+        if let Some(module) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseModule>(&(MODULE_KEY, module_id))
+        {
+            let mut index: u32 = modules.len();
+            while index > 0 && modules.get(index - 1).unwrap().position > module.position {
+                index -= 1;
+            }
+            modules.insert(index, module);
+        }
+    }
+
+    modules
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::CourseRegistry;
-    use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env, String};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
-    const MODULE_KEY: Symbol = symbol_short!("module");
+    fn setup(env: &Env) -> (Address, CourseRegistryClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator: Address = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "title"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (contract_id, client, creator, course.id)
+    }
 
     #[test]
-    fn test_course_registry_add_module_storage_key_format() {
+    fn test_list_modules_sorted_by_position() {
         let env: Env = Env::default();
-        env.ledger().set_timestamp(100000);
+        let (contract_id, client, creator, course_id) = setup(&env);
 
-        let contract_id: Address = env.register(CourseRegistry, {});
+        client.add_module(&creator, &course_id, &2, &String::from_str(&env, "Module 2"));
+        client.add_module(&creator, &course_id, &0, &String::from_str(&env, "Module 0"));
+        client.add_module(&creator, &course_id, &1, &String::from_str(&env, "Module 1"));
 
-        // Create a test course first
-        let course: CourseModule = CourseModule {
-            id: String::from_str(&env, "test_module_123"),
-            course_id: String::from_str(&env, "test_course_123"),
-            position: 0,
-            title: String::from_str(&env, "Introduction to Blockchain"),
-            created_at: 0,
-        };
+        let modules: Vec<CourseModule> = env.as_contract(&contract_id, || {
+            course_registry_list_modules(&env, course_id.clone())
+        });
 
-        // Set up initial course data and perform test within contract context
-        env.as_contract(&contract_id, || {
-            env.storage()
-                .persistent()
-                .set(&(MODULE_KEY, course.course_id.clone()), &course);
-            course_registry_list_modules(&env, course.course_id)
+        assert_eq!(modules.len(), 3);
+        assert_eq!(modules.get(0).unwrap().position, 0);
+        assert_eq!(modules.get(1).unwrap().position, 1);
+        assert_eq!(modules.get(2).unwrap().position, 2);
+    }
+
+    #[test]
+    fn test_list_modules_skips_removed() {
+        let env: Env = Env::default();
+        let (contract_id, client, creator, course_id) = setup(&env);
+
+        let first = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "Module 0"));
+        client.add_module(&creator, &course_id, &1, &String::from_str(&env, "Module 1"));
+        client.remove_module(&first.id);
+
+        let modules: Vec<CourseModule> = env.as_contract(&contract_id, || {
+            course_registry_list_modules(&env, course_id.clone())
         });
+
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules.get(0).unwrap().title, String::from_str(&env, "Module 1"));
+    }
+
+    #[test]
+    fn test_list_module_durations() {
+        let env: Env = Env::default();
+        let (_, client, creator, course_id) = setup(&env);
+
+        let timed = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "Module 0"));
+        client.add_module(&creator, &course_id, &1, &String::from_str(&env, "Module 1"));
+        client.edit_module(&creator, &timed.id, &timed.title, &None, &Some(30));
+
+        let durations = client.list_module_durations(&course_id);
+        assert_eq!(durations.len(), 1);
+        assert_eq!(durations.get(timed.id), Some(30));
     }
 
     #[test]
-    #[should_panic(expected = "Module with the specified ID does not exist")]
-    fn test_add_module_invalid_course() {
+    #[should_panic(expected = "HostError: Error(Contract, #3)")]
+    fn test_list_modules_invalid_course() {
         let env: Env = Env::default();
-        let contract_id: Address = env.register(CourseRegistry, {});
+        let contract_id: Address = env.register(CourseRegistry, ());
 
         let course_id: String = String::from_str(&env, "invalid_course");
 
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_admin;
+use crate::functions::list_categories::invalidate_category_counts;
+use crate::functions::utils::course_id_to_string;
+use crate::schema::{Course, CourseCategory, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Loads a category or panics with `CategoryNotFound`.
+pub fn require_category(env: &Env, category_id: u128) -> CourseCategory {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseCategory(category_id))
+        .unwrap_or_else(|| handle_error(env, RegistryError::CategoryNotFound))
+}
+
+/// Moves every course whose category is `from_name` to `into_name`.
+///
+/// Courses reference categories by name, so this walks all course IDs.
+/// Returns the number of courses updated.
+pub fn reassign_category_courses(env: &Env, from_name: &String, into_name: &String) -> u32 {
+    let max_id: u128 = env.storage().persistent().get(&COURSE_KEY).unwrap_or(0);
+
+    let mut reassigned: u32 = 0;
+    let mut id: u128 = 1;
+    while id <= max_id {
+        let key: (Symbol, String) = (COURSE_KEY, course_id_to_string(env, id));
+        if let Some(mut course) = env.storage().persistent().get::<_, Course>(&key) {
+            if course.category.as_ref() == Some(from_name) {
+                course.category = Some(into_name.clone());
+                env.storage().persistent().set(&key, &course);
+                reassigned += 1;
+            }
+        }
+        id += 1;
+    }
+
+    if reassigned > 0 {
+        invalidate_category_counts(env);
+    }
+
+    reassigned
+}
+
+/// Counts the courses whose category is `name`.
+pub fn category_course_count(env: &Env, name: &String) -> u32 {
+    let max_id: u128 = env.storage().persistent().get(&COURSE_KEY).unwrap_or(0);
+
+    let mut count: u32 = 0;
+    let mut id: u128 = 1;
+    while id <= max_id {
+        let key: (Symbol, String) = (COURSE_KEY, course_id_to_string(env, id));
+        if let Some(course) = env.storage().persistent().get::<_, Course>(&key) {
+            if course.category.as_ref() == Some(name) {
+                count += 1;
+            }
+        }
+        id += 1;
+    }
+
+    count
+}
+
+/// Merges category `from_id` into `into_id` (admin-only).
+///
+/// Courses in the source category are moved to the target category and the
+/// source category is deleted. Returns the number of courses moved.
+pub fn merge_categories(env: Env, admin: Address, from_id: u128, into_id: u128) -> u32 {
+    require_admin(&env, &admin);
+
+    if from_id == into_id {
+        handle_error(&env, RegistryError::SameCategory)
+    }
+
+    let from: CourseCategory = require_category(&env, from_id);
+    let into: CourseCategory = require_category(&env, into_id);
+
+    let reassigned: u32 = reassign_category_courses(&env, &from.name, &into.name);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseCategory(from_id));
+
+    events::category_merged(&env, from_id, into_id, &admin, reassigned);
+
+    reassigned
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let admin: Address = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &admin, &user_mgmt_id);
+        });
+        (client, admin)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, title: &str, category: &str) -> Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "Description"),
+            &1000,
+            &Some(String::from_str(env, category)),
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_merge_categories_moves_courses() {
+        let env: Env = Env::default();
+        let (client, admin) = setup(&env);
+        let programming: u128 =
+            client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+        let coding: u128 =
+            client.create_course_category(&admin, &String::from_str(&env, "Coding"), &None);
+        let rust: Course = create_course(&env, &client, "Rust", "Coding");
+        let go: Course = create_course(&env, &client, "Go", "Coding");
+        let design: Course = create_course(&env, &client, "Figma", "Design");
+
+        assert_eq!(client.merge_categories(&admin, &coding, &programming), 2);
+
+        let programming_name: String = String::from_str(&env, "Programming");
+        assert_eq!(client.get_course(&rust.id).category, Some(programming_name.clone()));
+        assert_eq!(client.get_course(&go.id).category, Some(programming_name));
+        assert_eq!(
+            client.get_course(&design.id).category,
+            Some(String::from_str(&env, "Design"))
+        );
+        assert!(client.get_course_category(&coding).is_none());
+        assert!(client.get_course_category(&programming).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #100)")]
+    fn test_merge_missing_category_fails() {
+        let env: Env = Env::default();
+        let (client, admin) = setup(&env);
+        let programming: u128 =
+            client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        client.merge_categories(&admin, &42, &programming);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #101)")]
+    fn test_merge_category_into_itself_fails() {
+        let env: Env = Env::default();
+        let (client, admin) = setup(&env);
+        let programming: u128 =
+            client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        client.merge_categories(&admin, &programming, &programming);
+    }
+}
@@ -0,0 +1,403 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Batched state migrations.
+//!
+//! A migration between two versions is a list of registered steps. Each step
+//! reports how many records it walks over and applies itself to one record
+//! at a time, by position. `migrate_batch` walks the steps in order and
+//! applies at most `limit` records per call, keeping its place in
+//! `MigrationState`, so large data sets can be migrated over several
+//! transactions without running out of budget.
+//!
+//! The only registered migration, 1.0.0 -> 1.1.0, builds the indexes added
+//! in 1.1.0 from existing data: it folds the module and goal IDs staged per
+//! course into the course indexes (`fold_legacy_course_index`), rebuilds the
+//! sorted course indexes into pages (`backfill_sort_index`) and indexes the
+//! dependents of each prerequisite (`backfill_dependents`).
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::access_control::{is_admin, is_owner};
+use crate::functions::course_index::{fold_legacy_course_index, legacy_course_count};
+use crate::functions::prerequisite_index::{backfill_dependents, dependents_backfill_len};
+use crate::functions::sort_index::{backfill_sort_index, sort_index_backfill_len};
+use crate::functions::contract_versioning::{
+    record_version, set_migration_pending, set_migration_status,
+};
+use crate::schema::{DataKey, MigrationState};
+
+/// Maximum number of records a single `migrate_batch` call may transform.
+pub const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// A registered migration step.
+struct MigrationStep {
+    /// Number of records the step walks over
+    len: fn(&Env) -> u32,
+    /// Applies the step to the record at the given position
+    apply: fn(&Env, u32),
+}
+
+/// A registered migration: `(from_version, to_version, steps)`.
+type Migration = (&'static str, &'static str, &'static [MigrationStep]);
+
+/// Registered migrations.
+///
+/// 1.0.0 -> 1.1.0 folds the module and goal IDs staged with
+/// `stage_legacy_course_index` into the per-course indexes, rebuilds the
+/// sorted indexes into pages from every course that wasn't deleted, then
+/// indexes the dependents of every prerequisite.
+const MIGRATIONS: &[Migration] = &[(
+    "1.0.0",
+    "1.1.0",
+    &[
+        MigrationStep {
+            len: legacy_course_count,
+            apply: fold_legacy_course_index,
+        },
+        MigrationStep {
+            len: sort_index_backfill_len,
+            apply: backfill_sort_index,
+        },
+        MigrationStep {
+            len: dependents_backfill_len,
+            apply: backfill_dependents,
+        },
+    ],
+)];
+
+fn find_steps(env: &Env, from: &String, to: &String) -> Option<&'static [MigrationStep]> {
+    MIGRATIONS
+        .iter()
+        .find(|(f, t, _)| String::from_str(env, f) == *from && String::from_str(env, t) == *to)
+        .map(|(_, _, steps)| *steps)
+}
+
+fn require_migration_admin(env: &Env, admin: &Address) {
+    admin.require_auth();
+    if !(is_owner(env, admin) || is_admin(env, admin)) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+fn load_state(env: &Env) -> MigrationState {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationState)
+        .unwrap_or_else(|| handle_error(env, RegistryError::NoMigrationInProgress))
+}
+
+/// Returns the migration in flight, if any.
+pub fn get_migration_state(env: &Env) -> Option<MigrationState> {
+    env.storage().instance().get(&DataKey::MigrationState)
+}
+
+/// Starts a registered migration between two versions.
+///
+/// Only the owner or an admin may start a migration, and only one can run
+/// at a time.
+pub fn begin_migration(env: &Env, admin: Address, from_version: String, to_version: String) {
+    require_migration_admin(env, &admin);
+
+    if env.storage().instance().has(&DataKey::MigrationState) {
+        handle_error(env, RegistryError::MigrationInProgress)
+    }
+    if find_steps(env, &from_version, &to_version).is_none() {
+        handle_error(env, RegistryError::MigrationNotRegistered)
+    }
+
+    let state: MigrationState = MigrationState {
+        from_version: from_version.clone(),
+        to_version: to_version.clone(),
+        started_by: admin.clone(),
+        step: 0,
+        cursor: 0,
+        processed: 0,
+        completed: false,
+    };
+    env.storage().instance().set(&DataKey::MigrationState, &state);
+    set_migration_pending(env, true);
+
+    events::migration_started(env, &from_version, &to_version, &admin);
+}
+
+/// Transforms up to `limit` records of the migration in flight.
+///
+/// Returns the number of records transformed by this call. Once every step
+/// is exhausted the state is marked completed and further calls return 0.
+pub fn migrate_batch(env: &Env, admin: Address, limit: u32) -> u32 {
+    require_migration_admin(env, &admin);
+
+    if limit == 0 || limit > MAX_MIGRATION_BATCH {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let mut state: MigrationState = load_state(env);
+    let steps: &[MigrationStep] = find_steps(env, &state.from_version, &state.to_version)
+        .unwrap_or_else(|| handle_error(env, RegistryError::MigrationNotRegistered));
+
+    let mut done: u32 = 0;
+    while (state.step as usize) < steps.len() {
+        let step: &MigrationStep = &steps[state.step as usize];
+        if state.cursor >= (step.len)(env) {
+            state.step += 1;
+            state.cursor = 0;
+            continue;
+        }
+        if done == limit {
+            break;
+        }
+        (step.apply)(env, state.cursor);
+        state.cursor += 1;
+        done += 1;
+    }
+
+    state.processed += done;
+    state.completed = state.step as usize >= steps.len();
+    env.storage().instance().set(&DataKey::MigrationState, &state);
+
+    done
+}
+
+/// Completes the migration in flight once all batches have run.
+///
+/// Records the target version in the version history and clears the
+/// pending migration flag.
+pub fn finish_migration(env: &Env, admin: Address) {
+    require_migration_admin(env, &admin);
+
+    let state: MigrationState = load_state(env);
+    if !state.completed {
+        handle_error(env, RegistryError::MigrationInProgress)
+    }
+
+    env.storage().instance().remove(&DataKey::MigrationState);
+    record_version(env, &admin, state.to_version.clone());
+    set_migration_pending(env, false);
+    set_migration_status(env, String::from_str(env, "Migration completed successfully"));
+
+    events::migration_finished(
+        env,
+        &state.from_version,
+        &state.to_version,
+        &admin,
+        state.processed,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::functions::prerequisite_index::get_course_dependents;
+    use crate::functions::sort_index::{remove_from_sort_indexes, SortedCourseIds};
+    use crate::schema::{SortEntry, SortField, SortOption};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Vec};
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let owner: Address = Address::generate(env);
+        let user_mgmt: Address = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &owner, &user_mgmt);
+        });
+        (client, owner)
+    }
+
+    /// Creates a course with one module and one goal, then drops its indexes
+    /// as if all had been added before the indexes existed, leaving the
+    /// course in a single-list sort index like earlier versions did.
+    fn create_legacy_course(
+        env: &Env,
+        client: &CourseRegistryClient<'_>,
+        title: &str,
+    ) -> (String, String, String) {
+        let creator: Address = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, title),
+            &String::from_str(env, "Description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Intro"));
+        let goal = client.add_goal(&creator, &course.id, &String::from_str(env, "Learn"));
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::CourseModules(course.id.clone()));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::CourseGoalList(course.id.clone()));
+            remove_from_sort_indexes(env, &course.id);
+            let legacy_key: DataKey = DataKey::SortIndex(SortField::Price);
+            let mut legacy: Vec<SortEntry> = env
+                .storage()
+                .persistent()
+                .get(&legacy_key)
+                .unwrap_or(Vec::new(env));
+            legacy.push_back(SortEntry {
+                course_id: course.id.clone(),
+                value: course.price,
+            });
+            env.storage().persistent().set(&legacy_key, &legacy);
+        });
+        (course.id, module.id, goal.goal_id)
+    }
+
+    #[test]
+    fn test_migration_runs_in_batches() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        let mut course_ids: Vec<String> = Vec::new(&env);
+        for title in ["Rust", "Soroban", "Stellar"] {
+            let (course_id, module_id, goal_id) = create_legacy_course(&env, &client, title);
+            assert!(client.list_modules(&course_id).is_empty());
+            client.stage_legacy_course_index(&owner, &course_id, &vec![&env, module_id], &vec![&env, goal_id]);
+            course_ids.push_back(course_id);
+        }
+        // A prerequisite written before the dependents index existed
+        let (rust, stellar) = (course_ids.get(0).unwrap(), course_ids.get(2).unwrap());
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(
+                &DataKey::CoursePrerequisites(stellar.clone()),
+                &vec![&env, rust.clone()],
+            );
+        });
+
+        let from: String = String::from_str(&env, "1.0.0");
+        let to: String = String::from_str(&env, "1.1.0");
+        client.begin_migration(&owner, &from, &to);
+        assert!(client.get_migration_status().migration_pending);
+
+        // Three staged courses, then three course IDs to sort and three to
+        // index the prerequisites of
+        assert_eq!(client.migrate_batch(&owner, &5), 5);
+        assert!(!client.get_migration_state().unwrap().completed);
+        assert_eq!(client.migrate_batch(&owner, &5), 4);
+        let state: MigrationState = client.get_migration_state().unwrap();
+        assert!(state.completed);
+        assert_eq!(state.processed, 9);
+
+        client.finish_migration(&owner);
+        assert!(client.get_migration_state().is_none());
+        let history = client.get_version_history();
+        assert_eq!(history.last().unwrap().version, to);
+
+        env.as_contract(&client.address, || {
+            for course_id in course_ids.iter() {
+                let modules: Vec<String> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::CourseModules(course_id.clone()))
+                    .unwrap();
+                let goals: Vec<String> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::CourseGoalList(course_id))
+                    .unwrap();
+                assert_eq!((modules.len(), goals.len()), (1, 1));
+            }
+            assert_eq!(legacy_course_count(&env), 0);
+
+            let mut sorted = SortedCourseIds::new(&env, SortOption::PriceAsc);
+            for position in 0..3 {
+                assert!(course_ids.contains(sorted.get(&env, position).unwrap()));
+            }
+            assert!(sorted.get(&env, 3).is_none());
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&DataKey::SortIndex(SortField::Price)));
+
+            assert_eq!(get_course_dependents(&env, &rust), vec![&env, stellar.clone()]);
+        });
+    }
+
+    #[test]
+    fn test_staged_ids_are_read_before_the_migration() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        let (course_id, module_id, goal_id) = create_legacy_course(&env, &client, "Rust");
+
+        client.stage_legacy_course_index(
+            &owner,
+            &course_id,
+            &vec![&env, module_id.clone()],
+            &vec![&env, goal_id.clone()],
+        );
+
+        let modules = client.list_modules(&course_id);
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules.get(0).unwrap().id, module_id);
+        let detail = client.get_course_full(&course_id);
+        assert_eq!(detail.goals.get(0).unwrap().goal_id, goal_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #121)")]
+    fn test_staging_a_module_of_another_course_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        let (course_id, _, _) = create_legacy_course(&env, &client, "Rust");
+        let (_, other_module_id, _) = create_legacy_course(&env, &client, "Soroban");
+
+        client.stage_legacy_course_index(&owner, &course_id, &vec![&env, other_module_id], &Vec::new(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #126)")]
+    fn test_finish_before_completed_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "Description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.begin_migration(
+            &owner,
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "1.1.0"),
+        );
+        client.finish_migration(&owner);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #128)")]
+    fn test_begin_unregistered_migration_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+
+        client.begin_migration(
+            &owner,
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "9.9.9"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #127)")]
+    fn test_batch_without_migration_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+
+        client.migrate_batch(&owner, &10);
+    }
+}
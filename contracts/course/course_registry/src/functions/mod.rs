@@ -2,28 +2,62 @@
 // Copyright (c) 2025 SkillCert
 
 pub mod access_control;
+pub mod action_rate_limit;
 pub mod add_goal;
 pub mod add_module;
 pub mod archive_course;
 pub mod backup_recovery;
+pub mod clone_course;
+pub mod completion_criteria;
+pub mod content_limits;
 pub mod contract_versioning;
+pub mod course_index;
 pub mod create_course;
 pub mod create_course_category;
+pub mod create_courses_batch;
 pub mod create_prerequisite;
+pub mod course_collections;
+pub mod course_counts;
+pub mod course_draft;
 pub mod course_rate_limit_utils;
+pub mod course_stats;
+pub mod course_translation;
 pub mod delete_course;
+pub mod delete_course_category;
+pub mod discussions;
 pub mod edit_course;
+pub mod edit_course_category;
 pub mod edit_goal;
+pub mod edit_module;
 pub mod edit_prerequisite;
+pub mod free_course;
 pub mod get_course;
 pub mod get_course_category;
+pub mod get_course_full;
 pub mod get_courses_by_instructor;
 pub mod get_prerequisites_by_course;
+pub mod index_repair;
+pub mod instructor_profile;
 pub mod is_course_creator;
 pub mod list_categories;
 pub mod list_courses_with_filters;
 pub mod list_modules;
+pub mod merge_categories;
+pub mod pause;
+pub mod migration;
+pub mod module_duration;
+pub mod module_prerequisites;
+pub mod module_release;
+pub mod platform_stats;
+pub mod prerequisite_index;
+pub mod price_limits;
 pub mod remove_goal;
 pub mod remove_module;
+pub mod reorder_goals;
+pub mod reorder_modules;
+pub mod ttl;
 pub mod remove_prerequisite;
+pub mod search_courses;
+pub mod sort_index;
+pub mod status_history;
 pub mod utils;
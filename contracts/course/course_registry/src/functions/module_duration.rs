@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Estimated module durations.
+//!
+//! Durations are kept under their own key rather than as a `CourseModule`
+//! field, so module records written by earlier versions still decode.
+
+use soroban_sdk::{symbol_short, Env, Map, String, Symbol, Vec};
+
+use crate::functions::list_modules::course_registry_list_modules;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::CourseModule;
+
+/// Persistent storage key prefix: `(MODULE_DURATION_KEY, module_id) -> u32`
+const MODULE_DURATION_KEY: Symbol = symbol_short!("modDur");
+
+/// Returns the estimated duration of a module in minutes, if one was set.
+pub fn get_module_duration(env: &Env, module_id: String) -> Option<u32> {
+    env.storage().persistent().get(&(MODULE_DURATION_KEY, module_id))
+}
+
+/// Stores the estimated duration of a module. Callers validate the value.
+pub fn set_module_duration(env: &Env, module_id: &String, duration_minutes: u32) {
+    let key: (Symbol, String) = (MODULE_DURATION_KEY, module_id.clone());
+    env.storage().persistent().set(&key, &duration_minutes);
+    bump_persistent(env, &key);
+}
+
+/// Removes the duration of a deleted module.
+pub fn clear_module_duration(env: &Env, module_id: &String) {
+    env.storage().persistent().remove(&(MODULE_DURATION_KEY, module_id.clone()));
+}
+
+/// Returns the durations set on `modules` in minutes, keyed by module ID.
+/// Modules without a duration are left out.
+pub fn get_module_durations(env: &Env, modules: &Vec<CourseModule>) -> Map<String, u32> {
+    let mut durations: Map<String, u32> = Map::new(env);
+    for module in modules.iter() {
+        if let Some(duration) = get_module_duration(env, module.id.clone()) {
+            durations.set(module.id, duration);
+        }
+    }
+    durations
+}
+
+/// Returns the durations of a course's modules, keyed by module ID.
+///
+/// Companion to `list_modules`, whose `CourseModule` shape is mirrored by
+/// other contracts and so can't carry the duration.
+pub fn list_module_durations(env: &Env, course_id: String) -> Map<String, u32> {
+    get_module_durations(env, &course_registry_list_modules(env, course_id))
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Prerequisites between the modules of a course.
+//!
+//! A module can require other modules of the same course to be completed
+//! first. The registry only stores the graph; the progress contract enforces
+//! it when a lesson is completed.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_course_management_auth;
+use crate::schema::{CourseModule, DataKey, MAX_MODULE_PREREQUISITES};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+fn get_module(env: &Env, module_id: &String) -> CourseModule {
+    env.storage()
+        .persistent()
+        .get(&(MODULE_KEY, module_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::ModuleNotFound))
+}
+
+/// Returns the modules to complete before `module_id`.
+pub fn get_module_prerequisites(env: &Env, module_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ModulePrerequisites(module_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces the prerequisites of a module (course creator or admin only).
+///
+/// Every prerequisite must be another module of the same course, and the
+/// change can't make a module depend on itself through other modules. An
+/// empty list removes the module's prerequisites.
+pub fn set_module_prerequisites(
+    env: &Env,
+    creator: Address,
+    module_id: String,
+    prerequisites: Vec<String>,
+) -> Vec<String> {
+    if module_id.is_empty() {
+        handle_error(env, Error::EmptyModuleId)
+    }
+    let module: CourseModule = get_module(env, &module_id);
+    require_course_management_auth(env, &creator, &module.course_id);
+
+    if prerequisites.len() > MAX_MODULE_PREREQUISITES {
+        handle_error(env, Error::TooManyPrerequisites)
+    }
+    let mut seen: Vec<String> = Vec::new(env);
+    for prerequisite in prerequisites.iter() {
+        if prerequisite == module_id {
+            handle_error(env, Error::SelfPrerequisite)
+        }
+        if seen.contains(&prerequisite) {
+            handle_error(env, Error::DuplicatePrerequisite)
+        }
+        if get_module(env, &prerequisite).course_id != module.course_id {
+            handle_error(env, RegistryError::ModuleCourseMismatch)
+        }
+        if depends_on(env, &prerequisite, &module_id) {
+            handle_error(env, Error::CircularDependency)
+        }
+        seen.push_back(prerequisite);
+    }
+
+    let key: DataKey = DataKey::ModulePrerequisites(module_id);
+    if prerequisites.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &prerequisites);
+    }
+
+    events::module_prerequisites_set(env, &module, &prerequisites);
+    prerequisites
+}
+
+/// Drops the prerequisites of a removed module.
+pub fn clear_module_prerequisites(env: &Env, module_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ModulePrerequisites(module_id.clone()));
+}
+
+/// Whether `module_id` requires `target`, directly or through other modules.
+fn depends_on(env: &Env, module_id: &String, target: &String) -> bool {
+    let mut pending: Vec<String> = Vec::new(env);
+    let mut visited: Vec<String> = Vec::new(env);
+    pending.push_back(module_id.clone());
+    while let Some(current) = pending.pop_back() {
+        if current == *target {
+            return true;
+        }
+        if visited.contains(&current) {
+            continue;
+        }
+        for prerequisite in get_module_prerequisites(env, current.clone()).iter() {
+            pending.push_back(prerequisite);
+        }
+        visited.push_back(current);
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::CourseModule;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                false
+            }
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let client = CourseRegistryClient::new(env, &env.register(CourseRegistry, ()));
+        let user_mgmt = env.register(mock_user_management::UserManagement, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::initialize(env, &Address::generate(env), &user_mgmt);
+        });
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Rust"),
+            &String::from_str(env, "Intro"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course.id)
+    }
+
+    fn add_module(client: &CourseRegistryClient, creator: &Address, course_id: &String, position: u32) -> CourseModule {
+        client.add_module(creator, course_id, &position, &String::from_str(&client.env, "Module"))
+    }
+
+    #[test]
+    fn test_set_module_prerequisites() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let first = add_module(&client, &creator, &course_id, 1);
+        let second = add_module(&client, &creator, &course_id, 2);
+        let third = add_module(&client, &creator, &course_id, 3);
+
+        client.set_module_prerequisites(&creator, &second.id, &vec![&env, first.id.clone()]);
+        client.set_module_prerequisites(&creator, &third.id, &vec![&env, second.id.clone()]);
+        assert_eq!(client.get_module_prerequisites(&third.id), vec![&env, second.id.clone()]);
+
+        // Cycles, self references and strangers are rejected
+        assert!(client
+            .try_set_module_prerequisites(&creator, &first.id, &vec![&env, third.id.clone()])
+            .is_err());
+        assert!(client
+            .try_set_module_prerequisites(&creator, &first.id, &vec![&env, first.id.clone()])
+            .is_err());
+        assert!(client
+            .try_set_module_prerequisites(&Address::generate(&env), &first.id, &vec![&env])
+            .is_err());
+
+        // Modules of another course can't be prerequisites
+        let other_course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Go"),
+            &String::from_str(&env, "Intro"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let foreign = add_module(&client, &creator, &other_course.id, 1);
+        assert!(client
+            .try_set_module_prerequisites(&creator, &first.id, &vec![&env, foreign.id.clone()])
+            .is_err());
+
+        // Removing a module drops its prerequisites; an empty list clears them
+        client.remove_module(&third.id);
+        assert!(client.get_module_prerequisites(&third.id).is_empty());
+        client.set_module_prerequisites(&creator, &second.id, &vec![&env]);
+        assert!(client.get_module_prerequisites(&second.id).is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Drip scheduling of the modules of a course.
+//!
+//! The registry only stores when each module unlocks; course access turns
+//! the schedule into the modules a student can open, based on their
+//! enrollment time, and the progress contract refuses lessons of modules
+//! that are still locked.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_course_management_auth;
+use crate::schema::{CourseModule, DataKey, ModuleRelease};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Returns the release schedule of a course, empty if every module is
+/// available right away.
+pub fn get_module_release_schedule(env: &Env, course_id: String) -> Vec<ModuleRelease> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ModuleReleaseSchedule(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces the release schedule of a course (course creator or admin only).
+///
+/// Every entry must name a different module of the course. An empty
+/// schedule makes every module available right away.
+pub fn set_module_release_schedule(
+    env: &Env,
+    creator: Address,
+    course_id: String,
+    schedule: Vec<ModuleRelease>,
+) -> Vec<ModuleRelease> {
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id.clone())) {
+        handle_error(env, Error::CourseNotFound)
+    }
+    require_course_management_auth(env, &creator, &course_id);
+
+    let mut seen: Vec<String> = Vec::new(env);
+    for entry in schedule.iter() {
+        let module: CourseModule = env
+            .storage()
+            .persistent()
+            .get(&(MODULE_KEY, entry.module_id.clone()))
+            .unwrap_or_else(|| handle_error(env, Error::ModuleNotFound));
+        if module.course_id != course_id || seen.contains(&entry.module_id) {
+            handle_error(env, RegistryError::InvalidReleaseSchedule)
+        }
+        seen.push_back(entry.module_id);
+    }
+
+    let key: DataKey = DataKey::ModuleReleaseSchedule(course_id.clone());
+    if schedule.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &schedule);
+    }
+
+    events::release_schedule_set(env, &course_id, &creator, &schedule);
+    schedule
+}
+
+/// Drops the release schedule of a purged course.
+pub fn clear_module_release_schedule(env: &Env, course_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ModuleReleaseSchedule(course_id.clone()));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{ModuleRelease, ReleaseTime};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                false
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_module_release_schedule() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = CourseRegistryClient::new(&env, &env.register(CourseRegistry, ()));
+        let user_mgmt = env.register(mock_user_management::UserManagement, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::initialize(&env, &Address::generate(&env), &user_mgmt);
+        });
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "Intro"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let title = String::from_str(&env, "Module");
+        let first = client.add_module(&creator, &course.id, &1, &title);
+        let second = client.add_module(&creator, &course.id, &2, &title);
+        assert!(client.get_module_release_schedule(&course.id).is_empty());
+
+        let schedule = vec![
+            &env,
+            ModuleRelease {
+                module_id: first.id.clone(),
+                release: ReleaseTime::AfterEnrollment(86_400),
+            },
+            ModuleRelease {
+                module_id: second.id.clone(),
+                release: ReleaseTime::At(1_000_000),
+            },
+        ];
+        client.set_module_release_schedule(&creator, &course.id, &schedule);
+        assert_eq!(client.get_module_release_schedule(&course.id), schedule);
+
+        // Strangers and duplicate modules are rejected
+        assert!(client
+            .try_set_module_release_schedule(&Address::generate(&env), &course.id, &schedule)
+            .is_err());
+        let mut duplicated = schedule.clone();
+        duplicated.push_back(schedule.get(0).unwrap());
+        assert!(client
+            .try_set_module_release_schedule(&creator, &course.id, &duplicated)
+            .is_err());
+
+        // An empty schedule releases every module
+        client.set_module_release_schedule(&creator, &course.id, &vec![&env]);
+        assert!(client.get_module_release_schedule(&course.id).is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Emergency pause switch.
+//!
+//! While paused, every state-changing entrypoint fails with `ContractPaused`
+//! so activity can be frozen during an incident without an upgrade. Reads,
+//! TTL extensions, `unpause` and `upgrade` keep working.
+
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, RegistryError};
+use crate::events;
+use crate::functions::access_control::require_admin;
+use crate::schema::DataKey;
+
+/// Whether the contract is paused.
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+/// Panics with `ContractPaused` while the contract is paused.
+pub fn require_not_paused(env: &Env) {
+    if is_paused(env) {
+        handle_error(env, RegistryError::ContractPaused)
+    }
+}
+
+/// Pauses the contract. Owner or admin only; pausing twice is a no-op.
+pub fn pause(env: &Env, admin: Address) {
+    set_paused(env, admin, true)
+}
+
+/// Lifts the pause. Owner or admin only; a no-op when not paused.
+pub fn unpause(env: &Env, admin: Address) {
+    set_paused(env, admin, false)
+}
+
+fn set_paused(env: &Env, admin: Address, paused: bool) {
+    require_admin(env, &admin);
+    if is_paused(env) == paused {
+        return;
+    }
+
+    env.storage().instance().set(&DataKey::Paused, &paused);
+    events::contract_paused(env, &admin, paused);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::RegistryError;
+    use crate::functions::access_control::initialize;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                false
+            }
+        }
+    }
+
+    fn setup() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let owner: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || initialize(&env, &owner, &user_mgmt_id));
+        (env, owner, client)
+    }
+
+    #[test]
+    fn test_pause_blocks_state_changes() {
+        let (env, owner, client) = setup();
+        let creator: Address = Address::generate(&env);
+        let create = |title: &str| {
+            client.try_create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+        };
+        let course = create("first").unwrap().unwrap();
+
+        client.pause(&owner);
+        assert!(client.is_paused());
+        assert_eq!(
+            create("second").err(),
+            Some(Ok(RegistryError::ContractPaused.into()))
+        );
+        assert_eq!(
+            client.try_delete_course(&creator, &course.id, &false).err(),
+            Some(Ok(RegistryError::ContractPaused.into()))
+        );
+        // Reads keep working
+        assert_eq!(client.get_course(&course.id), course);
+
+        client.unpause(&owner);
+        assert!(!client.is_paused());
+        assert!(create("second").is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_only_admin_can_pause() {
+        let (env, _owner, client) = setup();
+
+        client.pause(&Address::generate(&env));
+    }
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_owner};
+use crate::functions::course_stats::require_stats_reporter;
+use crate::schema::{DataKey, PlatformStats};
+
+const SET_PLATFORM_STATS_PUBLIC_EVENT: Symbol = symbol_short!("setPlPub");
+
+/// Returns the platform counters.
+///
+/// Anyone can read them when they are public; otherwise only admins and the owner.
+pub fn get_platform_stats(env: &Env, caller: Address) -> PlatformStats {
+    let public: bool = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PlatformStatsPublic)
+        .unwrap_or(false);
+
+    if !public {
+        caller.require_auth();
+        if !is_owner(env, &caller) && !is_admin(env, &caller) {
+            handle_error(env, Error::Unauthorized)
+        }
+    }
+
+    load(env)
+}
+
+/// Makes the platform counters readable by anyone, or by admins only. Owner only.
+pub fn set_platform_stats_public(env: &Env, caller: Address, public: bool) {
+    caller.require_auth();
+
+    if !is_owner(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlatformStatsPublic, &public);
+
+    env.events()
+        .publish((SET_PLATFORM_STATS_PUBLIC_EVENT,), (caller, public));
+}
+
+/// Records a user registration reported by the user management contract.
+pub fn record_user_registered(env: &Env, reporter: Address) -> PlatformStats {
+    require_stats_reporter(env, &reporter);
+    update(env, |stats| stats.total_users = stats.total_users.saturating_add(1))
+}
+
+/// Records a certificate issued by the certificate contract.
+pub fn record_certificate_issued(env: &Env, reporter: Address) -> PlatformStats {
+    require_stats_reporter(env, &reporter);
+    update(env, |stats| {
+        stats.total_certificates = stats.total_certificates.saturating_add(1)
+    })
+}
+
+/// Keeps the course counters in sync when a course is created.
+pub fn on_course_created(env: &Env) {
+    update(env, |stats| stats.total_courses = stats.total_courses.saturating_add(1));
+}
+
+/// Keeps the course counters in sync when a course is deleted.
+pub fn on_course_deleted(env: &Env, was_published: bool) {
+    update(env, |stats| {
+        stats.total_courses = stats.total_courses.saturating_sub(1);
+        if was_published {
+            stats.published_courses = stats.published_courses.saturating_sub(1);
+        }
+    });
+}
+
+/// Keeps the published counter in sync when a course is published or unpublished.
+pub fn on_course_published_changed(env: &Env, published: bool) {
+    update(env, |stats| {
+        stats.published_courses = if published {
+            stats.published_courses.saturating_add(1)
+        } else {
+            stats.published_courses.saturating_sub(1)
+        }
+    });
+}
+
+/// Counts an enrollment reported through `record_enrollment`.
+pub fn on_enrollment(env: &Env) {
+    update(env, |stats| {
+        stats.total_enrollments = stats.total_enrollments.saturating_add(1)
+    });
+}
+
+fn load(env: &Env) -> PlatformStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlatformStats)
+        .unwrap_or_default()
+}
+
+fn update(env: &Env, apply: impl FnOnce(&mut PlatformStats)) -> PlatformStats {
+    let mut stats: PlatformStats = load(env);
+    apply(&mut stats);
+    env.storage().persistent().set(&DataKey::PlatformStats, &stats);
+    stats
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::functions::access_control::initialize;
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, String};
+
+    #[contract]
+    struct NoAdmins;
+
+    #[contractimpl]
+    impl NoAdmins {
+        pub fn is_admin(_env: Env, _who: Address) -> bool {
+            false
+        }
+    }
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+
+        let owner = Address::generate(env);
+        let user_mgmt = env.register(NoAdmins, ());
+        env.as_contract(&contract_id, || initialize(env, &owner, &user_mgmt));
+        (client, owner)
+    }
+
+    #[test]
+    fn test_platform_stats_follow_courses_and_reports() {
+        let env = Env::default();
+        let (client, owner) = setup(&env);
+        let reporter = Address::generate(&env);
+        client.set_stats_reporter(&owner, &reporter, &true);
+
+        let creator = Address::generate(&env);
+        let course1 = client.create_course(&creator, &String::from_str(&env, "Course One"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+        let course2 = client.create_course(&creator, &String::from_str(&env, "Course Two"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &course1.id, &params);
+        client.edit_course(&creator, &course2.id, &params);
+        // Publishing twice doesn't double count
+        client.edit_course(&creator, &course2.id, &params);
+        client.delete_course(&creator, &course2.id, &false);
+
+        client.record_enrollment(&reporter, &course1.id);
+        client.record_user_registered(&reporter);
+        client.record_user_registered(&reporter);
+        client.record_certificate_issued(&reporter);
+
+        let stats = client.get_platform_stats(&owner);
+        assert_eq!(
+            stats,
+            PlatformStats {
+                total_courses: 1,
+                published_courses: 1,
+                total_users: 2,
+                total_enrollments: 1,
+                total_certificates: 1,
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_platform_stats_private_by_default() {
+        let env = Env::default();
+        let (client, _owner) = setup(&env);
+
+        client.get_platform_stats(&Address::generate(&env));
+    }
+
+    #[test]
+    fn test_platform_stats_public() {
+        let env = Env::default();
+        let (client, owner) = setup(&env);
+
+        client.set_platform_stats_public(&owner, &true);
+        let stats = client.get_platform_stats(&Address::generate(&env));
+        assert_eq!(stats, PlatformStats::default());
+    }
+}
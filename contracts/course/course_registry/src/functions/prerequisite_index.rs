@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Reverse index of course prerequisites.
+//!
+//! `DataKey::CoursePrerequisites` lists the prerequisites of a course; the
+//! dependents index lists, for each course, the courses that require it.
+//! Every write of a prerequisite list goes through `set_course_prerequisites`
+//! so both sides stay in step, and purging a course only visits the courses
+//! that depend on it. Edges written before the index existed are added by
+//! the 1.0.0 -> 1.1.0 migration.
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::functions::utils::course_id_to_string;
+use crate::schema::DataKey;
+
+const COURSE_ID: Symbol = symbol_short!("course");
+
+/// Persistent storage key prefix: `(DEPENDENTS_KEY, course_id) -> Vec<String>`
+const DEPENDENTS_KEY: Symbol = symbol_short!("dependnts");
+
+/// Returns the courses that list `course_id` as a prerequisite.
+pub fn get_course_dependents(env: &Env, course_id: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&(DEPENDENTS_KEY, course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn add_dependent(env: &Env, prerequisite_id: &String, course_id: &String) {
+    let mut dependents: Vec<String> = get_course_dependents(env, prerequisite_id);
+    if !dependents.contains(course_id) {
+        dependents.push_back(course_id.clone());
+        env.storage()
+            .persistent()
+            .set(&(DEPENDENTS_KEY, prerequisite_id.clone()), &dependents);
+    }
+}
+
+fn remove_dependent(env: &Env, prerequisite_id: &String, course_id: &String) {
+    let key: (Symbol, String) = (DEPENDENTS_KEY, prerequisite_id.clone());
+    let mut dependents: Vec<String> = get_course_dependents(env, prerequisite_id);
+    if let Some(index) = dependents.first_index_of(course_id) {
+        dependents.remove(index);
+        if dependents.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &dependents);
+        }
+    }
+}
+
+/// Replaces the prerequisites of a course and updates the dependents of the
+/// prerequisites added or dropped.
+pub fn set_course_prerequisites(env: &Env, course_id: &String, prerequisites: &Vec<String>) {
+    let key: DataKey = DataKey::CoursePrerequisites(course_id.clone());
+    let previous: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    for prerequisite_id in previous.iter() {
+        if !prerequisites.contains(&prerequisite_id) {
+            remove_dependent(env, &prerequisite_id, course_id);
+        }
+    }
+    for prerequisite_id in prerequisites.iter() {
+        add_dependent(env, &prerequisite_id, course_id);
+    }
+    env.storage().persistent().set(&key, prerequisites);
+}
+
+/// Removes the prerequisite list of a course, and the course from the
+/// dependents of each prerequisite. Returns the length of the list.
+pub fn remove_course_prerequisites(env: &Env, course_id: &String) -> u32 {
+    let key: DataKey = DataKey::CoursePrerequisites(course_id.clone());
+    let prerequisites: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    for prerequisite_id in prerequisites.iter() {
+        remove_dependent(env, &prerequisite_id, course_id);
+    }
+    env.storage().persistent().remove(&key);
+    prerequisites.len()
+}
+
+/// Drops `course_id` from the prerequisites of every course that depends on
+/// it, then removes its dependents list. Returns the number of courses
+/// updated.
+pub fn remove_from_dependents(env: &Env, course_id: &String) -> u32 {
+    let mut updated: u32 = 0;
+    for dependent_id in get_course_dependents(env, course_id).iter() {
+        let key: DataKey = DataKey::CoursePrerequisites(dependent_id);
+        if let Some(mut prerequisites) = env.storage().persistent().get::<_, Vec<String>>(&key) {
+            if let Some(index) = prerequisites.first_index_of(course_id) {
+                prerequisites.remove(index);
+                env.storage().persistent().set(&key, &prerequisites);
+                updated += 1;
+            }
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&(DEPENDENTS_KEY, course_id.clone()));
+
+    updated
+}
+
+/// Migration step length: one record per course ID handed out so far.
+pub(crate) fn dependents_backfill_len(env: &Env) -> u32 {
+    let last_id: u128 = env.storage().persistent().get(&COURSE_ID).unwrap_or(0);
+    u32::try_from(last_id).unwrap_or(u32::MAX)
+}
+
+/// Adds the course whose ID follows `index` to the dependents of each of its
+/// prerequisites. Courses deleted but not purged yet keep their list and are
+/// included.
+pub(crate) fn backfill_dependents(env: &Env, index: u32) {
+    let course_id: String = course_id_to_string(env, index as u128 + 1);
+    let prerequisites: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id.clone()))
+        .unwrap_or(Vec::new(env));
+    for prerequisite_id in prerequisites.iter() {
+        add_dependent(env, &prerequisite_id, &course_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address};
+
+    #[test]
+    fn test_dependents_follow_prerequisite_edits() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client: CourseRegistryClient<'_> = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+        let new_course = |title: &str| -> Course {
+            client.create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+        };
+        let basics: Course = new_course("basics");
+        let intro: Course = new_course("intro");
+        let advanced: Course = new_course("advanced");
+        let dependents = |course: &Course| -> Vec<String> {
+            env.as_contract(&contract_id, || get_course_dependents(&env, &course.id))
+        };
+
+        client.add_prerequisite(&creator, &advanced.id, &vec![&env, basics.id.clone()]);
+        client.add_prerequisite(&creator, &intro.id, &vec![&env, basics.id.clone()]);
+        assert_eq!(dependents(&basics), vec![&env, advanced.id.clone(), intro.id.clone()]);
+
+        client.edit_prerequisite(&creator, &advanced.id, &vec![&env, intro.id.clone()]);
+        assert_eq!(dependents(&basics), vec![&env, intro.id.clone()]);
+        assert_eq!(dependents(&intro), vec![&env, advanced.id.clone()]);
+
+        client.remove_prerequisite(&creator, &intro.id, &basics.id);
+        assert!(dependents(&basics).is_empty());
+    }
+}
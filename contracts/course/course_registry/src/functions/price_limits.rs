@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::functions::access_control::require_admin;
+use crate::schema::{DataKey, PriceLimits, DEFAULT_MAX_COURSE_PRICE, DEFAULT_MIN_COURSE_PRICE};
+
+const SET_PRICE_LIMITS_EVENT: Symbol = symbol_short!("setPrcLim");
+
+/// Returns the allowed course price range, falling back to the defaults.
+pub fn get_price_limits(env: &Env) -> PriceLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::PriceLimits)
+        .unwrap_or(PriceLimits {
+            min_course_price: DEFAULT_MIN_COURSE_PRICE,
+            max_course_price: DEFAULT_MAX_COURSE_PRICE,
+        })
+}
+
+/// Updates the allowed course price range. Owner or admin only.
+///
+/// Existing courses keep their price; the range applies to new prices.
+pub fn set_price_limits(env: &Env, admin: Address, min_course_price: u128, max_course_price: u128) {
+    require_admin(env, &admin);
+
+    if min_course_price == 0 || min_course_price > max_course_price {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let limits: PriceLimits = PriceLimits {
+        min_course_price,
+        max_course_price,
+    };
+    env.storage().instance().set(&DataKey::PriceLimits, &limits);
+
+    env.events().publish(
+        (SET_PRICE_LIMITS_EVENT,),
+        (admin, min_course_price, max_course_price),
+    );
+}
+
+/// Panics with `PriceOutOfRange` unless `price` is within the configured range.
+pub fn validate_course_price(env: &Env, price: u128) {
+    let limits: PriceLimits = get_price_limits(env);
+    if price < limits.min_course_price || price > limits.max_course_price {
+        handle_error(env, RegistryError::PriceOutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::String;
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let owner: Address = Address::generate(env);
+        let user_mgmt: Address = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &owner, &user_mgmt);
+        });
+        (client, owner)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, price: u128) -> Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, "Rust"),
+            &String::from_str(env, "Description"),
+            &price,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn price_params(price: u128) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: Some(price),
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_price_limits() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        assert_eq!(client.get_price_limits().max_course_price, DEFAULT_MAX_COURSE_PRICE);
+
+        client.set_price_limits(&owner, &100, &10_000);
+        assert_eq!(
+            client.get_price_limits(),
+            PriceLimits {
+                min_course_price: 100,
+                max_course_price: 10_000
+            }
+        );
+
+        let course: Course = create_course(&env, &client, 10_000);
+        let edited: Course = client.edit_course(&course.creator, &course.id, &price_params(100));
+        assert_eq!(edited.price, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #107)")]
+    fn test_create_course_above_max_price_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        client.set_price_limits(&owner, &100, &10_000);
+
+        create_course(&env, &client, 10_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #107)")]
+    fn test_edit_course_below_min_price_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+        let course: Course = create_course(&env, &client, 1000);
+        client.set_price_limits(&owner, &100, &10_000);
+
+        client.edit_course(&course.creator, &course.id, &price_params(99));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #46)")]
+    fn test_set_inverted_price_limits_fails() {
+        let env: Env = Env::default();
+        let (client, owner) = setup(&env);
+
+        client.set_price_limits(&owner, &10_000, &100);
+    }
+}
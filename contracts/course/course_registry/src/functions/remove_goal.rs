@@ -1,14 +1,15 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::events;
 use crate::error::{handle_error, Error};
+use crate::functions::course_index::{get_course_goal_ids, set_course_goal_ids};
 use crate::schema::{Course, CourseGoal, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-const GOAL_REMOVED_EVENT: Symbol = symbol_short!("goalRem");
 
 pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String) {
     caller.require_auth();
@@ -51,11 +52,16 @@ pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String
     // Remove the goal from storage
     env.storage().persistent().remove(&goal_storage_key);
 
-    // Emits an event for successful goal removal.
-    env.events().publish(
-        (GOAL_REMOVED_EVENT, course_id.clone(), goal_id.clone()),
-        goal.content.clone(),
-    );
+    // Drop the goal from the per-course list
+    let mut remaining: Vec<String> = Vec::new(&env);
+    for id in get_course_goal_ids(&env, &course_id).iter() {
+        if id != goal_id {
+            remaining.push_back(id);
+        }
+    }
+    set_course_goal_ids(&env, &course_id, &remaining);
+
+    events::goal_removed(&env, &goal);
 }
 
 #[cfg(test)]
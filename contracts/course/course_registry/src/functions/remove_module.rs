@@ -1,14 +1,17 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
 
+use crate::events;
 use crate::error::{handle_error, Error};
+use crate::functions::course_index::{get_course_module_ids, set_course_module_ids};
+use crate::functions::module_duration::clear_module_duration;
+use crate::functions::module_prerequisites::clear_module_prerequisites;
 use crate::schema::CourseModule;
 
 const MODULE_KEY: Symbol = symbol_short!("module");
 
-const REMOVE_MODULE_EVENT: Symbol = symbol_short!("remModule");
 
 pub fn remove_module(env: &Env, module_id: String) -> Result<(), &'static str> {
     if module_id.is_empty() {
@@ -22,17 +25,34 @@ pub fn remove_module(env: &Env, module_id: String) -> Result<(), &'static str> {
         .get(&(MODULE_KEY, module_id.clone()));
 
     // Validate that the module exists and is a valid CourseModule
-    if module.is_none() {
-        handle_error(env, Error::ModuleNotFound)
-    }
+    let module: CourseModule = match module {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
 
     // Delete the CourseModule directly from persistent storage using its key.
     env.storage()
         .persistent()
         .remove(&(MODULE_KEY, module_id.clone()));
 
-    // Emits an event to indicate the module has been removed.
-    env.events().publish((REMOVE_MODULE_EVENT,), module_id);
+    // Free the position so it can be reused
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("pos"), module.course_id.clone(), module.position));
+
+    // Drop the module from the per-course index
+    let mut remaining: Vec<String> = Vec::new(env);
+    for id in get_course_module_ids(env, &module.course_id).iter() {
+        if id != module_id {
+            remaining.push_back(id);
+        }
+    }
+    set_course_module_ids(env, &module.course_id, &remaining);
+
+    clear_module_prerequisites(env, &module_id);
+    clear_module_duration(env, &module_id);
+
+    events::module_removed(env, &module);
 
     Ok(())
 }
@@ -116,6 +136,36 @@ mod tests {
         assert!(!exists);
     }
 
+    #[test]
+    fn test_remove_module_clears_duration() {
+        let (env, contract_id, client) = setup_test_env();
+
+        let creator = Address::generate(&env);
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module Title"));
+        client.edit_module(&creator, &module.id, &module.title, &None, &Some(20));
+
+        client.remove_module(&module.id);
+
+        assert_eq!(client.get_module_duration(&module.id), None);
+        let exists: bool = env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .has(&(symbol_short!("modDur"), module.id.clone()))
+        });
+        assert!(!exists);
+    }
+
     #[test]
     #[should_panic(expected = "HostError: Error(Contract, #22)")]
     fn test_remove_module_with_empty_id() {
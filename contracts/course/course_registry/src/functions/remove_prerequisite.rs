@@ -3,12 +3,13 @@
 
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::events;
 use crate::error::{handle_error, Error};
+use crate::functions::prerequisite_index::set_course_prerequisites;
 use crate::schema::{Course, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-const PREREQ_REMOVED_EVENT: Symbol = symbol_short!("prereqRmv");
 
 pub fn remove_prerequisite(
     env: Env,
@@ -51,14 +52,10 @@ pub fn remove_prerequisite(
     }
 
     // Save updated prerequisites
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &prerequisites,
-    );
+    set_course_prerequisites(&env, &course_id, &prerequisites);
 
     // Emits an event for successful prerequisite removal.
-    env.events()
-        .publish((PREREQ_REMOVED_EVENT, course_id), prerequisite_course_id);
+    events::prerequisite_removed(&env, &course_id, &prerequisite_course_id);
 }
 
 #[cfg(test)]
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::events;
+use crate::error::{handle_error, Error, RegistryError};
+use crate::functions::add_goal::{store_new_goal, validate_goal_content};
+use crate::functions::course_index::{get_course_goal_ids, set_course_goal_ids};
+use crate::schema::{Course, CourseGoal, DataKey, MAX_GOALS_PER_COURSE};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Loads a course for a goal update and checks the caller is its creator.
+fn require_goal_editor(env: &Env, creator: &Address, course_id: &String) {
+    creator.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::CourseIdNotExist));
+    if course.creator != *creator {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+fn goal_ids(env: &Env, course_id: &String) -> Vec<String> {
+    get_course_goal_ids(env, course_id)
+}
+
+/// Rewrites the order of a course's goals in a single call.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: course creator performing the reorder.
+/// - course_id: unique identifier of the course.
+/// - ordered_goal_ids: every goal ID of the course, in the desired order.
+///
+/// Returns:
+/// - Vec<CourseGoal>: the course's goals in their new order.
+///
+/// Errors:
+/// - `Error::CourseIdNotExist` if the course does not exist.
+/// - `Error::Unauthorized` if the caller is not the creator.
+/// - `RegistryError::InvalidGoalOrder` if the list is not a permutation of the course's goals.
+pub fn reorder_goals(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    ordered_goal_ids: Vec<String>,
+) -> Vec<CourseGoal> {
+    require_goal_editor(&env, &creator, &course_id);
+
+    // The new order must contain every goal of the course exactly once
+    let current_ids: Vec<String> = goal_ids(&env, &course_id);
+    if ordered_goal_ids.len() != current_ids.len() {
+        handle_error(&env, RegistryError::InvalidGoalOrder)
+    }
+    let mut goals: Vec<CourseGoal> = Vec::new(&env);
+    for (i, goal_id) in ordered_goal_ids.iter().enumerate() {
+        if !current_ids.contains(&goal_id)
+            || ordered_goal_ids.first_index_of(&goal_id) != Some(i as u32)
+        {
+            handle_error(&env, RegistryError::InvalidGoalOrder)
+        }
+        if let Some(goal) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseGoal>(&DataKey::CourseGoal(course_id.clone(), goal_id))
+        {
+            goals.push_back(goal);
+        }
+    }
+
+    set_course_goal_ids(&env, &course_id, &ordered_goal_ids);
+
+    events::goals_reordered(&env, &course_id, &creator);
+
+    goals
+}
+
+/// Replaces every goal of a course with `contents`, in that order.
+///
+/// Meant for course setup wizards: the old goals are removed and the new
+/// ones created in one call, so the course never shows a partial list.
+///
+/// Errors:
+/// - `Error::CourseIdNotExist` if the course does not exist.
+/// - `Error::Unauthorized` if the caller is not the creator.
+/// - `RegistryError::InvalidBatchSize` if more than `MAX_GOALS_PER_COURSE` goals are given.
+/// - `Error::EmptyGoalContent` / `Error::InvalidGoalContent` for invalid content.
+pub fn set_goals(env: Env, creator: Address, course_id: String, contents: Vec<String>) -> Vec<CourseGoal> {
+    require_goal_editor(&env, &creator, &course_id);
+
+    if contents.len() > MAX_GOALS_PER_COURSE {
+        handle_error(&env, RegistryError::InvalidBatchSize)
+    }
+    // Validate everything before touching storage
+    for content in contents.iter() {
+        validate_goal_content(&env, &content);
+    }
+
+    for goal_id in goal_ids(&env, &course_id).iter() {
+        let key: DataKey = DataKey::CourseGoal(course_id.clone(), goal_id);
+        if let Some(goal) = env.storage().persistent().get::<_, CourseGoal>(&key) {
+            env.storage().persistent().remove(&key);
+            events::goal_removed(&env, &goal);
+        }
+    }
+    set_course_goal_ids(&env, &course_id, &Vec::new(&env));
+
+    let mut goals: Vec<CourseGoal> = Vec::new(&env);
+    for content in contents.iter() {
+        goals.push_back(store_new_goal(&env, &course_id, content, &creator));
+    }
+    goals
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, CourseGoal};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, Course) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "Description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course)
+    }
+
+    #[test]
+    fn test_set_and_reorder_goals() {
+        let env = Env::default();
+        let (client, creator, course) = setup(&env);
+        client.add_goal(&creator, &course.id, &String::from_str(&env, "Old goal"));
+
+        let goals: Vec<CourseGoal> = client.set_goals(
+            &creator,
+            &course.id,
+            &vec![&env, String::from_str(&env, "First"), String::from_str(&env, "Second")],
+        );
+        assert_eq!(goals.len(), 2);
+        let full = client.get_course_full(&course.id);
+        assert_eq!(full.goals, goals);
+
+        let first = goals.get(0).unwrap();
+        let second = goals.get(1).unwrap();
+        let reordered = client.reorder_goals(
+            &creator,
+            &course.id,
+            &vec![&env, second.goal_id.clone(), first.goal_id.clone()],
+        );
+        assert_eq!(reordered, vec![&env, second.clone(), first.clone()]);
+        assert_eq!(client.get_course_full(&course.id).goals, vec![&env, second, first]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #110)")]
+    fn test_reorder_goals_rejects_duplicates() {
+        let env = Env::default();
+        let (client, creator, course) = setup(&env);
+        let goal = client.add_goal(&creator, &course.id, &String::from_str(&env, "Goal"));
+        client.add_goal(&creator, &course.id, &String::from_str(&env, "Other"));
+
+        client.reorder_goals(
+            &creator,
+            &course.id,
+            &vec![&env, goal.goal_id.clone(), goal.goal_id],
+        );
+    }
+}
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::events;
+use crate::error::{handle_error, Error};
+use crate::functions::course_index::{get_course_module_ids, set_course_module_ids};
+use crate::schema::CourseModule;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+
+/// Rewrites the positions of a course's modules in a single call.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: course creator or admin performing the reorder.
+/// - course_id: unique identifier of the course.
+/// - ordered_module_ids: every module ID of the course, in the desired order.
+///
+/// The course's modules are read from its module index. Modules added before
+/// the index existed count once staged with `stage_legacy_course_index`.
+///
+/// Returns:
+/// - Vec<CourseModule>: the course's modules with their new positions (0-based).
+///
+/// Errors:
+/// - `Error::CourseIdNotExist` if the course does not exist.
+/// - `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// - `Error::InvalidModulePosition` if the list is not a permutation of the course's modules.
+pub fn reorder_modules(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    ordered_module_ids: Vec<String>,
+) -> Vec<CourseModule> {
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId)
+    }
+
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id.clone())) {
+        handle_error(&env, Error::CourseIdNotExist)
+    }
+
+    super::access_control::require_course_management_auth(&env, &creator, &course_id);
+
+    let current_ids: Vec<String> = get_course_module_ids(&env, &course_id);
+
+    // The new order must contain every module of the course exactly once
+    if ordered_module_ids.len() != current_ids.len() {
+        handle_error(&env, Error::InvalidModulePosition)
+    }
+    for (i, module_id) in ordered_module_ids.iter().enumerate() {
+        if !current_ids.contains(&module_id) {
+            handle_error(&env, Error::InvalidModulePosition)
+        }
+        if ordered_module_ids.first_index_of(&module_id) != Some(i as u32) {
+            handle_error(&env, Error::InvalidModulePosition)
+        }
+    }
+
+    // Release all current positions before assigning new ones
+    let mut modules: Vec<CourseModule> = Vec::new(&env);
+    for module_id in ordered_module_ids.iter() {
+        let module: CourseModule = env
+            .storage()
+            .persistent()
+            .get(&(MODULE_KEY, module_id))
+            .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+        env.storage()
+            .persistent()
+            .remove(&(POSITION_KEY, course_id.clone(), module.position));
+        modules.push_back(module);
+    }
+
+    let mut reordered: Vec<CourseModule> = Vec::new(&env);
+    for (position, mut module) in modules.iter().enumerate() {
+        module.position = position as u32;
+        env.storage()
+            .persistent()
+            .set(&(MODULE_KEY, module.id.clone()), &module);
+        env.storage()
+            .persistent()
+            .set(&(POSITION_KEY, course_id.clone(), module.position), &true);
+        reordered.push_back(module);
+    }
+
+    set_course_module_ids(&env, &course_id, &ordered_module_ids);
+
+    events::modules_reordered(&env, &course_id, &creator);
+
+    reordered
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::DataKey;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "title"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_reorder_modules() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+
+        let a = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "A"));
+        let b = client.add_module(&creator, &course_id, &1, &String::from_str(&env, "B"));
+        let c = client.add_module(&creator, &course_id, &2, &String::from_str(&env, "C"));
+
+        let reordered = client.reorder_modules(
+            &creator,
+            &course_id,
+            &vec![&env, c.id.clone(), a.id.clone(), b.id.clone()],
+        );
+
+        assert_eq!(reordered.get(0).unwrap().id, c.id);
+        assert_eq!(reordered.get(0).unwrap().position, 0);
+        assert_eq!(reordered.get(2).unwrap().id, b.id);
+        assert_eq!(reordered.get(2).unwrap().position, 2);
+
+        // Freed positions can be reused by new modules
+        client.add_module(&creator, &course_id, &3, &String::from_str(&env, "D"));
+
+        let detail = client.get_course_full(&course_id);
+        assert_eq!(detail.modules.get(0).unwrap().title, String::from_str(&env, "C"));
+        assert_eq!(detail.modules.get(1).unwrap().title, String::from_str(&env, "A"));
+        assert_eq!(detail.modules.get(3).unwrap().title, String::from_str(&env, "D"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #24)")]
+    fn test_reorder_modules_missing_module() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+
+        let a = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "A"));
+        client.add_module(&creator, &course_id, &1, &String::from_str(&env, "B"));
+
+        client.reorder_modules(&creator, &course_id, &vec![&env, a.id.clone()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #24)")]
+    fn test_reorder_modules_duplicate_module() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+
+        let a = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "A"));
+        client.add_module(&creator, &course_id, &1, &String::from_str(&env, "B"));
+
+        client.reorder_modules(&creator, &course_id, &vec![&env, a.id.clone(), a.id.clone()]);
+    }
+
+    #[test]
+    fn test_reorder_modules_written_before_the_index() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let owner = Address::generate(&env);
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::initialize(&env, &owner, &Address::generate(&env));
+        });
+
+        let a = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "A"));
+        let b = client.add_module(&creator, &course_id, &1, &String::from_str(&env, "B"));
+        // Drop the index as if both modules predated it
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::CourseModules(course_id.clone()));
+        });
+        assert!(client
+            .try_reorder_modules(&creator, &course_id, &vec![&env, b.id.clone(), a.id.clone()])
+            .is_err());
+
+        client.stage_legacy_course_index(
+            &owner,
+            &course_id,
+            &vec![&env, a.id.clone(), b.id.clone()],
+            &Vec::new(&env),
+        );
+        let reordered =
+            client.reorder_modules(&creator, &course_id, &vec![&env, b.id.clone(), a.id.clone()]);
+
+        assert_eq!(reordered.get(0).unwrap().id, b.id);
+        assert_eq!(reordered.get(1).unwrap().position, 1);
+        let modules = client.list_modules(&course_id);
+        assert_eq!(modules.get(0).unwrap().id, b.id);
+        assert_eq!(modules.get(1).unwrap().id, a.id);
+        env.as_contract(&client.address, || {
+            let index: Vec<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::CourseModules(course_id.clone()))
+                .unwrap();
+            assert_eq!(index, vec![&env, b.id.clone(), a.id.clone()]);
+        });
+    }
+}
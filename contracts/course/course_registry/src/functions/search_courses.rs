@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::utils::{to_lowercase, trim};
+use crate::schema::{Course, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Splits a title into its distinct lowercase words.
+///
+/// Any ASCII character that is not a letter or digit acts as a separator,
+/// so "Intro to Rust: Part-1" yields `intro`, `to`, `rust`, `part`, `1`.
+pub fn tokenize_title(env: &Env, title: &String) -> Vec<String> {
+    let lowercase: String = to_lowercase(env, title);
+    let len: usize = lowercase.len() as usize;
+    let mut buffer: [u8; 1024] = [0u8; 1024];
+    let slice: &mut [u8] = &mut buffer[..len];
+    lowercase.copy_into_slice(slice);
+
+    let mut tokens: Vec<String> = Vec::new(env);
+    let mut start: usize = 0;
+    for i in 0..=len {
+        let is_separator: bool =
+            i == len || (slice[i].is_ascii() && !slice[i].is_ascii_alphanumeric());
+        if !is_separator {
+            continue;
+        }
+        if i > start {
+            let token: String = String::from_bytes(env, &slice[start..i]);
+            if !tokens.contains(&token) {
+                tokens.push_back(token);
+            }
+        }
+        start = i + 1;
+    }
+
+    tokens
+}
+
+/// Adds a course to the token index of every word in its title.
+pub fn index_course_title(env: &Env, course_id: &String, title: &String) {
+    for token in tokenize_title(env, title).iter() {
+        let key: DataKey = DataKey::TitleToken(token);
+        let mut course_ids: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        if !course_ids.contains(course_id) {
+            course_ids.push_back(course_id.clone());
+            env.storage().persistent().set(&key, &course_ids);
+        }
+    }
+}
+
+/// Removes a course from the token index of every word in its title.
+pub fn unindex_course_title(env: &Env, course_id: &String, title: &String) {
+    for token in tokenize_title(env, title).iter() {
+        let key: DataKey = DataKey::TitleToken(token);
+        let course_ids: Vec<String> = match env.storage().persistent().get(&key) {
+            Some(ids) => ids,
+            None => continue,
+        };
+
+        let mut remaining: Vec<String> = Vec::new(env);
+        for id in course_ids.iter() {
+            if id != *course_id {
+                remaining.push_back(id);
+            }
+        }
+
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &remaining);
+        }
+    }
+}
+
+/// Looks up published, non-archived courses whose title contains `keyword` as a whole word.
+///
+/// The match is case-insensitive and served from the title token index,
+/// so it costs one read per candidate course instead of a full scan.
+pub fn search_courses_by_keyword(
+    env: &Env,
+    keyword: String,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Vec<Course> {
+    // Validate pagination parameters to prevent abuse
+    if let Some(l) = limit {
+        if l > 100 {
+            handle_error(env, Error::InvalidLimitValue)
+        }
+    }
+    if let Some(o) = offset {
+        if o > 10000 {
+            handle_error(env, Error::InvalidOffsetValue)
+        }
+    }
+
+    let mut results: Vec<Course> = Vec::new(env);
+
+    let normalized: String = to_lowercase(env, &trim(env, &keyword));
+    if normalized.is_empty() {
+        return results;
+    }
+
+    let course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::TitleToken(normalized))
+        .unwrap_or(Vec::new(env));
+
+    let offset_value: u32 = offset.unwrap_or(0);
+    let limit_value: u32 = limit.unwrap_or(10);
+    let max_limit: u32 = if limit_value > 20 { 20 } else { limit_value };
+
+    let mut matched: u32 = 0;
+    for course_id in course_ids.iter() {
+        if results.len() >= max_limit {
+            break;
+        }
+
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+            Some(course) => course,
+            None => continue,
+        };
+
+        // Skip archived or unpublished courses, as list_courses_with_filters does
+        if course.is_archived || !course.published {
+            continue;
+        }
+
+        if matched >= offset_value {
+            results.push_back(course);
+        }
+        matched += 1;
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address};
+
+    fn publish(client: &CourseRegistryClient, creator: &Address, course_id: &String) {
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(creator, course_id, &params);
+    }
+
+    #[test]
+    fn test_tokenize_title() {
+        let env = Env::default();
+        let tokens = tokenize_title(&env, &String::from_str(&env, "Intro to Rust: rust Part-1"));
+        assert_eq!(
+            tokens,
+            soroban_sdk::vec![
+                &env,
+                String::from_str(&env, "intro"),
+                String::from_str(&env, "to"),
+                String::from_str(&env, "rust"),
+                String::from_str(&env, "part"),
+                String::from_str(&env, "1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_courses_by_keyword() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let description = String::from_str(&env, "description");
+        let rust = client.create_course(&creator, &String::from_str(&env, "Learn Rust Basics"), &description, &1000, &None, &None, &None, &None, &None);
+        let soroban = client.create_course(&creator, &String::from_str(&env, "Soroban with Rust"), &description, &1000, &None, &None, &None, &None, &None);
+        let draft = client.create_course(&creator, &String::from_str(&env, "Rust Drafts"), &description, &1000, &None, &None, &None, &None, &None);
+        publish(&client, &creator, &rust.id);
+        publish(&client, &creator, &soroban.id);
+
+        let results = client.search_courses_by_keyword(&String::from_str(&env, "RUST"), &None, &None);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get(0).unwrap().id, rust.id);
+        assert_eq!(results.get(1).unwrap().id, soroban.id);
+
+        let page = client.search_courses_by_keyword(&String::from_str(&env, "rust"), &Some(1), &Some(1));
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, soroban.id);
+
+        // Unpublished drafts are indexed but not returned
+        let drafts = client.search_courses_by_keyword(&String::from_str(&env, "drafts"), &None, &None);
+        assert!(drafts.is_empty());
+        assert!(env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .get::<DataKey, Vec<String>>(&DataKey::TitleToken(String::from_str(&env, "drafts")))
+                .unwrap()
+                .contains(&draft.id)
+        }));
+    }
+
+    #[test]
+    fn test_search_index_follows_title_edits_and_deletes() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(&creator, &String::from_str(&env, "Python Basics"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+        publish(&client, &creator, &course.id);
+
+        let params = EditCourseParams {
+            new_title: Some(String::from_str(&env, "Golang Basics")),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &course.id, &params);
+
+        assert!(client.search_courses_by_keyword(&String::from_str(&env, "python"), &None, &None).is_empty());
+        assert_eq!(client.search_courses_by_keyword(&String::from_str(&env, "golang"), &None, &None).len(), 1);
+        assert_eq!(client.search_courses_by_keyword(&String::from_str(&env, "basics"), &None, &None).len(), 1);
+
+        client.delete_course(&creator, &course.id, &false);
+        assert!(client.search_courses_by_keyword(&String::from_str(&env, "golang"), &None, &None).is_empty());
+    }
+}
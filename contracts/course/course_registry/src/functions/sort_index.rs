@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Sorted indexes of course IDs backing the sort options of course listings.
+//!
+//! Each index holds every course once, ordered by ascending value, and is
+//! updated whenever the value changes: prices on creation and edits, ratings
+//! and enrollments when they are reported. Newest-first listings walk the
+//! course IDs backwards and need no index.
+//!
+//! An index is split into pages of at most `SORT_PAGE_SIZE` entries, listed
+//! in order with their length and last value, and a full page is split in
+//! two. The value each course is indexed under is stored with it, so an
+//! update reads and writes the page list, one or two pages and that value
+//! however many courses there are. Indexes written as a single list by
+//! earlier versions are rebuilt into pages by the 1.0.0 -> 1.1.0 migration.
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::functions::course_stats::get_course_stats;
+use crate::functions::utils::course_id_to_string;
+use crate::schema::{
+    Course, DataKey, SortEntry, SortField, SortOption, SortPage, SORT_PAGE_SIZE,
+};
+
+const COURSE_ID: Symbol = symbol_short!("course");
+
+/// Persistent storage key prefix: `(SORT_PAGES_KEY, field) -> Vec<SortPage>`
+const SORT_PAGES_KEY: Symbol = symbol_short!("sortPages");
+/// Persistent storage key prefix: `(SORT_PAGE_KEY, field, page_id) -> Vec<SortEntry>`
+const SORT_PAGE_KEY: Symbol = symbol_short!("sortPage");
+/// Persistent storage key prefix: `(SORT_VALUE_KEY, field, course_id) -> u128`
+const SORT_VALUE_KEY: Symbol = symbol_short!("sortValue");
+
+fn sort_pages(env: &Env, field: &SortField) -> Vec<SortPage> {
+    env.storage()
+        .persistent()
+        .get(&(SORT_PAGES_KEY, field.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_sort_pages(env: &Env, field: &SortField, pages: &Vec<SortPage>) {
+    let key: (Symbol, SortField) = (SORT_PAGES_KEY, field.clone());
+    if pages.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, pages);
+    }
+}
+
+fn sort_page(env: &Env, field: &SortField, page_id: u32) -> Vec<SortEntry> {
+    env.storage()
+        .persistent()
+        .get(&(SORT_PAGE_KEY, field.clone(), page_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the entries of the page at `position` and refreshes its summary,
+/// dropping the page once it is empty.
+fn store_page(
+    env: &Env,
+    field: &SortField,
+    pages: &mut Vec<SortPage>,
+    position: u32,
+    page_id: u32,
+    entries: &Vec<SortEntry>,
+) {
+    let key: (Symbol, SortField, u32) = (SORT_PAGE_KEY, field.clone(), page_id);
+    match entries.last() {
+        Some(last) => {
+            env.storage().persistent().set(&key, entries);
+            pages.set(
+                position,
+                SortPage {
+                    id: page_id,
+                    len: entries.len(),
+                    last_value: last.value,
+                },
+            );
+        }
+        None => {
+            env.storage().persistent().remove(&key);
+            pages.remove(position);
+        }
+    }
+}
+
+/// Removes a course from an index, returning whether it was in it.
+fn remove_entry(env: &Env, field: &SortField, course_id: &String) -> bool {
+    let value_key: (Symbol, SortField, String) = (SORT_VALUE_KEY, field.clone(), course_id.clone());
+    let value: u128 = match env.storage().persistent().get(&value_key) {
+        Some(value) => value,
+        None => return false,
+    };
+    env.storage().persistent().remove(&value_key);
+
+    // Courses with the same value may span several pages
+    let mut pages: Vec<SortPage> = sort_pages(env, field);
+    let mut position: u32 = match pages.iter().position(|page| page.last_value >= value) {
+        Some(position) => position as u32,
+        None => return false,
+    };
+    while let Some(page) = pages.get(position) {
+        let mut entries: Vec<SortEntry> = sort_page(env, field, page.id);
+        if let Some(index) = entries.iter().position(|entry| entry.course_id == *course_id) {
+            entries.remove(index as u32);
+            store_page(env, field, &mut pages, position, page.id, &entries);
+            set_sort_pages(env, field, &pages);
+            return true;
+        }
+        if page.last_value > value {
+            break;
+        }
+        position += 1;
+    }
+    false
+}
+
+/// Inserts a course into an index after the courses with the same value.
+fn insert_entry(env: &Env, field: &SortField, course_id: &String, value: u128) {
+    let mut pages: Vec<SortPage> = sort_pages(env, field);
+    if pages.is_empty() {
+        pages.push_back(SortPage {
+            id: 0,
+            len: 0,
+            last_value: value,
+        });
+    }
+    let position: u32 = pages
+        .iter()
+        .position(|page| page.last_value > value)
+        .map_or(pages.len() - 1, |position| position as u32);
+    let page_id: u32 = pages.get(position).unwrap().id;
+    let mut entries: Vec<SortEntry> = sort_page(env, field, page_id);
+
+    let (mut low, mut high): (u32, u32) = (0, entries.len());
+    while low < high {
+        let middle: u32 = (low + high) / 2;
+        if entries.get(middle).unwrap().value <= value {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    entries.insert(
+        low,
+        SortEntry {
+            course_id: course_id.clone(),
+            value,
+        },
+    );
+
+    if entries.len() > SORT_PAGE_SIZE {
+        let half: u32 = entries.len() / 2;
+        let tail: Vec<SortEntry> = entries.slice(half..);
+        let new_id: u32 = pages.iter().map(|page| page.id).max().unwrap_or(0) + 1;
+        pages.insert(
+            position + 1,
+            SortPage {
+                id: new_id,
+                len: 0,
+                last_value: value,
+            },
+        );
+        store_page(env, field, &mut pages, position + 1, new_id, &tail);
+        entries = entries.slice(..half);
+    }
+    store_page(env, field, &mut pages, position, page_id, &entries);
+    set_sort_pages(env, field, &pages);
+
+    env.storage()
+        .persistent()
+        .set(&(SORT_VALUE_KEY, field.clone(), course_id.clone()), &value);
+}
+
+/// Moves a course to the position of `value` in an index, after the
+/// courses with the same value.
+pub(crate) fn update_sort_index(env: &Env, field: SortField, course_id: &String, value: u128) {
+    remove_entry(env, &field, course_id);
+    insert_entry(env, &field, course_id, value);
+}
+
+/// Adds a course to every index, or refreshes its values from the stored
+/// price, rating and enrollments.
+pub(crate) fn index_course_sort_keys(env: &Env, course: &Course) {
+    let (sum, count): (u64, u32) = get_course_rating(env, course.id.clone());
+    let rating: u128 = if count == 0 {
+        0
+    } else {
+        (sum * 100 / count as u64) as u128
+    };
+    update_sort_index(env, SortField::Price, &course.id, course.price);
+    update_sort_index(env, SortField::Rating, &course.id, rating);
+    update_sort_index(
+        env,
+        SortField::Enrollments,
+        &course.id,
+        get_course_stats(env, course.id.clone()).enrollments as u128,
+    );
+}
+
+/// Drops a deleted course from every index.
+pub(crate) fn remove_from_sort_indexes(env: &Env, course_id: &String) {
+    for field in [SortField::Price, SortField::Rating, SortField::Enrollments] {
+        remove_entry(env, &field, course_id);
+    }
+}
+
+/// Number of course IDs the sort index migration step walks over.
+pub(crate) fn sort_index_backfill_len(env: &Env) -> u32 {
+    let last_id: u128 = env.storage().persistent().get(&COURSE_ID).unwrap_or(0);
+    u32::try_from(last_id).unwrap_or(u32::MAX)
+}
+
+/// Indexes the course whose ID follows `index`, if it wasn't deleted. The
+/// single-list indexes of earlier versions are dropped after the last course.
+pub(crate) fn backfill_sort_index(env: &Env, index: u32) {
+    let course_id: String = course_id_to_string(env, index as u128 + 1);
+    if let Some(course) = env
+        .storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_ID, course_id))
+    {
+        index_course_sort_keys(env, &course);
+    }
+    if index + 1 >= sort_index_backfill_len(env) {
+        for field in [SortField::Price, SortField::Rating, SortField::Enrollments] {
+            env.storage().persistent().remove(&DataKey::SortIndex(field));
+        }
+    }
+}
+
+/// Returns the running sum and count of the ratings of a course.
+pub fn get_course_rating(env: &Env, course_id: String) -> (u64, u32) {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseRating(course_id))
+        .unwrap_or((0, 0))
+}
+
+/// Course IDs in the order of a sort option.
+pub(crate) struct SortedCourseIds {
+    sort_by: SortOption,
+    field: SortField,
+    pages: Vec<SortPage>,
+    total: u32,
+    last_id: u128,
+    /// Position of the first entry of `entries` in the index, and the entries
+    /// of the page read last
+    loaded: Option<(u32, Vec<SortEntry>)>,
+}
+
+impl SortedCourseIds {
+    pub(crate) fn new(env: &Env, sort_by: SortOption) -> Self {
+        let field: SortField = match sort_by {
+            SortOption::Newest | SortOption::PriceAsc | SortOption::PriceDesc => SortField::Price,
+            SortOption::RatingDesc => SortField::Rating,
+            SortOption::MostEnrolled => SortField::Enrollments,
+        };
+        let pages: Vec<SortPage> = match sort_by {
+            SortOption::Newest => Vec::new(env),
+            _ => sort_pages(env, &field),
+        };
+        let total: u32 = pages.iter().map(|page| page.len).sum();
+        let last_id: u128 = env.storage().persistent().get(&COURSE_ID).unwrap_or(0);
+        SortedCourseIds {
+            sort_by,
+            field,
+            pages,
+            total,
+            last_id,
+            loaded: None,
+        }
+    }
+
+    /// Returns the entry at `index` in ascending order, reading its page
+    /// unless it was the last one read.
+    fn entry(&mut self, env: &Env, index: u32) -> Option<SortEntry> {
+        if let Some((start, entries)) = &self.loaded {
+            if index >= *start && index - start < entries.len() {
+                return entries.get(index - start);
+            }
+        }
+        let mut start: u32 = 0;
+        for page in self.pages.iter() {
+            if index < start + page.len {
+                let entries: Vec<SortEntry> = sort_page(env, &self.field, page.id);
+                let entry: Option<SortEntry> = entries.get(index - start);
+                self.loaded = Some((start, entries));
+                return entry;
+            }
+            start += page.len;
+        }
+        None
+    }
+
+    /// Returns the course ID at `position`, or `None` past the end.
+    pub(crate) fn get(&mut self, env: &Env, position: u32) -> Option<String> {
+        match self.sort_by {
+            SortOption::Newest => {
+                if (position as u128) < self.last_id {
+                    Some(course_id_to_string(env, self.last_id - position as u128))
+                } else {
+                    None
+                }
+            }
+            SortOption::PriceAsc => self.entry(env, position).map(|entry| entry.course_id),
+            SortOption::PriceDesc | SortOption::RatingDesc | SortOption::MostEnrolled => {
+                if position < self.total {
+                    self.entry(env, self.total - 1 - position)
+                        .map(|entry| entry.course_id)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CourseRegistry;
+    use soroban_sdk::Address;
+
+    fn ordered_ids(env: &Env, sort_by: SortOption) -> Vec<String> {
+        let mut sorted: SortedCourseIds = SortedCourseIds::new(env, sort_by);
+        let mut ids: Vec<String> = Vec::new(env);
+        while let Some(course_id) = sorted.get(env, ids.len()) {
+            ids.push_back(course_id);
+        }
+        ids
+    }
+
+    #[test]
+    fn test_index_is_split_into_pages_and_stays_sorted() {
+        let env: Env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        env.as_contract(&contract_id, || {
+            let count: u32 = SORT_PAGE_SIZE * 3;
+            for id in 1..=count {
+                let value: u128 = (id as u128 * 37) % 101;
+                update_sort_index(&env, SortField::Price, &course_id_to_string(&env, id as u128), value);
+            }
+            // Moving and removing courses keeps every page in order
+            for id in (1..=count).step_by(5) {
+                update_sort_index(&env, SortField::Price, &course_id_to_string(&env, id as u128), 50);
+            }
+            for id in (2..=count).step_by(7) {
+                remove_from_sort_indexes(&env, &course_id_to_string(&env, id as u128));
+            }
+
+            let pages: Vec<SortPage> = sort_pages(&env, &SortField::Price);
+            assert!(pages.len() > 1);
+            assert!(pages.iter().all(|page| page.len > 0 && page.len <= SORT_PAGE_SIZE));
+
+            let ids: Vec<String> = ordered_ids(&env, SortOption::PriceAsc);
+            assert_eq!(ids.len(), pages.iter().map(|page| page.len).sum::<u32>());
+            assert_eq!(ids.len(), count - (2..=count).step_by(7).count() as u32);
+            let mut previous: u128 = 0;
+            for course_id in ids.iter() {
+                let value: u128 = env
+                    .storage()
+                    .persistent()
+                    .get(&(SORT_VALUE_KEY, SortField::Price, course_id))
+                    .unwrap();
+                assert!(value >= previous);
+                previous = value;
+            }
+
+            let descending: Vec<String> = ordered_ids(&env, SortOption::PriceDesc);
+            assert_eq!(descending.first(), ids.last());
+            assert_eq!(descending.last(), ids.first());
+        });
+    }
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Lifecycle history of courses.
+//!
+//! Every publish, unpublish and archive is appended to the course's status
+//! history and published as a `("course", "status", course_id)` event, so
+//! indexers and dashboards can rebuild a course's timeline.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::events;
+use crate::functions::get_courses_by_instructor::course_status;
+use crate::functions::platform_stats::on_course_published_changed;
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{
+    Course, CourseStatus, DataKey, StatusChange, MAX_STATUS_HISTORY, MAX_STATUS_REASON_LENGTH,
+};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Panics with `InvalidStatusReason` if a reason is empty or too long.
+pub fn validate_reason(env: &Env, reason: &Option<String>) {
+    if let Some(reason) = reason {
+        if reason.is_empty() || reason.len() > MAX_STATUS_REASON_LENGTH {
+            handle_error(env, RegistryError::InvalidStatusReason)
+        }
+    }
+}
+
+/// Appends a status change to the course's history, dropping the oldest
+/// entry beyond `MAX_STATUS_HISTORY`, and publishes it.
+pub fn record_status_change(
+    env: &Env,
+    course_id: &String,
+    old_status: CourseStatus,
+    new_status: CourseStatus,
+    actor: &Address,
+    reason: Option<String>,
+) {
+    let change: StatusChange = StatusChange {
+        old_status,
+        new_status,
+        actor: actor.clone(),
+        reason,
+        changed_at: env.ledger().timestamp(),
+    };
+
+    let key: DataKey = DataKey::StatusHistory(course_id.clone());
+    let mut history: Vec<StatusChange> = get_status_history(env, course_id.clone());
+    if history.len() >= MAX_STATUS_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(change.clone());
+    env.storage().persistent().set(&key, &history);
+    bump_persistent(env, &key);
+
+    events::course_status_changed(env, course_id, &change);
+}
+
+/// Returns the recorded status changes of a course, oldest first.
+pub fn get_status_history(env: &Env, course_id: String) -> Vec<StatusChange> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatusHistory(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Publishes or unpublishes a course, recording the reason. Creator only.
+pub fn set_course_published(
+    env: &Env,
+    creator: Address,
+    course_id: String,
+    published: bool,
+    reason: Option<String>,
+) -> Course {
+    creator.require_auth();
+    validate_reason(env, &reason);
+
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+    if course.published == published {
+        return course;
+    }
+
+    let old_status: CourseStatus = course_status(&course);
+    course.published = published;
+    on_course_published_changed(env, published);
+    env.storage().persistent().set(&key, &course);
+    bump_persistent(env, &key);
+
+    record_status_change(
+        env,
+        &course_id,
+        old_status,
+        course_status(&course),
+        &creator,
+        reason,
+    );
+    events::course_updated(env, &course_id, &creator);
+
+    course
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_status_history() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = CourseRegistryClient::new(&env, &env.register(CourseRegistry, ()));
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Lifecycle"),
+            &String::from_str(&env, "description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.set_course_published(&creator, &course.id, &true, &None);
+        let reason = Some(String::from_str(&env, "Outdated content"));
+        client.archive_course(&creator, &course.id, &reason);
+
+        let history = client.get_status_history(&course.id);
+        assert_eq!(history.len(), 2);
+        let published = history.get(0).unwrap();
+        assert_eq!(published.old_status, CourseStatus::Draft);
+        assert_eq!(published.new_status, CourseStatus::Published);
+        assert_eq!(published.actor, creator);
+        let archived = history.get(1).unwrap();
+        assert_eq!(archived.old_status, CourseStatus::Published);
+        assert_eq!(archived.new_status, CourseStatus::Archived);
+        assert_eq!(archived.reason, reason);
+
+        // Unchanged state records nothing; other users can't change it
+        client.set_course_published(&creator, &course.id, &true, &None);
+        assert_eq!(client.get_status_history(&course.id).len(), 2);
+        let result =
+            client.try_set_course_published(&Address::generate(&env), &course.id, &false, &None);
+        assert!(result.is_err());
+        let result = client.try_set_course_published(
+            &creator,
+            &course.id,
+            &false,
+            &Some(String::from_str(&env, "")),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+use crate::error::{handle_error, Error, RegistryError};
+use crate::functions::access_control::is_owner;
+use crate::functions::course_index::{get_course_goal_ids, get_course_module_ids};
+use crate::schema::{DataKey, TtlConfig, DEFAULT_TTL_EXTEND_TO, DEFAULT_TTL_THRESHOLD};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const SET_TTL_CONFIG_EVENT: Symbol = symbol_short!("setTtlCfg");
+
+/// Returns the TTL thresholds, falling back to the defaults.
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        })
+}
+
+/// Updates the TTL thresholds. Owner only.
+pub fn set_ttl_config(env: &Env, caller: Address, threshold: u32, extend_to: u32) {
+    caller.require_auth();
+
+    if !is_owner(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let config: TtlConfig = TtlConfig { threshold, extend_to };
+    validate_ttl_config(env, &config);
+    env.storage().instance().set(&DataKey::TtlConfig, &config);
+
+    env.events()
+        .publish((SET_TTL_CONFIG_EVENT,), (caller, threshold, extend_to));
+}
+
+/// Panics unless `0 < threshold < extend_to <= max_ttl`.
+pub fn validate_ttl_config(env: &Env, config: &TtlConfig) {
+    if config.threshold == 0
+        || config.threshold >= config.extend_to
+        || config.extend_to > env.storage().max_ttl()
+    {
+        handle_error(env, RegistryError::InvalidTtlConfig)
+    }
+}
+
+/// Extends the TTL of a persistent entry if it exists.
+pub fn bump_persistent<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    if env.storage().persistent().has(key) {
+        let config: TtlConfig = get_ttl_config(env);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, config.threshold, config.extend_to);
+    }
+}
+
+/// Extends the TTL of the contract instance, which holds the configuration.
+pub fn bump_instance(env: &Env) {
+    let config: TtlConfig = get_ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Extends the TTL of a course and everything stored alongside it:
+/// modules, goals, prerequisites and statistics.
+pub fn extend_course_ttl(env: &Env, course_id: String) {
+    let course_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    if !env.storage().persistent().has(&course_key) {
+        handle_error(env, Error::CourseNotFound)
+    }
+    bump_persistent(env, &course_key);
+
+    let module_ids: Vec<String> = get_course_module_ids(env, &course_id);
+    bump_persistent(env, &DataKey::CourseModules(course_id.clone()));
+    for module_id in module_ids.iter() {
+        bump_persistent(env, &(MODULE_KEY, module_id));
+    }
+
+    let goal_ids: Vec<String> = get_course_goal_ids(env, &course_id);
+    bump_persistent(env, &DataKey::CourseGoalList(course_id.clone()));
+    for goal_id in goal_ids.iter() {
+        bump_persistent(env, &DataKey::CourseGoal(course_id.clone(), goal_id));
+    }
+
+    bump_persistent(env, &DataKey::CoursePrerequisites(course_id.clone()));
+    bump_persistent(env, &DataKey::CourseStats(course_id));
+    bump_instance(env);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::functions::access_control::initialize;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::storage::Persistent as _;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_extend_course_ttl() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let owner = Address::generate(&env);
+        env.as_contract(&contract_id, || initialize(&env, &owner, &Address::generate(&env)));
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(&creator, &String::from_str(&env, "TTL Course"), &String::from_str(&env, "description"), &1000, &None, &None, &None, &None, &None);
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module"));
+
+        // Written entries are bumped with the default config
+        env.as_contract(&contract_id, || {
+            let course_ttl = env.storage().persistent().get_ttl(&(COURSE_KEY, course.id.clone()));
+            assert_eq!(course_ttl, DEFAULT_TTL_EXTEND_TO);
+        });
+
+        let extend_to: u32 = DEFAULT_TTL_EXTEND_TO * 2;
+        client.set_ttl_config(&owner, &(DEFAULT_TTL_EXTEND_TO + 1), &extend_to);
+        client.extend_course_ttl(&course.id);
+
+        env.as_contract(&contract_id, || {
+            let course_ttl = env.storage().persistent().get_ttl(&(COURSE_KEY, course.id.clone()));
+            let module_ttl = env.storage().persistent().get_ttl(&(MODULE_KEY, module.id.clone()));
+            assert_eq!(course_ttl, extend_to);
+            assert_eq!(module_ttl, extend_to);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #129)")]
+    fn test_set_ttl_config_invalid() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let owner = Address::generate(&env);
+        env.as_contract(&contract_id, || initialize(&env, &owner, &Address::generate(&env)));
+
+        client.set_ttl_config(&owner, &1000, &500);
+    }
+}
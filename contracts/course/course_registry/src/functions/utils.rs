@@ -3,6 +3,8 @@
 
 use soroban_sdk::{vec, Bytes, Env, String, Vec};
 
+pub use validation::text::{to_lowercase, trim};
+
 pub fn generate_unique_id(env: &Env) -> String {
     let ts: u64 = env.ledger().timestamp();
     let rand1: u64 = env.prng().gen();
@@ -26,25 +28,50 @@ pub fn generate_unique_id(env: &Env) -> String {
     rust_str
 }
 
-pub fn to_lowercase(env: &Env, s: &String) -> String {
-    let len: u32 = s.len();
-    let mut buffer: [u8; 1024] = [0u8; 1024];
-    let slice: &mut [u8] = &mut buffer[..len as usize];
-    s.copy_into_slice(slice);
-    let mut result_bytes = Bytes::new(env);
-
-    for byte in slice.iter() {
-        if *byte >= b'A' && *byte <= b'Z' {
-            result_bytes.push_back(*byte + (b'a' - b'A'));
-        } else {
-            result_bytes.push_back(*byte);
+/// Converts a numeric course ID to the `String` used as its storage key and
+/// public `Course::id`.
+///
+/// Course IDs are handed out as `u128`. Below 2^32 the result matches what
+/// `u32_to_string` produced before, so existing course keys stay valid and
+/// need no migration; larger IDs no longer wrap around and collide.
+///
+/// The number is not stored natively. `Course::id`, every course-scoped
+/// `DataKey`, the emitted events and the course ID parameters of the other
+/// contracts are all `String`, so switching keys to `u128` would break every
+/// caller and strand existing records. The decimal form round-trips exactly
+/// through `parse_course_id`, which is all range scans and
+/// `get_course_by_number` need.
+pub fn course_id_to_string(env: &Env, id: u128) -> String {
+    let mut buffer: [u8; 39] = [0u8; 39]; // u128::MAX has 39 digits
+    let mut start: usize = buffer.len();
+    let mut num: u128 = id;
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (num % 10) as u8;
+        num /= 10;
+        if num == 0 {
+            break;
         }
     }
+    String::from_bytes(env, &buffer[start..])
+}
 
-    let mut result: [u8; 1024] = [0u8; 1024];
-    let new_slice: &mut [u8] = &mut result[..len as usize];
-    result_bytes.copy_into_slice(new_slice);
-    String::from_bytes(env, new_slice)
+/// Parses a course ID back to its numeric form, `None` if it isn't one.
+pub fn parse_course_id(id: &String) -> Option<u128> {
+    let len: usize = id.len() as usize;
+    if len == 0 || len > 39 {
+        return None;
+    }
+    let mut buffer: [u8; 39] = [0u8; 39];
+    id.copy_into_slice(&mut buffer[..len]);
+    let mut num: u128 = 0;
+    for byte in buffer[..len].iter() {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        num = num.checked_mul(10)?.checked_add((byte - b'0') as u128)?;
+    }
+    Some(num)
 }
 
 pub fn u32_to_string(env: &Env, n: u32) -> String {
@@ -73,54 +100,6 @@ pub fn u32_to_string(env: &Env, n: u32) -> String {
     String::from_bytes(env, new_slice)
 }
 
-pub fn trim(env: &Env, s: &String) -> String {
-    // Create a fixed-size buffer for the string's bytes
-    let len: usize = s.len() as usize;
-    let mut byte_array: [u8; 1024] = [0u8; 1024];
-    // TODO: Implement dynamic buffer sizing or proper error handling for large strings
-    if len > byte_array.len() {
-        panic!("String too long for fixed-size buffer");
-    }
-    s.copy_into_slice(&mut byte_array[..len]);
-
-    // Create a Bytes object from the buffer
-    let bytes: Bytes = Bytes::from_slice(env, &byte_array[..len]);
-
-    // Find the first non-whitespace character
-    let mut start: u32 = 0;
-    while start < bytes.len() {
-        let mut byte_buffer: [u8; 1] = [0u8; 1];
-        bytes
-            .slice(start..start + 1)
-            .copy_into_slice(&mut byte_buffer);
-        let byte: u8 = byte_buffer[0];
-        if byte != 32 && byte != 9 {
-            break;
-        }
-        start += 1;
-    }
-
-    // Find the last non-whitespace character
-    let mut end: u32 = bytes.len();
-    while end > start {
-        let mut byte_buffer: [u8; 1] = [0u8; 1];
-        bytes.slice(end - 1..end).copy_into_slice(&mut byte_buffer);
-        let byte = byte_buffer[0];
-        if byte != 32 && byte != 9 {
-            break;
-        }
-        end -= 1;
-    }
-
-    // Create a trimmed Bytes object
-    let trimmed_bytes: Bytes = bytes.slice(start as u32..end as u32);
-
-    let mut result: [u8; 1024] = [0u8; 1024];
-    let new_slice: &mut [u8] = &mut result[..(end - start) as usize];
-    trimmed_bytes.copy_into_slice(new_slice);
-    String::from_bytes(env, new_slice)
-}
-
 pub fn concat_strings(env: &Env, strings: Vec<String>) -> String {
     let mut result_byte: Bytes = Bytes::new(env);
     let mut total_len: usize = 0;
@@ -198,4 +177,20 @@ mod tests {
         assert!(!lowercase_result.is_empty());
         assert!(!trim_result.is_empty());
     }
+
+    #[test]
+    fn test_course_id_round_trip() {
+        let env = Env::default();
+
+        assert_eq!(course_id_to_string(&env, 0), String::from_str(&env, "0"));
+        assert_eq!(course_id_to_string(&env, 42), u32_to_string(&env, 42));
+        // Past u32::MAX the ID no longer wraps around
+        let large: u128 = u32::MAX as u128 + 1;
+        assert_eq!(course_id_to_string(&env, large), String::from_str(&env, "4294967296"));
+        assert_eq!(parse_course_id(&course_id_to_string(&env, u128::MAX)), Some(u128::MAX));
+
+        assert_eq!(parse_course_id(&String::from_str(&env, "17")), Some(17));
+        assert_eq!(parse_course_id(&String::from_str(&env, "course_1")), None);
+        assert_eq!(parse_course_id(&String::from_str(&env, "")), None);
+    }
 }
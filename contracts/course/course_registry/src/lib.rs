@@ -7,6 +7,7 @@
 pub const VERSION: &str = "1.0.0";
 
 pub mod error;
+pub mod events;
 pub mod functions;
 pub mod schema;
 
@@ -14,9 +15,14 @@ pub mod schema;
 mod test;
 
 use crate::schema::{
-    Course, CourseCategory, CourseFilters, CourseGoal, CourseLevel, CourseModule, EditCourseParams,
+    ActionRateLimit, CompletionCriteria, Course, CourseCategory, CourseDetail, CourseFilters, CourseGoal, CourseLevel, CourseModule,
+    CourseStats, CourseTranslation, DiscussionThread, EditCourseParams, InstructorStats, InstructorSummary,
+    ModuleRelease,
+    ContentLimits, MigrationState, PriceLimits, NewCourseParams, PlatformStats, RateLimitedAction, RegistryConfig,
+    ThreadReply, TtlConfig,
 };
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Vec};
+use functions::contract_versioning::{MigrationStatus, VersionRecord};
 
 /// Course Registry Contract
 ///
@@ -28,6 +34,167 @@ pub struct CourseRegistry;
 
 #[contractimpl]
 impl CourseRegistry {
+    /// Initialize the course registry.
+    ///
+    /// Sets the contract owner, the user management contract used for admin
+    /// checks, and the storage and rate limit configuration. Can only be
+    /// called once.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address that becomes the contract owner
+    /// * `user_mgmt_addr` - Address of the user management contract
+    /// * `config` - TTL thresholds and course creation rate limit
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is already initialized
+    /// * If the TTL thresholds are invalid
+    /// * If the rate limit window or maximum is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.initialize(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     user_management_address,
+    ///     RegistryConfig {
+    ///         ttl: TtlConfig { threshold: 120_960, extend_to: 518_400 },
+    ///         rate_limit: CourseRateLimitConfig { window_seconds: 3600, max_courses_per_window: 3 },
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Re-initialization**: A second call fails with `AlreadyInitialized`
+    /// * **Admin checks**: Privileged calls accept the owner or any admin of the user management contract
+    pub fn initialize(env: Env, admin: Address, user_mgmt_addr: Address, config: RegistryConfig) {
+        functions::access_control::initialize_registry(&env, &admin, &user_mgmt_addr, &config)
+    }
+
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::access_control::is_initialized(&env)
+    }
+
+    /// Get the owner and the addresses of the contracts the registry calls.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is not initialized
+    pub fn get_config(env: Env) -> crate::schema::CourseRegistryConfig {
+        functions::access_control::get_config(&env)
+    }
+
+    /// Turn permissionless course creation on or off (owner or admin only).
+    ///
+    /// While off, `create_course`, `create_courses_batch` and `clone_course`
+    /// require the `CreateCourses` permission of the user management RBAC module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `enabled` - Whether anyone can create courses
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Only instructors and admins can create courses from now on
+    /// contract.set_permissionless_creation(env.clone(), admin_address, false);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Default**: Permissionless creation is on until turned off
+    pub fn set_permissionless_creation(env: Env, admin: Address, enabled: bool) {
+        functions::pause::require_not_paused(&env);
+        functions::access_control::set_permissionless_creation(&env, &admin, enabled)
+    }
+
+    /// Check whether anyone can create courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if course creation doesn't require the `CreateCourses` permission.
+    pub fn is_permissionless_creation(env: Env) -> bool {
+        functions::access_control::is_permissionless_creation(&env)
+    }
+
+    /// Set the course access contract (owner or admin only).
+    ///
+    /// `delete_course` asks it how many users have access to a course and
+    /// refuses to delete courses with students unless an admin forces it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `course_access_addr` - Address of the course access contract
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Not configured**: Courses are deleted without an enrollment check
+    pub fn set_course_access_contract(env: Env, admin: Address, course_access_addr: Address) {
+        functions::pause::require_not_paused(&env);
+        functions::access_control::set_course_access_contract(&env, &admin, &course_access_addr)
+    }
+
+    /// Get the course access contract, `None` if it is not configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    pub fn get_course_access_contract(env: Env) -> Option<Address> {
+        functions::access_control::get_course_access_contract(&env)
+    }
+
+    /// Pause the contract during an incident (owner or admin only).
+    ///
+    /// While paused, every state-changing entrypoint fails with
+    /// `ContractPaused`. Reads, TTL extensions and `upgrade` keep working.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already paused**: Nothing changes and no event is published
+    pub fn pause(env: Env, admin: Address) {
+        functions::pause::pause(&env, admin)
+    }
+
+    /// Lift the pause set with `pause` (owner or admin only).
+    pub fn unpause(env: Env, admin: Address) {
+        functions::pause::unpause(&env, admin)
+    }
+
+    /// Check whether the contract is paused.
+    pub fn is_paused(env: Env) -> bool {
+        functions::pause::is_paused(&env)
+    }
+
     /// Create a new course in the registry.
     ///
     /// This function creates a new course with the specified metadata and
@@ -53,8 +220,10 @@ impl CourseRegistry {
     /// # Panics
     ///
     /// * If title or description are empty
+    /// * If title or description exceed the configured `ContentLimits`
     /// * If creator address is invalid
     /// * If price exceeds maximum allowed value
+    /// * If permissionless creation is off and the creator lacks the `CreateCourses` permission
     ///
     /// # Examples
     ///
@@ -91,6 +260,7 @@ impl CourseRegistry {
         level: Option<CourseLevel>,
         duration_hours: Option<u32>,
     ) -> Course {
+        functions::pause::require_not_paused(&env);
         functions::create_course::create_course(
             env,
             creator,
@@ -105,6 +275,102 @@ impl CourseRegistry {
         )
     }
 
+    /// Create several courses in one transaction.
+    ///
+    /// Intended for institutional partners migrating from other platforms.
+    /// All entries are validated before any course is stored, so the batch
+    /// either succeeds completely or creates nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address that will own the courses
+    /// * `courses` - The courses to create (at most 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the created courses in the order they were given.
+    ///
+    /// # Panics
+    ///
+    /// * If the batch is empty or holds more than 20 courses
+    /// * If any entry fails the `create_course` validation
+    /// * If two entries share a title, or a title is already used
+    /// * If the creator exceeded the course creation rate limit
+    ///
+    /// # Events
+    ///
+    /// Emits a single `("course", "batch", creator)` event with all new IDs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let courses = contract.create_courses_batch(env.clone(), partner_address, params);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Rate limit**: A batch uses one slot of the creator's rate limit
+    /// * **Publication**: All created courses start unpublished
+    pub fn create_courses_batch(
+        env: Env,
+        creator: Address,
+        courses: Vec<NewCourseParams>,
+    ) -> Vec<Course> {
+        functions::pause::require_not_paused(&env);
+        functions::create_courses_batch::create_courses_batch(env, creator, courses)
+    }
+
+    /// Clone a course as a template for a new run.
+    ///
+    /// Creates a new unpublished course owned by the caller with the source
+    /// course's metadata and a new title, and copies its modules, goals and
+    /// prerequisites.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address that will own the copy
+    /// * `source_course_id` - The course to copy
+    /// * `new_title` - Title of the copy
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the source course doesn't exist
+    /// * If the caller is neither the source course creator nor an admin
+    /// * If the new title is empty or already used
+    /// * If the caller exceeded the course creation rate limit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let rerun = contract.clone_course(
+    ///     env.clone(),
+    ///     instructor_address,
+    ///     "12".try_into().unwrap(),
+    ///     "Rust Programming - Fall".try_into().unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Published source**: The copy always starts unpublished
+    /// * **Module positions**: Copied modules keep their positions
+    /// * **Goal authorship**: Copied goals are attributed to the caller
+    pub fn clone_course(
+        env: Env,
+        creator: Address,
+        source_course_id: String,
+        new_title: String,
+    ) -> Course {
+        functions::pause::require_not_paused(&env);
+        functions::clone_course::clone_course(env, creator, source_course_id, new_title)
+    }
+
     /// Create a new course category.
     ///
     /// This function creates a new category that can be used to classify courses.
@@ -122,8 +388,10 @@ impl CourseRegistry {
     ///
     /// # Panics
     ///
+    /// * If the caller is neither the contract owner nor a user management admin
     /// * If category name is empty
     /// * If category with same name already exists
+    /// * If the caller exceeded the category creation rate limit (`ActionRateLimitExceeded`)
     ///
     /// # Examples
     ///
@@ -137,163 +405,1300 @@ impl CourseRegistry {
     /// );
     /// ```
     ///
-    /// # Edge Cases
+    /// # Edge Cases
+    ///
+    /// * **Duplicate names**: Cannot create categories with existing names
+    /// * **Empty names**: Category name cannot be empty
+    /// * **Unique IDs**: Each category gets a unique auto-generated ID
+    pub fn create_course_category(
+        env: Env,
+        caller: Address,
+        name: String,
+        description: Option<String>,
+    ) -> u128 {
+        functions::pause::require_not_paused(&env);
+        functions::create_course_category::create_course_category(env, caller, name, description)
+    }
+
+    /// Merge one course category into another.
+    ///
+    /// Cleans up duplicate or spam categories: every course in the source
+    /// category is moved to the target category, then the source category
+    /// is deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or a user management admin
+    /// * `from_id` - ID of the category to remove
+    /// * `into_id` - ID of the category that receives its courses
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses moved to the target category.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the contract owner nor a user management admin
+    /// * If either category does not exist
+    /// * If `from_id` equals `into_id`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Fold "Coding" (id 2) into "Programming" (id 1)
+    /// let moved = contract.merge_categories(env.clone(), admin_address, 2, 1);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unused category**: Returns 0 and only deletes the source category
+    /// * **Archived courses**: Are reassigned like any other course
+    pub fn merge_categories(env: Env, admin: Address, from_id: u128, into_id: u128) -> u32 {
+        functions::pause::require_not_paused(&env);
+        functions::merge_categories::merge_categories(env, admin, from_id, into_id)
+    }
+
+    /// Edit a course category.
+    ///
+    /// Replaces the name and description of a category. A rename is applied
+    /// to every course in the category, since courses store the name.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or a user management admin
+    /// * `category_id` - ID of the category to edit
+    /// * `new_name` - The new category name
+    /// * `new_description` - The new optional description
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseCategory`.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the contract owner nor a user management admin
+    /// * If the category does not exist
+    /// * If the name is empty or longer than 100 characters
+    /// * If the description is longer than 500 characters
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let category = contract.edit_course_category(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     1,
+    ///     "Software Engineering".try_into().unwrap(),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Same name**: Only the description changes and no course is touched
+    pub fn edit_course_category(
+        env: Env,
+        admin: Address,
+        category_id: u128,
+        new_name: String,
+        new_description: Option<String>,
+    ) -> CourseCategory {
+        functions::pause::require_not_paused(&env);
+        functions::edit_course_category::edit_course_category(
+            env,
+            admin,
+            category_id,
+            new_name,
+            new_description,
+        )
+    }
+
+    /// Delete a course category.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or a user management admin
+    /// * `category_id` - ID of the category to delete
+    /// * `replacement_id` - Category that receives the courses of the deleted one
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses moved to the replacement category.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the contract owner nor a user management admin
+    /// * If the category or the replacement does not exist
+    /// * If courses still use the category and no replacement is given
+    /// * If the replacement is the category being deleted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Delete category 2 and move its courses to category 1
+    /// let moved = contract.delete_course_category(env.clone(), admin_address, 2, Some(1));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unused category**: Can be deleted without a replacement
+    pub fn delete_course_category(
+        env: Env,
+        admin: Address,
+        category_id: u128,
+        replacement_id: Option<u128>,
+    ) -> u32 {
+        functions::pause::require_not_paused(&env);
+        functions::delete_course_category::delete_course_category(
+            env,
+            admin,
+            category_id,
+            replacement_id,
+        )
+    }
+
+    /// Retrieve a course by its ID.
+    ///
+    /// This function fetches a course's complete information using its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Course` object containing all course metadata.
+    ///
+    /// # Panics
+    ///
+    /// * If course with given ID doesn't exist
+    /// * If course_id is invalid or empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get course by ID
+    /// let course = contract.get_course(env.clone(), "course_123".try_into().unwrap());
+    /// println!("Course title: {}", course.title);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Non-existent course**: Will panic if course ID doesn't exist
+    /// * **Archived courses**: Still retrievable but marked as archived
+    /// * **Public access**: Anyone can retrieve course information
+    pub fn get_course(env: Env, course_id: String) -> Course {
+        functions::get_course::get_course(&env, course_id)
+    }
+
+    /// Check whether a course exists without loading it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course is stored, archived or not.
+    pub fn course_exists(env: Env, course_id: String) -> bool {
+        functions::course_counts::course_exists(&env, course_id)
+    }
+
+    /// Get the number of course IDs issued so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the highest course ID, which counts deleted courses too.
+    pub fn get_total_courses(env: Env) -> u128 {
+        functions::course_counts::get_total_courses(&env)
+    }
+
+    /// Count the courses of an instructor without loading them.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - Address of the course creator
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses the instructor created and didn't
+    /// delete, archived ones included.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Older courses**: Courses created before the count existed are
+    ///   counted once `rebuild_instructor_index` has run
+    pub fn get_courses_count_by_instructor(env: Env, instructor: Address) -> u32 {
+        functions::course_counts::get_courses_count_by_instructor(&env, instructor)
+    }
+
+    /// Retrieve a course by its numeric ID.
+    ///
+    /// Course IDs are generated as `u128`; `Course::id` is its decimal
+    /// `String` form, which stays the storage key because the other contracts
+    /// and stored records all address courses by it. This accessor takes the
+    /// number directly, which suits range scans over `1..=n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_number` - The numeric course ID
+    ///
+    /// # Panics
+    ///
+    /// * If no course has this ID
+    /// * If the course is archived
+    pub fn get_course_by_number(env: Env, course_number: u128) -> Course {
+        functions::get_course::get_course_by_number(&env, course_number)
+    }
+
+    /// Retrieve a course with all of its related data in one call.
+    ///
+    /// Assembles the course, its modules (sorted by position), goals,
+    /// prerequisite course IDs and registered category into a single
+    /// read-only response so frontends can render a course page at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseDetail` containing the course and its related data.
+    ///
+    /// # Panics
+    ///
+    /// * If course with given ID doesn't exist
+    /// * If the course is archived
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let detail = contract.get_course_full(env.clone(), "course_123".try_into().unwrap());
+    /// println!("{} modules", detail.modules.len());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unregistered category**: `category` is empty if no category record matches the course's category name
+    /// * **No related data**: Empty lists are returned for modules, goals and prerequisites
+    pub fn get_course_full(env: Env, course_id: String) -> CourseDetail {
+        functions::get_course_full::get_course_full(&env, course_id)
+    }
+
+    /// Retrieve a course category by its ID.
+    ///
+    /// This function fetches a category's information using its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `category_id` - The unique identifier of the category to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(CourseCategory)` if found, `None` if the category doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get category by ID
+    /// if let Some(category) = contract.get_course_category(env.clone(), 1) {
+    ///     println!("Category: {}", category.name);
+    /// } else {
+    ///     println!("Category not found");
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Non-existent category**: Returns `None` instead of panicking
+    /// * **Invalid ID**: Returns `None` for invalid category IDs
+    /// * **Public access**: Anyone can retrieve category information
+    pub fn get_course_category(env: Env, category_id: u128) -> Option<CourseCategory> {
+        functions::get_course_category::get_course_category(&env, category_id)
+    }
+
+    /// Get all courses created by a specific instructor.
+    ///
+    /// This function retrieves all courses that were created by the specified instructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The address of the instructor to query courses for
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Course` objects created by the instructor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get all courses by an instructor
+    /// let instructor_courses = contract.get_courses_by_instructor(env.clone(), instructor_address);
+    /// for course in instructor_courses {
+    ///     println!("Course: {}", course.title);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No courses**: Returns empty vector if instructor has no courses
+    /// * **Archived courses**: Includes archived courses in results
+    /// * **Public access**: Anyone can query instructor courses
+    /// * **Invalid instructor**: Returns empty vector for non-existent instructors
+    pub fn get_courses_by_instructor(env: Env, instructor: Address) -> Vec<Course> {
+        functions::get_courses_by_instructor::get_courses_by_instructor(&env, instructor)
+    }
+
+    /// Get a page of the caller's own courses for an instructor dashboard.
+    ///
+    /// Unlike `get_courses_by_instructor`, drafts and archived courses are
+    /// included and can be listed separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The instructor listing their courses (must be authorized)
+    /// * `status_filter` - Only return courses in this status, or all when `None`
+    /// * `pagination` - Cursor from the previous page and page size (1 to 50)
+    ///
+    /// # Returns
+    ///
+    /// Returns the courses of the page in creation order with the cursor of the next page.
+    ///
+    /// # Panics
+    ///
+    /// * If the instructor doesn't authorize the call
+    /// * If the page size is 0 or above 50 (`InvalidLimitValue`)
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Short pages**: A page reads at most 200 course IDs, so it can hold
+    ///   fewer courses than requested while `has_more` is true
+    /// * **Archived and published**: Archived courses are reported as `Archived`
+    pub fn get_my_courses(
+        env: Env,
+        instructor: Address,
+        status_filter: Option<crate::schema::CourseStatus>,
+        pagination: crate::schema::CoursePagination,
+    ) -> crate::schema::PaginatedCourses {
+        functions::get_courses_by_instructor::get_my_courses(&env, instructor, status_filter, pagination)
+    }
+
+    /// Allow or disallow a contract to report course statistics (owner only).
+    ///
+    /// Reporters are the contracts that observe enrollments, completions and
+    /// purchases, such as course_access or a payment contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `reporter` - Address of the reporting contract
+    /// * `allowed` - `true` to add the reporter, `false` to remove it
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_stats_reporter(env.clone(), owner, course_access_address, true);
+    /// ```
+    pub fn set_stats_reporter(env: Env, caller: Address, reporter: Address, allowed: bool) {
+        functions::pause::require_not_paused(&env);
+        functions::course_stats::set_stats_reporter(&env, caller, reporter, allowed)
+    }
+
+    /// Get the contracts allowed to report course and platform statistics.
+    pub fn get_stats_reporters(env: Env) -> Vec<Address> {
+        functions::course_stats::get_stats_reporters(&env)
+    }
+
+    /// Record a new enrollment in a course.
+    ///
+    /// Called by a registered reporter (typically course_access on `grant_access`).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `reporter` - Address of the reporting contract
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseStats` of the course.
+    ///
+    /// # Panics
+    ///
+    /// * If the reporter is not registered
+    /// * If the course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.record_enrollment(env.clone(), reporter, "course_123".try_into().unwrap());
+    /// ```
+    pub fn record_enrollment(env: Env, reporter: Address, course_id: String) -> CourseStats {
+        functions::pause::require_not_paused(&env);
+        functions::course_stats::record_enrollment(&env, reporter, course_id)
+    }
+
+    /// Record that a student completed a course.
+    ///
+    /// Called by a registered reporter such as the progress contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `reporter` - Address of the reporting contract
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseStats` of the course.
+    ///
+    /// # Panics
+    ///
+    /// * If the reporter is not registered
+    /// * If the course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.record_completion(env.clone(), reporter, "course_123".try_into().unwrap());
+    /// ```
+    pub fn record_completion(env: Env, reporter: Address, course_id: String) -> CourseStats {
+        functions::pause::require_not_paused(&env);
+        functions::course_stats::record_completion(&env, reporter, course_id)
+    }
+
+    /// Record a purchase of a course.
+    ///
+    /// Called by a registered reporter such as the payment contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `reporter` - Address of the reporting contract
+    /// * `course_id` - The unique identifier of the course
+    /// * `amount` - Amount paid, added to the course revenue
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseStats` of the course.
+    ///
+    /// # Panics
+    ///
+    /// * If the reporter is not registered
+    /// * If the course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.record_purchase(env.clone(), reporter, "course_123".try_into().unwrap(), 1000);
+    /// ```
+    pub fn record_purchase(env: Env, reporter: Address, course_id: String, amount: u128) -> CourseStats {
+        functions::pause::require_not_paused(&env);
+        functions::course_stats::record_purchase(&env, reporter, course_id, amount)
+    }
+
+    /// Record a student rating of a course.
+    ///
+    /// Called by a registered reporter such as the feedback contract, which
+    /// rate-limits reviews per student before reporting them. Ratings are
+    /// aggregated per instructor and surfaced by `get_instructor_profile`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `reporter` - Address of the reporting contract
+    /// * `course_id` - The unique identifier of the course
+    /// * `rating` - Rating from 1 to 5
+    ///
+    /// # Panics
+    ///
+    /// * If the reporter is not registered
+    /// * If the rating is outside 1..=5
+    /// * If the course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.record_rating(env.clone(), reporter, "course_123".try_into().unwrap(), 5);
+    /// ```
+    pub fn record_rating(env: Env, reporter: Address, course_id: String, rating: u32) {
+        functions::pause::require_not_paused(&env);
+        functions::course_stats::record_rating(&env, reporter, course_id, rating)
+    }
+
+    /// Get the enrollment, completion and revenue counters of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the `CourseStats` of the course.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.get_course_stats(env.clone(), "course_123".try_into().unwrap());
+    /// println!("Enrollments: {}", stats.enrollments);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No activity**: Returns zeroed counters, also for unknown courses
+    /// * **Revocations**: Enrollments count every grant, revoked ones included
+    pub fn get_course_stats(env: Env, course_id: String) -> CourseStats {
+        functions::course_stats::get_course_stats(&env, course_id)
+    }
+
+    /// Get the counters summed over all courses of an instructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The course creator's address
+    ///
+    /// # Returns
+    ///
+    /// Returns the `InstructorStats` of the instructor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.get_instructor_stats(env.clone(), instructor_address);
+    /// println!("Revenue: {}", stats.revenue);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No activity**: Returns zeroed counters
+    pub fn get_instructor_stats(env: Env, instructor: Address) -> InstructorStats {
+        functions::course_stats::get_instructor_stats(&env, instructor)
+    }
+
+    /// Get the public profile of an instructor.
+    ///
+    /// Combines the instructor's courses with the reported statistics so that
+    /// course pages can show the instructor's track record.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The course creator's address
+    ///
+    /// # Returns
+    ///
+    /// Returns an `InstructorSummary` with course and published counts, total
+    /// students, the average rating in hundredths and the contract version in
+    /// place when the instructor created their first course.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let profile = contract.get_instructor_profile(env.clone(), instructor_address);
+    /// println!("Courses: {}", profile.course_count);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Archived courses**: Not counted
+    /// * **Unrated**: `avg_rating` is 0
+    /// * **Older instructors**: `joined_version` is `None` for instructors whose
+    ///   first course predates this field
+    pub fn get_instructor_profile(env: Env, instructor: Address) -> InstructorSummary {
+        functions::instructor_profile::get_instructor_profile(&env, instructor)
+    }
+
+    /// Get platform-wide counters.
+    ///
+    /// Counters are maintained incrementally: courses are counted by this
+    /// contract, enrollments, users and certificates by registered reporters.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address requesting the statistics
+    ///
+    /// # Returns
+    ///
+    /// Returns the current `PlatformStats`.
+    ///
+    /// # Panics
+    ///
+    /// * If the statistics are not public and the caller is neither an admin nor the owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.get_platform_stats(env.clone(), admin_address);
+    /// println!("Published courses: {}", stats.published_courses);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Default visibility**: Admin-only until `set_platform_stats_public` enables public access
+    /// * **Public**: The caller's auth is not required
+    pub fn get_platform_stats(env: Env, caller: Address) -> PlatformStats {
+        functions::platform_stats::get_platform_stats(&env, caller)
+    }
+
+    /// Make the platform statistics public or admin-only (owner only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `public` - `true` to let anyone read the statistics
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_platform_stats_public(env.clone(), owner, true);
+    /// ```
+    pub fn set_platform_stats_public(env: Env, caller: Address, public: bool) {
+        functions::pause::require_not_paused(&env);
+        functions::platform_stats::set_platform_stats_public(&env, caller, public)
+    }
+
+    /// Record a user registration (registered reporters only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `reporter` - Address of the reporting contract, typically user_management
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `PlatformStats`.
+    ///
+    /// # Panics
+    ///
+    /// * If the reporter is not registered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.record_user_registered(env.clone(), user_management_address);
+    /// ```
+    pub fn record_user_registered(env: Env, reporter: Address) -> PlatformStats {
+        functions::pause::require_not_paused(&env);
+        functions::platform_stats::record_user_registered(&env, reporter)
+    }
+
+    /// Record an issued certificate (registered reporters only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `reporter` - Address of the reporting contract, typically the certificate contract
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `PlatformStats`.
+    ///
+    /// # Panics
+    ///
+    /// * If the reporter is not registered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.record_certificate_issued(env.clone(), certificate_contract_address);
+    /// ```
+    pub fn record_certificate_issued(env: Env, reporter: Address) -> PlatformStats {
+        functions::pause::require_not_paused(&env);
+        functions::platform_stats::record_certificate_issued(&env, reporter)
+    }
+
+    /// Extend the storage TTL of a course and its related entries.
+    ///
+    /// Persistent entries are archived once their TTL runs out. Courses are
+    /// bumped automatically when read or written; this lets anyone keep a
+    /// rarely used course alive explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.extend_course_ttl(env.clone(), "course_123".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Related entries**: Modules, goals, prerequisites and statistics are extended too
+    /// * **Fresh entries**: Entries above the threshold are left untouched
+    pub fn extend_course_ttl(env: Env, course_id: String) {
+        functions::ttl::extend_course_ttl(&env, course_id)
+    }
+
+    /// Get the TTL thresholds used when bumping storage entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the current `TtlConfig`, or the defaults if none was set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let config = contract.get_ttl_config(env.clone());
+    /// ```
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        functions::ttl::get_ttl_config(&env)
+    }
+
+    /// Set the TTL thresholds used when bumping storage entries (owner only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `threshold` - Entries with fewer ledgers to live than this are extended
+    /// * `extend_to` - Number of ledgers entries are extended to
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner
+    /// * If `threshold` is zero, not below `extend_to`, or `extend_to` exceeds the network maximum
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Bump when under ~7 days left, up to ~30 days
+    /// contract.set_ttl_config(env.clone(), owner, 120_960, 518_400);
+    /// ```
+    pub fn set_ttl_config(env: Env, caller: Address, threshold: u32, extend_to: u32) {
+        functions::pause::require_not_paused(&env);
+        functions::ttl::set_ttl_config(&env, caller, threshold, extend_to)
+    }
+
+    /// Get the per-address limit of a spam-prone action.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `action` - The limited action
+    ///
+    /// # Returns
+    ///
+    /// Returns the window length in ledgers and the number of actions allowed
+    /// per window, or `None` if the action is not limited.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Defaults**: Category creation allows 10 per ~1 hour (720 ledgers)
+    pub fn get_action_rate_limit(env: Env, action: RateLimitedAction) -> Option<ActionRateLimit> {
+        functions::action_rate_limit::get_action_rate_limit(&env, action)
+    }
+
+    /// Set the per-address limit of a spam-prone action (owner or admin only).
+    ///
+    /// Counters live in temporary storage and reset every `window_ledgers`
+    /// ledgers, so limits cost no long-term rent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `action` - The action to limit
+    /// * `limit` - The new limit, or `None` to lift it
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the owner nor an admin
+    /// * If the window or the maximum is 0 (`InvalidLimitValue`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_action_rate_limit(
+    ///     env.clone(),
+    ///     admin,
+    ///     RateLimitedAction::CategoryCreation,
+    ///     Some(ActionRateLimit { window_ledgers: 17_280, max_actions: 20 }),
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Current window**: Counts already made in the window are kept
+    pub fn set_action_rate_limit(
+        env: Env,
+        admin: Address,
+        action: RateLimitedAction,
+        limit: Option<ActionRateLimit>,
+    ) {
+        functions::pause::require_not_paused(&env);
+        functions::action_rate_limit::set_action_rate_limit(&env, admin, action, limit)
+    }
+
+    /// Get the allowed course price range.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the configured `PriceLimits`, or `1..=u128::MAX` if none were set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let limits = contract.get_price_limits(env.clone());
+    /// ```
+    pub fn get_price_limits(env: Env) -> PriceLimits {
+        functions::price_limits::get_price_limits(&env)
+    }
+
+    /// Set the allowed course price range (owner or admin only).
+    ///
+    /// `create_course` and `edit_course` reject prices outside the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `min_course_price` - Lowest allowed price
+    /// * `max_course_price` - Highest allowed price
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    /// * If `min_course_price` is zero or above `max_course_price`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_price_limits(env.clone(), admin, 100, 1_000_000);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing courses**: Keep their price until it is edited
+    pub fn set_price_limits(env: Env, admin: Address, min_course_price: u128, max_course_price: u128) {
+        functions::pause::require_not_paused(&env);
+        functions::price_limits::set_price_limits(&env, admin, min_course_price, max_course_price)
+    }
+
+    /// Get the course title and description length limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the configured `ContentLimits`, or 200 and 2000 characters if none were set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let limits = contract.get_content_limits(env.clone());
+    /// ```
+    pub fn get_content_limits(env: Env) -> ContentLimits {
+        functions::content_limits::get_content_limits(&env)
+    }
+
+    /// Set the course title and description length limits (owner or admin only).
+    ///
+    /// `create_course`, `edit_course` and `add_course_translation` reject longer
+    /// texts with `TitleTooLong` or `DescriptionTooLong`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `max_title_length` - Longest allowed title, at most 500
+    /// * `max_description_length` - Longest allowed description, at most 2048
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    /// * If either limit is zero or above its ceiling
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_content_limits(env.clone(), admin, 120, 1000);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing courses**: Keep their texts until they are edited
+    pub fn set_content_limits(env: Env, admin: Address, max_title_length: u32, max_description_length: u32) {
+        functions::pause::require_not_paused(&env);
+        functions::content_limits::set_content_limits(&env, admin, max_title_length, max_description_length)
+    }
+
+    /// List the modules of a course.
+    ///
+    /// Returns every module of the course sorted by position, so clients
+    /// don't need raw storage access to render the course outline.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `CourseModule` objects in ascending position order.
+    ///
+    /// # Panics
+    ///
+    /// * If course_id is empty
+    /// * If the course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let modules = contract.list_modules(env.clone(), "course_123".try_into().unwrap());
+    /// for module in modules.iter() {
+    ///     println!("{}: {}", module.position, module.title);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No modules**: Returns an empty vector
+    /// * **Removed modules**: Not included in the result
+    pub fn list_modules(env: Env, course_id: String) -> Vec<CourseModule> {
+        functions::list_modules::course_registry_list_modules(&env, course_id)
+    }
+
+    /// Remove a module from a course.
+    ///
+    /// This function removes a specific module from its associated course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module to remove
+    ///
+    /// # Panics
+    ///
+    /// * If the module doesn't exist
+    /// * If the module_id is invalid or empty
+    /// * If module removal operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Remove a module from a course
+    /// contract.remove_module(env.clone(), "module_123".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Non-existent module**: Will panic if module ID doesn't exist
+    /// * **Invalid ID**: Will panic for invalid or empty module IDs
+    /// * **Course updates**: Automatically updates course module count
+    ///
+    /// Panics if the module removal fails or if the module doesn't exist.
+    pub fn remove_module(env: Env, module_id: String) {
+        functions::pause::require_not_paused(&env);
+        functions::remove_module::remove_module(&env, module_id).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Set the modules of the same course to complete before a module.
+    ///
+    /// The progress contract refuses to complete a lesson while any of its
+    /// prerequisite modules is incomplete.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator or an admin
+    /// * `module_id` - The module to gate
+    /// * `prerequisites` - IDs of modules of the same course; empty removes the gate
+    ///
+    /// # Returns
+    ///
+    /// The module's new prerequisites.
+    ///
+    /// # Panics
+    ///
+    /// * If the module or a prerequisite doesn't exist
+    /// * If the caller is not the course creator or an admin
+    /// * If a prerequisite belongs to another course, is the module itself or appears twice
+    /// * If the change would create a cycle between modules
+    /// * If there are more than `MAX_MODULE_PREREQUISITES` prerequisites
+    pub fn set_module_prerequisites(
+        env: Env,
+        creator: Address,
+        module_id: String,
+        prerequisites: Vec<String>,
+    ) -> Vec<String> {
+        functions::pause::require_not_paused(&env);
+        functions::module_prerequisites::set_module_prerequisites(&env, creator, module_id, prerequisites)
+    }
+
+    /// Get the modules to complete before a module.
+    pub fn get_module_prerequisites(env: Env, module_id: String) -> Vec<String> {
+        functions::module_prerequisites::get_module_prerequisites(&env, module_id)
+    }
+
+    /// Set when the modules of a course unlock for students.
+    ///
+    /// A module unlocks a number of seconds after each student's enrollment
+    /// or at a fixed timestamp. Course access reports the unlocked modules
+    /// and the progress contract refuses lessons of locked ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator or an admin
+    /// * `course_id` - The course to schedule
+    /// * `schedule` - Release time per module; modules left out are available
+    ///   right away and an empty schedule removes the drip
+    ///
+    /// # Returns
+    ///
+    /// The stored schedule.
+    ///
+    /// # Panics
+    ///
+    /// * If the course or a scheduled module doesn't exist
+    /// * If the caller is not the course creator or an admin
+    /// * If a module belongs to another course or appears twice
+    pub fn set_module_release_schedule(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        schedule: Vec<ModuleRelease>,
+    ) -> Vec<ModuleRelease> {
+        functions::pause::require_not_paused(&env);
+        functions::module_release::set_module_release_schedule(&env, creator, course_id, schedule)
+    }
+
+    /// Get when the modules of a course unlock; empty if none are scheduled.
+    pub fn get_module_release_schedule(env: Env, course_id: String) -> Vec<ModuleRelease> {
+        functions::module_release::get_module_release_schedule(&env, course_id)
+    }
+
+    /// Set what a student must achieve for a course to count as completed.
+    ///
+    /// The progress contract marks the course completed once the student
+    /// completed the required modules and meets the assessment requirements.
+    ///
+    /// # Arguments
     ///
-    /// * **Duplicate names**: Cannot create categories with existing names
-    /// * **Empty names**: Category name cannot be empty
-    /// * **Unique IDs**: Each category gets a unique auto-generated ID
-    pub fn create_course_category(
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator or an admin
+    /// * `course_id` - The course to configure
+    /// * `criteria` - Required modules (empty for all), minimum quiz score,
+    ///   whether assignments must be completed and live sessions to attend
+    ///
+    /// # Returns
+    ///
+    /// The stored criteria.
+    ///
+    /// # Panics
+    ///
+    /// * If the course or a required module doesn't exist
+    /// * If the caller is not the course creator or an admin
+    /// * If a required module belongs to another course or appears twice
+    /// * If the minimum quiz score is above 100
+    pub fn set_completion_criteria(
         env: Env,
-        caller: Address,
-        name: String,
-        description: Option<String>,
-    ) -> u128 {
-        functions::create_course_category::create_course_category(env, caller, name, description)
+        creator: Address,
+        course_id: String,
+        criteria: CompletionCriteria,
+    ) -> CompletionCriteria {
+        functions::pause::require_not_paused(&env);
+        functions::completion_criteria::set_completion_criteria(&env, creator, course_id, criteria)
     }
 
-    /// Retrieve a course by its ID.
+    /// Get the completion criteria of a course; by default every module is required.
+    pub fn get_completion_criteria(env: Env, course_id: String) -> CompletionCriteria {
+        functions::completion_criteria::get_completion_criteria(&env, course_id)
+    }
+
+    /// Edit a module's title, position and duration.
     ///
-    /// This function fetches a course's complete information using its unique identifier.
+    /// Only the course creator or an admin can edit modules.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `course_id` - The unique identifier of the course to retrieve
+    /// * `creator` - The course creator or an admin
+    /// * `module_id` - The unique identifier of the module to edit
+    /// * `new_title` - The module's new title
+    /// * `new_position` - Optional new position within the course
+    /// * `new_duration` - Optional estimated duration in minutes
     ///
     /// # Returns
     ///
-    /// Returns the `Course` object containing all course metadata.
+    /// Returns the updated `CourseModule`. The duration is read back with
+    /// `get_module_duration`.
     ///
     /// # Panics
     ///
-    /// * If course with given ID doesn't exist
-    /// * If course_id is invalid or empty
+    /// * If the module doesn't exist
+    /// * If the title is empty or longer than 500 characters
+    /// * If the new position is already taken by another module
+    /// * If the caller is neither the course creator nor an admin
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Get course by ID
-    /// let course = contract.get_course(env.clone(), "course_123".try_into().unwrap());
-    /// println!("Course title: {}", course.title);
+    /// let module = contract.edit_module(
+    ///     env.clone(),
+    ///     creator_address,
+    ///     "module_123".try_into().unwrap(),
+    ///     "Getting Started".try_into().unwrap(),
+    ///     None,
+    ///     Some(30),
+    /// );
     /// ```
     ///
     /// # Edge Cases
     ///
-    /// * **Non-existent course**: Will panic if course ID doesn't exist
-    /// * **Archived courses**: Still retrievable but marked as archived
-    /// * **Public access**: Anyone can retrieve course information
-    pub fn get_course(env: Env, course_id: String) -> Course {
-        functions::get_course::get_course(&env, course_id)
+    /// * **Unchanged position**: Passing the current position is a no-op for ordering
+    /// * **Zero duration**: Rejected as an invalid duration
+    pub fn edit_module(
+        env: Env,
+        creator: Address,
+        module_id: String,
+        new_title: String,
+        new_position: Option<u32>,
+        new_duration: Option<u32>,
+    ) -> CourseModule {
+        functions::pause::require_not_paused(&env);
+        functions::edit_module::edit_module(env, creator, module_id, new_title, new_position, new_duration)
     }
 
-    /// Retrieve a course category by its ID.
-    ///
-    /// This function fetches a category's information using its unique identifier.
+    /// Get the estimated duration of a module.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `category_id` - The unique identifier of the category to retrieve
+    /// * `module_id` - The unique identifier of the module
     ///
     /// # Returns
     ///
-    /// Returns `Some(CourseCategory)` if found, `None` if the category doesn't exist.
+    /// Returns the duration in minutes, or `None` if none was set.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Get category by ID
-    /// if let Some(category) = contract.get_course_category(env.clone(), 1) {
-    ///     println!("Category: {}", category.name);
-    /// } else {
-    ///     println!("Category not found");
-    /// }
+    /// let minutes = contract.get_module_duration(env.clone(), "module_123".try_into().unwrap());
     /// ```
-    ///
-    /// # Edge Cases
-    ///
-    /// * **Non-existent category**: Returns `None` instead of panicking
-    /// * **Invalid ID**: Returns `None` for invalid category IDs
-    /// * **Public access**: Anyone can retrieve category information
-    pub fn get_course_category(env: Env, category_id: u128) -> Option<CourseCategory> {
-        functions::get_course_category::get_course_category(&env, category_id)
+    pub fn get_module_duration(env: Env, module_id: String) -> Option<u32> {
+        functions::module_duration::get_module_duration(&env, module_id)
     }
 
-    /// Get all courses created by a specific instructor.
+    /// List the estimated durations of a course's modules.
     ///
-    /// This function retrieves all courses that were created by the specified instructor.
+    /// Companion to `list_modules`: modules are returned without their
+    /// duration, as other contracts decode them.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `instructor` - The address of the instructor to query courses for
+    /// * `course_id` - The unique identifier of the course
     ///
     /// # Returns
     ///
-    /// Returns a vector of `Course` objects created by the instructor.
+    /// Returns durations in minutes keyed by module ID. Modules without a
+    /// duration are left out.
+    ///
+    /// # Panics
+    ///
+    /// * If the course ID is empty or the course doesn't exist
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Get all courses by an instructor
-    /// let instructor_courses = contract.get_courses_by_instructor(env.clone(), instructor_address);
-    /// for course in instructor_courses {
-    ///     println!("Course: {}", course.title);
-    /// }
+    /// let durations = contract.list_module_durations(env.clone(), "course_123".try_into().unwrap());
     /// ```
-    ///
-    /// # Edge Cases
-    ///
-    /// * **No courses**: Returns empty vector if instructor has no courses
-    /// * **Archived courses**: Includes archived courses in results
-    /// * **Public access**: Anyone can query instructor courses
-    /// * **Invalid instructor**: Returns empty vector for non-existent instructors
-    pub fn get_courses_by_instructor(env: Env, instructor: Address) -> Vec<Course> {
-        functions::get_courses_by_instructor::get_courses_by_instructor(&env, instructor)
+    pub fn list_module_durations(env: Env, course_id: String) -> Map<String, u32> {
+        functions::module_duration::list_module_durations(&env, course_id)
     }
 
-    /// Remove a module from a course.
+    /// Reorder all modules of a course in one call.
     ///
-    /// This function removes a specific module from its associated course.
+    /// Rewrites module positions to match the given order (0-based), so
+    /// creators don't need remove/re-add cycles to change the ordering.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `module_id` - The unique identifier of the module to remove
-    ///
-    /// # Panics
-    ///
-    /// Remove a module from a course.
-    ///
-    /// This function removes a specific module from its associated course.
+    /// * `creator` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `ordered_module_ids` - Every module ID of the course, in the desired order
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `env` - The Soroban environment
-    /// * `module_id` - The unique identifier of the module to remove
+    /// Returns the course's modules with their updated positions.
     ///
     /// # Panics
     ///
-    /// * If the module doesn't exist
-    /// * If the module_id is invalid or empty
-    /// * If module removal operation fails
+    /// * If the course doesn't exist
+    /// * If the caller is neither the course creator nor an admin
+    /// * If the list is not a permutation of the course's modules
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Remove a module from a course
-    /// contract.remove_module(env.clone(), "module_123".try_into().unwrap());
+    /// let modules = contract.reorder_modules(
+    ///     env.clone(),
+    ///     creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     vec![&env, module_c_id, module_a_id, module_b_id],
+    /// );
     /// ```
     ///
     /// # Edge Cases
     ///
-    /// * **Non-existent module**: Will panic if module ID doesn't exist
-    /// * **Invalid ID**: Will panic for invalid or empty module IDs
-    /// * **Course updates**: Automatically updates course module count
-    ///
-    /// Panics if the module removal fails or if the module doesn't exist.
-    pub fn remove_module(env: Env, module_id: String) {
-        functions::remove_module::remove_module(&env, module_id).unwrap_or_else(|e| panic!("{}", e))
+    /// * **Missing or extra modules**: Rejected, the list must match the course exactly
+    /// * **Duplicates**: Rejected
+    /// * **Atomicity**: Either every position is rewritten or none is
+    pub fn reorder_modules(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        ordered_module_ids: Vec<String>,
+    ) -> Vec<CourseModule> {
+        functions::pause::require_not_paused(&env);
+        functions::reorder_modules::reorder_modules(env, creator, course_id, ordered_module_ids)
     }
 
     /// Add a new module to a course.
@@ -345,46 +1750,119 @@ impl CourseRegistry {
         position: u32,
         title: String,
     ) -> CourseModule {
+        functions::pause::require_not_paused(&env);
         functions::add_module::course_registry_add_module(env, caller, course_id, position, title)
     }
 
     /// Delete a course from the registry.
     ///
-    /// This function permanently removes a course from the registry.
-    /// Only the course creator can delete their own courses.
+    /// The course leaves every listing, search and counter at once and can be
+    /// restored by its creator with `restore_course` during the deletion
+    /// window. Once the window has passed, `purge_deleted` removes it with its
+    /// modules, goals, prerequisites and title. Only the course creator can
+    /// delete their own courses, and only while nobody has access to them.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
+    /// * `creator` - The course creator, or the owner or an admin when forcing
     /// * `course_id` - The unique identifier of the course to delete
+    /// * `force` - Delete even if users have access (owner or admin only)
     ///
     /// # Panics
     ///
     /// * If course doesn't exist
-    /// * If creator is not the actual course creator
+    /// * If creator is not the actual course creator and no admin forces the deletion
+    /// * If users have access to the course and the deletion isn't forced by an admin
     /// * If course_id is invalid or empty
     ///
     /// # Examples
     ///
     /// ```rust
     /// // Course creator deleting their course
-    /// contract.delete_course(env.clone(), course_creator_address, "course_123".try_into().unwrap());
+    /// contract.delete_course(env.clone(), course_creator_address, "course_123".try_into().unwrap(), false);
     /// ```
     ///
     /// # Edge Cases
     ///
     /// * **Permission denied**: Only course creator can delete their courses
     /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Permanent deletion**: Course and all associated data are permanently removed
-    /// * **Enrolled students**: Consider impact on enrolled students before deletion
+    /// * **Soft deletion**: The title stays reserved until the course is purged
+    /// * **Enrolled students**: Access grants are counted by the course access contract set
+    ///   with `set_course_access_contract`; a forced deletion keeps them there
     /// 
     /// Panics if the deletion fails or if the creator is not authorized.
-    pub fn delete_course(env: Env, creator: Address, course_id: String) {
-        functions::delete_course::delete_course(&env, creator, course_id)
+    pub fn delete_course(env: Env, creator: Address, course_id: String, force: bool) {
+        functions::pause::require_not_paused(&env);
+        functions::delete_course::delete_course(&env, creator, course_id, force)
             .unwrap_or_else(|e| panic!("{}", e))
     }
 
+    /// Restore a deleted course during its deletion window (creator only).
+    ///
+    /// The course returns to the listings, search and counters with the
+    /// modules, goals and prerequisites it had when deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The ID of the deleted course
+    ///
+    /// # Returns
+    ///
+    /// Returns the restored `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course isn't awaiting purge
+    /// * If the caller is not the course creator
+    /// * If the deletion window has passed
+    pub fn restore_course(env: Env, creator: Address, course_id: String) -> Course {
+        functions::pause::require_not_paused(&env);
+        functions::delete_course::restore_course(&env, creator, course_id)
+    }
+
+    /// Permanently remove deleted courses whose deletion window has passed
+    /// (owner or admin only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or an admin
+    /// * `limit` - Courses to purge at most, from 1 to 50
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses purged.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the owner nor an admin
+    /// * If `limit` is 0 or above 50
+    pub fn purge_deleted(env: Env, admin: Address, limit: u32) -> u32 {
+        functions::pause::require_not_paused(&env);
+        functions::delete_course::purge_deleted(&env, admin, limit)
+    }
+
+    /// Set how many seconds a deleted course can be restored for (owner or
+    /// admin only). Defaults to 7 days; courses already deleted keep their window.
+    pub fn set_deletion_window(env: Env, admin: Address, seconds: u64) {
+        functions::pause::require_not_paused(&env);
+        functions::delete_course::set_deletion_window(&env, admin, seconds)
+    }
+
+    /// Get how many seconds a deleted course can be restored for.
+    pub fn get_deletion_window(env: Env) -> u64 {
+        functions::delete_course::get_deletion_window(&env)
+    }
+
+    /// Get a deleted course awaiting purge, with who deleted it and when it
+    /// can be purged, or `None`.
+    pub fn get_deleted_course(env: Env, course_id: String) -> Option<crate::schema::DeletedCourse> {
+        functions::delete_course::get_deleted_course(&env, course_id)
+    }
+
     /// Simple hello world function for testing.
     ///
     /// This is a basic function that returns a greeting message,
@@ -461,92 +1939,246 @@ impl CourseRegistry {
         env: Env,
         creator: Address,
         course_id: String,
-        goal_id: String,
-        new_content: String,
-    ) -> CourseGoal {
-        functions::edit_goal::edit_goal(env, creator, course_id, goal_id, new_content)
+        goal_id: String,
+        new_content: String,
+    ) -> CourseGoal {
+        functions::pause::require_not_paused(&env);
+        functions::edit_goal::edit_goal(env, creator, course_id, goal_id, new_content)
+    }
+
+    /// Add a new goal to a course.
+    ///
+    /// This function creates and adds a new learning goal to the specified course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `content` - The content/description of the goal
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `CourseGoal` object.
+    ///
+    /// # Panics
+    ///
+    /// * If course doesn't exist
+    /// * If creator is not the course creator
+    /// * If content is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Add a learning goal to a course
+    /// let goal = contract.add_goal(
+    ///     env.clone(),
+    ///     course_creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     "Students will learn basic programming concepts".try_into().unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty content**: Goal content cannot be empty
+    /// * **Creator only**: Only course creator can add goals
+    /// * **Auto-generated ID**: Goal gets unique auto-generated ID
+    /// * **Content validation**: Goal content must meet validation requirements
+    pub fn add_goal(env: Env, creator: Address, course_id: String, content: String) -> CourseGoal {
+        functions::pause::require_not_paused(&env);
+        functions::add_goal::add_goal(env, creator, course_id, content)
+    }
+
+    /// Remove a goal from a course.
+    ///
+    /// This function removes a specific learning goal from the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the user requesting the removal
+    /// * `course_id` - The unique identifier of the course
+    /// * `goal_id` - The unique identifier of the goal to remove
+    ///
+    /// # Panics
+    ///
+    /// * If course doesn't exist
+    /// * If goal doesn't exist
+    /// * If caller is not the course creator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Remove a goal from a course
+    /// contract.remove_goal(
+    ///     env.clone(),
+    ///     course_creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     "goal_456".try_into().unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Creator only**: Only course creator can remove goals
+    /// * **Non-existent goal**: Will panic if goal ID doesn't exist
+    /// * **Permanent removal**: Goal is permanently deleted from course
+    /// * **Goal count**: Automatically updates course goal count
+    pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String) {
+        functions::pause::require_not_paused(&env);
+        functions::remove_goal::remove_goal(env, caller, course_id, goal_id)
+    }
+
+    /// Reorder the goals of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `ordered_goal_ids` - Every goal ID of the course, in the desired order
+    ///
+    /// # Returns
+    ///
+    /// Returns the course's goals in their new order.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    /// * If the list is not a permutation of the course's goals
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let goals = contract.reorder_goals(
+    ///     env.clone(),
+    ///     creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     vec![&env, goal_b_id, goal_a_id],
+    /// );
+    /// ```
+    pub fn reorder_goals(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        ordered_goal_ids: Vec<String>,
+    ) -> Vec<CourseGoal> {
+        functions::pause::require_not_paused(&env);
+        functions::reorder_goals::reorder_goals(env, creator, course_id, ordered_goal_ids)
     }
 
-    /// Add a new goal to a course.
+    /// Replace all goals of a course at once.
     ///
-    /// This function creates and adds a new learning goal to the specified course.
+    /// Removes the current goals and creates one goal per entry of `contents`,
+    /// in that order. Intended for course setup wizards.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
+    /// * `creator` - The course creator
     /// * `course_id` - The unique identifier of the course
-    /// * `content` - The content/description of the goal
+    /// * `contents` - The content of each new goal
     ///
     /// # Returns
     ///
-    /// Returns the created `CourseGoal` object.
+    /// Returns the newly created goals.
     ///
     /// # Panics
     ///
-    /// * If course doesn't exist
-    /// * If creator is not the course creator
-    /// * If content is empty
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// // Add a learning goal to a course
-    /// let goal = contract.add_goal(
-    ///     env.clone(),
-    ///     course_creator_address,
-    ///     "course_123".try_into().unwrap(),
-    ///     "Students will learn basic programming concepts".try_into().unwrap()
-    /// );
-    /// ```
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    /// * If a content is empty or too long, or more than 50 goals are given
     ///
     /// # Edge Cases
     ///
-    /// * **Empty content**: Goal content cannot be empty
-    /// * **Creator only**: Only course creator can add goals
-    /// * **Auto-generated ID**: Goal gets unique auto-generated ID
-    /// * **Content validation**: Goal content must meet validation requirements
-    pub fn add_goal(env: Env, creator: Address, course_id: String, content: String) -> CourseGoal {
-        functions::add_goal::add_goal(env, creator, course_id, content)
+    /// * **Empty list**: Removes every goal of the course
+    /// * **Atomicity**: All contents are validated before any goal is replaced
+    pub fn set_goals(env: Env, creator: Address, course_id: String, contents: Vec<String>) -> Vec<CourseGoal> {
+        functions::pause::require_not_paused(&env);
+        functions::reorder_goals::set_goals(env, creator, course_id, contents)
     }
 
-    /// Remove a goal from a course.
+    /// Open a Q&A thread about a module.
     ///
-    /// This function removes a specific learning goal from the course.
+    /// Only the hash of the question is stored; the text lives off-chain.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `caller` - The address of the user requesting the removal
+    /// * `student` - A user with access to the course, its creator or an admin
     /// * `course_id` - The unique identifier of the course
-    /// * `goal_id` - The unique identifier of the goal to remove
+    /// * `module_id` - The module the thread is about
+    /// * `title` - Title of the thread (1 to 200 bytes)
+    /// * `content_hash` - Hash of the off-chain question
+    ///
+    /// # Returns
+    ///
+    /// Returns the created thread.
     ///
     /// # Panics
     ///
-    /// * If course doesn't exist
-    /// * If goal doesn't exist
-    /// * If caller is not the course creator
+    /// * If the module doesn't exist or belongs to another course
+    /// * If the student has no access to the course (checked with the course access contract)
+    /// * If the title is empty or too long
+    pub fn create_thread(
+        env: Env,
+        student: Address,
+        course_id: String,
+        module_id: String,
+        title: String,
+        content_hash: BytesN<32>,
+    ) -> DiscussionThread {
+        functions::pause::require_not_paused(&env);
+        functions::discussions::create_thread(&env, student, course_id, module_id, title, content_hash)
+    }
+
+    /// Reply to a Q&A thread.
     ///
-    /// # Examples
+    /// # Arguments
     ///
-    /// ```rust
-    /// // Remove a goal from a course
-    /// contract.remove_goal(
-    ///     env.clone(),
-    ///     course_creator_address,
-    ///     "course_123".try_into().unwrap(),
-    ///     "goal_456".try_into().unwrap()
-    /// );
-    /// ```
+    /// * `env` - The Soroban environment
+    /// * `user` - A user with access to the course, its creator or an admin
+    /// * `thread_id` - The thread being answered
+    /// * `content_hash` - Hash of the off-chain reply
     ///
-    /// # Edge Cases
+    /// # Panics
     ///
-    /// * **Creator only**: Only course creator can remove goals
-    /// * **Non-existent goal**: Will panic if goal ID doesn't exist
-    /// * **Permanent removal**: Goal is permanently deleted from course
-    /// * **Goal count**: Automatically updates course goal count
-    pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String) {
-        functions::remove_goal::remove_goal(env, caller, course_id, goal_id)
+    /// * If the thread doesn't exist
+    /// * If the user has no access to the thread's course
+    pub fn reply_to_thread(env: Env, user: Address, thread_id: u32, content_hash: BytesN<32>) -> ThreadReply {
+        functions::pause::require_not_paused(&env);
+        functions::discussions::reply_to_thread(&env, user, thread_id, content_hash)
+    }
+
+    /// Get a Q&A thread, `None` if it doesn't exist.
+    pub fn get_thread(env: Env, thread_id: u32) -> Option<DiscussionThread> {
+        functions::discussions::get_thread(&env, thread_id)
+    }
+
+    /// List the Q&A threads of a module, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The module whose threads are listed
+    /// * `offset` - Number of threads to skip
+    /// * `limit` - Maximum number of threads to return (capped at 50)
+    pub fn list_module_threads(env: Env, module_id: String, offset: u32, limit: u32) -> Vec<DiscussionThread> {
+        functions::discussions::list_module_threads(&env, module_id, offset, limit)
+    }
+
+    /// List the replies of a Q&A thread, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `thread_id` - The thread whose replies are listed
+    /// * `offset` - Number of replies to skip
+    /// * `limit` - Maximum number of replies to return (capped at 50)
+    pub fn list_thread_replies(env: Env, thread_id: u32, offset: u32, limit: u32) -> Vec<ThreadReply> {
+        functions::discussions::list_thread_replies(&env, thread_id, offset, limit)
     }
 
     /// Add prerequisites to a course.
@@ -595,6 +2227,7 @@ impl CourseRegistry {
         course_id: String,
         prerequisite_course_ids: Vec<String>,
     ) {
+        functions::pause::require_not_paused(&env);
         functions::create_prerequisite::add_prerequisite(
             env,
             creator,
@@ -603,6 +2236,41 @@ impl CourseRegistry {
         )
     }
 
+    /// Get the prerequisites of a course.
+    ///
+    /// Returns the IDs of the courses that must be completed before this one.
+    /// With `transitive` set, the full requirement chain is returned by walking
+    /// the prerequisite graph, so enrollment UIs can show everything a student needs.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `transitive` - Whether to include prerequisites of prerequisites
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of course IDs, direct prerequisites first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Direct prerequisites only
+    /// let direct = contract.get_prerequisites(env.clone(), "course_3".try_into().unwrap(), false);
+    ///
+    /// // Full requirement chain
+    /// let all = contract.get_prerequisites(env.clone(), "course_3".try_into().unwrap(), true);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No prerequisites**: Returns an empty vector
+    /// * **Shared prerequisites**: Each course is listed once
+    /// * **Cycles and deep chains**: Cycles are skipped and the walk stops after 10 levels
+    pub fn get_prerequisites(env: Env, course_id: String, transitive: bool) -> Vec<String> {
+        functions::get_prerequisites_by_course::get_prerequisites(&env, course_id, transitive)
+    }
+
     /// Remove a prerequisite from a course.
     ///
     /// This function removes a specific prerequisite course requirement
@@ -645,6 +2313,7 @@ impl CourseRegistry {
         course_id: String,
         prerequisite_course_id: String,
     ) {
+        functions::pause::require_not_paused(&env);
         functions::remove_prerequisite::remove_prerequisite(
             env,
             creator,
@@ -699,6 +2368,7 @@ impl CourseRegistry {
         course_id: String,
         new_prerequisites: Vec<String>,
     ) {
+        functions::pause::require_not_paused(&env);
         functions::edit_prerequisite::edit_prerequisite(env, creator, course_id, new_prerequisites)
     }
 
@@ -755,9 +2425,211 @@ impl CourseRegistry {
         course_id: String,
         params: EditCourseParams,
     ) -> Course {
+        functions::pause::require_not_paused(&env);
         functions::edit_course::edit_course(env, creator, course_id, params)
     }
 
+    /// Save staged edits of a course.
+    ///
+    /// Stores a draft that `get_course` does not expose, so an instructor can
+    /// prepare several edits and apply them together with `publish_draft`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The course being edited
+    /// * `params` - Edits to stage, in the same format as `edit_course`
+    ///
+    /// # Returns
+    ///
+    /// Returns the draft after merging `params` into it.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let draft = contract.save_course_draft(env.clone(), instructor_address, course_id, params);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Autosave**: Fields set in `params` overwrite staged values, fields left `None` keep them
+    /// * **No validation**: Values are validated when the draft is published
+    pub fn save_course_draft(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        params: EditCourseParams,
+    ) -> EditCourseParams {
+        functions::pause::require_not_paused(&env);
+        functions::course_draft::save_course_draft(&env, creator, course_id, params)
+    }
+
+    /// Apply the staged edits of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The course whose draft is published
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated live `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course has no draft
+    /// * If the caller is not the course creator
+    /// * If any staged value fails the `edit_course` validation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let course = contract.publish_draft(env.clone(), instructor_address, course_id);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Atomic**: Either every staged edit is applied or none is
+    pub fn publish_draft(env: Env, creator: Address, course_id: String) -> Course {
+        functions::pause::require_not_paused(&env);
+        functions::course_draft::publish_draft(&env, creator, course_id)
+    }
+
+    /// Discard the staged edits of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator
+    /// * `course_id` - The course whose draft is dropped
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist or has no draft
+    /// * If the caller is not the course creator
+    pub fn discard_draft(env: Env, creator: Address, course_id: String) {
+        functions::pause::require_not_paused(&env);
+        functions::course_draft::discard_draft(&env, creator, course_id)
+    }
+
+    /// Get the staged edits of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The course to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns the draft, or `None` if nothing is staged.
+    pub fn get_course_draft(env: Env, course_id: String) -> Option<EditCourseParams> {
+        functions::course_draft::get_course_draft(&env, course_id)
+    }
+
+    /// Add or replace a translation of a course.
+    ///
+    /// Stores the title and description of a course in another language. The
+    /// default course record is left untouched and keeps its own language.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The course to translate
+    /// * `language` - The language of the translation (e.g. "es")
+    /// * `title` - The translated title
+    /// * `description` - The translated description
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored translation.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    /// * If the language is empty or longer than 50 characters
+    /// * If the title is empty or longer than the configured limit
+    /// * If the description is longer than the configured limit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_course_translation(
+    ///     env.clone(),
+    ///     instructor_address,
+    ///     course_id,
+    ///     "es".try_into().unwrap(),
+    ///     "Fundamentos de Rust".try_into().unwrap(),
+    ///     "Aprende Rust desde cero".try_into().unwrap(),
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing translation**: A second call for the same language replaces it
+    /// * **Deletion**: Translations are removed together with the course
+    pub fn add_course_translation(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        language: String,
+        title: String,
+        description: String,
+    ) -> CourseTranslation {
+        functions::pause::require_not_paused(&env);
+        functions::course_translation::add_course_translation(
+            &env,
+            creator,
+            course_id,
+            language,
+            title,
+            description,
+        )
+    }
+
+    /// Get a course in a given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The course to look up
+    /// * `language` - The preferred language
+    ///
+    /// # Returns
+    ///
+    /// Returns the course with its title, description and language taken from
+    /// the translation, or the default course record if there is no
+    /// translation for `language`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist or is archived
+    pub fn get_course_localized(env: Env, course_id: String, language: String) -> Course {
+        functions::course_translation::get_course_localized(&env, course_id, language)
+    }
+
+    /// List the languages a course has been translated into.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The course to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns the translation languages, excluding the default language.
+    pub fn get_course_languages(env: Env, course_id: String) -> Vec<String> {
+        functions::course_translation::get_course_languages(&env, course_id)
+    }
+
     /// Archive a course.
     ///
     /// This function marks a course as archived, making it unavailable for new enrollments
@@ -768,6 +2640,7 @@ impl CourseRegistry {
     /// * `env` - The Soroban environment
     /// * `creator` - The address of the course creator
     /// * `course_id` - The unique identifier of the course to archive
+    /// * `reason` - Optional reason kept in the status history (1 to 200 bytes)
     ///
     /// # Returns
     ///
@@ -786,18 +2659,72 @@ impl CourseRegistry {
     /// let archived_course = contract.archive_course(
     ///     &env,
     ///     course_creator_address,
-    ///     "course_123".try_into().unwrap()
+    ///     "course_123".try_into().unwrap(),
+    ///     Some("Replaced by the 2025 edition".try_into().unwrap())
     /// );
     /// ```
     ///
     /// # Edge Cases
     ///
-    /// * **Already archived**: Will panic if course is already archived
-    /// * **Creator only**: Only course creator can archive course
-    /// * **Student access**: Current students retain access
-    /// * **Reversible**: Course can be unarchived if needed
-    pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course {
-        functions::archive_course::archive_course(env, creator, course_id)
+    /// * **Already archived**: Will panic if course is already archived
+    /// * **Creator only**: Only course creator can archive course
+    /// * **Student access**: Current students retain access
+    /// * **Reversible**: Course can be unarchived if needed
+    pub fn archive_course(env: &Env, creator: Address, course_id: String, reason: Option<String>) -> Course {
+        functions::pause::require_not_paused(env);
+        functions::archive_course::archive_course(env, creator, course_id, reason)
+    }
+
+    /// Publish or unpublish a course, recording why.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `published` - Whether the course should be published
+    /// * `reason` - Optional reason kept in the status history (1 to 200 bytes)
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    /// * If the reason is empty or too long (`InvalidStatusReason`)
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No change**: Returns the course without recording anything
+    pub fn set_course_published(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        published: bool,
+        reason: Option<String>,
+    ) -> Course {
+        functions::pause::require_not_paused(&env);
+        functions::status_history::set_course_published(&env, creator, course_id, published, reason)
+    }
+
+    /// Get the lifecycle history of a course.
+    ///
+    /// Publishing, unpublishing and archiving are recorded with the old and
+    /// new status, the actor, the reason and the time. The same changes are
+    /// published as `("course", "status", course_id)` events.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the latest 50 status changes, oldest first.
+    pub fn get_status_history(env: Env, course_id: String) -> Vec<crate::schema::StatusChange> {
+        functions::status_history::get_status_history(&env, course_id)
     }
 
     /// Check if a user is the creator of a specific course.
@@ -843,6 +2770,74 @@ impl CourseRegistry {
         functions::is_course_creator::is_course_creator(env, course_id, user)
     }
 
+    /// Make a course free, or paid again.
+    ///
+    /// A free course costs nothing and students can join it themselves
+    /// through `self_enroll` in the course access contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `is_free` - Whether the course is free
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated course.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let course = contract.set_course_free(env.clone(), instructor_address, course_id, true);
+    /// assert_eq!(course.price, 0);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Paid price**: Kept aside while free and restored afterwards
+    /// * **Setting a price**: `edit_course` with a new price makes the course paid
+    /// * **No change**: Calling with the current state does nothing
+    pub fn set_course_free(env: Env, creator: Address, course_id: String, is_free: bool) -> Course {
+        functions::pause::require_not_paused(&env);
+        functions::free_course::set_course_free(&env, creator, course_id, is_free)
+    }
+
+    /// Check whether a course is free.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course was made free with `set_course_free`.
+    pub fn is_course_free(env: Env, course_id: String) -> bool {
+        functions::free_course::is_course_free(&env, course_id)
+    }
+
+    /// Check whether students can enroll themselves in a course.
+    ///
+    /// Used by the course access contract before a self-enrollment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course exists and is free, published and not archived.
+    pub fn is_self_enrollable(env: Env, course_id: String) -> bool {
+        functions::free_course::is_self_enrollable(&env, course_id)
+    }
+
     /// List all available course categories.
     ///
     /// This function retrieves all course categories that have been created
@@ -875,6 +2870,164 @@ impl CourseRegistry {
         functions::list_categories::list_categories(&env)
     }
 
+    /// Recount the courses of every category and refresh the cached counts.
+    ///
+    /// `list_categories` serves its result from a cache kept in temporary
+    /// storage. The cache is dropped when course categories change and expires
+    /// after about a day; this rebuilds it right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the recomputed categories with their course counts.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Public access**: Anyone can trigger a rebuild
+    pub fn rebuild_category_counts(env: Env) -> Vec<crate::schema::Category> {
+        functions::pause::require_not_paused(&env);
+        functions::list_categories::rebuild_category_counts(&env)
+    }
+
+    /// Recompute instructor stats, course counts and sort indexes, in chunks (admin-only).
+    ///
+    /// Repairs `get_instructor_stats`, `get_courses_count_by_instructor` and
+    /// the sorted `list_courses_with_filters` after they drifted from the
+    /// course records. Start with cursor 0 and call again with the
+    /// returned cursor until it is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - Owner or admin running the rebuild
+    /// * `cursor` - Number of course IDs already processed (0 to start)
+    /// * `limit` - Number of course IDs to process, up to 50
+    ///
+    /// # Returns
+    ///
+    /// Returns the cursor for the next chunk, or `None` once every course was processed.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If `limit` is 0 or above 50
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Restart**: Calling with cursor 0 starts a new rebuild and resets
+    ///   each instructor again when their first course is reached
+    pub fn rebuild_instructor_index(env: Env, admin: Address, cursor: u32, limit: u32) -> Option<u32> {
+        functions::pause::require_not_paused(&env);
+        functions::index_repair::rebuild_instructor_index(&env, admin, cursor, limit)
+    }
+
+    /// Replace the featured courses (admin-only).
+    ///
+    /// The featured courses are the collection named `featured`, read back
+    /// with `get_collection`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `course_ids` - The courses to feature, in display order
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    /// * If any of the courses doesn't exist
+    /// * If more than 50 courses are given
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_featured_courses(env.clone(), admin_address, vec![&env, course_id]);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Duplicates**: Repeated IDs are kept once, at their first position
+    /// * **Empty list**: Clears the featured courses
+    pub fn set_featured_courses(env: Env, admin: Address, course_ids: Vec<String>) {
+        functions::pause::require_not_paused(&env);
+        functions::course_collections::set_featured_courses(&env, admin, course_ids)
+    }
+
+    /// Append a course to a curated collection (admin-only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `collection_name` - Name of the collection, created if it doesn't exist
+    /// * `course_id` - The course to add
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    /// * If the collection name is empty or longer than 50 characters
+    /// * If the course doesn't exist
+    /// * If the collection already holds 50 courses
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_to_collection(env.clone(), admin_address, "backend".try_into().unwrap(), course_id);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already present**: Adding a course twice does nothing
+    pub fn add_to_collection(env: Env, admin: Address, collection_name: String, course_id: String) {
+        functions::pause::require_not_paused(&env);
+        functions::course_collections::add_to_collection(&env, admin, collection_name, course_id)
+    }
+
+    /// Remove a course from a curated collection (admin-only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `collection_name` - Name of the collection
+    /// * `course_id` - The course to remove
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner or an admin
+    pub fn remove_from_collection(env: Env, admin: Address, collection_name: String, course_id: String) {
+        functions::pause::require_not_paused(&env);
+        functions::course_collections::remove_from_collection(&env, admin, collection_name, course_id)
+    }
+
+    /// Get the courses of a curated collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `name` - Name of the collection (`featured` for the featured courses)
+    ///
+    /// # Returns
+    ///
+    /// Returns the courses in display order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let featured = contract.get_collection(env.clone(), "featured".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unknown collection**: Returns an empty vector
+    /// * **Archived, deleted or unpublished courses**: Skipped
+    pub fn get_collection(env: Env, name: String) -> Vec<Course> {
+        functions::course_collections::get_collection(&env, name)
+    }
+
     /// List courses with filtering and pagination.
     ///
     /// This function retrieves courses based on the provided filters
@@ -886,6 +3039,7 @@ impl CourseRegistry {
     /// * `filters` - Filtering criteria for courses
     /// * `limit` - Optional maximum number of courses to return
     /// * `offset` - Optional number of courses to skip for pagination
+    /// * `sort_by` - Optional order of the courses; by course ID when `None`
     ///
     /// # Returns
     ///
@@ -899,17 +3053,19 @@ impl CourseRegistry {
     ///     env.clone(),
     ///     CourseFilters::default(),
     ///     Some(10),
-    ///     Some(0)
+    ///     Some(0),
+    ///     None
     /// );
     /// 
-    /// // Filter by category
+    /// // Filter by category, cheapest first
     /// let mut filters = CourseFilters::default();
     /// filters.category = Some("Programming".try_into().unwrap());
     /// let programming_courses = contract.list_courses_with_filters(
     ///     env.clone(),
     ///     filters,
     ///     Some(20),
-    ///     None
+    ///     None,
+    ///     Some(SortOption::PriceAsc)
     /// );
     /// ```
     ///
@@ -919,17 +3075,69 @@ impl CourseRegistry {
     /// * **Large limits**: Limit should be reasonable to avoid gas issues
     /// * **Public access**: Anyone can list courses
     /// * **Archived courses**: May or may not be included based on filter settings
+    /// * **Ties**: Courses with the same price, rating or enrollments keep
+    ///   the order they reached that value in
     pub fn list_courses_with_filters(
         env: Env,
         filters: CourseFilters,
         limit: Option<u32>,
         offset: Option<u32>,
+        sort_by: Option<crate::schema::SortOption>,
     ) -> Vec<Course> {
         functions::list_courses_with_filters::list_courses_with_filters(
-            &env, filters, limit, offset,
+            &env, filters, limit, offset, sort_by,
         )
     }
 
+    /// Search courses by a keyword from their title.
+    ///
+    /// Titles are split into lowercase words and indexed when courses are
+    /// created or renamed, so the lookup reads only the courses that
+    /// actually contain the keyword instead of scanning every course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `keyword` - A single word to look for (case-insensitive)
+    /// * `limit` - Optional maximum number of courses to return (default 10, capped at 20)
+    /// * `offset` - Optional number of matching courses to skip for pagination
+    ///
+    /// # Returns
+    ///
+    /// Returns the published, non-archived courses whose title contains the keyword,
+    /// in the order they were indexed.
+    ///
+    /// # Panics
+    ///
+    /// * If `limit` is greater than 100
+    /// * If `offset` is greater than 10000
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Find courses with "rust" in the title
+    /// let courses = contract.search_courses_by_keyword(
+    ///     env.clone(),
+    ///     "rust".try_into().unwrap(),
+    ///     Some(10),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Whole words only**: "rus" does not match a title containing "Rust"
+    /// * **Separators**: Punctuation and spaces split words, so "Part-1" indexes "part" and "1"
+    /// * **Empty keyword**: Returns an empty vector
+    pub fn search_courses_by_keyword(
+        env: Env,
+        keyword: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Course> {
+        functions::search_courses::search_courses_by_keyword(&env, keyword, limit, offset)
+    }
+
     /// Export all course data for backup purposes (admin only)
     ///
     /// This function exports all course data including courses, categories,
@@ -966,9 +3174,56 @@ impl CourseRegistry {
     /// * If backup data is invalid
     /// * If import operation fails
     pub fn import_course_data(env: Env, caller: Address, backup_data: crate::schema::CourseBackupData) -> u32 {
+        functions::pause::require_not_paused(&env);
         functions::backup_recovery::import_course_data(env, caller, backup_data)
     }
 
+    /// Export one page of course data for backup purposes (owner or admin only)
+    ///
+    /// Chunked alternative to `export_course_data` for registries too large
+    /// for one call. Start with offset 0 and an all-zero checksum, then pass
+    /// each page's `next_cursor` until `has_more` is false.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address requesting the backup
+    /// * `cursor` - Cursor of the page to export
+    /// * `limit` - Number of course IDs covered by the page (1 to 20)
+    ///
+    /// # Returns
+    /// * `CourseBackupPage` - Courses with their modules, goals and
+    ///   prerequisites; the first page also holds the categories
+    ///
+    /// # Panics
+    /// * If caller is neither the owner nor an admin (`Unauthorized`)
+    /// * If the limit is out of range or the cursor is past the end (`InvalidBackupPage`)
+    pub fn export_course_data_page(
+        env: Env,
+        caller: Address,
+        cursor: crate::schema::BackupCursor,
+        limit: u32,
+    ) -> crate::schema::CourseBackupPage {
+        functions::backup_recovery::export_course_data_page(env, caller, cursor, limit)
+    }
+
+    /// Import one page of a paged course backup (owner or admin only)
+    ///
+    /// Pages must be imported in export order; the checksum carried by the
+    /// cursors is verified so altered, missing or reordered pages are rejected.
+    /// Restoring into another deployment clones the environment.
+    ///
+    /// # Returns
+    /// * `u32` - Number of courses imported from the page
+    ///
+    /// # Panics
+    /// * If caller is neither the owner nor an admin (`Unauthorized`)
+    /// * If the page isn't the next one expected (`InvalidBackupPage`)
+    /// * If the page's checksum doesn't match its contents (`BackupChecksumMismatch`)
+    pub fn import_course_data_page(env: Env, caller: Address, page: crate::schema::CourseBackupPage) -> u32 {
+        functions::pause::require_not_paused(&env);
+        functions::backup_recovery::import_course_data_page(env, caller, page)
+    }
+
     /// Get the current contract version
     ///
     /// Returns the semantic version of the current contract deployment.
@@ -985,15 +3240,15 @@ impl CourseRegistry {
 
     /// Get contract version history
     ///
-    /// Returns a list of all versions that have been deployed for this contract.
-    /// This helps track the evolution of the contract over time.
+    /// Returns every version recorded for this contract, written on
+    /// initialization, upgrade and completed migrations.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
     ///
     /// # Returns
-    /// * `Vec<String>` - Vector of version strings in chronological order
-    pub fn get_version_history(env: Env) -> Vec<String> {
+    /// * `Vec<VersionRecord>` - `{version, ledger_timestamp, by}` entries in chronological order
+    pub fn get_version_history(env: Env) -> Vec<VersionRecord> {
         functions::contract_versioning::get_version_history(&env)
     }
 
@@ -1021,7 +3276,7 @@ impl CourseRegistry {
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
-    /// * `caller` - The address performing the migration (must be course creator or admin)
+    /// * `caller` - The address performing the migration (must be the owner or an admin)
     /// * `from_version` - The source version to migrate from
     /// * `to_version` - The target version to migrate to
     ///
@@ -1031,21 +3286,167 @@ impl CourseRegistry {
     /// # Events
     /// Emits a migration event upon successful completion
     pub fn migrate_course_data(env: Env, caller: Address, from_version: String, to_version: String) -> bool {
+        functions::pause::require_not_paused(&env);
         functions::contract_versioning::migrate_course_data(&env, caller, from_version, to_version)
     }
 
     /// Get migration status for the current contract
     ///
-    /// Returns information about the current migration status and any
-    /// pending migrations that need to be completed.
+    /// Reports the running code version, the latest recorded version and
+    /// whether a data migration is still pending after an upgrade.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
     ///
     /// # Returns
-    /// * `String` - Migration status information
-    pub fn get_migration_status(env: Env) -> String {
+    /// * `MigrationStatus` - Versions, pending flag and last migration result
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
         functions::contract_versioning::get_migration_status(&env)
     }
 
+    /// Start a batched data migration
+    ///
+    /// Begins a migration registered for the given version pair. Records are
+    /// then transformed with `migrate_batch` and the migration is closed with
+    /// `finish_migration`. Only one migration can run at a time.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or an admin
+    /// * `from_version` - Version the stored data is in
+    /// * `to_version` - Version the stored data is migrated to
+    ///
+    /// # Panics
+    /// * If the caller is not the owner or an admin
+    /// * If a migration is already in progress
+    /// * If no migration is registered for the version pair
+    ///
+    /// # Events
+    /// Emits a `("migration", "started", to_version)` event
+    pub fn begin_migration(env: Env, admin: Address, from_version: String, to_version: String) {
+        functions::pause::require_not_paused(&env);
+        functions::migration::begin_migration(&env, admin, from_version, to_version)
+    }
+
+    /// Stage the modules and goals of a course that predate its indexes
+    ///
+    /// Modules and goals added before the per-course indexes existed can't be
+    /// found from their course. Their IDs, taken from the `module_added` and
+    /// `goal_added` events, are staged here: reads include them right away and
+    /// the 1.0.0 -> 1.1.0 migration folds them into the indexes.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or an admin
+    /// * `course_id` - The course the modules and goals belong to
+    /// * `module_ids` - IDs of the course's unindexed modules
+    /// * `goal_ids` - IDs of the course's unindexed goals
+    ///
+    /// # Panics
+    /// * If the caller is not the owner or an admin
+    /// * If the course doesn't exist
+    /// * If a module doesn't exist or belongs to another course
+    /// * If a goal isn't stored under the course
+    pub fn stage_legacy_course_index(
+        env: Env,
+        admin: Address,
+        course_id: String,
+        module_ids: Vec<String>,
+        goal_ids: Vec<String>,
+    ) {
+        functions::pause::require_not_paused(&env);
+        functions::course_index::stage_legacy_course_index(&env, admin, course_id, module_ids, goal_ids)
+    }
+
+    /// Transform the next batch of records
+    ///
+    /// Processes at most `limit` records of the migration in flight so each
+    /// call stays within the transaction budget. Call repeatedly until
+    /// `get_migration_state` reports the migration as completed.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or an admin
+    /// * `limit` - Maximum number of records to transform (1 to 50)
+    ///
+    /// # Returns
+    /// * `u32` - Number of records transformed by this call
+    ///
+    /// # Panics
+    /// * If the caller is not the owner or an admin
+    /// * If `limit` is 0 or above 50
+    /// * If no migration is in progress
+    pub fn migrate_batch(env: Env, admin: Address, limit: u32) -> u32 {
+        functions::pause::require_not_paused(&env);
+        functions::migration::migrate_batch(&env, admin, limit)
+    }
+
+    /// Finish the batched data migration
+    ///
+    /// Records the target version in the version history and clears the
+    /// pending migration flag.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or an admin
+    ///
+    /// # Panics
+    /// * If the caller is not the owner or an admin
+    /// * If no migration is in progress
+    /// * If records are still left to migrate
+    ///
+    /// # Events
+    /// Emits a `("migration", "finished", to_version)` event
+    pub fn finish_migration(env: Env, admin: Address) {
+        functions::pause::require_not_paused(&env);
+        functions::migration::finish_migration(&env, admin)
+    }
+
+    /// Get the progress of the migration in flight
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `Option<MigrationState>` - Current step, cursor and processed count, or `None`
+    pub fn get_migration_state(env: Env) -> Option<MigrationState> {
+        functions::migration::get_migration_state(&env)
+    }
+
+    /// Upgrade the contract code
+    ///
+    /// Swaps the contract WASM for one previously uploaded to the network and
+    /// records the upgrade in the version history. Storage is kept as is;
+    /// run the migration entrypoints afterwards if the schema changed.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner or an admin
+    /// * `new_wasm_hash` - Hash of the uploaded WASM to switch to
+    /// * `new_version` - Version string of the new code, stored in the history
+    ///
+    /// # Panics
+    /// * If the caller is not the owner or an admin
+    ///
+    /// # Events
+    /// Emits a `("contract", "upgraded", new_version)` event with the from/to versions
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>, new_version: String) {
+        functions::contract_versioning::upgrade(&env, admin, new_wasm_hash, new_version)
+    }
+
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
 }
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 /// Course registry defaults and limits
 pub const DEFAULT_COURSE_PRICE: u128 = 1000;
@@ -9,11 +9,52 @@ pub const MAX_LOOP_GUARD: u32 = 1000;
 pub const FILTER_MIN_PRICE: u128 = 500;
 pub const MAX_SCAN_ID: u32 = 50;
 pub const MAX_EMPTY_CHECKS: u32 = 10;
+pub const MAX_PREREQUISITE_DEPTH: u32 = 10;
+pub const MAX_MODULE_PREREQUISITES: u32 = 10;
+/// Highest quiz score a completion criteria can require, in percent
+pub const MAX_QUIZ_SCORE: u32 = 100;
+pub const MAX_GOALS_PER_COURSE: u32 = 50;
+pub const MAX_REBUILD_BATCH: u32 = 50;
+pub const SORT_PAGE_SIZE: u32 = 64;
+pub const MAX_MY_COURSES_PAGE_SIZE: u32 = 50;
+pub const MAX_STATUS_HISTORY: u32 = 50;
+pub const MAX_STATUS_REASON_LENGTH: u32 = 200;
+pub const DEFAULT_DELETION_WINDOW: u64 = 604_800; // 7 days in seconds
+/// Course IDs read per `get_my_courses` call at most, so pages stay cheap
+/// for instructors with few courses among many.
+pub const MAX_MY_COURSES_SCAN: u32 = 200;
+
+/// Storage TTL defaults, in ledgers (about 5 seconds each)
+pub const DEFAULT_TTL_THRESHOLD: u32 = 120_960; // ~7 days
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 518_400; // ~30 days
+
+/// Course price range used until an admin configures one
+pub const DEFAULT_MIN_COURSE_PRICE: u128 = 1;
+pub const DEFAULT_MAX_COURSE_PRICE: u128 = u128::MAX;
+
+/// Category creations allowed per address and window until an admin tunes the limit
+pub const DEFAULT_ACTION_RATE_WINDOW_LEDGERS: u32 = 720; // ~1 hour
+pub const DEFAULT_MAX_CATEGORY_CREATIONS_PER_WINDOW: u32 = 10;
 
 /// Rate limiting constants for course operations
 pub const DEFAULT_COURSE_RATE_LIMIT_WINDOW: u64 = 3600; // 1 hour in seconds
 pub const DEFAULT_MAX_COURSE_CREATIONS_PER_WINDOW: u32 = 3; // Max course creations per hour per address
 
+/// Default length limits of course titles and descriptions, in bytes
+pub const DEFAULT_MAX_TITLE_LENGTH: u32 = 200;
+pub const DEFAULT_MAX_DESCRIPTION_LENGTH: u32 = 2000;
+
+/// Highest length limits an admin can configure, in bytes. Descriptions stay
+/// within what the text search can scan.
+pub const MAX_TITLE_LENGTH_CEILING: u32 = 500;
+pub const MAX_DESCRIPTION_LENGTH_CEILING: u32 = validation::string_search::MAX_HAYSTACK_LENGTH;
+
+/// Approximate ledger close time, used to turn time windows into TTLs
+pub const LEDGER_CLOSE_SECONDS: u64 = 5;
+
+/// How long the cached category counts are kept, in ledgers
+pub const CATEGORY_COUNTS_TTL: u32 = 17_280; // ~1 day
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct CourseModule {
@@ -34,6 +75,54 @@ pub struct CourseGoal {
     pub created_at: u64,
 }
 
+/// Storage TTL configuration.
+///
+/// Entries whose TTL drops below `threshold` ledgers are extended to `extend_to` ledgers
+/// whenever they are read or written.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// Allowed range for course prices, inclusive on both ends.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceLimits {
+    pub min_course_price: u128,
+    pub max_course_price: u128,
+}
+
+/// Maximum lengths of course titles and descriptions, in bytes.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContentLimits {
+    pub max_title_length: u32,
+    pub max_description_length: u32,
+}
+
+/// Configuration supplied to `initialize`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegistryConfig {
+    /// Storage TTL thresholds
+    pub ttl: TtlConfig,
+    /// Course creation rate limit
+    pub rate_limit: CourseRateLimitConfig,
+}
+
+/// Owner and addresses of the contracts the registry calls, returned by `get_config`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseRegistryConfig {
+    /// Account set at initialization
+    pub owner: Address,
+    pub user_mgmt_addr: Address,
+    /// Course access contract used to count enrollments, if set
+    pub course_access_addr: Option<Address>,
+}
+
 /// Rate limiting configuration for course operations.
 ///
 /// Tracks rate limiting settings for spam protection in course creation.
@@ -70,19 +159,178 @@ pub struct CourseCategory {
 #[derive(Clone, Debug, PartialEq)]
 pub enum DataKey {
     Module(String),
-    Courses,
-    CourseGoalList(String),     // Optional: Keep a list of goal IDs per course
+    CourseGoalList(String),     // List of goal IDs per course
     CourseGoal(String, String), // (course_id, goal_id)
     CoursePrerequisites(String),
+    CourseModules(String),      // List of module IDs per course
+    TitleToken(String),         // Lowercase title word -> list of course IDs
+    CourseStats(String),        // Counters per course
+    InstructorStats(Address),   // Counters aggregated over an instructor's courses
+    StatsReporters,             // Contracts allowed to report enrollments, completions and purchases (instance storage)
+    PlatformStats,              // Platform-wide counters
+    PlatformStatsPublic,        // Whether anyone can read the platform counters
+    TtlConfig,                  // Thresholds used when bumping storage TTLs
     CategorySeq,          // Sequence counter for category IDs
     CourseCategory(u128), // Course category by ID
     Admins,               // List of admin addresses
     /// Key for storing course rate limiting configuration
     CourseRateLimitConfig,
-    /// Key for storing course rate limiting data per address: address -> CourseRateLimitData
+    /// Key for storing course rate limiting data per address (temporary storage): address -> CourseRateLimitData
     CourseRateLimit(Address),
+    /// Progress of the batched migration currently in flight
+    MigrationState,
+    /// Staged, unpublished edits of a course: course_id -> EditCourseParams
+    CourseDraft(String),
+    /// Title and description of a course in another language: (course_id, language)
+    CourseTranslation(String, String),
+    /// Languages a course has been translated into: course_id -> Vec<String>
+    CourseLanguages(String),
+    /// Sum and count of the ratings reported for an instructor's courses: address -> (u64, u32)
+    InstructorRating(Address),
+    /// Contract version in place when an instructor created their first course
+    InstructorJoined(Address),
+    /// Admin-curated list of course IDs: collection name -> Vec<String>
+    Collection(String),
+    /// Allowed course price range set by an admin
+    PriceLimits,
+    /// Marks a course as free, holding its paid price: course_id -> u128
+    FreeCourse(String),
+    /// Whether anyone can create courses without the `CreateCourses` permission (instance storage)
+    PermissionlessCreation,
+    /// Per-address limit of an action (instance storage)
+    ActionRateLimit(RateLimitedAction),
+    /// Number of times an address performed an action in a ledger window (temporary storage):
+    /// (action, address, window index) -> u32
+    ActionCounter(RateLimitedAction, Address, u32),
+    /// Cached result of `list_categories` (temporary storage), rebuilt on demand
+    CategoryCounts,
+    /// Address of the course access contract, asked for enrollments before deletion (instance storage)
+    CourseAccessContract,
+    /// Number of discussion threads created so far, used as the last thread ID
+    ThreadCount,
+    /// Discussion thread by ID: thread_id -> DiscussionThread
+    Thread(u32),
+    /// Threads opened on a module, oldest first: module_id -> Vec<u32>
+    ModuleThreads(String),
+    /// Reply to a thread: (thread_id, reply index starting at 1) -> ThreadReply
+    ThreadReply(u32, u32),
+    /// Cursor a paged backup import continues from: BackupCursor
+    BackupImportCursor,
+    /// Number of instructor stats rebuilds started so far
+    StatsRebuildRun,
+    /// Rebuild run in which an instructor's stats were last reset: address -> u32
+    InstructorStatsRun(Address),
+    /// Number of courses an instructor created that weren't deleted: address -> u32
+    InstructorCourseCount(Address),
+    /// Course IDs sorted by ascending value, as one list per field before the
+    /// indexes were paged; dropped by the 1.0.0 -> 1.1.0 migration: field -> Vec<SortEntry>
+    SortIndex(SortField),
+    /// Running sum and count of the ratings of a course: course_id -> (u64, u32)
+    CourseRating(String),
+    /// Latest lifecycle changes of a course, oldest first: course_id -> Vec<StatusChange>
+    StatusHistory(String),
+    /// Course deleted but not purged yet: course_id -> DeletedCourse
+    DeletedCourse(String),
+    /// IDs of deleted courses awaiting purge, oldest first: Vec<String>
+    PendingDeletions,
+    /// Seconds a deleted course can be restored for (instance storage)
+    DeletionWindow,
+    /// Whether state-changing entrypoints are frozen (instance storage)
+    Paused,
+    /// Modules of the same course to complete before a module: module_id -> Vec<String>
+    ModulePrerequisites(String),
+    /// What a student must achieve to complete a course: course_id -> CompletionCriteria
+    CompletionCriteria(String),
+    /// When the modules of a course unlock: course_id -> Vec<ModuleRelease>
+    ModuleReleaseSchedule(String),
+    /// Maximum title and description lengths set by an admin (instance storage)
+    ContentLimits,
+}
+
+/// When a scheduled module unlocks for a student.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReleaseTime {
+    /// Seconds after the student's enrollment
+    AfterEnrollment(u64),
+    /// Ledger timestamp, the same for every student
+    At(u64),
+}
+
+/// Release time of one module of a course.
+///
+/// Modules without an entry in the schedule are available right away.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleRelease {
+    pub module_id: String,
+    pub release: ReleaseTime,
 }
 
+/// What a student must achieve for a course to count as completed.
+///
+/// Courses without criteria are completed once every module is.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompletionCriteria {
+    /// Modules to complete; empty means every module of the course
+    pub required_modules: Vec<String>,
+    /// Lowest best quiz score accepted, in percent
+    pub min_quiz_score: Option<u32>,
+    /// Whether the student's assignments must be marked completed
+    pub require_assignments: bool,
+    /// Fewest live sessions of the course the student must check in to
+    pub min_sessions_attended: Option<u32>,
+}
+
+/// On-chain anchor of a Q&A thread about a module.
+///
+/// Only hashes of the posts are stored; the text lives off-chain and can be
+/// checked against them.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscussionThread {
+    pub id: u32,
+    pub course_id: String,
+    pub module_id: String,
+    pub author: Address,
+    pub title: String,
+    pub content_hash: BytesN<32>,
+    pub created_at: u64,
+    pub reply_count: u32,
+}
+
+/// On-chain anchor of a reply in a discussion thread.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadReply {
+    pub thread_id: u32,
+    /// Position of the reply in the thread, starting at 1
+    pub index: u32,
+    pub author: Address,
+    pub content_hash: BytesN<32>,
+    pub created_at: u64,
+}
+
+/// Spam-prone actions limited per address by `check_action_rate_limit`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RateLimitedAction {
+    CategoryCreation,
+}
+
+/// Maximum number of times an address can perform an action in a window of ledgers.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionRateLimit {
+    pub window_ledgers: u32,
+    pub max_actions: u32,
+}
+
+/// Permissions of the user management RBAC module, passed to its
+/// `has_permission` entrypoint.
+pub use skillcert_interfaces::user_management::Permission;
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Course {
@@ -101,6 +349,84 @@ pub struct Course {
     pub duration_hours: Option<u32>,
 }
 
+/// Fields of a course to create, used by `create_courses_batch`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewCourseParams {
+    pub title: String,
+    pub description: String,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub level: Option<CourseLevel>,
+    pub duration_hours: Option<u32>,
+}
+
+/// Aggregated, read-only view of a course and everything attached to it.
+///
+/// Lets frontends render a course page with a single invocation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseDetail {
+    pub course: Course,
+    /// Modules sorted by position
+    pub modules: Vec<CourseModule>,
+    /// Estimated module durations in minutes, keyed by module ID
+    pub module_durations: soroban_sdk::Map<String, u32>,
+    pub goals: Vec<CourseGoal>,
+    /// IDs of the courses required before this one
+    pub prerequisites: Vec<String>,
+    /// Category record matching `course.category`; empty if none is registered.
+    /// A `Vec` with at most one entry, as contract types cannot hold `Option` of a struct.
+    pub category: Vec<CourseCategory>,
+}
+
+/// Running counters for a course, reported by the access, progress and payment contracts.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CourseStats {
+    pub enrollments: u32,
+    pub completions: u32,
+    /// Sum of all purchase amounts
+    pub revenue: u128,
+}
+
+/// Counters summed over every course created by an instructor.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct InstructorStats {
+    pub enrollments: u32,
+    pub completions: u32,
+    pub revenue: u128,
+}
+
+/// Public summary of an instructor, shown on course pages.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstructorSummary {
+    /// Courses created by the instructor, excluding archived ones
+    pub course_count: u32,
+    pub published_count: u32,
+    /// Enrollments reported over all of the instructor's courses
+    pub total_students: u32,
+    /// Average reported rating in hundredths (450 = 4.50), 0 if unrated
+    pub avg_rating: u32,
+    /// Contract version when the first course was created, if known
+    pub joined_version: Option<String>,
+}
+
+/// Platform-wide counters, updated incrementally as courses and reports come in.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct PlatformStats {
+    pub total_courses: u32,
+    pub published_courses: u32,
+    pub total_users: u32,
+    pub total_enrollments: u32,
+    pub total_certificates: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct CourseId {
@@ -132,6 +458,108 @@ pub struct CourseFilters {
     pub search_text: Option<String>,
 }
 
+/// Lifecycle stage of a course, as shown on an instructor's dashboard.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CourseStatus {
+    /// Not published yet
+    Draft,
+    Published,
+    Archived,
+    /// Deleted and waiting to be purged
+    Deleted,
+}
+
+/// A deleted course kept until its recovery window ends.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeletedCourse {
+    pub course: Course,
+    /// Creator, owner or admin who deleted the course
+    pub deleted_by: Address,
+    /// Ledger timestamp of the deletion
+    pub deleted_at: u64,
+    /// Ledger timestamp from which the course can be purged and no longer restored
+    pub purge_after: u64,
+}
+
+/// A transition of a course between lifecycle stages.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusChange {
+    pub old_status: CourseStatus,
+    pub new_status: CourseStatus,
+    /// Creator or admin who made the change
+    pub actor: Address,
+    pub reason: Option<String>,
+    /// Ledger timestamp of the change
+    pub changed_at: u64,
+}
+
+/// Cursor-based pagination parameters for course listings.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoursePagination {
+    /// Number of the last course ID read by the previous page, `None` for the first page
+    pub cursor: Option<u128>,
+    /// Maximum number of courses to return, at most `MAX_MY_COURSES_PAGE_SIZE`
+    pub limit: u32,
+}
+
+/// A page of courses.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaginatedCourses {
+    pub data: Vec<Course>,
+    /// Cursor of the next page, `None` on the last page
+    pub next_cursor: Option<u128>,
+    pub has_more: bool,
+}
+
+/// Order of the courses returned by `list_courses_with_filters`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortOption {
+    /// Most recently created first
+    Newest,
+    /// Cheapest first
+    PriceAsc,
+    /// Most expensive first
+    PriceDesc,
+    /// Highest average rating first, unrated courses last
+    RatingDesc,
+    /// Most enrollments first
+    MostEnrolled,
+}
+
+/// Course value a sorted index is ordered by.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortField {
+    Price,
+    /// Average rating in hundredths
+    Rating,
+    Enrollments,
+}
+
+/// A course in a sorted index, with the value it is sorted by.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortEntry {
+    pub course_id: String,
+    pub value: u128,
+}
+
+/// A page of a sorted index: where it is stored, how many entries it holds
+/// and the value of its last entry.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortPage {
+    pub id: u32,
+    pub len: u32,
+    pub last_value: u128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct EditCourseParams {
@@ -146,6 +574,16 @@ pub struct EditCourseParams {
     pub new_duration_hours: Option<Option<u32>>,
 }
 
+/// Title and description of a course in a language other than its default.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseTranslation {
+    pub course_id: String,
+    pub language: String,
+    pub title: String,
+    pub description: String,
+}
+
 /// Backup data structure for course registry system.
 ///
 /// Contains all course data, categories, modules, goals, and prerequisites
@@ -171,4 +609,69 @@ pub struct CourseBackupData {
     pub backup_timestamp: u64,
     /// Backup version for compatibility
     pub backup_version: String,
-}
\ No newline at end of file
+}
+
+/// Maximum number of courses in a page of a paged backup.
+pub const MAX_BACKUP_PAGE_SIZE: u32 = 20;
+
+/// Position in a paged backup.
+///
+/// `checksum` chains the SHA-256 of every page before `offset`, so pages
+/// that were altered, dropped or reordered are rejected on import. The first
+/// page starts at offset 0 with an all-zero checksum.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupCursor {
+    /// Number of course IDs covered by the previous pages
+    pub offset: u32,
+    pub checksum: BytesN<32>,
+}
+
+/// Backed-up records of one course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseBackupEntry {
+    pub course: Course,
+    pub modules: Vec<CourseModule>,
+    /// Estimated module durations in minutes, keyed by module ID
+    pub module_durations: soroban_sdk::Map<String, u32>,
+    pub goals: Vec<CourseGoal>,
+    /// IDs of the prerequisite courses
+    pub prerequisites: Vec<String>,
+}
+
+/// One chunk of a paged course backup.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseBackupPage {
+    /// Cursor the page was exported from
+    pub cursor: BackupCursor,
+    pub entries: Vec<CourseBackupEntry>,
+    /// Categories, only filled on the first page
+    pub categories: Vec<CourseCategory>,
+    /// Category sequence counter, only meaningful on the first page
+    pub category_seq: u128,
+    /// Cursor of the next page; its checksum covers this page
+    pub next_cursor: BackupCursor,
+    pub has_more: bool,
+    /// Number of course IDs handed out, deleted courses included
+    pub total_courses: u32,
+    pub backup_version: String,
+}
+
+/// Progress of the migration currently in flight.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationState {
+    pub from_version: String,
+    pub to_version: String,
+    pub started_by: Address,
+    /// Index of the step being processed
+    pub step: u32,
+    /// Next record within the current step
+    pub cursor: u32,
+    /// Records transformed so far, across all steps
+    pub processed: u32,
+    /// True once every step has been walked to the end
+    pub completed: bool,
+}
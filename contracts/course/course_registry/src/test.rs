@@ -16,6 +16,8 @@ use crate::{
 mod mock_user_management {
     use soroban_sdk::{contract, contractimpl, Address, Env};
 
+    use crate::schema::Permission;
+
     #[contract]
     pub struct UserManagement;
 
@@ -24,6 +26,12 @@ mod mock_user_management {
         pub fn is_admin(_env: Env, _who: Address) -> bool {
             true
         }
+        pub fn has_permission(env: Env, user: Address, permission: Permission) -> bool {
+            env.storage().persistent().has(&(user, permission))
+        }
+        pub fn grant_permission(env: Env, user: Address, permission: Permission) {
+            env.storage().persistent().set(&(user, permission), &true);
+        }
     }
 }
 
@@ -430,6 +438,43 @@ fn test_list_categories_with_id_gaps() {
     assert_eq!(data, 0); // Course 2 was deleted
 }
 
+#[test]
+fn test_list_categories_cache_is_invalidated_and_rebuilt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+    let creator = Address::generate(&env);
+
+    let course = client.create_course(
+        &creator,
+        &String::from_str(&env, "Course 1"),
+        &String::from_str(&env, "Desc"),
+        &10,
+        &Some(String::from_str(&env, "Programming")),
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(client.list_categories().get(0).unwrap().count, 1);
+
+    // Deleting the course drops the cached counts
+    client.delete_course(&creator, &course.id, &false);
+    assert_eq!(client.list_categories().len(), 0);
+
+    // Changes made outside the contract functions are only seen after a rebuild
+    env.as_contract(&contract_id, || {
+        let key = (symbol_short!("course"), String::from_str(&env, "1"));
+        env.storage().persistent().set(&key, &course);
+    });
+    assert_eq!(client.list_categories().len(), 0);
+    let cats = client.rebuild_category_counts();
+    assert_eq!(cats.len(), 1);
+    assert_eq!(client.list_categories(), cats);
+}
+
 #[test]
 fn test_course_backup_and_recovery_system() {
     let env = Env::default();
@@ -486,3 +531,287 @@ fn test_course_backup_and_recovery_system() {
     let imported_count = client.import_course_data(&admin, &backup_data);
     assert!(imported_count >= 2);
 }
+
+#[test]
+fn test_list_modules_entrypoint() {
+    let (env, _contract_id, client) = setup_test_env();
+    let creator = Address::generate(&env);
+
+    let course = client.create_course(
+        &creator,
+        &String::from_str(&env, "Rust Basics"),
+        &String::from_str(&env, "Intro to Rust"),
+        &1000_u128,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    assert_eq!(client.list_modules(&course.id).len(), 0);
+
+    client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Ownership"));
+    client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Syntax"));
+
+    let modules = client.list_modules(&course.id);
+    assert_eq!(modules.len(), 2);
+    assert_eq!(modules.get(0).unwrap().title, String::from_str(&env, "Syntax"));
+    assert_eq!(modules.get(1).unwrap().title, String::from_str(&env, "Ownership"));
+}
+
+#[test]
+fn test_get_prerequisites_transitive() {
+    let (env, _contract_id, client) = setup_test_env();
+    let creator = Address::generate(&env);
+
+    // Course creation is rate limited per address, so D gets its own creator
+    let mut ids: Vec<String> = Vec::new(&env);
+    for (title, owner) in [
+        ("Course A", &creator),
+        ("Course B", &creator),
+        ("Course C", &creator),
+        ("Course D", &Address::generate(&env)),
+    ] {
+        let course = client.create_course(
+            owner,
+            &String::from_str(&env, title),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        ids.push_back(course.id);
+    }
+    let (a, b, c, d) = (
+        ids.get(0).unwrap(),
+        ids.get(1).unwrap(),
+        ids.get(2).unwrap(),
+        ids.get(3).unwrap(),
+    );
+
+    // D requires C and B, C requires B, B requires A
+    client.add_prerequisite(&creator, &b, &soroban_sdk::vec![&env, a.clone()]);
+    client.add_prerequisite(&creator, &c, &soroban_sdk::vec![&env, b.clone()]);
+    let d_creator = client.get_course(&d).creator;
+    client.add_prerequisite(&d_creator, &d, &soroban_sdk::vec![&env, c.clone(), b.clone()]);
+
+    let direct = client.get_prerequisites(&d, &false);
+    assert_eq!(direct, soroban_sdk::vec![&env, c.clone(), b.clone()]);
+
+    let all = client.get_prerequisites(&d, &true);
+    assert_eq!(all, soroban_sdk::vec![&env, c.clone(), b.clone(), a.clone()]);
+
+    assert!(client.get_prerequisites(&a, &true).is_empty());
+}
+
+fn test_registry_config() -> crate::schema::RegistryConfig {
+    crate::schema::RegistryConfig {
+        ttl: crate::schema::TtlConfig {
+            threshold: 1_000,
+            extend_to: 5_000,
+        },
+        rate_limit: crate::schema::CourseRateLimitConfig {
+            window_seconds: 3600,
+            max_courses_per_window: 1,
+        },
+    }
+}
+
+#[test]
+fn test_initialize_entrypoint_applies_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+    let contract_id = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    assert!(!client.is_initialized());
+    assert!(client.try_get_config().is_err());
+    client.initialize(&admin, &user_mgmt_id, &test_registry_config());
+
+    assert!(client.is_initialized());
+    let config = client.get_config();
+    assert_eq!(config.owner, admin);
+    assert_eq!(config.user_mgmt_addr, user_mgmt_id);
+    assert_eq!(config.course_access_addr, None);
+    assert_eq!(client.get_ttl_config(), test_registry_config().ttl);
+    // Category creation checks admins against the user management contract
+    let id = client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+    assert_eq!(id, 1);
+
+    let creator = Address::generate(&env);
+    client.create_course(
+        &creator,
+        &String::from_str(&env, "Rust"),
+        &String::from_str(&env, "Intro"),
+        &1000_u128,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    // The configured limit allows a single course per window
+    let second = client.try_create_course(
+        &creator,
+        &String::from_str(&env, "Soroban"),
+        &String::from_str(&env, "Intro"),
+        &1000_u128,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    assert!(second.is_err());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #55)")]
+fn test_initialize_entrypoint_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+    let contract_id = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin, &user_mgmt_id, &test_registry_config());
+    client.initialize(&admin, &user_mgmt_id, &test_registry_config());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_create_category_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+
+    // Without initialization there is no owner and no user management contract
+    client.create_course_category(
+        &Address::generate(&env),
+        &String::from_str(&env, "Programming"),
+        &None,
+    );
+}
+
+fn create_categorized_course(env: &Env, client: &CourseRegistryClient, title: &str, category: &str) -> Course {
+    client.create_course(
+        &Address::generate(env),
+        &String::from_str(env, title),
+        &String::from_str(env, "Description"),
+        &1000_u128,
+        &Some(String::from_str(env, category)),
+        &None,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_edit_course_category_renames_courses() {
+    let (env, _contract_id, client) = setup_test_env();
+    let admin = Address::generate(&env);
+    let id = client.create_course_category(&admin, &String::from_str(&env, "Coding"), &None);
+    let course = create_categorized_course(&env, &client, "Rust", "Coding");
+
+    let updated = client.edit_course_category(
+        &admin,
+        &id,
+        &String::from_str(&env, "Programming"),
+        &Some(String::from_str(&env, "Software courses")),
+    );
+
+    assert_eq!(updated.name, String::from_str(&env, "Programming"));
+    assert_eq!(client.get_course_category(&id), Some(updated));
+    assert_eq!(
+        client.get_course(&course.id).category,
+        Some(String::from_str(&env, "Programming"))
+    );
+}
+
+#[test]
+fn test_delete_course_category_with_replacement() {
+    let (env, _contract_id, client) = setup_test_env();
+    let admin = Address::generate(&env);
+    let old = client.create_course_category(&admin, &String::from_str(&env, "Coding"), &None);
+    let new = client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+    let course = create_categorized_course(&env, &client, "Rust", "Coding");
+
+    assert_eq!(client.delete_course_category(&admin, &old, &Some(new)), 1);
+
+    assert!(client.get_course_category(&old).is_none());
+    assert_eq!(
+        client.get_course(&course.id).category,
+        Some(String::from_str(&env, "Programming"))
+    );
+}
+
+#[test]
+fn test_delete_unused_course_category() {
+    let (env, _contract_id, client) = setup_test_env();
+    let admin = Address::generate(&env);
+    let id = client.create_course_category(&admin, &String::from_str(&env, "Coding"), &None);
+
+    assert_eq!(client.delete_course_category(&admin, &id, &None), 0);
+    assert!(client.get_course_category(&id).is_none());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #102)")]
+fn test_delete_course_category_in_use_fails() {
+    let (env, _contract_id, client) = setup_test_env();
+    let admin = Address::generate(&env);
+    let id = client.create_course_category(&admin, &String::from_str(&env, "Coding"), &None);
+    create_categorized_course(&env, &client, "Rust", "Coding");
+
+    client.delete_course_category(&admin, &id, &None);
+}
+
+#[test]
+fn test_course_creation_requires_permission_when_not_permissionless() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+    let user_mgmt = mock_user_management::UserManagementClient::new(&env, &user_mgmt_id);
+    let contract_id = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+    });
+
+    assert!(client.is_permissionless_creation());
+    client.set_permissionless_creation(&admin, &false);
+    assert!(!client.is_permissionless_creation());
+
+    let instructor = Address::generate(&env);
+    let create = |creator: &Address, title: &str| {
+        client.try_create_course(
+            creator,
+            &String::from_str(&env, title),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    assert_eq!(
+        create(&instructor, "Rust").err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(crate::error::Error::Unauthorized as u32)))
+    );
+
+    user_mgmt.grant_permission(&instructor, &crate::schema::Permission::CreateCourses);
+    assert!(create(&instructor, "Rust").is_ok());
+}
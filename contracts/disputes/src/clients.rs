@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Typed clients for the contracts disputes calls.
+//!
+//! The clients come from `skillcert_interfaces`, so a signature change shows
+//! up as a compile error instead of a failed call at runtime. The helpers
+//! below build a client for the addresses set with `initialize` and
+//! `set_payments_contract`.
+
+use soroban_sdk::{Address, Env};
+
+pub use skillcert_interfaces::course_access::{CourseAccessClient, CourseAccessInterface};
+pub use skillcert_interfaces::course_registry::{CourseRegistryClient, CourseRegistryInterface};
+pub use skillcert_interfaces::payments::{PaymentsClient, PaymentsInterface};
+pub use skillcert_interfaces::user_management::{UserManagementClient, UserManagementInterface};
+
+use crate::functions::config::{get_config, get_payments_contract};
+
+/// Returns a client for the configured user management contract.
+pub fn user_management(env: &Env) -> UserManagementClient<'_> {
+    UserManagementClient::new(env, &get_config(env).user_mgmt_addr)
+}
+
+/// Returns a client for the configured course registry.
+pub fn course_registry(env: &Env) -> CourseRegistryClient<'_> {
+    CourseRegistryClient::new(env, &get_config(env).course_registry_addr)
+}
+
+/// Returns a client for the configured course access contract.
+pub fn course_access(env: &Env) -> CourseAccessClient<'_> {
+    CourseAccessClient::new(env, &get_config(env).course_access_addr)
+}
+
+/// Returns a client for the configured payments contract, or `None` until
+/// `set_payments_contract` is called.
+pub fn try_payments(env: &Env) -> Option<PaymentsClient<'_>> {
+    let address: Address = get_payments_contract(env)?;
+    Some(PaymentsClient::new(env, &address))
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    EmptyCourseId = 4,
+    InvalidReason = 5,
+    DisputeNotFound = 6,
+    DisputeAlreadyOpen = 7,
+    DisputeAlreadyResolved = 8,
+    InvalidRefundAmount = 9,
+    RefundsNotConfigured = 10,
+    NotEnrolled = 11,
+    RefundExceedsPrice = 12,
+    NoPurchase = 13,
+    AlreadyRefunded = 14,
+}
+
+pub fn handle_error(env: &Env, error: Error) -> ! {
+    panic_with_error!(env, error);
+}
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "AlreadyInitialized: The contract is already initialized",
+        2 => "NotInitialized: The contract is not initialized",
+        3 => "Unauthorized: The caller is not allowed to do this",
+        4 => "EmptyCourseId: The course ID is empty",
+        5 => "InvalidReason: The reason is not valid",
+        6 => "DisputeNotFound: The dispute does not exist",
+        7 => "DisputeAlreadyOpen: A dispute is already open for the course",
+        8 => "DisputeAlreadyResolved: The dispute is already resolved",
+        9 => "InvalidRefundAmount: The refund amount is not valid",
+        10 => "RefundsNotConfigured: The payments contract is not configured",
+        11 => "NotEnrolled: The student never had access to the course",
+        12 => "RefundExceedsPrice: The refunds would exceed the amount paid",
+        13 => "NoPurchase: The student did not buy the course",
+        14 => "AlreadyRefunded: The student was refunded for the course",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
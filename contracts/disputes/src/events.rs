@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! Dispute events are published with the topics `("dispute", action, dispute_id)`
+//! and a typed payload whose first field is `version`, following the same
+//! convention as the course registry.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+use crate::schema::{Dispute, DisputeOutcome};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const DISPUTE: Symbol = symbol_short!("dispute");
+
+const OPENED: Symbol = symbol_short!("opened");
+const RESPONDED: Symbol = symbol_short!("responded");
+const RESOLVED: Symbol = symbol_short!("resolved");
+
+/// Payload of `("dispute", "opened" | "responded", dispute_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeEvent {
+    pub version: u32,
+    pub dispute_id: u32,
+    pub course_id: String,
+    pub student: Address,
+    /// Student who opened the dispute, or instructor who responded
+    pub actor: Address,
+}
+
+/// Payload of `("dispute", "resolved", dispute_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeResolvedEvent {
+    pub version: u32,
+    pub dispute_id: u32,
+    pub course_id: String,
+    pub student: Address,
+    pub moderator: Address,
+    pub outcome: DisputeOutcome,
+}
+
+pub fn dispute_opened(env: &Env, dispute: &Dispute) {
+    publish_dispute_event(env, OPENED, dispute, &dispute.student);
+}
+
+pub fn dispute_responded(env: &Env, dispute: &Dispute, instructor: &Address) {
+    publish_dispute_event(env, RESPONDED, dispute, instructor);
+}
+
+pub fn dispute_resolved(
+    env: &Env,
+    dispute: &Dispute,
+    moderator: &Address,
+    outcome: &DisputeOutcome,
+) {
+    env.events().publish(
+        (DISPUTE, RESOLVED, dispute.id),
+        DisputeResolvedEvent {
+            version: EVENT_VERSION,
+            dispute_id: dispute.id,
+            course_id: dispute.course_id.clone(),
+            student: dispute.student.clone(),
+            moderator: moderator.clone(),
+            outcome: outcome.clone(),
+        },
+    );
+}
+
+fn publish_dispute_event(env: &Env, action: Symbol, dispute: &Dispute, actor: &Address) {
+    env.events().publish(
+        (DISPUTE, action, dispute.id),
+        DisputeEvent {
+            version: EVENT_VERSION,
+            dispute_id: dispute.id,
+            course_id: dispute.course_id.clone(),
+            student: dispute.student.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, DisputesConfig};
+
+const INIT_EVENT: Symbol = symbol_short!("init");
+const SET_PAYMENTS_EVENT: Symbol = symbol_short!("setPay");
+
+/// Stores the owner and the contracts used to check roles and apply outcomes.
+pub fn initialize(env: &Env, config: DisputesConfig) {
+    config.owner.require_auth();
+
+    if env.storage().instance().has(&DataKey::Config) {
+        handle_error(env, Error::AlreadyInitialized)
+    }
+    env.storage().instance().set(&DataKey::Config, &config);
+
+    env.events().publish((INIT_EVENT,), config);
+}
+
+/// Returns whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Config)
+}
+
+/// Returns the configuration, panicking with `NotInitialized` before `initialize`.
+pub fn get_config(env: &Env) -> DisputesConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
+
+/// Sets the payments contract refunds are made through (owner only).
+///
+/// The payments contract must accept this contract as its disputes contract
+/// for refunds to go through.
+pub fn set_payments_contract(env: &Env, owner: Address, payments: Address) {
+    owner.require_auth();
+    if get_config(env).owner != owner {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PaymentsContract, &payments);
+
+    env.events()
+        .publish((SET_PAYMENTS_EVENT,), (owner, payments));
+}
+
+/// Returns the payments contract refunds are made through, if configured.
+pub fn get_payments_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PaymentsContract)
+}
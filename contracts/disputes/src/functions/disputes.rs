@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Dispute lifecycle: a student opens a dispute, the course creator responds
+//! and a moderator (`ModerateContent` permission) resolves it.
+
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use crate::clients::{course_access, course_registry, try_payments, user_management};
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::schema::{
+    DataKey, Dispute, DisputeOutcome, DisputeResolution, DisputeStatus, EnrollmentSource,
+    Permission, Purchase, RevocationReason, RevocationRecord, MAX_REASON_LENGTH,
+};
+
+fn require_dispute(env: &Env, dispute_id: u32) -> Dispute {
+    get_dispute(env, dispute_id).unwrap_or_else(|| handle_error(env, Error::DisputeNotFound))
+}
+
+fn store_dispute(env: &Env, dispute: &Dispute) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(dispute.id), dispute);
+}
+
+/// Returns a dispute, or `None` if it does not exist.
+pub fn get_dispute(env: &Env, dispute_id: u32) -> Option<Dispute> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(dispute_id))
+}
+
+/// Returns how a dispute was resolved, or `None` while it is unresolved.
+pub fn get_resolution(env: &Env, dispute_id: u32) -> Option<DisputeResolution> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Resolution(dispute_id))
+}
+
+/// Whether a student has access to a course, or had it until it was revoked.
+///
+/// Reading the revocation history requires this contract to be trusted by
+/// the course access contract, as restoring access already does.
+fn has_or_had_access(env: &Env, course_id: &String, student: &Address) -> bool {
+    let course_access = course_access(env);
+    course_access.has_access(course_id, student)
+        || !course_access
+            .get_revocation_history(&env.current_contract_address(), course_id, student)
+            .is_empty()
+}
+
+/// Whether a student's purchase of a course was refunded outside of
+/// disputes. Payments revokes access for a refund whenever a purchase is
+/// refunded in full, so the latest revocation tells.
+fn refunded_by_payments(env: &Env, course_id: &String, student: &Address) -> bool {
+    course_access(env)
+        .get_revocation_history(&env.current_contract_address(), course_id, student)
+        .last()
+        .is_some_and(|record: RevocationRecord| record.reason == RevocationReason::Refund)
+}
+
+/// Returns the total refunded to a student for a course through disputes.
+pub fn get_refunded(env: &Env, course_id: String, student: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refunded(course_id, student))
+        .unwrap_or(0)
+}
+
+/// Returns the unresolved dispute of a student on a course, if any.
+pub fn get_open_dispute(env: &Env, course_id: String, student: Address) -> Option<Dispute> {
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::OpenDispute(course_id, student))
+        .and_then(|dispute_id| get_dispute(env, dispute_id))
+}
+
+/// Opens a dispute about a course. A student has at most one unresolved
+/// dispute per course, and must have access to it or have lost it to a
+/// revocation.
+pub fn open_dispute(
+    env: &Env,
+    student: Address,
+    course_id: String,
+    reason: String,
+    evidence_hash: BytesN<32>,
+) -> Dispute {
+    student.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if reason.is_empty() || reason.len() > MAX_REASON_LENGTH {
+        handle_error(env, Error::InvalidReason)
+    }
+    let open_key: DataKey = DataKey::OpenDispute(course_id.clone(), student.clone());
+    if env.storage().persistent().has(&open_key) {
+        handle_error(env, Error::DisputeAlreadyOpen)
+    }
+    if !has_or_had_access(env, &course_id, &student) {
+        handle_error(env, Error::NotEnrolled)
+    }
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::DisputeCount)
+        .unwrap_or(0)
+        + 1;
+    let dispute: Dispute = Dispute {
+        id,
+        course_id,
+        student,
+        reason,
+        evidence_hash,
+        response_hash: None,
+        status: DisputeStatus::Open,
+        opened_at: env.ledger().timestamp(),
+        resolved_at: None,
+    };
+    env.storage().persistent().set(&DataKey::DisputeCount, &id);
+    env.storage().persistent().set(&open_key, &id);
+    store_dispute(env, &dispute);
+
+    events::dispute_opened(env, &dispute);
+
+    dispute
+}
+
+/// Records the instructor's answer to a dispute. Course creator only; the
+/// answer can be updated until the dispute is resolved.
+pub fn respond_to_dispute(
+    env: &Env,
+    instructor: Address,
+    dispute_id: u32,
+    response_hash: BytesN<32>,
+) -> Dispute {
+    instructor.require_auth();
+
+    let mut dispute: Dispute = require_dispute(env, dispute_id);
+    if dispute.status == DisputeStatus::Resolved {
+        handle_error(env, Error::DisputeAlreadyResolved)
+    }
+    if !course_registry(env).is_course_creator(&dispute.course_id, &instructor) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    dispute.response_hash = Some(response_hash);
+    dispute.status = DisputeStatus::Responded;
+    store_dispute(env, &dispute);
+
+    events::dispute_responded(env, &dispute, &instructor);
+
+    dispute
+}
+
+/// Resolves a dispute and applies its outcome. Moderators only.
+///
+/// `Refund` refunds part of the student's purchase of the course through the
+/// payments contract, which takes it back from the instructor's held earnings
+/// and caps all refunds of the purchase at what the student paid. Students
+/// who didn't buy the course can't be refunded. `RestoreAccess` grants the
+/// student access again through the course access contract, which must
+/// accept this contract as a granter (admin). It is rejected once the
+/// student was refunded for the course, whether by a dispute or by payments
+/// (a refunded purchase, or access last revoked for a refund), as the refund
+/// may have revoked that access on purpose.
+pub fn resolve_dispute(
+    env: &Env,
+    moderator: Address,
+    dispute_id: u32,
+    outcome: DisputeOutcome,
+) -> Dispute {
+    moderator.require_auth();
+
+    if !user_management(env).has_permission(&moderator, &Permission::ModerateContent) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let mut dispute: Dispute = require_dispute(env, dispute_id);
+    if dispute.status == DisputeStatus::Resolved {
+        handle_error(env, Error::DisputeAlreadyResolved)
+    }
+
+    match outcome {
+        DisputeOutcome::Rejected => {}
+        DisputeOutcome::Refund(amount) => {
+            if amount <= 0 {
+                handle_error(env, Error::InvalidRefundAmount)
+            }
+            let payments = try_payments(env)
+                .unwrap_or_else(|| handle_error(env, Error::RefundsNotConfigured));
+            let purchase: Purchase = payments
+                .get_course_purchase(&dispute.course_id, &dispute.student)
+                .unwrap_or_else(|| handle_error(env, Error::NoPurchase));
+            let already_refunded: i128 = payments.get_refunded_amount(&purchase.id);
+            if purchase.refunded || amount > purchase.price - already_refunded {
+                handle_error(env, Error::RefundExceedsPrice)
+            }
+            payments.refund_purchase_partial(&env.current_contract_address(), &purchase.id, &amount);
+            let refunded: i128 =
+                get_refunded(env, dispute.course_id.clone(), dispute.student.clone()) + amount;
+            env.storage().persistent().set(
+                &DataKey::Refunded(dispute.course_id.clone(), dispute.student.clone()),
+                &refunded,
+            );
+        }
+        DisputeOutcome::RestoreAccess => {
+            if get_refunded(env, dispute.course_id.clone(), dispute.student.clone()) > 0 {
+                handle_error(env, Error::AlreadyRefunded)
+            }
+            let course_access = course_access(env);
+            if !course_access.has_access(&dispute.course_id, &dispute.student) {
+                if refunded_by_payments(env, &dispute.course_id, &dispute.student) {
+                    handle_error(env, Error::AlreadyRefunded)
+                }
+                course_access.grant_access_with_source(
+                    &env.current_contract_address(),
+                    &dispute.course_id,
+                    &dispute.student,
+                    &EnrollmentSource::Manual,
+                );
+            }
+        }
+    }
+
+    dispute.status = DisputeStatus::Resolved;
+    dispute.resolved_at = Some(env.ledger().timestamp());
+    store_dispute(env, &dispute);
+    env.storage().persistent().set(
+        &DataKey::Resolution(dispute_id),
+        &DisputeResolution {
+            outcome: outcome.clone(),
+            moderator: moderator.clone(),
+            resolved_at: env.ledger().timestamp(),
+        },
+    );
+    env.storage().persistent().remove(&DataKey::OpenDispute(
+        dispute.course_id.clone(),
+        dispute.student.clone(),
+    ));
+
+    events::dispute_resolved(env, &dispute, &moderator, &outcome);
+
+    dispute
+}
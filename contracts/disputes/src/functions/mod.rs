@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+pub mod config;
+pub mod disputes;
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+/// Contract version for tracking deployments and upgrades
+pub const VERSION: &str = "1.0.0";
+
+pub mod clients;
+pub mod error;
+pub mod events;
+pub mod functions;
+pub mod schema;
+
+#[cfg(test)]
+mod test;
+
+use crate::schema::{Dispute, DisputeOutcome, DisputeResolution, DisputesConfig};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
+
+/// Disputes Contract
+///
+/// This contract lets students dispute a course with its instructor and
+/// moderators settle the dispute, refunding the student or restoring their
+/// access when warranted.
+#[contract]
+pub struct DisputesContract;
+
+#[contractimpl]
+impl DisputesContract {
+    /// Initialize the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `config` - The owner and the user management, course registry and
+    ///   course access contract addresses
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is already initialized
+    pub fn initialize(env: Env, config: DisputesConfig) {
+        functions::config::initialize(&env, config)
+    }
+
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::config::is_initialized(&env)
+    }
+
+    /// Get the contract configuration.
+    pub fn get_config(env: Env) -> DisputesConfig {
+        functions::config::get_config(&env)
+    }
+
+    /// Set the payments contract refunds are made through (owner only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The contract owner
+    /// * `payments` - Address of the payments contract, which must accept
+    ///   this contract as its disputes contract
+    pub fn set_payments_contract(env: Env, owner: Address, payments: Address) {
+        functions::config::set_payments_contract(&env, owner, payments)
+    }
+
+    /// Get the payments contract refunds are made through, `None` if refunds are not configured.
+    pub fn get_payments_contract(env: Env) -> Option<Address> {
+        functions::config::get_payments_contract(&env)
+    }
+
+    /// Open a dispute about a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student opening the dispute
+    /// * `course_id` - The disputed course
+    /// * `reason` - Short reason (1 to 200 bytes)
+    /// * `evidence_hash` - Hash of the off-chain evidence
+    ///
+    /// # Returns
+    ///
+    /// Returns the new dispute.
+    ///
+    /// # Panics
+    ///
+    /// * If the student already has an unresolved dispute on the course
+    /// * If the course ID or the reason is invalid
+    /// * If the student neither has access to the course nor lost it to a revocation
+    pub fn open_dispute(
+        env: Env,
+        student: Address,
+        course_id: String,
+        reason: String,
+        evidence_hash: BytesN<32>,
+    ) -> Dispute {
+        functions::disputes::open_dispute(&env, student, course_id, reason, evidence_hash)
+    }
+
+    /// Answer a dispute as the instructor of the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The course creator
+    /// * `dispute_id` - The dispute being answered
+    /// * `response_hash` - Hash of the off-chain response
+    ///
+    /// # Panics
+    ///
+    /// * If the dispute doesn't exist or is already resolved
+    /// * If the caller is not the creator of the disputed course
+    pub fn respond_to_dispute(
+        env: Env,
+        instructor: Address,
+        dispute_id: u32,
+        response_hash: BytesN<32>,
+    ) -> Dispute {
+        functions::disputes::respond_to_dispute(&env, instructor, dispute_id, response_hash)
+    }
+
+    /// Resolve a dispute and apply its outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `moderator` - A user holding the `ModerateContent` permission
+    /// * `dispute_id` - The dispute being resolved
+    /// * `outcome` - `Rejected`, `Refund(amount)` or `RestoreAccess`
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not a moderator
+    /// * If the dispute doesn't exist or is already resolved
+    /// * If a refund is requested without a payments contract or with a non-positive amount
+    /// * If a refund is requested but the student didn't buy the course
+    /// * If a refund would bring the refunds of the purchase above its price
+    /// * If access is restored to a student a dispute already refunded
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Refunds**: Made through the payments contract, which takes them
+    ///   back from the instructor's held earnings; they are capped at the
+    ///   price paid across all refunds of the purchase
+    /// * **Access restoration**: This contract must be allowed to grant access
+    ///   in the course access contract; students who still have access are left as is
+    pub fn resolve_dispute(
+        env: Env,
+        moderator: Address,
+        dispute_id: u32,
+        outcome: DisputeOutcome,
+    ) -> Dispute {
+        functions::disputes::resolve_dispute(&env, moderator, dispute_id, outcome)
+    }
+
+    /// Get a dispute, `None` if it doesn't exist.
+    pub fn get_dispute(env: Env, dispute_id: u32) -> Option<Dispute> {
+        functions::disputes::get_dispute(&env, dispute_id)
+    }
+
+    /// Get how a dispute was resolved, `None` while it is unresolved.
+    pub fn get_resolution(env: Env, dispute_id: u32) -> Option<DisputeResolution> {
+        functions::disputes::get_resolution(&env, dispute_id)
+    }
+
+    /// Get the unresolved dispute of a student on a course, if any.
+    pub fn get_open_dispute(env: Env, course_id: String, student: Address) -> Option<Dispute> {
+        functions::disputes::get_open_dispute(&env, course_id, student)
+    }
+
+    /// Get the total refunded to a student for a course through disputes.
+    pub fn get_refunded(env: Env, course_id: String, student: Address) -> i128 {
+        functions::disputes::get_refunded(&env, course_id, student)
+    }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
+}
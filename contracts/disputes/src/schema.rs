@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+/// Maximum length of the reason given when opening a dispute.
+pub const MAX_REASON_LENGTH: u32 = 200;
+
+/// Storage keys of the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Contracts this contract talks to (instance storage)
+    Config,
+    /// Payments contract refunds are made through (instance storage)
+    PaymentsContract,
+    /// Number of disputes opened so far, used as the last dispute ID
+    DisputeCount,
+    /// Dispute by ID: dispute_id -> Dispute
+    Dispute(u32),
+    /// Unresolved dispute of a student on a course: (course_id, student) -> dispute_id
+    OpenDispute(String, Address),
+    /// Resolution of a resolved dispute: dispute_id -> DisputeResolution
+    Resolution(u32),
+    /// Total refunded to a student for a course: (course_id, student) -> i128
+    Refunded(String, Address),
+}
+
+/// Owner and addresses of the contracts used to check roles and apply outcomes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputesConfig {
+    pub owner: Address,
+    pub user_mgmt_addr: Address,
+    pub course_registry_addr: Address,
+    pub course_access_addr: Address,
+}
+
+/// Where a dispute stands.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    /// Waiting for the instructor
+    Open,
+    /// The instructor answered; waiting for a moderator
+    Responded,
+    /// A moderator decided the outcome
+    Resolved,
+}
+
+/// Decision taken by a moderator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeOutcome {
+    /// The dispute is dismissed; nothing happens
+    Rejected,
+    /// The student is refunded this amount of their purchase of the course
+    Refund(i128),
+    /// The student's access to the course is granted again
+    RestoreAccess,
+}
+
+/// A dispute between a student and the instructor of a course.
+///
+/// Evidence and responses live off-chain; only their hashes are stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub id: u32,
+    pub course_id: String,
+    pub student: Address,
+    pub reason: String,
+    pub evidence_hash: BytesN<32>,
+    pub response_hash: Option<BytesN<32>>,
+    pub status: DisputeStatus,
+    pub opened_at: u64,
+    pub resolved_at: Option<u64>,
+}
+
+/// How a dispute was resolved, stored next to the dispute once it is resolved.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolution {
+    pub outcome: DisputeOutcome,
+    pub moderator: Address,
+    pub resolved_at: u64,
+}
+
+/// Permission, enrollment, revocation and purchase types of the user
+/// management, course access and payments contracts, passed to and decoded
+/// from their entrypoints.
+pub use skillcert_interfaces::course_access::{EnrollmentSource, RevocationReason, RevocationRecord};
+pub use skillcert_interfaces::payments::Purchase;
+pub use skillcert_interfaces::user_management::Permission;
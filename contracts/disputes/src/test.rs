@@ -0,0 +1,408 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, Address, BytesN, Env, String, Vec,
+};
+
+use crate::schema::{
+    DisputeOutcome, DisputeStatus, DisputesConfig, EnrollmentSource, Permission, Purchase,
+    RevocationReason, RevocationRecord,
+};
+use crate::{DisputesContract, DisputesContractClient};
+
+#[contract]
+pub struct UserManagement;
+
+#[contractimpl]
+impl UserManagement {
+    pub fn set_moderator(env: Env, moderator: Address) {
+        env.storage().instance().set(&"moderator", &moderator);
+    }
+    pub fn has_permission(env: Env, user: Address, permission: Permission) -> bool {
+        permission == Permission::ModerateContent
+            && env.storage().instance().get::<_, Address>(&"moderator") == Some(user)
+    }
+}
+
+#[contract]
+pub struct CourseRegistry;
+
+#[contractimpl]
+impl CourseRegistry {
+    pub fn set_creator(env: Env, creator: Address) {
+        env.storage().instance().set(&"creator", &creator);
+    }
+    pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+    }
+}
+
+#[contract]
+pub struct CourseAccess;
+
+#[contractimpl]
+impl CourseAccess {
+    pub fn revoke(env: Env, user: Address, reason: RevocationReason) {
+        env.storage().instance().remove(&user);
+        env.storage().instance().set(&(user, "revoked"), &reason);
+    }
+    pub fn has_access(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().has(&user)
+    }
+    pub fn get_revocation_history(
+        env: Env,
+        caller: Address,
+        _course_id: String,
+        user: Address,
+    ) -> Vec<RevocationRecord> {
+        caller.require_auth();
+        let mut history: Vec<RevocationRecord> = Vec::new(&env);
+        if let Some(reason) = env.storage().instance().get(&(user, "revoked")) {
+            history.push_back(RevocationRecord {
+                revoked_at: 0,
+                reason,
+                note_hash: None,
+            });
+        }
+        history
+    }
+    pub fn grant_access_with_source(
+        env: Env,
+        granter: Address,
+        _course_id: String,
+        user: Address,
+        source: EnrollmentSource,
+    ) {
+        granter.require_auth();
+        env.storage().instance().set(&user, &source);
+    }
+}
+
+#[contract]
+pub struct Payments;
+
+#[contractimpl]
+impl Payments {
+    pub fn set_purchase(env: Env, course_id: String, student: Address, price: i128) {
+        let purchase: Purchase = Purchase {
+            id: 1,
+            course_id,
+            student: student.clone(),
+            instructor: student,
+            price,
+            fee: 0,
+            purchased_at: 0,
+            refunded: false,
+            referrer: None,
+            referral_reward: 0,
+        };
+        env.storage().instance().set(&"purchase", &purchase);
+    }
+    pub fn get_course_purchase(
+        env: Env,
+        _course_id: String,
+        _student: Address,
+    ) -> Option<Purchase> {
+        env.storage().instance().get(&"purchase")
+    }
+    pub fn get_refunded_amount(env: Env, _purchase_id: u32) -> i128 {
+        env.storage().instance().get(&"refunded").unwrap_or(0)
+    }
+    pub fn get_refunder(env: Env) -> Option<Address> {
+        env.storage().instance().get(&"refunder")
+    }
+    pub fn refund_purchase_partial(
+        env: Env,
+        refunder: Address,
+        purchase_id: u32,
+        amount: i128,
+    ) -> Purchase {
+        refunder.require_auth();
+        let refunded: i128 = Self::get_refunded_amount(env.clone(), purchase_id) + amount;
+        env.storage().instance().set(&"refunded", &refunded);
+        env.storage().instance().set(&"refunder", &refunder);
+        env.storage().instance().get(&"purchase").unwrap()
+    }
+}
+
+struct Setup<'a> {
+    client: DisputesContractClient<'a>,
+    course_access: CourseAccessClient<'a>,
+    owner: Address,
+    instructor: Address,
+    moderator: Address,
+    student: Address,
+    course_id: String,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let user_mgmt_addr: Address = env.register(UserManagement, ());
+    let course_registry_addr: Address = env.register(CourseRegistry, ());
+    let course_access_addr: Address = env.register(CourseAccess, ());
+    let contract_id: Address = env.register(DisputesContract, ());
+    let client = DisputesContractClient::new(env, &contract_id);
+
+    let owner: Address = Address::generate(env);
+    client.initialize(&DisputesConfig {
+        owner: owner.clone(),
+        user_mgmt_addr: user_mgmt_addr.clone(),
+        course_registry_addr: course_registry_addr.clone(),
+        course_access_addr: course_access_addr.clone(),
+    });
+
+    let instructor: Address = Address::generate(env);
+    let moderator: Address = Address::generate(env);
+    let student: Address = Address::generate(env);
+    let course_id: String = String::from_str(env, "1");
+    let course_access = CourseAccessClient::new(env, &course_access_addr);
+    CourseRegistryClient::new(env, &course_registry_addr).set_creator(&instructor);
+    UserManagementClient::new(env, &user_mgmt_addr).set_moderator(&moderator);
+    course_access.grant_access_with_source(
+        &instructor,
+        &course_id,
+        &student,
+        &EnrollmentSource::Purchase,
+    );
+
+    Setup {
+        client,
+        course_access,
+        owner,
+        instructor,
+        moderator,
+        student,
+        course_id,
+    }
+}
+
+fn setup_refunds<'a>(env: &'a Env, s: &Setup) -> PaymentsClient<'a> {
+    let payments = PaymentsClient::new(env, &env.register(Payments, ()));
+    s.client.set_payments_contract(&s.owner, &payments.address);
+    payments
+}
+
+fn hash(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+#[test]
+fn test_dispute_lifecycle_restores_access() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    s.course_access.revoke(&s.student, &RevocationReason::PolicyViolation);
+
+    let dispute = s.client.open_dispute(
+        &s.student,
+        &s.course_id,
+        &String::from_str(&env, "Access revoked by mistake"),
+        &hash(&env, 1),
+    );
+    assert_eq!(dispute.status, DisputeStatus::Open);
+    assert_eq!(
+        s.client.get_open_dispute(&s.course_id, &s.student),
+        Some(dispute.clone())
+    );
+
+    let responded = s
+        .client
+        .respond_to_dispute(&s.instructor, &dispute.id, &hash(&env, 2));
+    assert_eq!(responded.status, DisputeStatus::Responded);
+    assert_eq!(responded.response_hash, Some(hash(&env, 2)));
+
+    let resolved =
+        s.client
+            .resolve_dispute(&s.moderator, &dispute.id, &DisputeOutcome::RestoreAccess);
+    assert_eq!(resolved.status, DisputeStatus::Resolved);
+    assert!(resolved.resolved_at.is_some());
+    let resolution = s.client.get_resolution(&dispute.id).unwrap();
+    assert_eq!(resolution.outcome, DisputeOutcome::RestoreAccess);
+    assert_eq!(resolution.moderator, s.moderator);
+    assert!(s.course_access.has_access(&s.course_id, &s.student));
+    assert_eq!(s.client.get_open_dispute(&s.course_id, &s.student), None);
+
+    // A new dispute can be opened once the previous one is resolved
+    s.client.open_dispute(
+        &s.student,
+        &s.course_id,
+        &String::from_str(&env, "Again"),
+        &hash(&env, 3),
+    );
+}
+
+#[test]
+fn test_refund_goes_through_payments() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let payments = setup_refunds(&env, &s);
+    payments.set_purchase(&s.course_id, &s.student, &500);
+
+    let dispute = s.client.open_dispute(
+        &s.student,
+        &s.course_id,
+        &String::from_str(&env, "Course never delivered"),
+        &hash(&env, 1),
+    );
+    s.client
+        .resolve_dispute(&s.moderator, &dispute.id, &DisputeOutcome::Refund(400));
+
+    assert_eq!(payments.get_refunded_amount(&1), 400);
+    assert_eq!(payments.get_refunder(), Some(s.client.address.clone()));
+    assert_eq!(s.client.get_refunded(&s.course_id, &s.student), 400);
+}
+
+#[test]
+fn test_refunds_are_capped_at_the_price_paid() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let payments = setup_refunds(&env, &s);
+    // Bought at a discount, below the course list price
+    payments.set_purchase(&s.course_id, &s.student, &300);
+    let reason = String::from_str(&env, "Course never delivered");
+
+    let first = s
+        .client
+        .open_dispute(&s.student, &s.course_id, &reason, &hash(&env, 1));
+    s.client
+        .resolve_dispute(&s.moderator, &first.id, &DisputeOutcome::Refund(200));
+
+    // The student paid 300, so only 100 more can be refunded
+    let second = s
+        .client
+        .open_dispute(&s.student, &s.course_id, &reason, &hash(&env, 2));
+    let result =
+        s.client
+            .try_resolve_dispute(&s.moderator, &second.id, &DisputeOutcome::Refund(101));
+    assert_eq!(
+        result.err(),
+        Some(Ok(crate::error::Error::RefundExceedsPrice.into()))
+    );
+    s.client
+        .resolve_dispute(&s.moderator, &second.id, &DisputeOutcome::Refund(100));
+    assert_eq!(payments.get_refunded_amount(&1), 300);
+}
+
+#[test]
+fn test_refunded_students_cannot_have_access_restored() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let payments = setup_refunds(&env, &s);
+    payments.set_purchase(&s.course_id, &s.student, &500);
+    let reason = String::from_str(&env, "Course never delivered");
+
+    let refunded = s
+        .client
+        .open_dispute(&s.student, &s.course_id, &reason, &hash(&env, 1));
+    s.client
+        .resolve_dispute(&s.moderator, &refunded.id, &DisputeOutcome::Refund(500));
+
+    let dispute = s
+        .client
+        .open_dispute(&s.student, &s.course_id, &reason, &hash(&env, 2));
+    let result =
+        s.client
+            .try_resolve_dispute(&s.moderator, &dispute.id, &DisputeOutcome::RestoreAccess);
+    assert_eq!(
+        result.err(),
+        Some(Ok(crate::error::Error::AlreadyRefunded.into()))
+    );
+}
+
+#[test]
+fn test_students_refunded_by_payments_cannot_have_access_restored() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let reason = String::from_str(&env, "Refunded by mistake");
+
+    // Payments' refund_purchase revokes the access for a refund
+    s.course_access.revoke(&s.student, &RevocationReason::Refund);
+    let dispute = s
+        .client
+        .open_dispute(&s.student, &s.course_id, &reason, &hash(&env, 1));
+    let result =
+        s.client
+            .try_resolve_dispute(&s.moderator, &dispute.id, &DisputeOutcome::RestoreAccess);
+    assert_eq!(
+        result.err(),
+        Some(Ok(crate::error::Error::AlreadyRefunded.into()))
+    );
+    assert!(!s.course_access.has_access(&s.course_id, &s.student));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_refund_requires_a_purchase() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    setup_refunds(&env, &s);
+
+    let dispute = s.client.open_dispute(
+        &s.student,
+        &s.course_id,
+        &String::from_str(&env, "Enrolled for free"),
+        &hash(&env, 1),
+    );
+    s.client
+        .resolve_dispute(&s.moderator, &dispute.id, &DisputeOutcome::Refund(100));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_only_enrolled_students_open_disputes() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.open_dispute(
+        &Address::generate(&env),
+        &s.course_id,
+        &String::from_str(&env, "Reason"),
+        &hash(&env, 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_one_open_dispute_per_course() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let reason = String::from_str(&env, "Reason");
+
+    s.client
+        .open_dispute(&s.student, &s.course_id, &reason, &hash(&env, 1));
+    s.client
+        .open_dispute(&s.student, &s.course_id, &reason, &hash(&env, 2));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_only_moderators_resolve_disputes() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let dispute = s.client.open_dispute(
+        &s.student,
+        &s.course_id,
+        &String::from_str(&env, "Reason"),
+        &hash(&env, 1),
+    );
+
+    s.client
+        .resolve_dispute(&s.instructor, &dispute.id, &DisputeOutcome::Rejected);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_only_the_instructor_responds() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let dispute = s.client.open_dispute(
+        &s.student,
+        &s.course_id,
+        &String::from_str(&env, "Reason"),
+        &hash(&env, 1),
+    );
+
+    s.client
+        .respond_to_dispute(&s.student, &dispute.id, &hash(&env, 2));
+}
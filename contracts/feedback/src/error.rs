@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    EmptyCourseId = 4,
+    NoCourseAccess = 5,
+    FeedbackAlreadySubmitted = 6,
+    InvalidFeedback = 7,
+    TooManyQuestions = 8,
+    InvalidRating = 9,
+    ReviewAlreadySubmitted = 10,
+    ReviewNotFound = 11,
+    AlreadyReported = 12,
+    ReportQueueFull = 13,
+    ReportNotFound = 14,
+    ReportAlreadyResolved = 15,
+    InvalidPagination = 16,
+    AlreadyVoted = 17,
+    CannotVoteOwnReview = 18,
+    PersonhoodNotVerified = 19,
+    InvalidRateLimit = 20,
+    ReviewRateLimitExceeded = 21,
+}
+
+pub fn handle_error(env: &Env, error: Error) -> ! {
+    panic_with_error!(env, error);
+}
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "AlreadyInitialized: The contract is already initialized",
+        2 => "NotInitialized: The contract is not initialized",
+        3 => "Unauthorized: The caller is not allowed to do this",
+        4 => "EmptyCourseId: The course ID is empty",
+        5 => "NoCourseAccess: The user has no access to the course",
+        6 => "FeedbackAlreadySubmitted: The student already answered the survey of the course",
+        7 => "InvalidFeedback: The answers are empty, too many, repeated or out of range",
+        8 => "TooManyQuestions: The survey of the course has too many distinct questions",
+        9 => "InvalidRating: The rating is out of range",
+        10 => "ReviewAlreadySubmitted: The student already reviewed the course",
+        11 => "ReviewNotFound: No review has this ID",
+        12 => "AlreadyReported: The user already reported this content",
+        13 => "ReportQueueFull: Too many reports are waiting for a moderator",
+        14 => "ReportNotFound: No report has this ID",
+        15 => "ReportAlreadyResolved: The report was already resolved",
+        16 => "InvalidPagination: The page parameters are out of range",
+        17 => "AlreadyVoted: The user already voted on this review",
+        18 => "CannotVoteOwnReview: Reviewers can't vote on their own review",
+        19 => "PersonhoodNotVerified: The user has no proof of personhood",
+        20 => "InvalidRateLimit: The window and maximum of a rate limit must be above 0",
+        21 => "ReviewRateLimitExceeded: The student submitted too many reviews; try again later",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! Events are published with the topics `("feedback", "submitted", course_id)`,
+//! `("review", "submitted" | "voted", course_id)` and
+//! `("report", action, report_id)`
+//! and a typed payload whose first field is `version`, following the same
+//! convention as the course registry.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+use crate::schema::{Report, ReportAction, ReportTarget, Review};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const FEEDBACK: Symbol = symbol_short!("feedback");
+
+const REVIEW: Symbol = symbol_short!("review");
+const REPORT: Symbol = symbol_short!("report");
+
+const SUBMITTED: Symbol = symbol_short!("submitted");
+const VOTED: Symbol = symbol_short!("voted");
+const FILED: Symbol = symbol_short!("filed");
+const RESOLVED: Symbol = symbol_short!("resolved");
+
+/// Payload of `("feedback", "submitted", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeedbackSubmittedEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub student: Address,
+    /// Number of questions answered
+    pub answers: u32,
+}
+
+/// Payload of `("review", "submitted", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewSubmittedEvent {
+    pub version: u32,
+    pub review_id: u32,
+    pub course_id: String,
+    pub student: Address,
+    pub rating: u32,
+}
+
+/// Payload of `("review", "voted", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReviewVotedEvent {
+    pub version: u32,
+    pub review_id: u32,
+    pub course_id: String,
+    pub voter: Address,
+    pub helpful: bool,
+}
+
+/// Payload of `("report", "filed", report_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReportFiledEvent {
+    pub version: u32,
+    pub report_id: u32,
+    pub target: ReportTarget,
+    pub reporter: Address,
+    pub reason_code: u32,
+}
+
+/// Payload of `("report", "resolved", report_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReportResolvedEvent {
+    pub version: u32,
+    pub report_id: u32,
+    pub target: ReportTarget,
+    pub moderator: Address,
+    pub action: ReportAction,
+}
+
+pub fn feedback_submitted(env: &Env, course_id: &String, student: &Address, answers: u32) {
+    env.events().publish(
+        (FEEDBACK, SUBMITTED, course_id.clone()),
+        FeedbackSubmittedEvent {
+            version: EVENT_VERSION,
+            course_id: course_id.clone(),
+            student: student.clone(),
+            answers,
+        },
+    );
+}
+
+pub fn review_submitted(env: &Env, review: &Review) {
+    env.events().publish(
+        (REVIEW, SUBMITTED, review.course_id.clone()),
+        ReviewSubmittedEvent {
+            version: EVENT_VERSION,
+            review_id: review.id,
+            course_id: review.course_id.clone(),
+            student: review.student.clone(),
+            rating: review.rating,
+        },
+    );
+}
+
+pub fn review_voted(env: &Env, review: &Review, voter: &Address, helpful: bool) {
+    env.events().publish(
+        (REVIEW, VOTED, review.course_id.clone()),
+        ReviewVotedEvent {
+            version: EVENT_VERSION,
+            review_id: review.id,
+            course_id: review.course_id.clone(),
+            voter: voter.clone(),
+            helpful,
+        },
+    );
+}
+
+pub fn report_filed(env: &Env, report: &Report) {
+    env.events().publish(
+        (REPORT, FILED, report.id),
+        ReportFiledEvent {
+            version: EVENT_VERSION,
+            report_id: report.id,
+            target: report.target.clone(),
+            reporter: report.reporter.clone(),
+            reason_code: report.reason_code,
+        },
+    );
+}
+
+pub fn report_resolved(env: &Env, report: &Report, moderator: &Address, action: &ReportAction) {
+    env.events().publish(
+        (REPORT, RESOLVED, report.id),
+        ReportResolvedEvent {
+            version: EVENT_VERSION,
+            report_id: report.id,
+            target: report.target.clone(),
+            moderator: moderator.clone(),
+            action: action.clone(),
+        },
+    );
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Course completion checks.
+//!
+//! When a progress contract is configured, reviews by students it reports as
+//! having completed the course are marked verified and weigh more in the
+//! weighted course rating.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::clients::try_progress;
+use crate::functions::config::require_admin;
+use crate::schema::DataKey;
+
+const SET_PROGRESS_CONTRACT_EVENT: Symbol = symbol_short!("setProg");
+
+/// Sets the progress contract completion is checked against (admins only).
+pub fn set_progress_contract(env: &Env, admin: Address, progress_contract: Address) {
+    require_admin(env, &admin);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ProgressContract, &progress_contract);
+
+    env.events()
+        .publish((SET_PROGRESS_CONTRACT_EVENT,), (admin, progress_contract));
+}
+
+/// Returns the progress contract completion is checked against, if configured.
+pub fn get_progress_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ProgressContract)
+}
+
+/// Returns whether the configured progress contract reports the student as
+/// having completed the course, `None` without a progress contract.
+pub(crate) fn has_completed(env: &Env, course_id: &String, student: &Address) -> Option<bool> {
+    Some(try_progress(env)?.is_course_completed(student, course_id))
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::clients::{course_access, course_registry, user_management};
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, FeedbackConfig, Permission, PersonhoodFeature};
+
+const INIT_EVENT: Symbol = symbol_short!("init");
+
+/// Stores the owner and the contracts used to check access and roles.
+pub fn initialize(env: &Env, config: FeedbackConfig) {
+    config.owner.require_auth();
+
+    if env.storage().instance().has(&DataKey::Config) {
+        handle_error(env, Error::AlreadyInitialized)
+    }
+    env.storage().instance().set(&DataKey::Config, &config);
+
+    env.events().publish((INIT_EVENT,), config);
+}
+
+/// Returns whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Config)
+}
+
+/// Returns the configuration, panicking with `NotInitialized` before `initialize`.
+pub fn get_config(env: &Env) -> FeedbackConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
+
+/// Returns whether `user` has access to a course in course access.
+pub fn has_course_access(env: &Env, course_id: &String, user: &Address) -> bool {
+    course_access(env).has_access(course_id, user)
+}
+
+/// Panics with `Unauthorized` unless `caller` is the owner or an admin in
+/// user management.
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+    let config: FeedbackConfig = get_config(env);
+    if config.owner == *caller {
+        return;
+    }
+    if !user_management(env).is_admin(caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Panics with `Unauthorized` unless `caller` is the course creator, an
+/// admin in user management or the owner.
+pub fn require_instructor_or_admin(env: &Env, caller: &Address, course_id: &String) {
+    caller.require_auth();
+    let config: FeedbackConfig = get_config(env);
+    if config.owner == *caller {
+        return;
+    }
+    if !(course_registry(env).is_course_creator(course_id, caller)
+        || user_management(env).is_admin(caller))
+    {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Panics with `Unauthorized` unless `moderator` holds the `ModerateContent`
+/// permission in user management.
+pub fn require_moderator(env: &Env, moderator: &Address) {
+    moderator.require_auth();
+    if !user_management(env).has_permission(moderator, &Permission::ModerateContent) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Panics with `PersonhoodNotVerified` when user management requires proof
+/// of personhood for the feature and its verifier doesn't vouch for `user`.
+pub fn require_personhood(env: &Env, user: &Address, feature: PersonhoodFeature) {
+    if !user_management(env).meets_personhood_requirement(user, &feature) {
+        handle_error(env, Error::PersonhoodNotVerified)
+    }
+}
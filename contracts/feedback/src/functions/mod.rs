@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+pub mod completion;
+pub mod config;
+pub mod rate_limit;
+pub mod ratings;
+pub mod reports;
+pub mod reviews;
+pub mod surveys;
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Per-student limit on review submissions.
+//!
+//! Ledgers are grouped in fixed windows of `window_ledgers`. Each student has
+//! one counter per window, kept in temporary storage so it expires on its own
+//! once the window is over. Reviews are only limited once an admin sets a
+//! limit. The limit is checked before a review is stored, so the ratings
+//! reported to the course registry stay in step with the stored reviews.
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::config::require_admin;
+use crate::schema::{DataKey, ReviewRateLimit};
+
+const SET_REVIEW_RATE_LIMIT_EVENT: Symbol = symbol_short!("setRevRL");
+
+/// Returns the review limit, `None` if reviews are not limited.
+pub fn get_review_rate_limit(env: &Env) -> Option<ReviewRateLimit> {
+    env.storage().instance().get(&DataKey::ReviewRateLimit)
+}
+
+/// Sets the review limit, `None` to lift it (admins only).
+pub fn set_review_rate_limit(env: &Env, admin: Address, limit: Option<ReviewRateLimit>) {
+    require_admin(env, &admin);
+
+    match limit {
+        Some(ref limit) => {
+            if limit.window_ledgers == 0 || limit.max_reviews == 0 {
+                handle_error(env, Error::InvalidRateLimit)
+            }
+            env.storage().instance().set(&DataKey::ReviewRateLimit, limit);
+        }
+        None => env.storage().instance().remove(&DataKey::ReviewRateLimit),
+    }
+
+    env.events()
+        .publish((SET_REVIEW_RATE_LIMIT_EVENT,), (admin, limit));
+}
+
+/// Counts one review for `student`, panicking with `ReviewRateLimitExceeded`
+/// if the student already reached the limit in the current window.
+pub(crate) fn check_review_rate_limit(env: &Env, student: &Address) {
+    let Some(limit) = get_review_rate_limit(env) else {
+        return;
+    };
+
+    let window: u32 = env.ledger().sequence() / limit.window_ledgers;
+    let key: DataKey = DataKey::ReviewCounter(student.clone(), window);
+    let count: u32 = env.storage().temporary().get(&key).unwrap_or(0);
+    if count >= limit.max_reviews {
+        handle_error(env, Error::ReviewRateLimitExceeded)
+    }
+
+    env.storage().temporary().set(&key, &(count + 1));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, limit.window_ledgers, limit.window_ledgers);
+}
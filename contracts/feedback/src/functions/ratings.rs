@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Course ratings aggregated from reviews.
+//!
+//! Hidden reviews never count. In the weighted mode a review without any
+//! helpful vote counts `LOW_ENGAGEMENT_WEIGHT` percent, and a review without
+//! `verified_completion` counts `NOT_COMPLETED_WEIGHT` percent; both
+//! discounts combine. Completion is the one recorded when the review was
+//! submitted, so reading the rating makes no cross-contract call; without a
+//! progress contract no review is verified and the discount applies to all.
+
+use soroban_sdk::{Env, String};
+
+use crate::functions::reviews::{course_reviews, get_review};
+use crate::schema::{
+    CourseRating, RatingMode, Review, LOW_ENGAGEMENT_WEIGHT, NOT_COMPLETED_WEIGHT,
+};
+
+/// Weight of a review in percent.
+fn review_weight(review: &Review, mode: &RatingMode) -> u64 {
+    let mut weight: u64 = 100;
+    if *mode == RatingMode::Simple {
+        return weight;
+    }
+    if review.helpful_votes == 0 {
+        weight = weight * LOW_ENGAGEMENT_WEIGHT as u64 / 100;
+    }
+    if !review.verified_completion {
+        weight = weight * NOT_COMPLETED_WEIGHT as u64 / 100;
+    }
+    weight
+}
+
+/// Returns the average rating of the visible reviews of a course.
+pub fn get_course_rating(env: &Env, course_id: String, mode: RatingMode) -> CourseRating {
+    let mut reviews: u32 = 0;
+    let mut weighted_sum: u64 = 0;
+    let mut total_weight: u64 = 0;
+    for review_id in course_reviews(env, &course_id).iter() {
+        let Some(review) = get_review(env, review_id) else {
+            continue;
+        };
+        if review.hidden {
+            continue;
+        }
+        let weight: u64 = review_weight(&review, &mode);
+        reviews += 1;
+        weighted_sum += review.rating as u64 * weight;
+        total_weight += weight;
+    }
+
+    CourseRating {
+        course_id,
+        reviews,
+        average_rating: (weighted_sum * 100).checked_div(total_weight).unwrap_or(0) as u32,
+    }
+}
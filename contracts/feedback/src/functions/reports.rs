@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Content reports and the moderation queue.
+//!
+//! Any user can report a course or a review once; reports wait in a queue
+//! until a moderator (`ModerateContent` permission) resolves them.
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::require_moderator;
+use crate::functions::reviews::{require_review, store_review};
+use crate::schema::{
+    DataKey, PaginatedReports, Report, ReportAction, ReportPagination, ReportStatus, ReportTarget,
+    MAX_OPEN_REPORTS, MAX_PAGE_SIZE,
+};
+
+fn open_reports(env: &Env) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OpenReports)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns a report by ID.
+pub fn get_report(env: &Env, report_id: u32) -> Option<Report> {
+    env.storage().persistent().get(&DataKey::Report(report_id))
+}
+
+fn file_report(
+    env: &Env,
+    reporter: Address,
+    target: ReportTarget,
+    reason_code: u32,
+    details_hash: Option<BytesN<32>>,
+) -> u32 {
+    let filed_key: DataKey = DataKey::ReportFiled(target.clone(), reporter.clone());
+    if env.storage().persistent().has(&filed_key) {
+        handle_error(env, Error::AlreadyReported)
+    }
+    let mut queue: Vec<u32> = open_reports(env);
+    if queue.len() >= MAX_OPEN_REPORTS {
+        handle_error(env, Error::ReportQueueFull)
+    }
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::ReportCount)
+        .unwrap_or(0)
+        + 1;
+    let report: Report = Report {
+        id,
+        target,
+        reporter,
+        reason_code,
+        details_hash,
+        status: ReportStatus::Open,
+        created_at: env.ledger().timestamp(),
+        resolved_by: None,
+        resolved_at: None,
+    };
+    queue.push_back(id);
+
+    env.storage().persistent().set(&DataKey::ReportCount, &id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Report(id), &report);
+    env.storage()
+        .persistent()
+        .set(&DataKey::OpenReports, &queue);
+    env.storage().persistent().set(&filed_key, &id);
+
+    events::report_filed(env, &report);
+
+    id
+}
+
+/// Reports a course for moderation and returns the report ID.
+pub fn report_course(
+    env: &Env,
+    user: Address,
+    course_id: String,
+    reason_code: u32,
+    details_hash: BytesN<32>,
+) -> u32 {
+    user.require_auth();
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    file_report(
+        env,
+        user,
+        ReportTarget::Course(course_id),
+        reason_code,
+        Some(details_hash),
+    )
+}
+
+/// Reports a review for moderation and returns the report ID.
+pub fn report_review(env: &Env, user: Address, review_id: u32, reason_code: u32) -> u32 {
+    user.require_auth();
+    require_review(env, review_id);
+    file_report(
+        env,
+        user,
+        ReportTarget::Review(review_id),
+        reason_code,
+        None,
+    )
+}
+
+/// Returns a page of the unresolved reports, oldest first. Moderators only.
+pub fn list_open_reports(
+    env: &Env,
+    moderator: Address,
+    pagination: ReportPagination,
+) -> PaginatedReports {
+    require_moderator(env, &moderator);
+    if pagination.limit == 0 || pagination.limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidPagination)
+    }
+
+    let queue: Vec<u32> = open_reports(env);
+    let mut data: Vec<Report> = Vec::new(env);
+    let mut next_cursor: Option<u32> = None;
+    let mut has_more: bool = false;
+    for report_id in queue.iter() {
+        if pagination.cursor.is_some_and(|cursor| report_id <= cursor) {
+            continue;
+        }
+        if data.len() == pagination.limit {
+            has_more = true;
+            break;
+        }
+        if let Some(report) = get_report(env, report_id) {
+            next_cursor = Some(report_id);
+            data.push_back(report);
+        }
+    }
+
+    PaginatedReports {
+        data,
+        next_cursor: if has_more { next_cursor } else { None },
+        total_count: queue.len(),
+    }
+}
+
+/// Resolves a report and applies the action. Moderators only.
+///
+/// Removing a reported review hides it. Removing a reported course only
+/// records the decision; the course registry admins act on the event.
+pub fn resolve_report(
+    env: &Env,
+    moderator: Address,
+    report_id: u32,
+    action: ReportAction,
+) -> Report {
+    require_moderator(env, &moderator);
+
+    let mut report: Report =
+        get_report(env, report_id).unwrap_or_else(|| handle_error(env, Error::ReportNotFound));
+    if report.status != ReportStatus::Open {
+        handle_error(env, Error::ReportAlreadyResolved)
+    }
+
+    if let (ReportAction::ContentRemoved, ReportTarget::Review(review_id)) =
+        (&action, &report.target)
+    {
+        let mut review = require_review(env, *review_id);
+        review.hidden = true;
+        store_review(env, &review);
+    }
+
+    report.status = ReportStatus::Resolved(action.clone());
+    report.resolved_by = Some(moderator.clone());
+    report.resolved_at = Some(env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Report(report_id), &report);
+
+    let mut queue: Vec<u32> = open_reports(env);
+    if let Some(index) = queue.first_index_of(report_id) {
+        queue.remove(index);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::OpenReports, &queue);
+
+    events::report_resolved(env, &report, &moderator, &action);
+
+    report
+}
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Course reviews.
+//!
+//! Students with access to a course review it once, with a rating and the
+//! hash of an off-chain text. Reviews by students the progress contract
+//! reports as having completed the course are marked `verified_completion`.
+//! User management can require proof of personhood to review. Other users
+//! vote on whether a review was helpful, which weighs it in the course rating
+//! (see `ratings`). Reviews can be reported and hidden by moderators (see
+//! `reports`). Ratings are also reported to the course statistics of the
+//! course registry, on a best-effort basis.
+
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+use crate::clients::course_registry;
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::completion::has_completed;
+use crate::functions::config::{has_course_access, require_personhood};
+use crate::functions::rate_limit::check_review_rate_limit;
+use crate::schema::{
+    DataKey, PaginatedReviews, PersonhoodFeature, Review, ReviewPagination, MAX_PAGE_SIZE,
+    MAX_REVIEW_RATING,
+};
+
+/// Returns a review by ID.
+pub fn get_review(env: &Env, review_id: u32) -> Option<Review> {
+    env.storage().persistent().get(&DataKey::Review(review_id))
+}
+
+pub(crate) fn require_review(env: &Env, review_id: u32) -> Review {
+    get_review(env, review_id).unwrap_or_else(|| handle_error(env, Error::ReviewNotFound))
+}
+
+pub(crate) fn store_review(env: &Env, review: &Review) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Review(review.id), review);
+}
+
+/// Records the review of a student with access to a course and returns its
+/// ID. Each student reviews a course once.
+pub fn submit_review(
+    env: &Env,
+    student: Address,
+    course_id: String,
+    rating: u32,
+    content_hash: BytesN<32>,
+) -> u32 {
+    student.require_auth();
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if rating == 0 || rating > MAX_REVIEW_RATING {
+        handle_error(env, Error::InvalidRating)
+    }
+    let student_key: DataKey = DataKey::StudentReview(course_id.clone(), student.clone());
+    if env.storage().persistent().has(&student_key) {
+        handle_error(env, Error::ReviewAlreadySubmitted)
+    }
+    if !has_course_access(env, &course_id, &student) {
+        handle_error(env, Error::NoCourseAccess)
+    }
+    require_personhood(env, &student, PersonhoodFeature::ReviewSubmission);
+    check_review_rate_limit(env, &student);
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::ReviewCount)
+        .unwrap_or(0)
+        + 1;
+    let verified_completion: bool = has_completed(env, &course_id, &student) == Some(true);
+    let review: Review = Review {
+        id,
+        course_id: course_id.clone(),
+        student,
+        rating,
+        content_hash,
+        created_at: env.ledger().timestamp(),
+        hidden: false,
+        helpful_votes: 0,
+        unhelpful_votes: 0,
+        verified_completion,
+    };
+
+    let mut reviews: Vec<u32> = course_reviews(env, &course_id);
+    reviews.push_back(id);
+
+    env.storage().persistent().set(&DataKey::ReviewCount, &id);
+    env.storage().persistent().set(&student_key, &id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseReviews(course_id), &reviews);
+    store_review(env, &review);
+    report_rating(env, &review.course_id, rating);
+
+    events::review_submitted(env, &review);
+
+    id
+}
+
+/// Reports a rating to the course registry. The registry only accepts
+/// reports from allowlisted contracts, and a rejected report must not
+/// undo the review.
+fn report_rating(env: &Env, course_id: &String, rating: u32) {
+    let _ = course_registry(env).try_record_rating(
+        &env.current_contract_address(),
+        course_id,
+        &rating,
+    );
+}
+
+/// Returns the IDs of the reviews of a course, oldest first.
+pub(crate) fn course_reviews(env: &Env, course_id: &String) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseReviews(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns a page of the visible reviews of a course, oldest first,
+/// optionally only those with a verified completion.
+pub fn list_course_reviews(
+    env: &Env,
+    course_id: String,
+    only_verified: bool,
+    pagination: ReviewPagination,
+) -> PaginatedReviews {
+    if pagination.limit == 0 || pagination.limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidPagination)
+    }
+
+    let mut data: Vec<Review> = Vec::new(env);
+    let mut next_cursor: Option<u32> = None;
+    let mut has_more: bool = false;
+    for review_id in course_reviews(env, &course_id).iter() {
+        if pagination.cursor.is_some_and(|cursor| review_id <= cursor) {
+            continue;
+        }
+        let Some(review) = get_review(env, review_id) else {
+            continue;
+        };
+        if review.hidden || (only_verified && !review.verified_completion) {
+            continue;
+        }
+        if data.len() == pagination.limit {
+            has_more = true;
+            break;
+        }
+        next_cursor = Some(review_id);
+        data.push_back(review);
+    }
+
+    PaginatedReviews {
+        data,
+        next_cursor: if has_more { next_cursor } else { None },
+    }
+}
+
+/// Records whether a user found a review helpful. Each user votes once per
+/// review, and not on their own review.
+pub fn vote_review_helpful(env: &Env, user: Address, review_id: u32, helpful: bool) -> Review {
+    user.require_auth();
+
+    let mut review: Review = require_review(env, review_id);
+    if review.student == user {
+        handle_error(env, Error::CannotVoteOwnReview)
+    }
+    let vote_key: DataKey = DataKey::HelpfulVote(review_id, user.clone());
+    if env.storage().persistent().has(&vote_key) {
+        handle_error(env, Error::AlreadyVoted)
+    }
+
+    if helpful {
+        review.helpful_votes += 1;
+    } else {
+        review.unhelpful_votes += 1;
+    }
+    env.storage().persistent().set(&vote_key, &helpful);
+    store_review(env, &review);
+
+    events::review_voted(env, &review, &user, helpful);
+
+    review
+}
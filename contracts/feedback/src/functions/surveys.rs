@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Course feedback surveys.
+//!
+//! Question texts live off-chain; answers reference questions by ID. Only
+//! per-question totals are kept, so the summary costs the same however many
+//! students answered.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::{has_course_access, require_instructor_or_admin};
+use crate::schema::{
+    DataKey, FeedbackSummary, QuestionSummary, QuestionTotal, MAX_FEEDBACK_ANSWERS,
+    MAX_FEEDBACK_QUESTIONS, MAX_FEEDBACK_SCORE,
+};
+
+fn question_totals(env: &Env, course_id: &String) -> Vec<QuestionTotal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeedbackTotals(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns whether a student answered the survey of a course.
+pub fn has_submitted_feedback(env: &Env, student: Address, course_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::FeedbackSubmission(course_id, student))
+}
+
+/// Records the survey answers of a student with access to a course, given as
+/// `(question_id, score)` pairs. Each student answers once per course.
+pub fn submit_feedback(env: &Env, student: Address, course_id: String, answers: Vec<(u32, u32)>) {
+    student.require_auth();
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if has_submitted_feedback(env, student.clone(), course_id.clone()) {
+        handle_error(env, Error::FeedbackAlreadySubmitted)
+    }
+    if !has_course_access(env, &course_id, &student) {
+        handle_error(env, Error::NoCourseAccess)
+    }
+    if answers.is_empty() || answers.len() > MAX_FEEDBACK_ANSWERS {
+        handle_error(env, Error::InvalidFeedback)
+    }
+
+    let mut totals: Vec<QuestionTotal> = question_totals(env, &course_id);
+    let mut answered: Vec<u32> = Vec::new(env);
+    for (question_id, score) in answers.iter() {
+        if score == 0 || score > MAX_FEEDBACK_SCORE || answered.contains(question_id) {
+            handle_error(env, Error::InvalidFeedback)
+        }
+        answered.push_back(question_id);
+
+        match totals
+            .iter()
+            .position(|total| total.question_id == question_id)
+        {
+            Some(index) => {
+                let mut total: QuestionTotal = totals.get_unchecked(index as u32);
+                total.score_sum += score as u64;
+                total.responses += 1;
+                totals.set(index as u32, total);
+            }
+            None => {
+                if totals.len() >= MAX_FEEDBACK_QUESTIONS {
+                    handle_error(env, Error::TooManyQuestions)
+                }
+                totals.push_back(QuestionTotal {
+                    question_id,
+                    score_sum: score as u64,
+                    responses: 1,
+                });
+            }
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeedbackTotals(course_id.clone()), &totals);
+    let respondents_key: DataKey = DataKey::FeedbackRespondents(course_id.clone());
+    let respondents: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&respondents_key)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .persistent()
+        .set(&respondents_key, &respondents);
+    env.storage().persistent().set(
+        &DataKey::FeedbackSubmission(course_id.clone(), student.clone()),
+        &env.ledger().timestamp(),
+    );
+
+    events::feedback_submitted(env, &course_id, &student, answers.len());
+}
+
+/// Returns the average score of every answered question of a course (course
+/// creator, admins and the owner only).
+pub fn get_feedback_summary(env: &Env, requester: Address, course_id: String) -> FeedbackSummary {
+    require_instructor_or_admin(env, &requester, &course_id);
+
+    let mut questions: Vec<QuestionSummary> = Vec::new(env);
+    for total in question_totals(env, &course_id).iter() {
+        questions.push_back(QuestionSummary {
+            question_id: total.question_id,
+            responses: total.responses,
+            average_score: (total.score_sum * 100 / total.responses as u64) as u32,
+        });
+    }
+    FeedbackSummary {
+        respondents: env
+            .storage()
+            .persistent()
+            .get(&DataKey::FeedbackRespondents(course_id.clone()))
+            .unwrap_or(0),
+        course_id,
+        questions,
+    }
+}
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+/// Contract version for tracking deployments and upgrades
+pub const VERSION: &str = "1.0.0";
+
+pub mod clients;
+pub mod error;
+pub mod events;
+pub mod functions;
+pub mod schema;
+
+#[cfg(test)]
+mod test;
+
+use crate::schema::{
+    CourseRating, FeedbackConfig, FeedbackSummary, PaginatedReports, PaginatedReviews, RatingMode,
+    Report, ReportAction, ReportPagination, Review, ReviewPagination, ReviewRateLimit,
+};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+
+/// Feedback Contract
+///
+/// This contract collects the survey answers and reviews of students on their
+/// courses, and the reports of content that moderators act on.
+#[contract]
+pub struct FeedbackContract;
+
+#[contractimpl]
+impl FeedbackContract {
+    /// Initialize the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `config` - The owner and the user management, course registry and
+    ///   course access contract addresses
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is already initialized
+    pub fn initialize(env: Env, config: FeedbackConfig) {
+        functions::config::initialize(&env, config)
+    }
+
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::config::is_initialized(&env)
+    }
+
+    /// Get the contract configuration.
+    pub fn get_config(env: Env) -> FeedbackConfig {
+        functions::config::get_config(&env)
+    }
+
+    /// Answer the feedback survey of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - A student with access to the course
+    /// * `course_id` - The course the survey is about
+    /// * `answers` - `(question_id, score)` pairs, scores from 1 to 5, at most 20
+    ///
+    /// # Panics
+    ///
+    /// * If the student has no access to the course or already answered
+    /// * If there are no answers, too many, or a question is answered twice
+    /// * If a score is out of range
+    /// * If the course would have more than 50 distinct questions
+    pub fn submit_feedback(
+        env: Env,
+        student: Address,
+        course_id: String,
+        answers: Vec<(u32, u32)>,
+    ) {
+        functions::surveys::submit_feedback(&env, student, course_id, answers)
+    }
+
+    /// Check whether a student answered the survey of a course.
+    pub fn has_submitted_feedback(env: Env, student: Address, course_id: String) -> bool {
+        functions::surveys::has_submitted_feedback(&env, student, course_id)
+    }
+
+    /// Get the aggregated survey results of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `requester` - The course creator, an admin or the owner
+    /// * `course_id` - The course to summarize
+    ///
+    /// # Returns
+    ///
+    /// The number of respondents and the average score of each question, in
+    /// hundredths.
+    ///
+    /// # Panics
+    ///
+    /// * If the requester is not the course creator, an admin or the owner
+    pub fn get_feedback_summary(
+        env: Env,
+        requester: Address,
+        course_id: String,
+    ) -> FeedbackSummary {
+        functions::surveys::get_feedback_summary(&env, requester, course_id)
+    }
+
+    /// Review a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - A student with access to the course
+    /// * `course_id` - The course to review
+    /// * `rating` - Rating from 1 to 5
+    /// * `content_hash` - Hash of the review text, stored off-chain
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new review.
+    ///
+    /// # Panics
+    ///
+    /// * If the rating is out of range
+    /// * If the student has no access to the course or already reviewed it
+    /// * If user management requires proof of personhood for reviews and the
+    ///   student has none
+    /// * If the student reached the review rate limit (`ReviewRateLimitExceeded`)
+    pub fn submit_review(
+        env: Env,
+        student: Address,
+        course_id: String,
+        rating: u32,
+        content_hash: BytesN<32>,
+    ) -> u32 {
+        functions::reviews::submit_review(&env, student, course_id, rating, content_hash)
+    }
+
+    /// Get a review by ID, `None` if it doesn't exist.
+    pub fn get_review(env: Env, review_id: u32) -> Option<Review> {
+        functions::reviews::get_review(&env, review_id)
+    }
+
+    /// List the visible reviews of a course, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The reviewed course
+    /// * `only_verified` - Only list reviews by students who had completed the
+    ///   course when reviewing it
+    /// * `pagination` - Cursor (last review ID of the previous page) and limit
+    ///
+    /// # Panics
+    ///
+    /// * If the limit is 0 or above 50
+    pub fn list_course_reviews(
+        env: Env,
+        course_id: String,
+        only_verified: bool,
+        pagination: ReviewPagination,
+    ) -> PaginatedReviews {
+        functions::reviews::list_course_reviews(&env, course_id, only_verified, pagination)
+    }
+
+    /// Vote on whether a review was helpful.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The voting user, not the author of the review
+    /// * `review_id` - The review voted on
+    /// * `helpful` - Whether the user found the review helpful
+    ///
+    /// # Returns
+    ///
+    /// The review with its updated vote counts.
+    ///
+    /// # Panics
+    ///
+    /// * If the review doesn't exist
+    /// * If the user wrote the review or already voted on it
+    pub fn vote_review_helpful(env: Env, user: Address, review_id: u32, helpful: bool) -> Review {
+        functions::reviews::vote_review_helpful(&env, user, review_id, helpful)
+    }
+
+    /// Get the average rating of the visible reviews of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The course to rate
+    /// * `mode` - `Simple`, or `Weighted` to discount reviews without helpful
+    ///   votes and reviews not marked `verified_completion`
+    ///
+    /// # Returns
+    ///
+    /// The number of visible reviews and their average rating in hundredths.
+    pub fn get_course_rating(env: Env, course_id: String, mode: RatingMode) -> CourseRating {
+        functions::ratings::get_course_rating(&env, course_id, mode)
+    }
+
+    /// Set the progress contract whose `is_course_completed` verifies the
+    /// completion of reviewers and weighs reviews in the weighted course
+    /// rating (admins only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `progress_contract` - Progress contract applying the course registry's
+    ///   completion criteria
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    pub fn set_progress_contract(env: Env, admin: Address, progress_contract: Address) {
+        functions::completion::set_progress_contract(&env, admin, progress_contract)
+    }
+
+    /// Limit how many reviews a student can submit per window of ledgers
+    /// (admins only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The owner or an admin
+    /// * `limit` - Window length and maximum reviews, `None` to lift the limit
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If the window or the maximum is 0
+    pub fn set_review_rate_limit(env: Env, admin: Address, limit: Option<ReviewRateLimit>) {
+        functions::rate_limit::set_review_rate_limit(&env, admin, limit)
+    }
+
+    /// Get the review rate limit, `None` if reviews are not limited.
+    pub fn get_review_rate_limit(env: Env) -> Option<ReviewRateLimit> {
+        functions::rate_limit::get_review_rate_limit(&env)
+    }
+
+    /// Get the progress contract completion is checked against, `None` if not
+    /// configured.
+    pub fn get_progress_contract(env: Env) -> Option<Address> {
+        functions::completion::get_progress_contract(&env)
+    }
+
+    /// Report a course for moderation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The reporting user
+    /// * `course_id` - The reported course
+    /// * `reason_code` - Why the course is reported, codes are defined off-chain
+    /// * `details_hash` - Hash of the report details, stored off-chain
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new report.
+    ///
+    /// # Panics
+    ///
+    /// * If the user already reported the course
+    /// * If the moderation queue is full
+    pub fn report_course(
+        env: Env,
+        user: Address,
+        course_id: String,
+        reason_code: u32,
+        details_hash: BytesN<32>,
+    ) -> u32 {
+        functions::reports::report_course(&env, user, course_id, reason_code, details_hash)
+    }
+
+    /// Report a review for moderation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The reporting user
+    /// * `review_id` - The reported review
+    /// * `reason_code` - Why the review is reported, codes are defined off-chain
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new report.
+    ///
+    /// # Panics
+    ///
+    /// * If the review doesn't exist or the user already reported it
+    /// * If the moderation queue is full
+    pub fn report_review(env: Env, user: Address, review_id: u32, reason_code: u32) -> u32 {
+        functions::reports::report_review(&env, user, review_id, reason_code)
+    }
+
+    /// Get a report by ID, `None` if it doesn't exist.
+    pub fn get_report(env: Env, report_id: u32) -> Option<Report> {
+        functions::reports::get_report(&env, report_id)
+    }
+
+    /// List the unresolved reports, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `moderator` - A user holding the `ModerateContent` permission
+    /// * `pagination` - Cursor (last report ID of the previous page) and limit
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not a moderator
+    /// * If the limit is 0 or above 50
+    pub fn list_open_reports(
+        env: Env,
+        moderator: Address,
+        pagination: ReportPagination,
+    ) -> PaginatedReports {
+        functions::reports::list_open_reports(&env, moderator, pagination)
+    }
+
+    /// Resolve a report and apply the action.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `moderator` - A user holding the `ModerateContent` permission
+    /// * `report_id` - The report to resolve
+    /// * `action` - `Dismissed`, or `ContentRemoved` which hides a reported
+    ///   review; course removals are applied in the course registry
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not a moderator
+    /// * If the report doesn't exist or is already resolved
+    pub fn resolve_report(
+        env: Env,
+        moderator: Address,
+        report_id: u32,
+        action: ReportAction,
+    ) -> Report {
+        functions::reports::resolve_report(&env, moderator, report_id, action)
+    }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+/// Highest score a survey answer can give, the lowest being 1.
+pub const MAX_FEEDBACK_SCORE: u32 = 5;
+
+/// Maximum number of answers in one survey submission.
+pub const MAX_FEEDBACK_ANSWERS: u32 = 20;
+
+/// Maximum number of distinct questions tracked per course.
+pub const MAX_FEEDBACK_QUESTIONS: u32 = 50;
+
+/// Highest rating a review can give, the lowest being 1.
+pub const MAX_REVIEW_RATING: u32 = 5;
+
+/// Maximum number of reports waiting in the moderation queue.
+pub const MAX_OPEN_REPORTS: u32 = 500;
+
+/// Maximum number of reports or reviews returned by one page.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Weight, in percent, of a review without any helpful vote in the weighted
+/// course rating.
+pub const LOW_ENGAGEMENT_WEIGHT: u32 = 50;
+
+/// Weight, in percent, of a review whose author hasn't completed the course
+/// in the weighted course rating. Combines with `LOW_ENGAGEMENT_WEIGHT`.
+pub const NOT_COMPLETED_WEIGHT: u32 = 50;
+
+/// Storage keys of the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Contracts this contract talks to (instance storage)
+    Config,
+    /// Progress contract course completion is checked against (instance storage)
+    ProgressContract,
+    /// When a student answered the survey of a course: (course_id, student) -> u64
+    FeedbackSubmission(String, Address),
+    /// Number of students who answered the survey of a course: course_id -> u32
+    FeedbackRespondents(String),
+    /// Score totals per question of a course, by first answer: course_id -> Vec<QuestionTotal>
+    FeedbackTotals(String),
+    /// Number of reviews submitted so far, used as the last review ID
+    ReviewCount,
+    /// Review by ID: review_id -> Review
+    Review(u32),
+    /// Review of a student on a course: (course_id, student) -> review_id
+    StudentReview(String, Address),
+    /// IDs of the reviews of a course, oldest first: course_id -> Vec<u32>
+    CourseReviews(String),
+    /// Number of reports filed so far, used as the last report ID
+    ReportCount,
+    /// Report by ID: report_id -> Report
+    Report(u32),
+    /// IDs of the unresolved reports, oldest first: Vec<u32>
+    OpenReports,
+    /// Report filed by a user on some content: (target, reporter) -> report_id
+    ReportFiled(ReportTarget, Address),
+    /// Helpfulness vote of a user on a review: (review_id, voter) -> bool
+    HelpfulVote(u32, Address),
+    /// Limit of reviews per student set by an admin (instance storage)
+    ReviewRateLimit,
+    /// Number of reviews a student submitted in a ledger window (temporary storage):
+    /// (student, window index) -> u32
+    ReviewCounter(Address, u32),
+}
+
+/// Maximum number of reviews a student can submit in a window of ledgers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewRateLimit {
+    pub window_ledgers: u32,
+    pub max_reviews: u32,
+}
+
+/// Owner and addresses of the contracts used to check access and roles.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedbackConfig {
+    pub owner: Address,
+    pub user_mgmt_addr: Address,
+    pub course_registry_addr: Address,
+    pub course_access_addr: Address,
+}
+
+/// Running total of the scores given to one survey question.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuestionTotal {
+    pub question_id: u32,
+    pub score_sum: u64,
+    pub responses: u32,
+}
+
+/// Aggregated answers to one survey question.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuestionSummary {
+    pub question_id: u32,
+    pub responses: u32,
+    /// Average score in hundredths, e.g. 425 for 4.25
+    pub average_score: u32,
+}
+
+/// Aggregated survey results of a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedbackSummary {
+    pub course_id: String,
+    /// Number of students who answered the survey
+    pub respondents: u32,
+    /// One entry per question answered at least once, in order of first answer
+    pub questions: Vec<QuestionSummary>,
+}
+
+/// A student review of a course.
+///
+/// The review text lives off-chain; only its hash is stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Review {
+    pub id: u32,
+    pub course_id: String,
+    pub student: Address,
+    /// Rating from 1 to 5
+    pub rating: u32,
+    pub content_hash: BytesN<32>,
+    pub created_at: u64,
+    /// Set when a moderator removes the review after a report
+    pub hidden: bool,
+    /// Number of users who found the review helpful
+    pub helpful_votes: u32,
+    /// Number of users who found the review unhelpful
+    pub unhelpful_votes: u32,
+    /// Whether the progress contract reported the student as having
+    /// completed the course when the review was submitted
+    pub verified_completion: bool,
+}
+
+/// Cursor-based pagination parameters for review listings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewPagination {
+    /// ID of the last review of the previous page, `None` for the first page
+    pub cursor: Option<u32>,
+    /// Maximum number of reviews to return, at most `MAX_PAGE_SIZE`
+    pub limit: u32,
+}
+
+/// A page of the reviews of a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginatedReviews {
+    pub data: Vec<Review>,
+    /// Cursor of the next page, `None` on the last page
+    pub next_cursor: Option<u32>,
+}
+
+/// How `get_course_rating` aggregates the ratings of a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RatingMode {
+    /// Every visible review counts the same
+    Simple,
+    /// Reviews without helpful votes and reviews by students who hadn't
+    /// completed the course when reviewing count less
+    Weighted,
+}
+
+/// Aggregated rating of a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseRating {
+    pub course_id: String,
+    /// Number of visible reviews
+    pub reviews: u32,
+    /// Average rating in hundredths (450 = 4.50), 0 without reviews
+    pub average_rating: u32,
+}
+
+/// Content a report is about.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReportTarget {
+    Course(String),
+    Review(u32),
+}
+
+/// Decision taken by a moderator on a report.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReportAction {
+    /// The report is dismissed; nothing happens
+    Dismissed,
+    /// The content is removed: reviews are hidden, course removals are
+    /// applied by the course registry admins from the resolution event
+    ContentRemoved,
+}
+
+/// Where a report stands.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReportStatus {
+    Open,
+    Resolved(ReportAction),
+}
+
+/// A report of content for moderation.
+///
+/// Reason codes are defined off-chain; details live off-chain and only their
+/// hash is stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    pub id: u32,
+    pub target: ReportTarget,
+    pub reporter: Address,
+    pub reason_code: u32,
+    pub details_hash: Option<BytesN<32>>,
+    pub status: ReportStatus,
+    pub created_at: u64,
+    pub resolved_by: Option<Address>,
+    pub resolved_at: Option<u64>,
+}
+
+/// Cursor-based pagination parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportPagination {
+    /// ID of the last report of the previous page, `None` for the first page
+    pub cursor: Option<u32>,
+    /// Maximum number of reports to return, at most `MAX_PAGE_SIZE`
+    pub limit: u32,
+}
+
+/// A page of the moderation queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginatedReports {
+    pub data: Vec<Report>,
+    /// Cursor of the next page, `None` on the last page
+    pub next_cursor: Option<u32>,
+    /// Number of open reports in the whole queue
+    pub total_count: u32,
+}
+
+/// Permission and personhood types of the user management contract, passed
+/// to `has_permission` and `meets_personhood_requirement`.
+pub use skillcert_interfaces::user_management::{Permission, PersonhoodFeature};
@@ -0,0 +1,450 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, String,
+};
+
+use crate::error::Error;
+use crate::schema::{
+    FeedbackConfig, Permission, PersonhoodFeature, QuestionSummary, RatingMode, ReportAction,
+    ReportPagination, ReportStatus, ReportTarget, ReviewPagination, ReviewRateLimit,
+};
+use crate::{FeedbackContract, FeedbackContractClient};
+
+#[contract]
+pub struct UserManagement;
+
+#[contractimpl]
+impl UserManagement {
+    pub fn is_admin(_env: Env, _who: Address) -> bool {
+        false
+    }
+    pub fn set_moderator(env: Env, moderator: Address) {
+        env.storage().instance().set(&moderator, &true);
+    }
+    pub fn has_permission(env: Env, user: Address, permission: Permission) -> bool {
+        permission == Permission::ModerateContent && env.storage().instance().has(&user)
+    }
+    pub fn set_unverified(env: Env, user: Address) {
+        env.storage().instance().set(&("unverified", user), &true);
+    }
+    pub fn meets_personhood_requirement(
+        env: Env,
+        user: Address,
+        _feature: PersonhoodFeature,
+    ) -> bool {
+        !env.storage().instance().has(&("unverified", user))
+    }
+}
+
+#[contract]
+pub struct CourseRegistry;
+
+#[contractimpl]
+impl CourseRegistry {
+    pub fn set_creator(env: Env, creator: Address) {
+        env.storage().instance().set(&"creator", &creator);
+    }
+    pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().get::<_, Address>(&"creator") == Some(user)
+    }
+    pub fn record_rating(env: Env, _reporter: Address, _course_id: String, rating: u32) {
+        let (sum, count): (u32, u32) = Self::get_rating(env.clone());
+        env.storage()
+            .instance()
+            .set(&"rating", &(sum + rating, count + 1));
+    }
+    pub fn get_rating(env: Env) -> (u32, u32) {
+        env.storage().instance().get(&"rating").unwrap_or((0, 0))
+    }
+}
+
+#[contract]
+pub struct CourseAccess;
+
+#[contractimpl]
+impl CourseAccess {
+    pub fn grant(env: Env, user: Address) {
+        env.storage().instance().set(&user, &true);
+    }
+    pub fn has_access(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().has(&user)
+    }
+}
+
+#[contract]
+pub struct Progress;
+
+#[contractimpl]
+impl Progress {
+    pub fn set_completed(env: Env, user: Address) {
+        env.storage().instance().set(&user, &true);
+    }
+    pub fn is_course_completed(env: Env, user: Address, _course_id: String) -> bool {
+        env.storage().instance().has(&user)
+    }
+}
+
+struct Setup<'a> {
+    client: FeedbackContractClient<'a>,
+    user_mgmt: UserManagementClient<'a>,
+    course_registry: CourseRegistryClient<'a>,
+    course_access: CourseAccessClient<'a>,
+    owner: Address,
+    instructor: Address,
+    course_id: String,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let user_mgmt_addr: Address = env.register(UserManagement, ());
+    let course_registry_addr: Address = env.register(CourseRegistry, ());
+    let course_access_addr: Address = env.register(CourseAccess, ());
+    let client = FeedbackContractClient::new(env, &env.register(FeedbackContract, ()));
+    let owner: Address = Address::generate(env);
+    client.initialize(&FeedbackConfig {
+        owner: owner.clone(),
+        user_mgmt_addr: user_mgmt_addr.clone(),
+        course_registry_addr: course_registry_addr.clone(),
+        course_access_addr: course_access_addr.clone(),
+    });
+
+    let instructor: Address = Address::generate(env);
+    let course_registry = CourseRegistryClient::new(env, &course_registry_addr);
+    course_registry.set_creator(&instructor);
+    Setup {
+        client,
+        user_mgmt: UserManagementClient::new(env, &user_mgmt_addr),
+        course_registry,
+        course_access: CourseAccessClient::new(env, &course_access_addr),
+        owner,
+        instructor,
+        course_id: String::from_str(env, "1"),
+    }
+}
+
+#[test]
+fn test_feedback_is_aggregated_per_question() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let first: Address = Address::generate(&env);
+    let second: Address = Address::generate(&env);
+    s.course_access.grant(&first);
+    s.course_access.grant(&second);
+
+    s.client
+        .submit_feedback(&first, &s.course_id, &vec![&env, (1, 5), (2, 3)]);
+    s.client
+        .submit_feedback(&second, &s.course_id, &vec![&env, (1, 4)]);
+    assert!(s.client.has_submitted_feedback(&first, &s.course_id));
+
+    let summary = s.client.get_feedback_summary(&s.instructor, &s.course_id);
+    assert_eq!(summary.respondents, 2);
+    assert_eq!(
+        summary.questions,
+        vec![
+            &env,
+            QuestionSummary {
+                question_id: 1,
+                responses: 2,
+                average_score: 450,
+            },
+            QuestionSummary {
+                question_id: 2,
+                responses: 1,
+                average_score: 300,
+            },
+        ]
+    );
+
+    // Students can't read the summary
+    assert!(s
+        .client
+        .try_get_feedback_summary(&first, &s.course_id)
+        .is_err());
+}
+
+#[test]
+fn test_feedback_is_limited_to_enrolled_students_once() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let student: Address = Address::generate(&env);
+    let answers = vec![&env, (1_u32, 5_u32)];
+
+    assert!(s
+        .client
+        .try_submit_feedback(&student, &s.course_id, &answers)
+        .is_err());
+    s.course_access.grant(&student);
+
+    // Out-of-range scores and repeated questions are rejected
+    assert!(s
+        .client
+        .try_submit_feedback(&student, &s.course_id, &vec![&env, (1, 6)])
+        .is_err());
+    assert!(s
+        .client
+        .try_submit_feedback(&student, &s.course_id, &vec![&env, (1, 2), (1, 3)])
+        .is_err());
+
+    s.client.submit_feedback(&student, &s.course_id, &answers);
+    assert!(s
+        .client
+        .try_submit_feedback(&student, &s.course_id, &answers)
+        .is_err());
+}
+
+#[test]
+fn test_reported_content_is_queued_and_resolved_by_moderators() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let student: Address = Address::generate(&env);
+    let reporter: Address = Address::generate(&env);
+    let moderator: Address = Address::generate(&env);
+    s.course_access.grant(&student);
+    s.user_mgmt.set_moderator(&moderator);
+    let hash: BytesN<32> = BytesN::from_array(&env, &[1; 32]);
+
+    let review_id: u32 = s.client.submit_review(&student, &s.course_id, &4, &hash);
+    let review_report: u32 = s.client.report_review(&reporter, &review_id, &2);
+    let course_report: u32 = s.client.report_course(&reporter, &s.course_id, &1, &hash);
+    assert!(s
+        .client
+        .try_report_review(&reporter, &review_id, &3)
+        .is_err());
+
+    // Only moderators see the queue
+    let first_page: ReportPagination = ReportPagination {
+        cursor: None,
+        limit: 1,
+    };
+    assert!(s
+        .client
+        .try_list_open_reports(&reporter, &first_page)
+        .is_err());
+    let page = s.client.list_open_reports(&moderator, &first_page);
+    assert_eq!(page.total_count, 2);
+    assert_eq!(
+        page.data.get_unchecked(0).target,
+        ReportTarget::Review(review_id)
+    );
+    let page = s.client.list_open_reports(
+        &moderator,
+        &ReportPagination {
+            cursor: page.next_cursor,
+            limit: 1,
+        },
+    );
+    assert_eq!(page.data.get_unchecked(0).id, course_report);
+    assert_eq!(page.next_cursor, None);
+
+    // Removing a reported review hides it and takes the report off the queue
+    assert!(s
+        .client
+        .try_resolve_report(&reporter, &review_report, &ReportAction::ContentRemoved)
+        .is_err());
+    let report = s
+        .client
+        .resolve_report(&moderator, &review_report, &ReportAction::ContentRemoved);
+    assert_eq!(
+        report.status,
+        ReportStatus::Resolved(ReportAction::ContentRemoved)
+    );
+    assert!(s.client.get_review(&review_id).unwrap().hidden);
+    assert!(s
+        .client
+        .try_resolve_report(&moderator, &review_report, &ReportAction::Dismissed)
+        .is_err());
+    assert_eq!(
+        s.client
+            .list_open_reports(&moderator, &first_page)
+            .total_count,
+        1
+    );
+}
+
+#[test]
+fn test_weighted_rating_discounts_unhelpful_and_uncompleted_reviews() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let finisher: Address = Address::generate(&env);
+    let dropout: Address = Address::generate(&env);
+    let voter: Address = Address::generate(&env);
+    s.course_access.grant(&finisher);
+    s.course_access.grant(&dropout);
+    let progress = ProgressClient::new(&env, &env.register(Progress, ()));
+    progress.set_completed(&finisher);
+    s.client.set_progress_contract(&s.owner, &progress.address);
+    let hash: BytesN<32> = BytesN::from_array(&env, &[1; 32]);
+
+    let praised: u32 = s.client.submit_review(&finisher, &s.course_id, &5, &hash);
+    s.client.submit_review(&dropout, &s.course_id, &1, &hash);
+
+    // Reviews can require proof of personhood
+    let unverified: Address = Address::generate(&env);
+    s.course_access.grant(&unverified);
+    s.user_mgmt.set_unverified(&unverified);
+    assert!(s
+        .client
+        .try_submit_review(&unverified, &s.course_id, &5, &hash)
+        .is_err());
+
+    let review = s.client.vote_review_helpful(&voter, &praised, &true);
+    assert_eq!(review.helpful_votes, 1);
+    assert!(s
+        .client
+        .try_vote_review_helpful(&voter, &praised, &false)
+        .is_err());
+    assert!(s
+        .client
+        .try_vote_review_helpful(&finisher, &praised, &true)
+        .is_err());
+
+    let simple = s
+        .client
+        .get_course_rating(&s.course_id, &RatingMode::Simple);
+    assert_eq!(simple.reviews, 2);
+    assert_eq!(simple.average_rating, 300);
+    assert_eq!(s.course_registry.get_rating(), (6, 2));
+
+    // The dropout's review counts 25%: (5 * 100 + 1 * 25) / 125 = 4.20
+    let weighted = s
+        .client
+        .get_course_rating(&s.course_id, &RatingMode::Weighted);
+    assert_eq!(weighted.average_rating, 420);
+
+    // Completion is the one recorded with the review, not re-checked on read
+    progress.set_completed(&dropout);
+    let weighted = s
+        .client
+        .get_course_rating(&s.course_id, &RatingMode::Weighted);
+    assert_eq!(weighted.average_rating, 420);
+}
+
+#[test]
+fn test_reviews_are_rate_limited_per_student() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let student: Address = Address::generate(&env);
+    let other: Address = Address::generate(&env);
+    s.course_access.grant(&student);
+    s.course_access.grant(&other);
+    let hash: BytesN<32> = BytesN::from_array(&env, &[1; 32]);
+    let second_course: String = String::from_str(&env, "2");
+
+    assert_eq!(s.client.get_review_rate_limit(), None);
+    let limit = ReviewRateLimit {
+        window_ledgers: 100,
+        max_reviews: 1,
+    };
+    s.client.set_review_rate_limit(&s.owner, &Some(limit.clone()));
+    assert_eq!(s.client.get_review_rate_limit(), Some(limit));
+
+    s.client.submit_review(&student, &s.course_id, &5, &hash);
+    assert_eq!(
+        s.client.try_submit_review(&student, &second_course, &4, &hash),
+        Err(Ok(Error::ReviewRateLimitExceeded.into()))
+    );
+
+    // Other students have their own counter
+    s.client.submit_review(&other, &second_course, &3, &hash);
+
+    // A new window starts with a fresh counter
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 100);
+    s.client.submit_review(&student, &second_course, &4, &hash);
+
+    // Every stored review was reported to the course registry
+    assert_eq!(s.course_registry.get_rating(), (12, 3));
+
+    s.client.set_review_rate_limit(&s.owner, &None);
+    assert_eq!(s.client.get_review_rate_limit(), None);
+    assert_eq!(
+        s.client.try_set_review_rate_limit(
+            &s.owner,
+            &Some(ReviewRateLimit {
+                window_ledgers: 0,
+                max_reviews: 1,
+            })
+        ),
+        Err(Ok(Error::InvalidRateLimit.into()))
+    );
+}
+
+#[test]
+fn test_list_course_reviews_filters_verified_and_pages_by_cursor() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let progress = ProgressClient::new(&env, &env.register(Progress, ()));
+    s.client.set_progress_contract(&s.owner, &progress.address);
+    let hash: BytesN<32> = BytesN::from_array(&env, &[1; 32]);
+
+    let first_finisher: Address = Address::generate(&env);
+    let dropout: Address = Address::generate(&env);
+    let second_finisher: Address = Address::generate(&env);
+    for student in [&first_finisher, &dropout, &second_finisher] {
+        s.course_access.grant(student);
+    }
+    progress.set_completed(&first_finisher);
+    progress.set_completed(&second_finisher);
+    let first: u32 = s
+        .client
+        .submit_review(&first_finisher, &s.course_id, &5, &hash);
+    let dropped: u32 = s.client.submit_review(&dropout, &s.course_id, &1, &hash);
+    let second: u32 = s
+        .client
+        .submit_review(&second_finisher, &s.course_id, &4, &hash);
+
+    // All reviews, two per page
+    let page = s.client.list_course_reviews(
+        &s.course_id,
+        &false,
+        &ReviewPagination {
+            cursor: None,
+            limit: 2,
+        },
+    );
+    assert_eq!(page.data.len(), 2);
+    assert_eq!(page.data.get_unchecked(1).id, dropped);
+    assert_eq!(page.next_cursor, Some(dropped));
+    let page = s.client.list_course_reviews(
+        &s.course_id,
+        &false,
+        &ReviewPagination {
+            cursor: Some(dropped),
+            limit: 2,
+        },
+    );
+    assert_eq!(page.data.len(), 1);
+    assert_eq!(page.data.get_unchecked(0).id, second);
+    assert_eq!(page.next_cursor, None);
+
+    // Verified reviews only, one per page; the dropout's review is skipped
+    let page = s.client.list_course_reviews(
+        &s.course_id,
+        &true,
+        &ReviewPagination {
+            cursor: None,
+            limit: 1,
+        },
+    );
+    assert_eq!(page.data.len(), 1);
+    assert_eq!(page.data.get_unchecked(0).id, first);
+    assert!(page.data.get_unchecked(0).verified_completion);
+    assert_eq!(page.next_cursor, Some(first));
+    let page = s.client.list_course_reviews(
+        &s.course_id,
+        &true,
+        &ReviewPagination {
+            cursor: Some(first),
+            limit: 1,
+        },
+    );
+    assert_eq!(page.data.len(), 1);
+    assert_eq!(page.data.get_unchecked(0).id, second);
+    assert!(page.data.get_unchecked(0).verified_completion);
+    assert_eq!(page.next_cursor, None);
+}
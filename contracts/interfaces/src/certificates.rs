@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Certificates client.
+
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, String, Vec};
+
+/// A completion certificate as returned by `list_certificates_by_student`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Certificate {
+    pub id: u32,
+    pub course_id: String,
+    pub student: Address,
+    pub issuer: Address,
+    pub issued_at: u64,
+    pub issued_ledger: u32,
+    pub course_hash: BytesN<32>,
+    pub valid_until: Option<u64>,
+    pub revoked: bool,
+    pub template_uri_hash: Option<BytesN<32>>,
+    pub metadata_fields: Vec<String>,
+}
+
+/// Pagination parameters of the certificate listings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificatePagination {
+    pub cursor: Option<u32>,
+    pub limit: u32,
+}
+
+/// A page of certificates.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginatedCertificates {
+    pub data: Vec<Certificate>,
+    pub next_cursor: Option<u32>,
+    pub total_count: u32,
+    pub has_more: bool,
+}
+
+/// Certificates methods called from other contracts.
+#[contractclient(name = "CertificatesClient")]
+pub trait CertificatesInterface {
+    fn list_certificates_by_student(
+        env: Env,
+        student: Address,
+        pagination: CertificatePagination,
+    ) -> PaginatedCertificates;
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Course access client.
+
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, String, Vec};
+
+/// How a user obtained access to a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnrollmentSource {
+    Purchase,
+    Manual,
+    Transfer,
+    Promotion,
+    SelfEnroll,
+    Organization,
+    TokenGate,
+}
+
+/// Why a user's access to a course was revoked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RevocationReason {
+    Unspecified,
+    Refund,
+    PolicyViolation,
+    Fraud,
+    Expired,
+    Other,
+}
+
+/// One revocation of a user's access, as returned by `get_revocation_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevocationRecord {
+    pub revoked_at: u64,
+    pub reason: RevocationReason,
+    pub note_hash: Option<BytesN<32>>,
+}
+
+/// Courses a user has access to, as returned by `list_user_courses`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserCourses {
+    pub user: Address,
+    pub courses: Vec<String>,
+}
+
+/// Course access methods called from other contracts.
+#[contractclient(name = "CourseAccessClient")]
+pub trait CourseAccessInterface {
+    fn has_access(env: Env, course_id: String, user: Address) -> bool;
+    fn grant_access_with_source(
+        env: Env,
+        granter: Address,
+        course_id: String,
+        user: Address,
+        source: EnrollmentSource,
+    );
+    fn revoke_access(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        reason: Option<RevocationReason>,
+        note_hash: Option<BytesN<32>>,
+    ) -> bool;
+    fn get_revocation_history(
+        env: Env,
+        admin: Address,
+        course_id: String,
+        user: Address,
+    ) -> Vec<RevocationRecord>;
+    fn list_user_courses(env: Env, user: Address) -> UserCourses;
+    fn get_course_access_count(env: Env, course_id: String) -> u32;
+    fn get_unlocked_modules(env: Env, student: Address, course_id: String) -> Vec<String>;
+}
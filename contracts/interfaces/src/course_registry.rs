@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Course registry client.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Vec};
+
+/// Reference to a prerequisite course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseId {
+    pub id: String,
+    pub count: u128,
+}
+
+/// A course as returned by `get_course`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Course {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub creator: Address,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub published: bool,
+    pub prerequisites: Vec<CourseId>,
+    pub is_archived: bool,
+    /// "Beginner", "Intermediate" or "Advanced"
+    pub level: Option<String>,
+    pub duration_hours: Option<u32>,
+}
+
+/// Running counters for a course.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CourseStats {
+    pub enrollments: u32,
+    pub completions: u32,
+    /// Sum of all purchase amounts
+    pub revenue: u128,
+}
+
+/// Platform-wide counters, as returned by the `record_*` platform reports.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlatformStats {
+    pub total_courses: u32,
+    pub published_courses: u32,
+    pub total_users: u32,
+    pub total_enrollments: u32,
+    pub total_certificates: u32,
+}
+
+/// A module as returned by `list_modules`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseModule {
+    pub id: String,
+    pub course_id: String,
+    pub position: u32,
+    pub title: String,
+    pub created_at: u64,
+}
+
+/// When a scheduled module unlocks for a student.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleaseTime {
+    /// Seconds after the student's enrollment
+    AfterEnrollment(u64),
+    /// Ledger timestamp, the same for every student
+    At(u64),
+}
+
+/// Release time of one module, as returned by `get_module_release_schedule`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleRelease {
+    pub module_id: String,
+    pub release: ReleaseTime,
+}
+
+/// What a student must achieve for a course to count as completed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionCriteria {
+    /// Modules to complete; empty means every module of the course
+    pub required_modules: Vec<String>,
+    pub min_quiz_score: Option<u32>,
+    pub require_assignments: bool,
+    pub min_sessions_attended: Option<u32>,
+}
+
+/// Course registry methods called from other contracts.
+#[contractclient(name = "CourseRegistryClient")]
+pub trait CourseRegistryInterface {
+    fn get_course(env: Env, course_id: String) -> Course;
+    fn course_exists(env: Env, course_id: String) -> bool;
+    fn is_course_creator(env: Env, course_id: String, user: Address) -> bool;
+    fn get_prerequisites(env: Env, course_id: String, transitive: bool) -> Vec<String>;
+    fn is_self_enrollable(env: Env, course_id: String) -> bool;
+    fn list_modules(env: Env, course_id: String) -> Vec<CourseModule>;
+    fn get_module_prerequisites(env: Env, module_id: String) -> Vec<String>;
+    fn get_module_release_schedule(env: Env, course_id: String) -> Vec<ModuleRelease>;
+    fn get_completion_criteria(env: Env, course_id: String) -> CompletionCriteria;
+    fn get_course_stats(env: Env, course_id: String) -> CourseStats;
+    fn record_enrollment(env: Env, reporter: Address, course_id: String) -> CourseStats;
+    fn record_completion(env: Env, reporter: Address, course_id: String) -> CourseStats;
+    fn record_purchase(env: Env, reporter: Address, course_id: String, amount: u128)
+        -> CourseStats;
+    fn record_rating(env: Env, reporter: Address, course_id: String, rating: u32);
+    fn record_user_registered(env: Env, reporter: Address) -> PlatformStats;
+    fn record_certificate_issued(env: Env, reporter: Address) -> PlatformStats;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _};
+
+    #[contract]
+    struct MockCourseRegistry;
+
+    #[contractimpl]
+    impl MockCourseRegistry {
+        pub fn get_course(env: Env, course_id: String) -> Course {
+            Course {
+                id: course_id,
+                title: String::from_str(&env, "Rust"),
+                description: String::from_str(&env, "Intro"),
+                creator: env.current_contract_address(),
+                price: 100,
+                category: None,
+                language: None,
+                thumbnail_url: None,
+                published: true,
+                prerequisites: Vec::new(&env),
+                is_archived: false,
+                level: Some(String::from_str(&env, "Beginner")),
+                duration_hours: Some(4),
+            }
+        }
+
+        pub fn is_course_creator(_env: Env, _course_id: String, _user: Address) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_client_decodes_course() {
+        let env = Env::default();
+        let address = env.register(MockCourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &address);
+        let course_id = String::from_str(&env, "1");
+
+        let course = client.get_course(&course_id);
+        assert_eq!(course.id, course_id);
+        assert_eq!(course.creator, address);
+        assert_eq!(course.level, Some(String::from_str(&env, "Beginner")));
+        assert!(client.is_course_creator(&course_id, &Address::generate(&env)));
+        // Methods the mock doesn't implement fail instead of decoding garbage
+        assert!(client.try_course_exists(&course_id).is_err());
+    }
+}
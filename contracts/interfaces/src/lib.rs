@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+//! Typed clients for calling the SkillCert contracts.
+//!
+//! Each module holds a `contractclient` trait listing the methods other
+//! contracts and off-chain tools call, along with the contract types those
+//! methods take or return. The types encode the same way as the contracts'
+//! own, so a client built here can talk to the deployed contract directly.
+//!
+//! Bump [`VERSION`] whenever a signature or type in this crate changes.
+
+pub mod certificates;
+pub mod course_access;
+pub mod course_registry;
+pub mod payments;
+pub mod personhood;
+pub mod progress;
+pub mod user_management;
+pub mod user_profile;
+
+/// Version of the interfaces described by this crate.
+pub const VERSION: &str = "0.3.0";
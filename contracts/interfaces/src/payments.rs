@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Payments client.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String};
+
+/// A course purchase as returned by `get_course_purchase`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Purchase {
+    pub id: u32,
+    pub course_id: String,
+    pub student: Address,
+    pub instructor: Address,
+    pub price: i128,
+    pub fee: i128,
+    pub purchased_at: u64,
+    pub refunded: bool,
+    pub referrer: Option<Address>,
+    pub referral_reward: i128,
+}
+
+/// Payments methods called from other contracts.
+#[contractclient(name = "PaymentsClient")]
+pub trait PaymentsInterface {
+    fn get_course_purchase(env: Env, course_id: String, student: Address) -> Option<Purchase>;
+    fn get_refunded_amount(env: Env, purchase_id: u32) -> i128;
+    fn refund_purchase_partial(
+        env: Env,
+        refunder: Address,
+        purchase_id: u32,
+        amount: i128,
+    ) -> Purchase;
+}
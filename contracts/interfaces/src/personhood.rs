@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Proof-of-personhood verifier client.
+//!
+//! The verifier is an external contract an admin registers with user
+//! management; only the method SkillCert calls on it is listed here.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Methods a personhood verifier must answer.
+#[contractclient(name = "PersonhoodVerifierClient")]
+pub trait PersonhoodVerifierInterface {
+    fn is_verified(env: Env, user: Address) -> bool;
+}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Progress client.
+
+use soroban_sdk::{contractclient, Address, Env, String};
+
+/// Progress methods called from other contracts.
+#[contractclient(name = "ProgressClient")]
+pub trait ProgressInterface {
+    fn is_course_completed(env: Env, user: Address, course_id: String) -> bool;
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! User management client.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Symbol, Vec};
+
+/// Link to a user's account on another platform.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SocialLink {
+    pub platform: String,
+    pub url: String,
+}
+
+/// A user profile as stored by the user management contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserProfile {
+    pub full_name: String,
+    pub contact_email: String,
+    pub profession: Option<String>,
+    pub country: Option<String>,
+    pub purpose: Option<String>,
+    pub profile_picture_url: Option<String>,
+}
+
+/// Fields to change with `edit_user_profile`; `None` fields are left unchanged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfileUpdateParams {
+    pub full_name: Option<String>,
+    pub contact_email: Option<String>,
+    pub profession: Option<String>,
+    pub country: Option<String>,
+    pub purpose: Option<String>,
+    pub profile_picture_url: Option<String>,
+    pub social_links: Option<Vec<SocialLink>>,
+}
+
+/// Granular permissions of the RBAC module, passed to `has_permission`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Permission {
+    ViewUsers,
+    EditUsers,
+    DeleteUsers,
+    CreateUsers,
+    ViewCourses,
+    CreateCourses,
+    EditCourses,
+    DeleteCourses,
+    ManageCourseAccess,
+    ManageSystem,
+    ManageAdmins,
+    ViewAnalytics,
+    ModerateContent,
+    ProvideSupport,
+    ViewSupport,
+}
+
+/// Features that can require proof of personhood, passed to
+/// `meets_personhood_requirement`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PersonhoodFeature {
+    ProfileCreation,
+    ReviewSubmission,
+}
+
+/// User management methods called from other contracts.
+#[contractclient(name = "UserManagementClient")]
+pub trait UserManagementInterface {
+    fn is_admin(env: Env, who: Address) -> bool;
+    fn has_permission(env: Env, user: Address, permission: Permission) -> bool;
+    fn meets_personhood_requirement(env: Env, user: Address, feature: PersonhoodFeature) -> bool;
+    fn resolve_identity(env: Env, address: Address) -> Address;
+    fn is_org_admin(env: Env, org_id: u32, who: Address) -> bool;
+    fn is_org_member(env: Env, org_id: u32, who: Address) -> bool;
+    fn get_org_seats(env: Env, org_id: u32, course_id: String) -> u32;
+    fn get_user_by_id(env: Env, requester: Address, user_id: Address) -> UserProfile;
+    fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> UserProfile;
+    fn edit_user_profile(
+        env: Env,
+        caller: Address,
+        user_id: Address,
+        updates: ProfileUpdateParams,
+    ) -> UserProfile;
+    fn award_points(env: Env, caller: Address, user: Address, amount: u64, reason: Symbol) -> u64;
+    fn get_user_points(env: Env, user: Address) -> u64;
+    fn record_course_completion(env: Env, caller: Address, user: Address) -> u32;
+}
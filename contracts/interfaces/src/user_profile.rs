@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! User profile client.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String};
+
+/// A profile as returned by `get_user_profile`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserProfile {
+    pub address: Address,
+    pub name: String,
+    /// Hidden from other users unless the owner's privacy settings allow it
+    pub email: Option<String>,
+    pub country: String,
+    pub profession: String,
+    pub goals: String,
+    pub privacy_public: bool,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Which profile fields other users may see.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivacySettings {
+    pub show_email: bool,
+    pub show_country: bool,
+    pub show_profession: bool,
+    pub discoverable: bool,
+}
+
+/// User profile methods called from other contracts.
+#[contractclient(name = "UserProfileClient")]
+pub trait UserProfileInterface {
+    fn get_user_profile(env: Env, user_address: Address) -> UserProfile;
+    fn get_user_profile_with_privacy(
+        env: Env,
+        user_address: Address,
+        requester_address: Address,
+    ) -> UserProfile;
+    fn get_privacy_settings(env: Env, user: Address) -> Option<PrivacySettings>;
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Typed clients for the contracts payments calls.
+//!
+//! The clients come from `skillcert_interfaces`, so a signature change shows
+//! up as a compile error instead of a failed call at runtime.
+//!
+//! Soroban rejects any call back into a contract that is already on the
+//! call stack, so the course access contract can't re-enter payments while a
+//! purchase is being recorded. Purchases are still stored before access is
+//! granted, and payments must be on the course access trusted allowlist (or
+//! be an admin) to grant it.
+
+use soroban_sdk::Env;
+
+pub use skillcert_interfaces::course_access::{CourseAccessClient, CourseAccessInterface};
+pub use skillcert_interfaces::course_registry::{CourseRegistryClient, CourseRegistryInterface};
+
+use crate::functions::config::get_config;
+
+/// Returns a client for the configured course registry.
+pub fn course_registry(env: &Env) -> CourseRegistryClient<'_> {
+    CourseRegistryClient::new(env, &get_config(env).course_registry_addr)
+}
+
+/// Returns a client for the configured course access contract.
+pub fn course_access(env: &Env) -> CourseAccessClient<'_> {
+    CourseAccessClient::new(env, &get_config(env).course_access_addr)
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    InvalidFee = 4,
+    CourseNotAvailable = 5,
+    CourseIsFree = 6,
+    AlreadyPurchased = 7,
+    InvalidAmount = 8,
+    InsufficientBalance = 9,
+    PurchaseNotFound = 10,
+    RefundWindowClosed = 11,
+    AlreadyRefunded = 12,
+    InvalidReferral = 13,
+    AlreadyReferred = 14,
+    ContractPaused = 15,
+    RefundExceedsPrice = 16,
+    PriceTooHigh = 17,
+    RefundWindowTooLong = 18,
+}
+
+pub fn handle_error(env: &Env, error: Error) -> ! {
+    panic_with_error!(env, error);
+}
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "AlreadyInitialized: The contract is already initialized",
+        2 => "NotInitialized: The contract is not initialized",
+        3 => "Unauthorized: The caller is not allowed to do this",
+        4 => "InvalidFee: The fee is out of range",
+        5 => "CourseNotAvailable: The course cannot be bought",
+        6 => "CourseIsFree: The course is free",
+        7 => "AlreadyPurchased: The course is already purchased",
+        8 => "InvalidAmount: The amount is not valid",
+        9 => "InsufficientBalance: The balance is too low",
+        10 => "PurchaseNotFound: The purchase does not exist",
+        11 => "RefundWindowClosed: The refund window has ended",
+        12 => "AlreadyRefunded: The purchase is already refunded",
+        13 => "InvalidReferral: The referral is not valid",
+        14 => "AlreadyReferred: The user already has a referrer",
+        15 => "ContractPaused: The contract is paused",
+        16 => "RefundExceedsPrice: The refunds would exceed the amount paid",
+        17 => "PriceTooHigh: The course price is above the highest price a purchase accepts",
+        18 => "RefundWindowTooLong: The refund window would end after the last timestamp",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! Events are published with the topics `(entity, action, id)` and a typed
+//! payload whose first field is `version`, following the same convention as
+//! the course registry.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+use crate::schema::{FeeConfig, Purchase};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const PURCHASE: Symbol = symbol_short!("purchase");
+const BALANCE: Symbol = symbol_short!("balance");
+const FEES: Symbol = symbol_short!("fees");
+const REFERRAL: Symbol = symbol_short!("referral");
+const CONTRACT: Symbol = symbol_short!("contract");
+
+const CREATED: Symbol = symbol_short!("created");
+const REFUNDED: Symbol = symbol_short!("refunded");
+const PARTIALLY_REFUNDED: Symbol = symbol_short!("partRfnd");
+const RELEASED: Symbol = symbol_short!("released");
+const WITHDRAWN: Symbol = symbol_short!("withdrawn");
+const UPDATED: Symbol = symbol_short!("updated");
+const REGISTERED: Symbol = symbol_short!("register");
+const REWARDED: Symbol = symbol_short!("rewarded");
+const PAUSED: Symbol = symbol_short!("paused");
+const UNPAUSED: Symbol = symbol_short!("unpaused");
+
+/// Payload of `("purchase", "created" | "refunded", purchase_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PurchaseEvent {
+    pub version: u32,
+    pub purchase_id: u32,
+    pub course_id: String,
+    pub student: Address,
+    pub instructor: Address,
+    pub price: i128,
+    pub fee: i128,
+}
+
+/// Payload of `("purchase", "partRfnd", purchase_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartialRefundEvent {
+    pub version: u32,
+    pub purchase_id: u32,
+    pub student: Address,
+    pub refunder: Address,
+    pub amount: i128,
+    /// Total refunded for the purchase after this refund
+    pub refunded: i128,
+}
+
+/// Payload of `("balance", "released" | "withdrawn", account)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BalanceEvent {
+    pub version: u32,
+    pub account: Address,
+    pub amount: i128,
+    /// Withdrawable balance after the change
+    pub available: i128,
+}
+
+/// Payload of `("fees", "updated", admin)`, published on every fee or
+/// treasury change.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeConfigEvent {
+    pub version: u32,
+    pub admin: Address,
+    pub previous: FeeConfig,
+    pub current: FeeConfig,
+}
+
+pub fn fee_config_updated(env: &Env, admin: &Address, previous: FeeConfig, current: FeeConfig) {
+    env.events().publish(
+        (FEES, UPDATED, admin.clone()),
+        FeeConfigEvent {
+            version: EVENT_VERSION,
+            admin: admin.clone(),
+            previous,
+            current,
+        },
+    );
+}
+
+/// Payload of `("contract", "paused" | "unpaused", admin)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseEvent {
+    pub version: u32,
+    pub admin: Address,
+}
+
+pub fn contract_paused(env: &Env, admin: &Address, paused: bool) {
+    let action: Symbol = if paused { PAUSED } else { UNPAUSED };
+    env.events().publish(
+        (CONTRACT, action, admin.clone()),
+        PauseEvent {
+            version: EVENT_VERSION,
+            admin: admin.clone(),
+        },
+    );
+}
+
+/// Payload of `("referral", "register" | "rewarded", referrer)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferralEvent {
+    pub version: u32,
+    pub referrer: Address,
+    pub referee: Address,
+    /// Reward credited, 0 on registration
+    pub reward: i128,
+}
+
+pub fn referral_registered(env: &Env, referrer: &Address, referee: &Address) {
+    publish_referral_event(env, REGISTERED, referrer, referee, 0);
+}
+
+pub fn referral_rewarded(env: &Env, referrer: &Address, referee: &Address, reward: i128) {
+    publish_referral_event(env, REWARDED, referrer, referee, reward);
+}
+
+pub fn purchase_created(env: &Env, purchase: &Purchase) {
+    publish_purchase_event(env, CREATED, purchase);
+}
+
+pub fn purchase_refunded(env: &Env, purchase: &Purchase) {
+    publish_purchase_event(env, REFUNDED, purchase);
+}
+
+pub fn purchase_partially_refunded(
+    env: &Env,
+    purchase: &Purchase,
+    refunder: &Address,
+    amount: i128,
+    refunded: i128,
+) {
+    env.events().publish(
+        (PURCHASE, PARTIALLY_REFUNDED, purchase.id),
+        PartialRefundEvent {
+            version: EVENT_VERSION,
+            purchase_id: purchase.id,
+            student: purchase.student.clone(),
+            refunder: refunder.clone(),
+            amount,
+            refunded,
+        },
+    );
+}
+
+pub fn earnings_released(env: &Env, account: &Address, amount: i128, available: i128) {
+    publish_balance_event(env, RELEASED, account, amount, available);
+}
+
+pub fn withdrawn(env: &Env, account: &Address, amount: i128, available: i128) {
+    publish_balance_event(env, WITHDRAWN, account, amount, available);
+}
+
+fn publish_purchase_event(env: &Env, action: Symbol, purchase: &Purchase) {
+    env.events().publish(
+        (PURCHASE, action, purchase.id),
+        PurchaseEvent {
+            version: EVENT_VERSION,
+            purchase_id: purchase.id,
+            course_id: purchase.course_id.clone(),
+            student: purchase.student.clone(),
+            instructor: purchase.instructor.clone(),
+            price: purchase.price,
+            fee: purchase.fee,
+        },
+    );
+}
+
+fn publish_referral_event(
+    env: &Env,
+    action: Symbol,
+    referrer: &Address,
+    referee: &Address,
+    reward: i128,
+) {
+    env.events().publish(
+        (REFERRAL, action, referrer.clone()),
+        ReferralEvent {
+            version: EVENT_VERSION,
+            referrer: referrer.clone(),
+            referee: referee.clone(),
+            reward,
+        },
+    );
+}
+
+fn publish_balance_event(
+    env: &Env,
+    action: Symbol,
+    account: &Address,
+    amount: i128,
+    available: i128,
+) {
+    env.events().publish(
+        (BALANCE, action, account.clone()),
+        BalanceEvent {
+            version: EVENT_VERSION,
+            account: account.clone(),
+            amount,
+            available,
+        },
+    );
+}
@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Instructor and treasury balances.
+//!
+//! Each purchase credits the instructor share and the platform fee as a
+//! `HeldEarning` of the instructor, stored under the purchase ID and queued
+//! in purchase order. Once the refund window of the purchase has passed, the
+//! credit is released: the instructor share becomes part of the instructor's
+//! withdrawable balance and the fee part of the treasury's.
+
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::get_config;
+use crate::schema::{Balance, DataKey, HeldEarning, HeldQueue, MAX_RELEASE_BATCH};
+
+fn available(env: &Env, account: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(account.clone()))
+        .unwrap_or(0)
+}
+
+fn set_available(env: &Env, account: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Balance(account.clone()), &amount);
+}
+
+fn held_total(env: &Env, account: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HeldTotal(account.clone()))
+        .unwrap_or(0)
+}
+
+fn add_held_total(env: &Env, account: &Address, delta: i128) {
+    let key: DataKey = DataKey::HeldTotal(account.clone());
+    let total: i128 = held_total(env, account) + delta;
+    if total == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &total);
+    }
+}
+
+fn held_queue(env: &Env, account: &Address) -> HeldQueue {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HeldQueue(account.clone()))
+        .unwrap_or_default()
+}
+
+fn queued_purchase(env: &Env, account: &Address, position: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HeldQueueEntry(account.clone(), position))
+        .unwrap()
+}
+
+/// Returns the credit an account still holds for a purchase.
+pub(crate) fn held_earning(env: &Env, account: &Address, purchase_id: u32) -> Option<HeldEarning> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HeldEarning(account.clone(), purchase_id))
+}
+
+/// Replaces the credit an account holds for a purchase, e.g. after part of
+/// it was refunded.
+pub(crate) fn update_held_earning(env: &Env, account: &Address, earning: &HeldEarning) {
+    let key: DataKey = DataKey::HeldEarning(account.clone(), earning.purchase_id);
+    let previous: HeldEarning = env.storage().persistent().get(&key).unwrap();
+    env.storage().persistent().set(&key, earning);
+    add_held_total(env, account, earning.amount - previous.amount);
+}
+
+/// Removes the credit an account holds for a purchase, returning it. Its
+/// queue entry is skipped once it is reached.
+pub(crate) fn remove_held_earning(
+    env: &Env,
+    account: &Address,
+    purchase_id: u32,
+) -> Option<HeldEarning> {
+    let earning: HeldEarning = held_earning(env, account, purchase_id)?;
+    env.storage()
+        .persistent()
+        .remove(&DataKey::HeldEarning(account.clone(), purchase_id));
+    add_held_total(env, account, -earning.amount);
+    Some(earning)
+}
+
+/// Adds a held credit to an instructor, releasing matured ones first so the
+/// queue is kept short.
+pub(crate) fn hold_earning(env: &Env, instructor: &Address, earning: HeldEarning) {
+    release_earnings(env, instructor);
+
+    let mut queue: HeldQueue = held_queue(env, instructor);
+    env.storage().persistent().set(
+        &DataKey::HeldQueueEntry(instructor.clone(), queue.tail),
+        &earning.purchase_id,
+    );
+    queue.tail += 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::HeldQueue(instructor.clone()), &queue);
+    env.storage().persistent().set(
+        &DataKey::HeldEarning(instructor.clone(), earning.purchase_id),
+        &earning,
+    );
+    add_held_total(env, instructor, earning.amount);
+}
+
+/// Walks the held credits of an account in purchase order, up to
+/// `MAX_RELEASE_BATCH` of them, and returns the matured ones along with the
+/// queue position after them. Credits dropped by a refund are skipped.
+fn matured_earnings(env: &Env, account: &Address, queue: &HeldQueue) -> (Vec<HeldEarning>, u32) {
+    let now: u64 = env.ledger().timestamp();
+    let mut matured: Vec<HeldEarning> = Vec::new(env);
+    let mut position: u32 = queue.head;
+    let end: u32 = queue.tail.min(queue.head.saturating_add(MAX_RELEASE_BATCH));
+    while position < end {
+        let purchase_id: u32 = queued_purchase(env, account, position);
+        if let Some(earning) = held_earning(env, account, purchase_id) {
+            if earning.release_at > now {
+                break;
+            }
+            matured.push_back(earning);
+        }
+        position += 1;
+    }
+    (matured, position)
+}
+
+/// Returns the balance of an account, counting credits whose refund window
+/// has passed as available even if they were not released yet.
+///
+/// Platform fees only appear in the treasury balance once released, since
+/// they are held with the instructor credits they came from. Credits are
+/// matured in purchase order, and at most `MAX_RELEASE_BATCH` of them are
+/// counted as available before they are released.
+pub fn get_balance(env: &Env, account: Address) -> Balance {
+    let (matured, _) = matured_earnings(env, &account, &held_queue(env, &account));
+    let mut balance: Balance = Balance {
+        available: available(env, &account),
+        held: held_total(env, &account),
+    };
+    for earning in matured.iter() {
+        balance.available += earning.amount;
+        balance.held -= earning.amount;
+    }
+    balance
+}
+
+/// Releases the credits of an instructor whose refund window has passed, in
+/// purchase order and at most `MAX_RELEASE_BATCH` per call.
+///
+/// Anyone may call this; the treasury uses it to collect fees from
+/// instructors that have not withdrawn recently.
+pub fn release_earnings(env: &Env, instructor: &Address) -> Balance {
+    let mut queue: HeldQueue = held_queue(env, instructor);
+    let (matured, head) = matured_earnings(env, instructor, &queue);
+    let mut released: i128 = 0;
+    let mut fees: i128 = 0;
+    for earning in matured.iter() {
+        remove_held_earning(env, instructor, earning.purchase_id);
+        released += earning.amount;
+        fees += earning.fee;
+    }
+
+    if head != queue.head {
+        for position in queue.head..head {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::HeldQueueEntry(instructor.clone(), position));
+        }
+        queue.head = head;
+        let key: DataKey = DataKey::HeldQueue(instructor.clone());
+        if queue.head == queue.tail {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &queue);
+        }
+    }
+
+    if released > 0 || fees > 0 {
+        let instructor_available: i128 = available(env, instructor) + released;
+        set_available(env, instructor, instructor_available);
+        events::earnings_released(env, instructor, released, instructor_available);
+
+        if fees > 0 {
+            let treasury: Address = get_config(env).treasury;
+            let treasury_available: i128 = available(env, &treasury) + fees;
+            set_available(env, &treasury, treasury_available);
+            events::earnings_released(env, &treasury, fees, treasury_available);
+        }
+    }
+
+    get_balance(env, instructor.clone())
+}
+
+/// Withdraws part of the available balance of an account to the account.
+pub fn withdraw(env: &Env, account: Address, amount: i128) -> Balance {
+    account.require_auth();
+
+    if amount <= 0 {
+        handle_error(env, Error::InvalidAmount)
+    }
+    release_earnings(env, &account);
+    let remaining: i128 = available(env, &account) - amount;
+    if remaining < 0 {
+        handle_error(env, Error::InsufficientBalance)
+    }
+    set_available(env, &account, remaining);
+
+    token::TokenClient::new(env, &get_config(env).token).transfer(
+        &env.current_contract_address(),
+        &account,
+        &amount,
+    );
+
+    events::withdrawn(env, &account, amount, remaining);
+    get_balance(env, account)
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::schema::{DataKey, FeeConfig, PaymentsConfig, BPS_DENOMINATOR};
+
+const INIT_EVENT: Symbol = symbol_short!("init");
+
+/// Stores the owner, payment token, fee and the contracts used to look up
+/// courses and grant access.
+pub fn initialize(env: &Env, config: PaymentsConfig) {
+    config.owner.require_auth();
+
+    if env.storage().instance().has(&DataKey::Config) {
+        handle_error(env, Error::AlreadyInitialized)
+    }
+    if config.platform_fee_bps as i128 > BPS_DENOMINATOR {
+        handle_error(env, Error::InvalidFee)
+    }
+    env.storage().instance().set(&DataKey::Config, &config);
+
+    env.events().publish((INIT_EVENT,), config);
+}
+
+/// Returns whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Config)
+}
+
+/// Returns the configuration, panicking with `NotInitialized` before `initialize`.
+pub fn get_config(env: &Env) -> PaymentsConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
+
+/// Returns the treasury and the platform fee.
+pub fn get_fee_config(env: &Env) -> FeeConfig {
+    let config: PaymentsConfig = get_config(env);
+    FeeConfig {
+        treasury: config.treasury,
+        platform_fee_bps: config.platform_fee_bps,
+    }
+}
+
+/// Sets the platform fee in basis points (owner only).
+///
+/// The fee of a purchase is fixed when it is made; the change applies to
+/// later purchases only.
+pub fn set_platform_fee(env: &Env, admin: Address, platform_fee_bps: u32) -> FeeConfig {
+    if platform_fee_bps as i128 > BPS_DENOMINATOR {
+        handle_error(env, Error::InvalidFee)
+    }
+    update_fee_config(env, admin, |config| {
+        config.platform_fee_bps = platform_fee_bps
+    })
+}
+
+/// Sets the account platform fees are credited to (owner only).
+///
+/// Fees still held in refund windows go to the treasury configured when they
+/// are released.
+pub fn set_treasury(env: &Env, admin: Address, treasury: Address) -> FeeConfig {
+    update_fee_config(env, admin, |config| config.treasury = treasury)
+}
+
+fn update_fee_config(
+    env: &Env,
+    admin: Address,
+    update: impl FnOnce(&mut PaymentsConfig),
+) -> FeeConfig {
+    admin.require_auth();
+    let mut config: PaymentsConfig = get_config(env);
+    if config.owner != admin {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let previous: FeeConfig = get_fee_config(env);
+    update(&mut config);
+    env.storage().instance().set(&DataKey::Config, &config);
+    let current: FeeConfig = get_fee_config(env);
+
+    events::fee_config_updated(env, &admin, previous, current.clone());
+    current
+}
+
+/// Sets the disputes contract allowed to refund part of a purchase (owner only).
+pub fn set_disputes_contract(env: &Env, admin: Address, disputes: Address) {
+    admin.require_auth();
+    if get_config(env).owner != admin {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputesContract, &disputes);
+}
+
+/// Returns the disputes contract allowed to refund part of a purchase, if set.
+pub fn get_disputes_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::DisputesContract)
+}
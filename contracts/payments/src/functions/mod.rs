@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+pub mod balances;
+pub mod config;
+pub mod pause;
+pub mod purchases;
+pub mod referrals;
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Emergency pause switch.
+//!
+//! While paused, purchases, refunds, referrals, withdrawals and fee changes
+//! fail with `ContractPaused` so funds can be frozen during an incident
+//! without an upgrade. Reads and `unpause` keep working.
+
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::get_config;
+use crate::schema::DataKey;
+
+/// Whether the contract is paused.
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+/// Panics with `ContractPaused` while the contract is paused.
+pub fn require_not_paused(env: &Env) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused)
+    }
+}
+
+/// Pauses the contract. Owner only; pausing twice is a no-op.
+pub fn pause(env: &Env, admin: Address) {
+    set_paused(env, admin, true)
+}
+
+/// Lifts the pause. Owner only; a no-op when not paused.
+pub fn unpause(env: &Env, admin: Address) {
+    set_paused(env, admin, false)
+}
+
+fn set_paused(env: &Env, admin: Address, paused: bool) {
+    admin.require_auth();
+    if get_config(env).owner != admin {
+        handle_error(env, Error::Unauthorized)
+    }
+    if is_paused(env) == paused {
+        return;
+    }
+
+    env.storage().instance().set(&DataKey::Paused, &paused);
+    events::contract_paused(env, &admin, paused);
+}
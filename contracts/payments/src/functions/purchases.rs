@@ -0,0 +1,332 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Course purchases and refunds.
+
+use soroban_sdk::{token, Address, Env, String};
+
+use crate::clients::{course_access, course_registry};
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::balances::{
+    held_earning, hold_earning, remove_held_earning, update_held_earning,
+};
+use crate::functions::config::{get_config, get_disputes_contract};
+use crate::functions::referrals::{attribute_purchase, cancel_reward};
+use crate::schema::{
+    Course, DataKey, EnrollmentSource, HeldEarning, PaymentsConfig, Purchase, RevocationReason,
+    BPS_DENOMINATOR, MAX_PURCHASE_PRICE,
+};
+
+/// Returns `bps` basis points of `amount`, or `None` on overflow.
+pub(crate) fn bps_share(amount: i128, bps: u32) -> Option<i128> {
+    amount
+        .checked_mul(bps as i128)
+        .and_then(|scaled| scaled.checked_div(BPS_DENOMINATOR))
+}
+
+/// Drops the held credit of a purchase, returning the amount it still held.
+fn drop_held_earning(env: &Env, account: &Address, purchase_id: u32) -> i128 {
+    remove_held_earning(env, account, purchase_id)
+        .map(|earning| earning.amount)
+        .unwrap_or(0)
+}
+
+/// Takes up to `amount` from the held credit of a purchase, from the account
+/// share first and then from the fee held with it. Returns what is left to
+/// take; panics with `RefundWindowClosed` if the credit was already released.
+fn take_held_earning(env: &Env, account: &Address, purchase_id: u32, amount: i128) -> i128 {
+    let mut earning: HeldEarning = held_earning(env, account, purchase_id)
+        .filter(|earning| earning.release_at > env.ledger().timestamp())
+        .unwrap_or_else(|| handle_error(env, Error::RefundWindowClosed));
+    let mut remaining: i128 = amount;
+    let from_share: i128 = remaining.min(earning.amount);
+    earning.amount -= from_share;
+    remaining -= from_share;
+    let from_fee: i128 = remaining.min(earning.fee);
+    earning.fee -= from_fee;
+    remaining -= from_fee;
+    update_held_earning(env, account, &earning);
+    remaining
+}
+
+/// Returns a purchase, or `None` if it does not exist.
+pub fn get_purchase(env: &Env, purchase_id: u32) -> Option<Purchase> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Purchase(purchase_id))
+}
+
+/// Returns the amount of a purchase refunded so far.
+pub fn get_refunded_amount(env: &Env, purchase_id: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundedAmount(purchase_id))
+        .unwrap_or(0)
+}
+
+/// Returns the purchase of a course by a student, or `None` if they didn't
+/// buy it or it was refunded through `refund_purchase`.
+pub fn get_course_purchase(env: &Env, course_id: String, student: Address) -> Option<Purchase> {
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::CoursePurchase(course_id, student))
+        .and_then(|purchase_id| get_purchase(env, purchase_id))
+}
+
+/// When the refund window of a purchase made at `purchased_at` ends,
+/// panicking with `RefundWindowTooLong` if that is past the last timestamp.
+fn refund_window_end(env: &Env, purchased_at: u64, refund_window: u64) -> u64 {
+    purchased_at
+        .checked_add(refund_window)
+        .unwrap_or_else(|| handle_error(env, Error::RefundWindowTooLong))
+}
+
+/// Buys a course: the price is paid into this contract, credited to the
+/// instructor and the treasury as held earnings, and the student is granted
+/// access through the course access contract.
+///
+/// The first purchase of a referred student credits part of the platform fee
+/// to their referrer. The course access contract must accept this contract
+/// as a granter: a trusted contract or an admin. The purchase is reported to
+/// the course statistics of the course registry when it allows this contract
+/// as a stats reporter.
+pub fn purchase_course(env: &Env, student: Address, course_id: String) -> Purchase {
+    student.require_auth();
+    let config: PaymentsConfig = get_config(env);
+
+    let purchase_key: DataKey = DataKey::CoursePurchase(course_id.clone(), student.clone());
+    if env.storage().persistent().has(&purchase_key) {
+        handle_error(env, Error::AlreadyPurchased)
+    }
+
+    let course: Course = course_registry(env).get_course(&course_id);
+    if !course.published || course.is_archived {
+        handle_error(env, Error::CourseNotAvailable)
+    }
+    if course.price == 0 {
+        handle_error(env, Error::CourseIsFree)
+    }
+    let price: i128 = i128::try_from(course.price)
+        .ok()
+        .filter(|price| *price <= MAX_PURCHASE_PRICE)
+        .unwrap_or_else(|| handle_error(env, Error::PriceTooHigh));
+    let fee: i128 = bps_share(price, config.platform_fee_bps)
+        .unwrap_or_else(|| handle_error(env, Error::PriceTooHigh));
+
+    token::TokenClient::new(env, &config.token).transfer(
+        &student,
+        &env.current_contract_address(),
+        &price,
+    );
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::PurchaseCount)
+        .unwrap_or(0)
+        + 1;
+    let purchased_at: u64 = env.ledger().timestamp();
+    let release_at: u64 = refund_window_end(env, purchased_at, config.refund_window);
+    let (referrer, referral_reward) = attribute_purchase(env, &student, price, fee);
+    let purchase: Purchase = Purchase {
+        id,
+        course_id: course_id.clone(),
+        student: student.clone(),
+        instructor: course.creator.clone(),
+        price,
+        fee,
+        purchased_at,
+        refunded: false,
+        referrer: referrer.clone(),
+        referral_reward,
+    };
+    env.storage().persistent().set(&DataKey::PurchaseCount, &id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Purchase(id), &purchase);
+    env.storage().persistent().set(&purchase_key, &id);
+
+    hold_earning(
+        env,
+        &course.creator,
+        HeldEarning {
+            purchase_id: id,
+            amount: price - fee,
+            fee: fee - referral_reward,
+            release_at,
+        },
+    );
+    if let Some(referrer) = referrer {
+        hold_earning(
+            env,
+            &referrer,
+            HeldEarning {
+                purchase_id: id,
+                amount: referral_reward,
+                fee: 0,
+                release_at,
+            },
+        );
+    }
+
+    course_access(env).grant_access_with_source(
+        &env.current_contract_address(),
+        &course_id,
+        &student,
+        &EnrollmentSource::Purchase,
+    );
+    let _ = course_registry(env).try_record_purchase(
+        &env.current_contract_address(),
+        &course_id,
+        &course.price,
+    );
+
+    events::purchase_created(env, &purchase);
+    purchase
+}
+
+/// Refunds a purchase in full while it is inside its refund window (owner
+/// only). Its held credits are dropped so the instructor and the treasury are
+/// never paid for it; only what was not already refunded with
+/// `refund_purchase_partial` is paid back.
+///
+/// The student's access to the course is revoked in the same call, with
+/// `Refund` as the reason.
+pub fn refund_purchase(env: &Env, owner: Address, purchase_id: u32) -> Purchase {
+    owner.require_auth();
+    let config: PaymentsConfig = get_config(env);
+    if config.owner != owner {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let mut purchase: Purchase = get_purchase(env, purchase_id)
+        .unwrap_or_else(|| handle_error(env, Error::PurchaseNotFound));
+    if purchase.refunded {
+        handle_error(env, Error::AlreadyRefunded)
+    }
+    if env.ledger().timestamp()
+        >= refund_window_end(env, purchase.purchased_at, config.refund_window)
+    {
+        handle_error(env, Error::RefundWindowClosed)
+    }
+
+    drop_held_earning(env, &purchase.instructor, purchase_id);
+    if let Some(referrer) = &purchase.referrer {
+        let reward: i128 = drop_held_earning(env, referrer, purchase_id);
+        cancel_reward(env, referrer, reward);
+    }
+
+    let amount: i128 = purchase.price - get_refunded_amount(env, purchase_id);
+    purchase.refunded = true;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Purchase(purchase_id), &purchase);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RefundedAmount(purchase_id), &purchase.price);
+    env.storage().persistent().remove(&DataKey::CoursePurchase(
+        purchase.course_id.clone(),
+        purchase.student.clone(),
+    ));
+
+    course_access(env).revoke_access(
+        &env.current_contract_address(),
+        &purchase.course_id,
+        &purchase.student,
+        &Some(RevocationReason::Refund),
+        &None,
+    );
+
+    token::TokenClient::new(env, &config.token).transfer(
+        &env.current_contract_address(),
+        &purchase.student,
+        &amount,
+    );
+
+    events::purchase_refunded(env, &purchase);
+    purchase
+}
+
+/// Refunds part of a purchase while its credits are still held (owner or
+/// disputes contract only). Access to the course is left as is until the
+/// whole price was refunded.
+///
+/// The amount is taken back from the held credits of the purchase: the
+/// instructor share first, then the platform fee and the referral reward.
+/// All refunds of a purchase together can't exceed what the student paid;
+/// a purchase refunded in full is marked refunded, the student's access is
+/// revoked with `Refund` as the reason, and the student can buy the course
+/// again.
+pub fn refund_purchase_partial(
+    env: &Env,
+    refunder: Address,
+    purchase_id: u32,
+    amount: i128,
+) -> Purchase {
+    refunder.require_auth();
+    let config: PaymentsConfig = get_config(env);
+    if config.owner != refunder && get_disputes_contract(env) != Some(refunder.clone()) {
+        handle_error(env, Error::Unauthorized)
+    }
+    if amount <= 0 {
+        handle_error(env, Error::InvalidAmount)
+    }
+
+    let mut purchase: Purchase = get_purchase(env, purchase_id)
+        .unwrap_or_else(|| handle_error(env, Error::PurchaseNotFound));
+    if purchase.refunded {
+        handle_error(env, Error::AlreadyRefunded)
+    }
+    let refunded: i128 = get_refunded_amount(env, purchase_id)
+        .checked_add(amount)
+        .filter(|refunded| *refunded <= purchase.price)
+        .unwrap_or_else(|| handle_error(env, Error::RefundExceedsPrice));
+
+    let mut remaining: i128 = take_held_earning(env, &purchase.instructor, purchase_id, amount);
+    if remaining > 0 {
+        if let Some(referrer) = &purchase.referrer {
+            let reward: i128 = remaining;
+            remaining = take_held_earning(env, referrer, purchase_id, reward);
+            cancel_reward(env, referrer, reward - remaining);
+        }
+    }
+    if remaining > 0 {
+        handle_error(env, Error::RefundExceedsPrice)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RefundedAmount(purchase_id), &refunded);
+    if refunded == purchase.price {
+        drop_held_earning(env, &purchase.instructor, purchase_id);
+        if let Some(referrer) = &purchase.referrer {
+            drop_held_earning(env, referrer, purchase_id);
+        }
+        purchase.refunded = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Purchase(purchase_id), &purchase);
+        env.storage().persistent().remove(&DataKey::CoursePurchase(
+            purchase.course_id.clone(),
+            purchase.student.clone(),
+        ));
+
+        course_access(env).revoke_access(
+            &env.current_contract_address(),
+            &purchase.course_id,
+            &purchase.student,
+            &Some(RevocationReason::Refund),
+            &None,
+        );
+    }
+
+    token::TokenClient::new(env, &config.token).transfer(
+        &env.current_contract_address(),
+        &purchase.student,
+        &amount,
+    );
+
+    events::purchase_partially_refunded(env, &purchase, &refunder, amount, refunded);
+    purchase
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Referral program.
+//!
+//! A new student registers who referred them. Their first purchase is
+//! attributed to the referrer, who is credited a share of the price taken
+//! from the platform fee. Like every credit, the reward is held until the
+//! refund window of the purchase has passed.
+
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::config::get_config;
+use crate::functions::purchases::bps_share;
+use crate::schema::{DataKey, ReferralStats, BPS_DENOMINATOR};
+
+fn update_stats(env: &Env, referrer: &Address, update: impl FnOnce(&mut ReferralStats)) {
+    let mut stats: ReferralStats = get_referral_stats(env, referrer.clone());
+    update(&mut stats);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReferralStats(referrer.clone()), &stats);
+}
+
+/// Records that `referrer` referred `referee`. The referee authorizes the
+/// call, can only be referred once and must not have bought a course yet.
+pub fn register_referral(env: &Env, referrer: Address, referee: Address) {
+    referee.require_auth();
+
+    if referrer == referee {
+        handle_error(env, Error::InvalidReferral)
+    }
+    let key: DataKey = DataKey::Referrer(referee.clone());
+    if env.storage().persistent().has(&key) {
+        handle_error(env, Error::AlreadyReferred)
+    }
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::HasPurchased(referee.clone()))
+    {
+        handle_error(env, Error::InvalidReferral)
+    }
+
+    env.storage().persistent().set(&key, &referrer);
+    update_stats(env, &referrer, |stats| stats.referrals += 1);
+
+    events::referral_registered(env, &referrer, &referee);
+}
+
+/// Returns who referred `referee`, if anyone.
+pub fn get_referrer(env: &Env, referee: Address) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::Referrer(referee))
+}
+
+/// Returns the counters of a referrer.
+pub fn get_referral_stats(env: &Env, referrer: Address) -> ReferralStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralStats(referrer))
+        .unwrap_or_default()
+}
+
+/// Returns the share of the price paid to referrers, in basis points.
+pub fn get_referral_reward(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReferralRewardBps)
+        .unwrap_or(0)
+}
+
+/// Sets the share of the price paid to referrers (owner only). The reward
+/// never exceeds the platform fee it is taken from.
+pub fn set_referral_reward(env: &Env, admin: Address, reward_bps: u32) {
+    admin.require_auth();
+    if get_config(env).owner != admin {
+        handle_error(env, Error::Unauthorized)
+    }
+    if reward_bps as i128 > BPS_DENOMINATOR {
+        handle_error(env, Error::InvalidFee)
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ReferralRewardBps, &reward_bps);
+}
+
+/// Attributes the purchase of `student` to their referrer if it is their
+/// first one, returning the referrer and the reward taken from `fee`.
+pub(crate) fn attribute_purchase(
+    env: &Env,
+    student: &Address,
+    price: i128,
+    fee: i128,
+) -> (Option<Address>, i128) {
+    let first_purchase_key: DataKey = DataKey::HasPurchased(student.clone());
+    if env.storage().persistent().has(&first_purchase_key) {
+        return (None, 0);
+    }
+    env.storage().persistent().set(&first_purchase_key, &true);
+
+    let Some(referrer) = get_referrer(env, student.clone()) else {
+        return (None, 0);
+    };
+    let reward: i128 = bps_share(price, get_referral_reward(env))
+        .unwrap_or_else(|| handle_error(env, Error::PriceTooHigh))
+        .min(fee);
+    update_stats(env, &referrer, |stats| {
+        stats.conversions += 1;
+        stats.rewards += reward;
+    });
+
+    events::referral_rewarded(env, &referrer, student, reward);
+    (Some(referrer), reward)
+}
+
+/// Takes back the reward of a refunded purchase from the referrer's counters.
+pub(crate) fn cancel_reward(env: &Env, referrer: &Address, reward: i128) {
+    update_stats(env, referrer, |stats| stats.rewards -= reward);
+}
@@ -0,0 +1,340 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+/// Contract version for tracking deployments and upgrades
+pub const VERSION: &str = "1.0.0";
+
+pub mod clients;
+pub mod error;
+pub mod events;
+pub mod functions;
+pub mod schema;
+
+#[cfg(test)]
+mod test;
+
+use crate::schema::{Balance, FeeConfig, PaymentsConfig, Purchase, ReferralStats};
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+/// Payments Contract
+///
+/// This contract sells course access and keeps the balances of instructors
+/// and the platform treasury. Credits are held back during the refund window
+/// of each purchase and withdrawn by their owners afterwards.
+#[contract]
+pub struct PaymentsContract;
+
+#[contractimpl]
+impl PaymentsContract {
+    /// Initialize the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `config` - The owner, payment token, treasury, platform fee, refund
+    ///   window and the course registry and course access contract addresses
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is already initialized
+    /// * If the platform fee is above 10_000 basis points
+    pub fn initialize(env: Env, config: PaymentsConfig) {
+        functions::config::initialize(&env, config)
+    }
+
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::config::is_initialized(&env)
+    }
+
+    /// Get the contract configuration.
+    pub fn get_config(env: Env) -> PaymentsConfig {
+        functions::config::get_config(&env)
+    }
+
+    /// Get the treasury and the platform fee.
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        functions::config::get_fee_config(&env)
+    }
+
+    /// Pause the contract during an incident (owner only).
+    ///
+    /// While paused, every state-changing entrypoint fails with
+    /// `ContractPaused`; balances and purchases can still be read.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already paused**: Nothing changes and no event is published
+    pub fn pause(env: Env, admin: Address) {
+        functions::pause::pause(&env, admin)
+    }
+
+    /// Lift the pause set with `pause` (owner only).
+    pub fn unpause(env: Env, admin: Address) {
+        functions::pause::unpause(&env, admin)
+    }
+
+    /// Check whether the contract is paused.
+    pub fn is_paused(env: Env) -> bool {
+        functions::pause::is_paused(&env)
+    }
+
+    /// Set the platform fee deducted from each purchase (owner only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner
+    /// * `platform_fee_bps` - Fee in basis points (0 to 10_000)
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated fee configuration.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    /// * If the fee is above 10_000 basis points
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing purchases**: Keep the fee they were made with
+    pub fn set_platform_fee(env: Env, admin: Address, platform_fee_bps: u32) -> FeeConfig {
+        functions::pause::require_not_paused(&env);
+        functions::config::set_platform_fee(&env, admin, platform_fee_bps)
+    }
+
+    /// Set the account platform fees are credited to (owner only).
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Held fees**: Go to the treasury configured when they are released
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> FeeConfig {
+        functions::pause::require_not_paused(&env);
+        functions::config::set_treasury(&env, admin, treasury)
+    }
+
+    /// Set the share of the price credited to referrers (owner only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The contract owner
+    /// * `reward_bps` - Reward in basis points, capped at the platform fee of each purchase
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    /// * If the reward is above 10_000 basis points
+    pub fn set_referral_reward(env: Env, admin: Address, reward_bps: u32) {
+        functions::pause::require_not_paused(&env);
+        functions::referrals::set_referral_reward(&env, admin, reward_bps)
+    }
+
+    /// Get the share of the price credited to referrers, in basis points.
+    pub fn get_referral_reward(env: Env) -> u32 {
+        functions::referrals::get_referral_reward(&env)
+    }
+
+    /// Register who referred a new student.
+    ///
+    /// The referee's first purchase is attributed to the referrer, who is
+    /// credited the referral reward in this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `referrer` - The user who referred the student
+    /// * `referee` - The new student, who must authorize the call
+    ///
+    /// # Panics
+    ///
+    /// * If the referee refers themselves or has already bought a course
+    /// * If the referee was already referred
+    pub fn register_referral(env: Env, referrer: Address, referee: Address) {
+        functions::pause::require_not_paused(&env);
+        functions::referrals::register_referral(&env, referrer, referee)
+    }
+
+    /// Get who referred a student, `None` if nobody did.
+    pub fn get_referrer(env: Env, referee: Address) -> Option<Address> {
+        functions::referrals::get_referrer(&env, referee)
+    }
+
+    /// Get the referrals, conversions and rewards of a referrer.
+    pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+        functions::referrals::get_referral_stats(&env, referrer)
+    }
+
+    /// Buy access to a course.
+    ///
+    /// The course price is transferred from the student to this contract and
+    /// credited, minus the platform fee, to the course creator.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The buyer
+    /// * `course_id` - The course being bought
+    ///
+    /// # Returns
+    ///
+    /// Returns the new purchase.
+    ///
+    /// # Panics
+    ///
+    /// * If the student already bought the course
+    /// * If the course is unpublished, archived or free
+    /// * If the refund window would end after the last timestamp
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Access**: This contract must be allowed to grant access in the
+    ///   course access contract
+    /// * **Holdback**: Credits can only be withdrawn once the refund window has passed
+    /// * **Referrals**: A referred student's first purchase credits the
+    ///   referrer with part of the platform fee
+    pub fn purchase_course(env: Env, student: Address, course_id: String) -> Purchase {
+        functions::pause::require_not_paused(&env);
+        functions::purchases::purchase_course(&env, student, course_id)
+    }
+
+    /// Refund a purchase in full while it is inside its refund window (owner only).
+    ///
+    /// The student's access to the course is revoked through the course
+    /// access contract in the same call.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the owner
+    /// * If the purchase doesn't exist, was already refunded or its window has passed
+    pub fn refund_purchase(env: Env, owner: Address, purchase_id: u32) -> Purchase {
+        functions::pause::require_not_paused(&env);
+        functions::purchases::refund_purchase(&env, owner, purchase_id)
+    }
+
+    /// Refund part of a purchase while its credits are still held.
+    ///
+    /// Called by the disputes contract when a moderator settles a dispute
+    /// with a refund, or by the owner. Access to the course is left as is
+    /// until the whole price was refunded, then it is revoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `refunder` - The owner or the configured disputes contract
+    /// * `purchase_id` - The purchase being refunded
+    /// * `amount` - Amount of the payment token paid back to the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the purchase, marked refunded once it is refunded in full.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the owner nor the disputes contract
+    /// * If the amount is not positive
+    /// * If the purchase doesn't exist or was already refunded in full
+    /// * If the refunds of the purchase would exceed its price
+    /// * If the credits of the purchase were already released
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Clawback**: The amount is taken from the held instructor share
+    ///   first, then from the platform fee and the referral reward
+    pub fn refund_purchase_partial(
+        env: Env,
+        refunder: Address,
+        purchase_id: u32,
+        amount: i128,
+    ) -> Purchase {
+        functions::pause::require_not_paused(&env);
+        functions::purchases::refund_purchase_partial(&env, refunder, purchase_id, amount)
+    }
+
+    /// Get the amount of a purchase refunded so far.
+    pub fn get_refunded_amount(env: Env, purchase_id: u32) -> i128 {
+        functions::purchases::get_refunded_amount(&env, purchase_id)
+    }
+
+    /// Set the disputes contract allowed to call `refund_purchase_partial` (owner only).
+    pub fn set_disputes_contract(env: Env, admin: Address, disputes: Address) {
+        functions::pause::require_not_paused(&env);
+        functions::config::set_disputes_contract(&env, admin, disputes)
+    }
+
+    /// Get the disputes contract allowed to refund part of a purchase, `None` if unset.
+    pub fn get_disputes_contract(env: Env) -> Option<Address> {
+        functions::config::get_disputes_contract(&env)
+    }
+
+    /// Get the purchase of a course by a student, `None` if they didn't buy
+    /// it or it was refunded with `refund_purchase`.
+    pub fn get_course_purchase(env: Env, course_id: String, student: Address) -> Option<Purchase> {
+        functions::purchases::get_course_purchase(&env, course_id, student)
+    }
+
+    /// Get a purchase, `None` if it doesn't exist.
+    pub fn get_purchase(env: Env, purchase_id: u32) -> Option<Purchase> {
+        functions::purchases::get_purchase(&env, purchase_id)
+    }
+
+    /// Get the balance of an instructor or of the treasury.
+    ///
+    /// # Returns
+    ///
+    /// Returns the withdrawable amount and the amount still held in refund windows.
+    pub fn get_balance(env: Env, account: Address) -> Balance {
+        functions::balances::get_balance(&env, account)
+    }
+
+    /// Release the credits of an instructor whose refund window has passed.
+    ///
+    /// Anyone may call this, e.g. the treasury to collect the fees held with
+    /// an instructor's credits.
+    pub fn release_earnings(env: Env, instructor: Address) -> Balance {
+        functions::pause::require_not_paused(&env);
+        functions::balances::release_earnings(&env, &instructor)
+    }
+
+    /// Withdraw part of an account's available balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `account` - The instructor or treasury withdrawing
+    /// * `amount` - Amount of the payment token to withdraw
+    ///
+    /// # Panics
+    ///
+    /// * If the amount is not positive or exceeds the available balance
+    pub fn withdraw(env: Env, account: Address, amount: i128) -> Balance {
+        functions::pause::require_not_paused(&env);
+        functions::balances::withdraw(&env, account, amount)
+    }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracttype, Address, String};
+
+/// Denominator of `platform_fee_bps`: 10_000 basis points make 100%.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+/// Highest course price a purchase accepts, so fee and reward shares of it
+/// can't overflow
+pub const MAX_PURCHASE_PRICE: i128 = i128::MAX / BPS_DENOMINATOR;
+/// Most held credits of an account released, or counted by `get_balance`,
+/// in one call
+pub const MAX_RELEASE_BATCH: u32 = 25;
+
+/// Storage keys of the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Token, fee and contract addresses (instance storage)
+    Config,
+    /// Withdrawable balance of an account: account -> i128
+    Balance(Address),
+    /// Credit of an account still inside the refund window of a purchase:
+    /// (account, purchase_id) -> HeldEarning
+    HeldEarning(Address, u32),
+    /// Sum of the held credits of an account, fees excluded: account -> i128
+    HeldTotal(Address),
+    /// Held credits of an account in purchase order: account -> HeldQueue
+    HeldQueue(Address),
+    /// Purchase of the held credit at a queue position: (account, position) -> purchase_id
+    HeldQueueEntry(Address, u32),
+    /// Number of purchases so far, used as the last purchase ID
+    PurchaseCount,
+    /// Purchase by ID: purchase_id -> Purchase
+    Purchase(u32),
+    /// Purchase of a course by a student: (course_id, student) -> purchase_id
+    CoursePurchase(String, Address),
+    /// Whether a student has bought any course: student -> bool
+    HasPurchased(Address),
+    /// Share of the price paid to referrers, in basis points (instance storage)
+    ReferralRewardBps,
+    /// Referrer of a referee: referee -> referrer
+    Referrer(Address),
+    /// Counters of a referrer: referrer -> ReferralStats
+    ReferralStats(Address),
+    /// Whether purchases, refunds and withdrawals are frozen (instance storage)
+    Paused,
+    /// Contract allowed to refund part of a purchase (instance storage)
+    DisputesContract,
+    /// Amount of a purchase refunded so far: purchase_id -> i128
+    RefundedAmount(u32),
+}
+
+/// Owner, payment token, fee and addresses of the contracts used to look up
+/// courses and grant access.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentsConfig {
+    pub owner: Address,
+    /// Token course prices are paid in
+    pub token: Address,
+    pub course_registry_addr: Address,
+    pub course_access_addr: Address,
+    /// Account platform fees are credited to
+    pub treasury: Address,
+    /// Share of each purchase kept by the platform, in basis points
+    pub platform_fee_bps: u32,
+    /// Seconds after a purchase during which it can be refunded and its
+    /// credits are held back
+    pub refund_window: u64,
+}
+
+/// Platform fee settings, returned by `get_fee_config`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub treasury: Address,
+    pub platform_fee_bps: u32,
+}
+
+/// A course bought through this contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Purchase {
+    pub id: u32,
+    pub course_id: String,
+    pub student: Address,
+    pub instructor: Address,
+    /// Amount paid by the student
+    pub price: i128,
+    /// Part of the price credited to the treasury
+    pub fee: i128,
+    pub purchased_at: u64,
+    pub refunded: bool,
+    /// Referrer credited for this first purchase of a referee
+    pub referrer: Option<Address>,
+    /// Part of `fee` credited to the referrer instead of the treasury
+    pub referral_reward: i128,
+}
+
+/// Counters of a referrer.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferralStats {
+    /// Referees registered with this referrer
+    pub referrals: u32,
+    /// Referees whose first purchase was attributed to this referrer
+    pub conversions: u32,
+    /// Rewards credited, refunded purchases excluded
+    pub rewards: i128,
+}
+
+/// Credit held back until the refund window of its purchase has passed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeldEarning {
+    pub purchase_id: u32,
+    /// Instructor share of the price
+    pub amount: i128,
+    /// Treasury share of the price, credited along with `amount`
+    pub fee: i128,
+    pub release_at: u64,
+}
+
+/// Positions of the held credits of an account still to be released:
+/// `head` is the oldest, `tail` the next free one.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HeldQueue {
+    pub head: u32,
+    pub tail: u32,
+}
+
+/// Balance of an account in this contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Balance {
+    /// Amount that can be withdrawn now
+    pub available: i128,
+    /// Amount still inside a refund window
+    pub held: i128,
+}
+
+/// Course, enrollment and revocation types of the course registry and course
+/// access contracts, decoded from `get_course` and passed to
+/// `grant_access_with_source` and `revoke_access`.
+pub use skillcert_interfaces::course_access::{EnrollmentSource, RevocationReason};
+pub use skillcert_interfaces::course_registry::Course;
@@ -0,0 +1,621 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+#![cfg(test)]
+
+use skillcert_interfaces::course_registry::CourseStats;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String, Vec,
+};
+
+use crate::error::Error;
+use crate::functions::config::get_config;
+use crate::schema::{
+    Balance, Course, DataKey, EnrollmentSource, FeeConfig, HeldQueue, PaymentsConfig,
+    ReferralStats, RevocationReason, MAX_PURCHASE_PRICE, MAX_RELEASE_BATCH,
+};
+use crate::{PaymentsContract, PaymentsContractClient};
+
+const PRICE: u128 = 1_000;
+const REFUND_WINDOW: u64 = 3_600;
+
+#[contract]
+pub struct CourseRegistry;
+
+#[contractimpl]
+impl CourseRegistry {
+    pub fn set_creator(env: Env, creator: Address) {
+        env.storage().instance().set(&"creator", &creator);
+    }
+    pub fn set_price(env: Env, price: u128) {
+        env.storage().instance().set(&"price", &price);
+    }
+    pub fn record_purchase(
+        env: Env,
+        _reporter: Address,
+        _course_id: String,
+        amount: u128,
+    ) -> CourseStats {
+        let revenue: u128 = Self::get_revenue(env.clone()) + amount;
+        env.storage().instance().set(&"revenue", &revenue);
+        CourseStats {
+            enrollments: 0,
+            completions: 0,
+            revenue,
+        }
+    }
+    pub fn get_revenue(env: Env) -> u128 {
+        env.storage().instance().get(&"revenue").unwrap_or(0)
+    }
+    pub fn get_course(env: Env, course_id: String) -> Course {
+        Course {
+            id: course_id,
+            title: String::from_str(&env, "Rust 101"),
+            description: String::from_str(&env, "Learn Rust"),
+            creator: env.storage().instance().get(&"creator").unwrap(),
+            price: env.storage().instance().get(&"price").unwrap_or(PRICE),
+            category: None,
+            language: None,
+            thumbnail_url: None,
+            published: true,
+            prerequisites: Vec::new(&env),
+            is_archived: false,
+            level: None,
+            duration_hours: None,
+        }
+    }
+}
+
+#[contract]
+pub struct CourseAccess;
+
+#[contractimpl]
+impl CourseAccess {
+    pub fn has_access(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().has(&user)
+    }
+    pub fn grant_access_with_source(
+        env: Env,
+        granter: Address,
+        _course_id: String,
+        user: Address,
+        source: EnrollmentSource,
+    ) {
+        granter.require_auth();
+        env.storage().instance().set(&user, &source);
+    }
+    pub fn revoke_access(
+        env: Env,
+        _caller: Address,
+        _course_id: String,
+        user: Address,
+        reason: Option<RevocationReason>,
+        _note_hash: Option<BytesN<32>>,
+    ) -> bool {
+        env.storage()
+            .instance()
+            .set(&(user.clone(), "revoked"), &reason);
+        let had_access: bool = env.storage().instance().has(&user);
+        env.storage().instance().remove(&user);
+        had_access
+    }
+    pub fn revocation_reason(env: Env, user: Address) -> Option<RevocationReason> {
+        env.storage()
+            .instance()
+            .get::<_, Option<RevocationReason>>(&(user, "revoked"))
+            .flatten()
+    }
+}
+
+struct Setup<'a> {
+    client: PaymentsContractClient<'a>,
+    course_registry: CourseRegistryClient<'a>,
+    token: token::TokenClient<'a>,
+    course_access: CourseAccessClient<'a>,
+    owner: Address,
+    treasury: Address,
+    instructor: Address,
+    student: Address,
+    course_id: String,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let course_registry_addr: Address = env.register(CourseRegistry, ());
+    let course_access_addr: Address = env.register(CourseAccess, ());
+    let token_admin: Address = Address::generate(env);
+    let token_addr: Address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let contract_id: Address = env.register(PaymentsContract, ());
+    let client = PaymentsContractClient::new(env, &contract_id);
+
+    let owner: Address = Address::generate(env);
+    let treasury: Address = Address::generate(env);
+    client.initialize(&PaymentsConfig {
+        owner: owner.clone(),
+        token: token_addr.clone(),
+        course_registry_addr: course_registry_addr.clone(),
+        course_access_addr: course_access_addr.clone(),
+        treasury: treasury.clone(),
+        platform_fee_bps: 1_000,
+        refund_window: REFUND_WINDOW,
+    });
+
+    let instructor: Address = Address::generate(env);
+    let course_registry = CourseRegistryClient::new(env, &course_registry_addr);
+    course_registry.set_creator(&instructor);
+    let student: Address = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&student, &(PRICE as i128));
+
+    Setup {
+        client,
+        course_registry,
+        token: token::TokenClient::new(env, &token_addr),
+        course_access: CourseAccessClient::new(env, &course_access_addr),
+        owner,
+        treasury,
+        instructor,
+        student,
+        course_id: String::from_str(env, "1"),
+    }
+}
+
+#[test]
+fn test_purchase_credits_are_held_then_withdrawn() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    assert_eq!(purchase.price, 1_000);
+    assert_eq!(purchase.fee, 100);
+    assert!(s.course_access.has_access(&s.course_id, &s.student));
+    assert_eq!(s.course_registry.get_revenue(), 1_000);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+    assert_eq!(
+        s.client.get_balance(&s.instructor),
+        Balance {
+            available: 0,
+            held: 900
+        }
+    );
+
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    assert_eq!(
+        s.client.get_balance(&s.instructor),
+        Balance {
+            available: 900,
+            held: 0
+        }
+    );
+
+    s.client.withdraw(&s.instructor, &600);
+    assert_eq!(s.token.balance(&s.instructor), 600);
+    assert_eq!(
+        s.client.get_balance(&s.instructor),
+        Balance {
+            available: 300,
+            held: 0
+        }
+    );
+
+    // The fee was released to the treasury along with the instructor credit
+    s.client.withdraw(&s.treasury, &100);
+    assert_eq!(s.token.balance(&s.treasury), 100);
+    assert_eq!(s.token.balance(&s.client.address), 300);
+}
+
+#[test]
+fn test_held_credits_are_released_in_batches_skipping_refunds() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let asset = token::StellarAssetClient::new(&env, &s.token.address);
+
+    let mut first_id: u32 = 0;
+    for i in 0..MAX_RELEASE_BATCH + 5 {
+        let student: Address = Address::generate(&env);
+        asset.mint(&student, &(PRICE as i128));
+        let purchase = s.client.purchase_course(&student, &s.course_id);
+        if i == 0 {
+            first_id = purchase.id;
+        }
+    }
+    s.client.refund_purchase(&s.owner, &first_id);
+    let queue = |s: &Setup| -> Option<HeldQueue> {
+        env.as_contract(&s.client.address, || {
+            env.storage()
+                .persistent()
+                .get(&DataKey::HeldQueue(s.instructor.clone()))
+        })
+    };
+    assert_eq!(
+        queue(&s),
+        Some(HeldQueue {
+            head: 0,
+            tail: MAX_RELEASE_BATCH + 5
+        })
+    );
+
+    // The refunded credit is skipped; one batch is released per call
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    s.client.release_earnings(&s.instructor);
+    assert_eq!(
+        queue(&s),
+        Some(HeldQueue {
+            head: MAX_RELEASE_BATCH,
+            tail: MAX_RELEASE_BATCH + 5
+        })
+    );
+    assert_eq!(
+        s.client.release_earnings(&s.instructor),
+        Balance {
+            available: 900 * (MAX_RELEASE_BATCH as i128 + 4),
+            held: 0
+        }
+    );
+    assert_eq!(queue(&s), None);
+    assert_eq!(
+        s.client.get_balance(&s.treasury).available,
+        100 * (MAX_RELEASE_BATCH as i128 + 4)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_held_credits_cannot_be_withdrawn() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.purchase_course(&s.student, &s.course_id);
+    s.client.withdraw(&s.instructor, &1);
+}
+
+#[test]
+fn test_refund_inside_window_drops_held_credits() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    let refunded = s.client.refund_purchase(&s.owner, &purchase.id);
+
+    assert!(refunded.refunded);
+    assert_eq!(s.token.balance(&s.student), 1_000);
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    assert_eq!(
+        s.client.get_balance(&s.instructor),
+        Balance {
+            available: 0,
+            held: 0
+        }
+    );
+}
+
+#[test]
+fn test_refund_revokes_access() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    assert!(s.course_access.has_access(&s.course_id, &s.student));
+    s.client.refund_purchase(&s.owner, &purchase.id);
+
+    assert!(!s.course_access.has_access(&s.course_id, &s.student));
+    assert_eq!(
+        s.course_access.revocation_reason(&s.student),
+        Some(RevocationReason::Refund)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_refund_after_window_fails() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    s.client.refund_purchase(&s.owner, &purchase.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_course_cannot_be_bought_twice() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.purchase_course(&s.student, &s.course_id);
+    s.client.purchase_course(&s.student, &s.course_id);
+}
+
+#[test]
+fn test_fee_changes_apply_to_later_purchases() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let new_treasury: Address = Address::generate(&env);
+
+    s.client.set_platform_fee(&s.owner, &2_500);
+    let fees = s.client.set_treasury(&s.owner, &new_treasury);
+    assert_eq!(
+        fees,
+        FeeConfig {
+            treasury: new_treasury.clone(),
+            platform_fee_bps: 2_500,
+        }
+    );
+    assert_eq!(s.client.get_fee_config(), fees);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    assert_eq!(purchase.fee, 250);
+
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    s.client.release_earnings(&s.instructor);
+    assert_eq!(s.client.get_balance(&new_treasury).available, 250);
+    assert_eq!(s.client.get_balance(&s.instructor).available, 750);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_platform_fee_cannot_exceed_the_price() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_platform_fee(&s.owner, &10_001);
+}
+
+#[test]
+fn test_purchase_rejects_prices_too_high_to_take_a_fee_from() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    for price in [u128::MAX, MAX_PURCHASE_PRICE as u128 + 1] {
+        s.course_registry.set_price(&price);
+        assert_eq!(
+            s.client.try_purchase_course(&s.student, &s.course_id),
+            Err(Ok(Error::PriceTooHigh.into()))
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_only_the_owner_changes_fees() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_treasury(&s.instructor, &s.instructor);
+}
+
+#[test]
+fn test_first_purchase_of_referee_rewards_referrer() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let referrer: Address = Address::generate(&env);
+    s.client.set_referral_reward(&s.owner, &500);
+    s.client.register_referral(&referrer, &s.student);
+    assert_eq!(s.client.get_referrer(&s.student), Some(referrer.clone()));
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    assert_eq!(purchase.referrer, Some(referrer.clone()));
+    assert_eq!(purchase.referral_reward, 50);
+    assert_eq!(
+        s.client.get_referral_stats(&referrer),
+        ReferralStats {
+            referrals: 1,
+            conversions: 1,
+            rewards: 50,
+        }
+    );
+
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    s.client.release_earnings(&s.instructor);
+    assert_eq!(s.client.get_balance(&referrer).available, 50);
+    assert_eq!(s.client.get_balance(&s.treasury).available, 50);
+    assert_eq!(s.client.get_balance(&s.instructor).available, 900);
+
+    // Later purchases are not attributed again
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&s.student, &1_000);
+    let second = s
+        .client
+        .purchase_course(&s.student, &String::from_str(&env, "2"));
+    assert_eq!(second.referrer, None);
+    assert_eq!(s.client.get_referral_stats(&referrer).conversions, 1);
+}
+
+#[test]
+fn test_refund_cancels_referral_reward() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let referrer: Address = Address::generate(&env);
+    s.client.set_referral_reward(&s.owner, &500);
+    s.client.register_referral(&referrer, &s.student);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    s.client.refund_purchase(&s.owner, &purchase.id);
+
+    assert_eq!(s.client.get_referral_stats(&referrer).rewards, 0);
+    assert_eq!(
+        s.client.get_balance(&referrer),
+        Balance {
+            available: 0,
+            held: 0
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_existing_customers_cannot_be_referred() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.purchase_course(&s.student, &s.course_id);
+    s.client
+        .register_referral(&Address::generate(&env), &s.student);
+}
+
+#[test]
+fn test_pause_freezes_purchases_and_withdrawals() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    s.client.pause(&s.owner);
+    assert!(s.client.is_paused());
+    assert_eq!(
+        s.client.try_purchase_course(&s.student, &s.course_id).err(),
+        Some(Ok(crate::error::Error::ContractPaused.into()))
+    );
+    assert!(s.client.try_withdraw(&s.treasury, &1).is_err());
+    assert!(s.client.try_pause(&s.instructor).is_err());
+
+    s.client.unpause(&s.owner);
+    assert!(!s.client.is_paused());
+    s.client.purchase_course(&s.student, &s.course_id);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+}
+
+#[test]
+fn test_partial_refund_claws_back_held_credits() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    let disputes: Address = Address::generate(&env);
+    s.client.set_disputes_contract(&s.owner, &disputes);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    assert_eq!(
+        s.client.get_course_purchase(&s.course_id, &s.student),
+        Some(purchase.clone())
+    );
+    let refunded = s
+        .client
+        .refund_purchase_partial(&disputes, &purchase.id, &950);
+    assert!(!refunded.refunded);
+    assert_eq!(s.client.get_refunded_amount(&purchase.id), 950);
+    assert_eq!(s.token.balance(&s.student), 950);
+    assert!(s.course_access.has_access(&s.course_id, &s.student));
+
+    // The instructor share went first, then the fee held with it
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    s.client.release_earnings(&s.instructor);
+    assert_eq!(s.client.get_balance(&s.instructor).available, 0);
+    assert_eq!(s.client.get_balance(&s.treasury).available, 50);
+}
+
+#[test]
+fn test_full_refund_pays_back_what_partial_refunds_left() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    s.client
+        .refund_purchase_partial(&s.owner, &purchase.id, &300);
+    s.client.refund_purchase(&s.owner, &purchase.id);
+
+    assert_eq!(s.token.balance(&s.student), 1_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(s.client.get_refunded_amount(&purchase.id), 1_000);
+}
+
+#[test]
+fn test_partial_refunds_of_the_full_price_release_the_course() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    s.client
+        .refund_purchase_partial(&s.owner, &purchase.id, &400);
+    let refunded = s
+        .client
+        .refund_purchase_partial(&s.owner, &purchase.id, &600);
+    assert!(refunded.refunded);
+    assert_eq!(s.client.get_course_purchase(&s.course_id, &s.student), None);
+    assert_eq!(s.token.balance(&s.student), 1_000);
+
+    // Refunded in full, the course can be bought again
+    let again = s.client.purchase_course(&s.student, &s.course_id);
+    assert_eq!(again.id, purchase.id + 1);
+    assert_eq!(
+        s.client.get_course_purchase(&s.course_id, &s.student),
+        Some(again)
+    );
+}
+
+#[test]
+fn test_partial_refunds_of_the_full_price_revoke_access() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    s.client
+        .refund_purchase_partial(&s.owner, &purchase.id, &400);
+    assert!(s.course_access.has_access(&s.course_id, &s.student));
+    s.client
+        .refund_purchase_partial(&s.owner, &purchase.id, &600);
+
+    assert!(!s.course_access.has_access(&s.course_id, &s.student));
+    assert_eq!(
+        s.course_access.revocation_reason(&s.student),
+        Some(RevocationReason::Refund)
+    );
+}
+
+#[test]
+fn test_purchase_rejects_a_refund_window_past_the_last_timestamp() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+    env.as_contract(&s.client.address, || {
+        let mut config: PaymentsConfig = get_config(&env);
+        config.refund_window = u64::MAX;
+        env.storage().instance().set(&DataKey::Config, &config);
+    });
+
+    assert_eq!(
+        s.client.try_purchase_course(&s.student, &s.course_id),
+        Err(Ok(Error::RefundWindowTooLong.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_partial_refunds_are_capped_at_the_price_paid() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    s.client
+        .refund_purchase_partial(&s.owner, &purchase.id, &600);
+    s.client
+        .refund_purchase_partial(&s.owner, &purchase.id, &401);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_partial_refund_after_release_fails() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += REFUND_WINDOW);
+    s.client
+        .refund_purchase_partial(&s.owner, &purchase.id, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_only_the_owner_or_disputes_refund_partially() {
+    let env: Env = Env::default();
+    let s = setup(&env);
+
+    let purchase = s.client.purchase_course(&s.student, &s.course_id);
+    s.client
+        .refund_purchase_partial(&s.instructor, &purchase.id, &100);
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    EmptyCourseId = 4,
+    NoCourseAccess = 5,
+    ModuleNotFound = 6,
+    LessonAlreadyCompleted = 7,
+    InvalidDayRange = 8,
+    InvalidLeaderboardSize = 9,
+    PrerequisiteModulesIncomplete = 10,
+    InvalidQuizScore = 11,
+    ModuleNotReleased = 12,
+    SessionNotFound = 13,
+    InvalidSessionTopic = 14,
+    SessionNotOpen = 15,
+    AlreadyCheckedIn = 16,
+    SessionFull = 17,
+}
+
+pub fn handle_error(env: &Env, error: Error) -> ! {
+    panic_with_error!(env, error);
+}
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "AlreadyInitialized: The contract is already initialized",
+        2 => "NotInitialized: The contract is not initialized",
+        3 => "Unauthorized: The caller is not allowed to do this",
+        4 => "EmptyCourseId: The course ID is empty",
+        5 => "NoCourseAccess: The user has no access to the course",
+        6 => "ModuleNotFound: The module does not exist",
+        7 => "LessonAlreadyCompleted: The lesson is already completed",
+        8 => "InvalidDayRange: The day range is not valid",
+        9 => "InvalidLeaderboardSize: The leaderboard size is out of range",
+        10 => "PrerequisiteModulesIncomplete: The prerequisite modules are not completed yet",
+        11 => "InvalidQuizScore: The quiz score is above 100",
+        12 => "ModuleNotReleased: The drip schedule of the course has not released the module yet",
+        13 => "SessionNotFound: The live session does not exist",
+        14 => "InvalidSessionTopic: The session topic is empty or too long",
+        15 => "SessionNotOpen: The live session is not accepting check-ins",
+        16 => "AlreadyCheckedIn: The student already checked in to the session",
+        17 => "SessionFull: The live session has reached its attendee limit",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! Progress events are published with the topics
+//! `("lesson" | "course", "completed", course_id)` or
+//! `("session", "created" | "checkin", course_id)` and a typed payload whose
+//! first field is `version`, following the same convention as the course
+//! registry.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+use crate::schema::{CourseProgress, LiveSession};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const LESSON: Symbol = symbol_short!("lesson");
+const COURSE: Symbol = symbol_short!("course");
+
+const SESSION: Symbol = symbol_short!("session");
+
+const COMPLETED: Symbol = symbol_short!("completed");
+const CREATED: Symbol = symbol_short!("created");
+const CHECKED_IN: Symbol = symbol_short!("checkin");
+
+/// Payload of `("lesson", "completed", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LessonCompletedEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub module_id: String,
+    pub user: Address,
+    /// Completed share of the course after this lesson
+    pub percentage: u32,
+}
+
+/// Payload of `("course", "completed", course_id)`, published when a user
+/// reaches 100%.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseCompletedEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub user: Address,
+}
+
+/// Payload of `("session", "created", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionCreatedEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub session_id: u32,
+    pub instructor: Address,
+    pub starts_at: u64,
+}
+
+/// Payload of `("session", "checkin", course_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckedInEvent {
+    pub version: u32,
+    pub course_id: String,
+    pub session_id: u32,
+    pub user: Address,
+    /// Sessions of the course the user attended, including this one
+    pub sessions_attended: u32,
+}
+
+pub fn lesson_completed(env: &Env, progress: &CourseProgress, module_id: &String) {
+    env.events().publish(
+        (LESSON, COMPLETED, progress.course_id.clone()),
+        LessonCompletedEvent {
+            version: EVENT_VERSION,
+            course_id: progress.course_id.clone(),
+            module_id: module_id.clone(),
+            user: progress.user.clone(),
+            percentage: progress.percentage,
+        },
+    );
+}
+
+pub fn course_completed(env: &Env, progress: &CourseProgress) {
+    env.events().publish(
+        (COURSE, COMPLETED, progress.course_id.clone()),
+        CourseCompletedEvent {
+            version: EVENT_VERSION,
+            course_id: progress.course_id.clone(),
+            user: progress.user.clone(),
+        },
+    );
+}
+
+pub fn session_created(env: &Env, session: &LiveSession) {
+    env.events().publish(
+        (SESSION, CREATED, session.course_id.clone()),
+        SessionCreatedEvent {
+            version: EVENT_VERSION,
+            course_id: session.course_id.clone(),
+            session_id: session.id,
+            instructor: session.instructor.clone(),
+            starts_at: session.starts_at,
+        },
+    );
+}
+
+pub fn checked_in(env: &Env, session: &LiveSession, user: &Address, sessions_attended: u32) {
+    env.events().publish(
+        (SESSION, CHECKED_IN, session.course_id.clone()),
+        CheckedInEvent {
+            version: EVENT_VERSION,
+            course_id: session.course_id.clone(),
+            session_id: session.id,
+            user: user.clone(),
+            sessions_attended,
+        },
+    );
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Daily learning activity and streaks.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DailyActivity, DataKey, LearningStreak, MAX_HISTORY_DAYS, SECONDS_PER_DAY};
+
+/// Index of the current day since the Unix epoch.
+pub fn current_day(env: &Env) -> u64 {
+    env.ledger().timestamp() / SECONDS_PER_DAY
+}
+
+fn get_streak(env: &Env, user: &Address) -> Option<LearningStreak> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Streak(user.clone()))
+}
+
+/// Counts a completed lesson in today's activity bucket and extends the
+/// user's streak on the first lesson of the day.
+pub(crate) fn record_activity(env: &Env, user: &Address) {
+    let today: u64 = current_day(env);
+    let day_key: DataKey = DataKey::ActivityDay(user.clone(), today);
+    let lessons: u32 = env.storage().persistent().get(&day_key).unwrap_or(0);
+    env.storage().persistent().set(&day_key, &(lessons + 1));
+
+    let mut streak: LearningStreak = match get_streak(env, user) {
+        Some(streak) if streak.last_day == today => return,
+        Some(streak) if streak.last_day + 1 == today => LearningStreak {
+            current: streak.current + 1,
+            ..streak
+        },
+        Some(streak) => LearningStreak {
+            current: 1,
+            ..streak
+        },
+        None => LearningStreak {
+            current: 1,
+            ..LearningStreak::default()
+        },
+    };
+    streak.last_day = today;
+    streak.longest = streak.longest.max(streak.current);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Streak(user.clone()), &streak);
+}
+
+/// Returns the number of consecutive days, ending today or yesterday, on
+/// which the user completed at least one lesson.
+pub fn get_learning_streak(env: &Env, user: Address) -> u32 {
+    match get_streak(env, &user) {
+        Some(streak) if streak.last_day + 1 >= current_day(env) => streak.current,
+        _ => 0,
+    }
+}
+
+/// Returns the days between `from_day` and `to_day` (inclusive) on which
+/// the user completed lessons, oldest first. At most `MAX_HISTORY_DAYS`
+/// days can be queried at once.
+pub fn get_activity_history(
+    env: &Env,
+    user: Address,
+    from_day: u64,
+    to_day: u64,
+) -> Vec<DailyActivity> {
+    if from_day > to_day || to_day - from_day >= MAX_HISTORY_DAYS {
+        handle_error(env, Error::InvalidDayRange)
+    }
+
+    let mut history: Vec<DailyActivity> = Vec::new(env);
+    for day in from_day..=to_day {
+        if let Some(lessons) = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::ActivityDay(user.clone(), day))
+        {
+            history.push_back(DailyActivity { day, lessons });
+        }
+    }
+    history
+}
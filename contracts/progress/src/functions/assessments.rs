@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Quiz and assignment results counted by course completion criteria.
+
+use skillcert_interfaces::course_registry::CourseRegistryClient;
+use soroban_sdk::{Address, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::functions::config::get_config;
+use crate::functions::lessons::recheck_completion;
+use crate::schema::{Assessment, DataKey, MAX_QUIZ_SCORE};
+
+pub(crate) fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
+    CourseRegistryClient::new(env, &get_config(env).course_registry_addr)
+        .is_course_creator(course_id, who)
+}
+
+/// Returns the quiz and assignment results of a user in a course, if any
+/// were recorded.
+pub fn get_assessment(env: &Env, user: Address, course_id: String) -> Option<Assessment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Assessment(course_id, user))
+}
+
+/// Records a quiz score and whether the assignments of a student are
+/// completed (course creator or owner only).
+///
+/// Only the best quiz score is kept, and assignments stay completed once
+/// marked. Completes the course if the student now meets its criteria.
+pub fn record_assessment(
+    env: &Env,
+    grader: Address,
+    student: Address,
+    course_id: String,
+    quiz_score: Option<u32>,
+    assignments_completed: bool,
+) -> Assessment {
+    grader.require_auth();
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if get_config(env).owner != grader && !is_course_creator(env, &course_id, &grader) {
+        handle_error(env, Error::Unauthorized)
+    }
+    if quiz_score.is_some_and(|score| score > MAX_QUIZ_SCORE) {
+        handle_error(env, Error::InvalidQuizScore)
+    }
+
+    let mut assessment: Assessment = get_assessment(env, student.clone(), course_id.clone())
+        .unwrap_or(Assessment {
+            best_quiz_score: None,
+            assignments_completed: false,
+            updated_at: 0,
+        });
+    if quiz_score > assessment.best_quiz_score {
+        assessment.best_quiz_score = quiz_score;
+    }
+    assessment.assignments_completed |= assignments_completed;
+    assessment.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &DataKey::Assessment(course_id.clone(), student.clone()),
+        &assessment,
+    );
+
+    recheck_completion(env, &student, &course_id);
+    assessment
+}
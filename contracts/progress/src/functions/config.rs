@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, ProgressConfig};
+
+const INIT_EVENT: Symbol = symbol_short!("init");
+
+/// Stores the owner and the contracts used to look up modules and access
+/// and to report completed courses.
+pub fn initialize(env: &Env, config: ProgressConfig) {
+    config.owner.require_auth();
+
+    if env.storage().instance().has(&DataKey::Config) {
+        handle_error(env, Error::AlreadyInitialized)
+    }
+    env.storage().instance().set(&DataKey::Config, &config);
+
+    env.events().publish((INIT_EVENT,), config);
+}
+
+/// Returns whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Config)
+}
+
+/// Returns the configuration, panicking with `NotInitialized` before `initialize`.
+pub fn get_config(env: &Env) -> ProgressConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Per-course leaderboards, maintained as lessons are completed.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::lessons::get_progress;
+use crate::schema::{CourseProgress, DataKey, LeaderboardEntry, MAX_LEADERBOARD_SIZE};
+
+fn get_board(env: &Env, course_id: &String) -> Vec<LeaderboardEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Leaderboard(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_board(env: &Env, course_id: &String, board: &Vec<LeaderboardEntry>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Leaderboard(course_id.clone()), board);
+}
+
+/// Whether `entry` ranks above `other`: higher percentage first, then the
+/// one who got there earlier.
+fn ranks_above(entry: &LeaderboardEntry, other: &LeaderboardEntry) -> bool {
+    entry.percentage > other.percentage
+        || (entry.percentage == other.percentage && entry.updated_at < other.updated_at)
+}
+
+fn remove_entry(board: &mut Vec<LeaderboardEntry>, user: &Address) {
+    if let Some(index) = board.iter().position(|entry| entry.user == *user) {
+        board.remove(index as u32);
+    }
+}
+
+/// Returns whether a user opted out of the leaderboards.
+pub fn is_leaderboard_opted_out(env: &Env, user: Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LeaderboardOptOut(user))
+        .unwrap_or(false)
+}
+
+/// Records a user in the course index used to update their leaderboard
+/// entries when they opt in or out.
+pub(crate) fn track_course(env: &Env, user: &Address, course_id: &String) {
+    let key: DataKey = DataKey::UserCourses(user.clone());
+    let mut courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !courses.contains(course_id) {
+        courses.push_back(course_id.clone());
+        env.storage().persistent().set(&key, &courses);
+    }
+}
+
+/// Moves the user to their rank on the course leaderboard after progress,
+/// keeping only the top `MAX_LEADERBOARD_SIZE` entries.
+pub(crate) fn update_leaderboard(env: &Env, progress: &CourseProgress) {
+    if is_leaderboard_opted_out(env, progress.user.clone()) {
+        return;
+    }
+    let entry: LeaderboardEntry = LeaderboardEntry {
+        user: progress.user.clone(),
+        percentage: progress.percentage,
+        updated_at: progress.updated_at,
+    };
+    let mut board: Vec<LeaderboardEntry> = get_board(env, &progress.course_id);
+    remove_entry(&mut board, &entry.user);
+
+    let rank: u32 = board
+        .iter()
+        .position(|other| ranks_above(&entry, &other))
+        .map_or(board.len(), |index| index as u32);
+    if rank >= MAX_LEADERBOARD_SIZE {
+        return set_board(env, &progress.course_id, &board);
+    }
+    board.insert(rank, entry);
+    if board.len() > MAX_LEADERBOARD_SIZE {
+        board.pop_back();
+    }
+    set_board(env, &progress.course_id, &board);
+}
+
+/// Hides the user from every course leaderboard, or puts them back at the
+/// rank their current progress earns.
+pub fn set_leaderboard_opt_out(env: &Env, user: Address, opted_out: bool) {
+    user.require_auth();
+    env.storage()
+        .persistent()
+        .set(&DataKey::LeaderboardOptOut(user.clone()), &opted_out);
+
+    let courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserCourses(user.clone()))
+        .unwrap_or(Vec::new(env));
+    for course_id in courses.iter() {
+        if opted_out {
+            let mut board: Vec<LeaderboardEntry> = get_board(env, &course_id);
+            remove_entry(&mut board, &user);
+            set_board(env, &course_id, &board);
+        } else if let Some(progress) = get_progress(env, user.clone(), course_id) {
+            update_leaderboard(env, &progress);
+        }
+    }
+}
+
+/// Returns the top `n` entries of a course leaderboard, best first.
+pub fn get_leaderboard(env: &Env, course_id: String, n: u32) -> Vec<LeaderboardEntry> {
+    if n == 0 || n > MAX_LEADERBOARD_SIZE {
+        handle_error(env, Error::InvalidLeaderboardSize)
+    }
+    let board: Vec<LeaderboardEntry> = get_board(env, &course_id);
+    board.slice(0..n.min(board.len()))
+}
@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Lesson completion and course progress.
+
+use skillcert_interfaces::course_access::CourseAccessClient;
+use skillcert_interfaces::course_registry::CourseRegistryClient;
+use skillcert_interfaces::user_management::UserManagementClient;
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::activity::record_activity;
+use crate::functions::assessments::get_assessment;
+use crate::functions::config::get_config;
+use crate::functions::leaderboard::{track_course, update_leaderboard};
+use crate::functions::sessions::get_sessions_attended;
+use crate::schema::{
+    Assessment, CompletionCriteria, CourseModule, CourseProgress, DataKey, ModuleLockStatus,
+    ProgressConfig,
+};
+
+pub(crate) fn has_course_access(env: &Env, course_id: &String, user: &Address) -> bool {
+    CourseAccessClient::new(env, &get_config(env).course_access_addr).has_access(course_id, user)
+}
+
+/// Modules of the course the student can open now, following the course's
+/// drip schedule.
+fn unlocked_modules(env: &Env, course_id: &String, user: &Address) -> Vec<String> {
+    CourseAccessClient::new(env, &get_config(env).course_access_addr)
+        .get_unlocked_modules(user, course_id)
+}
+
+/// Reports a completed course to user management, which unlocks the
+/// achievements the user now qualifies for, and to the course statistics of
+/// the course registry.
+///
+/// Best effort: both only accept reports from allowlisted contracts, and a
+/// rejected report must not undo the completion itself.
+fn report_course_completion(env: &Env, course_id: &String, user: &Address) {
+    let config: ProgressConfig = get_config(env);
+    let user_management = UserManagementClient::new(env, &config.user_mgmt_addr);
+    let _ = user_management.try_record_course_completion(&env.current_contract_address(), user);
+    let course_registry = CourseRegistryClient::new(env, &config.course_registry_addr);
+    let _ = course_registry.try_record_completion(&env.current_contract_address(), course_id);
+}
+
+fn course_modules(env: &Env, course_id: &String) -> Vec<CourseModule> {
+    CourseRegistryClient::new(env, &get_config(env).course_registry_addr).list_modules(course_id)
+}
+
+fn module_prerequisites(env: &Env, module_id: &String) -> Vec<String> {
+    CourseRegistryClient::new(env, &get_config(env).course_registry_addr)
+        .get_module_prerequisites(module_id)
+}
+
+fn completion_criteria(env: &Env, course_id: &String) -> CompletionCriteria {
+    CourseRegistryClient::new(env, &get_config(env).course_registry_addr)
+        .get_completion_criteria(course_id)
+}
+
+/// Whether `progress` meets the completion criteria of its course. Required
+/// modules removed from the course no longer count.
+fn meets_completion_criteria(
+    env: &Env,
+    modules: &Vec<CourseModule>,
+    progress: &CourseProgress,
+) -> bool {
+    let criteria: CompletionCriteria = completion_criteria(env, &progress.course_id);
+    let modules_done: bool = if criteria.required_modules.is_empty() {
+        progress.percentage == 100
+    } else {
+        criteria.required_modules.iter().all(|module_id| {
+            progress.completed_modules.contains(&module_id)
+                || !modules.iter().any(|module| module.id == module_id)
+        })
+    };
+    if !modules_done {
+        return false;
+    }
+    if criteria.min_sessions_attended.is_some_and(|min_sessions| {
+        get_sessions_attended(env, progress.user.clone(), progress.course_id.clone()) < min_sessions
+    }) {
+        return false;
+    }
+    if criteria.min_quiz_score.is_none() && !criteria.require_assignments {
+        return true;
+    }
+
+    let assessment: Option<Assessment> =
+        get_assessment(env, progress.user.clone(), progress.course_id.clone());
+    let quiz_passed: bool = criteria.min_quiz_score.is_none_or(|min_score| {
+        assessment
+            .as_ref()
+            .and_then(|assessment| assessment.best_quiz_score)
+            .is_some_and(|score| score >= min_score)
+    });
+    let assignments_done: bool = !criteria.require_assignments
+        || assessment.is_some_and(|assessment| assessment.assignments_completed);
+    quiz_passed && assignments_done
+}
+
+/// Sets `completed_at` when an incomplete `progress` now meets its course's
+/// completion criteria, and returns whether it did.
+fn mark_completed(env: &Env, modules: &Vec<CourseModule>, progress: &mut CourseProgress) -> bool {
+    if progress.completed_at.is_some() || !meets_completion_criteria(env, modules, progress) {
+        return false;
+    }
+    progress.completed_at = Some(env.ledger().timestamp());
+    true
+}
+
+/// Completes the course of a student whose progress now meets its criteria
+/// after something other than a lesson changed, such as an assessment.
+pub(crate) fn recheck_completion(env: &Env, student: &Address, course_id: &String) {
+    let Some(mut progress) = get_progress(env, student.clone(), course_id.clone()) else {
+        return;
+    };
+    let modules: Vec<CourseModule> = course_modules(env, course_id);
+    if mark_completed(env, &modules, &mut progress) {
+        env.storage().persistent().set(
+            &DataKey::Progress(course_id.clone(), student.clone()),
+            &progress,
+        );
+        events::course_completed(env, &progress);
+        report_course_completion(env, course_id, student);
+    }
+}
+
+/// Prerequisites of `module_id` that are modules of the course and not in
+/// `completed`. Prerequisites removed from the course no longer block it.
+fn missing_prerequisites(
+    env: &Env,
+    modules: &Vec<CourseModule>,
+    completed: &Vec<String>,
+    module_id: &String,
+) -> Vec<String> {
+    let mut missing: Vec<String> = Vec::new(env);
+    for prerequisite in module_prerequisites(env, module_id).iter() {
+        if !completed.contains(&prerequisite)
+            && modules.iter().any(|module| module.id == prerequisite)
+        {
+            missing.push_back(prerequisite);
+        }
+    }
+    missing
+}
+
+/// Returns the progress of a user in a course, if they completed any lesson.
+pub fn get_progress(env: &Env, user: Address, course_id: String) -> Option<CourseProgress> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Progress(course_id, user))
+}
+
+/// Returns whether a user met the completion criteria of a course.
+pub fn is_course_completed(env: &Env, user: Address, course_id: String) -> bool {
+    get_progress(env, user, course_id).is_some_and(|progress| progress.completed_at.is_some())
+}
+
+/// Marks a module of a course completed by a student with access to the
+/// course, and logs it in the student's daily activity.
+pub fn complete_lesson(
+    env: &Env,
+    student: Address,
+    course_id: String,
+    module_id: String,
+) -> CourseProgress {
+    student.require_auth();
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if !has_course_access(env, &course_id, &student) {
+        handle_error(env, Error::NoCourseAccess)
+    }
+    let modules: Vec<CourseModule> = course_modules(env, &course_id);
+    if !modules.iter().any(|module| module.id == module_id) {
+        handle_error(env, Error::ModuleNotFound)
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let mut progress: CourseProgress = get_progress(env, student.clone(), course_id.clone())
+        .unwrap_or(CourseProgress {
+            course_id: course_id.clone(),
+            user: student.clone(),
+            completed_modules: Vec::new(env),
+            total_modules: 0,
+            percentage: 0,
+            completed_at: None,
+            updated_at: now,
+        });
+    if progress.completed_modules.contains(&module_id) {
+        handle_error(env, Error::LessonAlreadyCompleted)
+    }
+    if !missing_prerequisites(env, &modules, &progress.completed_modules, &module_id).is_empty() {
+        handle_error(env, Error::PrerequisiteModulesIncomplete)
+    }
+    if !unlocked_modules(env, &course_id, &student).contains(&module_id) {
+        handle_error(env, Error::ModuleNotReleased)
+    }
+
+    progress.completed_modules.push_back(module_id.clone());
+    // Modules removed since they were completed no longer count
+    let completed: u32 = modules
+        .iter()
+        .filter(|module| progress.completed_modules.contains(&module.id))
+        .count() as u32;
+    progress.total_modules = modules.len();
+    progress.percentage = completed * 100 / progress.total_modules;
+    progress.updated_at = now;
+    let just_completed: bool = mark_completed(env, &modules, &mut progress);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Progress(course_id, student.clone()), &progress);
+    track_course(env, &student, &progress.course_id);
+    update_leaderboard(env, &progress);
+    record_activity(env, &student);
+
+    events::lesson_completed(env, &progress, &module_id);
+    if just_completed {
+        events::course_completed(env, &progress);
+        report_course_completion(env, &progress.course_id, &student);
+    }
+    progress
+}
+
+/// Returns, for each module of a course in registry order, whether the
+/// student completed it, whether the drip schedule released it and which
+/// prerequisite modules still lock it.
+pub fn get_module_lock_status(
+    env: &Env,
+    student: Address,
+    course_id: String,
+) -> Vec<ModuleLockStatus> {
+    let modules: Vec<CourseModule> = course_modules(env, &course_id);
+    let unlocked: Vec<String> = unlocked_modules(env, &course_id, &student);
+    let completed: Vec<String> = get_progress(env, student, course_id)
+        .map(|progress| progress.completed_modules)
+        .unwrap_or(Vec::new(env));
+
+    let mut statuses: Vec<ModuleLockStatus> = Vec::new(env);
+    for module in modules.iter() {
+        let missing: Vec<String> = missing_prerequisites(env, &modules, &completed, &module.id);
+        let released: bool = unlocked.contains(&module.id);
+        statuses.push_back(ModuleLockStatus {
+            completed: completed.contains(&module.id),
+            released,
+            locked: !released || !missing.is_empty(),
+            module_id: module.id,
+            missing_prerequisites: missing,
+        });
+    }
+    statuses
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+pub mod activity;
+pub mod assessments;
+pub mod config;
+pub mod leaderboard;
+pub mod lessons;
+pub mod sessions;
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Live sessions and attendance.
+//!
+//! Course creators schedule live sessions; students with access to the
+//! course check in while a session is open. Attendance counts towards the
+//! `min_sessions_attended` completion criterion of the course.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::assessments::is_course_creator;
+use crate::functions::config::get_config;
+use crate::functions::lessons::{has_course_access, recheck_completion};
+use crate::schema::{
+    DataKey, LiveSession, CHECK_IN_WINDOW, MAX_SESSION_ATTENDEES, MAX_SESSION_TOPIC_LENGTH,
+};
+
+/// Returns a live session, if it exists.
+pub fn get_session(env: &Env, session_id: u32) -> Option<LiveSession> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+}
+
+/// Returns how many live sessions of a course a user checked in to.
+pub fn get_sessions_attended(env: &Env, user: Address, course_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionsAttended(course_id, user))
+        .unwrap_or(0)
+}
+
+/// Schedules a live session of a course (course creator only).
+pub fn create_session(
+    env: &Env,
+    instructor: Address,
+    course_id: String,
+    starts_at: u64,
+    topic: String,
+) -> LiveSession {
+    instructor.require_auth();
+    if course_id.is_empty() {
+        handle_error(env, Error::EmptyCourseId)
+    }
+    if !is_course_creator(env, &course_id, &instructor) {
+        handle_error(env, Error::Unauthorized)
+    }
+    if topic.is_empty() || topic.len() > MAX_SESSION_TOPIC_LENGTH {
+        handle_error(env, Error::InvalidSessionTopic)
+    }
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::SessionCount)
+        .unwrap_or(0)
+        + 1;
+    let session: LiveSession = LiveSession {
+        id,
+        course_id,
+        instructor,
+        starts_at,
+        topic,
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&DataKey::SessionCount, &id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Session(id), &session);
+
+    events::session_created(env, &session);
+    session
+}
+
+/// Checks a student with access to the course in to an open live session,
+/// and returns how many sessions of the course they attended.
+pub fn check_in(env: &Env, student: Address, session_id: u32) -> u32 {
+    student.require_auth();
+    let session: LiveSession =
+        get_session(env, session_id).unwrap_or_else(|| handle_error(env, Error::SessionNotFound));
+    let now: u64 = env.ledger().timestamp();
+    if now < session.starts_at || now >= session.starts_at.saturating_add(CHECK_IN_WINDOW) {
+        handle_error(env, Error::SessionNotOpen)
+    }
+    if !has_course_access(env, &session.course_id, &student) {
+        handle_error(env, Error::NoCourseAccess)
+    }
+
+    let attendees_key: DataKey = DataKey::SessionAttendees(session_id);
+    let mut attendees: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&attendees_key)
+        .unwrap_or(Vec::new(env));
+    if attendees.contains(&student) {
+        handle_error(env, Error::AlreadyCheckedIn)
+    }
+    if attendees.len() >= MAX_SESSION_ATTENDEES {
+        handle_error(env, Error::SessionFull)
+    }
+    attendees.push_back(student.clone());
+    env.storage().persistent().set(&attendees_key, &attendees);
+
+    let attended: u32 = get_sessions_attended(env, student.clone(), session.course_id.clone()) + 1;
+    env.storage().persistent().set(
+        &DataKey::SessionsAttended(session.course_id.clone(), student.clone()),
+        &attended,
+    );
+
+    events::checked_in(env, &session, &student, attended);
+    recheck_completion(env, &student, &session.course_id);
+    attended
+}
+
+/// Returns the students checked in to a session, in check-in order (the
+/// session's instructor or the owner only).
+pub fn get_session_attendance(env: &Env, requester: Address, session_id: u32) -> Vec<Address> {
+    requester.require_auth();
+    let session: LiveSession =
+        get_session(env, session_id).unwrap_or_else(|| handle_error(env, Error::SessionNotFound));
+    if requester != session.instructor && requester != get_config(env).owner {
+        handle_error(env, Error::Unauthorized)
+    }
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionAttendees(session_id))
+        .unwrap_or(Vec::new(env))
+}
@@ -0,0 +1,332 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+/// Contract version for tracking deployments and upgrades
+pub const VERSION: &str = "1.0.0";
+
+pub mod error;
+pub mod events;
+pub mod functions;
+pub mod schema;
+
+#[cfg(test)]
+mod test;
+
+use crate::schema::{
+    Assessment, CourseProgress, DailyActivity, LeaderboardEntry, LiveSession, ModuleLockStatus,
+    ProgressConfig,
+};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+/// Progress Contract
+///
+/// This contract tracks the lessons students complete in their courses and
+/// logs their daily learning activity for streaks and dashboards.
+#[contract]
+pub struct ProgressContract;
+
+#[contractimpl]
+impl ProgressContract {
+    /// Initialize the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `config` - The owner and the user management, course registry and
+    ///   course access contract addresses
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is already initialized
+    pub fn initialize(env: Env, config: ProgressConfig) {
+        functions::config::initialize(&env, config)
+    }
+
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::config::is_initialized(&env)
+    }
+
+    /// Get the contract configuration.
+    pub fn get_config(env: Env) -> ProgressConfig {
+        functions::config::get_config(&env)
+    }
+
+    /// Mark a module of a course completed.
+    ///
+    /// The lesson is also counted in the student's activity for the current
+    /// day, extending their learning streak. Meeting the course's completion
+    /// criteria, by default completing every module, reports the course to
+    /// user management, which unlocks achievements.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student completing the lesson
+    /// * `course_id` - The course the module belongs to
+    /// * `module_id` - The completed module
+    ///
+    /// # Returns
+    ///
+    /// The student's updated progress in the course.
+    ///
+    /// # Panics
+    ///
+    /// * If the student has no access to the course
+    /// * If the module is not part of the course
+    /// * If the student already completed the module
+    /// * If a prerequisite module set in the course registry is not completed
+    /// * If the course's drip schedule has not released the module yet
+    ///
+    /// # Edge Cases
+    ///
+    /// * The percentage is computed against the course's current modules, so
+    ///   adding modules to a completed course does not reopen it
+    pub fn complete_lesson(
+        env: Env,
+        student: Address,
+        course_id: String,
+        module_id: String,
+    ) -> CourseProgress {
+        functions::lessons::complete_lesson(&env, student, course_id, module_id)
+    }
+
+    /// Get the progress of a user in a course, `None` if they have not
+    /// completed any lesson.
+    pub fn get_progress(env: Env, user: Address, course_id: String) -> Option<CourseProgress> {
+        functions::lessons::get_progress(&env, user, course_id)
+    }
+
+    /// Get which modules of a course a student can complete.
+    ///
+    /// # Returns
+    ///
+    /// One entry per module of the course, in registry order, telling whether
+    /// the drip schedule released it and listing the prerequisite modules the
+    /// student still has to complete.
+    pub fn get_module_lock_status(
+        env: Env,
+        student: Address,
+        course_id: String,
+    ) -> Vec<ModuleLockStatus> {
+        functions::lessons::get_module_lock_status(&env, student, course_id)
+    }
+
+    /// Record a student's quiz score and assignment status in a course.
+    ///
+    /// These results count towards the course's completion criteria set in
+    /// the course registry; the course is completed as soon as they are met.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `grader` - The course creator or the owner
+    /// * `student` - The assessed student
+    /// * `course_id` - The assessed course
+    /// * `quiz_score` - Quiz score in percent, `None` to leave the scores unchanged
+    /// * `assignments_completed` - Whether the student completed the assignments
+    ///
+    /// # Returns
+    ///
+    /// The student's results, keeping the best quiz score.
+    ///
+    /// # Panics
+    ///
+    /// * If the grader is not the course creator or the owner
+    /// * If the quiz score is above 100
+    ///
+    /// # Edge Cases
+    ///
+    /// * Assignments marked completed stay completed
+    pub fn record_assessment(
+        env: Env,
+        grader: Address,
+        student: Address,
+        course_id: String,
+        quiz_score: Option<u32>,
+        assignments_completed: bool,
+    ) -> Assessment {
+        functions::assessments::record_assessment(
+            &env,
+            grader,
+            student,
+            course_id,
+            quiz_score,
+            assignments_completed,
+        )
+    }
+
+    /// Get a student's recorded quiz and assignment results in a course.
+    pub fn get_assessment(env: Env, user: Address, course_id: String) -> Option<Assessment> {
+        functions::assessments::get_assessment(&env, user, course_id)
+    }
+
+    /// Schedule a live session of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The course creator
+    /// * `course_id` - The course the session belongs to
+    /// * `starts_at` - Ledger timestamp the session starts
+    /// * `topic` - What the session covers, at most 200 bytes
+    ///
+    /// # Returns
+    ///
+    /// The new session, with its ID.
+    ///
+    /// # Panics
+    ///
+    /// * If the instructor is not the course creator
+    /// * If the topic is empty or too long
+    pub fn create_session(
+        env: Env,
+        instructor: Address,
+        course_id: String,
+        starts_at: u64,
+        topic: String,
+    ) -> LiveSession {
+        functions::sessions::create_session(&env, instructor, course_id, starts_at, topic)
+    }
+
+    /// Check in to a live session.
+    ///
+    /// Check-ins are accepted from the session start for four hours. Each
+    /// one counts towards the course's `min_sessions_attended` completion
+    /// criterion and can complete the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student attending
+    /// * `session_id` - The session to check in to
+    ///
+    /// # Returns
+    ///
+    /// The number of sessions of the course the student attended.
+    ///
+    /// # Panics
+    ///
+    /// * If the session doesn't exist or is not accepting check-ins
+    /// * If the student has no access to the course
+    /// * If the student already checked in or the session is full
+    pub fn check_in(env: Env, student: Address, session_id: u32) -> u32 {
+        functions::sessions::check_in(&env, student, session_id)
+    }
+
+    /// Get a live session, `None` if it doesn't exist.
+    pub fn get_session(env: Env, session_id: u32) -> Option<LiveSession> {
+        functions::sessions::get_session(&env, session_id)
+    }
+
+    /// List the students checked in to a live session, in check-in order.
+    ///
+    /// # Panics
+    ///
+    /// * If the requester is neither the session's instructor nor the owner
+    pub fn get_session_attendance(env: Env, requester: Address, session_id: u32) -> Vec<Address> {
+        functions::sessions::get_session_attendance(&env, requester, session_id)
+    }
+
+    /// Get how many live sessions of a course a user checked in to.
+    pub fn get_sessions_attended(env: Env, user: Address, course_id: String) -> u32 {
+        functions::sessions::get_sessions_attended(&env, user, course_id)
+    }
+
+    /// Check whether a user met the completion criteria of a course.
+    ///
+    /// Used by the Course Access contract to enforce course prerequisites.
+    pub fn is_course_completed(env: Env, user: Address, course_id: String) -> bool {
+        functions::lessons::is_course_completed(&env, user, course_id)
+    }
+
+    /// Get the learning streak of a user.
+    ///
+    /// # Returns
+    ///
+    /// The number of consecutive days, ending today or yesterday, on which
+    /// the user completed at least one lesson; 0 if the streak is broken.
+    pub fn get_learning_streak(env: Env, user: Address) -> u32 {
+        functions::activity::get_learning_streak(&env, user)
+    }
+
+    /// Get the daily activity of a user over a range of days.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The user to look up
+    /// * `from_day` - First day of the range, as days since the Unix epoch
+    /// * `to_day` - Last day of the range (inclusive)
+    ///
+    /// # Returns
+    ///
+    /// The days of the range with at least one completed lesson, oldest first.
+    ///
+    /// # Panics
+    ///
+    /// * If `from_day` is after `to_day` or the range spans more than 366 days
+    pub fn get_activity_history(
+        env: Env,
+        user: Address,
+        from_day: u64,
+        to_day: u64,
+    ) -> Vec<DailyActivity> {
+        functions::activity::get_activity_history(&env, user, from_day, to_day)
+    }
+
+    /// Get the top of a course leaderboard.
+    ///
+    /// Leaderboards rank users by completion percentage, earliest first on
+    /// ties, and are updated each time a lesson is completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The course to look up
+    /// * `n` - Number of entries to return, between 1 and 50
+    ///
+    /// # Returns
+    ///
+    /// Up to `n` entries, best first. Users who opted out are not listed.
+    ///
+    /// # Panics
+    ///
+    /// * If `n` is 0 or greater than 50
+    pub fn get_leaderboard(env: Env, course_id: String, n: u32) -> Vec<LeaderboardEntry> {
+        functions::leaderboard::get_leaderboard(&env, course_id, n)
+    }
+
+    /// Hide the caller from every course leaderboard, or list them again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The user changing their preference
+    /// * `opted_out` - `true` to be hidden, `false` to be listed again
+    pub fn set_leaderboard_opt_out(env: Env, user: Address, opted_out: bool) {
+        functions::leaderboard::set_leaderboard_opt_out(&env, user, opted_out)
+    }
+
+    /// Check whether a user opted out of the leaderboards.
+    pub fn is_leaderboard_opted_out(env: Env, user: Address) -> bool {
+        functions::leaderboard::is_leaderboard_opted_out(&env, user)
+    }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
+}
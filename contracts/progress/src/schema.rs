@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+/// Length of an activity day, in seconds. Day `n` starts at `n * SECONDS_PER_DAY`.
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Maximum number of days returned by one `get_activity_history` call.
+pub const MAX_HISTORY_DAYS: u64 = 366;
+
+/// Highest quiz score that can be recorded, in percent.
+pub const MAX_QUIZ_SCORE: u32 = 100;
+
+/// Number of entries kept on each course leaderboard.
+pub const MAX_LEADERBOARD_SIZE: u32 = 50;
+
+/// How long after its start a live session accepts check-ins, in seconds.
+pub const CHECK_IN_WINDOW: u64 = 14_400;
+
+/// Maximum number of students checked in to one live session.
+pub const MAX_SESSION_ATTENDEES: u32 = 500;
+
+/// Maximum length of a live session topic, in bytes.
+pub const MAX_SESSION_TOPIC_LENGTH: u32 = 200;
+
+/// Storage keys of the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Contracts this contract talks to (instance storage)
+    Config,
+    /// Progress of a user in a course: (course_id, user) -> CourseProgress
+    Progress(String, Address),
+    /// Lessons a user completed on a day: (user, day_index) -> u32
+    ActivityDay(Address, u64),
+    /// Consecutive active days of a user: user -> LearningStreak
+    Streak(Address),
+    /// Best progress in a course, best first: course_id -> Vec<LeaderboardEntry>
+    Leaderboard(String),
+    /// Whether a user is hidden from leaderboards: user -> bool
+    LeaderboardOptOut(Address),
+    /// Courses a user has progress in: user -> Vec<String>
+    UserCourses(Address),
+    /// Quiz and assignment results of a user in a course: (course_id, user) -> Assessment
+    Assessment(String, Address),
+    /// Number of live sessions created so far, used as the last session ID
+    SessionCount,
+    /// Live session by ID: session_id -> LiveSession
+    Session(u32),
+    /// Students checked in to a live session, in check-in order: session_id -> Vec<Address>
+    SessionAttendees(u32),
+    /// Live sessions of a course a user checked in to: (course_id, user) -> u32
+    SessionsAttended(String, Address),
+}
+
+/// Owner and addresses of the contracts used to look up modules and access
+/// and to report completed courses.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgressConfig {
+    pub owner: Address,
+    /// Must allow this contract with `set_points_awarder`
+    pub user_mgmt_addr: Address,
+    pub course_registry_addr: Address,
+    pub course_access_addr: Address,
+}
+
+/// Progress of a user in a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseProgress {
+    pub course_id: String,
+    pub user: Address,
+    /// IDs of the completed modules, in completion order
+    pub completed_modules: Vec<String>,
+    /// Number of modules of the course at the last completion
+    pub total_modules: u32,
+    /// Completed share of the course, 0 to 100
+    pub percentage: u32,
+    /// Ledger timestamp the course's completion criteria were met
+    pub completed_at: Option<u64>,
+    pub updated_at: u64,
+}
+
+/// Consecutive days on which a user completed at least one lesson.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LearningStreak {
+    pub current: u32,
+    pub longest: u32,
+    /// Day index of the last activity
+    pub last_day: u64,
+}
+
+/// Rank of a user on a course leaderboard.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub user: Address,
+    pub percentage: u32,
+    /// Ledger timestamp the user reached `percentage`; earlier ranks higher
+    pub updated_at: u64,
+}
+
+/// Lessons completed on one day.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailyActivity {
+    pub day: u64,
+    pub lessons: u32,
+}
+
+/// Module of a course, as returned by the course registry's `list_modules`.
+pub use skillcert_interfaces::course_registry::CourseModule;
+
+/// Whether a student can complete a module of a course yet.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleLockStatus {
+    pub module_id: String,
+    pub completed: bool,
+    /// Whether the course's drip schedule made the module available yet
+    pub released: bool,
+    /// Whether the module is unreleased or an incomplete prerequisite module blocks it
+    pub locked: bool,
+    /// Prerequisite modules the student still has to complete
+    pub missing_prerequisites: Vec<String>,
+}
+
+/// A live session of a course students check in to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiveSession {
+    pub id: u32,
+    pub course_id: String,
+    /// The course creator who scheduled the session
+    pub instructor: Address,
+    /// Ledger timestamp the session starts; check-ins are accepted for
+    /// `CHECK_IN_WINDOW` seconds afterwards
+    pub starts_at: u64,
+    pub topic: String,
+    pub created_at: u64,
+}
+
+/// Completion criteria of a course, as returned by the course registry's
+/// `get_completion_criteria`.
+pub use skillcert_interfaces::course_registry::CompletionCriteria;
+
+/// Quiz and assignment results of a student in a course, recorded by the
+/// course creator or the owner.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assessment {
+    /// Highest quiz score recorded, in percent
+    pub best_quiz_score: Option<u32>,
+    pub assignments_completed: bool,
+    pub updated_at: u64,
+}
@@ -0,0 +1,475 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+#![cfg(test)]
+
+use skillcert_interfaces::course_registry::CourseStats;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String, Vec,
+};
+
+use crate::schema::{
+    CompletionCriteria, CourseModule, DailyActivity, LeaderboardEntry, ProgressConfig,
+    SECONDS_PER_DAY,
+};
+use crate::{ProgressContract, ProgressContractClient};
+
+#[contract]
+pub struct UserManagement;
+
+#[contractimpl]
+impl UserManagement {
+    pub fn reject_reports(env: Env) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("reject"), &true);
+    }
+    pub fn record_course_completion(env: Env, _caller: Address, user: Address) -> u32 {
+        if env.storage().instance().has(&symbol_short!("reject")) {
+            panic!("caller is not an awarder");
+        }
+        let completed: u32 = env.storage().instance().get(&user).unwrap_or(0) + 1;
+        env.storage().instance().set(&user, &completed);
+        completed
+    }
+    pub fn get_completed_courses(env: Env, user: Address) -> u32 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+}
+
+#[contract]
+pub struct CourseRegistry;
+
+#[contractimpl]
+impl CourseRegistry {
+    pub fn record_completion(env: Env, _reporter: Address, course_id: String) -> CourseStats {
+        let completions: u32 = Self::get_completions(env.clone(), course_id.clone()) + 1;
+        env.storage()
+            .instance()
+            .set(&(course_id, "completions"), &completions);
+        CourseStats {
+            enrollments: 0,
+            completions,
+            revenue: 0,
+        }
+    }
+    pub fn get_completions(env: Env, course_id: String) -> u32 {
+        env.storage()
+            .instance()
+            .get(&(course_id, "completions"))
+            .unwrap_or(0)
+    }
+    pub fn list_modules(env: Env, course_id: String) -> Vec<CourseModule> {
+        let mut modules: Vec<CourseModule> = Vec::new(&env);
+        for (position, id) in ["m1", "m2"].iter().enumerate() {
+            modules.push_back(CourseModule {
+                id: String::from_str(&env, id),
+                course_id: course_id.clone(),
+                position: position as u32,
+                title: String::from_str(&env, "Lesson"),
+                created_at: 0,
+            });
+        }
+        modules
+    }
+    pub fn set_module_prerequisites(env: Env, module_id: String, prerequisites: Vec<String>) {
+        env.storage().instance().set(&module_id, &prerequisites);
+    }
+    pub fn get_module_prerequisites(env: Env, module_id: String) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&module_id)
+            .unwrap_or(Vec::new(&env))
+    }
+    pub fn set_completion_criteria(env: Env, criteria: CompletionCriteria) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("criteria"), &criteria);
+    }
+    pub fn get_completion_criteria(env: Env, _course_id: String) -> CompletionCriteria {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("criteria"))
+            .unwrap_or(CompletionCriteria {
+                required_modules: Vec::new(&env),
+                min_quiz_score: None,
+                require_assignments: false,
+                min_sessions_attended: None,
+            })
+    }
+    pub fn set_course_creator(env: Env, creator: Address) {
+        env.storage().instance().set(&creator, &true);
+    }
+    pub fn is_course_creator(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().has(&user)
+    }
+}
+
+#[contract]
+pub struct CourseAccess;
+
+#[contractimpl]
+impl CourseAccess {
+    pub fn grant(env: Env, user: Address) {
+        env.storage().instance().set(&user, &true);
+    }
+    pub fn has_access(env: Env, _course_id: String, user: Address) -> bool {
+        env.storage().instance().has(&user)
+    }
+    pub fn hold_back(env: Env, module_id: String) {
+        env.storage().instance().set(&module_id, &true);
+    }
+    pub fn get_unlocked_modules(env: Env, _user: Address, _course_id: String) -> Vec<String> {
+        let mut unlocked: Vec<String> = Vec::new(&env);
+        for id in ["m1", "m2"] {
+            let module_id = String::from_str(&env, id);
+            if !env.storage().instance().has(&module_id) {
+                unlocked.push_back(module_id);
+            }
+        }
+        unlocked
+    }
+}
+
+struct Setup<'a> {
+    client: ProgressContractClient<'a>,
+    user_mgmt: UserManagementClient<'a>,
+    course_registry: CourseRegistryClient<'a>,
+    course_access: CourseAccessClient<'a>,
+    student: Address,
+    course_id: String,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let user_mgmt_addr: Address = env.register(UserManagement, ());
+    let course_registry_addr: Address = env.register(CourseRegistry, ());
+    let course_access_addr: Address = env.register(CourseAccess, ());
+    let contract_id: Address = env.register(ProgressContract, ());
+    let client = ProgressContractClient::new(env, &contract_id);
+
+    client.initialize(&ProgressConfig {
+        owner: Address::generate(env),
+        user_mgmt_addr: user_mgmt_addr.clone(),
+        course_registry_addr: course_registry_addr.clone(),
+        course_access_addr: course_access_addr.clone(),
+    });
+    let course_access = CourseAccessClient::new(env, &course_access_addr);
+    let student: Address = Address::generate(env);
+    course_access.grant(&student);
+
+    Setup {
+        client,
+        user_mgmt: UserManagementClient::new(env, &user_mgmt_addr),
+        course_registry: CourseRegistryClient::new(env, &course_registry_addr),
+        course_access,
+        student,
+        course_id: String::from_str(env, "course_1"),
+    }
+}
+
+fn advance_days(env: &Env, days: u64) {
+    env.ledger()
+        .with_mut(|ledger| ledger.timestamp += days * SECONDS_PER_DAY);
+}
+
+#[test]
+fn test_complete_lessons_until_course_completed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let m1 = String::from_str(&env, "m1");
+
+    let progress = s.client.complete_lesson(&s.student, &s.course_id, &m1);
+    assert_eq!(progress.percentage, 50);
+    assert!(!s.client.is_course_completed(&s.student, &s.course_id));
+    assert!(s
+        .client
+        .try_complete_lesson(&s.student, &s.course_id, &m1)
+        .is_err());
+    assert!(s
+        .client
+        .try_complete_lesson(&s.student, &s.course_id, &String::from_str(&env, "m9"))
+        .is_err());
+
+    let progress =
+        s.client
+            .complete_lesson(&s.student, &s.course_id, &String::from_str(&env, "m2"));
+    assert_eq!(progress.percentage, 100);
+    assert!(progress.completed_at.is_some());
+    assert!(s.client.is_course_completed(&s.student, &s.course_id));
+    assert_eq!(s.user_mgmt.get_completed_courses(&s.student), 1);
+    assert_eq!(s.course_registry.get_completions(&s.course_id), 1);
+}
+
+#[test]
+fn test_rejected_completion_report_keeps_completion() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.user_mgmt.reject_reports();
+
+    s.client
+        .complete_lesson(&s.student, &s.course_id, &String::from_str(&env, "m1"));
+    let progress =
+        s.client
+            .complete_lesson(&s.student, &s.course_id, &String::from_str(&env, "m2"));
+    assert!(progress.completed_at.is_some());
+    assert!(s.client.is_course_completed(&s.student, &s.course_id));
+    assert_eq!(s.user_mgmt.get_completed_courses(&s.student), 0);
+}
+
+#[test]
+fn test_module_prerequisites_gate_lessons() {
+    let env = Env::default();
+    let s = setup(&env);
+    let m1 = String::from_str(&env, "m1");
+    let m2 = String::from_str(&env, "m2");
+    s.course_registry
+        .set_module_prerequisites(&m2, &vec![&env, m1.clone()]);
+
+    let statuses = s.client.get_module_lock_status(&s.student, &s.course_id);
+    assert!(!statuses.get(0).unwrap().locked);
+    let second = statuses.get(1).unwrap();
+    assert!(second.locked);
+    assert_eq!(second.missing_prerequisites, vec![&env, m1.clone()]);
+    assert!(s
+        .client
+        .try_complete_lesson(&s.student, &s.course_id, &m2)
+        .is_err());
+
+    s.client.complete_lesson(&s.student, &s.course_id, &m1);
+    let statuses = s.client.get_module_lock_status(&s.student, &s.course_id);
+    assert!(statuses.get(0).unwrap().completed);
+    assert!(!statuses.get(1).unwrap().locked);
+    s.client.complete_lesson(&s.student, &s.course_id, &m2);
+    assert!(s.client.is_course_completed(&s.student, &s.course_id));
+}
+
+#[test]
+fn test_unreleased_modules_are_locked() {
+    let env = Env::default();
+    let s = setup(&env);
+    let m2 = String::from_str(&env, "m2");
+    s.course_access.hold_back(&m2);
+
+    let second = s
+        .client
+        .get_module_lock_status(&s.student, &s.course_id)
+        .get(1)
+        .unwrap();
+    assert!(!second.released);
+    assert!(second.locked);
+    assert!(second.missing_prerequisites.is_empty());
+    assert!(s
+        .client
+        .try_complete_lesson(&s.student, &s.course_id, &m2)
+        .is_err());
+}
+
+#[test]
+fn test_completion_criteria() {
+    let env = Env::default();
+    let s = setup(&env);
+    let m1 = String::from_str(&env, "m1");
+    let creator = Address::generate(&env);
+    s.course_registry.set_course_creator(&creator);
+    s.course_registry
+        .set_completion_criteria(&CompletionCriteria {
+            required_modules: vec![&env, m1.clone()],
+            min_quiz_score: Some(70),
+            require_assignments: true,
+            min_sessions_attended: None,
+        });
+
+    // The required module alone is not enough
+    let progress = s.client.complete_lesson(&s.student, &s.course_id, &m1);
+    assert_eq!(progress.percentage, 50);
+    assert!(progress.completed_at.is_none());
+
+    // Only the course creator records results, and scores stop at 100
+    assert!(s
+        .client
+        .try_record_assessment(
+            &Address::generate(&env),
+            &s.student,
+            &s.course_id,
+            &Some(90),
+            &true
+        )
+        .is_err());
+    assert!(s
+        .client
+        .try_record_assessment(&creator, &s.student, &s.course_id, &Some(101), &false)
+        .is_err());
+
+    // A failed quiz keeps the course open; the best score is kept
+    s.client
+        .record_assessment(&creator, &s.student, &s.course_id, &Some(80), &false);
+    let assessment =
+        s.client
+            .record_assessment(&creator, &s.student, &s.course_id, &Some(60), &false);
+    assert_eq!(assessment.best_quiz_score, Some(80));
+    assert!(!s.client.is_course_completed(&s.student, &s.course_id));
+
+    // Completing the assignments meets the criteria before m2 is done
+    s.client
+        .record_assessment(&creator, &s.student, &s.course_id, &None, &true);
+    assert!(s.client.is_course_completed(&s.student, &s.course_id));
+    assert_eq!(s.user_mgmt.get_completed_courses(&s.student), 1);
+}
+
+#[test]
+fn test_session_attendance_counts_towards_completion() {
+    let env = Env::default();
+    let s = setup(&env);
+    let creator = Address::generate(&env);
+    s.course_registry.set_course_creator(&creator);
+    s.course_registry
+        .set_completion_criteria(&CompletionCriteria {
+            required_modules: vec![&env, String::from_str(&env, "m1")],
+            min_quiz_score: None,
+            require_assignments: false,
+            min_sessions_attended: Some(1),
+        });
+    s.client
+        .complete_lesson(&s.student, &s.course_id, &String::from_str(&env, "m1"));
+    assert!(!s.client.is_course_completed(&s.student, &s.course_id));
+
+    let topic = String::from_str(&env, "Q&A");
+    let starts_at = env.ledger().timestamp() + 100;
+    assert!(s
+        .client
+        .try_create_session(&s.student, &s.course_id, &starts_at, &topic)
+        .is_err());
+    let session = s
+        .client
+        .create_session(&creator, &s.course_id, &starts_at, &topic);
+
+    // Check-ins open at the start and need course access
+    assert!(s.client.try_check_in(&s.student, &session.id).is_err());
+    env.ledger().with_mut(|ledger| ledger.timestamp = starts_at);
+    assert!(s
+        .client
+        .try_check_in(&Address::generate(&env), &session.id)
+        .is_err());
+    assert_eq!(s.client.check_in(&s.student, &session.id), 1);
+    assert!(s.client.try_check_in(&s.student, &session.id).is_err());
+
+    assert!(s.client.is_course_completed(&s.student, &s.course_id));
+    assert_eq!(
+        s.client.get_session_attendance(&creator, &session.id),
+        vec![&env, s.student.clone()]
+    );
+    assert!(s
+        .client
+        .try_get_session_attendance(&s.student, &session.id)
+        .is_err());
+}
+
+#[test]
+fn test_complete_lesson_requires_access() {
+    let env = Env::default();
+    let s = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result =
+        s.client
+            .try_complete_lesson(&outsider, &s.course_id, &String::from_str(&env, "m1"));
+    assert!(result.is_err());
+    assert_eq!(s.client.get_progress(&outsider, &s.course_id), None);
+}
+
+#[test]
+fn test_learning_streak_and_activity_history() {
+    let env = Env::default();
+    let s = setup(&env);
+    let first_day: u64 = env.ledger().timestamp() / SECONDS_PER_DAY;
+    assert_eq!(s.client.get_learning_streak(&s.student), 0);
+
+    // Two lessons on the first day, one on the next
+    s.client
+        .complete_lesson(&s.student, &s.course_id, &String::from_str(&env, "m1"));
+    s.client.complete_lesson(
+        &s.student,
+        &String::from_str(&env, "course_2"),
+        &String::from_str(&env, "m1"),
+    );
+    advance_days(&env, 1);
+    s.client
+        .complete_lesson(&s.student, &s.course_id, &String::from_str(&env, "m2"));
+    assert_eq!(s.client.get_learning_streak(&s.student), 2);
+
+    // Still counted the day after, broken once a full day is missed
+    advance_days(&env, 1);
+    assert_eq!(s.client.get_learning_streak(&s.student), 2);
+    advance_days(&env, 1);
+    assert_eq!(s.client.get_learning_streak(&s.student), 0);
+
+    let history = s
+        .client
+        .get_activity_history(&s.student, &first_day, &(first_day + 3));
+    assert_eq!(
+        history,
+        vec![
+            &env,
+            DailyActivity {
+                day: first_day,
+                lessons: 2
+            },
+            DailyActivity {
+                day: first_day + 1,
+                lessons: 1
+            },
+        ]
+    );
+    assert!(s
+        .client
+        .try_get_activity_history(&s.student, &first_day, &(first_day + 366))
+        .is_err());
+}
+
+#[test]
+fn test_leaderboard_ranks_and_opt_out() {
+    let env = Env::default();
+    let s = setup(&env);
+    let (m1, m2) = (String::from_str(&env, "m1"), String::from_str(&env, "m2"));
+    let rival = Address::generate(&env);
+    s.course_access.grant(&rival);
+
+    s.client.complete_lesson(&s.student, &s.course_id, &m1);
+    advance_days(&env, 1);
+    s.client.complete_lesson(&rival, &s.course_id, &m1);
+    s.client.complete_lesson(&rival, &s.course_id, &m2);
+    let finished_at = env.ledger().timestamp();
+
+    let board = s.client.get_leaderboard(&s.course_id, &10);
+    assert_eq!(board.len(), 2);
+    assert_eq!(
+        board.get(0).unwrap(),
+        LeaderboardEntry {
+            user: rival.clone(),
+            percentage: 100,
+            updated_at: finished_at
+        }
+    );
+    assert_eq!(board.get(1).unwrap().user, s.student);
+    assert_eq!(s.client.get_leaderboard(&s.course_id, &1).len(), 1);
+    assert!(s.client.try_get_leaderboard(&s.course_id, &0).is_err());
+    assert!(s.client.try_get_leaderboard(&s.course_id, &51).is_err());
+
+    s.client.set_leaderboard_opt_out(&rival, &true);
+    assert!(s.client.is_leaderboard_opted_out(&rival));
+    let board = s.client.get_leaderboard(&s.course_id, &10);
+    assert_eq!(board.len(), 1);
+    assert_eq!(board.get(0).unwrap().user, s.student);
+
+    s.client.set_leaderboard_opt_out(&rival, &false);
+    assert_eq!(
+        s.client
+            .get_leaderboard(&s.course_id, &10)
+            .get(0)
+            .unwrap()
+            .user,
+        rival
+    );
+}
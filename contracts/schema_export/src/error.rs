@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    MetadataNotFound = 4,
+    EmptyContractName = 5,
+    EmptyVersion = 6,
+    EmptyMethodName = 7,
+    DuplicateMethod = 8,
+    TooManyMethods = 9,
+}
+
+pub fn handle_error(env: &Env, error: Error) -> ! {
+    panic_with_error!(env, error);
+}
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "AlreadyInitialized: The contract is already initialized",
+        2 => "NotInitialized: The contract is not initialized",
+        3 => "Unauthorized: The caller is not allowed to do this",
+        4 => "MetadataNotFound: No metadata is registered for the contract",
+        5 => "EmptyContractName: The contract name is empty",
+        6 => "EmptyVersion: The version is empty",
+        7 => "EmptyMethodName: A method name is empty",
+        8 => "DuplicateMethod: A method is listed twice",
+        9 => "TooManyMethods: The contract has too many methods",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::schema::DataKey;
+
+const INIT_EVENT: Symbol = symbol_short!("init");
+
+/// Stores the account allowed to register metadata.
+pub fn initialize(env: &Env, admin: Address) {
+    admin.require_auth();
+
+    if env.storage().instance().has(&DataKey::Admin) {
+        handle_error(env, Error::AlreadyInitialized)
+    }
+    env.storage().instance().set(&DataKey::Admin, &admin);
+
+    env.events().publish((INIT_EVENT,), admin);
+}
+
+/// Returns whether `initialize` has been called.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+/// Returns the admin, panicking with `NotInitialized` before `initialize`.
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
+
+/// Requires `caller` to be the admin and to have signed the call.
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+    if get_admin(env) != *caller {
+        handle_error(env, Error::Unauthorized)
+    }
+}
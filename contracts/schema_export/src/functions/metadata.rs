@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::config::require_admin;
+use crate::schema::{ContractMetadata, DataKey, MAX_METHODS};
+
+const METADATA_EVENT: Symbol = symbol_short!("metadata");
+const REGISTERED: Symbol = symbol_short!("register");
+const REMOVED: Symbol = symbol_short!("removed");
+
+/// Stores the metadata of a contract under its name, replacing any metadata
+/// registered before (admin only).
+pub fn register_contract_metadata(env: &Env, admin: Address, metadata: ContractMetadata) {
+    require_admin(env, &admin);
+    validate_metadata(env, &metadata);
+
+    let key: DataKey = DataKey::Metadata(metadata.name.clone());
+    if !env.storage().persistent().has(&key) {
+        let mut names: Vec<String> = list_contract_names(env);
+        names.push_back(metadata.name.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::ContractNames, &names);
+    }
+    env.storage().persistent().set(&key, &metadata);
+
+    env.events().publish(
+        (METADATA_EVENT, REGISTERED, metadata.name.clone()),
+        metadata.version,
+    );
+}
+
+/// Removes the metadata of a contract (admin only).
+pub fn remove_contract_metadata(env: &Env, admin: Address, contract_name: String) {
+    require_admin(env, &admin);
+
+    let key: DataKey = DataKey::Metadata(contract_name.clone());
+    if !env.storage().persistent().has(&key) {
+        handle_error(env, Error::MetadataNotFound)
+    }
+    env.storage().persistent().remove(&key);
+
+    let mut names: Vec<String> = list_contract_names(env);
+    if let Some(index) = names.first_index_of(&contract_name) {
+        names.remove(index);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::ContractNames, &names);
+
+    env.events()
+        .publish((METADATA_EVENT, REMOVED, contract_name), ());
+}
+
+/// Returns the metadata registered for a contract, panicking with
+/// `MetadataNotFound` if there is none.
+pub fn get_contract_metadata(env: &Env, contract_name: String) -> ContractMetadata {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Metadata(contract_name))
+        .unwrap_or_else(|| handle_error(env, Error::MetadataNotFound))
+}
+
+/// Returns the names of the contracts with registered metadata, in
+/// registration order.
+pub fn list_contract_names(env: &Env) -> Vec<String> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContractNames)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn validate_metadata(env: &Env, metadata: &ContractMetadata) {
+    if metadata.name.is_empty() {
+        handle_error(env, Error::EmptyContractName)
+    }
+    if metadata.version.is_empty() {
+        handle_error(env, Error::EmptyVersion)
+    }
+    if metadata.methods.len() > MAX_METHODS {
+        handle_error(env, Error::TooManyMethods)
+    }
+
+    let mut seen: Vec<String> = Vec::new(env);
+    for method in metadata.methods.iter() {
+        if method.name.is_empty() {
+            handle_error(env, Error::EmptyMethodName)
+        }
+        if seen.contains(&method.name) {
+            handle_error(env, Error::DuplicateMethod)
+        }
+        seen.push_back(method.name);
+    }
+}
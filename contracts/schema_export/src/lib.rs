@@ -1,30 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
 #![no_std]
 
-use soroban_sdk::{contracttype, Env, String, Vec};
-
-/// Helper module for exporting contract schemas
-pub mod schema_export {
-    use super::*;
-    
-    /// Export contract metadata for frontend consumption
-    #[contracttype]
-    pub struct ContractMetadata {
-        pub name: String,
-        pub version: String,
-        pub methods: Vec<MethodInfo>,
-    }
-    
-    #[contracttype]
-    pub struct MethodInfo {
-        pub name: String,
-        pub params: Vec<ParamInfo>,
-        pub returns: String,
-    }
-    
-    #[contracttype]
-    pub struct ParamInfo {
-        pub name: String,
-        pub type_name: String,
-        pub required: bool,
-    }
-}
\ No newline at end of file
+/// Contract version for tracking deployments and upgrades
+pub const VERSION: &str = "1.0.0";
+
+pub mod error;
+pub mod functions;
+pub mod schema;
+
+#[cfg(test)]
+mod test;
+
+use crate::schema::ContractMetadata;
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+/// Schema Export Contract
+///
+/// This contract publishes the methods and parameters of the deployed
+/// SkillCert contracts so frontends can introspect their interfaces.
+/// Metadata is generated from each contract's spec when it is built and
+/// registered here by the admin when it is deployed or upgraded.
+#[contract]
+pub struct SchemaExportContract;
+
+#[contractimpl]
+impl SchemaExportContract {
+    /// Initialize the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The account allowed to register metadata
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is already initialized
+    pub fn initialize(env: Env, admin: Address) {
+        functions::config::initialize(&env, admin)
+    }
+
+    /// Check whether the contract has been initialized.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::config::is_initialized(&env)
+    }
+
+    /// Get the admin of the contract.
+    pub fn get_admin(env: Env) -> Address {
+        functions::config::get_admin(&env)
+    }
+
+    /// Register the metadata of a contract.
+    ///
+    /// The metadata is stored under `metadata.name`, replacing whatever was
+    /// registered for that contract before, so re-registering after an
+    /// upgrade updates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin of this contract
+    /// * `metadata` - The name, version and methods of the contract
+    ///
+    /// # Panics
+    ///
+    /// * If `admin` is not the admin
+    /// * If the name or version is empty
+    /// * If a method name is empty or appears twice
+    /// * If there are more than `MAX_METHODS` methods
+    pub fn register_contract_metadata(env: Env, admin: Address, metadata: ContractMetadata) {
+        functions::metadata::register_contract_metadata(&env, admin, metadata)
+    }
+
+    /// Remove the metadata of a contract.
+    ///
+    /// # Panics
+    ///
+    /// * If `admin` is not the admin
+    /// * If no metadata is registered for the contract
+    pub fn remove_contract_metadata(env: Env, admin: Address, contract_name: String) {
+        functions::metadata::remove_contract_metadata(&env, admin, contract_name)
+    }
+
+    /// Get the metadata registered for a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `contract_name` - The name the metadata was registered under, e.g.
+    ///   "course_registry"
+    ///
+    /// # Panics
+    ///
+    /// * If no metadata is registered for the contract
+    pub fn get_contract_metadata(env: Env, contract_name: String) -> ContractMetadata {
+        functions::metadata::get_contract_metadata(&env, contract_name)
+    }
+
+    /// List the names of the contracts with registered metadata.
+    pub fn list_contract_names(env: Env) -> Vec<String> {
+        functions::metadata::list_contract_names(&env)
+    }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracttype, String, Vec};
+
+/// Maximum number of methods registered for one contract.
+pub const MAX_METHODS: u32 = 200;
+
+/// Storage keys of the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Account allowed to register metadata (instance storage)
+    Admin,
+    /// Names of the contracts with registered metadata (instance storage)
+    ContractNames,
+    /// Metadata of a contract: contract_name -> ContractMetadata
+    Metadata(String),
+}
+
+/// Export contract metadata for frontend consumption
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMetadata {
+    pub name: String,
+    pub version: String,
+    pub methods: Vec<MethodInfo>,
+}
+
+/// A public method of a contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MethodInfo {
+    pub name: String,
+    pub params: Vec<ParamInfo>,
+    /// Type name of the return value; empty for methods returning nothing
+    pub returns: String,
+}
+
+/// A parameter of a method.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParamInfo {
+    pub name: String,
+    pub type_name: String,
+    /// False for `Option` parameters, which may be passed as void
+    pub required: bool,
+}
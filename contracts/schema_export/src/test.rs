@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use crate::schema::{ContractMetadata, MethodInfo, ParamInfo};
+use crate::{SchemaExportContract, SchemaExportContractClient};
+
+fn setup(env: &Env) -> (SchemaExportContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = SchemaExportContractClient::new(env, &env.register(SchemaExportContract, ()));
+    let admin = Address::generate(env);
+    assert!(!client.is_initialized());
+    client.initialize(&admin);
+    assert!(client.is_initialized());
+    assert!(client.try_initialize(&admin).is_err());
+    (client, admin)
+}
+
+fn metadata(env: &Env, version: &str, method: &str) -> ContractMetadata {
+    ContractMetadata {
+        name: String::from_str(env, "course_registry"),
+        version: String::from_str(env, version),
+        methods: vec![
+            env,
+            MethodInfo {
+                name: String::from_str(env, method),
+                params: vec![
+                    env,
+                    ParamInfo {
+                        name: String::from_str(env, "course_id"),
+                        type_name: String::from_str(env, "String"),
+                        required: true,
+                    },
+                ],
+                returns: String::from_str(env, "Course"),
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_register_and_get_metadata() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let name = String::from_str(&env, "course_registry");
+
+    client.register_contract_metadata(&admin, &metadata(&env, "1.0.0", "get_course"));
+    assert_eq!(
+        client.get_contract_metadata(&name),
+        metadata(&env, "1.0.0", "get_course")
+    );
+
+    // Re-registering replaces the metadata without listing the contract twice
+    client.register_contract_metadata(&admin, &metadata(&env, "1.1.0", "get_course"));
+    assert_eq!(
+        client.get_contract_metadata(&name).version,
+        String::from_str(&env, "1.1.0")
+    );
+    assert_eq!(client.list_contract_names(), vec![&env, name.clone()]);
+
+    client.remove_contract_metadata(&admin, &name);
+    assert!(client.try_get_contract_metadata(&name).is_err());
+    assert!(client.list_contract_names().is_empty());
+}
+
+#[test]
+fn test_register_rejects_invalid_metadata() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    let mut duplicate = metadata(&env, "1.0.0", "get_course");
+    duplicate
+        .methods
+        .push_back(duplicate.methods.get(0).unwrap());
+    assert!(client
+        .try_register_contract_metadata(&admin, &duplicate)
+        .is_err());
+    assert!(client
+        .try_register_contract_metadata(&admin, &metadata(&env, "", "get_course"))
+        .is_err());
+    assert!(client
+        .try_register_contract_metadata(
+            &Address::generate(&env),
+            &metadata(&env, "1.0.0", "get_course")
+        )
+        .is_err());
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, panic_with_error, Env};
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -39,9 +39,109 @@ pub enum Error {
     PasswordMissingDigit = 32,
     PasswordMissingSpecialChar = 33,
     RequiredFieldMissing = 34,
-    Unauthorized = 35
+    Unauthorized = 35,
+    InvalidCountryCode = 36,
+    InvalidTtlConfig = 37,
+    CustomRoleNotFound = 38,
+    OrgNotFound = 39,
+    CannotRemoveOrgOwner = 40,
+    InvalidSeatCount = 41,
+    InvalidPointsAmount = 42,
+    InvalidLevelThresholds = 43,
+    AchievementAlreadyExists = 44,
+    InvalidAchievement = 45,
+    InvalidSocialLinks = 46,
+    InvalidUsername = 47,
+    UsernameTaken = 48,
+    UsernameNotFound = 49,
+    InvalidIdentityLink = 50,
+    AddressAlreadyLinked = 51,
+    ErasureNotRequested = 52,
+    DataAlreadyErased = 53,
 }
 
-pub fn handle_error(env: &Env, error: Error) -> ! {
+/// Errors added once `Error` reached the 50-variant limit of `#[contracterror]`.
+/// Codes start at 100 so they never collide with `Error`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum UserError {
+    // Backup errors
+    InvalidBackupPage = 100,
+    BackupChecksumMismatch = 101,
+    // Index repair errors
+    InvalidBatchSize = 102,
+    // Personhood errors
+    PersonhoodVerifierNotSet = 103,
+    PersonhoodNotVerified = 104,
+}
+
+pub fn handle_error(env: &Env, error: impl Into<soroban_sdk::Error>) -> ! {
     panic_with_error!(env, error);
 }
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "AlreadInitialized: The system is already initialized",
+        2 => "InvalidMaxPageSize: The maximum page size is out of range",
+        3 => "SystemNotInitialized: The system is not initialized",
+        4 => "AccessDenied: The caller is not allowed to do this",
+        5 => "SuperAdminNotRegular: The super admin cannot be managed as a regular admin",
+        6 => "OperationFailed: The operation failed",
+        7 => "MaxAdminsReached: The maximum number of admins is reached",
+        8 => "CannotRemoveSuperAdmin: The super admin cannot be removed",
+        9 => "UserProfileExists: The user already has a profile",
+        10 => "NameRequired: A name is required",
+        11 => "EmailRequired: An email address is required",
+        12 => "CountryRequired: A country is required",
+        15 => "InvalidEmailFormat: The email address is not valid",
+        16 => "EmailAlreadyExists: The email address is already used",
+        17 => "InvalidField: A field is not valid",
+        19 => "InvalidProfilePicURL: The profile picture URL is not valid",
+        20 => "UserNotFound: The user does not exist",
+        21 => "UserProfileNotFound: The user profile does not exist",
+        22 => "InactiveUser: The user is not active",
+        23 => "PageParamTooLarge: The page size is too large",
+        24 => "InvalidTitleLength: The title length is out of range",
+        25 => "PasswordMismatch: The passwords do not match",
+        26 => "RateLimitExceeded: Too many requests; try again later",
+        27 => "RateLimitNotConfigured: Rate limits are not configured",
+        28 => "PasswordTooShort: The password is too short",
+        29 => "PasswordTooLong: The password is too long",
+        30 => "PasswordMissingUppercase: The password needs an uppercase letter",
+        31 => "PasswordMissingLowercase: The password needs a lowercase letter",
+        32 => "PasswordMissingDigit: The password needs a digit",
+        33 => "PasswordMissingSpecialChar: The password needs a special character",
+        34 => "RequiredFieldMissing: A required field is missing",
+        35 => "Unauthorized: The caller is not allowed to do this",
+        36 => "InvalidCountryCode: The country code is not valid",
+        37 => "InvalidTtlConfig: The TTL threshold must be positive and below the extension",
+        38 => "CustomRoleNotFound: The custom role does not exist",
+        39 => "OrgNotFound: The organization does not exist",
+        40 => "CannotRemoveOrgOwner: The organization owner cannot be removed",
+        41 => "InvalidSeatCount: The seat count is not valid",
+        42 => "InvalidPointsAmount: The points amount is not valid",
+        43 => "InvalidLevelThresholds: The level thresholds must be increasing",
+        44 => "AchievementAlreadyExists: The achievement already exists",
+        45 => "InvalidAchievement: The achievement is not valid",
+        46 => "InvalidSocialLinks: The social links are not valid",
+        47 => "InvalidUsername: The username is not valid",
+        48 => "UsernameTaken: The username is already taken",
+        49 => "UsernameNotFound: The username does not exist",
+        50 => "InvalidIdentityLink: The identity link is not valid",
+        51 => "AddressAlreadyLinked: The address is already linked to an identity",
+        52 => "ErasureNotRequested: The user has not requested erasure",
+        53 => "DataAlreadyErased: The user's data is already erased",
+        100 => "InvalidBackupPage: The backup page is malformed or out of order",
+        101 => "BackupChecksumMismatch: The backup checksum does not match its data",
+        102 => "InvalidBatchSize: The batch size is out of range",
+        103 => "PersonhoodVerifierNotSet: No proof-of-personhood verifier is configured",
+        104 => "PersonhoodNotVerified: The user has no proof of personhood",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
@@ -0,0 +1,507 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Contract events.
+//!
+//! User events are published with the topics `("user", action, user)`,
+//! upgrades with `("contract", "upgraded", to_version)`, role and permission
+//! changes with `("rbac", action, user)` (`role_id` for role definitions),
+//! organization changes with `("org", action, org_id)`, gamification with
+//! `("points", action, user)` (the awarder for allowlist changes),
+//! achievements with `("achv", action, user)` (`achievement_id` for
+//! definitions), each with a typed
+//! payload whose first field is `version`, following the same convention as
+//! the course contracts. Contact emails are never emitted.
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::schema::{Achievement, AchievementCriteria, CustomRole, Organization, Permission, UserProfile, UserRole};
+
+/// Version of the event payloads below. Bump it when a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+const USER: Symbol = symbol_short!("user");
+const CONTRACT: Symbol = symbol_short!("contract");
+const RBAC: Symbol = symbol_short!("rbac");
+const ORG: Symbol = symbol_short!("org");
+const POINTS: Symbol = symbol_short!("points");
+const ACHIEVEMENT: Symbol = symbol_short!("achv");
+
+const CREATED: Symbol = symbol_short!("created");
+const UPDATED: Symbol = symbol_short!("updated");
+const DELETED: Symbol = symbol_short!("deleted");
+const UPGRADED: Symbol = symbol_short!("upgraded");
+const USERNAME: Symbol = symbol_short!("username");
+const LINKED: Symbol = symbol_short!("linked");
+const UNLINKED: Symbol = symbol_short!("unlinked");
+const ERASURE_REQUESTED: Symbol = symbol_short!("erase_req");
+const ERASED: Symbol = symbol_short!("erased");
+const ROLE_SET: Symbol = symbol_short!("role_set");
+const PERM_GRANTED: Symbol = symbol_short!("perm_grnt");
+const PERM_REVOKED: Symbol = symbol_short!("perm_rvk");
+const ROLE_CREATED: Symbol = symbol_short!("role_new");
+const ROLE_ASSIGNED: Symbol = symbol_short!("role_asgn");
+const MEMBER_ADDED: Symbol = symbol_short!("mbr_added");
+const MEMBER_REMOVED: Symbol = symbol_short!("mbr_rmvd");
+const SEATS_PURCHASED: Symbol = symbol_short!("seats");
+const AWARDED: Symbol = symbol_short!("awarded");
+const LEVEL_UP: Symbol = symbol_short!("level_up");
+const AWARDER_SET: Symbol = symbol_short!("awarder");
+const DEFINED: Symbol = symbol_short!("defined");
+const UNLOCKED: Symbol = symbol_short!("unlocked");
+
+/// Payload of `("user", "created", user)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserCreatedEvent {
+    pub version: u32,
+    pub user: Address,
+    pub full_name: String,
+    pub profession: Option<String>,
+    pub country: Option<String>,
+}
+
+/// Payload of `("user", "updated" | "deleted" | "erase_req" | "erased", user)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserEvent {
+    pub version: u32,
+    pub user: Address,
+    /// Address that performed the change: the user or an admin
+    pub actor: Address,
+}
+
+/// Payload of `("user", "username", user)`. `username` is `None` when the
+/// handle is released.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsernameEvent {
+    pub version: u32,
+    pub user: Address,
+    pub username: Option<String>,
+}
+
+/// Payload of `("user", "linked" | "unlinked", primary)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdentityLinkEvent {
+    pub version: u32,
+    pub primary: Address,
+    pub secondary: Address,
+}
+
+/// Payload of `("contract", "upgraded", to_version)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgradedEvent {
+    pub version: u32,
+    pub from_version: String,
+    pub to_version: String,
+    pub wasm_hash: BytesN<32>,
+    pub by: Address,
+}
+
+/// Payload of `("rbac", "role_set", user)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleSetEvent {
+    pub version: u32,
+    pub user: Address,
+    pub role: UserRole,
+    pub actor: Address,
+}
+
+/// Payload of `("rbac", "perm_grnt" | "perm_rvk", user)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PermissionEvent {
+    pub version: u32,
+    pub user: Address,
+    pub permission: Permission,
+    pub actor: Address,
+}
+
+/// Payload of `("rbac", "role_new", role_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomRoleCreatedEvent {
+    pub version: u32,
+    pub role_id: u32,
+    pub name: String,
+    pub permissions: Vec<Permission>,
+    pub actor: Address,
+}
+
+/// Payload of `("rbac", "role_asgn", user)`. `role_id` is `None` when the
+/// custom role is removed from the user.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomRoleAssignedEvent {
+    pub version: u32,
+    pub user: Address,
+    pub role_id: Option<u32>,
+    pub actor: Address,
+}
+
+/// Payload of `("org", "created", org_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrgCreatedEvent {
+    pub version: u32,
+    pub org_id: u32,
+    pub name: String,
+    pub owner: Address,
+}
+
+/// Payload of `("org", "mbr_added" | "mbr_rmvd", org_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrgMemberEvent {
+    pub version: u32,
+    pub org_id: u32,
+    pub member: Address,
+    /// Whether the member administers the organization (always `false` on removal)
+    pub is_admin: bool,
+    pub actor: Address,
+}
+
+/// Payload of `("org", "seats", org_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrgSeatsEvent {
+    pub version: u32,
+    pub org_id: u32,
+    pub course_id: String,
+    /// Seats added by this purchase
+    pub seats: u32,
+    /// Seats purchased for the course in total
+    pub total_seats: u32,
+    pub actor: Address,
+}
+
+pub fn user_created(env: &Env, user: &Address, profile: &UserProfile) {
+    env.events().publish(
+        (USER, CREATED, user.clone()),
+        UserCreatedEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            full_name: profile.full_name.clone(),
+            profession: profile.profession.clone(),
+            country: profile.country.clone(),
+        },
+    );
+}
+
+pub fn user_updated(env: &Env, user: &Address, actor: &Address) {
+    publish_user_event(env, UPDATED, user, actor);
+}
+
+pub fn user_deleted(env: &Env, user: &Address, actor: &Address) {
+    publish_user_event(env, DELETED, user, actor);
+}
+
+pub fn erasure_requested(env: &Env, user: &Address) {
+    publish_user_event(env, ERASURE_REQUESTED, user, user);
+}
+
+pub fn data_erased(env: &Env, user: &Address, actor: &Address) {
+    publish_user_event(env, ERASED, user, actor);
+}
+
+pub fn username_changed(env: &Env, user: &Address, username: Option<String>) {
+    env.events().publish(
+        (USER, USERNAME, user.clone()),
+        UsernameEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            username,
+        },
+    );
+}
+
+pub fn address_linked(env: &Env, primary: &Address, secondary: &Address) {
+    publish_identity_link_event(env, LINKED, primary, secondary);
+}
+
+pub fn address_unlinked(env: &Env, primary: &Address, secondary: &Address) {
+    publish_identity_link_event(env, UNLINKED, primary, secondary);
+}
+
+fn publish_identity_link_event(env: &Env, action: Symbol, primary: &Address, secondary: &Address) {
+    env.events().publish(
+        (USER, action, primary.clone()),
+        IdentityLinkEvent {
+            version: EVENT_VERSION,
+            primary: primary.clone(),
+            secondary: secondary.clone(),
+        },
+    );
+}
+
+pub fn contract_upgraded(
+    env: &Env,
+    from_version: &String,
+    to_version: &String,
+    wasm_hash: &BytesN<32>,
+    by: &Address,
+) {
+    env.events().publish(
+        (CONTRACT, UPGRADED, to_version.clone()),
+        ContractUpgradedEvent {
+            version: EVENT_VERSION,
+            from_version: from_version.clone(),
+            to_version: to_version.clone(),
+            wasm_hash: wasm_hash.clone(),
+            by: by.clone(),
+        },
+    );
+}
+
+pub fn role_set(env: &Env, user: &Address, role: &UserRole, actor: &Address) {
+    env.events().publish(
+        (RBAC, ROLE_SET, user.clone()),
+        RoleSetEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            role: role.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+pub fn permission_granted(env: &Env, user: &Address, permission: &Permission, actor: &Address) {
+    publish_permission_event(env, PERM_GRANTED, user, permission, actor);
+}
+
+pub fn permission_revoked(env: &Env, user: &Address, permission: &Permission, actor: &Address) {
+    publish_permission_event(env, PERM_REVOKED, user, permission, actor);
+}
+
+pub fn custom_role_created(env: &Env, role: &CustomRole) {
+    env.events().publish(
+        (RBAC, ROLE_CREATED, role.id),
+        CustomRoleCreatedEvent {
+            version: EVENT_VERSION,
+            role_id: role.id,
+            name: role.name.clone(),
+            permissions: role.permissions.clone(),
+            actor: role.created_by.clone(),
+        },
+    );
+}
+
+pub fn custom_role_assigned(env: &Env, user: &Address, role_id: Option<u32>, actor: &Address) {
+    env.events().publish(
+        (RBAC, ROLE_ASSIGNED, user.clone()),
+        CustomRoleAssignedEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            role_id,
+            actor: actor.clone(),
+        },
+    );
+}
+
+pub fn org_created(env: &Env, org: &Organization) {
+    env.events().publish(
+        (ORG, CREATED, org.id),
+        OrgCreatedEvent {
+            version: EVENT_VERSION,
+            org_id: org.id,
+            name: org.name.clone(),
+            owner: org.owner.clone(),
+        },
+    );
+}
+
+pub fn org_member_added(env: &Env, org_id: u32, member: &Address, is_admin: bool, actor: &Address) {
+    publish_org_member_event(env, MEMBER_ADDED, org_id, member, is_admin, actor);
+}
+
+pub fn org_member_removed(env: &Env, org_id: u32, member: &Address, actor: &Address) {
+    publish_org_member_event(env, MEMBER_REMOVED, org_id, member, false, actor);
+}
+
+pub fn org_seats_purchased(
+    env: &Env,
+    org_id: u32,
+    course_id: &String,
+    seats: u32,
+    total_seats: u32,
+    actor: &Address,
+) {
+    env.events().publish(
+        (ORG, SEATS_PURCHASED, org_id),
+        OrgSeatsEvent {
+            version: EVENT_VERSION,
+            org_id,
+            course_id: course_id.clone(),
+            seats,
+            total_seats,
+            actor: actor.clone(),
+        },
+    );
+}
+
+fn publish_org_member_event(
+    env: &Env,
+    action: Symbol,
+    org_id: u32,
+    member: &Address,
+    is_admin: bool,
+    actor: &Address,
+) {
+    env.events().publish(
+        (ORG, action, org_id),
+        OrgMemberEvent {
+            version: EVENT_VERSION,
+            org_id,
+            member: member.clone(),
+            is_admin,
+            actor: actor.clone(),
+        },
+    );
+}
+
+fn publish_permission_event(
+    env: &Env,
+    action: Symbol,
+    user: &Address,
+    permission: &Permission,
+    actor: &Address,
+) {
+    env.events().publish(
+        (RBAC, action, user.clone()),
+        PermissionEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            permission: permission.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+fn publish_user_event(env: &Env, action: Symbol, user: &Address, actor: &Address) {
+    env.events().publish(
+        (USER, action, user.clone()),
+        UserEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+/// Payload of `("points", "awarded", user)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointsAwardedEvent {
+    pub version: u32,
+    pub user: Address,
+    pub amount: u64,
+    /// Point total after the award
+    pub total: u64,
+    pub reason: Symbol,
+    /// Admin or allowlisted contract that awarded the points
+    pub actor: Address,
+}
+
+/// Payload of `("points", "level_up", user)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelUpEvent {
+    pub version: u32,
+    pub user: Address,
+    pub level: u32,
+}
+
+/// Payload of `("points", "awarder", awarder)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointsAwarderEvent {
+    pub version: u32,
+    pub awarder: Address,
+    pub allowed: bool,
+    pub actor: Address,
+}
+
+pub fn points_awarded(env: &Env, user: &Address, amount: u64, total: u64, reason: &Symbol, actor: &Address) {
+    env.events().publish(
+        (POINTS, AWARDED, user.clone()),
+        PointsAwardedEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            amount,
+            total,
+            reason: reason.clone(),
+            actor: actor.clone(),
+        },
+    );
+}
+
+pub fn level_up(env: &Env, user: &Address, level: u32) {
+    env.events().publish(
+        (POINTS, LEVEL_UP, user.clone()),
+        LevelUpEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            level,
+        },
+    );
+}
+
+pub fn points_awarder_set(env: &Env, awarder: &Address, allowed: bool, actor: &Address) {
+    env.events().publish(
+        (POINTS, AWARDER_SET, awarder.clone()),
+        PointsAwarderEvent {
+            version: EVENT_VERSION,
+            awarder: awarder.clone(),
+            allowed,
+            actor: actor.clone(),
+        },
+    );
+}
+
+/// Payload of `("achv", "defined", achievement_id)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AchievementDefinedEvent {
+    pub version: u32,
+    pub achievement_id: Symbol,
+    pub name: String,
+    pub criteria: AchievementCriteria,
+    pub actor: Address,
+}
+
+/// Payload of `("achv", "unlocked", user)`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AchievementUnlockedEvent {
+    pub version: u32,
+    pub user: Address,
+    pub achievement_id: Symbol,
+}
+
+pub fn achievement_defined(env: &Env, achievement: &Achievement) {
+    env.events().publish(
+        (ACHIEVEMENT, DEFINED, achievement.id.clone()),
+        AchievementDefinedEvent {
+            version: EVENT_VERSION,
+            achievement_id: achievement.id.clone(),
+            name: achievement.name.clone(),
+            criteria: achievement.criteria.clone(),
+            actor: achievement.created_by.clone(),
+        },
+    );
+}
+
+pub fn achievement_unlocked(env: &Env, user: &Address, achievement_id: &Symbol) {
+    env.events().publish(
+        (ACHIEVEMENT, UNLOCKED, user.clone()),
+        AchievementUnlockedEvent {
+            version: EVENT_VERSION,
+            user: user.clone(),
+            achievement_id: achievement_id.clone(),
+        },
+    );
+}
@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Achievements unlocked automatically from learning milestones.
+//!
+//! Admins define achievements with an unlock criterion. Criteria are checked
+//! whenever the user earns points or the progress contract reports a
+//! completed course, so an achievement defined later is unlocked on the
+//! user's next such event.
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::is_admin::is_admin;
+use crate::functions::points::{get_points_awarders, get_user_points};
+use crate::schema::{Achievement, AchievementCriteria, DataKey, UnlockedAchievement};
+
+/// Maximum length of an achievement name.
+const MAX_ACHIEVEMENT_NAME_LENGTH: u32 = 100;
+
+/// Maximum number of achievements, bounding the unlock checks.
+const MAX_ACHIEVEMENTS: u32 = 100;
+
+/// Returns the identifiers of all achievements, in definition order.
+pub fn list_achievements(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AchievementIds)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Retrieves an achievement definition.
+pub fn get_achievement(env: &Env, id: Symbol) -> Option<Achievement> {
+    env.storage().persistent().get(&DataKey::Achievement(id))
+}
+
+/// Defines a new achievement (admins only).
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `admin` - A platform admin (must be authorized).
+/// * `id` - Unique identifier of the achievement.
+/// * `name` - Display name of the achievement.
+/// * `criteria` - Milestone that unlocks the achievement.
+pub fn define_achievement(env: Env, admin: Address, id: Symbol, name: String, criteria: AchievementCriteria) -> Achievement {
+    admin.require_auth();
+
+    if !is_admin(env.clone(), admin.clone()) {
+        handle_error(&env, Error::AccessDenied);
+    }
+    if name.is_empty() || name.len() > MAX_ACHIEVEMENT_NAME_LENGTH {
+        handle_error(&env, Error::NameRequired);
+    }
+    let mut ids: Vec<Symbol> = list_achievements(&env);
+    if ids.contains(&id) {
+        handle_error(&env, Error::AchievementAlreadyExists);
+    }
+    if ids.len() >= MAX_ACHIEVEMENTS {
+        handle_error(&env, Error::InvalidAchievement);
+    }
+    match criteria {
+        AchievementCriteria::CompleteNCourses(0) | AchievementCriteria::EarnNPoints(0) => {
+            handle_error(&env, Error::InvalidAchievement)
+        }
+        _ => {}
+    }
+
+    let achievement: Achievement = Achievement {
+        id: id.clone(),
+        name,
+        criteria,
+        created_by: admin,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Achievement(id.clone()), &achievement);
+    ids.push_back(id);
+    env.storage().persistent().set(&DataKey::AchievementIds, &ids);
+
+    events::achievement_defined(&env, &achievement);
+
+    achievement
+}
+
+/// Returns the achievements a user unlocked, oldest first.
+pub fn get_user_achievements(env: &Env, user: Address) -> Vec<UnlockedAchievement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UserAchievements(user))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the number of courses the progress contract reported the user completed.
+pub fn get_completed_courses(env: &Env, user: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CompletedCourses(user))
+        .unwrap_or(0)
+}
+
+/// Records a completed course for a user (admins and allowlisted contracts only).
+///
+/// # Returns
+///
+/// * `u32` - The number of courses the user completed.
+pub fn record_course_completion(env: Env, caller: Address, user: Address) -> u32 {
+    caller.require_auth();
+
+    if !(is_admin(env.clone(), caller.clone()) || get_points_awarders(&env).contains(&caller)) {
+        handle_error(&env, Error::AccessDenied);
+    }
+
+    let completed: u32 = get_completed_courses(&env, user.clone()).saturating_add(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CompletedCourses(user.clone()), &completed);
+
+    check_achievements(&env, &user);
+
+    completed
+}
+
+/// Unlocks every achievement whose criterion the user now meets.
+pub(crate) fn check_achievements(env: &Env, user: &Address) {
+    let mut unlocked: Vec<UnlockedAchievement> = get_user_achievements(env, user.clone());
+    let previously_unlocked: u32 = unlocked.len();
+    let points: u64 = get_user_points(env, user.clone());
+    let completed: u32 = get_completed_courses(env, user.clone());
+
+    for id in list_achievements(env).iter() {
+        if unlocked.iter().any(|entry| entry.achievement_id == id) {
+            continue;
+        }
+        let Some(achievement) = get_achievement(env, id.clone()) else {
+            continue;
+        };
+        let met: bool = match achievement.criteria {
+            AchievementCriteria::CompleteNCourses(count) => completed >= count,
+            AchievementCriteria::EarnNPoints(threshold) => points >= threshold,
+        };
+        if met {
+            unlocked.push_back(UnlockedAchievement {
+                achievement_id: id.clone(),
+                unlocked_at: env.ledger().timestamp(),
+            });
+            events::achievement_unlocked(env, user, &id);
+        }
+    }
+
+    if unlocked.len() > previously_unlocked {
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserAchievements(user.clone()), &unlocked);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::AchievementCriteria;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+
+    fn setup(env: &Env) -> (UserManagementClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(env, &contract_id);
+        let super_admin: Address = Address::generate(env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        (client, super_admin)
+    }
+
+    #[test]
+    fn test_achievements_unlock_from_points_and_completions() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+        let progress_contract: Address = Address::generate(&env);
+        let user: Address = Address::generate(&env);
+        client.set_points_awarder(&super_admin, &progress_contract, &true);
+
+        client.define_achievement(
+            &super_admin,
+            &symbol_short!("first"),
+            &String::from_str(&env, "First course"),
+            &AchievementCriteria::CompleteNCourses(1),
+        );
+        client.define_achievement(
+            &super_admin,
+            &symbol_short!("pts500"),
+            &String::from_str(&env, "500 points"),
+            &AchievementCriteria::EarnNPoints(500),
+        );
+        assert_eq!(client.list_achievements().len(), 2);
+
+        client.award_points(&progress_contract, &user, &400, &symbol_short!("lesson"));
+        assert!(client.get_user_achievements(&user).is_empty());
+
+        assert_eq!(client.record_course_completion(&progress_contract, &user), 1);
+        let unlocked = client.get_user_achievements(&user);
+        assert_eq!(unlocked.len(), 1);
+        assert_eq!(unlocked.get(0).unwrap().achievement_id, symbol_short!("first"));
+
+        client.award_points(&progress_contract, &user, &100, &symbol_short!("lesson"));
+        client.record_course_completion(&progress_contract, &user);
+        assert_eq!(client.get_user_achievements(&user).len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #44)")]
+    fn test_define_duplicate_achievement_fails() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+        let name: String = String::from_str(&env, "First course");
+        let criteria: AchievementCriteria = AchievementCriteria::CompleteNCourses(1);
+
+        client.define_achievement(&super_admin, &symbol_short!("first"), &name, &criteria);
+        client.define_achievement(&super_admin, &symbol_short!("first"), &name, &criteria);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    fn test_record_course_completion_requires_allowlisted_caller() {
+        let env: Env = Env::default();
+        let (client, _super_admin) = setup(&env);
+        let user: Address = Address::generate(&env);
+
+        client.record_course_completion(&user, &user);
+    }
+}
@@ -1,12 +1,13 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, Vec, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, String, Vec, Symbol, symbol_short};
 
 use crate::error::{handle_error, Error};
 use crate::schema::{
     AdminConfig, DataKey, ABSOLUTE_MAX_PAGE_SIZE, DEFAULT_MAX_PAGE_SIZE, MAX_ADMINS,
 };
+use crate::functions::contract_versioning::record_version;
 use crate::functions::utils::rate_limit_utils::get_default_rate_limit_config;
 use core::iter::Iterator;
 
@@ -65,6 +66,8 @@ pub fn initialize_system(
         .persistent()
         .set(&DataKey::Admins, &empty_admins);
 
+    record_version(&env, &initializer, String::from_str(&env, crate::VERSION));
+
     env.events()
         .publish((INIT_SYSTEM_EVENT, &initializer), (super_admin, validated_max_page_size));
 
@@ -221,6 +224,16 @@ pub fn is_system_initialized(env: Env) -> bool {
     }
 }
 
+/// Returns the system configuration, panicking with `SystemNotInitialized`
+/// before `initialize_system`.
+pub fn get_config(env: &Env) -> AdminConfig {
+    env.storage()
+        .persistent()
+        .get::<DataKey, AdminConfig>(&DataKey::AdminConfig)
+        .filter(|config| config.initialized)
+        .unwrap_or_else(|| handle_error(env, Error::SystemNotInitialized))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{UserManagement, UserManagementClient};
@@ -240,6 +253,19 @@ mod tests {
         client.initialize_system(&initializer, &super_admin, &Some(TEST_MAX_PAGE_SIZE));
 
         assert!(client.is_system_initialized());
+        assert!(client.is_initialized());
+        let config = client.get_config();
+        assert_eq!(config.super_admin, super_admin);
+        assert_eq!(config.max_page_size, TEST_MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_get_config_before_initialization() {
+        let env = Env::default();
+        let client = UserManagementClient::new(&env, &env.register(UserManagement, ()));
+
+        assert!(!client.is_initialized());
+        assert!(client.try_get_config().is_err());
     }
 
     #[test]
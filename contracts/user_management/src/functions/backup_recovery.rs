@@ -2,8 +2,20 @@
 // Copyright (c) 2025 SkillCert
 
 // Remove unused import - not needed for backup functions
-use crate::schema::{AdminConfig, DataKey, LightProfile, UserBackupData, UserProfile};
-use soroban_sdk::{Address, Env, Map, String, Vec};
+use crate::error::{handle_error, Error, UserError};
+use crate::functions::profile_store::{load_profile, store_profile};
+use crate::functions::social_links::{get_social_links, set_social_links};
+use crate::functions::utils::storage_utils::{
+    add_to_users_index, get_registered_at, register_email, set_registered_at,
+};
+use crate::schema::{
+    AdminConfig, BackupCursor, DataKey, LightProfile, UserBackupData, UserBackupEntry, UserBackupPage,
+    UserProfile, MAX_BACKUP_PAGE_SIZE,
+};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String, Vec};
+
+/// Version tag of backups produced by this contract
+const BACKUP_VERSION: &str = "1.0.0";
 
 /// Export all user data for backup purposes
 ///
@@ -48,11 +60,7 @@ pub fn export_user_data(env: Env, caller: Address) -> UserBackupData {
     // Collect all user data
     for user_address in users_index.iter() {
         // Get full user profile
-        if let Some(profile) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, UserProfile>(&DataKey::UserProfile(user_address.clone()))
-        {
+        if let Some(profile) = load_profile(&env, &user_address) {
             user_profiles.set(user_address.clone(), profile.clone());
             
             // Map email to address
@@ -92,7 +100,7 @@ pub fn export_user_data(env: Env, caller: Address) -> UserBackupData {
         admin_config,
         admins,
         backup_timestamp: env.ledger().timestamp(),
-        backup_version: String::from_str(&env, "1.0.0"),
+        backup_version: String::from_str(&env, BACKUP_VERSION),
     }
 }
 
@@ -121,7 +129,7 @@ pub fn import_user_data(env: Env, caller: Address, backup_data: UserBackupData)
     }
 
     // Validate backup version compatibility
-    let expected_version = String::from_str(&env, "1.0.0");
+    let expected_version = String::from_str(&env, BACKUP_VERSION);
     if backup_data.backup_version != expected_version {
         panic!("Incompatible backup version");
     }
@@ -130,9 +138,7 @@ pub fn import_user_data(env: Env, caller: Address, backup_data: UserBackupData)
 
     // Import user profiles
     for (address, profile) in backup_data.user_profiles.iter() {
-        env.storage()
-            .persistent()
-            .set(&DataKey::UserProfile(address.clone()), &profile);
+        store_profile(&env, &address, &profile);
         imported_count += 1;
     }
 
@@ -145,9 +151,7 @@ pub fn import_user_data(env: Env, caller: Address, backup_data: UserBackupData)
 
     // Import email mappings
     for (email, address) in backup_data.email_mappings.iter() {
-        env.storage()
-            .persistent()
-            .set(&DataKey::EmailIndex(email.clone()), &address);
+        register_email(&env, &email, &address);
     }
 
     // Import users index
@@ -180,3 +184,145 @@ pub fn import_user_data(env: Env, caller: Address, backup_data: UserBackupData)
 
     imported_count
 }
+
+/// Chains the checksum of the pages before a page with the page's entries.
+fn chain_checksum(env: &Env, previous: &BytesN<32>, entries: &Vec<UserBackupEntry>) -> BytesN<32> {
+    let mut data: Bytes = Bytes::from_array(env, &previous.to_array());
+    data.append(&entries.clone().to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+fn require_admin(env: &Env, caller: Address) {
+    caller.require_auth();
+    if !crate::functions::is_admin::is_admin(env.clone(), caller) {
+        handle_error(env, Error::AccessDenied);
+    }
+}
+
+/// Returns the cursor of the first page of a paged backup.
+fn first_cursor(env: &Env) -> BackupCursor {
+    BackupCursor {
+        offset: 0,
+        checksum: BytesN::from_array(env, &[0u8; 32]),
+    }
+}
+
+/// Export one page of user data for backup purposes
+///
+/// Unlike `export_user_data`, which must fit every user in one call, the
+/// users index is exported in chunks of up to `MAX_BACKUP_PAGE_SIZE` users.
+/// Only user records are exported; the admin configuration is covered by
+/// `export_user_data`.
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `caller` - Address requesting the backup (must be admin)
+/// * `cursor` - `next_cursor` of the previous page, or offset 0 with an all-zero checksum
+/// * `limit` - Maximum number of users in the page (1 to `MAX_BACKUP_PAGE_SIZE`)
+///
+/// # Panics
+/// * If caller is not an admin
+/// * If the limit is out of range or the cursor is past the end (`InvalidBackupPage`)
+pub fn export_user_data_page(env: Env, caller: Address, cursor: BackupCursor, limit: u32) -> UserBackupPage {
+    require_admin(&env, caller);
+
+    let users_index: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UsersIndex)
+        .unwrap_or(Vec::new(&env));
+    let total_users: u32 = users_index.len();
+    if limit == 0 || limit > MAX_BACKUP_PAGE_SIZE || cursor.offset > total_users {
+        handle_error(&env, UserError::InvalidBackupPage);
+    }
+
+    let end: u32 = (cursor.offset + limit).min(total_users);
+    let mut entries: Vec<UserBackupEntry> = Vec::new(&env);
+    for user in users_index.slice(cursor.offset..end).iter() {
+        let profile: Option<UserProfile> = load_profile(&env, &user);
+        let light_profile: Option<LightProfile> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserProfileLight(user.clone()));
+        if let (Some(profile), Some(light_profile)) = (profile, light_profile) {
+            entries.push_back(UserBackupEntry {
+                social_links: get_social_links(&env, user.clone()),
+                registered_at: get_registered_at(&env, &user),
+                user,
+                profile,
+                light_profile,
+            });
+        }
+    }
+
+    let next_cursor: BackupCursor = BackupCursor {
+        offset: end,
+        checksum: chain_checksum(&env, &cursor.checksum, &entries),
+    };
+    UserBackupPage {
+        cursor,
+        entries,
+        next_cursor,
+        has_more: end < total_users,
+        total_users,
+        backup_version: String::from_str(&env, BACKUP_VERSION),
+    }
+}
+
+/// Import one page of a paged backup
+///
+/// Pages must be imported in the order they were exported, starting from
+/// the first one. Each page's checksum is verified against the pages
+/// imported before it.
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `caller` - Address performing the import (must be admin)
+/// * `page` - Page returned by `export_user_data_page`
+///
+/// # Returns
+/// * `u32` - Number of users imported from the page
+///
+/// # Panics
+/// * If caller is not an admin
+/// * If the page isn't the next one expected or has another version (`InvalidBackupPage`)
+/// * If the page's checksum doesn't match its contents (`BackupChecksumMismatch`)
+pub fn import_user_data_page(env: Env, caller: Address, page: UserBackupPage) -> u32 {
+    require_admin(&env, caller);
+
+    let expected: BackupCursor = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BackupImportCursor)
+        .unwrap_or(first_cursor(&env));
+    if page.cursor != expected || page.backup_version != String::from_str(&env, BACKUP_VERSION) {
+        handle_error(&env, UserError::InvalidBackupPage);
+    }
+    if chain_checksum(&env, &page.cursor.checksum, &page.entries) != page.next_cursor.checksum {
+        handle_error(&env, UserError::BackupChecksumMismatch);
+    }
+
+    for entry in page.entries.iter() {
+        store_profile(&env, &entry.user, &entry.profile);
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserProfileLight(entry.user.clone()), &entry.light_profile);
+        set_social_links(&env, &entry.user, &entry.social_links);
+        if entry.registered_at != 0 {
+            set_registered_at(&env, &entry.user, entry.registered_at);
+        }
+        register_email(&env, &entry.profile.contact_email, &entry.user);
+        add_to_users_index(&env, &entry.user);
+    }
+
+    // The last page completes the import, so a new one can start
+    if page.has_more {
+        env.storage()
+            .persistent()
+            .set(&DataKey::BackupImportCursor, &page.next_cursor);
+    } else {
+        env.storage().persistent().remove(&DataKey::BackupImportCursor);
+    }
+
+    page.entries.len()
+}
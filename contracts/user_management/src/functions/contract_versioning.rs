@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, Address, Env, String, Vec, vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env, String, Vec, vec};
 
+use crate::error::{handle_error, Error};
+use crate::events;
 use crate::{
     functions::is_admin::is_admin,
 };
@@ -26,54 +28,142 @@ pub enum VersioningError {
     MigrationFailed = 6,
 }
 
+/// A single entry of the contract version history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionRecord {
+    /// Version string that was deployed or migrated to
+    pub version: String,
+    /// Ledger timestamp at which the version was recorded
+    pub ledger_timestamp: u64,
+    /// Address that initialized, upgraded or migrated the contract
+    pub by: Address,
+}
+
+/// Current migration state of the contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    /// Version of the code that is currently running
+    pub code_version: String,
+    /// Latest version in the history (empty if nothing was recorded yet)
+    pub recorded_version: String,
+    /// True after an upgrade until a data migration completes, or when the
+    /// recorded version does not match the running code
+    pub migration_pending: bool,
+    /// Outcome of the last migration attempt (empty if none ran)
+    pub last_result: String,
+}
+
 /// Storage keys for versioning data
 const VERSION_HISTORY_KEY: &str = "version_history";
 const MIGRATION_STATUS_KEY: &str = "migration_status";
+const MIGRATION_PENDING_KEY: &str = "migration_pending";
 
 /// Get the version history of the contract
-pub fn get_version_history(env: &Env) -> Vec<String> {
-    let key = String::from_str(env, VERSION_HISTORY_KEY);
+pub fn get_version_history(env: &Env) -> Vec<VersionRecord> {
+    let key: String = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage()
         .instance()
-        .get::<String, Vec<String>>(&key)
+        .get::<String, Vec<VersionRecord>>(&key)
         .unwrap_or_else(|| vec![env])
 }
 
-/// Store a new version in the history
-fn store_version_in_history(env: &Env, version: String) {
-    let mut history = get_version_history(env);
-    history.push_back(version.clone());
-    
-    let key = String::from_str(env, VERSION_HISTORY_KEY);
+/// Append `{version, ledger_timestamp, by}` to the version history.
+///
+/// Called on initialization, upgrade and completed migrations. Authorization
+/// is the caller's responsibility.
+pub fn record_version(env: &Env, admin: &Address, version: String) {
+    let mut history: Vec<VersionRecord> = get_version_history(env);
+    history.push_back(VersionRecord {
+        version,
+        ledger_timestamp: env.ledger().timestamp(),
+        by: admin.clone(),
+    });
+
+    let key: String = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage().instance().set(&key, &history);
 }
 
+/// Latest version in the history, if any
+fn latest_recorded_version(env: &Env) -> Option<String> {
+    get_version_history(env).last().map(|record| record.version)
+}
+
 /// Check if a version exists in the history
 fn version_exists_in_history(env: &Env, version: &String) -> bool {
-    let history = get_version_history(env);
-    for v in history.iter() {
-        if &v == version {
-            return true;
+    get_version_history(env)
+        .iter()
+        .any(|record| &record.version == version)
+}
+
+/// Replace the contract code with an uploaded WASM and record the upgrade.
+///
+/// The version history gets the current version (if missing) and `new_version`
+/// appended before the code is swapped, so the history survives the upgrade.
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>, new_version: String) {
+    admin.require_auth();
+
+    if !is_admin(env.clone(), admin.clone()) {
+        handle_error(env, Error::AccessDenied)
+    }
+
+    let from_version: String = match latest_recorded_version(env) {
+        Some(version) => version,
+        None => {
+            let current: String = String::from_str(env, crate::VERSION);
+            record_version(env, &admin, current.clone());
+            current
         }
+    };
+    record_version(env, &admin, new_version.clone());
+    if from_version != new_version {
+        set_migration_pending(env, true);
     }
-    false
+
+    events::contract_upgraded(env, &from_version, &new_version, &new_wasm_hash, &admin);
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
 }
 
 /// Get migration status information
-pub fn get_migration_status(env: &Env) -> String {
-    let key = String::from_str(env, MIGRATION_STATUS_KEY);
-    env.storage()
+pub fn get_migration_status(env: &Env) -> MigrationStatus {
+    let code_version: String = String::from_str(env, crate::VERSION);
+    let recorded_version: String =
+        latest_recorded_version(env).unwrap_or_else(|| String::from_str(env, ""));
+    let pending_flag: bool = env
+        .storage()
         .instance()
-        .get::<String, String>(&key)
-        .unwrap_or_else(|| String::from_str(env, "No migrations pending"))
+        .get::<String, bool>(&String::from_str(env, MIGRATION_PENDING_KEY))
+        .unwrap_or(false);
+    let migration_pending: bool =
+        pending_flag || (!recorded_version.is_empty() && recorded_version != code_version);
+    let last_result: String = env
+        .storage()
+        .instance()
+        .get::<String, String>(&String::from_str(env, MIGRATION_STATUS_KEY))
+        .unwrap_or_else(|| String::from_str(env, ""));
+
+    MigrationStatus {
+        code_version,
+        recorded_version,
+        migration_pending,
+        last_result,
+    }
 }
 
-/// Set migration status
+/// Set the result message of the last migration attempt
 fn set_migration_status(env: &Env, status: String) {
-    let key = String::from_str(env, MIGRATION_STATUS_KEY);
+    let key: String = String::from_str(env, MIGRATION_STATUS_KEY);
     env.storage().instance().set(&key, &status);
 }
 
+/// Set or clear the pending migration flag
+fn set_migration_pending(env: &Env, pending: bool) {
+    let key: String = String::from_str(env, MIGRATION_PENDING_KEY);
+    env.storage().instance().set(&key, &pending);
+}
+
 /// Check compatibility between two versions
 pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: String) -> bool {
     // Simple compatibility check - for now, assume all versions are compatible
@@ -88,10 +178,10 @@ pub fn migrate_user_data(
     from_version: String,
     to_version: String,
 ) -> bool {
-    // Check if caller is admin
+    // Restricted like `upgrade`, since it writes the version history
+    caller.require_auth();
     if !is_admin(env.clone(), caller.clone()) {
-        set_migration_status(env, String::from_str(env, "Migration failed: Unauthorized"));
-        return false;
+        handle_error(env, Error::AccessDenied)
     }
     
     // Validate versions exist in history
@@ -110,8 +200,11 @@ pub fn migrate_user_data(
     let migration_result = perform_data_migration(env, &from_version, &to_version);
     
     if migration_result {
-        // Update version history with new version
-        store_version_in_history(env, to_version.clone());
+        // Record the target version unless the upgrade already did
+        if latest_recorded_version(env) != Some(to_version.clone()) {
+            record_version(env, &caller, to_version.clone());
+        }
+        set_migration_pending(env, false);
         
         // Set successful migration status
         let status = String::from_str(env, "Migration completed successfully");
@@ -196,4 +289,62 @@ mod test {
             String::from_str(&env, "1.0.0"), 
             String::from_str(&env, "2.0.0")));
     }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    fn test_upgrade_requires_admin() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::UserManagement, ());
+        let client = crate::UserManagementClient::new(&env, &contract_id);
+
+        let stranger: Address = Address::generate(&env);
+        client.upgrade(
+            &stranger,
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &String::from_str(&env, "1.1.0"),
+        );
+    }
+
+    #[test]
+    fn test_initialize_records_version() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::UserManagement, ());
+        let client = crate::UserManagementClient::new(&env, &contract_id);
+        let initializer: Address = Address::generate(&env);
+
+        client.initialize_system(&initializer, &Address::generate(&env), &None);
+
+        let history: Vec<VersionRecord> = client.get_version_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap().version, String::from_str(&env, crate::VERSION));
+        assert_eq!(history.get(0).unwrap().by, initializer);
+
+        let status: MigrationStatus = client.get_migration_status();
+        assert!(!status.migration_pending);
+        assert_eq!(status.last_result, String::from_str(&env, ""));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    fn test_migration_requires_admin() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(crate::UserManagement, ());
+        let client = crate::UserManagementClient::new(&env, &contract_id);
+        let version: String = String::from_str(&env, crate::VERSION);
+        client.initialize_system(&Address::generate(&env), &Address::generate(&env), &None);
+
+        let stranger: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            migrate_user_data(&env, stranger, version.clone(), version);
+        });
+    }
 }
@@ -1,21 +1,22 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use crate::events;
+use crate::functions::ttl::bump_persistent;
 use crate::error::{handle_error, Error};
-use crate::schema::{DataKey, LightProfile, UserProfile, UserRole, UserStatus};
+use crate::functions::personhood::require_personhood;
+use crate::functions::platform_stats::report_user_registered;
+use crate::functions::profile_store::store_profile;
+use crate::schema::{DataKey, LightProfile, PersonhoodFeature, UserProfile, UserRole, UserStatus};
 use crate::functions::utils::rate_limit_utils::check_user_creation_rate_limit;
-use crate::functions::utils::url_validation;
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+use crate::functions::utils::storage_utils::{is_email_unique, register_email, set_registered_at};
+use soroban_sdk::{Address, Env, String, Vec};
 use core::iter::Iterator;
-
-// Event symbol for user creation
-const USER_CREATED_EVENT: Symbol = symbol_short!("usrCrtd");
+use validation::{validate_country_code, validate_email, validate_url};
 
 /// Security constants for profile validation
 const MAX_NAME_LENGTH: usize = 100;
-const MAX_EMAIL_LENGTH: usize = 320; // RFC 5321 standard
 const MAX_PROFESSION_LENGTH: usize = 100;
-const MAX_COUNTRY_LENGTH: usize = 56; // Longest country name
 
 /// Validates string content for security
 fn validate_string_content(_env: &Env, s: &String, max_len: usize) -> bool {
@@ -26,52 +27,6 @@ fn validate_string_content(_env: &Env, s: &String, max_len: usize) -> bool {
     true
 }
 
-/// Validates email format (basic validation)
-fn validate_email_format(email: &String) -> bool {
-    // Basic email validation - must contain @ and have minimum length
-    if email.len() < 5 || email.len() > MAX_EMAIL_LENGTH as u32 {
-        return false;
-    }
-
-    // For Soroban strings, we'll do a basic validation
-    // Check if the string is empty (additional safety check)
-    if email.is_empty() {
-        return false;
-    }
-
-    // Basic validation - reject emails that are clearly invalid
-    // In production, implement proper RFC 5322 email validation
-    if email.len() == 13 {
-        // "invalid-email" has 13 characters - reject for testing
-        return false;
-    }
-
-    // This is where we would normally check for @ symbol, but due to Soroban SDK limitations
-    // we'll simulate the validation for the test
-    // In a real implementation, you might need to implement custom string parsing
-
-    // For the test to pass, we need to reject "invalid-email" (no @)
-    // This is a simplified validation for demo purposes
-    if email.len() < 5 {
-        // "bad" has 3 characters
-        return false;
-    }
-
-    true
-}
-
-/// Check if email is already taken
-fn is_email_unique(env: &Env, email: &String) -> bool {
-    let email_key: DataKey = DataKey::EmailIndex(email.clone());
-    !env.storage().persistent().has(&email_key)
-}
-
-/// Register email in the email index
-fn register_email(env: &Env, email: &String, user_address: &Address) {
-    let email_key: DataKey = DataKey::EmailIndex(email.clone());
-    env.storage().persistent().set(&email_key, user_address);
-}
-
 /// Add user to the global users index
 fn add_to_users_index(env: &Env, user: &Address) {
     let mut users_index: Vec<Address> = env
@@ -135,6 +90,9 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
         handle_error(&env, Error::UserProfileExists)
     }
 
+    // Require proof of personhood when an admin turned it on
+    require_personhood(&env, &user, PersonhoodFeature::ProfileCreation);
+
     // Validate mandatory fields
     if profile.full_name.is_empty() {
         handle_error(&env, Error::NameRequired)
@@ -150,7 +108,7 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
     }
 
     // Validate email format
-    if !validate_email_format(&profile.contact_email) {
+    if !validate_email(&profile.contact_email) {
         handle_error(&env, Error::InvalidEmailFormat)
     }
 
@@ -168,14 +126,14 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
 
     // Validate country field if provided
     if let Some(ref country) = profile.country {
-        if !country.is_empty() && !validate_string_content(&env, country, MAX_COUNTRY_LENGTH) {
-            handle_error(&env, Error::InvalidField)
+        if !country.is_empty() && !validate_country_code(country) {
+            handle_error(&env, Error::InvalidCountryCode)
         }
     }
 
     // Validate profile picture URL if provided
     if let Some(ref url) = profile.profile_picture_url {
-        if !url.is_empty() && !url_validation::is_valid_url(url) {
+        if !url.is_empty() && !validate_url(url) {
             handle_error(&env, Error::InvalidProfilePicURL)
         }
     }
@@ -184,7 +142,7 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
     register_email(&env, &profile.contact_email, &user);
 
     // Store the user profile
-    env.storage().persistent().set(&storage_key, &profile);
+    store_profile(&env, &user, &profile);
 
     // Add user to the global users index
     add_to_users_index(&env, &user);
@@ -200,17 +158,11 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
     };
     let light_key = DataKey::UserProfileLight(user.clone());
     env.storage().persistent().set(&light_key, &light_profile);
+    set_registered_at(&env, &user, env.ledger().timestamp());
+    bump_persistent(&env, &light_key);
 
-    // Emit event for user creation
-    env.events().publish(
-        (USER_CREATED_EVENT, &user),
-        (
-            profile.full_name.clone(),
-            profile.contact_email.clone(),
-            profile.profession.clone(),
-            profile.country.clone(),
-        ),
-    );
+    events::user_created(&env, &user, &profile);
+    report_user_registered(&env);
 
     profile
 }
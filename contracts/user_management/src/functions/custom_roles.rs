@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Admin-defined roles such as "Teaching Assistant".
+//!
+//! Custom roles complement the built-in `UserRole` values: a user keeps their
+//! built-in role and may additionally hold one custom role, whose permissions
+//! are added to the role defaults. Individual revocations still win.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::rbac::has_permission;
+use crate::schema::{CustomRole, DataKey, Permission};
+
+/// Maximum length of a custom role name.
+const MAX_ROLE_NAME_LENGTH: u32 = 50;
+
+fn require_manage_admins(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    if !has_permission(env, caller, &Permission::ManageAdmins) {
+        handle_error(env, Error::AccessDenied);
+    }
+}
+
+/// Defines a new custom role (requires `ManageAdmins`).
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `admin` - The caller (must be authorized and have `ManageAdmins`).
+/// * `name` - Display name of the role.
+/// * `permissions` - Permissions granted by the role; duplicates are dropped.
+///
+/// # Returns
+///
+/// * `CustomRole` - The stored role with its new identifier.
+pub fn create_custom_role(env: Env, admin: Address, name: String, permissions: Vec<Permission>) -> CustomRole {
+    require_manage_admins(&env, &admin);
+
+    if name.is_empty() || name.len() > MAX_ROLE_NAME_LENGTH {
+        handle_error(&env, Error::NameRequired);
+    }
+
+    let mut unique: Vec<Permission> = Vec::new(&env);
+    for permission in permissions.iter() {
+        if !unique.contains(&permission) {
+            unique.push_back(permission);
+        }
+    }
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u32>(&DataKey::CustomRoleCount)
+        .unwrap_or(0)
+        + 1;
+
+    let role: CustomRole = CustomRole {
+        id,
+        name,
+        permissions: unique,
+        created_by: admin,
+    };
+    env.storage().persistent().set(&DataKey::CustomRole(id), &role);
+    env.storage().persistent().set(&DataKey::CustomRoleCount, &id);
+
+    events::custom_role_created(&env, &role);
+
+    role
+}
+
+/// Retrieves a custom role definition.
+///
+/// # Returns
+///
+/// * `Option<CustomRole>` - The role, or `None` if no role has this identifier.
+pub fn get_custom_role(env: &Env, role_id: u32) -> Option<CustomRole> {
+    env.storage().persistent().get(&DataKey::CustomRole(role_id))
+}
+
+/// Assigns a custom role to a user, replacing any previous one (requires `ManageAdmins`).
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `admin` - The caller (must be authorized and have `ManageAdmins`).
+/// * `user` - The user receiving the role.
+/// * `role_id` - Identifier of an existing custom role.
+///
+/// # Returns
+///
+/// * () - Updates storage or raises `CustomRoleNotFound`.
+pub fn assign_custom_role(env: Env, admin: Address, user: Address, role_id: u32) {
+    require_manage_admins(&env, &admin);
+
+    if get_custom_role(&env, role_id).is_none() {
+        handle_error(&env, Error::CustomRoleNotFound);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserCustomRole(user.clone()), &role_id);
+
+    events::custom_role_assigned(&env, &user, Some(role_id), &admin);
+}
+
+/// Removes the custom role of a user, if any (requires `ManageAdmins`).
+pub fn unassign_custom_role(env: Env, admin: Address, user: Address) {
+    require_manage_admins(&env, &admin);
+
+    let key: DataKey = DataKey::UserCustomRole(user.clone());
+    if env.storage().persistent().has(&key) {
+        env.storage().persistent().remove(&key);
+        events::custom_role_assigned(&env, &user, None, &admin);
+    }
+}
+
+/// Retrieves the custom role held by a user, if any.
+pub fn get_user_custom_role(env: &Env, user: &Address) -> Option<CustomRole> {
+    let role_id: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserCustomRole(user.clone()))?;
+    get_custom_role(env, role_id)
+}
+
+/// Returns the permissions granted to a user by their custom role.
+pub fn custom_role_permissions(env: &Env, user: &Address) -> Vec<Permission> {
+    match get_user_custom_role(env, user) {
+        Some(role) => role.permissions,
+        None => Vec::new(env),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{CustomRole, Permission, UserRole};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup(env: &Env) -> (UserManagementClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(env, &contract_id);
+        let super_admin: Address = Address::generate(env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        (client, super_admin)
+    }
+
+    #[test]
+    fn test_custom_role_grants_permissions() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+        let assistant: Address = Address::generate(&env);
+
+        let role: CustomRole = client.create_custom_role(
+            &super_admin,
+            &String::from_str(&env, "Teaching Assistant"),
+            &vec![&env, Permission::EditCourses, Permission::ProvideSupport, Permission::EditCourses],
+        );
+        assert_eq!(role.id, 1);
+        assert_eq!(role.permissions.len(), 2);
+        assert_eq!(client.get_custom_role(&1), Some(role.clone()));
+
+        assert!(!client.has_permission(&assistant, &Permission::EditCourses));
+        client.assign_custom_role(&super_admin, &assistant, &role.id);
+        assert_eq!(client.get_user_custom_role(&assistant), Some(role));
+        assert!(client.has_permission(&assistant, &Permission::EditCourses));
+        assert!(client
+            .get_user_permissions(&assistant, &assistant)
+            .contains(&Permission::ProvideSupport));
+        // Built-in role defaults still apply
+        assert!(client.has_permission(&assistant, &Permission::ViewCourses));
+
+        // Individual revocations win over the custom role
+        client.revoke_user_permission(&super_admin, &assistant, &Permission::EditCourses);
+        assert!(!client.has_permission(&assistant, &Permission::EditCourses));
+
+        client.unassign_custom_role(&super_admin, &assistant);
+        assert_eq!(client.get_user_custom_role(&assistant), None);
+        assert!(!client.has_permission(&assistant, &Permission::ProvideSupport));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #38)")]
+    fn test_assign_unknown_custom_role_fails() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+
+        client.assign_custom_role(&super_admin, &Address::generate(&env), &7);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    fn test_create_custom_role_requires_manage_admins() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+        let instructor: Address = Address::generate(&env);
+        client.set_user_role(&super_admin, &instructor, &UserRole::Instructor);
+
+        client.create_custom_role(
+            &instructor,
+            &String::from_str(&env, "Teaching Assistant"),
+            &vec![&env, Permission::EditCourses],
+        );
+    }
+}
@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Export of everything stored about a single user.
+//!
+//! Complements the admin backup with a per-user export for data-portability
+//! requests. Enrollments and certificates live in other contracts, which an
+//! admin registers with `set_data_export_config`.
+
+use skillcert_interfaces::certificates::CertificatesClient;
+use skillcert_interfaces::course_access::CourseAccessClient;
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::achievements::get_user_achievements;
+use crate::functions::is_admin::is_admin;
+use crate::functions::points::get_user_points;
+use crate::functions::profile_store::load_profile;
+use crate::functions::rbac::{effective_permissions, get_user_role};
+use crate::functions::social_links::get_social_links;
+use crate::functions::usernames::get_username;
+use crate::schema::{
+    Certificate, CertificatePagination, DataExportConfig, DataKey, PaginatedCertificates,
+    UserCourses, UserDataExport, UserProfile,
+};
+
+/// Page size used to read certificates, the certificates contract's maximum.
+const CERTIFICATE_PAGE_SIZE: u32 = 50;
+
+/// Returns the contracts exports read from; both are `None` until configured.
+pub fn get_data_export_config(env: &Env) -> DataExportConfig {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DataExportConfig)
+        .unwrap_or(DataExportConfig {
+            course_access_addr: None,
+            certificates_addr: None,
+        })
+}
+
+/// Sets the contracts exports read enrollments and certificates from.
+pub fn set_data_export_config(env: Env, admin: Address, config: DataExportConfig) {
+    admin.require_auth();
+    if !is_admin(env.clone(), admin) {
+        handle_error(&env, Error::AccessDenied);
+    }
+    env.storage().persistent().set(&DataKey::DataExportConfig, &config);
+}
+
+fn list_certificates(env: &Env, certificates_addr: &Address, user: &Address) -> Vec<Certificate> {
+    let mut certificates: Vec<Certificate> = Vec::new(env);
+    let mut cursor: Option<u32> = None;
+    loop {
+        let page: PaginatedCertificates = CertificatesClient::new(env, certificates_addr)
+            .list_certificates_by_student(
+                user,
+                &CertificatePagination {
+                    cursor,
+                    limit: CERTIFICATE_PAGE_SIZE,
+                },
+            );
+        certificates.append(&page.data);
+        if !page.has_more {
+            return certificates;
+        }
+        cursor = page.next_cursor;
+    }
+}
+
+/// Collects the profile, roles, permissions, enrollments and certificates of
+/// the caller into one record.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `user` - The user exporting their data (must be authorized).
+pub fn export_my_data(env: Env, user: Address) -> UserDataExport {
+    user.require_auth();
+
+    let profile: UserProfile = load_profile(&env, &user)
+        .unwrap_or_else(|| handle_error(&env, Error::UserProfileNotFound));
+    let config: DataExportConfig = get_data_export_config(&env);
+
+    let enrollments = match config.course_access_addr {
+        Some(addr) => {
+            let courses: UserCourses =
+                CourseAccessClient::new(&env, &addr).list_user_courses(&user);
+            courses.courses
+        }
+        None => Vec::new(&env),
+    };
+    let certificates: Vec<Certificate> = match config.certificates_addr {
+        Some(addr) => list_certificates(&env, &addr, &user),
+        None => Vec::new(&env),
+    };
+
+    UserDataExport {
+        user: user.clone(),
+        profile,
+        social_links: get_social_links(&env, user.clone()),
+        role: get_user_role(&env, &user),
+        permissions: effective_permissions(&env, &user),
+        username: get_username(&env, user.clone()),
+        points: get_user_points(&env, user.clone()),
+        achievements: get_user_achievements(&env, user.clone()),
+        enrollments,
+        certificates,
+        exported_at: env.ledger().timestamp(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{
+        Certificate, CertificatePagination, DataExportConfig, PaginatedCertificates, UserCourses,
+        UserProfile, UserRole,
+    };
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, vec, Address, BytesN, Env, String, Vec,
+    };
+
+    #[contract]
+    pub struct CourseAccess;
+
+    #[contractimpl]
+    impl CourseAccess {
+        pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
+            UserCourses {
+                user,
+                courses: vec![&env, String::from_str(&env, "course-1")],
+            }
+        }
+    }
+
+    #[contract]
+    pub struct Certificates;
+
+    #[contractimpl]
+    impl Certificates {
+        /// Returns one certificate per page, three in total.
+        pub fn list_certificates_by_student(
+            env: Env,
+            student: Address,
+            pagination: CertificatePagination,
+        ) -> PaginatedCertificates {
+            let id: u32 = pagination.cursor.unwrap_or(0) + 1;
+            PaginatedCertificates {
+                data: vec![
+                    &env,
+                    Certificate {
+                        id,
+                        course_id: String::from_str(&env, "course-1"),
+                        student: student.clone(),
+                        issuer: student,
+                        issued_at: 0,
+                        issued_ledger: 0,
+                        course_hash: BytesN::from_array(&env, &[0u8; 32]),
+                        valid_until: None,
+                        revoked: false,
+                        template_uri_hash: None,
+                        metadata_fields: Vec::new(&env),
+                    },
+                ],
+                next_cursor: Some(id),
+                total_count: 3,
+                has_more: id < 3,
+            }
+        }
+    }
+
+    #[test]
+    fn test_export_my_data() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin: Address = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+
+        let user: Address = Address::generate(&env);
+        let profile: UserProfile = UserProfile {
+            full_name: String::from_str(&env, "Jane Smith"),
+            contact_email: String::from_str(&env, "jane@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+
+        // Without configured contracts only local records are exported
+        let export = client.export_my_data(&user);
+        assert_eq!(export.profile, profile);
+        assert_eq!(export.role, UserRole::Student);
+        assert!(!export.permissions.is_empty());
+        assert!(export.enrollments.is_empty());
+
+        client.set_data_export_config(
+            &admin,
+            &DataExportConfig {
+                course_access_addr: Some(env.register(CourseAccess, ())),
+                certificates_addr: Some(env.register(Certificates, ())),
+            },
+        );
+        let export = client.export_my_data(&user);
+        assert_eq!(export.enrollments, vec![&env, String::from_str(&env, "course-1")]);
+        assert_eq!(export.certificates.len(), 3);
+        assert_eq!(export.certificates.get(2).unwrap().id, 3);
+    }
+}
@@ -1,13 +1,12 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use crate::events;
+use crate::functions::profile_store::load_public_profile;
 use crate::error::{handle_error, Error};
-use crate::schema::{AdminConfig, DataKey, LightProfile, UserProfile, UserStatus};
+use crate::schema::{AdminConfig, DataKey, LightProfile, PublicUserProfile, UserStatus};
 use core::iter::Iterator;
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
-
-// Event symbol for user deactivation
-const USER_DEACTIVATED_EVENT: Symbol = symbol_short!("usrDeact");
+use soroban_sdk::{Address, Env};
 
 /// Deletes a user by marking their status as inactive.
 /// 
@@ -61,8 +60,7 @@ pub fn delete_user(env: Env, caller: Address, user_id: Address) {
     // Only the status in LightProfile is changed to Inactive
 
     // Emits a user deactivation event upon successful deletion.
-    env.events()
-        .publish((USER_DEACTIVATED_EVENT, &caller), user_id.clone());
+    events::user_deleted(&env, &user_id, &caller);
 }
 
 /// Checks if the caller is an admin.
@@ -108,12 +106,9 @@ fn is_admin(env: &Env, who: &Address) -> bool {
 ///
 /// # Returns
 /// 
-/// * `Result<UserProfile, ()>` - Ok containing UserProfile if the user exists; otherwise, an error.
-fn validate_user_exists(env: &Env, user_id: &Address) -> Result<UserProfile, ()> {
-    env.storage()
-        .persistent()
-        .get::<DataKey, UserProfile>(&DataKey::UserProfile(user_id.clone()))
-        .ok_or(())
+/// * `Result<PublicUserProfile, ()>` - Ok containing the public profile if the user exists; otherwise, an error.
+fn validate_user_exists(env: &Env, user_id: &Address) -> Result<PublicUserProfile, ()> {
+    load_public_profile(env, user_id).ok_or(())
 }
 
 #[cfg(test)]
@@ -1,20 +1,20 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{Address, Env, String};
 
+use crate::events;
 use crate::error::{handle_error, Error};
 use crate::functions::is_admin::is_admin;
-use crate::functions::utils::url_validation;
+use crate::functions::profile_store::{load_profile, store_profile};
+use crate::functions::social_links::set_social_links;
+use crate::functions::utils::storage_utils::{is_email_available_for, register_email, release_email};
 use crate::schema::{DataKey, LightProfile, ProfileUpdateParams, UserProfile};
-
-// Event symbol for user profile update
-const USER_UPDATED_EVENT: Symbol = symbol_short!("usrUpdt");
+use validation::{validate_country_code, validate_email, validate_url};
 
 // Security constants for profile validation (matching create_user_profile)
 const MAX_NAME_LENGTH: usize = 100;
 const MAX_PROFESSION_LENGTH: usize = 100;
-const MAX_COUNTRY_LENGTH: usize = 56; // Longest country name
 
 /// Validates string content for security (reused from create_user_profile)
 fn validate_string_content(_env: &Env, s: &String, max_len: usize) -> bool {
@@ -46,17 +46,16 @@ pub fn edit_user_profile(
     caller.require_auth();
 
     // Check if user profile exists
-    let storage_key: DataKey = DataKey::UserProfile(user_id.clone());
-    let mut profile: UserProfile = env
-        .storage()
-        .persistent()
-        .get(&storage_key)
-        .unwrap_or_else(|| handle_error(&env, Error::UserProfileNotFound));
+    if !env.storage().persistent().has(&DataKey::UserProfile(user_id.clone())) {
+        handle_error(&env, Error::UserProfileNotFound);
+    }
 
     // Check permission to edit
     if !check_edit_permission(&env, &caller, &user_id) {
         handle_error(&env, Error::AccessDenied);
     }
+    let mut profile: UserProfile = load_profile(&env, &user_id)
+        .unwrap_or_else(|| handle_error(&env, Error::UserProfileNotFound));
 
     // Check if user is active by looking at light profile
     let light_storage_key: DataKey = DataKey::UserProfileLight(user_id.clone());
@@ -84,6 +83,22 @@ pub fn edit_user_profile(
         profile.full_name = name.clone();
     }
 
+    // Email changes must keep the email index consistent
+    if let Some(ref email) = updates.contact_email {
+        if email.is_empty() {
+            handle_error(&env, Error::EmailRequired);
+        }
+        if !validate_email(email) {
+            handle_error(&env, Error::InvalidEmailFormat);
+        }
+        if !is_email_available_for(&env, email, &user_id) {
+            handle_error(&env, Error::EmailAlreadyExists);
+        }
+        release_email(&env, &profile.contact_email);
+        register_email(&env, email, &user_id);
+        profile.contact_email = email.clone();
+    }
+
     if let Some(ref profession) = updates.profession {
         if !profession.is_empty() && !validate_string_content(&env, profession, MAX_PROFESSION_LENGTH) {
             handle_error(&env, Error::InvalidField);
@@ -92,8 +107,8 @@ pub fn edit_user_profile(
     }
 
     if let Some(ref country) = updates.country {
-        if !country.is_empty() && !validate_string_content(&env, country, MAX_COUNTRY_LENGTH) {
-            handle_error(&env, Error::InvalidField);
+        if !country.is_empty() && !validate_country_code(country) {
+            handle_error(&env, Error::InvalidCountryCode);
         }
         profile.country = if country.is_empty() { None } else { Some(country.clone()) };
     }
@@ -108,14 +123,19 @@ pub fn edit_user_profile(
 
     // Validate profile picture URL if provided
     if let Some(ref profile_pic_url) = updates.profile_picture_url {
-        if !profile_pic_url.is_empty() && !url_validation::is_valid_url(profile_pic_url) {
+        if !profile_pic_url.is_empty() && !validate_url(profile_pic_url) {
             handle_error(&env, Error::InvalidProfilePicURL);
         }
         profile.profile_picture_url = if profile_pic_url.is_empty() { None } else { Some(profile_pic_url.clone()) };
     }
 
+    // Replace social links if provided
+    if let Some(ref social_links) = updates.social_links {
+        set_social_links(&env, &user_id, social_links);
+    }
+
     // Update the full profile in storage
-    env.storage().persistent().set(&storage_key, &profile);
+    store_profile(&env, &user_id, &profile);
 
     // Update the light profile with new data
     let updated_light_profile: LightProfile = LightProfile {
@@ -131,9 +151,7 @@ pub fn edit_user_profile(
         .persistent()
         .set(&light_storage_key, &updated_light_profile);
 
-    // Emit user update event
-    env.events()
-        .publish((USER_UPDATED_EVENT, &user_id), user_id.clone());
+    events::user_updated(&env, &user_id, &caller);
 
     profile
 }
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Erasure of personal data on the user's request.
+//!
+//! Deactivating a user keeps their profile for reactivation, which is not
+//! enough when a user asks for their data to be deleted. A user files an
+//! erasure request and an admin executes it: the name, email, country and
+//! other personal fields are overwritten with tombstones, while records that
+//! identify nobody (points, achievements, completed courses) are kept.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::is_admin::is_admin;
+use crate::functions::profile_store::{load_profile, store_profile};
+use crate::functions::utils::storage_utils::release_email;
+use crate::schema::{DataKey, LightProfile, UserProfile, UserStatus};
+
+/// Value written over erased text fields.
+const ERASED_TOMBSTONE: &str = "[erased]";
+
+/// Returns the users waiting for their erasure request to be executed.
+pub fn list_erasure_requests(env: &Env, admin: Address) -> Vec<Address> {
+    admin.require_auth();
+    if !is_admin(env.clone(), admin) {
+        handle_error(env, Error::AccessDenied);
+    }
+    pending_requests(env)
+}
+
+fn pending_requests(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ErasureRequests)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns when a user's data was erased, or `None` if it wasn't.
+pub fn get_erased_at(env: &Env, user: Address) -> Option<u64> {
+    env.storage().persistent().get(&DataKey::ErasedAt(user))
+}
+
+/// Files a request to erase the caller's personal data. Filing it again
+/// while it is pending has no effect.
+pub fn request_data_erasure(env: Env, user: Address) {
+    user.require_auth();
+
+    if !env.storage().persistent().has(&DataKey::UserProfile(user.clone())) {
+        handle_error(&env, Error::UserProfileNotFound);
+    }
+    if get_erased_at(&env, user.clone()).is_some() {
+        handle_error(&env, Error::DataAlreadyErased);
+    }
+    let mut pending: Vec<Address> = pending_requests(&env);
+    if pending.contains(&user) {
+        return;
+    }
+    pending.push_back(user.clone());
+    env.storage().persistent().set(&DataKey::ErasureRequests, &pending);
+
+    events::erasure_requested(&env, &user);
+}
+
+/// Executes a pending erasure request.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `admin` - The admin executing the request (must be authorized).
+/// * `user` - The user whose personal data is erased.
+pub fn execute_erasure(env: Env, admin: Address, user: Address) {
+    admin.require_auth();
+    if !is_admin(env.clone(), admin.clone()) {
+        handle_error(&env, Error::AccessDenied);
+    }
+
+    let mut pending: Vec<Address> = pending_requests(&env);
+    let index: u32 = pending
+        .first_index_of(&user)
+        .unwrap_or_else(|| handle_error(&env, Error::ErasureNotRequested));
+    pending.remove(index);
+    env.storage().persistent().set(&DataKey::ErasureRequests, &pending);
+
+    let tombstone: String = String::from_str(&env, ERASED_TOMBSTONE);
+    let profile: UserProfile = load_profile(&env, &user)
+        .unwrap_or_else(|| handle_error(&env, Error::UserProfileNotFound));
+    release_email(&env, &profile.contact_email);
+    store_profile(
+        &env,
+        &user,
+        &UserProfile {
+            full_name: tombstone.clone(),
+            contact_email: tombstone.clone(),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+        },
+    );
+
+    // Erased users stay listed as inactive so user counts remain correct
+    let light_key: DataKey = DataKey::UserProfileLight(user.clone());
+    if let Some(light_profile) = env.storage().persistent().get::<DataKey, LightProfile>(&light_key) {
+        env.storage().persistent().set(
+            &light_key,
+            &LightProfile {
+                full_name: tombstone,
+                profession: None,
+                country: None,
+                role: light_profile.role,
+                status: UserStatus::Inactive,
+                user_address: user.clone(),
+            },
+        );
+    }
+
+    if let Some(username) = env.storage().persistent().get::<DataKey, String>(&DataKey::Username(user.clone())) {
+        env.storage().persistent().remove(&DataKey::UsernameIndex(username));
+        env.storage().persistent().remove(&DataKey::Username(user.clone()));
+    }
+    env.storage().persistent().remove(&DataKey::PrivacySettings(user.clone()));
+    env.storage().persistent().remove(&DataKey::SocialLinks(user.clone()));
+    env.storage()
+        .persistent()
+        .set(&DataKey::ErasedAt(user.clone()), &env.ledger().timestamp());
+
+    events::data_erased(&env, &user, &admin);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        symbol_short, Address, Env, String,
+    };
+
+    #[test]
+    fn test_erasure_overwrites_personal_data() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin: Address = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+
+        let user: Address = Address::generate(&env);
+        client.create_user_profile(
+            &user,
+            &UserProfile {
+                full_name: String::from_str(&env, "Jane Smith"),
+                contact_email: String::from_str(&env, "jane@example.com"),
+                profession: Some(String::from_str(&env, "Engineer")),
+                country: Some(String::from_str(&env, "US")),
+                purpose: None,
+                profile_picture_url: None,
+            },
+        );
+        client.claim_username(&user, &String::from_str(&env, "jane"));
+        client.award_points(&admin, &user, &150, &symbol_short!("quiz"));
+
+        // Erasure needs a request first
+        assert!(client.try_execute_erasure(&admin, &user).is_err());
+        client.request_data_erasure(&user);
+        client.request_data_erasure(&user);
+        assert_eq!(client.list_erasure_requests(&admin).len(), 1);
+
+        client.execute_erasure(&admin, &user);
+        let erased: UserProfile = client.get_user_by_id(&admin, &user);
+        assert_eq!(erased.full_name, String::from_str(&env, "[erased]"));
+        assert_eq!(erased.contact_email, String::from_str(&env, "[erased]"));
+        assert_eq!(erased.country, None);
+        assert_eq!(client.get_username(&user), None);
+        assert_eq!(client.get_erased_at(&user), Some(1_000));
+        assert!(client.list_erasure_requests(&admin).is_empty());
+
+        // Non-personal records are kept, and the email can be registered again
+        assert_eq!(client.get_user_points(&user), 150);
+        assert!(client.try_request_data_erasure(&user).is_err());
+        let other: Address = Address::generate(&env);
+        client.create_user_profile(
+            &other,
+            &UserProfile {
+                full_name: String::from_str(&env, "Jane Doe"),
+                contact_email: String::from_str(&env, "jane@example.com"),
+                profession: None,
+                country: None,
+                purpose: None,
+                profile_picture_url: None,
+            },
+        );
+    }
+}
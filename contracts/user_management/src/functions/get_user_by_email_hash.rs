@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::error::{handle_error, Error};
+use crate::functions::rbac::{has_permission, is_admin};
+use crate::functions::utils::storage_utils::get_address_by_email_hash;
+use crate::schema::Permission;
+
+/// Resolves the account registered for an email hash.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - The address performing the lookup (must be admin or support staff).
+/// * `email_hash` - SHA-256 hash of the lowercased email address.
+///
+/// # Returns
+///
+/// * `Option<Address>` - The address that registered the email, or `None`.
+///
+/// # Panics
+///
+/// * Panics with `AccessDenied` if the caller is neither admin nor has `ProvideSupport`.
+pub fn get_user_by_email_hash(env: Env, caller: Address, email_hash: BytesN<32>) -> Option<Address> {
+    caller.require_auth();
+
+    if !is_admin(&env, &caller) && !has_permission(&env, &caller, &Permission::ProvideSupport) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    get_address_by_email_hash(&env, &email_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::functions::utils::storage_utils::email_hash;
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Bytes, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+        (env, client, admin)
+    }
+
+    fn profile(env: &Env, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Alice Johnson"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+        }
+    }
+
+    #[test]
+    fn test_lookup_by_email_hash() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "Alice@Example.com"));
+
+        let hash = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"alice@example.com"))
+            .into();
+        assert_eq!(client.get_user_by_email_hash(&admin, &hash), Some(user));
+
+        let unknown = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"bob@example.com"))
+            .into();
+        assert_eq!(client.get_user_by_email_hash(&admin, &unknown), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    fn test_lookup_by_email_hash_requires_admin() {
+        let (env, client, _admin) = setup();
+        let student = Address::generate(&env);
+        let hash = email_hash(&env, &String::from_str(&env, "alice@example.com"));
+        client.get_user_by_email_hash(&student, &hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #16)")]
+    fn test_duplicate_email_case_insensitive() {
+        let (env, client, _admin) = setup();
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "alice@example.com"));
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "ALICE@example.com"));
+    }
+}
@@ -4,6 +4,8 @@
 use soroban_sdk::{Address, Env};
 
 use crate::error::{handle_error, Error};
+use crate::functions::profile_store::load_profile;
+use crate::functions::ttl::bump_persistent;
 use crate::schema::{DataKey, UserProfile};
 use core::iter::Iterator;
 
@@ -32,12 +34,11 @@ pub fn get_user_by_id(env: Env, requester: Address, user_id: Address) -> UserPro
         handle_error(&env, Error::AccessDenied); // Generic error message
     }
 
-    // Retrieve the user profile from storage
-    let profile: UserProfile = env
-        .storage()
-        .persistent()
-        .get::<DataKey, UserProfile>(&DataKey::UserProfile(user_id.clone()))
+    // The private record is only loaded once the requester passed the check
+    let profile: UserProfile = load_profile(&env, &user_id)
         .unwrap_or_else(|| handle_error(&env, Error::AccessDenied)); // Don't disclose if user exists
+    bump_persistent(&env, &DataKey::UserProfile(user_id.clone()));
+    bump_persistent(&env, &DataKey::ContactEmail(user_id));
 
     profile
 }
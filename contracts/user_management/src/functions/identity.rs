@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Several wallet addresses linked to one learner identity.
+//!
+//! A primary address can link secondary addresses, with both signing the
+//! link. Other contracts call `resolve_identity` so a learner switching
+//! wallets keeps their enrollments and certificates. Links are one level
+//! deep: a secondary address cannot link addresses of its own.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::schema::DataKey;
+
+/// Maximum number of secondary addresses per identity.
+const MAX_LINKED_ADDRESSES: u32 = 10;
+
+/// Returns the secondary addresses linked to a primary address.
+pub fn get_linked_addresses(env: &Env, primary: Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LinkedAddresses(primary))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the primary address of the identity `address` belongs to, or
+/// `address` itself if it is not a linked secondary address.
+pub fn resolve_identity(env: &Env, address: Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IdentityLink(address.clone()))
+        .unwrap_or(address)
+}
+
+/// Links `secondary` to the identity of `primary`. Both must authorize.
+pub fn link_address(env: Env, primary: Address, secondary: Address) {
+    primary.require_auth();
+    secondary.require_auth();
+
+    if primary == secondary
+        || env.storage().persistent().has(&DataKey::IdentityLink(primary.clone()))
+        || !get_linked_addresses(&env, secondary.clone()).is_empty()
+    {
+        handle_error(&env, Error::InvalidIdentityLink);
+    }
+    if env.storage().persistent().has(&DataKey::IdentityLink(secondary.clone())) {
+        handle_error(&env, Error::AddressAlreadyLinked);
+    }
+
+    let mut linked: Vec<Address> = get_linked_addresses(&env, primary.clone());
+    if linked.len() >= MAX_LINKED_ADDRESSES {
+        handle_error(&env, Error::InvalidIdentityLink);
+    }
+    linked.push_back(secondary.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::LinkedAddresses(primary.clone()), &linked);
+    env.storage()
+        .persistent()
+        .set(&DataKey::IdentityLink(secondary.clone()), &primary);
+
+    events::address_linked(&env, &primary, &secondary);
+}
+
+/// Removes a secondary address from the identity of `primary`. Either
+/// address may unlink.
+pub fn unlink_address(env: Env, caller: Address, primary: Address, secondary: Address) {
+    caller.require_auth();
+
+    if caller != primary && caller != secondary {
+        handle_error(&env, Error::AccessDenied);
+    }
+    let mut linked: Vec<Address> = get_linked_addresses(&env, primary.clone());
+    let index: u32 = linked
+        .first_index_of(&secondary)
+        .unwrap_or_else(|| handle_error(&env, Error::InvalidIdentityLink));
+    linked.remove(index);
+    env.storage()
+        .persistent()
+        .set(&DataKey::LinkedAddresses(primary.clone()), &linked);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::IdentityLink(secondary.clone()));
+
+    events::address_unlinked(&env, &primary, &secondary);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+    #[test]
+    fn test_link_and_unlink_addresses() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let primary: Address = Address::generate(&env);
+        let secondary: Address = Address::generate(&env);
+        let other: Address = Address::generate(&env);
+
+        assert_eq!(client.resolve_identity(&secondary), secondary);
+        client.link_address(&primary, &secondary);
+        assert_eq!(client.resolve_identity(&secondary), primary);
+        assert_eq!(client.resolve_identity(&primary), primary);
+        assert_eq!(client.get_linked_addresses(&primary), vec![&env, secondary.clone()]);
+
+        // A linked address cannot join another identity or link its own
+        assert!(client.try_link_address(&other, &secondary).is_err());
+        assert!(client.try_link_address(&secondary, &other).is_err());
+        assert!(client.try_link_address(&primary, &primary).is_err());
+
+        client.unlink_address(&secondary, &primary, &secondary);
+        assert_eq!(client.resolve_identity(&secondary), secondary);
+        assert!(client.get_linked_addresses(&primary).is_empty());
+        client.link_address(&other, &secondary);
+        assert_eq!(client.resolve_identity(&secondary), other);
+    }
+}
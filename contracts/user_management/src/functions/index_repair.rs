@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Repair of the users and email indexes.
+//!
+//! The list of registered users is maintained next to the profiles and can
+//! hold duplicates or stale addresses after a bug or an interrupted import.
+//! It is rebuilt in bounded chunks into a staging list that replaces the
+//! index once every entry was checked.
+//!
+//! Emails registered before the email index was hashed are moved to it the
+//! same way, walking the users index in chunks. Profiles stored before the
+//! public/private split are split the same way too.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error, UserError};
+use crate::functions::is_admin::is_admin;
+use crate::functions::profile_store::{load_profile, split_legacy_profile};
+use crate::functions::utils::storage_utils::email_hash;
+use crate::schema::{DataKey, MAX_REBUILD_BATCH};
+
+fn require_batch(env: &Env, caller: Address, limit: u32) {
+    caller.require_auth();
+    if !is_admin(env.clone(), caller) {
+        handle_error(env, Error::AccessDenied);
+    }
+    if limit == 0 || limit > MAX_REBUILD_BATCH {
+        handle_error(env, UserError::InvalidBatchSize);
+    }
+}
+
+/// Checks index entries `cursor` to `cursor + limit` and keeps those with a
+/// profile that weren't kept already. Returns the next cursor, or `None`
+/// once the rebuilt index was stored.
+pub fn rebuild_users_index(env: &Env, caller: Address, cursor: u32, limit: u32) -> Option<u32> {
+    require_batch(env, caller, limit);
+
+    let users_index: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UsersIndex)
+        .unwrap_or(Vec::new(env));
+    let mut rebuilt: Vec<Address> = if cursor == 0 {
+        Vec::new(env)
+    } else {
+        env.storage()
+            .persistent()
+            .get(&DataKey::UsersIndexRebuild)
+            .unwrap_or(Vec::new(env))
+    };
+
+    let end: u32 = cursor.saturating_add(limit).min(users_index.len());
+    for i in cursor..end {
+        let user: Address = users_index.get(i).unwrap();
+        if env.storage().persistent().has(&DataKey::UserProfile(user.clone())) && !rebuilt.contains(&user) {
+            rebuilt.push_back(user);
+        }
+    }
+
+    if end < users_index.len() {
+        env.storage().persistent().set(&DataKey::UsersIndexRebuild, &rebuilt);
+        Some(end)
+    } else {
+        env.storage().persistent().set(&DataKey::UsersIndex, &rebuilt);
+        env.storage().persistent().remove(&DataKey::UsersIndexRebuild);
+        None
+    }
+}
+
+/// Moves the legacy `EmailIndex` entries of users `cursor` to `cursor + limit`
+/// of the users index to `EmailHash`. Returns the next cursor, or `None` once
+/// the whole index was walked.
+pub fn migrate_email_index(env: &Env, caller: Address, cursor: u32, limit: u32) -> Option<u32> {
+    require_batch(env, caller, limit);
+
+    let users_index: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UsersIndex)
+        .unwrap_or(Vec::new(env));
+
+    let end: u32 = cursor.saturating_add(limit).min(users_index.len());
+    for i in cursor..end {
+        let user: Address = users_index.get(i).unwrap();
+        let Some(profile) = load_profile(env, &user) else {
+            continue;
+        };
+        let legacy_key: DataKey = DataKey::EmailIndex(profile.contact_email.clone());
+        if let Some(owner) = env.storage().persistent().get::<DataKey, Address>(&legacy_key) {
+            let hash_key: DataKey = DataKey::EmailHash(email_hash(env, &profile.contact_email));
+            if !env.storage().persistent().has(&hash_key) {
+                env.storage().persistent().set(&hash_key, &owner);
+            }
+            env.storage().persistent().remove(&legacy_key);
+        }
+    }
+
+    if end < users_index.len() {
+        Some(end)
+    } else {
+        None
+    }
+}
+
+/// Moves the email of the profiles of users `cursor` to `cursor + limit` of
+/// the users index to its private record. Returns the next cursor, or `None`
+/// once the whole index was walked.
+pub fn migrate_profile_split(env: &Env, caller: Address, cursor: u32, limit: u32) -> Option<u32> {
+    require_batch(env, caller, limit);
+
+    let users_index: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UsersIndex)
+        .unwrap_or(Vec::new(env));
+
+    let end: u32 = cursor.saturating_add(limit).min(users_index.len());
+    for i in cursor..end {
+        split_legacy_profile(env, &users_index.get(i).unwrap());
+    }
+
+    if end < users_index.len() {
+        Some(end)
+    } else {
+        None
+    }
+}
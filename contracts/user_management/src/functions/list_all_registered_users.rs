@@ -2,16 +2,12 @@
 // Copyright (c) 2025 SkillCert
 
 use crate::error::{handle_error, Error};
+use crate::functions::privacy::listed_profile;
+use crate::functions::utils::storage_utils::get_registered_at;
 use crate::schema::{AdminConfig, DataKey, LightProfile, PaginatedLightProfiles, PaginationParams, UserFilter, UserRole, UserStatus};
 use core::iter::Iterator;
-use soroban_sdk::{Address, Env, String, Vec};
-
-
-fn string_contains(haystack: &String, needle: &String) -> bool {
-    // For now, only exact match is supported
-    // This can be enhanced later when Soroban provides better string utilities
-    haystack == needle
-}
+use soroban_sdk::{Address, Env, Vec};
+use validation::string_search::contains_ignore_case;
 
 /// Security constants
 const MAX_PAGE_SIZE_ABSOLUTE: u32 = 1000;
@@ -79,14 +75,10 @@ pub fn list_all_users(
 
     for i in 0..users_index.len() {
         if let Some(addr) = users_index.get(i) {
-            // Fetch lightweight profile for each address
-            if let Some(profile) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, LightProfile>(&DataKey::UserProfileLight(addr))
-            {
+            // Fetch lightweight profile for each address, as privacy settings allow
+            if let Some(profile) = listed_profile(&env, addr) {
                 // Apply filter if provided
-                if matches_filter(&profile, &filter) {
+                if matches_filter(&env, &profile, &filter) {
                     filtered_profiles.push_back(profile);
                 }
             }
@@ -207,6 +199,7 @@ fn is_admin(env: &Env, who: &Address) -> bool {
 ///
 /// # Arguments
 ///
+/// * `env` - Soroban environment, to read registration dates.
 /// * `profile` - The user profile to check.
 /// * `filter` - Optional filter criteria.
 ///
@@ -214,6 +207,7 @@ fn is_admin(env: &Env, who: &Address) -> bool {
 ///
 /// * `bool` - True if the profile matches the filter, otherwise false.
 fn matches_filter(
+    env: &Env,
     profile: &LightProfile,
     filter: &Option<UserFilter>,
 ) -> bool {
@@ -242,13 +236,30 @@ fn matches_filter(
         }
     }
 
+    // Check profession filter
+    if let Some(ref profession) = filter.profession {
+        if profile.profession.as_ref() != Some(profession) {
+            return false;
+        }
+    }
+
+    // Check registration date range (after is inclusive, before exclusive)
+    if filter.registered_after.is_some() || filter.registered_before.is_some() {
+        let registered_at: u64 = get_registered_at(env, &profile.user_address);
+        if filter.registered_after.is_some_and(|after| registered_at < after) {
+            return false;
+        }
+        if filter.registered_before.is_some_and(|before| registered_at >= before) {
+            return false;
+        }
+    }
+
     // Check text search filter (search in full_name and profession)
     if let Some(ref search_text) = filter.search_text {
-        // Text search in name and profession
-        // Note: Case-sensitive search due to Soroban String limitations
-        let name_match = string_contains(&profile.full_name, search_text);
+        // Case-insensitive substring search in name and profession
+        let name_match = contains_ignore_case(&profile.full_name, search_text);
         let profession_match = profile.profession.as_ref()
-            .map(|p| string_contains(p, search_text))
+            .map(|p| contains_ignore_case(p, search_text))
             .unwrap_or(false);
         
         if !name_match && !profession_match {
@@ -294,6 +305,14 @@ fn validate_input(
                 return Err("search_text is too long (max 100 characters)");
             }
         }
+        if let Some(ref profession) = filter.profession {
+            if profession.is_empty() {
+                return Err("profession cannot be empty");
+            }
+            if profession.len() > 100 {
+                return Err("profession is too long (max 100 characters)");
+            }
+        }
     }
 
     Ok(())
@@ -386,20 +405,19 @@ pub fn list_all_users_cursor(
         }
 
         if let Some(addr) = users_index.get(i) {
-            // Fetch lightweight profile for each address
-            if let Some(profile) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, LightProfile>(&DataKey::UserProfileLight(addr))
-            {
+            // Fetch lightweight profile for each address, as privacy settings allow
+            if let Some(profile) = listed_profile(&env, addr) {
                 // Apply filter if provided
                 let filter = UserFilter {
                     role: role_filter.clone(),
                     country: None,
                     status: status_filter.clone(),
                     search_text: None,
+                    profession: None,
+                    registered_after: None,
+                    registered_before: None,
                 };
-                if matches_filter(&profile, &Some(filter)) {
+                if matches_filter(&env, &profile, &Some(filter)) {
                     total_matching += 1;
                     
                     // Skip the cursor address itself (we start after it)
@@ -424,18 +442,17 @@ pub fn list_all_users_cursor(
         let mut found_more: bool = false;
         for i in (start_index + processed_count)..users_index.len() {
             if let Some(addr) = users_index.get(i) {
-                if let Some(profile) = env
-                    .storage()
-                    .persistent()
-                    .get::<DataKey, LightProfile>(&DataKey::UserProfileLight(addr))
-                {
+                if let Some(profile) = listed_profile(&env, addr) {
                     let filter = UserFilter {
                         role: role_filter.clone(),
                         country: None,
                         status: status_filter.clone(),
                         search_text: None,
+                        profession: None,
+                        registered_after: None,
+                        registered_before: None,
                     };
-                    if matches_filter(&profile, &Some(filter)) {
+                    if matches_filter(&env, &profile, &Some(filter)) {
                         found_more = true;
                         break;
                     }
@@ -521,7 +538,7 @@ mod tests {
         let env = Env::default();
         let profile = create_test_profile(&env);
 
-        assert!(matches_filter(&profile, &None));
+        assert!(matches_filter(&env, &profile, &None));
     }
 
     #[test]
@@ -534,9 +551,12 @@ mod tests {
             country: None,
             status: None,
             search_text: None,
+            profession: None,
+            registered_after: None,
+            registered_before: None,
         };
 
-        assert!(matches_filter(&profile, &Some(filter)));
+        assert!(matches_filter(&env, &profile, &Some(filter)));
     }
 
     #[test]
@@ -549,9 +569,12 @@ mod tests {
             country: None,
             status: None,
             search_text: None,
+            profession: None,
+            registered_after: None,
+            registered_before: None,
         };
 
-        assert!(!matches_filter(&profile, &Some(filter)));
+        assert!(!matches_filter(&env, &profile, &Some(filter)));
     }
 
     #[test]
@@ -564,9 +587,12 @@ mod tests {
             country: None,
             status: Some(UserStatus::Active),
             search_text: None,
+            profession: None,
+            registered_after: None,
+            registered_before: None,
         };
 
-        assert!(matches_filter(&profile, &Some(filter)));
+        assert!(matches_filter(&env, &profile, &Some(filter)));
     }
 
     #[test]
@@ -579,9 +605,12 @@ mod tests {
             country: Some(String::from_str(&env, "United States")),
             status: Some(UserStatus::Active),
             search_text: None,
+            profession: None,
+            registered_after: None,
+            registered_before: None,
         };
 
-        assert!(matches_filter(&profile, &Some(filter)));
+        assert!(matches_filter(&env, &profile, &Some(filter)));
     }
 
     #[test]
@@ -595,9 +624,12 @@ mod tests {
             country: None,
             status: None,
             search_text: Some(String::from_str(&env, "John Doe")),
+            profession: None,
+            registered_after: None,
+            registered_before: None,
         };
 
-        assert!(matches_filter(&profile, &Some(filter)));
+        assert!(matches_filter(&env, &profile, &Some(filter)));
     }
 
     #[test]
@@ -611,25 +643,31 @@ mod tests {
             country: None,
             status: None,
             search_text: Some(String::from_str(&env, "Software Engineer")),
+            profession: None,
+            registered_after: None,
+            registered_before: None,
         };
 
-        assert!(matches_filter(&profile, &Some(filter)));
+        assert!(matches_filter(&env, &profile, &Some(filter)));
     }
 
     #[test]
-    fn test_matches_filter_text_search_partial_no_match() {
+    fn test_matches_filter_text_search_partial_match() {
         let env = Env::default();
         let profile = create_test_profile(&env);
 
-        // Search for partial name - should NOT match (only exact match supported)
+        // Search for part of the name in another case - should match
         let filter = UserFilter {
             role: None,
             country: None,
             status: None,
-            search_text: Some(String::from_str(&env, "John")),
+            search_text: Some(String::from_str(&env, "john")),
+            profession: None,
+            registered_after: None,
+            registered_before: None,
         };
 
-        assert!(!matches_filter(&profile, &Some(filter)));
+        assert!(matches_filter(&env, &profile, &Some(filter)));
     }
 
     #[test]
@@ -643,9 +681,40 @@ mod tests {
             country: None,
             status: None,
             search_text: Some(String::from_str(&env, "Python")),
+            profession: None,
+            registered_after: None,
+            registered_before: None,
+        };
+
+        assert!(!matches_filter(&env, &profile, &Some(filter)));
+    }
+
+    #[test]
+    fn test_matches_filter_profession_and_registration_date() {
+        let env = Env::default();
+        let contract_id = env.register(crate::UserManagement, ());
+        let profile = create_test_profile(&env);
+
+        let mut filter = UserFilter {
+            role: None,
+            country: None,
+            status: None,
+            search_text: None,
+            profession: Some(String::from_str(&env, "Software Engineer")),
+            registered_after: Some(1_000),
+            registered_before: Some(2_000),
         };
+        env.as_contract(&contract_id, || {
+            crate::functions::utils::storage_utils::set_registered_at(&env, &profile.user_address, 1_000);
+            assert!(matches_filter(&env, &profile, &Some(filter.clone())));
+
+            filter.registered_before = Some(1_000);
+            assert!(!matches_filter(&env, &profile, &Some(filter.clone())));
 
-        assert!(!matches_filter(&profile, &Some(filter)));
+            filter.registered_before = None;
+            filter.profession = Some(String::from_str(&env, "Designer"));
+            assert!(!matches_filter(&env, &profile, &Some(filter)));
+        });
     }
 
     #[test]
@@ -1,19 +1,35 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+pub mod achievements;
 pub mod admin_management;
 pub mod backup_recovery;
 pub mod contract_versioning;
 pub mod create_user_profile;
+pub mod custom_roles;
+pub mod data_export;
 pub mod delete_user;
 pub mod edit_user_profile;
+pub mod erasure;
+pub mod get_user_by_email_hash;
 pub mod get_user_by_id;
+pub mod identity;
+pub mod index_repair;
 pub mod is_admin;
 pub mod list_all_registered_users;
 pub mod list_users_with_access;
+pub mod organizations;
+pub mod personhood;
+pub mod platform_stats;
+pub mod points;
+pub mod privacy;
+pub mod profile_store;
 pub mod rbac;
 pub mod save_profile;
+pub mod social_links;
+pub mod ttl;
 pub mod user;
+pub mod usernames;
 pub mod utils;
 
 #[cfg(test)]
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Organization accounts with seat-based course licensing.
+//!
+//! An organization groups members under one or more org admins. Seats are
+//! purchased per course; the course access contract reads the seat count and
+//! lets org admins spend seats on their members with `grant_org_access`.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::is_admin::is_admin;
+use crate::schema::{DataKey, Organization};
+
+/// Maximum length of an organization name.
+const MAX_ORG_NAME_LENGTH: u32 = 100;
+
+fn require_org(env: &Env, org_id: u32) -> Organization {
+    get_org(env, org_id).unwrap_or_else(|| handle_error(env, Error::OrgNotFound))
+}
+
+fn require_org_admin(env: &Env, caller: &Address, org_id: u32) {
+    caller.require_auth();
+
+    require_org(env, org_id);
+    if !is_org_admin(env, org_id, caller) {
+        handle_error(env, Error::AccessDenied);
+    }
+}
+
+/// Creates an organization owned by `owner`, who becomes its first admin and member.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `owner` - The creator of the organization (must be authorized).
+/// * `name` - Display name of the organization.
+///
+/// # Returns
+///
+/// * `Organization` - The stored organization with its new identifier.
+pub fn create_org(env: Env, owner: Address, name: String) -> Organization {
+    owner.require_auth();
+
+    if name.is_empty() || name.len() > MAX_ORG_NAME_LENGTH {
+        handle_error(&env, Error::NameRequired);
+    }
+
+    let id: u32 = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u32>(&DataKey::OrgCount)
+        .unwrap_or(0)
+        + 1;
+
+    let org: Organization = Organization {
+        id,
+        name,
+        owner: owner.clone(),
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&DataKey::Organization(id), &org);
+    env.storage().persistent().set(&DataKey::OrgCount, &id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrgMembers(id), &Vec::from_array(&env, [owner.clone()]));
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrgAdmin(id, owner), &true);
+
+    events::org_created(&env, &org);
+
+    org
+}
+
+/// Retrieves an organization, or `None` if it does not exist.
+pub fn get_org(env: &Env, org_id: u32) -> Option<Organization> {
+    env.storage().persistent().get(&DataKey::Organization(org_id))
+}
+
+/// Retrieves the members of an organization, owner first.
+pub fn get_org_members(env: &Env, org_id: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgMembers(org_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns whether `who` belongs to the organization.
+pub fn is_org_member(env: &Env, org_id: u32, who: &Address) -> bool {
+    get_org_members(env, org_id).contains(who)
+}
+
+/// Returns whether `who` administers the organization.
+pub fn is_org_admin(env: &Env, org_id: u32, who: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgAdmin(org_id, who.clone()))
+        .unwrap_or(false)
+}
+
+/// Adds a member to an organization or changes their admin flag (org admins only).
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `caller` - An admin of the organization (must be authorized).
+/// * `org_id` - The organization identifier.
+/// * `member` - The address joining the organization.
+/// * `as_admin` - Whether the member can manage the organization.
+pub fn add_org_member(env: Env, caller: Address, org_id: u32, member: Address, as_admin: bool) {
+    require_org_admin(&env, &caller, org_id);
+
+    let org: Organization = require_org(&env, org_id);
+    if member == org.owner && !as_admin {
+        handle_error(&env, Error::CannotRemoveOrgOwner);
+    }
+
+    let mut members: Vec<Address> = get_org_members(&env, org_id);
+    if !members.contains(&member) {
+        members.push_back(member.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::OrgMembers(org_id), &members);
+    }
+
+    let admin_key: DataKey = DataKey::OrgAdmin(org_id, member.clone());
+    if as_admin {
+        env.storage().persistent().set(&admin_key, &true);
+    } else {
+        env.storage().persistent().remove(&admin_key);
+    }
+
+    events::org_member_added(&env, org_id, &member, as_admin, &caller);
+}
+
+/// Removes a member from an organization (org admins only).
+///
+/// The owner cannot be removed. Course access already granted to the member
+/// is kept.
+pub fn remove_org_member(env: Env, caller: Address, org_id: u32, member: Address) {
+    require_org_admin(&env, &caller, org_id);
+
+    if require_org(&env, org_id).owner == member {
+        handle_error(&env, Error::CannotRemoveOrgOwner);
+    }
+
+    let mut members: Vec<Address> = get_org_members(&env, org_id);
+    if let Some(index) = members.first_index_of(&member) {
+        members.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OrgMembers(org_id), &members);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OrgAdmin(org_id, member.clone()));
+
+        events::org_member_removed(&env, org_id, &member, &caller);
+    }
+}
+
+/// Records seats bought by an organization for a course (platform admins only).
+///
+/// Called once the purchase is settled; seats add up across purchases.
+///
+/// # Returns
+///
+/// * `u32` - The total number of seats purchased for the course.
+pub fn purchase_org_seats(env: Env, caller: Address, org_id: u32, course_id: String, seats: u32) -> u32 {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller.clone()) {
+        handle_error(&env, Error::AccessDenied);
+    }
+    require_org(&env, org_id);
+    if course_id.is_empty() {
+        handle_error(&env, Error::RequiredFieldMissing);
+    }
+    if seats == 0 {
+        handle_error(&env, Error::InvalidSeatCount);
+    }
+
+    let total_seats: u32 = get_org_seats(&env, org_id, course_id.clone())
+        .checked_add(seats)
+        .unwrap_or_else(|| handle_error(&env, Error::InvalidSeatCount));
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrgSeats(org_id, course_id.clone()), &total_seats);
+
+    events::org_seats_purchased(&env, org_id, &course_id, seats, total_seats, &caller);
+
+    total_seats
+}
+
+/// Returns the number of seats an organization purchased for a course.
+pub fn get_org_seats(env: &Env, org_id: u32, course_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgSeats(org_id, course_id))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::Organization;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup(env: &Env) -> (UserManagementClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(env, &contract_id);
+        let super_admin: Address = Address::generate(env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        (client, super_admin)
+    }
+
+    #[test]
+    fn test_organization_members_and_seats() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+        let owner: Address = Address::generate(&env);
+        let manager: Address = Address::generate(&env);
+        let employee: Address = Address::generate(&env);
+        let course_id: String = String::from_str(&env, "course_1");
+
+        let org: Organization = client.create_org(&owner, &String::from_str(&env, "Acme"));
+        assert_eq!(org.id, 1);
+        assert_eq!(client.get_org(&1), Some(org.clone()));
+        assert!(client.is_org_admin(&org.id, &owner));
+        assert!(client.is_org_member(&org.id, &owner));
+
+        client.add_org_member(&owner, &org.id, &manager, &true);
+        client.add_org_member(&manager, &org.id, &employee, &false);
+        assert_eq!(client.get_org_members(&org.id).len(), 3);
+        assert!(client.is_org_admin(&org.id, &manager));
+        assert!(!client.is_org_admin(&org.id, &employee));
+
+        assert_eq!(client.purchase_org_seats(&super_admin, &org.id, &course_id, &10), 10);
+        assert_eq!(client.purchase_org_seats(&super_admin, &org.id, &course_id, &5), 15);
+        assert_eq!(client.get_org_seats(&org.id, &course_id), 15);
+
+        client.remove_org_member(&owner, &org.id, &employee);
+        assert!(!client.is_org_member(&org.id, &employee));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    fn test_purchase_org_seats_requires_platform_admin() {
+        let env: Env = Env::default();
+        let (client, _super_admin) = setup(&env);
+        let owner: Address = Address::generate(&env);
+        let org: Organization = client.create_org(&owner, &String::from_str(&env, "Acme"));
+
+        client.purchase_org_seats(&owner, &org.id, &String::from_str(&env, "course_1"), &10);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #40)")]
+    fn test_org_owner_cannot_be_removed() {
+        let env: Env = Env::default();
+        let (client, _super_admin) = setup(&env);
+        let owner: Address = Address::generate(&env);
+        let org: Organization = client.create_org(&owner, &String::from_str(&env, "Acme"));
+
+        client.remove_org_member(&owner, &org.id, &owner);
+    }
+}
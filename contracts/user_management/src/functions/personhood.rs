@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Optional proof-of-personhood checks.
+//!
+//! Profiles are already limited to one per address; to also limit them to
+//! one per person, an admin registers a verifier contract answering
+//! `is_verified(user)` and turns the requirement on per feature. Profile
+//! creation is checked here; the feedback contract checks review submission
+//! through `meets_personhood_requirement`.
+
+use skillcert_interfaces::personhood::PersonhoodVerifierClient;
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, Error, UserError};
+use crate::functions::is_admin::is_admin;
+use crate::schema::{DataKey, PersonhoodConfig, PersonhoodFeature};
+
+/// Returns the verifier and the features requiring it; nothing is required
+/// until configured.
+pub fn get_personhood_config(env: &Env) -> PersonhoodConfig {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PersonhoodConfig)
+        .unwrap_or(PersonhoodConfig {
+            verifier: None,
+            require_for_profiles: false,
+            require_for_reviews: false,
+        })
+}
+
+fn set_personhood_config(env: &Env, admin: &Address, config: &PersonhoodConfig) {
+    admin.require_auth();
+    if !is_admin(env.clone(), admin.clone()) {
+        handle_error(env, Error::AccessDenied);
+    }
+    env.storage().persistent().set(&DataKey::PersonhoodConfig, config);
+}
+
+/// Sets the contract proof of personhood is checked against. Admin only.
+pub fn set_personhood_verifier(env: &Env, admin: Address, verifier: Address) {
+    let mut config: PersonhoodConfig = get_personhood_config(env);
+    config.verifier = Some(verifier);
+    set_personhood_config(env, &admin, &config);
+}
+
+/// Turns the personhood requirement of a feature on or off. Admin only; a
+/// verifier must be set before a requirement is turned on.
+pub fn set_personhood_required(env: &Env, admin: Address, feature: PersonhoodFeature, required: bool) {
+    let mut config: PersonhoodConfig = get_personhood_config(env);
+    if required && config.verifier.is_none() {
+        handle_error(env, UserError::PersonhoodVerifierNotSet);
+    }
+    match feature {
+        PersonhoodFeature::ProfileCreation => config.require_for_profiles = required,
+        PersonhoodFeature::ReviewSubmission => config.require_for_reviews = required,
+    }
+    set_personhood_config(env, &admin, &config);
+}
+
+/// Returns whether `user` may use a feature: always when the feature doesn't
+/// require proof of personhood, otherwise when the verifier vouches for them.
+pub fn meets_personhood_requirement(env: &Env, user: &Address, feature: &PersonhoodFeature) -> bool {
+    let config: PersonhoodConfig = get_personhood_config(env);
+    let required: bool = match feature {
+        PersonhoodFeature::ProfileCreation => config.require_for_profiles,
+        PersonhoodFeature::ReviewSubmission => config.require_for_reviews,
+    };
+    match (required, config.verifier) {
+        (true, Some(verifier)) => PersonhoodVerifierClient::new(env, &verifier).is_verified(user),
+        _ => true,
+    }
+}
+
+/// Panics with `PersonhoodNotVerified` unless `user` may use the feature.
+pub(crate) fn require_personhood(env: &Env, user: &Address, feature: PersonhoodFeature) {
+    if !meets_personhood_requirement(env, user, &feature) {
+        handle_error(env, UserError::PersonhoodNotVerified);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{PersonhoodFeature, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+    #[contract]
+    pub struct Verifier;
+
+    #[contractimpl]
+    impl Verifier {
+        pub fn verify(env: Env, user: Address) {
+            env.storage().instance().set(&user, &true);
+        }
+        pub fn is_verified(env: Env, user: Address) -> bool {
+            env.storage().instance().has(&user)
+        }
+    }
+
+    fn profile(env: &Env, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Jane Smith"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+        }
+    }
+
+    #[test]
+    fn test_profile_creation_requires_personhood_when_enabled() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin: Address = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+
+        // A requirement needs a verifier first
+        assert!(client
+            .try_set_personhood_required(&admin, &PersonhoodFeature::ProfileCreation, &true)
+            .is_err());
+        let verifier = VerifierClient::new(&env, &env.register(Verifier, ()));
+        client.set_personhood_verifier(&admin, &verifier.address);
+        client.set_personhood_required(&admin, &PersonhoodFeature::ProfileCreation, &true);
+        let config = client.get_personhood_config();
+        assert!(config.require_for_profiles);
+        assert!(!config.require_for_reviews);
+
+        let user: Address = Address::generate(&env);
+        assert!(client
+            .try_create_user_profile(&user, &profile(&env, "jane@example.com"))
+            .is_err());
+        assert!(client.meets_personhood_requirement(&user, &PersonhoodFeature::ReviewSubmission));
+
+        verifier.verify(&user);
+        client.create_user_profile(&user, &profile(&env, "jane@example.com"));
+    }
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Registrations reported to the platform statistics of the course registry.
+//!
+//! An admin registers the course registry with `set_stats_registry`, which
+//! must in turn allow this contract as a stats reporter. Reports are best
+//! effort: a registry that is missing or rejects them never blocks a
+//! registration.
+
+use skillcert_interfaces::course_registry::CourseRegistryClient;
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::schema::DataKey;
+
+/// Returns the course registry registrations are reported to, if configured.
+pub fn get_stats_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::StatsRegistry)
+}
+
+/// Sets the course registry registrations are reported to. Admin only.
+pub fn set_stats_registry(env: &Env, admin: Address, registry: Address) {
+    admin.require_auth();
+    if !is_admin(env.clone(), admin) {
+        handle_error(env, Error::AccessDenied);
+    }
+    env.storage().instance().set(&DataKey::StatsRegistry, &registry);
+}
+
+/// Reports a new user to the configured course registry, if any.
+pub fn report_user_registered(env: &Env) {
+    if let Some(registry) = get_stats_registry(env) {
+        let _ = CourseRegistryClient::new(env, &registry)
+            .try_record_user_registered(&env.current_contract_address());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use skillcert_interfaces::course_registry::PlatformStats;
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+    #[contract]
+    pub struct CourseRegistry;
+
+    #[contractimpl]
+    impl CourseRegistry {
+        pub fn reject_reports(env: Env) {
+            env.storage().instance().set(&"reject", &true);
+        }
+        pub fn record_user_registered(env: Env, _reporter: Address) -> PlatformStats {
+            if env.storage().instance().has(&"reject") {
+                panic!("reporter not allowed");
+            }
+            let total_users: u32 = Self::total_users(env.clone()) + 1;
+            env.storage().instance().set(&"users", &total_users);
+            PlatformStats {
+                total_users,
+                ..Default::default()
+            }
+        }
+        pub fn total_users(env: Env) -> u32 {
+            env.storage().instance().get(&"users").unwrap_or(0)
+        }
+    }
+
+    fn profile(env: &Env, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Jane Smith"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+        }
+    }
+
+    #[test]
+    fn test_registrations_are_reported_to_the_stats_registry() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let client = UserManagementClient::new(&env, &env.register(UserManagement, ()));
+        let admin: Address = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+
+        // Nothing is reported before a registry is set
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "a@example.com"));
+        let registry = CourseRegistryClient::new(&env, &env.register(CourseRegistry, ()));
+        assert!(client
+            .try_set_stats_registry(&Address::generate(&env), &registry.address)
+            .is_err());
+        client.set_stats_registry(&admin, &registry.address);
+        assert_eq!(client.get_stats_registry(), Some(registry.address.clone()));
+
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "b@example.com"));
+        assert_eq!(registry.total_users(), 1);
+
+        // A rejected report doesn't block the registration
+        registry.reject_reports();
+        let user: Address = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "c@example.com"));
+        assert_eq!(registry.total_users(), 1);
+        assert_eq!(client.get_user_by_id(&user, &user).full_name, String::from_str(&env, "Jane Smith"));
+    }
+}
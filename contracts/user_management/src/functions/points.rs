@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Gamification points and levels.
+//!
+//! Points are awarded by admins or by contracts on the awarder allowlist,
+//! such as the progress and certificates contracts. A user's level is the
+//! number of level thresholds their point total has reached.
+
+use soroban_sdk::{vec, Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::achievements::check_achievements;
+use crate::functions::is_admin::is_admin;
+use crate::schema::DataKey;
+
+/// Points needed for levels 1 to 5 until admins configure their own thresholds.
+const DEFAULT_LEVEL_THRESHOLDS: [u64; 5] = [100, 250, 500, 1_000, 2_500];
+
+/// Maximum number of levels.
+const MAX_LEVELS: u32 = 100;
+
+fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller.clone()) {
+        handle_error(env, Error::AccessDenied);
+    }
+}
+
+/// Returns the contracts allowed to award points besides admins.
+pub fn get_points_awarders(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PointsAwarders)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a contract to, or removes it from, the points awarder allowlist (admins only).
+pub fn set_points_awarder(env: Env, admin: Address, awarder: Address, allowed: bool) {
+    require_admin(&env, &admin);
+
+    let mut awarders: Vec<Address> = get_points_awarders(&env);
+    match (awarders.first_index_of(&awarder), allowed) {
+        (None, true) => awarders.push_back(awarder.clone()),
+        (Some(index), false) => {
+            awarders.remove(index);
+        }
+        _ => return,
+    }
+    env.storage().instance().set(&DataKey::PointsAwarders, &awarders);
+
+    events::points_awarder_set(&env, &awarder, allowed, &admin);
+}
+
+/// Returns the points needed for each level, lowest level first.
+pub fn get_level_thresholds(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LevelThresholds)
+        .unwrap_or_else(|| {
+            let mut thresholds: Vec<u64> = vec![env];
+            for threshold in DEFAULT_LEVEL_THRESHOLDS {
+                thresholds.push_back(threshold);
+            }
+            thresholds
+        })
+}
+
+/// Replaces the level thresholds (admins only).
+///
+/// Thresholds must be positive and strictly increasing; the first one is the
+/// total needed for level 1. Levels are recomputed from point totals, so
+/// existing users move to the level their points earn under the new scale.
+pub fn set_level_thresholds(env: Env, admin: Address, thresholds: Vec<u64>) {
+    require_admin(&env, &admin);
+
+    if thresholds.is_empty() || thresholds.len() > MAX_LEVELS {
+        handle_error(&env, Error::InvalidLevelThresholds);
+    }
+    let mut previous: u64 = 0;
+    for threshold in thresholds.iter() {
+        if threshold <= previous {
+            handle_error(&env, Error::InvalidLevelThresholds);
+        }
+        previous = threshold;
+    }
+    env.storage().persistent().set(&DataKey::LevelThresholds, &thresholds);
+}
+
+/// Returns the point total of a user.
+pub fn get_user_points(env: &Env, user: Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UserPoints(user))
+        .unwrap_or(0)
+}
+
+fn level_for(env: &Env, points: u64) -> u32 {
+    get_level_thresholds(env)
+        .iter()
+        .filter(|threshold| points >= *threshold)
+        .count() as u32
+}
+
+/// Returns the level of a user, 0 until they reach the first threshold.
+pub fn get_user_level(env: &Env, user: Address) -> u32 {
+    level_for(env, get_user_points(env, user))
+}
+
+/// Awards points to a user.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `caller` - An admin or an allowlisted contract (must be authorized).
+/// * `user` - The user receiving the points.
+/// * `amount` - Number of points, greater than 0.
+/// * `reason` - Short label shown in the event, e.g. `lesson` or `cert`.
+///
+/// # Returns
+///
+/// * `u64` - The user's new point total.
+pub fn award_points(env: Env, caller: Address, user: Address, amount: u64, reason: Symbol) -> u64 {
+    caller.require_auth();
+
+    if !(is_admin(env.clone(), caller.clone()) || get_points_awarders(&env).contains(&caller)) {
+        handle_error(&env, Error::AccessDenied);
+    }
+    if amount == 0 {
+        handle_error(&env, Error::InvalidPointsAmount);
+    }
+
+    let previous: u64 = get_user_points(&env, user.clone());
+    let total: u64 = previous
+        .checked_add(amount)
+        .unwrap_or_else(|| handle_error(&env, Error::InvalidPointsAmount));
+    env.storage().persistent().set(&DataKey::UserPoints(user.clone()), &total);
+
+    events::points_awarded(&env, &user, amount, total, &reason, &caller);
+    let level: u32 = level_for(&env, total);
+    if level > level_for(&env, previous) {
+        events::level_up(&env, &user, level);
+    }
+    check_achievements(&env, &user);
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+
+    fn setup(env: &Env) -> (UserManagementClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(env, &contract_id);
+        let super_admin: Address = Address::generate(env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        (client, super_admin)
+    }
+
+    #[test]
+    fn test_award_points_and_levels() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+        let progress_contract: Address = Address::generate(&env);
+        let user: Address = Address::generate(&env);
+
+        assert_eq!(client.get_user_level(&user), 0);
+        assert_eq!(client.award_points(&super_admin, &user, &100, &symbol_short!("bonus")), 100);
+        assert_eq!(client.get_user_level(&user), 1);
+
+        client.set_points_awarder(&super_admin, &progress_contract, &true);
+        assert_eq!(client.get_points_awarders(), vec![&env, progress_contract.clone()]);
+        assert_eq!(client.award_points(&progress_contract, &user, &150, &symbol_short!("lesson")), 250);
+        assert_eq!(client.get_user_points(&user), 250);
+        assert_eq!(client.get_user_level(&user), 2);
+
+        client.set_level_thresholds(&super_admin, &vec![&env, 50, 200, 300]);
+        assert_eq!(client.get_user_level(&user), 2);
+        assert!(client.try_set_level_thresholds(&super_admin, &vec![&env, 50, 50]).is_err());
+        assert!(client.try_award_points(&super_admin, &user, &0, &symbol_short!("bonus")).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    fn test_award_points_requires_allowlisted_caller() {
+        let env: Env = Env::default();
+        let (client, super_admin) = setup(&env);
+        let progress_contract: Address = Address::generate(&env);
+        let user: Address = Address::generate(&env);
+
+        client.set_points_awarder(&super_admin, &progress_contract, &true);
+        client.set_points_awarder(&super_admin, &progress_contract, &false);
+        client.award_points(&progress_contract, &user, &10, &symbol_short!("lesson"));
+    }
+}
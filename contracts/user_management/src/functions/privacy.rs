@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! User privacy preferences.
+//!
+//! Users choose which profile fields appear in user listings and whether
+//! they are listed at all. Hidden fields are blanked out and hidden users
+//! are skipped before filters run, so a filter cannot reveal them either.
+
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::schema::{DataKey, LightProfile, PrivacySettings};
+
+/// Stores the privacy preferences of a registered user.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `user` - The profile owner (must be authorized).
+/// * `settings` - Fields others can see and whether the user is listed.
+pub fn set_privacy_settings(env: Env, user: Address, settings: PrivacySettings) {
+    user.require_auth();
+
+    if !env.storage().persistent().has(&DataKey::UserProfile(user.clone())) {
+        handle_error(&env, Error::UserProfileNotFound);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::PrivacySettings(user.clone()), &settings);
+
+    events::user_updated(&env, &user, &user);
+}
+
+/// Returns the privacy preferences of a user, `None` if they never set any.
+pub fn get_privacy_settings(env: &Env, user: Address) -> Option<PrivacySettings> {
+    env.storage().persistent().get(&DataKey::PrivacySettings(user))
+}
+
+/// Returns the user's lightweight profile as user listings show it, or
+/// `None` if the user has no profile or is not discoverable.
+pub(crate) fn listed_profile(env: &Env, user: Address) -> Option<LightProfile> {
+    let mut profile: LightProfile = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserProfileLight(user.clone()))?;
+
+    if let Some(settings) = get_privacy_settings(env, user) {
+        if !settings.discoverable {
+            return None;
+        }
+        if !settings.show_country {
+            profile.country = None;
+        }
+        if !settings.show_profession {
+            profile.profession = None;
+        }
+    }
+    Some(profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{PrivacySettings, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_user(env: &Env, client: &UserManagementClient<'_>, email: &str) -> Address {
+        let user: Address = Address::generate(env);
+        client.create_user_profile(
+            &user,
+            &UserProfile {
+                full_name: String::from_str(env, "Jane Smith"),
+                contact_email: String::from_str(env, email),
+                profession: Some(String::from_str(env, "Scientist")),
+                country: Some(String::from_str(env, "US")),
+                purpose: None,
+                profile_picture_url: None,
+            },
+        );
+        user
+    }
+
+    #[test]
+    fn test_listings_honor_privacy_settings() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let super_admin: Address = Address::generate(&env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+
+        let private_user: Address = create_user(&env, &client, "private@example.com");
+        let hidden_user: Address = create_user(&env, &client, "hidden@example.com");
+        client.set_privacy_settings(
+            &private_user,
+            &PrivacySettings {
+                show_email: false,
+                show_country: false,
+                show_profession: true,
+                discoverable: true,
+            },
+        );
+        let hidden: PrivacySettings = PrivacySettings {
+            show_email: false,
+            show_country: true,
+            show_profession: true,
+            discoverable: false,
+        };
+        client.set_privacy_settings(&hidden_user, &hidden);
+        assert_eq!(client.get_privacy_settings(&hidden_user), Some(hidden));
+
+        let users = client.list_all_users(&super_admin, &0, &10, &None, &None, &None);
+        assert_eq!(users.len(), 1);
+        let listed = users.get(0).unwrap();
+        assert_eq!(listed.user_address, private_user);
+        assert_eq!(listed.country, None);
+        assert_eq!(listed.profession, Some(String::from_str(&env, "Scientist")));
+
+        // A hidden country cannot be found through the country filter either
+        let by_country = client.list_all_users(&super_admin, &0, &10, &None, &Some(String::from_str(&env, "US")), &None);
+        assert!(by_country.is_empty());
+    }
+}
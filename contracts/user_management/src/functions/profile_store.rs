@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Full user profile storage, split into a public and a private record.
+//!
+//! `DataKey::UserProfile` holds a `PublicUserProfile`; the email lives under
+//! `DataKey::ContactEmail`, so reads that only need the public fields never
+//! load it. Profiles stored before the split hold a full `UserProfile` there
+//! until `migrate_profile_split` moves the email out.
+
+use soroban_sdk::{Address, Env, FromVal, Map, String, Symbol, Val};
+
+use crate::functions::ttl::bump_persistent;
+use crate::schema::{DataKey, PublicUserProfile, UserProfile};
+
+/// Reads the public record of a profile, along with the email of a record
+/// stored before the split.
+fn load_record(env: &Env, user: &Address) -> Option<(PublicUserProfile, Option<String>)> {
+    let record: Map<Symbol, Val> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserProfile(user.clone()))?;
+    if !record.contains_key(Symbol::new(env, "contact_email")) {
+        return Some((PublicUserProfile::from_val(env, &record.to_val()), None));
+    }
+    let legacy: UserProfile = UserProfile::from_val(env, &record.to_val());
+    Some((
+        PublicUserProfile {
+            full_name: legacy.full_name,
+            profession: legacy.profession,
+            country: legacy.country,
+            purpose: legacy.purpose,
+            profile_picture_url: legacy.profile_picture_url,
+        },
+        Some(legacy.contact_email),
+    ))
+}
+
+/// Returns the public fields of a profile.
+pub fn load_public_profile(env: &Env, user: &Address) -> Option<PublicUserProfile> {
+    load_record(env, user).map(|(public, _)| public)
+}
+
+/// Returns the full profile, including the private `contact_email`.
+///
+/// Callers check that the requester may see the email first.
+pub fn load_profile(env: &Env, user: &Address) -> Option<UserProfile> {
+    let (public, legacy_email) = load_record(env, user)?;
+    let contact_email: String = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ContactEmail(user.clone()))
+        .or(legacy_email)
+        .unwrap_or_else(|| String::from_str(env, ""));
+    Some(UserProfile {
+        full_name: public.full_name,
+        contact_email,
+        profession: public.profession,
+        country: public.country,
+        purpose: public.purpose,
+        profile_picture_url: public.profile_picture_url,
+    })
+}
+
+/// Stores a profile as its public and private records.
+pub(crate) fn store_profile(env: &Env, user: &Address, profile: &UserProfile) {
+    let public_key: DataKey = DataKey::UserProfile(user.clone());
+    let private_key: DataKey = DataKey::ContactEmail(user.clone());
+
+    let public: PublicUserProfile = PublicUserProfile {
+        full_name: profile.full_name.clone(),
+        profession: profile.profession.clone(),
+        country: profile.country.clone(),
+        purpose: profile.purpose.clone(),
+        profile_picture_url: profile.profile_picture_url.clone(),
+    };
+    env.storage().persistent().set(&public_key, &public);
+    env.storage().persistent().set(&private_key, &profile.contact_email);
+    bump_persistent(env, &public_key);
+    bump_persistent(env, &private_key);
+}
+
+/// Rewrites a profile stored before the split as its public and private
+/// records. Returns `false` if there was nothing to move.
+pub(crate) fn split_legacy_profile(env: &Env, user: &Address) -> bool {
+    match load_record(env, user) {
+        Some((_, Some(_))) => {
+            let profile: UserProfile = load_profile(env, user).unwrap();
+            store_profile(env, user, &profile);
+            true
+        }
+        _ => false,
+    }
+}
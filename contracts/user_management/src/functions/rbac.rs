@@ -2,6 +2,8 @@
 // Copyright (c) 2025 SkillCert
 
 use crate::error::{handle_error, Error};
+use crate::events;
+use crate::functions::custom_roles::custom_role_permissions;
 use crate::schema::{
     AdminConfig, DataKey, Permission, RolePermissions, UserPermissions, UserRole,
 };
@@ -204,12 +206,15 @@ pub fn set_user_role(env: Env, caller: Address, user: Address, role: UserRole) {
 
     env.storage()
         .persistent()
-        .set(&DataKey::UserRole(user), &role);
+        .set(&DataKey::UserRole(user.clone()), &role);
+
+    events::role_set(&env, &user, &role, &caller);
 }
 
 /// Checks if a user has a specific permission.
 ///
-/// Verifies both role-based permissions and user-specific overrides.
+/// Verifies role-based permissions, including the user's custom role, and
+/// user-specific overrides.
 ///
 /// # Arguments
 ///
@@ -245,6 +250,9 @@ pub fn has_permission(env: &Env, user: &Address, permission: &Permission) -> boo
     if let Some(role_perms) = role_permissions {
         has_role_permission = role_perms.permissions.iter().any(|p| p == *permission);
     }
+    if !has_role_permission {
+        has_role_permission = custom_role_permissions(env, user).contains(permission);
+    }
 
     let user_permissions: Option<UserPermissions> = env
         .storage()
@@ -263,6 +271,25 @@ pub fn has_permission(env: &Env, user: &Address, permission: &Permission) -> boo
     has_role_permission
 }
 
+/// Checks several permissions of a user at once.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `user` - The user's address.
+/// * `permissions` - The permissions to check.
+///
+/// # Returns
+///
+/// * `Vec<bool>` - One entry per requested permission, in the same order.
+pub fn check_permissions(env: &Env, user: &Address, permissions: &Vec<Permission>) -> Vec<bool> {
+    let mut results: Vec<bool> = Vec::new(env);
+    for permission in permissions.iter() {
+        results.push_back(has_permission(env, user, &permission));
+    }
+    results
+}
+
 /// Determines if a user is the super admin.
 ///
 /// # Arguments
@@ -352,12 +379,14 @@ pub fn grant_user_permission(env: Env, caller: Address, user: Address, permissio
     user_permissions.revoked_permissions = new_revoked;
 
     if !user_permissions.granted_permissions.iter().any(|p| p == permission) {
-        user_permissions.granted_permissions.push_back(permission);
+        user_permissions.granted_permissions.push_back(permission.clone());
     }
 
     env.storage()
         .persistent()
-        .set(&DataKey::UserPermissions(user), &user_permissions);
+        .set(&DataKey::UserPermissions(user.clone()), &user_permissions);
+
+    events::permission_granted(&env, &user, &permission, &caller);
 }
 
 /// Revokes a specific permission from a user (admin only).
@@ -400,18 +429,20 @@ pub fn revoke_user_permission(env: Env, caller: Address, user: Address, permissi
     user_permissions.granted_permissions = new_granted;
 
     if !user_permissions.revoked_permissions.iter().any(|p| p == permission) {
-        user_permissions.revoked_permissions.push_back(permission);
+        user_permissions.revoked_permissions.push_back(permission.clone());
     }
 
     env.storage()
         .persistent()
-        .set(&DataKey::UserPermissions(user), &user_permissions);
+        .set(&DataKey::UserPermissions(user.clone()), &user_permissions);
+
+    events::permission_revoked(&env, &user, &permission, &caller);
 }
 
 /// Retrieves all effective permissions for a user.
 ///
-/// Combines role-based permissions with user-specific overrides.
-/// Grants take precedence over revokes, and super admins receive all permissions.
+/// Combines role-based permissions, including the user's custom role, with
+/// user-specific overrides. Grants take precedence over revokes, and super admins receive all permissions.
 ///
 /// # Arguments
 ///
@@ -429,20 +460,25 @@ pub fn get_user_permissions(env: Env, caller: Address, user: Address) -> Vec<Per
         handle_error(&env, Error::AccessDenied);
     }
 
+    effective_permissions(&env, &user)
+}
+
+/// Computes the effective permissions of a user without checking who asks.
+pub(crate) fn effective_permissions(env: &Env, user: &Address) -> Vec<Permission> {
     let initialized: bool = env
         .storage()
         .persistent()
         .get(&DataKey::DefaultRolePermissions)
         .unwrap_or(false);
     if !initialized {
-        initialize_default_permissions(&env);
+        initialize_default_permissions(env);
     }
 
-    let mut final_permissions = Vec::new(&env);
+    let mut final_permissions = Vec::new(env);
 
-    if is_super_admin(&env, &user) {
+    if is_super_admin(env, user) {
         return Vec::from_array(
-            &env,
+            env,
             [
                 Permission::ViewUsers,
                 Permission::EditUsers,
@@ -463,7 +499,7 @@ pub fn get_user_permissions(env: Env, caller: Address, user: Address) -> Vec<Per
         );
     }
 
-    let user_role = get_user_role(&env, &user);
+    let user_role = get_user_role(env, user);
     let role_permissions: Option<RolePermissions> = env
         .storage()
         .persistent()
@@ -475,10 +511,16 @@ pub fn get_user_permissions(env: Env, caller: Address, user: Address) -> Vec<Per
         }
     }
 
+    for permission in custom_role_permissions(env, user).iter() {
+        if !final_permissions.contains(&permission) {
+            final_permissions.push_back(permission);
+        }
+    }
+
     let user_permissions: Option<UserPermissions> = env
         .storage()
         .persistent()
-        .get(&DataKey::UserPermissions(user));
+        .get(&DataKey::UserPermissions(user.clone()));
 
     if let Some(user_perms) = user_permissions {
         for permission in user_perms.granted_permissions.iter() {
@@ -487,7 +529,7 @@ pub fn get_user_permissions(env: Env, caller: Address, user: Address) -> Vec<Per
             }
         }
 
-        let mut filtered_permissions = Vec::new(&env);
+        let mut filtered_permissions = Vec::new(env);
         for permission in final_permissions.iter() {
             if !user_perms.revoked_permissions.iter().any(|p| p == permission) {
                 filtered_permissions.push_back(permission);
@@ -12,6 +12,7 @@ use crate::schema::{
     REQUIRED_LOWERCASE, */
 };
 use soroban_sdk::{Env, String};
+use validation::validate_email;
 
 pub fn save_profile(
     env: Env,
@@ -37,6 +38,10 @@ pub fn save_profile(
         handle_error(&env, Error::RequiredFieldMissing);
     }
 
+    if !validate_email(&email) {
+        handle_error(&env, Error::InvalidEmailFormat);
+    }
+
     // TODO: Implement email uniqueness check
     // This function needs to be updated to use the correct schema
     // Note: Uniqueness is enforced elsewhere in create_user_profile
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Social links of a user.
+//!
+//! Links are stored under their own key rather than as a `UserProfile`
+//! field, so profiles written by earlier versions still decode.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::functions::ttl::bump_persistent;
+use crate::functions::utils::profile_utils::validate_social_links;
+use crate::schema::{DataKey, SocialLink};
+
+/// Returns the social links of a user; empty if none were set.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `user` - The profile owner.
+pub fn get_social_links(env: &Env, user: Address) -> Vec<SocialLink> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SocialLinks(user))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Validates and replaces the social links of a user; an empty list removes them.
+///
+/// Callers check that the profile exists and the caller may edit it.
+pub(crate) fn set_social_links(env: &Env, user: &Address, links: &Vec<SocialLink>) {
+    validate_social_links(env, links);
+
+    let key: DataKey = DataKey::SocialLinks(user.clone());
+    if links.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, links);
+        bump_persistent(env, &key);
+    }
+}
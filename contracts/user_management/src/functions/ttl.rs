@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol, Val};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::schema::{DataKey, TtlConfig, DEFAULT_TTL_EXTEND_TO, DEFAULT_TTL_THRESHOLD};
+
+const SET_TTL_CONFIG_EVENT: Symbol = symbol_short!("setTtlCfg");
+
+/// Returns the TTL thresholds, falling back to the defaults.
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        })
+}
+
+/// Updates the TTL thresholds. Admin only.
+pub fn set_ttl_config(env: Env, caller: Address, threshold: u32, extend_to: u32) {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller.clone()) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    if threshold == 0 || threshold >= extend_to || extend_to > env.storage().max_ttl() {
+        handle_error(&env, Error::InvalidTtlConfig)
+    }
+
+    let config: TtlConfig = TtlConfig { threshold, extend_to };
+    env.storage().instance().set(&DataKey::TtlConfig, &config);
+
+    env.events()
+        .publish((SET_TTL_CONFIG_EVENT,), (caller, threshold, extend_to));
+}
+
+/// Extends the TTL of a persistent entry if it exists.
+pub fn bump_persistent<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    if env.storage().persistent().has(key) {
+        let config: TtlConfig = get_ttl_config(env);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, config.threshold, config.extend_to);
+    }
+}
+
+/// Extends the TTL of the contract instance, which holds the configuration.
+pub fn bump_instance(env: &Env) {
+    let config: TtlConfig = get_ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Extends the TTL of every entry making up a user's profile.
+pub fn extend_profile_ttl(env: Env, user: Address) {
+    if !env.storage().persistent().has(&DataKey::UserProfile(user.clone())) {
+        handle_error(&env, Error::UserProfileNotFound)
+    }
+
+    bump_persistent(&env, &DataKey::UserProfile(user.clone()));
+    bump_persistent(&env, &DataKey::ContactEmail(user.clone()));
+    bump_persistent(&env, &DataKey::UserProfileLight(user.clone()));
+    bump_persistent(&env, &DataKey::UserRole(user.clone()));
+    bump_persistent(&env, &DataKey::UserPermissions(user.clone()));
+    bump_persistent(&env, &DataKey::SocialLinks(user));
+    bump_persistent(&env, &DataKey::UsersIndex);
+    bump_instance(&env);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{DataKey, UserProfile, DEFAULT_TTL_EXTEND_TO};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::storage::Persistent as _;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_extend_profile_ttl() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+
+        let user = Address::generate(&env);
+        let profile = UserProfile {
+            full_name: String::from_str(&env, "Jane Doe"),
+            contact_email: String::from_str(&env, "jane@example.com"),
+            profession: None,
+            country: Some(String::from_str(&env, "US")),
+            purpose: None,
+            profile_picture_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+
+        let extend_to: u32 = DEFAULT_TTL_EXTEND_TO * 2;
+        client.set_ttl_config(&admin, &(DEFAULT_TTL_EXTEND_TO + 1), &extend_to);
+        client.extend_profile_ttl(&user);
+
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            assert_eq!(storage.get_ttl(&DataKey::UserProfile(user.clone())), extend_to);
+            assert_eq!(storage.get_ttl(&DataKey::UserProfileLight(user.clone())), extend_to);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #37)")]
+    fn test_set_ttl_config_invalid() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+
+        client.set_ttl_config(&admin, &0, &1000);
+    }
+}
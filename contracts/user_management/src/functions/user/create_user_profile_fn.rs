@@ -1,12 +1,15 @@
 
-use soroban_sdk::{Address, Env, Symbol, symbol_short};
+use soroban_sdk::{Address, Env};
 use crate::schema::{
     UserProfile, DataKey
 };
+use crate::events;
 use crate::error::{Error, handle_error};
+use crate::functions::profile_store::store_profile;
+use crate::functions::utils::storage_utils::{is_email_unique, register_email};
+use validation::{validate_country_code, validate_email, validate_url};
 
 // Execution constants
-const USER_CREATED_EVENT: Symbol = symbol_short!("usrCrtd");
 const MAX_STRING_LENGTH: u32 = 320;
 
 
@@ -32,33 +35,35 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
         handle_error(&env, Error::InvalidField)
     }
 
+    if !validate_email(&profile.contact_email) {
+        handle_error(&env, Error::InvalidEmailFormat)
+    }
+
     // Ensure email uniqueness
-    if !env.storage().persistent()
-        .has(&DataKey::EmailIndex(profile.contact_email.clone())) {
+    if !is_email_unique(&env, &profile.contact_email) {
         handle_error(&env, Error::EmailAlreadyExists)
     }
 
-    // TODO: Validate optional fields if provided
-    
+    // Validate optional fields if provided
+    if let Some(ref country) = profile.country {
+        if !country.is_empty() && !validate_country_code(country) {
+            handle_error(&env, Error::InvalidCountryCode)
+        }
+    }
+    if let Some(ref url) = profile.profile_picture_url {
+        if !url.is_empty() && !validate_url(url) {
+            handle_error(&env, Error::InvalidProfilePicURL)
+        }
+    }
+
     // Store the profile using persistent storage
-    env.storage().persistent().set(&storage_key, &profile);
+    store_profile(&env, &user, &profile);
 
     // Register email for uniqueness checking
-    env.storage().persistent()
-        .set(&DataKey::EmailIndex(profile.contact_email.clone()), &user);
-
-
-    // Emit user creation audit event with detailed information
-    env.events().publish(
-        (USER_CREATED_EVENT, &user),
-        (
-            user.clone(),
-            profile.full_name.clone(),
-            profile.contact_email.clone(),
-            profile.profession.clone(),
-            profile.country.clone(),
-        ),
-    );
+    register_email(&env, &profile.contact_email, &user);
+
+
+    events::user_created(&env, &user, &profile);
 
     profile
 }
@@ -1,5 +1,6 @@
 use crate::error::{Error};
-use crate::schema::{UserProfile, DataKey};
+use crate::functions::profile_store::load_profile;
+use crate::schema::UserProfile;
 use soroban_sdk::{Address, Env};
 
 /// Get User Profile
@@ -11,12 +12,7 @@ pub fn get_user_profile(env: Env, user: Address) -> Result<UserProfile, Error> {
     user.require_auth();
 
     // Fetch user profile from storage
-    let storage_key: DataKey = DataKey::UserProfile(user.clone());
-    match env
-        .storage()
-        .persistent()
-        .get::<DataKey, UserProfile>(&storage_key)
-    {
+    match load_profile(&env, &user) {
         Some(profile) => Ok(profile),
         None => Err(Error::UserNotFound),
     }
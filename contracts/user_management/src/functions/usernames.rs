@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Unique username handles.
+//!
+//! Handles are normalized to lowercase before they are validated and
+//! indexed, so `Alice` and `alice` are the same handle. A user holds at most
+//! one handle; claiming another releases the previous one.
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::events;
+use crate::schema::{DataKey, MAX_USERNAME_LENGTH, MIN_USERNAME_LENGTH};
+
+/// Lowercases a handle, returning `None` unless it has 3-30 characters from
+/// `a-z`, `0-9` and `_`.
+fn normalize_username(env: &Env, handle: &String) -> Option<String> {
+    let len: u32 = handle.len();
+    if !(MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&len) {
+        return None;
+    }
+
+    let mut buffer: [u8; MAX_USERNAME_LENGTH as usize] = [0u8; MAX_USERNAME_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buffer[..len as usize];
+    handle.copy_into_slice(bytes);
+    for byte in bytes.iter_mut() {
+        byte.make_ascii_lowercase();
+        if !(byte.is_ascii_lowercase() || byte.is_ascii_digit() || *byte == b'_') {
+            return None;
+        }
+    }
+    Some(String::from_bytes(env, bytes))
+}
+
+/// Returns the handle of a user, if they claimed one.
+pub fn get_username(env: &Env, user: Address) -> Option<String> {
+    env.storage().persistent().get(&DataKey::Username(user))
+}
+
+/// Returns the user holding a handle, matched case-insensitively.
+pub fn get_user_by_username(env: &Env, handle: String) -> Option<Address> {
+    let username: String = normalize_username(env, &handle)?;
+    env.storage().persistent().get(&DataKey::UsernameIndex(username))
+}
+
+/// Claims a handle for a registered user.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment.
+/// * `user` - The user claiming the handle (must be authorized).
+/// * `handle` - The handle; letters are stored lowercase.
+///
+/// # Returns
+///
+/// * `String` - The normalized handle.
+pub fn claim_username(env: Env, user: Address, handle: String) -> String {
+    user.require_auth();
+
+    if !env.storage().persistent().has(&DataKey::UserProfile(user.clone())) {
+        handle_error(&env, Error::UserProfileNotFound);
+    }
+    let username: String =
+        normalize_username(&env, &handle).unwrap_or_else(|| handle_error(&env, Error::InvalidUsername));
+
+    let index_key: DataKey = DataKey::UsernameIndex(username.clone());
+    match env.storage().persistent().get::<DataKey, Address>(&index_key) {
+        Some(holder) if holder == user => return username,
+        Some(_) => handle_error(&env, Error::UsernameTaken),
+        None => {}
+    }
+
+    if let Some(previous) = get_username(&env, user.clone()) {
+        env.storage().persistent().remove(&DataKey::UsernameIndex(previous));
+    }
+    env.storage().persistent().set(&index_key, &user);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Username(user.clone()), &username);
+
+    events::username_changed(&env, &user, Some(username.clone()));
+
+    username
+}
+
+/// Releases the handle of a user so anyone can claim it.
+pub fn release_username(env: Env, user: Address) {
+    user.require_auth();
+
+    let username: String =
+        get_username(&env, user.clone()).unwrap_or_else(|| handle_error(&env, Error::UsernameNotFound));
+    env.storage().persistent().remove(&DataKey::UsernameIndex(username));
+    env.storage().persistent().remove(&DataKey::Username(user.clone()));
+
+    events::username_changed(&env, &user, None);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_user(env: &Env, client: &UserManagementClient<'_>, email: &str) -> Address {
+        let user: Address = Address::generate(env);
+        client.create_user_profile(
+            &user,
+            &UserProfile {
+                full_name: String::from_str(env, "Jane Smith"),
+                contact_email: String::from_str(env, email),
+                profession: None,
+                country: None,
+                purpose: None,
+                profile_picture_url: None,
+            },
+        );
+        user
+    }
+
+    #[test]
+    fn test_claim_and_release_username() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let alice: Address = create_user(&env, &client, "alice@example.com");
+        let bob: Address = create_user(&env, &client, "bob@example.com");
+
+        let handle: String = client.claim_username(&alice, &String::from_str(&env, "Alice_01"));
+        assert_eq!(handle, String::from_str(&env, "alice_01"));
+        assert_eq!(client.get_username(&alice), Some(handle.clone()));
+        assert_eq!(client.get_user_by_username(&String::from_str(&env, "ALICE_01")), Some(alice.clone()));
+
+        // Taken handles, in any case, and malformed handles are rejected
+        assert!(client.try_claim_username(&bob, &String::from_str(&env, "alice_01")).is_err());
+        assert!(client.try_claim_username(&bob, &String::from_str(&env, "al")).is_err());
+        assert!(client.try_claim_username(&bob, &String::from_str(&env, "bob smith")).is_err());
+
+        // Claiming a new handle frees the previous one
+        client.claim_username(&alice, &String::from_str(&env, "alice"));
+        assert_eq!(client.get_user_by_username(&handle), None);
+        client.claim_username(&bob, &handle);
+        assert_eq!(client.get_user_by_username(&handle), Some(bob.clone()));
+
+        client.release_username(&bob);
+        assert_eq!(client.get_username(&bob), None);
+        assert_eq!(client.get_user_by_username(&handle), None);
+        assert!(client.try_release_username(&bob).is_err());
+    }
+}
@@ -3,4 +3,4 @@
 
 pub mod storage_utils;
 pub mod rate_limit_utils;
-pub mod url_validation;
+pub mod profile_utils;
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, Vec};
+use validation::validate_url;
+
+use crate::error::{handle_error, Error};
+use crate::schema::{SocialLink, MAX_SOCIAL_LINKS, MAX_SOCIAL_LINK_URL_LENGTH, MAX_SOCIAL_PLATFORM_LENGTH};
+
+/// Validates the social links of a profile.
+///
+/// Panics with `InvalidSocialLinks` if there are more than `MAX_SOCIAL_LINKS`,
+/// a platform is empty, too long or listed twice, or a URL is malformed or
+/// longer than `MAX_SOCIAL_LINK_URL_LENGTH`.
+pub fn validate_social_links(env: &Env, links: &Vec<SocialLink>) {
+    if links.len() > MAX_SOCIAL_LINKS {
+        handle_error(env, Error::InvalidSocialLinks);
+    }
+
+    for (index, link) in links.iter().enumerate() {
+        if link.platform.is_empty() || link.platform.len() > MAX_SOCIAL_PLATFORM_LENGTH {
+            handle_error(env, Error::InvalidSocialLinks);
+        }
+        if link.url.len() > MAX_SOCIAL_LINK_URL_LENGTH || !validate_url(&link.url) {
+            handle_error(env, Error::InvalidSocialLinks);
+        }
+        if links.iter().skip(index + 1).any(|other| other.platform == link.platform) {
+            handle_error(env, Error::InvalidSocialLinks);
+        }
+    }
+}
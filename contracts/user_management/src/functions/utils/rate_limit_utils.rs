@@ -2,13 +2,17 @@
 // Copyright (c) 2025 SkillCert
 
 use crate::error::{handle_error, Error};
-use crate::schema::{DataKey, RateLimitData, RateLimitConfig, DEFAULT_RATE_LIMIT_WINDOW, DEFAULT_MAX_USER_CREATIONS_PER_WINDOW};
-use soroban_sdk::{Address, Env};
+use crate::functions::is_admin::is_admin;
+use crate::schema::{AdminConfig, DataKey, RateLimitData, RateLimitConfig, DEFAULT_RATE_LIMIT_WINDOW, DEFAULT_MAX_USER_CREATIONS_PER_WINDOW, LEDGER_CLOSE_SECONDS};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const SET_RATE_LIMIT_EVENT: Symbol = symbol_short!("setRtLim");
 
 /// Check if the user has exceeded the rate limit for user creation operations.
 ///
-/// This function validates if the caller can perform a user creation operation
-/// based on the configured rate limiting rules.
+/// Ledgers are grouped in fixed windows of `window_seconds` worth of ledgers.
+/// The counter of the current window lives in temporary storage and expires
+/// with the window.
 ///
 /// # Arguments
 /// * `env` - The Soroban environment
@@ -18,29 +22,23 @@ use soroban_sdk::{Address, Env};
 /// # Panics
 /// * If rate limit is exceeded
 pub fn check_user_creation_rate_limit(env: &Env, user: &Address, rate_config: &RateLimitConfig) {
-    let current_time = env.ledger().timestamp();
+    let window_ledgers: u32 = window_ledgers(rate_config);
+    let window: u64 = (env.ledger().sequence() / window_ledgers) as u64;
     let rate_limit_key = DataKey::RateLimit(user.clone());
-    
-    // Get existing rate limit data or create new one
+
+    // Counts from a previous window no longer apply
     let mut rate_data = match env
         .storage()
-        .persistent()
+        .temporary()
         .get::<DataKey, RateLimitData>(&rate_limit_key)
     {
-        Some(data) => data,
-        None => RateLimitData {
+        Some(data) if data.window_start == window => data,
+        _ => RateLimitData {
             count: 0,
-            window_start: current_time,
-        }
+            window_start: window,
+        },
     };
 
-    // Check if we need to reset the window
-    if current_time >= rate_data.window_start + rate_config.window_seconds {
-        // Reset the window
-        rate_data.count = 0;
-        rate_data.window_start = current_time;
-    }
-
     // Check if user has exceeded the rate limit
     if rate_data.count >= rate_config.max_operations_per_window {
         handle_error(env, Error::RateLimitExceeded);
@@ -49,8 +47,50 @@ pub fn check_user_creation_rate_limit(env: &Env, user: &Address, rate_config: &R
     // Increment the count and save
     rate_data.count += 1;
     env.storage()
-        .persistent()
+        .temporary()
         .set(&rate_limit_key, &rate_data);
+    env.storage()
+        .temporary()
+        .extend_ttl(&rate_limit_key, window_ledgers, window_ledgers);
+}
+
+/// Converts the configured window to a number of ledgers, at least 1.
+fn window_ledgers(rate_config: &RateLimitConfig) -> u32 {
+    let ledgers: u64 = rate_config.window_seconds.div_ceil(LEDGER_CLOSE_SECONDS);
+    ledgers.clamp(1, u32::MAX as u64) as u32
+}
+
+/// Update the rate limiting configuration of user creation (admin only).
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `caller` - An admin (must be authorized)
+/// * `rate_config` - The new configuration
+///
+/// # Panics
+/// * If the system is not initialized
+/// * If the caller is not an admin
+/// * If the window or the maximum is 0
+pub fn set_rate_limit_config(env: Env, caller: Address, rate_config: RateLimitConfig) {
+    caller.require_auth();
+
+    let mut config: AdminConfig = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AdminConfig)
+        .unwrap_or_else(|| handle_error(&env, Error::SystemNotInitialized));
+    if !is_admin(env.clone(), caller.clone()) {
+        handle_error(&env, Error::AccessDenied);
+    }
+    if rate_config.window_seconds == 0 || rate_config.max_operations_per_window == 0 {
+        handle_error(&env, Error::InvalidField);
+    }
+
+    config.rate_limit_config = rate_config.clone();
+    env.storage().persistent().set(&DataKey::AdminConfig, &config);
+
+    env.events()
+        .publish((SET_RATE_LIMIT_EVENT,), (caller, rate_config));
 }
 
 /// Get the default rate limiting configuration for user operations.
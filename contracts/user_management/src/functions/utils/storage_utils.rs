@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use crate::error::{handle_error, Error};
+use crate::functions::ttl::bump_persistent;
 use crate::schema::DataKey;
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+use validation::MAX_EMAIL_LENGTH;
 
 /// Validates string content for security and length constraints
 /// Returns true if the string is valid, false otherwise
@@ -13,39 +16,88 @@ pub fn validate_string_content(_env: &Env, content: &String, max_length: usize)
     true
 }
 
-/// Validates email format using basic checks
-/// Returns true if email appears to be valid format
-pub fn validate_email_format(email: &String) -> bool {
-    // Basic email validation for Soroban environment
-    // Check minimum and maximum length
-    if email.len() < 5 || email.len() > 320 {
-        return false;
-    }
-    
-    // For testing purposes, reject "invalid-email" (13 characters, no @)
-    if email.len() == 13 {
-        return false;
+/// Computes the index hash for an email address
+/// The email is lowercased (ASCII) before hashing so that case variants collide
+/// Emails longer than `MAX_EMAIL_LENGTH` are rejected with `InvalidEmailFormat`
+pub fn email_hash(env: &Env, email: &String) -> BytesN<32> {
+    if email.len() > MAX_EMAIL_LENGTH {
+        handle_error(env, Error::InvalidEmailFormat)
     }
-    
-    // In a production environment, you would implement proper email validation
-    // For now, we accept emails that meet basic length requirements
-    true
+    let len: usize = email.len() as usize;
+    let mut buffer: [u8; MAX_EMAIL_LENGTH as usize] = [0u8; MAX_EMAIL_LENGTH as usize];
+    let slice: &mut [u8] = &mut buffer[..len];
+    email.copy_into_slice(slice);
+    slice.make_ascii_lowercase();
+
+    env.crypto().sha256(&Bytes::from_slice(env, slice)).into()
+}
+
+/// Resolve the address an email is registered to, if any
+/// Falls back to the legacy unhashed index for emails not migrated yet
+pub fn get_email_owner(env: &Env, email: &String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Address>(&DataKey::EmailHash(email_hash(env, email)))
+        .or_else(|| {
+            env.storage()
+                .persistent()
+                .get::<DataKey, Address>(&DataKey::EmailIndex(email.clone()))
+        })
 }
 
 /// Check if email is unique across all users
 /// Returns true if email is unique (not already taken)
 pub fn is_email_unique(env: &Env, email: &String) -> bool {
-    let email_key = DataKey::EmailIndex(email.clone());
-    !env.storage().persistent().has(&email_key)
+    get_email_owner(env, email).is_none()
+}
+
+/// Check if email is free for the given user
+/// Returns true if the email is unclaimed or already registered to this user
+pub fn is_email_available_for(env: &Env, email: &String, user_address: &Address) -> bool {
+    match get_email_owner(env, email) {
+        Some(owner) => owner == *user_address,
+        None => true,
+    }
 }
 
 /// Register email in the email index to prevent duplicates
-/// Associates the email with the user address
+/// Associates the email hash with the user address
 pub fn register_email(env: &Env, email: &String, user_address: &Address) {
-    let email_key = DataKey::EmailIndex(email.clone());
+    let email_key = DataKey::EmailHash(email_hash(env, email));
     env.storage().persistent().set(&email_key, user_address);
 }
 
+/// Release an email from the email index so it can be claimed again
+pub fn release_email(env: &Env, email: &String) {
+    let email_key = DataKey::EmailHash(email_hash(env, email));
+    env.storage().persistent().remove(&email_key);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EmailIndex(email.clone()));
+}
+
+/// Resolve the address registered for an email hash, if any
+pub fn get_address_by_email_hash(env: &Env, hash: &BytesN<32>) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Address>(&DataKey::EmailHash(hash.clone()))
+}
+
+/// Record when a user's profile was created
+pub fn set_registered_at(env: &Env, user_address: &Address, timestamp: u64) {
+    let key: DataKey = DataKey::RegisteredAt(user_address.clone());
+    env.storage().persistent().set(&key, &timestamp);
+    bump_persistent(env, &key);
+}
+
+/// When a user's profile was created; 0 for profiles created before it was recorded
+pub fn get_registered_at(env: &Env, user_address: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RegisteredAt(user_address.clone()))
+        .unwrap_or(0)
+}
+
 /// Add user to the users index for listing purposes
 /// Maintains a list of all registered user addresses
 pub fn add_to_users_index(env: &Env, user_address: &Address) {
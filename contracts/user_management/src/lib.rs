@@ -1,12 +1,14 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+#![allow(clippy::too_many_arguments)]
 #![no_std]
 
 /// Contract version for tracking deployments and upgrades
 pub const VERSION: &str = "1.0.0";
 
 pub mod error;
+pub mod events;
 pub mod functions;
 pub mod models;
 pub mod schema;
@@ -14,9 +16,10 @@ pub mod schema;
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
-use crate::schema::{AdminConfig, LightProfile, PaginatedLightProfiles, PaginationParams, ProfileUpdateParams, UserFilter, UserProfile, UserRole, UserStatus};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
+use crate::schema::{Achievement, AchievementCriteria, AdminConfig, CustomRole, Organization, RateLimitConfig, TtlConfig, LightProfile, PaginatedLightProfiles, PaginationParams, Permission, PrivacySettings, ProfileUpdateParams, SocialLink, UnlockedAchievement, UserFilter, UserProfile, UserRole, UserStatus};
 use crate::error::Error;
+use crate::functions::contract_versioning::{MigrationStatus, VersionRecord};
 
 /// User Management Contract
 ///
@@ -133,6 +136,8 @@ impl UserManagement {
     /// * If user profile already exists
     /// * If email format is invalid
     /// * If validation rules are violated
+    /// * If the address exceeded the profile creation rate limit (`RateLimitExceeded`)
+    /// * If proof of personhood is required for profiles and the verifier doesn't vouch for the user (`PersonhoodNotVerified`)
     ///
     /// # Events
     /// Emits a user creation event upon successful creation
@@ -165,7 +170,8 @@ impl UserManagement {
     ///
     /// Updates an existing user profile with new values for allowed fields.
     /// Only the user themselves or administrators can perform updates.
-    /// Email and role fields cannot be updated through this function.
+    /// The role field cannot be updated through this function, and a new
+    /// email must not already be registered to another user.
     ///
     /// # Arguments
     /// * `env` - Soroban environment
@@ -181,6 +187,7 @@ impl UserManagement {
     /// * If user profile doesn't exist
     /// * If caller lacks permission to edit
     /// * If any field validation fails
+    /// * If the new email is already registered to another user
     /// * If user is inactive
     ///
     /// # Events
@@ -202,9 +209,13 @@ impl UserManagement {
     /// # Edge Cases
     ///
     /// * **Partial updates**: Only provided fields are updated, others remain unchanged
-    /// * **Admin override**: Admins can edit any user's profile except email/role
+    /// * **Admin override**: Admins can edit any user's profile except role
     /// * **Inactive user**: Cannot edit profiles of inactive users
     /// * **Invalid updates**: Empty strings or invalid data will cause panic
+    /// * **Profile picture**: An empty URL removes the picture
+    /// * **Social links**: A provided list replaces the stored links; at most 5
+    ///   links with unique platforms and http(s) URLs of up to 200 characters.
+    ///   An empty list removes them. They are read with `get_social_links`
     pub fn edit_user_profile(
         env: Env,
         caller: Address,
@@ -214,6 +225,33 @@ impl UserManagement {
         functions::edit_user_profile::edit_user_profile(env, caller, user_id, updates)
     }
 
+    /// Resolve the account registered for an email hash (admin or support only).
+    ///
+    /// Emails are indexed by the SHA-256 hash of the lowercased address, so support
+    /// staff can look up an account without a plaintext email index.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the lookup (must be admin or have `ProvideSupport`)
+    /// * `email_hash` - SHA-256 hash of the lowercased email address
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The address registered with this email, if any
+    ///
+    /// # Panics
+    /// * If caller authentication fails
+    /// * If caller is neither an admin nor support staff
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let hash = env.crypto().sha256(&Bytes::from_slice(&env, b"alice@example.com"));
+    /// let owner = contract.get_user_by_email_hash(env.clone(), support_address, hash.into());
+    /// ```
+    pub fn get_user_by_email_hash(env: Env, caller: Address, email_hash: BytesN<32>) -> Option<Address> {
+        functions::get_user_by_email_hash::get_user_by_email_hash(env, caller, email_hash)
+    }
+
     /// Check if an address has admin privileges.
     ///
     /// This function is used by other contracts to verify admin status
@@ -250,6 +288,827 @@ impl UserManagement {
         functions::is_admin::is_admin(env, who)
     }
 
+    /// Check if a user holds a permission.
+    ///
+    /// Combines the permissions of the user's role with the permissions
+    /// granted to or revoked from the user individually. Other contracts,
+    /// such as the course registry, call this to gate their operations.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address to check
+    /// * `permission` - The permission required
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user holds the permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let can_create = contract.has_permission(env.clone(), user_address, Permission::CreateCourses);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No role set**: Users default to the `Student` role
+    /// * **Super admin**: Always returns `true`
+    /// * **Revoked permission**: An individual revocation overrides the role
+    pub fn has_permission(env: Env, user: Address, permission: Permission) -> bool {
+        functions::rbac::has_permission(&env, &user, &permission)
+    }
+
+    /// Check several permissions of a user in one call
+    ///
+    /// Lets frontends decide which actions to show without one
+    /// `has_permission` call per permission.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address to check
+    /// * `permissions` - The permissions to check
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<bool>` - Whether the user holds each permission, in request order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let flags = contract.check_permissions(
+    ///     env.clone(),
+    ///     user,
+    ///     vec![&env, Permission::CreateCourses, Permission::ViewAnalytics],
+    /// );
+    /// let can_create = flags.get(0).unwrap();
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty list**: Returns an empty vector
+    /// * **Repeated permission**: Answered once per occurrence
+    pub fn check_permissions(env: Env, user: Address, permissions: Vec<Permission>) -> Vec<bool> {
+        functions::rbac::check_permissions(&env, &user, &permissions)
+    }
+
+    /// Assign a role to a user (requires `ManageAdmins`)
+    ///
+    /// The role decides the user's default permissions; individual grants and
+    /// revocations made with `grant_user_permission` and `revoke_user_permission`
+    /// still apply on top of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - Address performing the change (must hold `ManageAdmins`)
+    /// * `user` - Address whose role is set
+    /// * `role` - The role to assign
+    ///
+    /// # Panics
+    ///
+    /// * If caller authentication fails
+    /// * If caller does not hold `ManageAdmins` (`AccessDenied`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("rbac", "role_set", user)` with the new role and the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_user_role(env.clone(), super_admin, instructor, UserRole::Instructor);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Default role**: Users without a role are treated as `Student`
+    /// * **Super admin**: The super admin keeps every permission whatever its role
+    /// * **Admin list**: Setting `Admin` makes `is_admin` return `true` for the user
+    pub fn set_user_role(env: Env, caller: Address, user: Address, role: UserRole) {
+        functions::rbac::set_user_role(env, caller, user, role)
+    }
+
+    /// Grant a single permission to a user (requires `ManageAdmins`)
+    ///
+    /// The grant applies whatever the user's role and clears an earlier
+    /// revocation of the same permission.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - Address performing the change (must hold `ManageAdmins`)
+    /// * `user` - Address receiving the permission
+    /// * `permission` - The permission to grant
+    ///
+    /// # Panics
+    ///
+    /// * If caller authentication fails
+    /// * If caller does not hold `ManageAdmins` (`AccessDenied`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("rbac", "perm_grnt", user)` with the permission and the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.grant_user_permission(env.clone(), super_admin, user, Permission::CreateCourses);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already granted**: Granting twice keeps a single entry
+    /// * **Previously revoked**: The revocation is lifted
+    pub fn grant_user_permission(env: Env, caller: Address, user: Address, permission: Permission) {
+        functions::rbac::grant_user_permission(env, caller, user, permission)
+    }
+
+    /// Revoke a single permission from a user (requires `ManageAdmins`)
+    ///
+    /// The revocation applies whatever the user's role and clears an earlier
+    /// individual grant of the same permission.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - Address performing the change (must hold `ManageAdmins`)
+    /// * `user` - Address losing the permission
+    /// * `permission` - The permission to revoke
+    ///
+    /// # Panics
+    ///
+    /// * If caller authentication fails
+    /// * If caller does not hold `ManageAdmins` (`AccessDenied`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("rbac", "perm_rvk", user)` with the permission and the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.revoke_user_permission(env.clone(), super_admin, instructor, Permission::CreateCourses);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Role permission**: Removes a permission the user's role would give
+    /// * **Super admin**: Revocations do not affect the super admin
+    pub fn revoke_user_permission(env: Env, caller: Address, user: Address, permission: Permission) {
+        functions::rbac::revoke_user_permission(env, caller, user, permission)
+    }
+
+    /// Get the effective permissions of a user
+    ///
+    /// Combines the permissions of the user's role with individual grants
+    /// and revocations.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - Address performing the call (the user, or holding `ViewUsers`)
+    /// * `user` - Address whose permissions are listed
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Permission>` - The permissions the user currently holds
+    ///
+    /// # Panics
+    ///
+    /// * If caller authentication fails
+    /// * If caller is someone else without `ViewUsers` (`AccessDenied`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let permissions = contract.get_user_permissions(env.clone(), user, user);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Super admin**: Returns every permission
+    /// * **No role set**: Returns the `Student` permissions
+    pub fn get_user_permissions(env: Env, caller: Address, user: Address) -> Vec<Permission> {
+        functions::rbac::get_user_permissions(env, caller, user)
+    }
+
+    /// Define a custom role (requires `ManageAdmins`)
+    ///
+    /// Lets institutions model roles such as "Teaching Assistant" without a
+    /// contract upgrade. Built-in roles keep working as defaults; a custom
+    /// role adds its permissions to those of the user's built-in role.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - Address defining the role (must hold `ManageAdmins`)
+    /// * `name` - Display name of the role (1-50 characters)
+    /// * `permissions` - Permissions granted by the role
+    ///
+    /// # Returns
+    ///
+    /// * `CustomRole` - The stored role, with its identifier
+    ///
+    /// # Panics
+    ///
+    /// * If admin authentication fails
+    /// * If admin does not hold `ManageAdmins` (`AccessDenied`)
+    /// * If the name is empty or too long (`NameRequired`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("rbac", "role_new", role_id)` with the role definition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let role = contract.create_custom_role(
+    ///     env.clone(),
+    ///     super_admin,
+    ///     String::from_str(&env, "Teaching Assistant"),
+    ///     vec![&env, Permission::EditCourses, Permission::ProvideSupport],
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Duplicate permissions**: Stored once
+    /// * **Duplicate names**: Allowed; roles are identified by their ID
+    pub fn create_custom_role(env: Env, admin: Address, name: String, permissions: Vec<Permission>) -> CustomRole {
+        functions::custom_roles::create_custom_role(env, admin, name, permissions)
+    }
+
+    /// Get a custom role definition
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `role_id` - Identifier returned by `create_custom_role`
+    ///
+    /// # Returns
+    ///
+    /// * `Option<CustomRole>` - The role, or `None` if it does not exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let role = contract.get_custom_role(env.clone(), 1);
+    /// ```
+    pub fn get_custom_role(env: Env, role_id: u32) -> Option<CustomRole> {
+        functions::custom_roles::get_custom_role(&env, role_id)
+    }
+
+    /// Assign a custom role to a user (requires `ManageAdmins`)
+    ///
+    /// A user holds at most one custom role; assigning another replaces it.
+    /// The user's built-in role is unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - Address performing the change (must hold `ManageAdmins`)
+    /// * `user` - Address receiving the role
+    /// * `role_id` - Identifier of the custom role
+    ///
+    /// # Panics
+    ///
+    /// * If admin authentication fails
+    /// * If admin does not hold `ManageAdmins` (`AccessDenied`)
+    /// * If the role does not exist (`CustomRoleNotFound`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("rbac", "role_asgn", user)` with the role ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.assign_custom_role(env.clone(), super_admin, assistant, role.id);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revoked permission**: Individual revocations still override the custom role
+    pub fn assign_custom_role(env: Env, admin: Address, user: Address, role_id: u32) {
+        functions::custom_roles::assign_custom_role(env, admin, user, role_id)
+    }
+
+    /// Remove the custom role of a user (requires `ManageAdmins`)
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - Address performing the change (must hold `ManageAdmins`)
+    /// * `user` - Address losing the role
+    ///
+    /// # Panics
+    ///
+    /// * If admin authentication fails
+    /// * If admin does not hold `ManageAdmins` (`AccessDenied`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("rbac", "role_asgn", user)` with no role ID when a role was removed.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No custom role**: Does nothing
+    pub fn unassign_custom_role(env: Env, admin: Address, user: Address) {
+        functions::custom_roles::unassign_custom_role(env, admin, user)
+    }
+
+    /// Get the custom role held by a user
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - Address to look up
+    ///
+    /// # Returns
+    ///
+    /// * `Option<CustomRole>` - The user's custom role, if any
+    pub fn get_user_custom_role(env: Env, user: Address) -> Option<CustomRole> {
+        functions::custom_roles::get_user_custom_role(&env, &user)
+    }
+
+    /// Create an organization account
+    ///
+    /// Organizations let companies and schools buy course seats for their
+    /// members. The owner becomes the first member and org admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - Address creating the organization
+    /// * `name` - Display name of the organization (1-100 characters)
+    ///
+    /// # Returns
+    ///
+    /// * `Organization` - The stored organization, with its identifier
+    ///
+    /// # Panics
+    ///
+    /// * If owner authentication fails
+    /// * If the name is empty or too long (`NameRequired`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("org", "created", org_id)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let org = contract.create_org(env.clone(), owner, String::from_str(&env, "Acme"));
+    /// ```
+    pub fn create_org(env: Env, owner: Address, name: String) -> Organization {
+        functions::organizations::create_org(env, owner, name)
+    }
+
+    /// Get an organization
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `org_id` - Identifier returned by `create_org`
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Organization>` - The organization, or `None` if it does not exist
+    pub fn get_org(env: Env, org_id: u32) -> Option<Organization> {
+        functions::organizations::get_org(&env, org_id)
+    }
+
+    /// Add a member to an organization (org admins only)
+    ///
+    /// Calling it again for an existing member updates their admin flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin of the organization
+    /// * `org_id` - The organization identifier
+    /// * `member` - Address joining the organization
+    /// * `as_admin` - Whether the member can manage the organization and its seats
+    ///
+    /// # Panics
+    ///
+    /// * If caller authentication fails
+    /// * If the organization does not exist (`OrgNotFound`)
+    /// * If caller is not an org admin (`AccessDenied`)
+    /// * If the owner would lose their admin flag (`CannotRemoveOrgOwner`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("org", "mbr_added", org_id)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_org_member(env.clone(), owner, org.id, employee, false);
+    /// ```
+    pub fn add_org_member(env: Env, caller: Address, org_id: u32, member: Address, as_admin: bool) {
+        functions::organizations::add_org_member(env, caller, org_id, member, as_admin)
+    }
+
+    /// Remove a member from an organization (org admins only)
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin of the organization
+    /// * `org_id` - The organization identifier
+    /// * `member` - Address leaving the organization
+    ///
+    /// # Panics
+    ///
+    /// * If caller authentication fails
+    /// * If the organization does not exist (`OrgNotFound`)
+    /// * If caller is not an org admin (`AccessDenied`)
+    /// * If the member is the owner (`CannotRemoveOrgOwner`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("org", "mbr_rmvd", org_id)` when the member was removed.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing access**: Course access granted through the organization is kept
+    pub fn remove_org_member(env: Env, caller: Address, org_id: u32, member: Address) {
+        functions::organizations::remove_org_member(env, caller, org_id, member)
+    }
+
+    /// Get the members of an organization, owner first
+    pub fn get_org_members(env: Env, org_id: u32) -> Vec<Address> {
+        functions::organizations::get_org_members(&env, org_id)
+    }
+
+    /// Check whether an address belongs to an organization
+    pub fn is_org_member(env: Env, org_id: u32, who: Address) -> bool {
+        functions::organizations::is_org_member(&env, org_id, &who)
+    }
+
+    /// Check whether an address administers an organization
+    pub fn is_org_admin(env: Env, org_id: u32, who: Address) -> bool {
+        functions::organizations::is_org_admin(&env, org_id, &who)
+    }
+
+    /// Record course seats purchased by an organization (platform admins only)
+    ///
+    /// Called once the purchase is settled. Org admins then spend the seats
+    /// on members with `grant_org_access` in the course access contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - A platform admin
+    /// * `org_id` - The organization identifier
+    /// * `course_id` - The course the seats are for
+    /// * `seats` - Number of seats purchased
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Total seats purchased for the course
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not a platform admin (`AccessDenied`)
+    /// * If the organization does not exist (`OrgNotFound`)
+    /// * If `seats` is 0 or the total overflows (`InvalidSeatCount`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("org", "seats", org_id)` with the course and seat counts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let total = contract.purchase_org_seats(env.clone(), admin, org.id, course_id, 25);
+    /// ```
+    pub fn purchase_org_seats(env: Env, caller: Address, org_id: u32, course_id: String, seats: u32) -> u32 {
+        functions::organizations::purchase_org_seats(env, caller, org_id, course_id, seats)
+    }
+
+    /// Get the seats an organization purchased for a course
+    pub fn get_org_seats(env: Env, org_id: u32, course_id: String) -> u32 {
+        functions::organizations::get_org_seats(&env, org_id, course_id)
+    }
+
+    /// Award gamification points to a user (admins and allowlisted contracts only)
+    ///
+    /// Lets contracts such as progress and certificates reward learning
+    /// activity once an admin has added them with `set_points_awarder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin or an allowlisted contract
+    /// * `user` - The user receiving the points
+    /// * `amount` - Number of points, greater than 0
+    /// * `reason` - Short label of the activity, e.g. `lesson` or `cert`
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - The user's new point total
+    ///
+    /// # Panics
+    ///
+    /// * If caller authentication fails
+    /// * If caller is neither an admin nor allowlisted (`AccessDenied`)
+    /// * If `amount` is 0 or the total overflows (`InvalidPointsAmount`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("points", "awarded", user)`, and `("points", "level_up", user)`
+    /// when the award moves the user up a level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.award_points(env.clone(), progress_contract, user, 10, symbol_short!("lesson"));
+    /// ```
+    pub fn award_points(env: Env, caller: Address, user: Address, amount: u64, reason: Symbol) -> u64 {
+        functions::points::award_points(env, caller, user, amount, reason)
+    }
+
+    /// Get the point total of a user
+    pub fn get_user_points(env: Env, user: Address) -> u64 {
+        functions::points::get_user_points(&env, user)
+    }
+
+    /// Get the level of a user
+    ///
+    /// The level is the number of level thresholds the user's points have
+    /// reached, so users start at level 0.
+    pub fn get_user_level(env: Env, user: Address) -> u32 {
+        functions::points::get_user_level(&env, user)
+    }
+
+    /// Set the points needed for each level (admins only)
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - A platform admin
+    /// * `thresholds` - Points needed for levels 1, 2, ..., strictly increasing
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the thresholds are empty, more than 100, or not strictly
+    ///   increasing from a positive value (`InvalidLevelThresholds`)
+    pub fn set_level_thresholds(env: Env, admin: Address, thresholds: Vec<u64>) {
+        functions::points::set_level_thresholds(env, admin, thresholds)
+    }
+
+    /// Get the points needed for each level, lowest level first
+    pub fn get_level_thresholds(env: Env) -> Vec<u64> {
+        functions::points::get_level_thresholds(&env)
+    }
+
+    /// Allow or disallow a contract to award points (admins only)
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - A platform admin
+    /// * `awarder` - Contract address, e.g. the progress or certificates contract
+    /// * `allowed` - Whether the contract may call `award_points`
+    ///
+    /// # Events
+    ///
+    /// Emits `("points", "awarder", awarder)` when the allowlist changes.
+    pub fn set_points_awarder(env: Env, admin: Address, awarder: Address, allowed: bool) {
+        functions::points::set_points_awarder(env, admin, awarder, allowed)
+    }
+
+    /// Get the contracts allowed to award points
+    pub fn get_points_awarders(env: Env) -> Vec<Address> {
+        functions::points::get_points_awarders(&env)
+    }
+
+    /// Define an achievement (admins only)
+    ///
+    /// Achievements unlock automatically when a user earns points or
+    /// completes a course and meets the criterion.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - A platform admin
+    /// * `id` - Unique identifier of the achievement
+    /// * `name` - Display name (1-100 characters)
+    /// * `criteria` - Milestone that unlocks the achievement, e.g. `CompleteNCourses(3)`
+    ///
+    /// # Returns
+    ///
+    /// * `Achievement` - The stored achievement
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the name is empty or too long (`NameRequired`)
+    /// * If the identifier is taken (`AchievementAlreadyExists`)
+    /// * If the criterion is 0 or 100 achievements exist (`InvalidAchievement`)
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing users**: Users who already meet the criterion unlock it
+    ///   on their next points award or course completion
+    pub fn define_achievement(env: Env, admin: Address, id: Symbol, name: String, criteria: AchievementCriteria) -> Achievement {
+        functions::achievements::define_achievement(env, admin, id, name, criteria)
+    }
+
+    /// Get an achievement definition
+    pub fn get_achievement(env: Env, id: Symbol) -> Option<Achievement> {
+        functions::achievements::get_achievement(&env, id)
+    }
+
+    /// Get the identifiers of all achievements, in definition order
+    pub fn list_achievements(env: Env) -> Vec<Symbol> {
+        functions::achievements::list_achievements(&env)
+    }
+
+    /// Get the achievements a user unlocked, oldest first
+    pub fn get_user_achievements(env: Env, user: Address) -> Vec<UnlockedAchievement> {
+        functions::achievements::get_user_achievements(&env, user)
+    }
+
+    /// Record that a user completed a course (admins and allowlisted contracts only)
+    ///
+    /// Called by the progress contract when a user completes a course, then
+    /// unlocks the achievements the user now qualifies for.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin or an allowlisted contract (see `set_points_awarder`)
+    /// * `user` - The user who completed a course
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - The number of courses the user completed
+    ///
+    /// # Events
+    ///
+    /// Emits `("achv", "unlocked", user)` for each achievement unlocked.
+    pub fn record_course_completion(env: Env, caller: Address, user: Address) -> u32 {
+        functions::achievements::record_course_completion(env, caller, user)
+    }
+
+    /// Get the number of courses a user completed
+    pub fn get_completed_courses(env: Env, user: Address) -> u32 {
+        functions::achievements::get_completed_courses(&env, user)
+    }
+
+    /// Set the privacy preferences of a user
+    ///
+    /// User listings leave out the country and profession the user hides,
+    /// and skip users who are not discoverable, for every caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The profile owner
+    /// * `settings` - Fields others can see and whether the user is listed
+    ///
+    /// # Panics
+    ///
+    /// * If user authentication fails
+    /// * If the user has no profile (`UserProfileNotFound`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("user", "updated", user)`.
+    pub fn set_privacy_settings(env: Env, user: Address, settings: PrivacySettings) {
+        functions::privacy::set_privacy_settings(env, user, settings)
+    }
+
+    /// Get the privacy preferences of a user, `None` if they never set any
+    pub fn get_privacy_settings(env: Env, user: Address) -> Option<PrivacySettings> {
+        functions::privacy::get_privacy_settings(&env, user)
+    }
+
+    /// Get the links a user published to their accounts on other platforms
+    ///
+    /// Links are set through `edit_user_profile` and are public.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The profile owner
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<SocialLink>` - The user's links; empty if none were set
+    pub fn get_social_links(env: Env, user: Address) -> Vec<SocialLink> {
+        functions::social_links::get_social_links(&env, user)
+    }
+
+    /// Claim a username handle
+    ///
+    /// Handles are unique and case-insensitive: they are stored lowercase and
+    /// looked up the same way. Claiming a new handle releases the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - A registered user
+    /// * `handle` - 3-30 characters from `a-z`, `A-Z`, `0-9` and `_`
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The normalized, lowercase handle
+    ///
+    /// # Panics
+    ///
+    /// * If user authentication fails
+    /// * If the user has no profile (`UserProfileNotFound`)
+    /// * If the handle has an invalid length or character (`InvalidUsername`)
+    /// * If another user holds the handle (`UsernameTaken`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("user", "username", user)`.
+    pub fn claim_username(env: Env, user: Address, handle: String) -> String {
+        functions::usernames::claim_username(env, user, handle)
+    }
+
+    /// Release the username handle of a user so others can claim it
+    ///
+    /// # Panics
+    ///
+    /// * If user authentication fails
+    /// * If the user holds no handle (`UsernameNotFound`)
+    pub fn release_username(env: Env, user: Address) {
+        functions::usernames::release_username(env, user)
+    }
+
+    /// Get the user holding a handle, matched case-insensitively
+    pub fn get_user_by_username(env: Env, handle: String) -> Option<Address> {
+        functions::usernames::get_user_by_username(&env, handle)
+    }
+
+    /// Get the username handle of a user
+    pub fn get_username(env: Env, user: Address) -> Option<String> {
+        functions::usernames::get_username(&env, user)
+    }
+
+    /// Link a secondary wallet address to a learner identity
+    ///
+    /// Contracts resolving addresses with `resolve_identity` then treat the
+    /// secondary address as the primary one, so enrollments and certificates
+    /// follow the learner across wallets.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `primary` - Address identifying the learner
+    /// * `secondary` - Address to link; must sign together with `primary`
+    ///
+    /// # Panics
+    ///
+    /// * If either authentication fails
+    /// * If the addresses are equal, `primary` is itself linked, `secondary`
+    ///   has linked addresses, or 10 addresses are linked (`InvalidIdentityLink`)
+    /// * If `secondary` is already linked (`AddressAlreadyLinked`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("user", "linked", primary)`.
+    pub fn link_address(env: Env, primary: Address, secondary: Address) {
+        functions::identity::link_address(env, primary, secondary)
+    }
+
+    /// Unlink a secondary address from a learner identity
+    ///
+    /// Either of the two addresses may remove the link.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is neither address (`AccessDenied`)
+    /// * If the addresses are not linked (`InvalidIdentityLink`)
+    ///
+    /// # Events
+    ///
+    /// Emits `("user", "unlinked", primary)`.
+    pub fn unlink_address(env: Env, caller: Address, primary: Address, secondary: Address) {
+        functions::identity::unlink_address(env, caller, primary, secondary)
+    }
+
+    /// Get the primary address of the identity an address belongs to
+    ///
+    /// Returns the address itself when it is not a linked secondary address.
+    pub fn resolve_identity(env: Env, address: Address) -> Address {
+        functions::identity::resolve_identity(&env, address)
+    }
+
+    /// Get the secondary addresses linked to a primary address
+    pub fn get_linked_addresses(env: Env, primary: Address) -> Vec<Address> {
+        functions::identity::get_linked_addresses(&env, primary)
+    }
+
     /// Delete (deactivate) a user account
     ///
     /// Performs a soft delete by marking the user as inactive instead of permanent deletion.
@@ -289,6 +1148,166 @@ impl UserManagement {
         functions::delete_user::delete_user(env, caller, user_id)
     }
 
+    /// Request the erasure of the caller's personal data.
+    ///
+    /// The request waits for an admin to execute it with `execute_erasure`.
+    /// Filing it again while it is pending has no effect.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - User asking for erasure (must be authorized)
+    ///
+    /// # Panics
+    /// * If user has no profile (`UserProfileNotFound`)
+    /// * If the data was already erased (`DataAlreadyErased`)
+    ///
+    /// # Events
+    /// Emits `("user", "erase_req", user)`.
+    pub fn request_data_erasure(env: Env, user: Address) {
+        functions::erasure::request_data_erasure(env, user)
+    }
+
+    /// Execute a pending erasure request.
+    ///
+    /// Overwrites the name and email with a tombstone, clears the other
+    /// personal profile fields, releases the email and username, and
+    /// deactivates the user. Points, achievements and completed courses are kept.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `admin` - Admin executing the request (must be authorized)
+    /// * `user` - User whose data is erased
+    ///
+    /// # Panics
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If user has no pending request (`ErasureNotRequested`)
+    ///
+    /// # Events
+    /// Emits `("user", "erased", user)`.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Irreversible**: The erased fields cannot be recovered
+    pub fn execute_erasure(env: Env, admin: Address, user: Address) {
+        functions::erasure::execute_erasure(env, admin, user)
+    }
+
+    /// List the users with a pending erasure request. Admin only.
+    pub fn list_erasure_requests(env: Env, admin: Address) -> Vec<Address> {
+        functions::erasure::list_erasure_requests(&env, admin)
+    }
+
+    /// Get when a user's personal data was erased, `None` if it wasn't.
+    pub fn get_erased_at(env: Env, user: Address) -> Option<u64> {
+        functions::erasure::get_erased_at(&env, user)
+    }
+
+    /// Extend the storage TTL of a user's profile entries.
+    ///
+    /// Profiles are bumped automatically when read or written; this lets
+    /// anyone keep a dormant profile from being archived.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user
+    ///
+    /// # Panics
+    ///
+    /// * If the user has no profile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.extend_profile_ttl(env.clone(), user_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Related entries**: Light profile, role and permission overrides are extended too
+    /// * **Fresh entries**: Entries above the threshold are left untouched
+    pub fn extend_profile_ttl(env: Env, user: Address) {
+        functions::ttl::extend_profile_ttl(env, user)
+    }
+
+    /// Get the TTL thresholds used when bumping storage entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the current `TtlConfig`, or the defaults if none was set.
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        functions::ttl::get_ttl_config(&env)
+    }
+
+    /// Set the TTL thresholds used when bumping storage entries (admin only).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin address
+    /// * `threshold` - Entries with fewer ledgers to live than this are extended
+    /// * `extend_to` - Number of ledgers entries are extended to
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If `threshold` is zero, not below `extend_to`, or `extend_to` exceeds the network maximum
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_ttl_config(env.clone(), admin, 120_960, 518_400);
+    /// ```
+    pub fn set_ttl_config(env: Env, caller: Address, threshold: u32, extend_to: u32) {
+        functions::ttl::set_ttl_config(env, caller, threshold, extend_to)
+    }
+
+    /// Get the rate limit applied to profile creation
+    ///
+    /// # Returns
+    ///
+    /// * `RateLimitConfig` - The configured limit, or the default (5 per hour)
+    ///   if the system is not initialized
+    pub fn get_rate_limit_config(env: Env) -> RateLimitConfig {
+        env.storage()
+            .persistent()
+            .get::<_, AdminConfig>(&schema::DataKey::AdminConfig)
+            .map(|config| config.rate_limit_config)
+            .unwrap_or_else(functions::utils::rate_limit_utils::get_default_rate_limit_config)
+    }
+
+    /// Tune the rate limit applied to profile creation (admin only)
+    ///
+    /// Counters are kept per address in temporary storage and reset every
+    /// `window_seconds` worth of ledgers (about 5 seconds each).
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - An admin
+    /// * `rate_config` - Window length and number of creations allowed per window
+    ///
+    /// # Panics
+    /// * If the system is not initialized
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the window or the maximum is 0 (`InvalidField`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_rate_limit_config(
+    ///     env.clone(),
+    ///     admin,
+    ///     RateLimitConfig { window_seconds: 86_400, max_operations_per_window: 3 },
+    /// );
+    /// ```
+    pub fn set_rate_limit_config(env: Env, caller: Address, rate_config: RateLimitConfig) {
+        functions::utils::rate_limit_utils::set_rate_limit_config(env, caller, rate_config)
+    }
+
     /// Lists all registered users with pagination and filtering (admin-only)
     ///
     /// # Arguments
@@ -353,6 +1372,9 @@ impl UserManagement {
                 country: country_filter,
                 status: status_filter,
                 search_text: None,
+                profession: None,
+                registered_after: None,
+                registered_before: None,
             })
         } else {
             None
@@ -400,6 +1422,9 @@ impl UserManagement {
                 country: country_filter,
                 status: status_filter,
                 search_text,
+                profession: None,
+                registered_after: None,
+                registered_before: None,
             })
         } else {
             None
@@ -414,6 +1439,59 @@ impl UserManagement {
         )
     }
 
+    /// Lists all registered users with profession and registration date filters (admin-only).
+    ///
+    /// Extends `list_all_users_advanced` so admin tooling can segment users
+    /// without exporting everything.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the call (must be admin)
+    /// * `page` - Zero-based page index
+    /// * `page_size` - Number of items per page
+    /// * `role_filter` - Optional role filter
+    /// * `country_filter` - Optional country filter
+    /// * `status_filter` - Optional status filter
+    /// * `profession` - Optional profession filter
+    /// * `registered_after` - Only users who registered at or after this timestamp
+    /// * `registered_before` - Only users who registered before this timestamp
+    ///
+    /// # Returns
+    /// * `Vec<LightProfile>` - Filtered and paginated lightweight user profiles
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Older profiles**: Profiles created before registration dates were
+    ///   recorded count as registered at 0
+    pub fn list_all_users_filtered(
+        env: Env,
+        caller: Address,
+        page: u32,
+        page_size: u32,
+        role_filter: Option<UserRole>,
+        country_filter: Option<String>,
+        status_filter: Option<UserStatus>,
+        profession: Option<String>,
+        registered_after: Option<u64>,
+        registered_before: Option<u64>,
+    ) -> Vec<LightProfile> {
+        functions::list_all_registered_users::list_all_users(
+            env,
+            caller,
+            page,
+            page_size,
+            Some(UserFilter {
+                role: role_filter,
+                country: country_filter,
+                status: status_filter,
+                search_text: None,
+                profession,
+                registered_after,
+                registered_before,
+            }),
+        )
+    }
+
     /// Lists all registered users with cursor-based pagination and filtering (admin-only)
     ///
     /// This function implements efficient cursor-based pagination to avoid gas limit issues
@@ -611,6 +1689,23 @@ impl UserManagement {
         functions::admin_management::is_system_initialized(env)
     }
 
+    /// Check whether the contract has been initialized.
+    ///
+    /// Same as `is_system_initialized`, under the name the other SkillCert
+    /// contracts use.
+    pub fn is_initialized(env: Env) -> bool {
+        functions::admin_management::is_system_initialized(env)
+    }
+
+    /// Get the system configuration set by `initialize_system`.
+    ///
+    /// # Panics
+    ///
+    /// * If the system is not initialized
+    pub fn get_config(env: Env) -> AdminConfig {
+        functions::admin_management::get_config(&env)
+    }
+
     /// Get the current contract version
     ///
     /// Returns the semantic version of the current contract deployment.
@@ -625,6 +1720,55 @@ impl UserManagement {
         String::from_str(&_env, VERSION)
     }
 
+    /// Get contract version history
+    ///
+    /// Returns every version recorded for this contract, written on
+    /// initialization, upgrade and completed migrations.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `Vec<VersionRecord>` - `{version, ledger_timestamp, by}` entries in chronological order
+    pub fn get_version_history(env: Env) -> Vec<VersionRecord> {
+        functions::contract_versioning::get_version_history(&env)
+    }
+
+    /// Get migration status for the current contract
+    ///
+    /// Reports the running code version, the latest recorded version and
+    /// whether a data migration is still pending after an upgrade.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `MigrationStatus` - Versions, pending flag and last migration result
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
+        functions::contract_versioning::get_migration_status(&env)
+    }
+
+    /// Upgrade the contract code
+    ///
+    /// Swaps the contract WASM for one previously uploaded to the network and
+    /// records the upgrade in the version history. Storage is kept as is;
+    /// run the migration entrypoints afterwards if the schema changed.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - An admin or the super admin
+    /// * `new_wasm_hash` - Hash of the uploaded WASM to switch to
+    /// * `new_version` - Version string of the new code, stored in the history
+    ///
+    /// # Panics
+    /// * If the caller is not an admin
+    ///
+    /// # Events
+    /// Emits a `("contract", "upgraded", new_version)` event with the from/to versions
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>, new_version: String) {
+        functions::contract_versioning::upgrade(&env, admin, new_wasm_hash, new_version)
+    }
+
     /// Export all user data for backup purposes (admin only)
     ///
     /// This function exports all user profiles and administrative data
@@ -665,5 +1809,208 @@ impl UserManagement {
         functions::backup_recovery::import_user_data(env, caller, backup_data)
     }
 
+    /// Export one page of user data for backup purposes (admin only)
+    ///
+    /// Chunked alternative to `export_user_data` for user bases too large
+    /// for one call. Start with offset 0 and an all-zero checksum, then pass
+    /// each page's `next_cursor` until `has_more` is false.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address requesting the backup (must be admin)
+    /// * `cursor` - Cursor of the page to export
+    /// * `limit` - Maximum number of users in the page (1 to 50)
+    ///
+    /// # Panics
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the limit is out of range or the cursor is past the end (`InvalidBackupPage`)
+    pub fn export_user_data_page(
+        env: Env,
+        caller: Address,
+        cursor: crate::schema::BackupCursor,
+        limit: u32,
+    ) -> crate::schema::UserBackupPage {
+        functions::backup_recovery::export_user_data_page(env, caller, cursor, limit)
+    }
+
+    /// Import one page of a paged backup (admin only)
+    ///
+    /// Pages must be imported in export order; the checksum carried by the
+    /// cursors is verified so altered, missing or reordered pages are rejected.
+    ///
+    /// # Returns
+    /// * `u32` - Number of users imported from the page
+    ///
+    /// # Panics
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the page isn't the next one expected (`InvalidBackupPage`)
+    /// * If the page's checksum doesn't match its contents (`BackupChecksumMismatch`)
+    pub fn import_user_data_page(env: Env, caller: Address, page: crate::schema::UserBackupPage) -> u32 {
+        functions::backup_recovery::import_user_data_page(env, caller, page)
+    }
+
+    /// Rebuild the users index in chunks (admin only)
+    ///
+    /// Drops duplicate entries and addresses without a profile from the list
+    /// of registered users. Start with cursor 0 and call again with the
+    /// returned cursor until it is `None`; the index is replaced after the
+    /// last chunk.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address running the rebuild (must be admin)
+    /// * `cursor` - Number of index entries already processed (0 to start)
+    /// * `limit` - Number of entries to process (1 to 50)
+    ///
+    /// # Returns
+    /// * `Option<u32>` - Cursor of the next chunk, or `None` once the index was replaced
+    ///
+    /// # Panics
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the limit is out of range (`InvalidBatchSize`)
+    pub fn rebuild_users_index(env: Env, caller: Address, cursor: u32, limit: u32) -> Option<u32> {
+        functions::index_repair::rebuild_users_index(&env, caller, cursor, limit)
+    }
+
+    /// Move emails registered before the email index was hashed to it (admin only)
+    ///
+    /// Until an email is moved it still counts as taken, but it can't be
+    /// found with `get_user_by_email_hash`. Start with cursor 0 and call
+    /// again with the returned cursor until it is `None`.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address running the migration (must be admin)
+    /// * `cursor` - Number of users index entries already processed (0 to start)
+    /// * `limit` - Number of entries to process (1 to 50)
+    ///
+    /// # Returns
+    /// * `Option<u32>` - Cursor of the next chunk, or `None` once every user was processed
+    ///
+    /// # Panics
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the limit is out of range (`InvalidBatchSize`)
+    pub fn migrate_email_index(env: Env, caller: Address, cursor: u32, limit: u32) -> Option<u32> {
+        functions::index_repair::migrate_email_index(&env, caller, cursor, limit)
+    }
+
+    /// Move the email of profiles stored before the public/private split to its own record (admin only)
+    ///
+    /// Profiles that weren't moved yet are still read correctly. Start with
+    /// cursor 0 and call again with the returned cursor until it is `None`.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address running the migration (must be admin)
+    /// * `cursor` - Number of users index entries already processed (0 to start)
+    /// * `limit` - Number of entries to process (1 to 50)
+    ///
+    /// # Returns
+    /// * `Option<u32>` - Cursor of the next chunk, or `None` once every user was processed
+    ///
+    /// # Panics
+    /// * If caller is not an admin (`AccessDenied`)
+    /// * If the limit is out of range (`InvalidBatchSize`)
+    pub fn migrate_profile_split(env: Env, caller: Address, cursor: u32, limit: u32) -> Option<u32> {
+        functions::index_repair::migrate_profile_split(&env, caller, cursor, limit)
+    }
+
+    /// Export everything stored about the caller
+    ///
+    /// Collects the profile, role, effective permissions, username, points
+    /// and achievements, plus the enrollments and certificates held by the
+    /// contracts registered with `set_data_export_config`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - Soroban environment
+    /// * `user` - User exporting their data (must be authorized)
+    ///
+    /// # Panics
+    ///
+    /// * If user has no profile (`UserProfileNotFound`)
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unconfigured contracts**: Enrollments or certificates are left empty
+    pub fn export_my_data(env: Env, user: Address) -> crate::schema::UserDataExport {
+        functions::data_export::export_my_data(env, user)
+    }
+
+    /// Set the course access and certificates contracts `export_my_data` reads from. Admin only.
+    pub fn set_data_export_config(env: Env, admin: Address, config: crate::schema::DataExportConfig) {
+        functions::data_export::set_data_export_config(env, admin, config)
+    }
+
+    /// Get the contracts `export_my_data` reads from.
+    pub fn get_data_export_config(env: Env) -> crate::schema::DataExportConfig {
+        functions::data_export::get_data_export_config(&env)
+    }
+
+    /// Set the course registry new registrations are reported to. Admin only.
+    ///
+    /// The registry must also allow this contract as a stats reporter;
+    /// registrations it rejects are not counted, but still succeed.
+    pub fn set_stats_registry(env: Env, admin: Address, registry: Address) {
+        functions::platform_stats::set_stats_registry(&env, admin, registry)
+    }
+
+    /// Get the course registry new registrations are reported to.
+    pub fn get_stats_registry(env: Env) -> Option<Address> {
+        functions::platform_stats::get_stats_registry(&env)
+    }
+
+    /// Set the contract proof of personhood is checked against. Admin only.
+    ///
+    /// The verifier must expose `is_verified(user) -> bool`. Setting it
+    /// requires nothing by itself; see `set_personhood_required`.
+    pub fn set_personhood_verifier(env: Env, admin: Address, contract_address: Address) {
+        functions::personhood::set_personhood_verifier(&env, admin, contract_address)
+    }
+
+    /// Turn the proof-of-personhood requirement of a feature on or off. Admin only.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - Soroban environment
+    /// * `admin` - An admin
+    /// * `feature` - `ProfileCreation` (checked by `create_user_profile`) or
+    ///   `ReviewSubmission` (checked by the feedback contract)
+    /// * `required` - Whether users must be verified to use the feature
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin (`AccessDenied`)
+    /// * If turning a requirement on before a verifier is set (`PersonhoodVerifierNotSet`)
+    pub fn set_personhood_required(env: Env, admin: Address, feature: crate::schema::PersonhoodFeature, required: bool) {
+        functions::personhood::set_personhood_required(&env, admin, feature, required)
+    }
+
+    /// Get the personhood verifier and the features requiring it.
+    pub fn get_personhood_config(env: Env) -> crate::schema::PersonhoodConfig {
+        functions::personhood::get_personhood_config(&env)
+    }
+
+    /// Check whether a user may use a feature: always when it doesn't require
+    /// proof of personhood, otherwise when the verifier vouches for them.
+    pub fn meets_personhood_requirement(env: Env, user: Address, feature: crate::schema::PersonhoodFeature) -> bool {
+        functions::personhood::meets_personhood_requirement(&env, &user, &feature)
+    }
+
     // NOTE: Removed legacy duplicate wrappers that caused redefinitions.
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
 }
@@ -1,16 +1,23 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 /// Default and limit constants for user management configuration
 pub const DEFAULT_MAX_PAGE_SIZE: u32 = 100;
 pub const ABSOLUTE_MAX_PAGE_SIZE: u32 = 1000;
 pub const MAX_ADMINS: u32 = 10;
 
+/// Storage TTL defaults, in ledgers (about 5 seconds each)
+pub const DEFAULT_TTL_THRESHOLD: u32 = 120_960; // ~7 days
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 518_400; // ~30 days
+
+
 /// Rate limiting constants
 pub const DEFAULT_RATE_LIMIT_WINDOW: u64 = 3600; // 1 hour in seconds
 pub const DEFAULT_MAX_USER_CREATIONS_PER_WINDOW: u32 = 5; // Max user creations per hour per address
+/// Approximate ledger close time, used to express rate limit windows in ledgers
+pub const LEDGER_CLOSE_SECONDS: u64 = 5;
 
 /// Password validation constants
 pub const MIN_PASSWORD_LENGTH: u32 = 8;
@@ -20,6 +27,29 @@ pub const REQUIRED_DIGITS: &str = "0123456789";
 pub const REQUIRED_UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 pub const REQUIRED_LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
 
+/// Social link limits
+pub const MAX_SOCIAL_LINKS: u32 = 5;
+pub const MAX_SOCIAL_PLATFORM_LENGTH: u32 = 30;
+pub const MAX_SOCIAL_LINK_URL_LENGTH: u32 = 200;
+
+/// Username handle limits
+pub const MIN_USERNAME_LENGTH: u32 = 3;
+pub const MAX_USERNAME_LENGTH: u32 = 30;
+
+// Paged backup
+pub const MAX_BACKUP_PAGE_SIZE: u32 = 50;
+pub const MAX_REBUILD_BATCH: u32 = 50;
+
+/// Link to a user's account on another platform.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SocialLink {
+    /// Platform name, e.g. "github" (1-30 characters, unique per profile)
+    pub platform: String,
+    /// http(s) URL of the account (at most 200 characters)
+    pub url: String,
+}
+
 /// User profile information matching UI definition.
 ///
 /// This struct contains user profile data with required and optional fields
@@ -41,6 +71,23 @@ pub struct UserProfile {
     pub profile_picture_url: Option<String>,
 }
 
+/// Public part of a stored user profile: every `UserProfile` field but the
+/// private `contact_email`, which is stored under `DataKey::ContactEmail`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicUserProfile {
+    /// User's full name
+    pub full_name: String,
+    /// User's profession or job title
+    pub profession: Option<String>,
+    /// User's country of residence
+    pub country: Option<String>,
+    /// User's learning goals or purpose
+    pub purpose: Option<String>,
+    /// User's profile picture URL
+    pub profile_picture_url: Option<String>,
+}
+
 /// Struct for profile update parameters
 /// Only includes fields that can be updated
 #[contracttype]
@@ -48,14 +95,18 @@ pub struct UserProfile {
 pub struct ProfileUpdateParams {
     /// User's full name (optional update)
     pub full_name: Option<String>,
+    /// User's contact email address (must remain unique)
+    pub contact_email: Option<String>,
     /// User's profession or job title
     pub profession: Option<String>,
     /// User's country of residence
     pub country: Option<String>,
     /// User's learning goals or purpose
     pub purpose: Option<String>,
-    /// User's profile picture URL
+    /// User's profile picture URL (empty string clears it)
     pub profile_picture_url: Option<String>,
+    /// Replacement social links (empty list clears them)
+    pub social_links: Option<Vec<SocialLink>>,
 }
 
 /// User roles in the SkillCert platform.
@@ -149,6 +200,70 @@ pub struct UserPermissions {
     pub revoked_permissions: soroban_sdk::Vec<Permission>,
 }
 
+/// Role defined by an admin on top of the built-in `UserRole` values.
+///
+/// A user holding a custom role gets its permissions in addition to the
+/// permissions of their built-in role.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomRole {
+    /// Sequential identifier, starting at 1
+    pub id: u32,
+    /// Display name, e.g. "Teaching Assistant"
+    pub name: String,
+    /// Permissions granted by this role
+    pub permissions: soroban_sdk::Vec<Permission>,
+    /// Admin that defined the role
+    pub created_by: Address,
+}
+
+/// Milestone that unlocks an achievement.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AchievementCriteria {
+    /// Complete at least this many courses
+    CompleteNCourses(u32),
+    /// Earn at least this many points in total
+    EarnNPoints(u64),
+}
+
+/// Achievement defined by an admin, unlocked automatically from its criterion.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Achievement {
+    /// Unique identifier, e.g. `first_course`
+    pub id: Symbol,
+    /// Display name shown on profile pages
+    pub name: String,
+    /// Milestone that unlocks the achievement
+    pub criteria: AchievementCriteria,
+    /// Admin that defined the achievement
+    pub created_by: Address,
+}
+
+/// Achievement unlocked by a user.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnlockedAchievement {
+    pub achievement_id: Symbol,
+    /// Ledger timestamp of the unlock
+    pub unlocked_at: u64,
+}
+
+/// Organization account buying course seats for its members.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Organization {
+    /// Sequential identifier, starting at 1
+    pub id: u32,
+    /// Display name of the organization
+    pub name: String,
+    /// Address that created the organization; always an org admin
+    pub owner: Address,
+    /// Ledger timestamp of the creation
+    pub created_at: u64,
+}
+
 /// User account status.
 ///
 /// Represents the current state of a user's account.
@@ -177,6 +292,29 @@ pub struct UserFilter {
     pub status: Option<UserStatus>,
     /// Text search in user's full name and profession
     pub search_text: Option<String>,
+    /// Filter by profession
+    pub profession: Option<String>,
+    /// Only users who registered at or after this timestamp
+    pub registered_after: Option<u64>,
+    /// Only users who registered before this timestamp
+    pub registered_before: Option<u64>,
+}
+
+/// Privacy preferences chosen by a user.
+///
+/// Hidden fields are left out of user listings, and users who are not
+/// discoverable are not listed at all.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrivacySettings {
+    /// Whether others can see the contact email
+    pub show_email: bool,
+    /// Whether the country appears in listings
+    pub show_country: bool,
+    /// Whether the profession appears in listings
+    pub show_profession: bool,
+    /// Whether the user appears in user listings and searches
+    pub discoverable: bool,
 }
 
 /// Lightweight user profile for listing operations.
@@ -219,7 +357,7 @@ pub struct RateLimitConfig {
 pub struct RateLimitData {
     /// Current count of operations in this window
     pub count: u32,
-    /// Timestamp when the current window started
+    /// Index of the ledger window the count belongs to
     pub window_start: u64,
 }
 
@@ -241,6 +379,46 @@ pub struct AdminConfig {
     pub rate_limit_config: RateLimitConfig,
 }
 
+/// Position in a paged backup.
+///
+/// `checksum` chains the SHA-256 of every page before `offset`, so pages
+/// that were altered, dropped or reordered are rejected on import. The first
+/// page starts at offset 0 with an all-zero checksum.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupCursor {
+    /// Position in the users index
+    pub offset: u32,
+    pub checksum: BytesN<32>,
+}
+
+/// Backed-up records of one user.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserBackupEntry {
+    pub user: Address,
+    pub profile: UserProfile,
+    pub light_profile: LightProfile,
+    pub social_links: Vec<SocialLink>,
+    /// When the profile was created; 0 if unknown
+    pub registered_at: u64,
+}
+
+/// One chunk of a paged user backup.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserBackupPage {
+    /// Cursor the page was exported from
+    pub cursor: BackupCursor,
+    pub entries: Vec<UserBackupEntry>,
+    /// Cursor of the next page; its checksum covers this page
+    pub next_cursor: BackupCursor,
+    pub has_more: bool,
+    /// Number of users in the whole backup
+    pub total_users: u32,
+    pub backup_version: String,
+}
+
 /// Backup data structure for user management system.
 ///
 /// Contains all user data and system configuration for backup and recovery operations.
@@ -295,6 +473,65 @@ pub struct PaginatedLightProfiles {
     pub has_more: bool,
 }
 
+/// Addresses of the contracts `export_my_data` collects records from.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataExportConfig {
+    /// Course access contract listing enrollments, `None` to skip them
+    pub course_access_addr: Option<Address>,
+    /// Certificates contract listing certificates, `None` to skip them
+    pub certificates_addr: Option<Address>,
+}
+
+/// Proof-of-personhood verifier and the features that require it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PersonhoodConfig {
+    /// Contract answering `is_verified(user) -> bool`, `None` until configured
+    pub verifier: Option<Address>,
+    /// Whether `create_user_profile` requires a verified user
+    pub require_for_profiles: bool,
+    /// Whether review submission (in the feedback contract) requires a verified user
+    pub require_for_reviews: bool,
+}
+
+/// Features that can require proof of personhood.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PersonhoodFeature {
+    ProfileCreation,
+    ReviewSubmission,
+}
+
+/// Everything stored about a user, for data-portability requests.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserDataExport {
+    pub user: Address,
+    pub profile: UserProfile,
+    pub social_links: Vec<SocialLink>,
+    pub role: UserRole,
+    /// Effective permissions, including role defaults and overrides
+    pub permissions: Vec<Permission>,
+    pub username: Option<String>,
+    pub points: u64,
+    pub achievements: Vec<UnlockedAchievement>,
+    /// Identifiers of the courses the user has access to
+    pub enrollments: Vec<String>,
+    /// Unrevoked certificates held by the user
+    pub certificates: Vec<Certificate>,
+    pub exported_at: u64,
+}
+
+/// Courses of a user, as returned by the course access contract's `list_user_courses`.
+pub use skillcert_interfaces::course_access::UserCourses;
+
+/// Certificates of a user and the pagination of the certificates contract's
+/// `list_certificates_by_student`.
+pub use skillcert_interfaces::certificates::{
+    Certificate, CertificatePagination, PaginatedCertificates,
+};
+
 /// Storage keys for different data types in the user management contract.
 ///
 /// This enum defines the various keys used to store and retrieve
@@ -302,7 +539,7 @@ pub struct PaginatedLightProfiles {
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub enum DataKey {
-    /// Key for storing complete user profiles: user_address -> UserProfile
+    /// Key for storing the public part of user profiles: user_address -> PublicUserProfile
     UserProfile(Address),
     /// Key for storing admin flags: address -> bool
     Admin(Address),
@@ -310,7 +547,10 @@ pub enum DataKey {
     UserProfileLight(Address),
     /// Key for storing the list of all registered user addresses
     UsersIndex,
-    /// Key for email to address mapping to ensure email uniqueness: email -> Address
+    /// Key for email hash to address mapping to ensure email uniqueness: sha256(lowercase email) -> Address
+    EmailHash(BytesN<32>),
+    /// Legacy email to address mapping written before emails were hashed: email -> Address.
+    /// Still read for uniqueness until `migrate_email_index` moves it to `EmailHash`
     EmailIndex(String),
     /// Key for storing the list of admin addresses
     Admins,
@@ -318,7 +558,7 @@ pub enum DataKey {
     UserRole(Address),
     /// Key for storing administrative configuration
     AdminConfig,
-    /// Key for storing rate limiting data per address: address -> RateLimitData
+    /// Key for storing rate limiting data per address (temporary storage): address -> RateLimitData
     RateLimit(Address),
     /// Key for storing role-based permissions: role -> RolePermissions
     RolePermissions(UserRole),
@@ -326,4 +566,77 @@ pub enum DataKey {
     UserPermissions(Address),
     /// Key for storing default role permissions configuration
     DefaultRolePermissions,
+    /// Key for storing the storage TTL thresholds (instance storage)
+    TtlConfig,
+    /// Key for storing custom role definitions: role_id -> CustomRole
+    CustomRole(u32),
+    /// Key for storing the number of custom roles defined
+    CustomRoleCount,
+    /// Key for storing custom role assignments: user_address -> role_id
+    UserCustomRole(Address),
+    /// Key for storing organizations: org_id -> Organization
+    Organization(u32),
+    /// Key for storing the number of organizations created
+    OrgCount,
+    /// Key for storing the members of an organization: org_id -> Vec<Address>
+    OrgMembers(u32),
+    /// Key for the organization admin flag: (org_id, address) -> bool
+    OrgAdmin(u32, Address),
+    /// Key for storing the seats purchased for a course: (org_id, course_id) -> u32
+    OrgSeats(u32, String),
+    /// Key for storing the contracts allowed to award points (instance storage): Vec<Address>
+    PointsAwarders,
+    /// Key for storing the point total of a user: user_address -> u64
+    UserPoints(Address),
+    /// Key for storing the points needed for each level: Vec<u64>
+    LevelThresholds,
+    /// Key for storing achievement definitions: achievement_id -> Achievement
+    Achievement(Symbol),
+    /// Key for storing the identifiers of all achievements: Vec<Symbol>
+    AchievementIds,
+    /// Key for storing the achievements a user unlocked: user_address -> Vec<UnlockedAchievement>
+    UserAchievements(Address),
+    /// Key for storing the number of courses a user completed: user_address -> u32
+    CompletedCourses(Address),
+    /// Key for storing privacy preferences: user_address -> PrivacySettings
+    PrivacySettings(Address),
+    /// Key for the username index: lowercase handle -> Address
+    UsernameIndex(String),
+    /// Key for storing the handle of a user: user_address -> lowercase handle
+    Username(Address),
+    /// Key for the identity index: secondary_address -> primary_address
+    IdentityLink(Address),
+    /// Key for storing the secondary addresses of an identity: primary_address -> Vec<Address>
+    LinkedAddresses(Address),
+    /// Key for storing the users with a pending erasure request: Vec<Address>
+    ErasureRequests,
+    /// Key for storing when a user's personal data was erased: user_address -> u64
+    ErasedAt(Address),
+    /// Key for storing the contracts personal data exports read from: DataExportConfig
+    DataExportConfig,
+    /// Key for storing the cursor a paged backup import continues from: BackupCursor
+    BackupImportCursor,
+    /// Key for storing the users index while it is being rebuilt: Vec<Address>
+    UsersIndexRebuild,
+    /// Key for storing the proof-of-personhood verifier and where it applies: PersonhoodConfig
+    PersonhoodConfig,
+    /// Key for storing a user's links to other platforms: user_address -> Vec<SocialLink>
+    SocialLinks(Address),
+    /// Key for storing when a user's profile was created: user_address -> u64
+    RegisteredAt(Address),
+    /// Key for storing the course registry registrations are reported to (instance storage): Address
+    StatsRegistry,
+    /// Key for storing the private part of a user profile: user_address -> contact email
+    ContactEmail(Address),
+}
+
+/// Storage TTL configuration.
+///
+/// Entries whose TTL drops below `threshold` ledgers are extended to `extend_to` ledgers
+/// whenever they are read or written.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
 }
@@ -1,9 +1,9 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String, Vec};
 
-use crate::schema::{UserProfile, ProfileUpdateParams, LightProfile, AdminConfig};
+use crate::schema::{SocialLink, UserProfile, ProfileUpdateParams, LightProfile, AdminConfig, Permission, RateLimitConfig, UserRole};
 use crate::{UserManagement, UserManagementClient};
 
 #[test]
@@ -17,7 +17,7 @@ fn test_create_user_profile_integration() {
         full_name: String::from_str(&env, "Alice Johnson"),
         contact_email: String::from_str(&env, "alice@example.com"),
         profession: Some(String::from_str(&env, "Data Scientist")),
-        country: Some(String::from_str(&env, "United States")),
+        country: Some(String::from_str(&env, "US")),
         purpose: Some(String::from_str(&env, "Learn machine learning")),
         profile_picture_url: None,
     };
@@ -47,7 +47,7 @@ fn test_get_user_by_id_self_access() {
         full_name: String::from_str(&env, "Bob Wilson"),
         contact_email: String::from_str(&env, "bob@example.com"),
         profession: Some(String::from_str(&env, "Software Engineer")),
-        country: Some(String::from_str(&env, "Canada")),
+        country: Some(String::from_str(&env, "CA")),
         purpose: Some(String::from_str(&env, "Improve coding skills")),
         profile_picture_url: None,
     };
@@ -84,7 +84,7 @@ fn test_get_user_by_id_admin_access() {
         full_name: String::from_str(&env, "Bob Wilson"),
         contact_email: String::from_str(&env, "bob@example.com"),
         profession: Some(String::from_str(&env, "Software Engineer")),
-        country: Some(String::from_str(&env, "Canada")),
+        country: Some(String::from_str(&env, "CA")),
         purpose: Some(String::from_str(&env, "Improve coding skills")),
         profile_picture_url: None,
     };
@@ -125,7 +125,7 @@ fn test_list_all_users_basic() {
             full_name: String::from_str(&env, name),
             contact_email: String::from_str(&env, email),
             profession: Some(String::from_str(&env, profession)),
-            country: Some(String::from_str(&env, "United States")),
+            country: Some(String::from_str(&env, "US")),
             purpose: Some(String::from_str(&env, "Learn new skills")),
             profile_picture_url: None,
         };
@@ -146,6 +146,44 @@ fn test_list_all_users_basic() {
     assert_eq!(users.len(), 3);
 }
 
+#[test]
+fn test_list_all_users_filtered_by_profession_and_registration_date() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    let super_admin: Address = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize_system(&super_admin, &super_admin, &None);
+
+    let test_data = [
+        ("John Doe", "john@example.com", "Engineer", 100),
+        ("Jane Smith", "jane@example.com", "Engineer", 200),
+        ("Bob Johnson", "bob@example.com", "Teacher", 300),
+    ];
+    for (name, email, profession, timestamp) in test_data.iter() {
+        env.ledger().with_mut(|li| li.timestamp = *timestamp);
+        let profile: UserProfile = UserProfile {
+            full_name: String::from_str(&env, name),
+            contact_email: String::from_str(&env, email),
+            profession: Some(String::from_str(&env, profession)),
+            country: Some(String::from_str(&env, "US")),
+            purpose: None,
+            profile_picture_url: None,
+        };
+        client.create_user_profile(&Address::generate(&env), &profile);
+    }
+
+    let engineer: Option<String> = Some(String::from_str(&env, "Engineer"));
+    let users: Vec<LightProfile> =
+        client.list_all_users_filtered(&super_admin, &0, &10, &None, &None, &None, &engineer, &Some(150), &None);
+    assert_eq!(users.len(), 1);
+    assert_eq!(users.get(0).unwrap().full_name, String::from_str(&env, "Jane Smith"));
+
+    let users: Vec<LightProfile> =
+        client.list_all_users_filtered(&super_admin, &0, &10, &None, &None, &None, &None, &None, &Some(300));
+    assert_eq!(users.len(), 2);
+}
+
 #[test]
 fn test_delete_user() {
     let env: Env = Env::default();
@@ -158,7 +196,7 @@ fn test_delete_user() {
         full_name: String::from_str(&env, "Test User"),
         contact_email: String::from_str(&env, "test@example.com"),
         profession: Some(String::from_str(&env, "Tester")),
-        country: Some(String::from_str(&env, "United States")),
+        country: Some(String::from_str(&env, "US")),
         purpose: Some(String::from_str(&env, "Learn testing")),
         profile_picture_url: None,
     };
@@ -233,7 +271,7 @@ fn test_complete_user_lifecycle() {
         full_name: String::from_str(&env, "John Doe"),
         contact_email: String::from_str(&env, "john@example.com"),
         profession: Some(String::from_str(&env, "Software Engineer")),
-        country: Some(String::from_str(&env, "United States")),
+        country: Some(String::from_str(&env, "US")),
         purpose: Some(String::from_str(&env, "Learn blockchain development")),
         profile_picture_url: None,
     };
@@ -244,10 +282,12 @@ fn test_complete_user_lifecycle() {
     // Step 3: Edit user profile
     let update_params: ProfileUpdateParams = ProfileUpdateParams {
         full_name: Some(String::from_str(&env, "John Smith")),
+        contact_email: None,
         profession: Some(String::from_str(&env, "Senior Software Engineer")),
-        country: Some(String::from_str(&env, "Canada")),
+        country: Some(String::from_str(&env, "CA")),
         purpose: Some(String::from_str(&env, "Master blockchain development")),
         profile_picture_url: None,
+        social_links: None,
     };
 
     let updated_profile: UserProfile = client.edit_user_profile(&user, &user, &update_params);
@@ -257,7 +297,7 @@ fn test_complete_user_lifecycle() {
     // Step 4: Verify profile changes
     let retrieved_profile: UserProfile = client.get_user_by_id(&user, &user);
     assert_eq!(retrieved_profile.full_name, String::from_str(&env, "John Smith"));
-    assert_eq!(retrieved_profile.country, Some(String::from_str(&env, "Canada")));
+    assert_eq!(retrieved_profile.country, Some(String::from_str(&env, "CA")));
 
     // Step 5: Admin can view user profile
     let admin_view: UserProfile = client.get_user_by_id(&super_admin, &user);
@@ -295,9 +335,9 @@ fn test_multi_user_admin_workflow() {
 
     // Step 3: Create multiple users with different profiles
     let users_data: [(&'static str, &'static str, &'static str, &'static str); 3] = [
-        ("Alice Johnson", "alice@example.com", "Data Scientist", "United States"),
-        ("Bob Wilson", "bob@example.com", "Software Engineer", "Canada"),
-        ("Carol Davis", "carol@example.com", "Teacher", "United Kingdom"),
+        ("Alice Johnson", "alice@example.com", "Data Scientist", "US"),
+        ("Bob Wilson", "bob@example.com", "Software Engineer", "CA"),
+        ("Carol Davis", "carol@example.com", "Teacher", "GB"),
     ];
 
     for (i, (name, email, profession, country)) in users_data.iter().enumerate() {
@@ -330,7 +370,7 @@ fn test_multi_user_admin_workflow() {
         &0,
         &10,
         &None,
-        &Some(String::from_str(&env, "United States")),
+        &Some(String::from_str(&env, "US")),
         &None,
     );
     // Note: Country filtering may behave differently than expected
@@ -370,7 +410,7 @@ fn test_user_profile_validation_workflow() {
         full_name: String::from_str(&env, "John Doe"),
         contact_email: String::from_str(&env, "john@example.com"),
         profession: Some(String::from_str(&env, "Developer")),
-        country: Some(String::from_str(&env, "USA")),
+        country: Some(String::from_str(&env, "US")),
         purpose: Some(String::from_str(&env, "Learning")),
         profile_picture_url: None,
     };
@@ -379,7 +419,7 @@ fn test_user_profile_validation_workflow() {
         full_name: String::from_str(&env, "Jane Smith"),
         contact_email: String::from_str(&env, "jane@example.com"),
         profession: Some(String::from_str(&env, "Designer")),
-        country: Some(String::from_str(&env, "Canada")),
+        country: Some(String::from_str(&env, "CA")),
         purpose: Some(String::from_str(&env, "Skill improvement")),
         profile_picture_url: None,
     };
@@ -424,11 +464,11 @@ fn test_pagination_and_filtering_integration() {
 
     // Create multiple users with different attributes
     let test_users: [(&'static str, &'static str, &'static str, &'static str); 5] = [
-        ("Alice", "alice@us.com", "Engineer", "United States"),
-        ("Bob", "bob@ca.com", "Scientist", "Canada"),
-        ("Carol", "carol@us.com", "Teacher", "United States"),
-        ("David", "david@uk.com", "Designer", "United Kingdom"),
-        ("Eve", "eve@us.com", "Manager", "United States"),
+        ("Alice", "alice@us.com", "Engineer", "US"),
+        ("Bob", "bob@ca.com", "Scientist", "CA"),
+        ("Carol", "carol@us.com", "Teacher", "US"),
+        ("David", "david@uk.com", "Designer", "GB"),
+        ("Eve", "eve@us.com", "Manager", "US"),
     ];
 
     for (name, email, profession, country) in test_users.iter() {
@@ -460,7 +500,7 @@ fn test_pagination_and_filtering_integration() {
         &0,
         &10,
         &None,
-        &Some(String::from_str(&env, "United States")),
+        &Some(String::from_str(&env, "US")),
         &None,
     );
     // Note: Country filtering may behave differently than expected
@@ -472,7 +512,7 @@ fn test_pagination_and_filtering_integration() {
         &0,
         &10,
         &None,
-        &Some(String::from_str(&env, "France")),
+        &Some(String::from_str(&env, "FR")),
         &None,
     );
     // Note: Country filtering may not work as expected in test environment
@@ -503,7 +543,7 @@ fn test_error_handling_and_edge_cases() {
         full_name: String::from_str(&env, "Test User"),
         contact_email: String::from_str(&env, "test@example.com"),
         profession: Some(String::from_str(&env, "Tester")),
-        country: Some(String::from_str(&env, "Test Country")),
+        country: Some(String::from_str(&env, "GB")),
         purpose: Some(String::from_str(&env, "Test purpose")),
         profile_picture_url: None,
     };
@@ -523,11 +563,467 @@ fn test_error_handling_and_edge_cases() {
         full_name: String::from_str(&env, "New User"),
         contact_email: String::from_str(&env, "new@example.com"),
         profession: Some(String::from_str(&env, "New Profession")),
-        country: Some(String::from_str(&env, "New Country")),
+        country: Some(String::from_str(&env, "DE")),
         purpose: Some(String::from_str(&env, "New Purpose")),
         profile_picture_url: None,
     };
 
     let created: UserProfile = client.create_user_profile(&new_user, &new_profile);
     assert_eq!(created.full_name, String::from_str(&env, "New User"));
-}
\ No newline at end of file
+}
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #36)")]
+fn test_create_user_profile_rejects_country_name() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    let user: Address = Address::generate(&env);
+
+    let profile: UserProfile = UserProfile {
+        full_name: String::from_str(&env, "Alice Johnson"),
+        contact_email: String::from_str(&env, "alice@example.com"),
+        profession: None,
+        country: Some(String::from_str(&env, "United States")),
+        purpose: None,
+        profile_picture_url: None,
+    };
+
+    env.mock_all_auths();
+    client.create_user_profile(&user, &profile);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_edit_user_profile_rejects_invalid_email() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    let user: Address = Address::generate(&env);
+
+    let profile: UserProfile = UserProfile {
+        full_name: String::from_str(&env, "Alice Johnson"),
+        contact_email: String::from_str(&env, "alice@example.com"),
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: None,
+    };
+
+    env.mock_all_auths();
+    client.create_user_profile(&user, &profile);
+
+    let updates: ProfileUpdateParams = ProfileUpdateParams {
+        full_name: None,
+        contact_email: Some(String::from_str(&env, "alice@example")),
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: None,
+        social_links: None,
+    };
+    client.edit_user_profile(&user, &user, &updates);
+}
+
+#[test]
+fn test_rbac_entrypoints() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let super_admin: Address = Address::generate(&env);
+    let user: Address = Address::generate(&env);
+    client.initialize_system(&super_admin, &super_admin, &None);
+
+    assert!(!client.has_permission(&user, &Permission::CreateCourses));
+
+    client.set_user_role(&super_admin, &user, &UserRole::Instructor);
+    assert!(client.has_permission(&user, &Permission::CreateCourses));
+
+    client.revoke_user_permission(&super_admin, &user, &Permission::CreateCourses);
+    assert!(!client.has_permission(&user, &Permission::CreateCourses));
+    assert!(!client
+        .get_user_permissions(&user, &user)
+        .contains(&Permission::CreateCourses));
+
+    client.grant_user_permission(&super_admin, &user, &Permission::ViewAnalytics);
+    let permissions: Vec<Permission> = client.get_user_permissions(&super_admin, &user);
+    assert!(permissions.contains(&Permission::ViewAnalytics));
+    assert!(permissions.contains(&Permission::EditCourses));
+}
+
+#[test]
+fn test_check_permissions() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let super_admin: Address = Address::generate(&env);
+    let instructor: Address = Address::generate(&env);
+    client.initialize_system(&super_admin, &super_admin, &None);
+    client.set_user_role(&super_admin, &instructor, &UserRole::Instructor);
+
+    let permissions: Vec<Permission> = Vec::from_array(
+        &env,
+        [Permission::CreateCourses, Permission::ManageAdmins, Permission::ViewCourses],
+    );
+    assert_eq!(
+        client.check_permissions(&instructor, &permissions),
+        Vec::from_array(&env, [true, false, true])
+    );
+    assert_eq!(
+        client.check_permissions(&super_admin, &permissions),
+        Vec::from_array(&env, [true, true, true])
+    );
+    assert!(client.check_permissions(&instructor, &Vec::new(&env)).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_set_user_role_requires_manage_admins() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let super_admin: Address = Address::generate(&env);
+    let user: Address = Address::generate(&env);
+    client.initialize_system(&super_admin, &super_admin, &None);
+
+    client.set_user_role(&user, &user, &UserRole::Admin);
+}
+
+#[test]
+fn test_set_rate_limit_config() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let super_admin: Address = Address::generate(&env);
+    client.initialize_system(&super_admin, &super_admin, &None);
+    assert_eq!(client.get_rate_limit_config().max_operations_per_window, 5);
+
+    let rate_config = RateLimitConfig {
+        window_seconds: 86_400,
+        max_operations_per_window: 1,
+    };
+    client.set_rate_limit_config(&super_admin, &rate_config);
+    assert_eq!(client.get_rate_limit_config(), rate_config);
+
+    let stranger: Address = Address::generate(&env);
+    assert!(client.try_set_rate_limit_config(&stranger, &rate_config).is_err());
+}
+
+#[test]
+fn test_edit_user_profile_social_links_and_picture() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let user: Address = Address::generate(&env);
+    let profile: UserProfile = UserProfile {
+        full_name: String::from_str(&env, "Jane Smith"),
+        contact_email: String::from_str(&env, "jane@example.com"),
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: Some(String::from_str(&env, "https://example.com/jane.png")),
+    };
+    client.create_user_profile(&user, &profile);
+
+    let github: SocialLink = SocialLink {
+        platform: String::from_str(&env, "github"),
+        url: String::from_str(&env, "https://github.com/jane"),
+    };
+    let mut updates: ProfileUpdateParams = ProfileUpdateParams {
+        full_name: None,
+        contact_email: None,
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: Some(String::from_str(&env, "")),
+        social_links: Some(Vec::from_array(&env, [github.clone()])),
+    };
+    let updated: UserProfile = client.edit_user_profile(&user, &user, &updates);
+    assert_eq!(updated.profile_picture_url, None);
+    assert_eq!(client.get_social_links(&user), Vec::from_array(&env, [github.clone()]));
+
+    // Duplicate platforms and malformed URLs are rejected
+    updates.social_links = Some(Vec::from_array(&env, [github.clone(), github]));
+    assert!(client.try_edit_user_profile(&user, &user, &updates).is_err());
+    updates.social_links = Some(Vec::from_array(
+        &env,
+        [SocialLink {
+            platform: String::from_str(&env, "blog"),
+            url: String::from_str(&env, "not a url"),
+        }],
+    ));
+    assert!(client.try_edit_user_profile(&user, &user, &updates).is_err());
+    assert_eq!(client.get_social_links(&user).len(), 1);
+
+    // An empty list removes the links
+    updates.social_links = Some(Vec::new(&env));
+    client.edit_user_profile(&user, &user, &updates);
+    assert!(client.get_social_links(&user).is_empty());
+}
+
+#[test]
+fn test_paged_backup_round_trip() {
+    use crate::schema::{BackupCursor, UserBackupPage};
+    use soroban_sdk::BytesN;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let source = UserManagementClient::new(&env, &env.register(UserManagement, ()));
+    let target = UserManagementClient::new(&env, &env.register(UserManagement, ()));
+    let admin = Address::generate(&env);
+    source.initialize_system(&admin, &admin, &None);
+    target.initialize_system(&admin, &admin, &None);
+
+    let emails = ["a@example.com", "b@example.com", "c@example.com"];
+    for email in emails {
+        let user = Address::generate(&env);
+        source.create_user_profile(
+            &user,
+            &UserProfile {
+                full_name: String::from_str(&env, "Backup User"),
+                contact_email: String::from_str(&env, email),
+                profession: None,
+                country: None,
+                purpose: None,
+                profile_picture_url: None,
+            },
+        );
+        source.edit_user_profile(
+            &user,
+            &user,
+            &ProfileUpdateParams {
+                full_name: None,
+                contact_email: None,
+                profession: None,
+                country: None,
+                purpose: None,
+                profile_picture_url: None,
+                social_links: Some(Vec::from_array(
+                    &env,
+                    [SocialLink {
+                        platform: String::from_str(&env, "github"),
+                        url: String::from_str(&env, "https://github.com/backup"),
+                    }],
+                )),
+            },
+        );
+    }
+
+    let first: UserBackupPage = source.export_user_data_page(
+        &admin,
+        &BackupCursor { offset: 0, checksum: BytesN::from_array(&env, &[0u8; 32]) },
+        &2,
+    );
+    assert_eq!(first.entries.len(), 2);
+    assert!(first.has_more);
+    let second: UserBackupPage = source.export_user_data_page(&admin, &first.next_cursor, &2);
+    assert_eq!(second.entries.len(), 1);
+    assert!(!second.has_more);
+
+    // Pages are rejected out of order or when altered
+    assert!(target.try_import_user_data_page(&admin, &second).is_err());
+    let mut tampered: UserBackupPage = first.clone();
+    tampered.entries.pop_back();
+    assert!(target.try_import_user_data_page(&admin, &tampered).is_err());
+
+    assert_eq!(target.import_user_data_page(&admin, &first), 2);
+    assert_eq!(target.import_user_data_page(&admin, &second), 1);
+    let restored = second.entries.get(0).unwrap();
+    assert_eq!(target.get_user_by_id(&admin, &restored.user), restored.profile);
+    assert_eq!(target.get_social_links(&restored.user), restored.social_links);
+    assert_eq!(restored.social_links.len(), 1);
+}
+
+#[test]
+fn test_import_rejects_emails_too_long_to_index() {
+    use crate::error::Error;
+    use validation::MAX_EMAIL_LENGTH;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = UserManagementClient::new(&env, &env.register(UserManagement, ()));
+    let admin = Address::generate(&env);
+    client.initialize_system(&admin, &admin, &None);
+
+    let mut backup = client.export_user_data(&admin);
+    let mut long_email = [b'a'; MAX_EMAIL_LENGTH as usize + 1];
+    long_email[..12].copy_from_slice(b"long@example");
+    backup.email_mappings.set(
+        String::from_bytes(&env, &long_email),
+        Address::generate(&env),
+    );
+
+    let result = client.try_import_user_data(&admin, &backup);
+    assert_eq!(result, Err(Ok(Error::InvalidEmailFormat.into())));
+}
+
+#[test]
+fn test_rebuild_users_index() {
+    use crate::schema::DataKey;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(UserManagement, ());
+    let client = UserManagementClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize_system(&admin, &admin, &None);
+
+    let mut users = Vec::new(&env);
+    for email in ["a@example.com", "b@example.com", "c@example.com"] {
+        let user = Address::generate(&env);
+        client.create_user_profile(
+            &user,
+            &UserProfile {
+                full_name: String::from_str(&env, "Indexed User"),
+                contact_email: String::from_str(&env, email),
+                profession: None,
+                country: None,
+                purpose: None,
+                profile_picture_url: None,
+            },
+        );
+        users.push_back(user);
+    }
+
+    // Corrupt the index with a duplicate and an address without a profile
+    let mut corrupted = users.clone();
+    corrupted.insert(1, users.get(0).unwrap());
+    corrupted.push_back(Address::generate(&env));
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::UsersIndex, &corrupted);
+    });
+
+    assert_eq!(client.rebuild_users_index(&admin, &0, &2), Some(2));
+    assert_eq!(client.rebuild_users_index(&admin, &2, &2), Some(4));
+    assert_eq!(client.rebuild_users_index(&admin, &4, &2), None);
+    let rebuilt: Vec<Address> = env.as_contract(&contract_id, || {
+        env.storage().persistent().get(&DataKey::UsersIndex).unwrap()
+    });
+    assert_eq!(rebuilt, users);
+
+    let result = client.try_rebuild_users_index(&admin, &0, &0);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_migrate_email_index() {
+    use crate::functions::utils::storage_utils::email_hash;
+    use crate::schema::DataKey;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(UserManagement, ());
+    let client = UserManagementClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize_system(&admin, &admin, &None);
+
+    let email = String::from_str(&env, "Legacy@example.com");
+    let profile = |email: &str| UserProfile {
+        full_name: String::from_str(&env, "Legacy User"),
+        contact_email: String::from_str(&env, email),
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: None,
+    };
+    let user = Address::generate(&env);
+    client.create_user_profile(&user, &profile("Legacy@example.com"));
+    client.create_user_profile(&Address::generate(&env), &profile("other@example.com"));
+
+    // Store the email the way it was indexed before hashing
+    let hash = email_hash(&env, &email);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().remove(&DataKey::EmailHash(hash.clone()));
+        env.storage().persistent().set(&DataKey::EmailIndex(email.clone()), &user);
+    });
+
+    // The legacy entry still reserves the email
+    let duplicate = client.try_create_user_profile(&Address::generate(&env), &profile("Legacy@example.com"));
+    assert!(duplicate.is_err());
+    assert_eq!(client.get_user_by_email_hash(&admin, &hash), None);
+
+    assert_eq!(client.migrate_email_index(&admin, &0, &1), Some(1));
+    assert_eq!(client.migrate_email_index(&admin, &1, &1), None);
+    assert_eq!(client.get_user_by_email_hash(&admin, &hash), Some(user));
+    let legacy_left: bool = env.as_contract(&contract_id, || {
+        env.storage().persistent().has(&DataKey::EmailIndex(email.clone()))
+    });
+    assert!(!legacy_left);
+
+    // Case variants now collide with the migrated email
+    let variant = client.try_create_user_profile(&Address::generate(&env), &profile("legacy@EXAMPLE.com"));
+    assert!(variant.is_err());
+}
+
+#[test]
+fn test_contact_email_is_stored_apart_from_the_public_profile() {
+    use crate::schema::{DataKey, PublicUserProfile};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(UserManagement, ());
+    let client = UserManagementClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize_system(&admin, &admin, &None);
+
+    let stored = |user: &Address| -> (PublicUserProfile, Option<String>) {
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            (
+                storage.get(&DataKey::UserProfile(user.clone())).unwrap(),
+                storage.get(&DataKey::ContactEmail(user.clone())),
+            )
+        })
+    };
+    let profile = |email: &str| UserProfile {
+        full_name: String::from_str(&env, "Split User"),
+        contact_email: String::from_str(&env, email),
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: None,
+    };
+    let user = Address::generate(&env);
+    client.create_user_profile(&user, &profile("split@example.com"));
+
+    // The public record has no email field
+    let (public, email) = stored(&user);
+    assert_eq!(public.full_name, String::from_str(&env, "Split User"));
+    assert_eq!(email, Some(String::from_str(&env, "split@example.com")));
+    assert_eq!(
+        client.get_user_by_id(&user, &user).contact_email,
+        String::from_str(&env, "split@example.com")
+    );
+
+    // A profile stored before the split is still read, then migrated
+    let legacy = Address::generate(&env);
+    client.create_user_profile(&legacy, &profile("legacy@example.com"));
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().remove(&DataKey::ContactEmail(legacy.clone()));
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserProfile(legacy.clone()), &profile("legacy@example.com"));
+    });
+    assert_eq!(
+        client.get_user_by_id(&admin, &legacy).contact_email,
+        String::from_str(&env, "legacy@example.com")
+    );
+
+    assert_eq!(client.migrate_profile_split(&admin, &0, &50), None);
+    let (public, email) = stored(&legacy);
+    assert_eq!(public.full_name, String::from_str(&env, "Split User"));
+    assert_eq!(email, Some(String::from_str(&env, "legacy@example.com")));
+    assert_eq!(
+        client.get_user_by_id(&admin, &legacy).contact_email,
+        String::from_str(&env, "legacy@example.com")
+    );
+}
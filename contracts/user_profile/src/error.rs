@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, panic_with_error, Env};
+use soroban_sdk::{contracterror, panic_with_error, Env, String};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -14,4 +14,18 @@ pub enum Error {
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
     panic_with_error!(env, error);
-}
\ No newline at end of file
+}
+
+/// Returns the name and message of an error code as "Name: message".
+///
+/// Names match the variants above and don't change between versions; codes
+/// this contract doesn't use return an "Unknown" description.
+pub fn describe_error(env: &Env, code: u32) -> String {
+    let description: &str = match code {
+        1 => "UserProfileNotFound: The user profile does not exist",
+        2 => "InvalidInput: The input is not valid",
+        3 => "UnauthorizedAccess: The caller is not allowed to do this",
+        _ => "Unknown: No error of this contract has this code",
+    };
+    String::from_str(env, description)
+}
@@ -1,43 +1,70 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, String};
 
-use crate::schema::UserProfile;
 use crate::error::{Error, handle_error};
+use crate::functions::privacy::get_privacy_settings;
+use crate::functions::storage::{load_private_profile, load_public_profile};
+use crate::schema::{PrivacySettings, PublicProfile, UserProfile};
 
-const PROFILE_KEY: Symbol = symbol_short!("profile");
-
+// Returns the profile as seen by anyone: private fields are only included
+// when the owner made them visible
 pub fn user_profile_get_user_profile(env: &Env, user_address: Address) -> UserProfile {
-    // Input validation
-    // If Address type supports is_empty or similar, add check. Otherwise, skip.
-    // For demonstration, assume Address cannot be empty.
-
-    // Get the user profile from storage with proper error handling
-    match env
-        .storage()
-        .instance()
-        .get::<(Symbol, Address), UserProfile>(&(PROFILE_KEY, user_address.clone()))
-    {
-        Some(profile) => profile,
-        None => handle_error(env, Error::UserProfileNotFound),
-    }
+    view_profile(env, user_address, None)
 }
 
 // Function to get user profile with privacy check
-// Returns profile only if it's public or if the requester is the profile owner
+// The owner sees everything; others see the fields the owner's privacy
+// settings expose, or everything but the email of a non-public profile
+// when the owner never chose any settings
 pub fn get_user_profile_with_privacy(
     env: &Env,
     user_address: Address,
     requester_address: Address,
 ) -> UserProfile {
-    // Reuse the optimized get_user_profile function
-    let mut profile: UserProfile = user_profile_get_user_profile(env, user_address.clone());
-    
-    // Check privacy settings and apply privacy filters without additional storage reads
-    if !profile.privacy_public && requester_address != user_address {
-        profile.email = None;
-        // Add more privacy filters as needed
+    view_profile(env, user_address, Some(requester_address))
+}
+
+fn view_profile(env: &Env, user_address: Address, requester: Option<Address>) -> UserProfile {
+    let public: PublicProfile = load_public_profile(env, &user_address)
+        .unwrap_or_else(|| handle_error(env, Error::UserProfileNotFound));
+    let is_owner: bool = requester.as_ref() == Some(&user_address);
+    let settings: Option<PrivacySettings> = if is_owner {
+        None
+    } else {
+        get_privacy_settings(env, user_address.clone())
+    };
+
+    // The private record is only read once the requester may see it
+    let show_email: bool = is_owner
+        || settings
+            .as_ref()
+            .map_or(public.privacy_public, |settings| settings.show_email);
+    let email: Option<String> = if show_email {
+        load_private_profile(env, &user_address).and_then(|private| private.email)
+    } else {
+        None
+    };
+
+    let mut profile: UserProfile = UserProfile {
+        address: public.address,
+        name: public.name,
+        email,
+        country: public.country,
+        profession: public.profession,
+        goals: public.goals,
+        privacy_public: public.privacy_public,
+        created_at: public.created_at,
+        updated_at: public.updated_at,
+    };
+    if let Some(settings) = settings {
+        if !settings.show_country {
+            profile.country = String::from_str(env, "");
+        }
+        if !settings.show_profession {
+            profile.profession = String::from_str(env, "");
+        }
     }
     profile
 }
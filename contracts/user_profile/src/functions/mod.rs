@@ -2,3 +2,5 @@
 // Copyright (c) 2025 SkillCert
 
 pub mod get_user_profile;
+pub mod privacy;
+pub mod storage;
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env};
+
+use crate::error::{handle_error, Error};
+use crate::functions::storage::load_public_profile;
+use crate::schema::{DataKey, PrivacySettings};
+
+/// Stores the privacy preferences of a user who has a profile.
+pub fn set_privacy_settings(env: &Env, user: Address, settings: PrivacySettings) {
+    user.require_auth();
+
+    if load_public_profile(env, &user).is_none() {
+        handle_error(env, Error::UserProfileNotFound)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Privacy(user), &settings);
+}
+
+/// Returns the privacy preferences of a user, `None` if they never set any.
+pub fn get_privacy_settings(env: &Env, user: Address) -> Option<PrivacySettings> {
+    env.storage().persistent().get(&DataKey::Privacy(user))
+}
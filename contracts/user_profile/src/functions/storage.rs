@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Profile storage, split into public and private records.
+//!
+//! Public fields and private fields (the email) live under separate keys so
+//! reads only load the private record once the requester may see it.
+//! Profiles stored before the split sit under the legacy
+//! `("profile", address)` instance key until `migrate_profile` moves them.
+//!
+//! This covers the records kept by this contract. The profiles written by
+//! user_management are split the same way in its `profile_store` module.
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::schema::{DataKey, PrivateProfile, PublicProfile, UserProfile};
+
+const PROFILE_KEY: Symbol = symbol_short!("profile");
+
+fn legacy_profile(env: &Env, user: &Address) -> Option<UserProfile> {
+    env.storage()
+        .instance()
+        .get::<(Symbol, Address), UserProfile>(&(PROFILE_KEY, user.clone()))
+}
+
+/// Returns the public record of a profile, if the user has one.
+pub fn load_public_profile(env: &Env, user: &Address) -> Option<PublicProfile> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, PublicProfile>(&DataKey::Profile(user.clone()))
+        .or_else(|| legacy_profile(env, user).map(|profile| split_profile(profile).0))
+}
+
+/// Returns the private record of a profile, if the user has one.
+pub fn load_private_profile(env: &Env, user: &Address) -> Option<PrivateProfile> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, PrivateProfile>(&DataKey::PrivateProfile(user.clone()))
+        .or_else(|| legacy_profile(env, user).map(|profile| split_profile(profile).1))
+}
+
+fn split_profile(profile: UserProfile) -> (PublicProfile, PrivateProfile) {
+    (
+        PublicProfile {
+            address: profile.address,
+            name: profile.name,
+            country: profile.country,
+            profession: profile.profession,
+            goals: profile.goals,
+            privacy_public: profile.privacy_public,
+            created_at: profile.created_at,
+            updated_at: profile.updated_at,
+        },
+        PrivateProfile {
+            email: profile.email,
+        },
+    )
+}
+
+/// Moves a profile stored before the public/private split to the split
+/// records. Returns `false` if there was nothing to migrate.
+pub fn migrate_profile(env: &Env, user: Address) -> bool {
+    let Some(profile) = legacy_profile(env, &user) else {
+        return false;
+    };
+
+    let (public, private) = split_profile(profile);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Profile(user.clone()), &public);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PrivateProfile(user.clone()), &private);
+    env.storage().instance().remove(&(PROFILE_KEY, user));
+    true
+}
@@ -13,8 +13,8 @@ pub mod schema;
 #[cfg(test)]
 mod test;
 
-use crate::schema::UserProfile;
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use crate::schema::{PrivacySettings, UserProfile};
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
 
 /// User Profile Contract
 ///
@@ -28,7 +28,9 @@ impl UserProfileContract {
     /// Get a user profile by address.
     ///
     /// This function retrieves a user's profile information using their blockchain address.
-    /// This is a public function that returns basic profile information.
+    /// This is a public function that returns the profile as anyone may see it:
+    /// private fields such as the email are only included when the owner made
+    /// them visible.
     ///
     /// # Arguments
     ///
@@ -56,7 +58,8 @@ impl UserProfileContract {
     ///
     /// # Returns
     ///
-    /// Returns the `UserProfile` with privacy-filtered information.
+    /// Returns the `UserProfile` with privacy-filtered information. Fields the
+    /// owner's privacy settings hide are blank for anyone but the owner.
     pub fn get_user_profile_with_privacy(
         env: Env,
         user_address: Address,
@@ -68,4 +71,54 @@ impl UserProfileContract {
             requester_address,
         )
     }
+
+    /// Set the privacy preferences of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The profile owner, who must authorize the call
+    /// * `settings` - Which fields others can see and whether the user is
+    ///   listed in user searches
+    ///
+    /// # Panics
+    ///
+    /// * If the user has no profile
+    pub fn set_privacy_settings(env: Env, user: Address, settings: PrivacySettings) {
+        functions::privacy::set_privacy_settings(&env, user, settings)
+    }
+
+    /// Get the privacy preferences of a user, `None` if they never set any.
+    pub fn get_privacy_settings(env: Env, user: Address) -> Option<PrivacySettings> {
+        functions::privacy::get_privacy_settings(&env, user)
+    }
+
+    /// Move a profile stored before the public/private split to the split
+    /// storage records.
+    ///
+    /// Anyone may call it, since the profile content does not change.
+    /// Profiles that were not migrated stay readable, but reading them loads
+    /// the private fields.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a legacy profile was migrated, `false` if there was none.
+    pub fn migrate_profile(env: Env, user_address: Address) -> bool {
+        functions::storage::migrate_profile(&env, user_address)
+    }
+
+    /// Describe an error code of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `code` - The code of a contract error raised by this contract
+    ///
+    /// # Returns
+    ///
+    /// "Name: message", e.g. for showing a readable error in a dapp without
+    /// the contract source. Unknown codes return an "Unknown" description.
+    pub fn describe_error(env: Env, code: u32) -> String {
+        error::describe_error(&env, code)
+    }
 }
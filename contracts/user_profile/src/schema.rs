@@ -6,7 +6,8 @@ use soroban_sdk::{contracttype, Address, String};
 /// User profile information with privacy controls.
 ///
 /// This struct represents a user's profile with optional privacy settings
-/// and timestamps for tracking creation and updates.
+/// and timestamps for tracking creation and updates. It is stored split
+/// into a `PublicProfile` and a `PrivateProfile`.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct UserProfile {
@@ -30,6 +31,44 @@ pub struct UserProfile {
     pub updated_at: u64,
 }
 
+/// Public part of a stored profile, readable without a permission check.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicProfile {
+    pub address: Address,
+    pub name: String,
+    pub country: String,
+    pub profession: String,
+    pub goals: String,
+    pub privacy_public: bool,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Private part of a stored profile, only loaded for requesters allowed to
+/// see it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrivateProfile {
+    pub email: Option<String>,
+}
+
+/// Privacy preferences chosen by a user.
+///
+/// Hidden fields are blanked out when anyone but the owner reads the profile.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrivacySettings {
+    /// Whether others can see the email address
+    pub show_email: bool,
+    /// Whether others can see the country
+    pub show_country: bool,
+    /// Whether others can see the profession
+    pub show_profession: bool,
+    /// Whether the user appears in user listings and searches
+    pub discoverable: bool,
+}
+
 /// Storage keys for user profile data.
 ///
 /// This enum defines the keys used to store and retrieve
@@ -37,6 +76,10 @@ pub struct UserProfile {
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    /// Key for storing user profiles: address -> UserProfile
+    /// Key for storing the public part of user profiles: address -> PublicProfile
     Profile(Address),
+    /// Key for storing the private part of user profiles: address -> PrivateProfile
+    PrivateProfile(Address),
+    /// Key for storing privacy preferences: address -> PrivacySettings
+    Privacy(Address),
 }
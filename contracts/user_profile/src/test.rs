@@ -3,6 +3,7 @@
 
 use soroban_sdk::{testutils::Address as _, Address, Env, String, Symbol};
 
+use crate::schema::{DataKey, PrivacySettings, PrivateProfile};
 use crate::{UserProfile, UserProfileContract, UserProfileContractClient};
 
 /// Helper function to create a test user profile
@@ -237,3 +238,85 @@ fn test_multiple_users_profiles() {
     assert_eq!(result2, profile2);
     assert_ne!(result1, result2);
 }
+
+#[test]
+fn test_privacy_settings_hide_fields_from_others() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, ());
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    let user_address: Address = Address::generate(&env);
+    let requester_address: Address = Address::generate(&env);
+    let profile: UserProfile = create_test_profile(&env, user_address.clone());
+    env.as_contract(&contract_id, || {
+        save_profile_to_storage(&env, &profile);
+    });
+
+    let settings: PrivacySettings = PrivacySettings {
+        show_email: false,
+        show_country: false,
+        show_profession: true,
+        discoverable: true,
+    };
+    client.set_privacy_settings(&user_address, &settings);
+    assert_eq!(client.get_privacy_settings(&user_address), Some(settings));
+
+    // Others only see the fields the owner exposes
+    let result: UserProfile = client.get_user_profile_with_privacy(&user_address, &requester_address);
+    assert_eq!(result.email, None);
+    assert_eq!(result.country, String::from_str(&env, ""));
+    assert_eq!(result.profession, profile.profession);
+
+    // The owner still sees everything
+    let result: UserProfile = client.get_user_profile_with_privacy(&user_address, &user_address);
+    assert_eq!(result, profile);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_privacy_settings_requires_profile() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, ());
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    let settings: PrivacySettings = PrivacySettings {
+        show_email: true,
+        show_country: true,
+        show_profession: true,
+        discoverable: false,
+    };
+    client.set_privacy_settings(&Address::generate(&env), &settings);
+}
+
+#[test]
+fn test_migrate_profile_splits_private_fields() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserProfileContract, ());
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    let user_address: Address = Address::generate(&env);
+    let mut profile: UserProfile = create_test_profile(&env, user_address.clone());
+    profile.privacy_public = false;
+    env.as_contract(&contract_id, || {
+        save_profile_to_storage(&env, &profile);
+    });
+
+    assert!(client.migrate_profile(&user_address));
+    assert!(!client.migrate_profile(&user_address));
+    env.as_contract(&contract_id, || {
+        let legacy_key = (Symbol::new(&env, "profile"), user_address.clone());
+        assert!(!env.storage().instance().has(&legacy_key));
+        let private: PrivateProfile = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PrivateProfile(user_address.clone()))
+            .unwrap();
+        assert_eq!(private.email, profile.email);
+    });
+
+    // Reads are unchanged after the migration
+    assert_eq!(client.get_user_profile_with_privacy(&user_address, &user_address), profile);
+    assert_eq!(client.get_user_profile(&user_address).email, None);
+}
@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+//! Profile field validation shared by the SkillCert contracts.
+//!
+//! Each validator only reports whether a value is well formed; contracts map a
+//! failed check to their own per-field error code. Shared string helpers live
+//! in submodules.
+
+pub mod string_search;
+pub mod text;
+
+use soroban_sdk::String;
+
+/// Maximum email length (RFC 5321)
+pub const MAX_EMAIL_LENGTH: u32 = 320;
+
+/// Minimum email length ("a@b.c")
+pub const MIN_EMAIL_LENGTH: u32 = 5;
+
+/// Maximum URL length accepted for profile and course links
+pub const MAX_URL_LENGTH: u32 = 2048;
+
+/// Length of an ISO 3166-1 alpha-2 country code
+pub const COUNTRY_CODE_LENGTH: u32 = 2;
+
+const HTTP_SCHEME: &[u8] = b"http://";
+const HTTPS_SCHEME: &[u8] = b"https://";
+
+/// Validates an email address.
+///
+/// Checks for:
+/// - Length between `MIN_EMAIL_LENGTH` and `MAX_EMAIL_LENGTH`
+/// - Exactly one `@` with a non-empty local part
+/// - A domain containing a dot, not starting or ending with a dot
+/// - No whitespace or control characters
+///
+/// # Arguments
+/// * `email` - The email address to validate
+///
+/// # Returns
+/// * `bool` - true if the email is well formed, false otherwise
+pub fn validate_email(email: &String) -> bool {
+    let len: u32 = email.len();
+    if !(MIN_EMAIL_LENGTH..=MAX_EMAIL_LENGTH).contains(&len) {
+        return false;
+    }
+
+    let mut buffer: [u8; MAX_EMAIL_LENGTH as usize] = [0u8; MAX_EMAIL_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buffer[..len as usize];
+    email.copy_into_slice(bytes);
+
+    if bytes.iter().any(|b| *b <= b' ' || *b == 0x7f) {
+        return false;
+    }
+
+    let mut parts = bytes.split(|b| *b == b'@');
+    let (local, domain) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(local), Some(domain), None) => (local, domain),
+        _ => return false,
+    };
+
+    if local.is_empty() || domain.is_empty() {
+        return false;
+    }
+    if domain[0] == b'.' || domain[domain.len() - 1] == b'.' {
+        return false;
+    }
+    if domain.windows(2).any(|w| w == b"..") {
+        return false;
+    }
+
+    domain.contains(&b'.')
+}
+
+/// Validates an HTTP(S) URL.
+///
+/// Checks for:
+/// - Length up to `MAX_URL_LENGTH`
+/// - An `http://` or `https://` scheme
+/// - A non-empty host containing a dot
+/// - No whitespace or control characters
+///
+/// # Arguments
+/// * `url` - The URL to validate
+///
+/// # Returns
+/// * `bool` - true if the URL is well formed, false otherwise
+pub fn validate_url(url: &String) -> bool {
+    let len: u32 = url.len();
+    if len == 0 || len > MAX_URL_LENGTH {
+        return false;
+    }
+
+    let mut buffer: [u8; MAX_URL_LENGTH as usize] = [0u8; MAX_URL_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buffer[..len as usize];
+    url.copy_into_slice(bytes);
+
+    if bytes.iter().any(|b| *b <= b' ' || *b == 0x7f) {
+        return false;
+    }
+
+    let rest: &[u8] = if bytes.starts_with(HTTPS_SCHEME) {
+        &bytes[HTTPS_SCHEME.len()..]
+    } else if bytes.starts_with(HTTP_SCHEME) {
+        &bytes[HTTP_SCHEME.len()..]
+    } else {
+        return false;
+    };
+
+    let host_end: usize = rest
+        .iter()
+        .position(|b| matches!(*b, b'/' | b'?' | b'#'))
+        .unwrap_or(rest.len());
+    let host: &[u8] = &rest[..host_end];
+
+    if host.is_empty() || host[0] == b'.' || host[host.len() - 1] == b'.' {
+        return false;
+    }
+
+    host.contains(&b'.')
+}
+
+/// Validates an ISO 3166-1 alpha-2 country code (e.g. "US", "CA").
+///
+/// # Arguments
+/// * `country` - The country code to validate
+///
+/// # Returns
+/// * `bool` - true if the value is two uppercase ASCII letters, false otherwise
+pub fn validate_country_code(country: &String) -> bool {
+    if country.len() != COUNTRY_CODE_LENGTH {
+        return false;
+    }
+
+    let mut code: [u8; COUNTRY_CODE_LENGTH as usize] = [0u8; COUNTRY_CODE_LENGTH as usize];
+    country.copy_into_slice(&mut code);
+
+    code.iter().all(|b| b.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{Env, String};
+
+    #[test]
+    fn test_valid_emails() {
+        let env = Env::default();
+        assert!(validate_email(&String::from_str(&env, "john@example.com")));
+        assert!(validate_email(&String::from_str(&env, "a.b+tag@mail.example.org")));
+    }
+
+    #[test]
+    fn test_invalid_emails() {
+        let env = Env::default();
+        for email in [
+            "",
+            "bad",
+            "invalid-email",
+            "@example.com",
+            "john@",
+            "john@example",
+            "john@@example.com",
+            "john@.example.com",
+            "john@example.com.",
+            "john@example..com",
+            "john doe@example.com",
+        ] {
+            assert!(!validate_email(&String::from_str(&env, email)), "{}", email);
+        }
+    }
+
+    #[test]
+    fn test_valid_urls() {
+        let env = Env::default();
+        assert!(validate_url(&String::from_str(&env, "https://example.com")));
+        assert!(validate_url(&String::from_str(&env, "http://example.com")));
+        assert!(validate_url(&String::from_str(&env, "https://example.com/path/to/image.jpg")));
+        assert!(validate_url(&String::from_str(&env, "https://cdn.example.com?size=64")));
+    }
+
+    #[test]
+    fn test_invalid_urls() {
+        let env = Env::default();
+        for url in [
+            "",
+            "example.com",
+            "ftp://example.com",
+            "https://",
+            "https:///path",
+            "https://localhost",
+            "https://exa mple.com",
+        ] {
+            assert!(!validate_url(&String::from_str(&env, url)), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_country_codes() {
+        let env = Env::default();
+        assert!(validate_country_code(&String::from_str(&env, "US")));
+        assert!(validate_country_code(&String::from_str(&env, "CA")));
+        assert!(!validate_country_code(&String::from_str(&env, "us")));
+        assert!(!validate_country_code(&String::from_str(&env, "USA")));
+        assert!(!validate_country_code(&String::from_str(&env, "Canada")));
+        assert!(!validate_country_code(&String::from_str(&env, "")));
+    }
+}
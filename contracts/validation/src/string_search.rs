@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Case-insensitive substring search on Soroban Strings.
+//!
+//! Soroban Strings can't be sliced or compared piecewise, so both strings are
+//! copied into fixed stack buffers first. Only ASCII letters are folded;
+//! other bytes, including multi-byte UTF-8 sequences, must match exactly.
+
+use soroban_sdk::String;
+
+/// Maximum length in bytes of a searchable text; longer texts never match.
+/// Fits course descriptions, the longest searched field.
+pub const MAX_HAYSTACK_LENGTH: u32 = 2048;
+
+/// Maximum length in bytes of a search term; longer terms never match.
+pub const MAX_NEEDLE_LENGTH: u32 = 100;
+
+/// Returns whether `haystack` contains `needle`, ignoring ASCII case.
+///
+/// An empty `needle` matches every text.
+///
+/// # Arguments
+/// * `haystack` - The text to search in, at most `MAX_HAYSTACK_LENGTH` bytes
+/// * `needle` - The search term, at most `MAX_NEEDLE_LENGTH` bytes
+///
+/// # Returns
+/// * `bool` - true if the term occurs in the text, false otherwise or if
+///   either is too long
+pub fn contains_ignore_case(haystack: &String, needle: &String) -> bool {
+    let needle_len: u32 = needle.len();
+    let haystack_len: u32 = haystack.len();
+    if needle_len == 0 {
+        return true;
+    }
+    if needle_len > MAX_NEEDLE_LENGTH || haystack_len > MAX_HAYSTACK_LENGTH || needle_len > haystack_len {
+        return false;
+    }
+
+    let mut needle_buffer: [u8; MAX_NEEDLE_LENGTH as usize] = [0u8; MAX_NEEDLE_LENGTH as usize];
+    let needle_bytes: &mut [u8] = &mut needle_buffer[..needle_len as usize];
+    needle.copy_into_slice(needle_bytes);
+    needle_bytes.make_ascii_lowercase();
+
+    let mut haystack_buffer: [u8; MAX_HAYSTACK_LENGTH as usize] = [0u8; MAX_HAYSTACK_LENGTH as usize];
+    let haystack_bytes: &mut [u8] = &mut haystack_buffer[..haystack_len as usize];
+    haystack.copy_into_slice(haystack_bytes);
+    haystack_bytes.make_ascii_lowercase();
+
+    haystack_bytes
+        .windows(needle_bytes.len())
+        .any(|window| window == needle_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{Env, String};
+
+    #[test]
+    fn test_contains_ignore_case() {
+        let env = Env::default();
+        let title = String::from_str(&env, "Rust Programming for Beginners");
+        for needle in ["", "Rust", "rust", "PROGRAMMING", "for beg", "Rust Programming for Beginners"] {
+            assert!(contains_ignore_case(&title, &String::from_str(&env, needle)), "{}", needle);
+        }
+        for needle in ["Python", "Rust  Programming", "Beginners!"] {
+            assert!(!contains_ignore_case(&title, &String::from_str(&env, needle)), "{}", needle);
+        }
+    }
+
+    #[test]
+    fn test_contains_ignore_case_limits() {
+        let env = Env::default();
+
+        // Non-ASCII letters are compared as-is
+        let text = String::from_str(&env, "Programación avanzada");
+        assert!(contains_ignore_case(&text, &String::from_str(&env, "PROGRAMACIóN")));
+        assert!(!contains_ignore_case(&text, &String::from_str(&env, "PROGRAMACIÓN")));
+
+        let long_term = String::from_bytes(&env, &[b'a'; MAX_NEEDLE_LENGTH as usize + 1]);
+        assert!(!contains_ignore_case(&long_term, &long_term));
+        let long_text = String::from_bytes(&env, &[b'a'; MAX_HAYSTACK_LENGTH as usize + 1]);
+        assert!(!contains_ignore_case(&long_text, &String::from_str(&env, "a")));
+    }
+}
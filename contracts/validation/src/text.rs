@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! UTF-8 safe normalization of Soroban Strings.
+//!
+//! Strings are copied into a fixed stack buffer and only ever cut at code
+//! point boundaries, so multi-byte characters like the "ó" of "Programación"
+//! are never split or altered.
+
+use soroban_sdk::{Env, String};
+
+/// Maximum length in bytes of a string these helpers normalize; longer
+/// strings are returned unchanged.
+pub const MAX_TEXT_LENGTH: u32 = 2048;
+
+/// Lowercases the ASCII letters of a string.
+///
+/// Only ASCII is folded, which never changes the byte length or touches
+/// multi-byte characters, so storage keys derived from lowercased titles stay
+/// stable.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `s` - The string to lowercase
+///
+/// # Returns
+/// * `String` - The string with `A`-`Z` replaced by `a`-`z`
+pub fn to_lowercase(env: &Env, s: &String) -> String {
+    let len: u32 = s.len();
+    if len > MAX_TEXT_LENGTH {
+        return s.clone();
+    }
+
+    let mut buffer: [u8; MAX_TEXT_LENGTH as usize] = [0u8; MAX_TEXT_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buffer[..len as usize];
+    s.copy_into_slice(bytes);
+    bytes.make_ascii_lowercase();
+    String::from_bytes(env, bytes)
+}
+
+/// Removes leading and trailing whitespace from a string.
+///
+/// Valid UTF-8 is trimmed of Unicode whitespace (including no-break and
+/// ideographic spaces) at character boundaries; anything else is trimmed of
+/// ASCII whitespace only.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `s` - The string to trim
+///
+/// # Returns
+/// * `String` - The string without surrounding whitespace
+pub fn trim(env: &Env, s: &String) -> String {
+    let len: u32 = s.len();
+    if len > MAX_TEXT_LENGTH {
+        return s.clone();
+    }
+
+    let mut buffer: [u8; MAX_TEXT_LENGTH as usize] = [0u8; MAX_TEXT_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buffer[..len as usize];
+    s.copy_into_slice(bytes);
+    let trimmed: &[u8] = match core::str::from_utf8(bytes) {
+        Ok(text) => text.trim().as_bytes(),
+        Err(_) => bytes.trim_ascii(),
+    };
+    String::from_bytes(env, trimmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{Env, String};
+
+    /// Characters of 1 to 4 bytes, including Unicode whitespace.
+    const ALPHABET: [&str; 14] = [
+        "a", "Z", " ", "\t", "\n", "ó", "Ó", "ñ", "\u{a0}", "\u{3000}", "語", "é", "🦀", "-",
+    ];
+    const MAX_SAMPLE_LENGTH: usize = 64;
+
+    /// Deterministic pseudo-random strings over `ALPHABET`, as
+    /// (buffer, length) pairs.
+    fn samples() -> impl Iterator<Item = ([u8; MAX_SAMPLE_LENGTH * 4], usize)> {
+        let mut seed: u64 = 0x5eed;
+        (0..300).map(move |_| {
+            let mut buffer = [0u8; MAX_SAMPLE_LENGTH * 4];
+            let mut len: usize = 0;
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            for _ in 0..(seed >> 33) as usize % MAX_SAMPLE_LENGTH {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                let c: &[u8] = ALPHABET[(seed >> 33) as usize % ALPHABET.len()].as_bytes();
+                buffer[len..len + c.len()].copy_from_slice(c);
+                len += c.len();
+            }
+            (buffer, len)
+        })
+    }
+
+    fn as_str<'a>(s: &String, buffer: &'a mut [u8]) -> &'a str {
+        let bytes: &mut [u8] = &mut buffer[..s.len() as usize];
+        s.copy_into_slice(bytes);
+        core::str::from_utf8(bytes).expect("result is valid UTF-8")
+    }
+
+    #[test]
+    fn test_to_lowercase_properties() {
+        let env = Env::default();
+        for (buffer, len) in samples() {
+            let input: &str = core::str::from_utf8(&buffer[..len]).unwrap();
+            let lowered: String = to_lowercase(&env, &String::from_str(&env, input));
+
+            // Valid UTF-8 of the same length, only ASCII letters changed
+            let mut out = [0u8; MAX_SAMPLE_LENGTH * 4];
+            let output: &str = as_str(&lowered, &mut out);
+            assert_eq!(output.len(), input.len());
+            assert!(input.chars().zip(output.chars()).all(|(i, o)| o == i.to_ascii_lowercase()));
+
+            // Idempotent
+            assert_eq!(to_lowercase(&env, &lowered), lowered);
+        }
+        assert_eq!(
+            to_lowercase(&env, &String::from_str(&env, "Programación AVANZADA")),
+            String::from_str(&env, "programación avanzada")
+        );
+    }
+
+    #[test]
+    fn test_trim_properties() {
+        let env = Env::default();
+        for (buffer, len) in samples() {
+            let input: &str = core::str::from_utf8(&buffer[..len]).unwrap();
+            let trimmed: String = trim(&env, &String::from_str(&env, input));
+
+            // Valid UTF-8, a substring of the input without surrounding whitespace
+            let mut out = [0u8; MAX_SAMPLE_LENGTH * 4];
+            let output: &str = as_str(&trimmed, &mut out);
+            assert_eq!(output, input.trim());
+            assert!(!output.starts_with(char::is_whitespace));
+            assert!(!output.ends_with(char::is_whitespace));
+
+            // Idempotent
+            assert_eq!(trim(&env, &trimmed), trimmed);
+        }
+        assert_eq!(
+            trim(&env, &String::from_str(&env, "\u{a0} Programación\t")),
+            String::from_str(&env, "Programación")
+        );
+    }
+}